@@ -46,7 +46,7 @@
 `+z[c].replace(" at new "," at ");return r.displayName&&te.includes("<anonymous>")&&(te=te.replace("<anonymous>",r.displayName)),te}while(1<=c&&0<=v);break}}}finally{it=!1,Error.prepareStackTrace=l}return(l=r?r.displayName||r.name:"")?Ae(l):""}function zt(r,i){switch(r.tag){case 26:case 27:case 5:return Ae(r.type);case 16:return Ae("Lazy");case 13:return r.child!==i&&i!==null?Ae("Suspense Fallback"):Ae("Suspense");case 19:return Ae("SuspenseList");case 0:case 15:return ut(r.type,!1);case 11:return ut(r.type.render,!1);case 1:return ut(r.type,!0);case 31:return Ae("Activity");default:return""}}function Cn(r){try{var i="",l=null;do i+=zt(r,l),l=r,r=r.return;while(r);return i}catch(c){return`
 Error generating stack: `+c.message+`
 `+c.stack}}var kn=Object.prototype.hasOwnProperty,Dr=e.unstable_scheduleCallback,Nn=e.unstable_cancelCallback,er=e.unstable_shouldYield,ju=e.unstable_requestPaint,$t=e.unstable_now,No=e.unstable_getCurrentPriorityLevel,zb=e.unstable_ImmediatePriority,$b=e.unstable_UserBlockingPriority,Do=e.unstable_NormalPriority,qN=e.unstable_LowPriority,Bb=e.unstable_IdlePriority,zN=e.log,$N=e.unstable_setDisableYieldValue,Mu=null,en=null;function Rr(r){if(typeof zN=="function"&&$N(r),en&&typeof en.setStrictMode=="function")try{en.setStrictMode(Mu,r)}catch{}}var tn=Math.clz32?Math.clz32:UN,BN=Math.log,LN=Math.LN2;function UN(r){return r>>>=0,r===0?32:31-(BN(r)/LN|0)|0}var Ro=256,Po=262144,qo=4194304;function pa(r){var i=r&42;if(i!==0)return i;switch(r&-r){case 1:return 1;case 2:return 2;case 4:return 4;case 8:return 8;case 16:return 16;case 32:return 32;case 64:return 64;case 128:return 128;case 256:case 512:case 1024:case 2048:case 4096:case 8192:case 16384:case 32768:case 65536:case 131072:return r&261888;case 262144:case 524288:case 1048576:case 2097152:return r&3932160;case 4194304:case 8388608:case 16777216:case 33554432:return r&62914560;case 67108864:return 67108864;case 134217728:return 134217728;case 268435456:return 268435456;case 536870912:return 536870912;case 1073741824:return 0;default:return r}}function zo(r,i,l){var c=r.pendingLanes;if(c===0)return 0;var v=0,p=r.suspendedLanes,S=r.pingedLanes;r=r.warmLanes;var C=c&134217727;return C!==0?(c=C&~p,c!==0?v=pa(c):(S&=C,S!==0?v=pa(S):l||(l=C&~r,l!==0&&(v=pa(l))))):(C=c&~p,C!==0?v=pa(C):S!==0?v=pa(S):l||(l=c&~r,l!==0&&(v=pa(l)))),v===0?0:i!==0&&i!==v&&(i&p)===0&&(p=v&-v,l=i&-i,p>=l||p===32&&(l&4194048)!==0)?i:v}function Cu(r,i){return(r.pendingLanes&~(r.suspendedLanes&~r.pingedLanes)&i)===0}function kN(r,i){switch(r){case 1:case 2:case 4:case 8:case 64:return i+250;case 16:case 32:case 128:case 256:case 512:case 1024:case 2048:case 4096:case 8192:case 16384:case 32768:case 65536:case 131072:case 262144:case 524288:case 1048576:case 2097152:return i+5e3;case 4194304:case 8388608:case 16777216:case 33554432:return-1;case 67108864:case 134217728:case 268435456:case 536870912:case 1073741824:return-1;default:return-1}}function Lb(){var r=qo;return qo<<=1,(qo&62914560)===0&&(qo=4194304),r}function Yf(r){for(var i=[],l=0;31>l;l++)i.push(r);return i}function Nu(r,i){r.pendingLanes|=i,i!==268435456&&(r.suspendedLanes=0,r.pingedLanes=0,r.warmLanes=0)}function HN(r,i,l,c,v,p){var S=r.pendingLanes;r.pendingLanes=l,r.suspendedLanes=0,r.pingedLanes=0,r.warmLanes=0,r.expiredLanes&=l,r.entangledLanes&=l,r.errorRecoveryDisabledLanes&=l,r.shellSuspendCounter=0;var C=r.entanglements,z=r.expirationTimes,X=r.hiddenUpdates;for(l=S&~l;0<l;){var te=31-tn(l),ie=1<<te;C[te]=0,z[te]=-1;var K=X[te];if(K!==null)for(X[te]=null,te=0;te<K.length;te++){var Z=K[te];Z!==null&&(Z.lane&=-536870913)}l&=~ie}c!==0&&Ub(r,c,0),p!==0&&v===0&&r.tag!==0&&(r.suspendedLanes|=p&~(S&~i))}function Ub(r,i,l){r.pendingLanes|=i,r.suspendedLanes&=~i;var c=31-tn(i);r.entangledLanes|=i,r.entanglements[c]=r.entanglements[c]|1073741824|l&261930}function kb(r,i){var l=r.entangledLanes|=i;for(r=r.entanglements;l;){var c=31-tn(l),v=1<<c;v&i|r[c]&i&&(r[c]|=i),l&=~v}}function Hb(r,i){var l=i&-i;return l=(l&42)!==0?1:Xf(l),(l&(r.suspendedLanes|i))!==0?0:l}function Xf(r){switch(r){case 2:r=1;break;case 8:r=4;break;case 32:r=16;break;case 256:case 512:case 1024:case 2048:case 4096:case 8192:case 16384:case 32768:case 65536:case 131072:case 262144:case 524288:case 1048576:case 2097152:case 4194304:case 8388608:case 16777216:case 33554432:r=128;break;case 268435456:r=134217728;break;default:r=0}return r}function Kf(r){return r&=-r,2<r?8<r?(r&134217727)!==0?32:268435456:8:2}function Ib(){var r=I.p;return r!==0?r:(r=window.event,r===void 0?32:j_(r.type))}function Gb(r,i){var l=I.p;try{return I.p=r,i()}finally{I.p=l}}var Pr=Math.random().toString(36).slice(2),Mt="__reactFiber$"+Pr,Yt="__reactProps$"+Pr,ti="__reactContainer$"+Pr,Vf="__reactEvents$"+Pr,IN="__reactListeners$"+Pr,GN="__reactHandles$"+Pr,Yb="__reactResources$"+Pr,Du="__reactMarker$"+Pr;function Zf(r){delete r[Mt],delete r[Yt],delete r[Vf],delete r[IN],delete r[GN]}function ni(r){var i=r[Mt];if(i)return i;for(var l=r.parentNode;l;){if(i=l[ti]||l[Mt]){if(l=i.alternate,i.child!==null||l!==null&&l.child!==null)for(r=d_(r);r!==null;){if(l=r[Mt])return l;r=d_(r)}return i}r=l,l=r.parentNode}return null}function ri(r){if(r=r[Mt]||r[ti]){var i=r.tag;if(i===5||i===6||i===13||i===31||i===26||i===27||i===3)return r}return null}function Ru(r){var i=r.tag;if(i===5||i===26||i===27||i===6)return r.stateNode;throw Error(a(33))}function ai(r){var i=r[Yb];return i||(i=r[Yb]={hoistableStyles:new Map,hoistableScripts:new Map}),i}function At(r){r[Du]=!0}var Xb=new Set,Kb={};function ya(r,i){ii(r,i),ii(r+"Capture",i)}function ii(r,i){for(Kb[r]=i,r=0;r<i.length;r++)Xb.add(i[r])}var YN=RegExp("^[:A-Z_a-z\\u00C0-\\u00D6\\u00D8-\\u00F6\\u00F8-\\u02FF\\u0370-\\u037D\\u037F-\\u1FFF\\u200C-\\u200D\\u2070-\\u218F\\u2C00-\\u2FEF\\u3001-\\uD7FF\\uF900-\\uFDCF\\uFDF0-\\uFFFD][:A-Z_a-z\\u00C0-\\u00D6\\u00D8-\\u00F6\\u00F8-\\u02FF\\u0370-\\u037D\\u037F-\\u1FFF\\u200C-\\u200D\\u2070-\\u218F\\u2C00-\\u2FEF\\u3001-\\uD7FF\\uF900-\\uFDCF\\uFDF0-\\uFFFD\\-.0-9\\u00B7\\u0300-\\u036F\\u203F-\\u2040]*$"),Vb={},Zb={};function XN(r){return kn.call(Zb,r)?!0:kn.call(Vb,r)?!1:YN.test(r)?Zb[r]=!0:(Vb[r]=!0,!1)}function $o(r,i,l){if(XN(i))if(l===null)r.removeAttribute(i);else{switch(typeof l){case"undefined":case"function":case"symbol":r.removeAttribute(i);return;case"boolean":var c=i.toLowerCase().slice(0,5);if(c!=="data-"&&c!=="aria-"){r.removeAttribute(i);return}}r.setAttribute(i,""+l)}}function Bo(r,i,l){if(l===null)r.removeAttribute(i);else{switch(typeof l){case"undefined":case"function":case"symbol":case"boolean":r.removeAttribute(i);return}r.setAttribute(i,""+l)}}function tr(r,i,l,c){if(c===null)r.removeAttribute(l);else{switch(typeof c){case"undefined":case"function":case"symbol":case"boolean":r.removeAttribute(l);return}r.setAttributeNS(i,l,""+c)}}function hn(r){switch(typeof r){case"bigint":case"boolean":case"number":case"string":case"undefined":return r;case"object":return r;default:return""}}function Fb(r){var i=r.type;return(r=r.nodeName)&&r.toLowerCase()==="input"&&(i==="checkbox"||i==="radio")}function KN(r,i,l){var c=Object.getOwnPropertyDescriptor(r.constructor.prototype,i);if(!r.hasOwnProperty(i)&&typeof c<"u"&&typeof c.get=="function"&&typeof c.set=="function"){var v=c.get,p=c.set;return Object.defineProperty(r,i,{configurable:!0,get:function(){return v.call(this)},set:function(S){l=""+S,p.call(this,S)}}),Object.defineProperty(r,i,{enumerable:c.enumerable}),{getValue:function(){return l},setValue:function(S){l=""+S},stopTracking:function(){r._valueTracker=null,delete r[i]}}}}function Ff(r){if(!r._valueTracker){var i=Fb(r)?"checked":"value";r._valueTracker=KN(r,i,""+r[i])}}function Qb(r){if(!r)return!1;var i=r._valueTracker;if(!i)return!0;var l=i.getValue(),c="";return r&&(c=Fb(r)?r.checked?"true":"false":r.value),r=c,r!==l?(i.setValue(r),!0):!1}function Lo(r){if(r=r||(typeof document<"u"?document:void 0),typeof r>"u")return null;try{return r.activeElement||r.body}catch{return r.body}}var VN=/[\n"\\]/g;function vn(r){return r.replace(VN,function(i){return"\\"+i.charCodeAt(0).toString(16)+" "})}function Qf(r,i,l,c,v,p,S,C){r.name="",S!=null&&typeof S!="function"&&typeof S!="symbol"&&typeof S!="boolean"?r.type=S:r.removeAttribute("type"),i!=null?S==="number"?(i===0&&r.value===""||r.value!=i)&&(r.value=""+hn(i)):r.value!==""+hn(i)&&(r.value=""+hn(i)):S!=="submit"&&S!=="reset"||r.removeAttribute("value"),i!=null?Wf(r,S,hn(i)):l!=null?Wf(r,S,hn(l)):c!=null&&r.removeAttribute("value"),v==null&&p!=null&&(r.defaultChecked=!!p),v!=null&&(r.checked=v&&typeof v!="function"&&typeof v!="symbol"),C!=null&&typeof C!="function"&&typeof C!="symbol"&&typeof C!="boolean"?r.name=""+hn(C):r.removeAttribute("name")}function Wb(r,i,l,c,v,p,S,C){if(p!=null&&typeof p!="function"&&typeof p!="symbol"&&typeof p!="boolean"&&(r.type=p),i!=null||l!=null){if(!(p!=="submit"&&p!=="reset"||i!=null)){Ff(r);return}l=l!=null?""+hn(l):"",i=i!=null?""+hn(i):l,C||i===r.value||(r.value=i),r.defaultValue=i}c=c??v,c=typeof c!="function"&&typeof c!="symbol"&&!!c,r.checked=C?r.checked:!!c,r.defaultChecked=!!c,S!=null&&typeof S!="function"&&typeof S!="symbol"&&typeof S!="boolean"&&(r.name=S),Ff(r)}function Wf(r,i,l){i==="number"&&Lo(r.ownerDocument)===r||r.defaultValue===""+l||(r.defaultValue=""+l)}function ui(r,i,l,c){if(r=r.options,i){i={};for(var v=0;v<l.length;v++)i["$"+l[v]]=!0;for(l=0;l<r.length;l++)v=i.hasOwnProperty("$"+r[l].value),r[l].selected!==v&&(r[l].selected=v),v&&c&&(r[l].defaultSelected=!0)}else{for(l=""+hn(l),i=null,v=0;v<r.length;v++){if(r[v].value===l){r[v].selected=!0,c&&(r[v].defaultSelected=!0);return}i!==null||r[v].disabled||(i=r[v])}i!==null&&(i.selected=!0)}}function Jb(r,i,l){if(i!=null&&(i=""+hn(i),i!==r.value&&(r.value=i),l==null)){r.defaultValue!==i&&(r.defaultValue=i);return}r.defaultValue=l!=null?""+hn(l):""}function e1(r,i,l,c){if(i==null){if(c!=null){if(l!=null)throw Error(a(92));if(F(c)){if(1<c.length)throw Error(a(93));c=c[0]}l=c}l==null&&(l=""),i=l}l=hn(i),r.defaultValue=l,c=r.textContent,c===l&&c!==""&&c!==null&&(r.value=c),Ff(r)}function li(r,i){if(i){var l=r.firstChild;if(l&&l===r.lastChild&&l.nodeType===3){l.nodeValue=i;return}}r.textContent=i}var ZN=new Set("animationIterationCount aspectRatio borderImageOutset borderImageSlice borderImageWidth boxFlex boxFlexGroup boxOrdinalGroup columnCount columns flex flexGrow flexPositive flexShrink flexNegative flexOrder gridArea gridRow gridRowEnd gridRowSpan gridRowStart gridColumn gridColumnEnd gridColumnSpan gridColumnStart fontWeight lineClamp lineHeight opacity order orphans scale tabSize widows zIndex zoom fillOpacity floodOpacity stopOpacity strokeDasharray strokeDashoffset strokeMiterlimit strokeOpacity strokeWidth MozAnimationIterationCount MozBoxFlex MozBoxFlexGroup MozLineClamp msAnimationIterationCount msFlex msZoom msFlexGrow msFlexNegative msFlexOrder msFlexPositive msFlexShrink msGridColumn msGridColumnSpan msGridRow msGridRowSpan WebkitAnimationIterationCount WebkitBoxFlex WebKitBoxFlexGroup WebkitBoxOrdinalGroup WebkitColumnCount WebkitColumns WebkitFlex WebkitFlexGrow WebkitFlexPositive WebkitFlexShrink WebkitLineClamp".split(" "));function t1(r,i,l){var c=i.indexOf("--")===0;l==null||typeof l=="boolean"||l===""?c?r.setProperty(i,""):i==="float"?r.cssFloat="":r[i]="":c?r.setProperty(i,l):typeof l!="number"||l===0||ZN.has(i)?i==="float"?r.cssFloat=l:r[i]=(""+l).trim():r[i]=l+"px"}function n1(r,i,l){if(i!=null&&typeof i!="object")throw Error(a(62));if(r=r.style,l!=null){for(var c in l)!l.hasOwnProperty(c)||i!=null&&i.hasOwnProperty(c)||(c.indexOf("--")===0?r.setProperty(c,""):c==="float"?r.cssFloat="":r[c]="");for(var v in i)c=i[v],i.hasOwnProperty(v)&&l[v]!==c&&t1(r,v,c)}else for(var p in i)i.hasOwnProperty(p)&&t1(r,p,i[p])}function Jf(r){if(r.indexOf("-")===-1)return!1;switch(r){case"annotation-xml":case"color-profile":case"font-face":case"font-face-src":case"font-face-uri":case"font-face-format":case"font-face-name":case"missing-glyph":return!1;default:return!0}}var FN=new Map([["acceptCharset","accept-charset"],["htmlFor","for"],["httpEquiv","http-equiv"],["crossOrigin","crossorigin"],["accentHeight","accent-height"],["alignmentBaseline","alignment-baseline"],["arabicForm","arabic-form"],["baselineShift","baseline-shift"],["capHeight","cap-height"],["clipPath","clip-path"],["clipRule","clip-rule"],["colorInterpolation","color-interpolation"],["colorInterpolationFilters","color-interpolation-filters"],["colorProfile","color-profile"],["colorRendering","color-rendering"],["dominantBaseline","dominant-baseline"],["enableBackground","enable-background"],["fillOpacity","fill-opacity"],["fillRule","fill-rule"],["floodColor","flood-color"],["floodOpacity","flood-opacity"],["fontFamily","font-family"],["fontSize","font-size"],["fontSizeAdjust","font-size-adjust"],["fontStretch","font-stretch"],["fontStyle","font-style"],["fontVariant","font-variant"],["fontWeight","font-weight"],["glyphName","glyph-name"],["glyphOrientationHorizontal","glyph-orientation-horizontal"],["glyphOrientationVertical","glyph-orientation-vertical"],["horizAdvX","horiz-adv-x"],["horizOriginX","horiz-origin-x"],["imageRendering","image-rendering"],["letterSpacing","letter-spacing"],["lightingColor","lighting-color"],["markerEnd","marker-end"],["markerMid","marker-mid"],["markerStart","marker-start"],["overlinePosition","overline-position"],["overlineThickness","overline-thickness"],["paintOrder","paint-order"],["panose-1","panose-1"],["pointerEvents","pointer-events"],["renderingIntent","rendering-intent"],["shapeRendering","shape-rendering"],["stopColor","stop-color"],["stopOpacity","stop-opacity"],["strikethroughPosition","strikethrough-position"],["strikethroughThickness","strikethrough-thickness"],["strokeDasharray","stroke-dasharray"],["strokeDashoffset","stroke-dashoffset"],["strokeLinecap","stroke-linecap"],["strokeLinejoin","stroke-linejoin"],["strokeMiterlimit","stroke-miterlimit"],["strokeOpacity","stroke-opacity"],["strokeWidth","stroke-width"],["textAnchor","text-anchor"],["textDecoration","text-decoration"],["textRendering","text-rendering"],["transformOrigin","transform-origin"],["underlinePosition","underline-position"],["underlineThickness","underline-thickness"],["unicodeBidi","unicode-bidi"],["unicodeRange","unicode-range"],["unitsPerEm","units-per-em"],["vAlphabetic","v-alphabetic"],["vHanging","v-hanging"],["vIdeographic","v-ideographic"],["vMathematical","v-mathematical"],["vectorEffect","vector-effect"],["vertAdvY","vert-adv-y"],["vertOriginX","vert-origin-x"],["vertOriginY","vert-origin-y"],["wordSpacing","word-spacing"],["writingMode","writing-mode"],["xmlnsXlink","xmlns:xlink"],["xHeight","x-height"]]),QN=/^[\u0000-\u001F ]*j[\r\n\t]*a[\r\n\t]*v[\r\n\t]*a[\r\n\t]*s[\r\n\t]*c[\r\n\t]*r[\r\n\t]*i[\r\n\t]*p[\r\n\t]*t[\r\n\t]*:/i;function Uo(r){return QN.test(""+r)?"javascript:throw new Error('React has blocked a javascript: URL as a security precaution.')":r}function nr(){}var ed=null;function td(r){return r=r.target||r.srcElement||window,r.correspondingUseElement&&(r=r.correspondingUseElement),r.nodeType===3?r.parentNode:r}var oi=null,ci=null;function r1(r){var i=ri(r);if(i&&(r=i.stateNode)){var l=r[Yt]||null;e:switch(r=i.stateNode,i.type){case"input":if(Qf(r,l.value,l.defaultValue,l.defaultValue,l.checked,l.defaultChecked,l.type,l.name),i=l.name,l.type==="radio"&&i!=null){for(l=r;l.parentNode;)l=l.parentNode;for(l=l.querySelectorAll('input[name="'+vn(""+i)+'"][type="radio"]'),i=0;i<l.length;i++){var c=l[i];if(c!==r&&c.form===r.form){var v=c[Yt]||null;if(!v)throw Error(a(90));Qf(c,v.value,v.defaultValue,v.defaultValue,v.checked,v.defaultChecked,v.type,v.name)}}for(i=0;i<l.length;i++)c=l[i],c.form===r.form&&Qb(c)}break e;case"textarea":Jb(r,l.value,l.defaultValue);break e;case"select":i=l.value,i!=null&&ui(r,!!l.multiple,i,!1)}}}var nd=!1;function a1(r,i,l){if(nd)return r(i,l);nd=!0;try{var c=r(i);return c}finally{if(nd=!1,(oi!==null||ci!==null)&&(Ec(),oi&&(i=oi,r=ci,ci=oi=null,r1(i),r)))for(i=0;i<r.length;i++)r1(r[i])}}function Pu(r,i){var l=r.stateNode;if(l===null)return null;var c=l[Yt]||null;if(c===null)return null;l=c[i];e:switch(i){case"onClick":case"onClickCapture":case"onDoubleClick":case"onDoubleClickCapture":case"onMouseDown":case"onMouseDownCapture":case"onMouseMove":case"onMouseMoveCapture":case"onMouseUp":case"onMouseUpCapture":case"onMouseEnter":(c=!c.disabled)||(r=r.type,c=!(r==="button"||r==="input"||r==="select"||r==="textarea")),r=!c;break e;default:r=!1}if(r)return null;if(l&&typeof l!="function")throw Error(a(231,i,typeof l));return l}var rr=!(typeof window>"u"||typeof window.document>"u"||typeof window.document.createElement>"u"),rd=!1;if(rr)try{var qu={};Object.defineProperty(qu,"passive",{get:function(){rd=!0}}),window.addEventListener("test",qu,qu),window.removeEventListener("test",qu,qu)}catch{rd=!1}var qr=null,ad=null,ko=null;function i1(){if(ko)return ko;var r,i=ad,l=i.length,c,v="value"in qr?qr.value:qr.textContent,p=v.length;for(r=0;r<l&&i[r]===v[r];r++);var S=l-r;for(c=1;c<=S&&i[l-c]===v[p-c];c++);return ko=v.slice(r,1<c?1-c:void 0)}function Ho(r){var i=r.keyCode;return"charCode"in r?(r=r.charCode,r===0&&i===13&&(r=13)):r=i,r===10&&(r=13),32<=r||r===13?r:0}function Io(){return!0}function u1(){return!1}function Xt(r){function i(l,c,v,p,S){this._reactName=l,this._targetInst=v,this.type=c,this.nativeEvent=p,this.target=S,this.currentTarget=null;for(var C in r)r.hasOwnProperty(C)&&(l=r[C],this[C]=l?l(p):p[C]);return this.isDefaultPrevented=(p.defaultPrevented!=null?p.defaultPrevented:p.returnValue===!1)?Io:u1,this.isPropagationStopped=u1,this}return y(i.prototype,{preventDefault:function(){this.defaultPrevented=!0;var l=this.nativeEvent;l&&(l.preventDefault?l.preventDefault():typeof l.returnValue!="unknown"&&(l.returnValue=!1),this.isDefaultPrevented=Io)},stopPropagation:function(){var l=this.nativeEvent;l&&(l.stopPropagation?l.stopPropagation():typeof l.cancelBubble!="unknown"&&(l.cancelBubble=!0),this.isPropagationStopped=Io)},persist:function(){},isPersistent:Io}),i}var ma={eventPhase:0,bubbles:0,cancelable:0,timeStamp:function(r){return r.timeStamp||Date.now()},defaultPrevented:0,isTrusted:0},Go=Xt(ma),zu=y({},ma,{view:0,detail:0}),WN=Xt(zu),id,ud,$u,Yo=y({},zu,{screenX:0,screenY:0,clientX:0,clientY:0,pageX:0,pageY:0,ctrlKey:0,shiftKey:0,altKey:0,metaKey:0,getModifierState:od,button:0,buttons:0,relatedTarget:function(r){return r.relatedTarget===void 0?r.fromElement===r.srcElement?r.toElement:r.fromElement:r.relatedTarget},movementX:function(r){return"movementX"in r?r.movementX:(r!==$u&&($u&&r.type==="mousemove"?(id=r.screenX-$u.screenX,ud=r.screenY-$u.screenY):ud=id=0,$u=r),id)},movementY:function(r){return"movementY"in r?r.movementY:ud}}),l1=Xt(Yo),JN=y({},Yo,{dataTransfer:0}),eD=Xt(JN),tD=y({},zu,{relatedTarget:0}),ld=Xt(tD),nD=y({},ma,{animationName:0,elapsedTime:0,pseudoElement:0}),rD=Xt(nD),aD=y({},ma,{clipboardData:function(r){return"clipboardData"in r?r.clipboardData:window.clipboardData}}),iD=Xt(aD),uD=y({},ma,{data:0}),o1=Xt(uD),lD={Esc:"Escape",Spacebar:" ",Left:"ArrowLeft",Up:"ArrowUp",Right:"ArrowRight",Down:"ArrowDown",Del:"Delete",Win:"OS",Menu:"ContextMenu",Apps:"ContextMenu",Scroll:"ScrollLock",MozPrintableKey:"Unidentified"},oD={8:"Backspace",9:"Tab",12:"Clear",13:"Enter",16:"Shift",17:"Control",18:"Alt",19:"Pause",20:"CapsLock",27:"Escape",32:" ",33:"PageUp",34:"PageDown",35:"End",36:"Home",37:"ArrowLeft",38:"ArrowUp",39:"ArrowRight",40:"ArrowDown",45:"Insert",46:"Delete",112:"F1",113:"F2",114:"F3",115:"F4",116:"F5",117:"F6",118:"F7",119:"F8",120:"F9",121:"F10",122:"F11",123:"F12",144:"NumLock",145:"ScrollLock",224:"Meta"},cD={Alt:"altKey",Control:"ctrlKey",Meta:"metaKey",Shift:"shiftKey"};function sD(r){var i=this.nativeEvent;return i.getModifierState?i.getModifierState(r):(r=cD[r])?!!i[r]:!1}function od(){return sD}var fD=y({},zu,{key:function(r){if(r.key){var i=lD[r.key]||r.key;if(i!=="Unidentified")return i}return r.type==="keypress"?(r=Ho(r),r===13?"Enter":String.fromCharCode(r)):r.type==="keydown"||r.type==="keyup"?oD[r.keyCode]||"Unidentified":""},code:0,location:0,ctrlKey:0,shiftKey:0,altKey:0,metaKey:0,repeat:0,locale:0,getModifierState:od,charCode:function(r){return r.type==="keypress"?Ho(r):0},keyCode:function(r){return r.type==="keydown"||r.type==="keyup"?r.keyCode:0},which:function(r){return r.type==="keypress"?Ho(r):r.type==="keydown"||r.type==="keyup"?r.keyCode:0}}),dD=Xt(fD),hD=y({},Yo,{pointerId:0,width:0,height:0,pressure:0,tangentialPressure:0,tiltX:0,tiltY:0,twist:0,pointerType:0,isPrimary:0}),c1=Xt(hD),vD=y({},zu,{touches:0,targetTouches:0,changedTouches:0,altKey:0,metaKey:0,ctrlKey:0,shiftKey:0,getModifierState:od}),pD=Xt(vD),yD=y({},ma,{propertyName:0,elapsedTime:0,pseudoElement:0}),mD=Xt(yD),gD=y({},Yo,{deltaX:function(r){return"deltaX"in r?r.deltaX:"wheelDeltaX"in r?-r.wheelDeltaX:0},deltaY:function(r){return"deltaY"in r?r.deltaY:"wheelDeltaY"in r?-r.wheelDeltaY:"wheelDelta"in r?-r.wheelDelta:0},deltaZ:0,deltaMode:0}),bD=Xt(gD),xD=y({},ma,{newState:0,oldState:0}),SD=Xt(xD),_D=[9,13,27,32],cd=rr&&"CompositionEvent"in window,Bu=null;rr&&"documentMode"in document&&(Bu=document.documentMode);var OD=rr&&"TextEvent"in window&&!Bu,s1=rr&&(!cd||Bu&&8<Bu&&11>=Bu),f1=" ",d1=!1;function h1(r,i){switch(r){case"keyup":return _D.indexOf(i.keyCode)!==-1;case"keydown":return i.keyCode!==229;case"keypress":case"mousedown":case"focusout":return!0;default:return!1}}function v1(r){return r=r.detail,typeof r=="object"&&"data"in r?r.data:null}var si=!1;function AD(r,i){switch(r){case"compositionend":return v1(i);case"keypress":return i.which!==32?null:(d1=!0,f1);case"textInput":return r=i.data,r===f1&&d1?null:r;default:return null}}function wD(r,i){if(si)return r==="compositionend"||!cd&&h1(r,i)?(r=i1(),ko=ad=qr=null,si=!1,r):null;switch(r){case"paste":return null;case"keypress":if(!(i.ctrlKey||i.altKey||i.metaKey)||i.ctrlKey&&i.altKey){if(i.char&&1<i.char.length)return i.char;if(i.which)return String.fromCharCode(i.which)}return null;case"compositionend":return s1&&i.locale!=="ko"?null:i.data;default:return null}}var TD={color:!0,date:!0,datetime:!0,"datetime-local":!0,email:!0,month:!0,number:!0,password:!0,range:!0,search:!0,tel:!0,text:!0,time:!0,url:!0,week:!0};function p1(r){var i=r&&r.nodeName&&r.nodeName.toLowerCase();return i==="input"?!!TD[r.type]:i==="textarea"}function y1(r,i,l,c){oi?ci?ci.push(c):ci=[c]:oi=c,i=Pc(i,"onChange"),0<i.length&&(l=new Go("onChange","change",null,l,c),r.push({event:l,listeners:i}))}var Lu=null,Uu=null;function ED(r){WS(r,0)}function Xo(r){var i=Ru(r);if(Qb(i))return r}function m1(r,i){if(r==="change")return i}var g1=!1;if(rr){var sd;if(rr){var fd="oninput"in document;if(!fd){var b1=document.createElement("div");b1.setAttribute("oninput","return;"),fd=typeof b1.oninput=="function"}sd=fd}else sd=!1;g1=sd&&(!document.documentMode||9<document.documentMode)}function x1(){Lu&&(Lu.detachEvent("onpropertychange",S1),Uu=Lu=null)}function S1(r){if(r.propertyName==="value"&&Xo(Uu)){var i=[];y1(i,Uu,r,td(r)),a1(ED,i)}}function jD(r,i,l){r==="focusin"?(x1(),Lu=i,Uu=l,Lu.attachEvent("onpropertychange",S1)):r==="focusout"&&x1()}function MD(r){if(r==="selectionchange"||r==="keyup"||r==="keydown")return Xo(Uu)}function CD(r,i){if(r==="click")return Xo(i)}function ND(r,i){if(r==="input"||r==="change")return Xo(i)}function DD(r,i){return r===i&&(r!==0||1/r===1/i)||r!==r&&i!==i}var nn=typeof Object.is=="function"?Object.is:DD;function ku(r,i){if(nn(r,i))return!0;if(typeof r!="object"||r===null||typeof i!="object"||i===null)return!1;var l=Object.keys(r),c=Object.keys(i);if(l.length!==c.length)return!1;for(c=0;c<l.length;c++){var v=l[c];if(!kn.call(i,v)||!nn(r[v],i[v]))return!1}return!0}function _1(r){for(;r&&r.firstChild;)r=r.firstChild;return r}function O1(r,i){var l=_1(r);r=0;for(var c;l;){if(l.nodeType===3){if(c=r+l.textContent.length,r<=i&&c>=i)return{node:l,offset:i-r};r=c}e:{for(;l;){if(l.nextSibling){l=l.nextSibling;break e}l=l.parentNode}l=void 0}l=_1(l)}}function A1(r,i){return r&&i?r===i?!0:r&&r.nodeType===3?!1:i&&i.nodeType===3?A1(r,i.parentNode):"contains"in r?r.contains(i):r.compareDocumentPosition?!!(r.compareDocumentPosition(i)&16):!1:!1}function w1(r){r=r!=null&&r.ownerDocument!=null&&r.ownerDocument.defaultView!=null?r.ownerDocument.defaultView:window;for(var i=Lo(r.document);i instanceof r.HTMLIFrameElement;){try{var l=typeof i.contentWindow.location.href=="string"}catch{l=!1}if(l)r=i.contentWindow;else break;i=Lo(r.document)}return i}function dd(r){var i=r&&r.nodeName&&r.nodeName.toLowerCase();return i&&(i==="input"&&(r.type==="text"||r.type==="search"||r.type==="tel"||r.type==="url"||r.type==="password")||i==="textarea"||r.contentEditable==="true")}var RD=rr&&"documentMode"in document&&11>=document.documentMode,fi=null,hd=null,Hu=null,vd=!1;function T1(r,i,l){var c=l.window===l?l.document:l.nodeType===9?l:l.ownerDocument;vd||fi==null||fi!==Lo(c)||(c=fi,"selectionStart"in c&&dd(c)?c={start:c.selectionStart,end:c.selectionEnd}:(c=(c.ownerDocument&&c.ownerDocument.defaultView||window).getSelection(),c={anchorNode:c.anchorNode,anchorOffset:c.anchorOffset,focusNode:c.focusNode,focusOffset:c.focusOffset}),Hu&&ku(Hu,c)||(Hu=c,c=Pc(hd,"onSelect"),0<c.length&&(i=new Go("onSelect","select",null,i,l),r.push({event:i,listeners:c}),i.target=fi)))}function ga(r,i){var l={};return l[r.toLowerCase()]=i.toLowerCase(),l["Webkit"+r]="webkit"+i,l["Moz"+r]="moz"+i,l}var di={animationend:ga("Animation","AnimationEnd"),animationiteration:ga("Animation","AnimationIteration"),animationstart:ga("Animation","AnimationStart"),transitionrun:ga("Transition","TransitionRun"),transitionstart:ga("Transition","TransitionStart"),transitioncancel:ga("Transition","TransitionCancel"),transitionend:ga("Transition","TransitionEnd")},pd={},E1={};rr&&(E1=document.createElement("div").style,"AnimationEvent"in window||(delete di.animationend.animation,delete di.animationiteration.animation,delete di.animationstart.animation),"TransitionEvent"in window||delete di.transitionend.transition);function ba(r){if(pd[r])return pd[r];if(!di[r])return r;var i=di[r],l;for(l in i)if(i.hasOwnProperty(l)&&l in E1)return pd[r]=i[l];return r}var j1=ba("animationend"),M1=ba("animationiteration"),C1=ba("animationstart"),PD=ba("transitionrun"),qD=ba("transitionstart"),zD=ba("transitioncancel"),N1=ba("transitionend"),D1=new Map,yd="abort auxClick beforeToggle cancel canPlay canPlayThrough click close contextMenu copy cut drag dragEnd dragEnter dragExit dragLeave dragOver dragStart drop durationChange emptied encrypted ended error gotPointerCapture input invalid keyDown keyPress keyUp load loadedData loadedMetadata loadStart lostPointerCapture mouseDown mouseMove mouseOut mouseOver mouseUp paste pause play playing pointerCancel pointerDown pointerMove pointerOut pointerOver pointerUp progress rateChange reset resize seeked seeking stalled submit suspend timeUpdate touchCancel touchEnd touchStart volumeChange scroll toggle touchMove waiting wheel".split(" ");yd.push("scrollEnd");function Dn(r,i){D1.set(r,i),ya(i,[r])}var Ko=typeof reportError=="function"?reportError:function(r){if(typeof window=="object"&&typeof window.ErrorEvent=="function"){var i=new window.ErrorEvent("error",{bubbles:!0,cancelable:!0,message:typeof r=="object"&&r!==null&&typeof r.message=="string"?String(r.message):String(r),error:r});if(!window.dispatchEvent(i))return}else if(typeof process=="object"&&typeof process.emit=="function"){process.emit("uncaughtException",r);return}console.error(r)},pn=[],hi=0,md=0;function Vo(){for(var r=hi,i=md=hi=0;i<r;){var l=pn[i];pn[i++]=null;var c=pn[i];pn[i++]=null;var v=pn[i];pn[i++]=null;var p=pn[i];if(pn[i++]=null,c!==null&&v!==null){var S=c.pending;S===null?v.next=v:(v.next=S.next,S.next=v),c.pending=v}p!==0&&R1(l,v,p)}}function Zo(r,i,l,c){pn[hi++]=r,pn[hi++]=i,pn[hi++]=l,pn[hi++]=c,md|=c,r.lanes|=c,r=r.alternate,r!==null&&(r.lanes|=c)}function gd(r,i,l,c){return Zo(r,i,l,c),Fo(r)}function xa(r,i){return Zo(r,null,null,i),Fo(r)}function R1(r,i,l){r.lanes|=l;var c=r.alternate;c!==null&&(c.lanes|=l);for(var v=!1,p=r.return;p!==null;)p.childLanes|=l,c=p.alternate,c!==null&&(c.childLanes|=l),p.tag===22&&(r=p.stateNode,r===null||r._visibility&1||(v=!0)),r=p,p=p.return;return r.tag===3?(p=r.stateNode,v&&i!==null&&(v=31-tn(l),r=p.hiddenUpdates,c=r[v],c===null?r[v]=[i]:c.push(i),i.lane=l|536870912),p):null}function Fo(r){if(50<sl)throw sl=0,Eh=null,Error(a(185));for(var i=r.return;i!==null;)r=i,i=r.return;return r.tag===3?r.stateNode:null}var vi={};function $D(r,i,l,c){this.tag=r,this.key=l,this.sibling=this.child=this.return=this.stateNode=this.type=this.elementType=null,this.index=0,this.refCleanup=this.ref=null,this.pendingProps=i,this.dependencies=this.memoizedState=this.updateQueue=this.memoizedProps=null,this.mode=c,this.subtreeFlags=this.flags=0,this.deletions=null,this.childLanes=this.lanes=0,this.alternate=null}function rn(r,i,l,c){return new $D(r,i,l,c)}function bd(r){return r=r.prototype,!(!r||!r.isReactComponent)}function ar(r,i){var l=r.alternate;return l===null?(l=rn(r.tag,i,r.key,r.mode),l.elementType=r.elementType,l.type=r.type,l.stateNode=r.stateNode,l.alternate=r,r.alternate=l):(l.pendingProps=i,l.type=r.type,l.flags=0,l.subtreeFlags=0,l.deletions=null),l.flags=r.flags&65011712,l.childLanes=r.childLanes,l.lanes=r.lanes,l.child=r.child,l.memoizedProps=r.memoizedProps,l.memoizedState=r.memoizedState,l.updateQueue=r.updateQueue,i=r.dependencies,l.dependencies=i===null?null:{lanes:i.lanes,firstContext:i.firstContext},l.sibling=r.sibling,l.index=r.index,l.ref=r.ref,l.refCleanup=r.refCleanup,l}function P1(r,i){r.flags&=65011714;var l=r.alternate;return l===null?(r.childLanes=0,r.lanes=i,r.child=null,r.subtreeFlags=0,r.memoizedProps=null,r.memoizedState=null,r.updateQueue=null,r.dependencies=null,r.stateNode=null):(r.childLanes=l.childLanes,r.lanes=l.lanes,r.child=l.child,r.subtreeFlags=0,r.deletions=null,r.memoizedProps=l.memoizedProps,r.memoizedState=l.memoizedState,r.updateQueue=l.updateQueue,r.type=l.type,i=l.dependencies,r.dependencies=i===null?null:{lanes:i.lanes,firstContext:i.firstContext}),r}function Qo(r,i,l,c,v,p){var S=0;if(c=r,typeof r=="function")bd(r)&&(S=1);else if(typeof r=="string")S=HR(r,l,se.current)?26:r==="html"||r==="head"||r==="body"?27:5;else e:switch(r){case B:return r=rn(31,l,i,v),r.elementType=B,r.lanes=p,r;case _:return Sa(l.children,v,p,i);case x:S=8,v|=24;break;case T:return r=rn(12,l,i,v|2),r.elementType=T,r.lanes=p,r;case A:return r=rn(13,l,i,v),r.elementType=A,r.lanes=p,r;case w:return r=rn(19,l,i,v),r.elementType=w,r.lanes=p,r;default:if(typeof r=="object"&&r!==null)switch(r.$$typeof){case M:S=10;break e;case j:S=9;break e;case N:S=11;break e;case E:S=14;break e;case P:S=16,c=null;break e}S=29,l=Error(a(130,r===null?"null":typeof r,"")),c=null}return i=rn(S,l,i,v),i.elementType=r,i.type=c,i.lanes=p,i}function Sa(r,i,l,c){return r=rn(7,r,c,i),r.lanes=l,r}function xd(r,i,l){return r=rn(6,r,null,i),r.lanes=l,r}function q1(r){var i=rn(18,null,null,0);return i.stateNode=r,i}function Sd(r,i,l){return i=rn(4,r.children!==null?r.children:[],r.key,i),i.lanes=l,i.stateNode={containerInfo:r.containerInfo,pendingChildren:null,implementation:r.implementation},i}var z1=new WeakMap;function yn(r,i){if(typeof r=="object"&&r!==null){var l=z1.get(r);return l!==void 0?l:(i={value:r,source:i,stack:Cn(i)},z1.set(r,i),i)}return{value:r,source:i,stack:Cn(i)}}var pi=[],yi=0,Wo=null,Iu=0,mn=[],gn=0,zr=null,Hn=1,In="";function ir(r,i){pi[yi++]=Iu,pi[yi++]=Wo,Wo=r,Iu=i}function $1(r,i,l){mn[gn++]=Hn,mn[gn++]=In,mn[gn++]=zr,zr=r;var c=Hn;r=In;var v=32-tn(c)-1;c&=~(1<<v),l+=1;var p=32-tn(i)+v;if(30<p){var S=v-v%5;p=(c&(1<<S)-1).toString(32),c>>=S,v-=S,Hn=1<<32-tn(i)+v|l<<v|c,In=p+r}else Hn=1<<p|l<<v|c,In=r}function _d(r){r.return!==null&&(ir(r,1),$1(r,1,0))}function Od(r){for(;r===Wo;)Wo=pi[--yi],pi[yi]=null,Iu=pi[--yi],pi[yi]=null;for(;r===zr;)zr=mn[--gn],mn[gn]=null,In=mn[--gn],mn[gn]=null,Hn=mn[--gn],mn[gn]=null}function B1(r,i){mn[gn++]=Hn,mn[gn++]=In,mn[gn++]=zr,Hn=i.id,In=i.overflow,zr=r}var Ct=null,et=null,De=!1,$r=null,bn=!1,Ad=Error(a(519));function Br(r){var i=Error(a(418,1<arguments.length&&arguments[1]!==void 0&&arguments[1]?"text":"HTML",""));throw Gu(yn(i,r)),Ad}function L1(r){var i=r.stateNode,l=r.type,c=r.memoizedProps;switch(i[Mt]=r,i[Yt]=c,l){case"dialog":Me("cancel",i),Me("close",i);break;case"iframe":case"object":case"embed":Me("load",i);break;case"video":case"audio":for(l=0;l<dl.length;l++)Me(dl[l],i);break;case"source":Me("error",i);break;case"img":case"image":case"link":Me("error",i),Me("load",i);break;case"details":Me("toggle",i);break;case"input":Me("invalid",i),Wb(i,c.value,c.defaultValue,c.checked,c.defaultChecked,c.type,c.name,!0);break;case"select":Me("invalid",i);break;case"textarea":Me("invalid",i),e1(i,c.value,c.defaultValue,c.children)}l=c.children,typeof l!="string"&&typeof l!="number"&&typeof l!="bigint"||i.textContent===""+l||c.suppressHydrationWarning===!0||n_(i.textContent,l)?(c.popover!=null&&(Me("beforetoggle",i),Me("toggle",i)),c.onScroll!=null&&Me("scroll",i),c.onScrollEnd!=null&&Me("scrollend",i),c.onClick!=null&&(i.onclick=nr),i=!0):i=!1,i||Br(r,!0)}function U1(r){for(Ct=r.return;Ct;)switch(Ct.tag){case 5:case 31:case 13:bn=!1;return;case 27:case 3:bn=!0;return;default:Ct=Ct.return}}function mi(r){if(r!==Ct)return!1;if(!De)return U1(r),De=!0,!1;var i=r.tag,l;if((l=i!==3&&i!==27)&&((l=i===5)&&(l=r.type,l=!(l!=="form"&&l!=="button")||Hh(r.type,r.memoizedProps)),l=!l),l&&et&&Br(r),U1(r),i===13){if(r=r.memoizedState,r=r!==null?r.dehydrated:null,!r)throw Error(a(317));et=f_(r)}else if(i===31){if(r=r.memoizedState,r=r!==null?r.dehydrated:null,!r)throw Error(a(317));et=f_(r)}else i===27?(i=et,Wr(r.type)?(r=Kh,Kh=null,et=r):et=i):et=Ct?Sn(r.stateNode.nextSibling):null;return!0}function _a(){et=Ct=null,De=!1}function wd(){var r=$r;return r!==null&&(Ft===null?Ft=r:Ft.push.apply(Ft,r),$r=null),r}function Gu(r){$r===null?$r=[r]:$r.push(r)}var Td=R(null),Oa=null,ur=null;function Lr(r,i,l){re(Td,i._currentValue),i._currentValue=l}function lr(r){r._currentValue=Td.current,U(Td)}function Ed(r,i,l){for(;r!==null;){var c=r.alternate;if((r.childLanes&i)!==i?(r.childLanes|=i,c!==null&&(c.childLanes|=i)):c!==null&&(c.childLanes&i)!==i&&(c.childLanes|=i),r===l)break;r=r.return}}function jd(r,i,l,c){var v=r.child;for(v!==null&&(v.return=r);v!==null;){var p=v.dependencies;if(p!==null){var S=v.child;p=p.firstContext;e:for(;p!==null;){var C=p;p=v;for(var z=0;z<i.length;z++)if(C.context===i[z]){p.lanes|=l,C=p.alternate,C!==null&&(C.lanes|=l),Ed(p.return,l,r),c||(S=null);break e}p=C.next}}else if(v.tag===18){if(S=v.return,S===null)throw Error(a(341));S.lanes|=l,p=S.alternate,p!==null&&(p.lanes|=l),Ed(S,l,r),S=null}else S=v.child;if(S!==null)S.return=v;else for(S=v;S!==null;){if(S===r){S=null;break}if(v=S.sibling,v!==null){v.return=S.return,S=v;break}S=S.return}v=S}}function gi(r,i,l,c){r=null;for(var v=i,p=!1;v!==null;){if(!p){if((v.flags&524288)!==0)p=!0;else if((v.flags&262144)!==0)break}if(v.tag===10){var S=v.alternate;if(S===null)throw Error(a(387));if(S=S.memoizedProps,S!==null){var C=v.type;nn(v.pendingProps.value,S.value)||(r!==null?r.push(C):r=[C])}}else if(v===Te.current){if(S=v.alternate,S===null)throw Error(a(387));S.memoizedState.memoizedState!==v.memoizedState.memoizedState&&(r!==null?r.push(ml):r=[ml])}v=v.return}r!==null&&jd(i,r,l,c),i.flags|=262144}function Jo(r){for(r=r.firstContext;r!==null;){if(!nn(r.context._currentValue,r.memoizedValue))return!0;r=r.next}return!1}function Aa(r){Oa=r,ur=null,r=r.dependencies,r!==null&&(r.firstContext=null)}function Nt(r){return k1(Oa,r)}function ec(r,i){return Oa===null&&Aa(r),k1(r,i)}function k1(r,i){var l=i._currentValue;if(i={context:i,memoizedValue:l,next:null},ur===null){if(r===null)throw Error(a(308));ur=i,r.dependencies={lanes:0,firstContext:i},r.flags|=524288}else ur=ur.next=i;return l}var BD=typeof AbortController<"u"?AbortController:function(){var r=[],i=this.signal={aborted:!1,addEventListener:function(l,c){r.push(c)}};this.abort=function(){i.aborted=!0,r.forEach(function(l){return l()})}},LD=e.unstable_scheduleCallback,UD=e.unstable_NormalPriority,dt={$$typeof:M,Consumer:null,Provider:null,_currentValue:null,_currentValue2:null,_threadCount:0};function Md(){return{controller:new BD,data:new Map,refCount:0}}function Yu(r){r.refCount--,r.refCount===0&&LD(UD,function(){r.controller.abort()})}var Xu=null,Cd=0,bi=0,xi=null;function kD(r,i){if(Xu===null){var l=Xu=[];Cd=0,bi=Rh(),xi={status:"pending",value:void 0,then:function(c){l.push(c)}}}return Cd++,i.then(H1,H1),i}function H1(){if(--Cd===0&&Xu!==null){xi!==null&&(xi.status="fulfilled");var r=Xu;Xu=null,bi=0,xi=null;for(var i=0;i<r.length;i++)(0,r[i])()}}function HD(r,i){var l=[],c={status:"pending",value:null,reason:null,then:function(v){l.push(v)}};return r.then(function(){c.status="fulfilled",c.value=i;for(var v=0;v<l.length;v++)(0,l[v])(i)},function(v){for(c.status="rejected",c.reason=v,v=0;v<l.length;v++)(0,l[v])(void 0)}),c}var I1=q.S;q.S=function(r,i){TS=$t(),typeof i=="object"&&i!==null&&typeof i.then=="function"&&kD(r,i),I1!==null&&I1(r,i)};var wa=R(null);function Nd(){var r=wa.current;return r!==null?r:Ze.pooledCache}function tc(r,i){i===null?re(wa,wa.current):re(wa,i.pool)}function G1(){var r=Nd();return r===null?null:{parent:dt._currentValue,pool:r}}var Si=Error(a(460)),Dd=Error(a(474)),nc=Error(a(542)),rc={then:function(){}};function Y1(r){return r=r.status,r==="fulfilled"||r==="rejected"}function X1(r,i,l){switch(l=r[l],l===void 0?r.push(i):l!==i&&(i.then(nr,nr),i=l),i.status){case"fulfilled":return i.value;case"rejected":throw r=i.reason,V1(r),r;default:if(typeof i.status=="string")i.then(nr,nr);else{if(r=Ze,r!==null&&100<r.shellSuspendCounter)throw Error(a(482));r=i,r.status="pending",r.then(function(c){if(i.status==="pending"){var v=i;v.status="fulfilled",v.value=c}},function(c){if(i.status==="pending"){var v=i;v.status="rejected",v.reason=c}})}switch(i.status){case"fulfilled":return i.value;case"rejected":throw r=i.reason,V1(r),r}throw Ea=i,Si}}function Ta(r){try{var i=r._init;return i(r._payload)}catch(l){throw l!==null&&typeof l=="object"&&typeof l.then=="function"?(Ea=l,Si):l}}var Ea=null;function K1(){if(Ea===null)throw Error(a(459));var r=Ea;return Ea=null,r}function V1(r){if(r===Si||r===nc)throw Error(a(483))}var _i=null,Ku=0;function ac(r){var i=Ku;return Ku+=1,_i===null&&(_i=[]),X1(_i,r,i)}function Vu(r,i){i=i.props.ref,r.ref=i!==void 0?i:null}function ic(r,i){throw i.$$typeof===g?Error(a(525)):(r=Object.prototype.toString.call(i),Error(a(31,r==="[object Object]"?"object with keys {"+Object.keys(i).join(", ")+"}":r)))}function Z1(r){function i(k,$){if(r){var Y=k.deletions;Y===null?(k.deletions=[$],k.flags|=16):Y.push($)}}function l(k,$){if(!r)return null;for(;$!==null;)i(k,$),$=$.sibling;return null}function c(k){for(var $=new Map;k!==null;)k.key!==null?$.set(k.key,k):$.set(k.index,k),k=k.sibling;return $}function v(k,$){return k=ar(k,$),k.index=0,k.sibling=null,k}function p(k,$,Y){return k.index=Y,r?(Y=k.alternate,Y!==null?(Y=Y.index,Y<$?(k.flags|=67108866,$):Y):(k.flags|=67108866,$)):(k.flags|=1048576,$)}function S(k){return r&&k.alternate===null&&(k.flags|=67108866),k}function C(k,$,Y,ae){return $===null||$.tag!==6?($=xd(Y,k.mode,ae),$.return=k,$):($=v($,Y),$.return=k,$)}function z(k,$,Y,ae){var ye=Y.type;return ye===_?te(k,$,Y.props.children,ae,Y.key):$!==null&&($.elementType===ye||typeof ye=="object"&&ye!==null&&ye.$$typeof===P&&Ta(ye)===$.type)?($=v($,Y.props),Vu($,Y),$.return=k,$):($=Qo(Y.type,Y.key,Y.props,null,k.mode,ae),Vu($,Y),$.return=k,$)}function X(k,$,Y,ae){return $===null||$.tag!==4||$.stateNode.containerInfo!==Y.containerInfo||$.stateNode.implementation!==Y.implementation?($=Sd(Y,k.mode,ae),$.return=k,$):($=v($,Y.children||[]),$.return=k,$)}function te(k,$,Y,ae,ye){return $===null||$.tag!==7?($=Sa(Y,k.mode,ae,ye),$.return=k,$):($=v($,Y),$.return=k,$)}function ie(k,$,Y){if(typeof $=="string"&&$!==""||typeof $=="number"||typeof $=="bigint")return $=xd(""+$,k.mode,Y),$.return=k,$;if(typeof $=="object"&&$!==null){switch($.$$typeof){case b:return Y=Qo($.type,$.key,$.props,null,k.mode,Y),Vu(Y,$),Y.return=k,Y;case O:return $=Sd($,k.mode,Y),$.return=k,$;case P:return $=Ta($),ie(k,$,Y)}if(F($)||L($))return $=Sa($,k.mode,Y,null),$.return=k,$;if(typeof $.then=="function")return ie(k,ac($),Y);if($.$$typeof===M)return ie(k,ec(k,$),Y);ic(k,$)}return null}function K(k,$,Y,ae){var ye=$!==null?$.key:null;if(typeof Y=="string"&&Y!==""||typeof Y=="number"||typeof Y=="bigint")return ye!==null?null:C(k,$,""+Y,ae);if(typeof Y=="object"&&Y!==null){switch(Y.$$typeof){case b:return Y.key===ye?z(k,$,Y,ae):null;case O:return Y.key===ye?X(k,$,Y,ae):null;case P:return Y=Ta(Y),K(k,$,Y,ae)}if(F(Y)||L(Y))return ye!==null?null:te(k,$,Y,ae,null);if(typeof Y.then=="function")return K(k,$,ac(Y),ae);if(Y.$$typeof===M)return K(k,$,ec(k,Y),ae);ic(k,Y)}return null}function Z(k,$,Y,ae,ye){if(typeof ae=="string"&&ae!==""||typeof ae=="number"||typeof ae=="bigint")return k=k.get(Y)||null,C($,k,""+ae,ye);if(typeof ae=="object"&&ae!==null){switch(ae.$$typeof){case b:return k=k.get(ae.key===null?Y:ae.key)||null,z($,k,ae,ye);case O:return k=k.get(ae.key===null?Y:ae.key)||null,X($,k,ae,ye);case P:return ae=Ta(ae),Z(k,$,Y,ae,ye)}if(F(ae)||L(ae))return k=k.get(Y)||null,te($,k,ae,ye,null);if(typeof ae.then=="function")return Z(k,$,Y,ac(ae),ye);if(ae.$$typeof===M)return Z(k,$,Y,ec($,ae),ye);ic($,ae)}return null}function he(k,$,Y,ae){for(var ye=null,Re=null,pe=$,we=$=0,Ne=null;pe!==null&&we<Y.length;we++){pe.index>we?(Ne=pe,pe=null):Ne=pe.sibling;var Pe=K(k,pe,Y[we],ae);if(Pe===null){pe===null&&(pe=Ne);break}r&&pe&&Pe.alternate===null&&i(k,pe),$=p(Pe,$,we),Re===null?ye=Pe:Re.sibling=Pe,Re=Pe,pe=Ne}if(we===Y.length)return l(k,pe),De&&ir(k,we),ye;if(pe===null){for(;we<Y.length;we++)pe=ie(k,Y[we],ae),pe!==null&&($=p(pe,$,we),Re===null?ye=pe:Re.sibling=pe,Re=pe);return De&&ir(k,we),ye}for(pe=c(pe);we<Y.length;we++)Ne=Z(pe,k,we,Y[we],ae),Ne!==null&&(r&&Ne.alternate!==null&&pe.delete(Ne.key===null?we:Ne.key),$=p(Ne,$,we),Re===null?ye=Ne:Re.sibling=Ne,Re=Ne);return r&&pe.forEach(function(ra){return i(k,ra)}),De&&ir(k,we),ye}function ge(k,$,Y,ae){if(Y==null)throw Error(a(151));for(var ye=null,Re=null,pe=$,we=$=0,Ne=null,Pe=Y.next();pe!==null&&!Pe.done;we++,Pe=Y.next()){pe.index>we?(Ne=pe,pe=null):Ne=pe.sibling;var ra=K(k,pe,Pe.value,ae);if(ra===null){pe===null&&(pe=Ne);break}r&&pe&&ra.alternate===null&&i(k,pe),$=p(ra,$,we),Re===null?ye=ra:Re.sibling=ra,Re=ra,pe=Ne}if(Pe.done)return l(k,pe),De&&ir(k,we),ye;if(pe===null){for(;!Pe.done;we++,Pe=Y.next())Pe=ie(k,Pe.value,ae),Pe!==null&&($=p(Pe,$,we),Re===null?ye=Pe:Re.sibling=Pe,Re=Pe);return De&&ir(k,we),ye}for(pe=c(pe);!Pe.done;we++,Pe=Y.next())Pe=Z(pe,k,we,Pe.value,ae),Pe!==null&&(r&&Pe.alternate!==null&&pe.delete(Pe.key===null?we:Pe.key),$=p(Pe,$,we),Re===null?ye=Pe:Re.sibling=Pe,Re=Pe);return r&&pe.forEach(function(JR){return i(k,JR)}),De&&ir(k,we),ye}function Ke(k,$,Y,ae){if(typeof Y=="object"&&Y!==null&&Y.type===_&&Y.key===null&&(Y=Y.props.children),typeof Y=="object"&&Y!==null){switch(Y.$$typeof){case b:e:{for(var ye=Y.key;$!==null;){if($.key===ye){if(ye=Y.type,ye===_){if($.tag===7){l(k,$.sibling),ae=v($,Y.props.children),ae.return=k,k=ae;break e}}else if($.elementType===ye||typeof ye=="object"&&ye!==null&&ye.$$typeof===P&&Ta(ye)===$.type){l(k,$.sibling),ae=v($,Y.props),Vu(ae,Y),ae.return=k,k=ae;break e}l(k,$);break}else i(k,$);$=$.sibling}Y.type===_?(ae=Sa(Y.props.children,k.mode,ae,Y.key),ae.return=k,k=ae):(ae=Qo(Y.type,Y.key,Y.props,null,k.mode,ae),Vu(ae,Y),ae.return=k,k=ae)}return S(k);case O:e:{for(ye=Y.key;$!==null;){if($.key===ye)if($.tag===4&&$.stateNode.containerInfo===Y.containerInfo&&$.stateNode.implementation===Y.implementation){l(k,$.sibling),ae=v($,Y.children||[]),ae.return=k,k=ae;break e}else{l(k,$);break}else i(k,$);$=$.sibling}ae=Sd(Y,k.mode,ae),ae.return=k,k=ae}return S(k);case P:return Y=Ta(Y),Ke(k,$,Y,ae)}if(F(Y))return he(k,$,Y,ae);if(L(Y)){if(ye=L(Y),typeof ye!="function")throw Error(a(150));return Y=ye.call(Y),ge(k,$,Y,ae)}if(typeof Y.then=="function")return Ke(k,$,ac(Y),ae);if(Y.$$typeof===M)return Ke(k,$,ec(k,Y),ae);ic(k,Y)}return typeof Y=="string"&&Y!==""||typeof Y=="number"||typeof Y=="bigint"?(Y=""+Y,$!==null&&$.tag===6?(l(k,$.sibling),ae=v($,Y),ae.return=k,k=ae):(l(k,$),ae=xd(Y,k.mode,ae),ae.return=k,k=ae),S(k)):l(k,$)}return function(k,$,Y,ae){try{Ku=0;var ye=Ke(k,$,Y,ae);return _i=null,ye}catch(pe){if(pe===Si||pe===nc)throw pe;var Re=rn(29,pe,null,k.mode);return Re.lanes=ae,Re.return=k,Re}finally{}}}var ja=Z1(!0),F1=Z1(!1),Ur=!1;function Rd(r){r.updateQueue={baseState:r.memoizedState,firstBaseUpdate:null,lastBaseUpdate:null,shared:{pending:null,lanes:0,hiddenCallbacks:null},callbacks:null}}function Pd(r,i){r=r.updateQueue,i.updateQueue===r&&(i.updateQueue={baseState:r.baseState,firstBaseUpdate:r.firstBaseUpdate,lastBaseUpdate:r.lastBaseUpdate,shared:r.shared,callbacks:null})}function kr(r){return{lane:r,tag:0,payload:null,callback:null,next:null}}function Hr(r,i,l){var c=r.updateQueue;if(c===null)return null;if(c=c.shared,(ze&2)!==0){var v=c.pending;return v===null?i.next=i:(i.next=v.next,v.next=i),c.pending=i,i=Fo(r),R1(r,null,l),i}return Zo(r,c,i,l),Fo(r)}function Zu(r,i,l){if(i=i.updateQueue,i!==null&&(i=i.shared,(l&4194048)!==0)){var c=i.lanes;c&=r.pendingLanes,l|=c,i.lanes=l,kb(r,l)}}function qd(r,i){var l=r.updateQueue,c=r.alternate;if(c!==null&&(c=c.updateQueue,l===c)){var v=null,p=null;if(l=l.firstBaseUpdate,l!==null){do{var S={lane:l.lane,tag:l.tag,payload:l.payload,callback:null,next:null};p===null?v=p=S:p=p.next=S,l=l.next}while(l!==null);p===null?v=p=i:p=p.next=i}else v=p=i;l={baseState:c.baseState,firstBaseUpdate:v,lastBaseUpdate:p,shared:c.shared,callbacks:c.callbacks},r.updateQueue=l;return}r=l.lastBaseUpdate,r===null?l.firstBaseUpdate=i:r.next=i,l.lastBaseUpdate=i}var zd=!1;function Fu(){if(zd){var r=xi;if(r!==null)throw r}}function Qu(r,i,l,c){zd=!1;var v=r.updateQueue;Ur=!1;var p=v.firstBaseUpdate,S=v.lastBaseUpdate,C=v.shared.pending;if(C!==null){v.shared.pending=null;var z=C,X=z.next;z.next=null,S===null?p=X:S.next=X,S=z;var te=r.alternate;te!==null&&(te=te.updateQueue,C=te.lastBaseUpdate,C!==S&&(C===null?te.firstBaseUpdate=X:C.next=X,te.lastBaseUpdate=z))}if(p!==null){var ie=v.baseState;S=0,te=X=z=null,C=p;do{var K=C.lane&-536870913,Z=K!==C.lane;if(Z?(Ce&K)===K:(c&K)===K){K!==0&&K===bi&&(zd=!0),te!==null&&(te=te.next={lane:0,tag:C.tag,payload:C.payload,callback:null,next:null});e:{var he=r,ge=C;K=i;var Ke=l;switch(ge.tag){case 1:if(he=ge.payload,typeof he=="function"){ie=he.call(Ke,ie,K);break e}ie=he;break e;case 3:he.flags=he.flags&-65537|128;case 0:if(he=ge.payload,K=typeof he=="function"?he.call(Ke,ie,K):he,K==null)break e;ie=y({},ie,K);break e;case 2:Ur=!0}}K=C.callback,K!==null&&(r.flags|=64,Z&&(r.flags|=8192),Z=v.callbacks,Z===null?v.callbacks=[K]:Z.push(K))}else Z={lane:K,tag:C.tag,payload:C.payload,callback:C.callback,next:null},te===null?(X=te=Z,z=ie):te=te.next=Z,S|=K;if(C=C.next,C===null){if(C=v.shared.pending,C===null)break;Z=C,C=Z.next,Z.next=null,v.lastBaseUpdate=Z,v.shared.pending=null}}while(!0);te===null&&(z=ie),v.baseState=z,v.firstBaseUpdate=X,v.lastBaseUpdate=te,p===null&&(v.shared.lanes=0),Kr|=S,r.lanes=S,r.memoizedState=ie}}function Q1(r,i){if(typeof r!="function")throw Error(a(191,r));r.call(i)}function W1(r,i){var l=r.callbacks;if(l!==null)for(r.callbacks=null,r=0;r<l.length;r++)Q1(l[r],i)}var Oi=R(null),uc=R(0);function J1(r,i){r=yr,re(uc,r),re(Oi,i),yr=r|i.baseLanes}function $d(){re(uc,yr),re(Oi,Oi.current)}function Bd(){yr=uc.current,U(Oi),U(uc)}var an=R(null),xn=null;function Ir(r){var i=r.alternate;re(st,st.current&1),re(an,r),xn===null&&(i===null||Oi.current!==null||i.memoizedState!==null)&&(xn=r)}function Ld(r){re(st,st.current),re(an,r),xn===null&&(xn=r)}function ex(r){r.tag===22?(re(st,st.current),re(an,r),xn===null&&(xn=r)):Gr()}function Gr(){re(st,st.current),re(an,an.current)}function un(r){U(an),xn===r&&(xn=null),U(st)}var st=R(0);function lc(r){for(var i=r;i!==null;){if(i.tag===13){var l=i.memoizedState;if(l!==null&&(l=l.dehydrated,l===null||Yh(l)||Xh(l)))return i}else if(i.tag===19&&(i.memoizedProps.revealOrder==="forwards"||i.memoizedProps.revealOrder==="backwards"||i.memoizedProps.revealOrder==="unstable_legacy-backwards"||i.memoizedProps.revealOrder==="together")){if((i.flags&128)!==0)return i}else if(i.child!==null){i.child.return=i,i=i.child;continue}if(i===r)break;for(;i.sibling===null;){if(i.return===null||i.return===r)return null;i=i.return}i.sibling.return=i.return,i=i.sibling}return null}var or=0,Oe=null,Ye=null,ht=null,oc=!1,Ai=!1,Ma=!1,cc=0,Wu=0,wi=null,ID=0;function lt(){throw Error(a(321))}function Ud(r,i){if(i===null)return!1;for(var l=0;l<i.length&&l<r.length;l++)if(!nn(r[l],i[l]))return!1;return!0}function kd(r,i,l,c,v,p){return or=p,Oe=i,i.memoizedState=null,i.updateQueue=null,i.lanes=0,q.H=r===null||r.memoizedState===null?$x:nh,Ma=!1,p=l(c,v),Ma=!1,Ai&&(p=nx(i,l,c,v)),tx(r),p}function tx(r){q.H=tl;var i=Ye!==null&&Ye.next!==null;if(or=0,ht=Ye=Oe=null,oc=!1,Wu=0,wi=null,i)throw Error(a(300));r===null||vt||(r=r.dependencies,r!==null&&Jo(r)&&(vt=!0))}function nx(r,i,l,c){Oe=r;var v=0;do{if(Ai&&(wi=null),Wu=0,Ai=!1,25<=v)throw Error(a(301));if(v+=1,ht=Ye=null,r.updateQueue!=null){var p=r.updateQueue;p.lastEffect=null,p.events=null,p.stores=null,p.memoCache!=null&&(p.memoCache.index=0)}q.H=Bx,p=i(l,c)}while(Ai);return p}function GD(){var r=q.H,i=r.useState()[0];return i=typeof i.then=="function"?Ju(i):i,r=r.useState()[0],(Ye!==null?Ye.memoizedState:null)!==r&&(Oe.flags|=1024),i}function Hd(){var r=cc!==0;return cc=0,r}function Id(r,i,l){i.updateQueue=r.updateQueue,i.flags&=-2053,r.lanes&=~l}function Gd(r){if(oc){for(r=r.memoizedState;r!==null;){var i=r.queue;i!==null&&(i.pending=null),r=r.next}oc=!1}or=0,ht=Ye=Oe=null,Ai=!1,Wu=cc=0,wi=null}function It(){var r={memoizedState:null,baseState:null,baseQueue:null,queue:null,next:null};return ht===null?Oe.memoizedState=ht=r:ht=ht.next=r,ht}function ft(){if(Ye===null){var r=Oe.alternate;r=r!==null?r.memoizedState:null}else r=Ye.next;var i=ht===null?Oe.memoizedState:ht.next;if(i!==null)ht=i,Ye=r;else{if(r===null)throw Oe.alternate===null?Error(a(467)):Error(a(310));Ye=r,r={memoizedState:Ye.memoizedState,baseState:Ye.baseState,baseQueue:Ye.baseQueue,queue:Ye.queue,next:null},ht===null?Oe.memoizedState=ht=r:ht=ht.next=r}return ht}function sc(){return{lastEffect:null,events:null,stores:null,memoCache:null}}function Ju(r){var i=Wu;return Wu+=1,wi===null&&(wi=[]),r=X1(wi,r,i),i=Oe,(ht===null?i.memoizedState:ht.next)===null&&(i=i.alternate,q.H=i===null||i.memoizedState===null?$x:nh),r}function fc(r){if(r!==null&&typeof r=="object"){if(typeof r.then=="function")return Ju(r);if(r.$$typeof===M)return Nt(r)}throw Error(a(438,String(r)))}function Yd(r){var i=null,l=Oe.updateQueue;if(l!==null&&(i=l.memoCache),i==null){var c=Oe.alternate;c!==null&&(c=c.updateQueue,c!==null&&(c=c.memoCache,c!=null&&(i={data:c.data.map(function(v){return v.slice()}),index:0})))}if(i==null&&(i={data:[],index:0}),l===null&&(l=sc(),Oe.updateQueue=l),l.memoCache=i,l=i.data[i.index],l===void 0)for(l=i.data[i.index]=Array(r),c=0;c<r;c++)l[c]=W;return i.index++,l}function cr(r,i){return typeof i=="function"?i(r):i}function dc(r){var i=ft();return Xd(i,Ye,r)}function Xd(r,i,l){var c=r.queue;if(c===null)throw Error(a(311));c.lastRenderedReducer=l;var v=r.baseQueue,p=c.pending;if(p!==null){if(v!==null){var S=v.next;v.next=p.next,p.next=S}i.baseQueue=v=p,c.pending=null}if(p=r.baseState,v===null)r.memoizedState=p;else{i=v.next;var C=S=null,z=null,X=i,te=!1;do{var ie=X.lane&-536870913;if(ie!==X.lane?(Ce&ie)===ie:(or&ie)===ie){var K=X.revertLane;if(K===0)z!==null&&(z=z.next={lane:0,revertLane:0,gesture:null,action:X.action,hasEagerState:X.hasEagerState,eagerState:X.eagerState,next:null}),ie===bi&&(te=!0);else if((or&K)===K){X=X.next,K===bi&&(te=!0);continue}else ie={lane:0,revertLane:X.revertLane,gesture:null,action:X.action,hasEagerState:X.hasEagerState,eagerState:X.eagerState,next:null},z===null?(C=z=ie,S=p):z=z.next=ie,Oe.lanes|=K,Kr|=K;ie=X.action,Ma&&l(p,ie),p=X.hasEagerState?X.eagerState:l(p,ie)}else K={lane:ie,revertLane:X.revertLane,gesture:X.gesture,action:X.action,hasEagerState:X.hasEagerState,eagerState:X.eagerState,next:null},z===null?(C=z=K,S=p):z=z.next=K,Oe.lanes|=ie,Kr|=ie;X=X.next}while(X!==null&&X!==i);if(z===null?S=p:z.next=C,!nn(p,r.memoizedState)&&(vt=!0,te&&(l=xi,l!==null)))throw l;r.memoizedState=p,r.baseState=S,r.baseQueue=z,c.lastRenderedState=p}return v===null&&(c.lanes=0),[r.memoizedState,c.dispatch]}function Kd(r){var i=ft(),l=i.queue;if(l===null)throw Error(a(311));l.lastRenderedReducer=r;var c=l.dispatch,v=l.pending,p=i.memoizedState;if(v!==null){l.pending=null;var S=v=v.next;do p=r(p,S.action),S=S.next;while(S!==v);nn(p,i.memoizedState)||(vt=!0),i.memoizedState=p,i.baseQueue===null&&(i.baseState=p),l.lastRenderedState=p}return[p,c]}function rx(r,i,l){var c=Oe,v=ft(),p=De;if(p){if(l===void 0)throw Error(a(407));l=l()}else l=i();var S=!nn((Ye||v).memoizedState,l);if(S&&(v.memoizedState=l,vt=!0),v=v.queue,Fd(ux.bind(null,c,v,r),[r]),v.getSnapshot!==i||S||ht!==null&&ht.memoizedState.tag&1){if(c.flags|=2048,Ti(9,{destroy:void 0},ix.bind(null,c,v,l,i),null),Ze===null)throw Error(a(349));p||(or&127)!==0||ax(c,i,l)}return l}function ax(r,i,l){r.flags|=16384,r={getSnapshot:i,value:l},i=Oe.updateQueue,i===null?(i=sc(),Oe.updateQueue=i,i.stores=[r]):(l=i.stores,l===null?i.stores=[r]:l.push(r))}function ix(r,i,l,c){i.value=l,i.getSnapshot=c,lx(i)&&ox(r)}function ux(r,i,l){return l(function(){lx(i)&&ox(r)})}function lx(r){var i=r.getSnapshot;r=r.value;try{var l=i();return!nn(r,l)}catch{return!0}}function ox(r){var i=xa(r,2);i!==null&&Qt(i,r,2)}function Vd(r){var i=It();if(typeof r=="function"){var l=r;if(r=l(),Ma){Rr(!0);try{l()}finally{Rr(!1)}}}return i.memoizedState=i.baseState=r,i.queue={pending:null,lanes:0,dispatch:null,lastRenderedReducer:cr,lastRenderedState:r},i}function cx(r,i,l,c){return r.baseState=l,Xd(r,Ye,typeof c=="function"?c:cr)}function YD(r,i,l,c,v){if(pc(r))throw Error(a(485));if(r=i.action,r!==null){var p={payload:v,action:r,next:null,isTransition:!0,status:"pending",value:null,reason:null,listeners:[],then:function(S){p.listeners.push(S)}};q.T!==null?l(!0):p.isTransition=!1,c(p),l=i.pending,l===null?(p.next=i.pending=p,sx(i,p)):(p.next=l.next,i.pending=l.next=p)}}function sx(r,i){var l=i.action,c=i.payload,v=r.state;if(i.isTransition){var p=q.T,S={};q.T=S;try{var C=l(v,c),z=q.S;z!==null&&z(S,C),fx(r,i,C)}catch(X){Zd(r,i,X)}finally{p!==null&&S.types!==null&&(p.types=S.types),q.T=p}}else try{p=l(v,c),fx(r,i,p)}catch(X){Zd(r,i,X)}}function fx(r,i,l){l!==null&&typeof l=="object"&&typeof l.then=="function"?l.then(function(c){dx(r,i,c)},function(c){return Zd(r,i,c)}):dx(r,i,l)}function dx(r,i,l){i.status="fulfilled",i.value=l,hx(i),r.state=l,i=r.pending,i!==null&&(l=i.next,l===i?r.pending=null:(l=l.next,i.next=l,sx(r,l)))}function Zd(r,i,l){var c=r.pending;if(r.pending=null,c!==null){c=c.next;do i.status="rejected",i.reason=l,hx(i),i=i.next;while(i!==c)}r.action=null}function hx(r){r=r.listeners;for(var i=0;i<r.length;i++)(0,r[i])()}function vx(r,i){return i}function px(r,i){if(De){var l=Ze.formState;if(l!==null){e:{var c=Oe;if(De){if(et){t:{for(var v=et,p=bn;v.nodeType!==8;){if(!p){v=null;break t}if(v=Sn(v.nextSibling),v===null){v=null;break t}}p=v.data,v=p==="F!"||p==="F"?v:null}if(v){et=Sn(v.nextSibling),c=v.data==="F!";break e}}Br(c)}c=!1}c&&(i=l[0])}}return l=It(),l.memoizedState=l.baseState=i,c={pending:null,lanes:0,dispatch:null,lastRenderedReducer:vx,lastRenderedState:i},l.queue=c,l=Px.bind(null,Oe,c),c.dispatch=l,c=Vd(!1),p=th.bind(null,Oe,!1,c.queue),c=It(),v={state:i,dispatch:null,action:r,pending:null},c.queue=v,l=YD.bind(null,Oe,v,p,l),v.dispatch=l,c.memoizedState=r,[i,l,!1]}function yx(r){var i=ft();return mx(i,Ye,r)}function mx(r,i,l){if(i=Xd(r,i,vx)[0],r=dc(cr)[0],typeof i=="object"&&i!==null&&typeof i.then=="function")try{var c=Ju(i)}catch(S){throw S===Si?nc:S}else c=i;i=ft();var v=i.queue,p=v.dispatch;return l!==i.memoizedState&&(Oe.flags|=2048,Ti(9,{destroy:void 0},XD.bind(null,v,l),null)),[c,p,r]}function XD(r,i){r.action=i}function gx(r){var i=ft(),l=Ye;if(l!==null)return mx(i,l,r);ft(),i=i.memoizedState,l=ft();var c=l.queue.dispatch;return l.memoizedState=r,[i,c,!1]}function Ti(r,i,l,c){return r={tag:r,create:l,deps:c,inst:i,next:null},i=Oe.updateQueue,i===null&&(i=sc(),Oe.updateQueue=i),l=i.lastEffect,l===null?i.lastEffect=r.next=r:(c=l.next,l.next=r,r.next=c,i.lastEffect=r),r}function bx(){return ft().memoizedState}function hc(r,i,l,c){var v=It();Oe.flags|=r,v.memoizedState=Ti(1|i,{destroy:void 0},l,c===void 0?null:c)}function vc(r,i,l,c){var v=ft();c=c===void 0?null:c;var p=v.memoizedState.inst;Ye!==null&&c!==null&&Ud(c,Ye.memoizedState.deps)?v.memoizedState=Ti(i,p,l,c):(Oe.flags|=r,v.memoizedState=Ti(1|i,p,l,c))}function xx(r,i){hc(8390656,8,r,i)}function Fd(r,i){vc(2048,8,r,i)}function KD(r){Oe.flags|=4;var i=Oe.updateQueue;if(i===null)i=sc(),Oe.updateQueue=i,i.events=[r];else{var l=i.events;l===null?i.events=[r]:l.push(r)}}function Sx(r){var i=ft().memoizedState;return KD({ref:i,nextImpl:r}),function(){if((ze&2)!==0)throw Error(a(440));return i.impl.apply(void 0,arguments)}}function _x(r,i){return vc(4,2,r,i)}function Ox(r,i){return vc(4,4,r,i)}function Ax(r,i){if(typeof i=="function"){r=r();var l=i(r);return function(){typeof l=="function"?l():i(null)}}if(i!=null)return r=r(),i.current=r,function(){i.current=null}}function wx(r,i,l){l=l!=null?l.concat([r]):null,vc(4,4,Ax.bind(null,i,r),l)}function Qd(){}function Tx(r,i){var l=ft();i=i===void 0?null:i;var c=l.memoizedState;return i!==null&&Ud(i,c[1])?c[0]:(l.memoizedState=[r,i],r)}function Ex(r,i){var l=ft();i=i===void 0?null:i;var c=l.memoizedState;if(i!==null&&Ud(i,c[1]))return c[0];if(c=r(),Ma){Rr(!0);try{r()}finally{Rr(!1)}}return l.memoizedState=[c,i],c}function Wd(r,i,l){return l===void 0||(or&1073741824)!==0&&(Ce&261930)===0?r.memoizedState=i:(r.memoizedState=l,r=jS(),Oe.lanes|=r,Kr|=r,l)}function jx(r,i,l,c){return nn(l,i)?l:Oi.current!==null?(r=Wd(r,l,c),nn(r,i)||(vt=!0),r):(or&42)===0||(or&1073741824)!==0&&(Ce&261930)===0?(vt=!0,r.memoizedState=l):(r=jS(),Oe.lanes|=r,Kr|=r,i)}function Mx(r,i,l,c,v){var p=I.p;I.p=p!==0&&8>p?p:8;var S=q.T,C={};q.T=C,th(r,!1,i,l);try{var z=v(),X=q.S;if(X!==null&&X(C,z),z!==null&&typeof z=="object"&&typeof z.then=="function"){var te=HD(z,c);el(r,i,te,cn(r))}else el(r,i,c,cn(r))}catch(ie){el(r,i,{then:function(){},status:"rejected",reason:ie},cn())}finally{I.p=p,S!==null&&C.types!==null&&(S.types=C.types),q.T=S}}function VD(){}function Jd(r,i,l,c){if(r.tag!==5)throw Error(a(476));var v=Cx(r).queue;Mx(r,v,i,ee,l===null?VD:function(){return Nx(r),l(c)})}function Cx(r){var i=r.memoizedState;if(i!==null)return i;i={memoizedState:ee,baseState:ee,baseQueue:null,queue:{pending:null,lanes:0,dispatch:null,lastRenderedReducer:cr,lastRenderedState:ee},next:null};var l={};return i.next={memoizedState:l,baseState:l,baseQueue:null,queue:{pending:null,lanes:0,dispatch:null,lastRenderedReducer:cr,lastRenderedState:l},next:null},r.memoizedState=i,r=r.alternate,r!==null&&(r.memoizedState=i),i}function Nx(r){var i=Cx(r);i.next===null&&(i=r.alternate.memoizedState),el(r,i.next.queue,{},cn())}function eh(){return Nt(ml)}function Dx(){return ft().memoizedState}function Rx(){return ft().memoizedState}function ZD(r){for(var i=r.return;i!==null;){switch(i.tag){case 24:case 3:var l=cn();r=kr(l);var c=Hr(i,r,l);c!==null&&(Qt(c,i,l),Zu(c,i,l)),i={cache:Md()},r.payload=i;return}i=i.return}}function FD(r,i,l){var c=cn();l={lane:c,revertLane:0,gesture:null,action:l,hasEagerState:!1,eagerState:null,next:null},pc(r)?qx(i,l):(l=gd(r,i,l,c),l!==null&&(Qt(l,r,c),zx(l,i,c)))}function Px(r,i,l){var c=cn();el(r,i,l,c)}function el(r,i,l,c){var v={lane:c,revertLane:0,gesture:null,action:l,hasEagerState:!1,eagerState:null,next:null};if(pc(r))qx(i,v);else{var p=r.alternate;if(r.lanes===0&&(p===null||p.lanes===0)&&(p=i.lastRenderedReducer,p!==null))try{var S=i.lastRenderedState,C=p(S,l);if(v.hasEagerState=!0,v.eagerState=C,nn(C,S))return Zo(r,i,v,0),Ze===null&&Vo(),!1}catch{}finally{}if(l=gd(r,i,v,c),l!==null)return Qt(l,r,c),zx(l,i,c),!0}return!1}function th(r,i,l,c){if(c={lane:2,revertLane:Rh(),gesture:null,action:c,hasEagerState:!1,eagerState:null,next:null},pc(r)){if(i)throw Error(a(479))}else i=gd(r,l,c,2),i!==null&&Qt(i,r,2)}function pc(r){var i=r.alternate;return r===Oe||i!==null&&i===Oe}function qx(r,i){Ai=oc=!0;var l=r.pending;l===null?i.next=i:(i.next=l.next,l.next=i),r.pending=i}function zx(r,i,l){if((l&4194048)!==0){var c=i.lanes;c&=r.pendingLanes,l|=c,i.lanes=l,kb(r,l)}}var tl={readContext:Nt,use:fc,useCallback:lt,useContext:lt,useEffect:lt,useImperativeHandle:lt,useLayoutEffect:lt,useInsertionEffect:lt,useMemo:lt,useReducer:lt,useRef:lt,useState:lt,useDebugValue:lt,useDeferredValue:lt,useTransition:lt,useSyncExternalStore:lt,useId:lt,useHostTransitionStatus:lt,useFormState:lt,useActionState:lt,useOptimistic:lt,useMemoCache:lt,useCacheRefresh:lt};tl.useEffectEvent=lt;var $x={readContext:Nt,use:fc,useCallback:function(r,i){return It().memoizedState=[r,i===void 0?null:i],r},useContext:Nt,useEffect:xx,useImperativeHandle:function(r,i,l){l=l!=null?l.concat([r]):null,hc(4194308,4,Ax.bind(null,i,r),l)},useLayoutEffect:function(r,i){return hc(4194308,4,r,i)},useInsertionEffect:function(r,i){hc(4,2,r,i)},useMemo:function(r,i){var l=It();i=i===void 0?null:i;var c=r();if(Ma){Rr(!0);try{r()}finally{Rr(!1)}}return l.memoizedState=[c,i],c},useReducer:function(r,i,l){var c=It();if(l!==void 0){var v=l(i);if(Ma){Rr(!0);try{l(i)}finally{Rr(!1)}}}else v=i;return c.memoizedState=c.baseState=v,r={pending:null,lanes:0,dispatch:null,lastRenderedReducer:r,lastRenderedState:v},c.queue=r,r=r.dispatch=FD.bind(null,Oe,r),[c.memoizedState,r]},useRef:function(r){var i=It();return r={current:r},i.memoizedState=r},useState:function(r){r=Vd(r);var i=r.queue,l=Px.bind(null,Oe,i);return i.dispatch=l,[r.memoizedState,l]},useDebugValue:Qd,useDeferredValue:function(r,i){var l=It();return Wd(l,r,i)},useTransition:function(){var r=Vd(!1);return r=Mx.bind(null,Oe,r.queue,!0,!1),It().memoizedState=r,[!1,r]},useSyncExternalStore:function(r,i,l){var c=Oe,v=It();if(De){if(l===void 0)throw Error(a(407));l=l()}else{if(l=i(),Ze===null)throw Error(a(349));(Ce&127)!==0||ax(c,i,l)}v.memoizedState=l;var p={value:l,getSnapshot:i};return v.queue=p,xx(ux.bind(null,c,p,r),[r]),c.flags|=2048,Ti(9,{destroy:void 0},ix.bind(null,c,p,l,i),null),l},useId:function(){var r=It(),i=Ze.identifierPrefix;if(De){var l=In,c=Hn;l=(c&~(1<<32-tn(c)-1)).toString(32)+l,i="_"+i+"R_"+l,l=cc++,0<l&&(i+="H"+l.toString(32)),i+="_"}else l=ID++,i="_"+i+"r_"+l.toString(32)+"_";return r.memoizedState=i},useHostTransitionStatus:eh,useFormState:px,useActionState:px,useOptimistic:function(r){var i=It();i.memoizedState=i.baseState=r;var l={pending:null,lanes:0,dispatch:null,lastRenderedReducer:null,lastRenderedState:null};return i.queue=l,i=th.bind(null,Oe,!0,l),l.dispatch=i,[r,i]},useMemoCache:Yd,useCacheRefresh:function(){return It().memoizedState=ZD.bind(null,Oe)},useEffectEvent:function(r){var i=It(),l={impl:r};return i.memoizedState=l,function(){if((ze&2)!==0)throw Error(a(440));return l.impl.apply(void 0,arguments)}}},nh={readContext:Nt,use:fc,useCallback:Tx,useContext:Nt,useEffect:Fd,useImperativeHandle:wx,useInsertionEffect:_x,useLayoutEffect:Ox,useMemo:Ex,useReducer:dc,useRef:bx,useState:function(){return dc(cr)},useDebugValue:Qd,useDeferredValue:function(r,i){var l=ft();return jx(l,Ye.memoizedState,r,i)},useTransition:function(){var r=dc(cr)[0],i=ft().memoizedState;return[typeof r=="boolean"?r:Ju(r),i]},useSyncExternalStore:rx,useId:Dx,useHostTransitionStatus:eh,useFormState:yx,useActionState:yx,useOptimistic:function(r,i){var l=ft();return cx(l,Ye,r,i)},useMemoCache:Yd,useCacheRefresh:Rx};nh.useEffectEvent=Sx;var Bx={readContext:Nt,use:fc,useCallback:Tx,useContext:Nt,useEffect:Fd,useImperativeHandle:wx,useInsertionEffect:_x,useLayoutEffect:Ox,useMemo:Ex,useReducer:Kd,useRef:bx,useState:function(){return Kd(cr)},useDebugValue:Qd,useDeferredValue:function(r,i){var l=ft();return Ye===null?Wd(l,r,i):jx(l,Ye.memoizedState,r,i)},useTransition:function(){var r=Kd(cr)[0],i=ft().memoizedState;return[typeof r=="boolean"?r:Ju(r),i]},useSyncExternalStore:rx,useId:Dx,useHostTransitionStatus:eh,useFormState:gx,useActionState:gx,useOptimistic:function(r,i){var l=ft();return Ye!==null?cx(l,Ye,r,i):(l.baseState=r,[r,l.queue.dispatch])},useMemoCache:Yd,useCacheRefresh:Rx};Bx.useEffectEvent=Sx;function rh(r,i,l,c){i=r.memoizedState,l=l(c,i),l=l==null?i:y({},i,l),r.memoizedState=l,r.lanes===0&&(r.updateQueue.baseState=l)}var ah={enqueueSetState:function(r,i,l){r=r._reactInternals;var c=cn(),v=kr(c);v.payload=i,l!=null&&(v.callback=l),i=Hr(r,v,c),i!==null&&(Qt(i,r,c),Zu(i,r,c))},enqueueReplaceState:function(r,i,l){r=r._reactInternals;var c=cn(),v=kr(c);v.tag=1,v.payload=i,l!=null&&(v.callback=l),i=Hr(r,v,c),i!==null&&(Qt(i,r,c),Zu(i,r,c))},enqueueForceUpdate:function(r,i){r=r._reactInternals;var l=cn(),c=kr(l);c.tag=2,i!=null&&(c.callback=i),i=Hr(r,c,l),i!==null&&(Qt(i,r,l),Zu(i,r,l))}};function Lx(r,i,l,c,v,p,S){return r=r.stateNode,typeof r.shouldComponentUpdate=="function"?r.shouldComponentUpdate(c,p,S):i.prototype&&i.prototype.isPureReactComponent?!ku(l,c)||!ku(v,p):!0}function Ux(r,i,l,c){r=i.state,typeof i.componentWillReceiveProps=="function"&&i.componentWillReceiveProps(l,c),typeof i.UNSAFE_componentWillReceiveProps=="function"&&i.UNSAFE_componentWillReceiveProps(l,c),i.state!==r&&ah.enqueueReplaceState(i,i.state,null)}function Ca(r,i){var l=i;if("ref"in i){l={};for(var c in i)c!=="ref"&&(l[c]=i[c])}if(r=r.defaultProps){l===i&&(l=y({},l));for(var v in r)l[v]===void 0&&(l[v]=r[v])}return l}function kx(r){Ko(r)}function Hx(r){console.error(r)}function Ix(r){Ko(r)}function yc(r,i){try{var l=r.onUncaughtError;l(i.value,{componentStack:i.stack})}catch(c){setTimeout(function(){throw c})}}function Gx(r,i,l){try{var c=r.onCaughtError;c(l.value,{componentStack:l.stack,errorBoundary:i.tag===1?i.stateNode:null})}catch(v){setTimeout(function(){throw v})}}function ih(r,i,l){return l=kr(l),l.tag=3,l.payload={element:null},l.callback=function(){yc(r,i)},l}function Yx(r){return r=kr(r),r.tag=3,r}function Xx(r,i,l,c){var v=l.type.getDerivedStateFromError;if(typeof v=="function"){var p=c.value;r.payload=function(){return v(p)},r.callback=function(){Gx(i,l,c)}}var S=l.stateNode;S!==null&&typeof S.componentDidCatch=="function"&&(r.callback=function(){Gx(i,l,c),typeof v!="function"&&(Vr===null?Vr=new Set([this]):Vr.add(this));var C=c.stack;this.componentDidCatch(c.value,{componentStack:C!==null?C:""})})}function QD(r,i,l,c,v){if(l.flags|=32768,c!==null&&typeof c=="object"&&typeof c.then=="function"){if(i=l.alternate,i!==null&&gi(i,l,v,!0),l=an.current,l!==null){switch(l.tag){case 31:case 13:return xn===null?jc():l.alternate===null&&ot===0&&(ot=3),l.flags&=-257,l.flags|=65536,l.lanes=v,c===rc?l.flags|=16384:(i=l.updateQueue,i===null?l.updateQueue=new Set([c]):i.add(c),Ch(r,c,v)),!1;case 22:return l.flags|=65536,c===rc?l.flags|=16384:(i=l.updateQueue,i===null?(i={transitions:null,markerInstances:null,retryQueue:new Set([c])},l.updateQueue=i):(l=i.retryQueue,l===null?i.retryQueue=new Set([c]):l.add(c)),Ch(r,c,v)),!1}throw Error(a(435,l.tag))}return Ch(r,c,v),jc(),!1}if(De)return i=an.current,i!==null?((i.flags&65536)===0&&(i.flags|=256),i.flags|=65536,i.lanes=v,c!==Ad&&(r=Error(a(422),{cause:c}),Gu(yn(r,l)))):(c!==Ad&&(i=Error(a(423),{cause:c}),Gu(yn(i,l))),r=r.current.alternate,r.flags|=65536,v&=-v,r.lanes|=v,c=yn(c,l),v=ih(r.stateNode,c,v),qd(r,v),ot!==4&&(ot=2)),!1;var p=Error(a(520),{cause:c});if(p=yn(p,l),cl===null?cl=[p]:cl.push(p),ot!==4&&(ot=2),i===null)return!0;c=yn(c,l),l=i;do{switch(l.tag){case 3:return l.flags|=65536,r=v&-v,l.lanes|=r,r=ih(l.stateNode,c,r),qd(l,r),!1;case 1:if(i=l.type,p=l.stateNode,(l.flags&128)===0&&(typeof i.getDerivedStateFromError=="function"||p!==null&&typeof p.componentDidCatch=="function"&&(Vr===null||!Vr.has(p))))return l.flags|=65536,v&=-v,l.lanes|=v,v=Yx(v),Xx(v,r,l,c),qd(l,v),!1}l=l.return}while(l!==null);return!1}var uh=Error(a(461)),vt=!1;function Dt(r,i,l,c){i.child=r===null?F1(i,null,l,c):ja(i,r.child,l,c)}function Kx(r,i,l,c,v){l=l.render;var p=i.ref;if("ref"in c){var S={};for(var C in c)C!=="ref"&&(S[C]=c[C])}else S=c;return Aa(i),c=kd(r,i,l,S,p,v),C=Hd(),r!==null&&!vt?(Id(r,i,v),sr(r,i,v)):(De&&C&&_d(i),i.flags|=1,Dt(r,i,c,v),i.child)}function Vx(r,i,l,c,v){if(r===null){var p=l.type;return typeof p=="function"&&!bd(p)&&p.defaultProps===void 0&&l.compare===null?(i.tag=15,i.type=p,Zx(r,i,p,c,v)):(r=Qo(l.type,null,c,i,i.mode,v),r.ref=i.ref,r.return=i,i.child=r)}if(p=r.child,!vh(r,v)){var S=p.memoizedProps;if(l=l.compare,l=l!==null?l:ku,l(S,c)&&r.ref===i.ref)return sr(r,i,v)}return i.flags|=1,r=ar(p,c),r.ref=i.ref,r.return=i,i.child=r}function Zx(r,i,l,c,v){if(r!==null){var p=r.memoizedProps;if(ku(p,c)&&r.ref===i.ref)if(vt=!1,i.pendingProps=c=p,vh(r,v))(r.flags&131072)!==0&&(vt=!0);else return i.lanes=r.lanes,sr(r,i,v)}return lh(r,i,l,c,v)}function Fx(r,i,l,c){var v=c.children,p=r!==null?r.memoizedState:null;if(r===null&&i.stateNode===null&&(i.stateNode={_visibility:1,_pendingMarkers:null,_retryCache:null,_transitions:null}),c.mode==="hidden"){if((i.flags&128)!==0){if(p=p!==null?p.baseLanes|l:l,r!==null){for(c=i.child=r.child,v=0;c!==null;)v=v|c.lanes|c.childLanes,c=c.sibling;c=v&~p}else c=0,i.child=null;return Qx(r,i,p,l,c)}if((l&536870912)!==0)i.memoizedState={baseLanes:0,cachePool:null},r!==null&&tc(i,p!==null?p.cachePool:null),p!==null?J1(i,p):$d(),ex(i);else return c=i.lanes=536870912,Qx(r,i,p!==null?p.baseLanes|l:l,l,c)}else p!==null?(tc(i,p.cachePool),J1(i,p),Gr(),i.memoizedState=null):(r!==null&&tc(i,null),$d(),Gr());return Dt(r,i,v,l),i.child}function nl(r,i){return r!==null&&r.tag===22||i.stateNode!==null||(i.stateNode={_visibility:1,_pendingMarkers:null,_retryCache:null,_transitions:null}),i.sibling}function Qx(r,i,l,c,v){var p=Nd();return p=p===null?null:{parent:dt._currentValue,pool:p},i.memoizedState={baseLanes:l,cachePool:p},r!==null&&tc(i,null),$d(),ex(i),r!==null&&gi(r,i,c,!0),i.childLanes=v,null}function mc(r,i){return i=bc({mode:i.mode,children:i.children},r.mode),i.ref=r.ref,r.child=i,i.return=r,i}function Wx(r,i,l){return ja(i,r.child,null,l),r=mc(i,i.pendingProps),r.flags|=2,un(i),i.memoizedState=null,r}function WD(r,i,l){var c=i.pendingProps,v=(i.flags&128)!==0;if(i.flags&=-129,r===null){if(De){if(c.mode==="hidden")return r=mc(i,c),i.lanes=536870912,nl(null,r);if(Ld(i),(r=et)?(r=s_(r,bn),r=r!==null&&r.data==="&"?r:null,r!==null&&(i.memoizedState={dehydrated:r,treeContext:zr!==null?{id:Hn,overflow:In}:null,retryLane:536870912,hydrationErrors:null},l=q1(r),l.return=i,i.child=l,Ct=i,et=null)):r=null,r===null)throw Br(i);return i.lanes=536870912,null}return mc(i,c)}var p=r.memoizedState;if(p!==null){var S=p.dehydrated;if(Ld(i),v)if(i.flags&256)i.flags&=-257,i=Wx(r,i,l);else if(i.memoizedState!==null)i.child=r.child,i.flags|=128,i=null;else throw Error(a(558));else if(vt||gi(r,i,l,!1),v=(l&r.childLanes)!==0,vt||v){if(c=Ze,c!==null&&(S=Hb(c,l),S!==0&&S!==p.retryLane))throw p.retryLane=S,xa(r,S),Qt(c,r,S),uh;jc(),i=Wx(r,i,l)}else r=p.treeContext,et=Sn(S.nextSibling),Ct=i,De=!0,$r=null,bn=!1,r!==null&&B1(i,r),i=mc(i,c),i.flags|=4096;return i}return r=ar(r.child,{mode:c.mode,children:c.children}),r.ref=i.ref,i.child=r,r.return=i,r}function gc(r,i){var l=i.ref;if(l===null)r!==null&&r.ref!==null&&(i.flags|=4194816);else{if(typeof l!="function"&&typeof l!="object")throw Error(a(284));(r===null||r.ref!==l)&&(i.flags|=4194816)}}function lh(r,i,l,c,v){return Aa(i),l=kd(r,i,l,c,void 0,v),c=Hd(),r!==null&&!vt?(Id(r,i,v),sr(r,i,v)):(De&&c&&_d(i),i.flags|=1,Dt(r,i,l,v),i.child)}function Jx(r,i,l,c,v,p){return Aa(i),i.updateQueue=null,l=nx(i,c,l,v),tx(r),c=Hd(),r!==null&&!vt?(Id(r,i,p),sr(r,i,p)):(De&&c&&_d(i),i.flags|=1,Dt(r,i,l,p),i.child)}function eS(r,i,l,c,v){if(Aa(i),i.stateNode===null){var p=vi,S=l.contextType;typeof S=="object"&&S!==null&&(p=Nt(S)),p=new l(c,p),i.memoizedState=p.state!==null&&p.state!==void 0?p.state:null,p.updater=ah,i.stateNode=p,p._reactInternals=i,p=i.stateNode,p.props=c,p.state=i.memoizedState,p.refs={},Rd(i),S=l.contextType,p.context=typeof S=="object"&&S!==null?Nt(S):vi,p.state=i.memoizedState,S=l.getDerivedStateFromProps,typeof S=="function"&&(rh(i,l,S,c),p.state=i.memoizedState),typeof l.getDerivedStateFromProps=="function"||typeof p.getSnapshotBeforeUpdate=="function"||typeof p.UNSAFE_componentWillMount!="function"&&typeof p.componentWillMount!="function"||(S=p.state,typeof p.componentWillMount=="function"&&p.componentWillMount(),typeof p.UNSAFE_componentWillMount=="function"&&p.UNSAFE_componentWillMount(),S!==p.state&&ah.enqueueReplaceState(p,p.state,null),Qu(i,c,p,v),Fu(),p.state=i.memoizedState),typeof p.componentDidMount=="function"&&(i.flags|=4194308),c=!0}else if(r===null){p=i.stateNode;var C=i.memoizedProps,z=Ca(l,C);p.props=z;var X=p.context,te=l.contextType;S=vi,typeof te=="object"&&te!==null&&(S=Nt(te));var ie=l.getDerivedStateFromProps;te=typeof ie=="function"||typeof p.getSnapshotBeforeUpdate=="function",C=i.pendingProps!==C,te||typeof p.UNSAFE_componentWillReceiveProps!="function"&&typeof p.componentWillReceiveProps!="function"||(C||X!==S)&&Ux(i,p,c,S),Ur=!1;var K=i.memoizedState;p.state=K,Qu(i,c,p,v),Fu(),X=i.memoizedState,C||K!==X||Ur?(typeof ie=="function"&&(rh(i,l,ie,c),X=i.memoizedState),(z=Ur||Lx(i,l,z,c,K,X,S))?(te||typeof p.UNSAFE_componentWillMount!="function"&&typeof p.componentWillMount!="function"||(typeof p.componentWillMount=="function"&&p.componentWillMount(),typeof p.UNSAFE_componentWillMount=="function"&&p.UNSAFE_componentWillMount()),typeof p.componentDidMount=="function"&&(i.flags|=4194308)):(typeof p.componentDidMount=="function"&&(i.flags|=4194308),i.memoizedProps=c,i.memoizedState=X),p.props=c,p.state=X,p.context=S,c=z):(typeof p.componentDidMount=="function"&&(i.flags|=4194308),c=!1)}else{p=i.stateNode,Pd(r,i),S=i.memoizedProps,te=Ca(l,S),p.props=te,ie=i.pendingProps,K=p.context,X=l.contextType,z=vi,typeof X=="object"&&X!==null&&(z=Nt(X)),C=l.getDerivedStateFromProps,(X=typeof C=="function"||typeof p.getSnapshotBeforeUpdate=="function")||typeof p.UNSAFE_componentWillReceiveProps!="function"&&typeof p.componentWillReceiveProps!="function"||(S!==ie||K!==z)&&Ux(i,p,c,z),Ur=!1,K=i.memoizedState,p.state=K,Qu(i,c,p,v),Fu();var Z=i.memoizedState;S!==ie||K!==Z||Ur||r!==null&&r.dependencies!==null&&Jo(r.dependencies)?(typeof C=="function"&&(rh(i,l,C,c),Z=i.memoizedState),(te=Ur||Lx(i,l,te,c,K,Z,z)||r!==null&&r.dependencies!==null&&Jo(r.dependencies))?(X||typeof p.UNSAFE_componentWillUpdate!="function"&&typeof p.componentWillUpdate!="function"||(typeof p.componentWillUpdate=="function"&&p.componentWillUpdate(c,Z,z),typeof p.UNSAFE_componentWillUpdate=="function"&&p.UNSAFE_componentWillUpdate(c,Z,z)),typeof p.componentDidUpdate=="function"&&(i.flags|=4),typeof p.getSnapshotBeforeUpdate=="function"&&(i.flags|=1024)):(typeof p.componentDidUpdate!="function"||S===r.memoizedProps&&K===r.memoizedState||(i.flags|=4),typeof p.getSnapshotBeforeUpdate!="function"||S===r.memoizedProps&&K===r.memoizedState||(i.flags|=1024),i.memoizedProps=c,i.memoizedState=Z),p.props=c,p.state=Z,p.context=z,c=te):(typeof p.componentDidUpdate!="function"||S===r.memoizedProps&&K===r.memoizedState||(i.flags|=4),typeof p.getSnapshotBeforeUpdate!="function"||S===r.memoizedProps&&K===r.memoizedState||(i.flags|=1024),c=!1)}return p=c,gc(r,i),c=(i.flags&128)!==0,p||c?(p=i.stateNode,l=c&&typeof l.getDerivedStateFromError!="function"?null:p.render(),i.flags|=1,r!==null&&c?(i.child=ja(i,r.child,null,v),i.child=ja(i,null,l,v)):Dt(r,i,l,v),i.memoizedState=p.state,r=i.child):r=sr(r,i,v),r}function tS(r,i,l,c){return _a(),i.flags|=256,Dt(r,i,l,c),i.child}var oh={dehydrated:null,treeContext:null,retryLane:0,hydrationErrors:null};function ch(r){return{baseLanes:r,cachePool:G1()}}function sh(r,i,l){return r=r!==null?r.childLanes&~l:0,i&&(r|=on),r}function nS(r,i,l){var c=i.pendingProps,v=!1,p=(i.flags&128)!==0,S;if((S=p)||(S=r!==null&&r.memoizedState===null?!1:(st.current&2)!==0),S&&(v=!0,i.flags&=-129),S=(i.flags&32)!==0,i.flags&=-33,r===null){if(De){if(v?Ir(i):Gr(),(r=et)?(r=s_(r,bn),r=r!==null&&r.data!=="&"?r:null,r!==null&&(i.memoizedState={dehydrated:r,treeContext:zr!==null?{id:Hn,overflow:In}:null,retryLane:536870912,hydrationErrors:null},l=q1(r),l.return=i,i.child=l,Ct=i,et=null)):r=null,r===null)throw Br(i);return Xh(r)?i.lanes=32:i.lanes=536870912,null}var C=c.children;return c=c.fallback,v?(Gr(),v=i.mode,C=bc({mode:"hidden",children:C},v),c=Sa(c,v,l,null),C.return=i,c.return=i,C.sibling=c,i.child=C,c=i.child,c.memoizedState=ch(l),c.childLanes=sh(r,S,l),i.memoizedState=oh,nl(null,c)):(Ir(i),fh(i,C))}var z=r.memoizedState;if(z!==null&&(C=z.dehydrated,C!==null)){if(p)i.flags&256?(Ir(i),i.flags&=-257,i=dh(r,i,l)):i.memoizedState!==null?(Gr(),i.child=r.child,i.flags|=128,i=null):(Gr(),C=c.fallback,v=i.mode,c=bc({mode:"visible",children:c.children},v),C=Sa(C,v,l,null),C.flags|=2,c.return=i,C.return=i,c.sibling=C,i.child=c,ja(i,r.child,null,l),c=i.child,c.memoizedState=ch(l),c.childLanes=sh(r,S,l),i.memoizedState=oh,i=nl(null,c));else if(Ir(i),Xh(C)){if(S=C.nextSibling&&C.nextSibling.dataset,S)var X=S.dgst;S=X,c=Error(a(419)),c.stack="",c.digest=S,Gu({value:c,source:null,stack:null}),i=dh(r,i,l)}else if(vt||gi(r,i,l,!1),S=(l&r.childLanes)!==0,vt||S){if(S=Ze,S!==null&&(c=Hb(S,l),c!==0&&c!==z.retryLane))throw z.retryLane=c,xa(r,c),Qt(S,r,c),uh;Yh(C)||jc(),i=dh(r,i,l)}else Yh(C)?(i.flags|=192,i.child=r.child,i=null):(r=z.treeContext,et=Sn(C.nextSibling),Ct=i,De=!0,$r=null,bn=!1,r!==null&&B1(i,r),i=fh(i,c.children),i.flags|=4096);return i}return v?(Gr(),C=c.fallback,v=i.mode,z=r.child,X=z.sibling,c=ar(z,{mode:"hidden",children:c.children}),c.subtreeFlags=z.subtreeFlags&65011712,X!==null?C=ar(X,C):(C=Sa(C,v,l,null),C.flags|=2),C.return=i,c.return=i,c.sibling=C,i.child=c,nl(null,c),c=i.child,C=r.child.memoizedState,C===null?C=ch(l):(v=C.cachePool,v!==null?(z=dt._currentValue,v=v.parent!==z?{parent:z,pool:z}:v):v=G1(),C={baseLanes:C.baseLanes|l,cachePool:v}),c.memoizedState=C,c.childLanes=sh(r,S,l),i.memoizedState=oh,nl(r.child,c)):(Ir(i),l=r.child,r=l.sibling,l=ar(l,{mode:"visible",children:c.children}),l.return=i,l.sibling=null,r!==null&&(S=i.deletions,S===null?(i.deletions=[r],i.flags|=16):S.push(r)),i.child=l,i.memoizedState=null,l)}function fh(r,i){return i=bc({mode:"visible",children:i},r.mode),i.return=r,r.child=i}function bc(r,i){return r=rn(22,r,null,i),r.lanes=0,r}function dh(r,i,l){return ja(i,r.child,null,l),r=fh(i,i.pendingProps.children),r.flags|=2,i.memoizedState=null,r}function rS(r,i,l){r.lanes|=i;var c=r.alternate;c!==null&&(c.lanes|=i),Ed(r.return,i,l)}function hh(r,i,l,c,v,p){var S=r.memoizedState;S===null?r.memoizedState={isBackwards:i,rendering:null,renderingStartTime:0,last:c,tail:l,tailMode:v,treeForkCount:p}:(S.isBackwards=i,S.rendering=null,S.renderingStartTime=0,S.last=c,S.tail=l,S.tailMode=v,S.treeForkCount=p)}function aS(r,i,l){var c=i.pendingProps,v=c.revealOrder,p=c.tail;c=c.children;var S=st.current,C=(S&2)!==0;if(C?(S=S&1|2,i.flags|=128):S&=1,re(st,S),Dt(r,i,c,l),c=De?Iu:0,!C&&r!==null&&(r.flags&128)!==0)e:for(r=i.child;r!==null;){if(r.tag===13)r.memoizedState!==null&&rS(r,l,i);else if(r.tag===19)rS(r,l,i);else if(r.child!==null){r.child.return=r,r=r.child;continue}if(r===i)break e;for(;r.sibling===null;){if(r.return===null||r.return===i)break e;r=r.return}r.sibling.return=r.return,r=r.sibling}switch(v){case"forwards":for(l=i.child,v=null;l!==null;)r=l.alternate,r!==null&&lc(r)===null&&(v=l),l=l.sibling;l=v,l===null?(v=i.child,i.child=null):(v=l.sibling,l.sibling=null),hh(i,!1,v,l,p,c);break;case"backwards":case"unstable_legacy-backwards":for(l=null,v=i.child,i.child=null;v!==null;){if(r=v.alternate,r!==null&&lc(r)===null){i.child=v;break}r=v.sibling,v.sibling=l,l=v,v=r}hh(i,!0,l,null,p,c);break;case"together":hh(i,!1,null,null,void 0,c);break;default:i.memoizedState=null}return i.child}function sr(r,i,l){if(r!==null&&(i.dependencies=r.dependencies),Kr|=i.lanes,(l&i.childLanes)===0)if(r!==null){if(gi(r,i,l,!1),(l&i.childLanes)===0)return null}else return null;if(r!==null&&i.child!==r.child)throw Error(a(153));if(i.child!==null){for(r=i.child,l=ar(r,r.pendingProps),i.child=l,l.return=i;r.sibling!==null;)r=r.sibling,l=l.sibling=ar(r,r.pendingProps),l.return=i;l.sibling=null}return i.child}function vh(r,i){return(r.lanes&i)!==0?!0:(r=r.dependencies,!!(r!==null&&Jo(r)))}function JD(r,i,l){switch(i.tag){case 3:Ue(i,i.stateNode.containerInfo),Lr(i,dt,r.memoizedState.cache),_a();break;case 27:case 5:xe(i);break;case 4:Ue(i,i.stateNode.containerInfo);break;case 10:Lr(i,i.type,i.memoizedProps.value);break;case 31:if(i.memoizedState!==null)return i.flags|=128,Ld(i),null;break;case 13:var c=i.memoizedState;if(c!==null)return c.dehydrated!==null?(Ir(i),i.flags|=128,null):(l&i.child.childLanes)!==0?nS(r,i,l):(Ir(i),r=sr(r,i,l),r!==null?r.sibling:null);Ir(i);break;case 19:var v=(r.flags&128)!==0;if(c=(l&i.childLanes)!==0,c||(gi(r,i,l,!1),c=(l&i.childLanes)!==0),v){if(c)return aS(r,i,l);i.flags|=128}if(v=i.memoizedState,v!==null&&(v.rendering=null,v.tail=null,v.lastEffect=null),re(st,st.current),c)break;return null;case 22:return i.lanes=0,Fx(r,i,l,i.pendingProps);case 24:Lr(i,dt,r.memoizedState.cache)}return sr(r,i,l)}function iS(r,i,l){if(r!==null)if(r.memoizedProps!==i.pendingProps)vt=!0;else{if(!vh(r,l)&&(i.flags&128)===0)return vt=!1,JD(r,i,l);vt=(r.flags&131072)!==0}else vt=!1,De&&(i.flags&1048576)!==0&&$1(i,Iu,i.index);switch(i.lanes=0,i.tag){case 16:e:{var c=i.pendingProps;if(r=Ta(i.elementType),i.type=r,typeof r=="function")bd(r)?(c=Ca(r,c),i.tag=1,i=eS(null,i,r,c,l)):(i.tag=0,i=lh(null,i,r,c,l));else{if(r!=null){var v=r.$$typeof;if(v===N){i.tag=11,i=Kx(null,i,r,c,l);break e}else if(v===E){i.tag=14,i=Vx(null,i,r,c,l);break e}}throw i=Q(r)||r,Error(a(306,i,""))}}return i;case 0:return lh(r,i,i.type,i.pendingProps,l);case 1:return c=i.type,v=Ca(c,i.pendingProps),eS(r,i,c,v,l);case 3:e:{if(Ue(i,i.stateNode.containerInfo),r===null)throw Error(a(387));c=i.pendingProps;var p=i.memoizedState;v=p.element,Pd(r,i),Qu(i,c,null,l);var S=i.memoizedState;if(c=S.cache,Lr(i,dt,c),c!==p.cache&&jd(i,[dt],l,!0),Fu(),c=S.element,p.isDehydrated)if(p={element:c,isDehydrated:!1,cache:S.cache},i.updateQueue.baseState=p,i.memoizedState=p,i.flags&256){i=tS(r,i,c,l);break e}else if(c!==v){v=yn(Error(a(424)),i),Gu(v),i=tS(r,i,c,l);break e}else{switch(r=i.stateNode.containerInfo,r.nodeType){case 9:r=r.body;break;default:r=r.nodeName==="HTML"?r.ownerDocument.body:r}for(et=Sn(r.firstChild),Ct=i,De=!0,$r=null,bn=!0,l=F1(i,null,c,l),i.child=l;l;)l.flags=l.flags&-3|4096,l=l.sibling}else{if(_a(),c===v){i=sr(r,i,l);break e}Dt(r,i,c,l)}i=i.child}return i;case 26:return gc(r,i),r===null?(l=y_(i.type,null,i.pendingProps,null))?i.memoizedState=l:De||(l=i.type,r=i.pendingProps,c=qc(me.current).createElement(l),c[Mt]=i,c[Yt]=r,Rt(c,l,r),At(c),i.stateNode=c):i.memoizedState=y_(i.type,r.memoizedProps,i.pendingProps,r.memoizedState),null;case 27:return xe(i),r===null&&De&&(c=i.stateNode=h_(i.type,i.pendingProps,me.current),Ct=i,bn=!0,v=et,Wr(i.type)?(Kh=v,et=Sn(c.firstChild)):et=v),Dt(r,i,i.pendingProps.children,l),gc(r,i),r===null&&(i.flags|=4194304),i.child;case 5:return r===null&&De&&((v=c=et)&&(c=MR(c,i.type,i.pendingProps,bn),c!==null?(i.stateNode=c,Ct=i,et=Sn(c.firstChild),bn=!1,v=!0):v=!1),v||Br(i)),xe(i),v=i.type,p=i.pendingProps,S=r!==null?r.memoizedProps:null,c=p.children,Hh(v,p)?c=null:S!==null&&Hh(v,S)&&(i.flags|=32),i.memoizedState!==null&&(v=kd(r,i,GD,null,null,l),ml._currentValue=v),gc(r,i),Dt(r,i,c,l),i.child;case 6:return r===null&&De&&((r=l=et)&&(l=CR(l,i.pendingProps,bn),l!==null?(i.stateNode=l,Ct=i,et=null,r=!0):r=!1),r||Br(i)),null;case 13:return nS(r,i,l);case 4:return Ue(i,i.stateNode.containerInfo),c=i.pendingProps,r===null?i.child=ja(i,null,c,l):Dt(r,i,c,l),i.child;case 11:return Kx(r,i,i.type,i.pendingProps,l);case 7:return Dt(r,i,i.pendingProps,l),i.child;case 8:return Dt(r,i,i.pendingProps.children,l),i.child;case 12:return Dt(r,i,i.pendingProps.children,l),i.child;case 10:return c=i.pendingProps,Lr(i,i.type,c.value),Dt(r,i,c.children,l),i.child;case 9:return v=i.type._context,c=i.pendingProps.children,Aa(i),v=Nt(v),c=c(v),i.flags|=1,Dt(r,i,c,l),i.child;case 14:return Vx(r,i,i.type,i.pendingProps,l);case 15:return Zx(r,i,i.type,i.pendingProps,l);case 19:return aS(r,i,l);case 31:return WD(r,i,l);case 22:return Fx(r,i,l,i.pendingProps);case 24:return Aa(i),c=Nt(dt),r===null?(v=Nd(),v===null&&(v=Ze,p=Md(),v.pooledCache=p,p.refCount++,p!==null&&(v.pooledCacheLanes|=l),v=p),i.memoizedState={parent:c,cache:v},Rd(i),Lr(i,dt,v)):((r.lanes&l)!==0&&(Pd(r,i),Qu(i,null,null,l),Fu()),v=r.memoizedState,p=i.memoizedState,v.parent!==c?(v={parent:c,cache:c},i.memoizedState=v,i.lanes===0&&(i.memoizedState=i.updateQueue.baseState=v),Lr(i,dt,c)):(c=p.cache,Lr(i,dt,c),c!==v.cache&&jd(i,[dt],l,!0))),Dt(r,i,i.pendingProps.children,l),i.child;case 29:throw i.pendingProps}throw Error(a(156,i.tag))}function fr(r){r.flags|=4}function ph(r,i,l,c,v){if((i=(r.mode&32)!==0)&&(i=!1),i){if(r.flags|=16777216,(v&335544128)===v)if(r.stateNode.complete)r.flags|=8192;else if(DS())r.flags|=8192;else throw Ea=rc,Dd}else r.flags&=-16777217}function uS(r,i){if(i.type!=="stylesheet"||(i.state.loading&4)!==0)r.flags&=-16777217;else if(r.flags|=16777216,!S_(i))if(DS())r.flags|=8192;else throw Ea=rc,Dd}function xc(r,i){i!==null&&(r.flags|=4),r.flags&16384&&(i=r.tag!==22?Lb():536870912,r.lanes|=i,Ci|=i)}function rl(r,i){if(!De)switch(r.tailMode){case"hidden":i=r.tail;for(var l=null;i!==null;)i.alternate!==null&&(l=i),i=i.sibling;l===null?r.tail=null:l.sibling=null;break;case"collapsed":l=r.tail;for(var c=null;l!==null;)l.alternate!==null&&(c=l),l=l.sibling;c===null?i||r.tail===null?r.tail=null:r.tail.sibling=null:c.sibling=null}}function tt(r){var i=r.alternate!==null&&r.alternate.child===r.child,l=0,c=0;if(i)for(var v=r.child;v!==null;)l|=v.lanes|v.childLanes,c|=v.subtreeFlags&65011712,c|=v.flags&65011712,v.return=r,v=v.sibling;else for(v=r.child;v!==null;)l|=v.lanes|v.childLanes,c|=v.subtreeFlags,c|=v.flags,v.return=r,v=v.sibling;return r.subtreeFlags|=c,r.childLanes=l,i}function eR(r,i,l){var c=i.pendingProps;switch(Od(i),i.tag){case 16:case 15:case 0:case 11:case 7:case 8:case 12:case 9:case 14:return tt(i),null;case 1:return tt(i),null;case 3:return l=i.stateNode,c=null,r!==null&&(c=r.memoizedState.cache),i.memoizedState.cache!==c&&(i.flags|=2048),lr(dt),ce(),l.pendingContext&&(l.context=l.pendingContext,l.pendingContext=null),(r===null||r.child===null)&&(mi(i)?fr(i):r===null||r.memoizedState.isDehydrated&&(i.flags&256)===0||(i.flags|=1024,wd())),tt(i),null;case 26:var v=i.type,p=i.memoizedState;return r===null?(fr(i),p!==null?(tt(i),uS(i,p)):(tt(i),ph(i,v,null,c,l))):p?p!==r.memoizedState?(fr(i),tt(i),uS(i,p)):(tt(i),i.flags&=-16777217):(r=r.memoizedProps,r!==c&&fr(i),tt(i),ph(i,v,r,c,l)),null;case 27:if(Se(i),l=me.current,v=i.type,r!==null&&i.stateNode!=null)r.memoizedProps!==c&&fr(i);else{if(!c){if(i.stateNode===null)throw Error(a(166));return tt(i),null}r=se.current,mi(i)?L1(i):(r=h_(v,c,l),i.stateNode=r,fr(i))}return tt(i),null;case 5:if(Se(i),v=i.type,r!==null&&i.stateNode!=null)r.memoizedProps!==c&&fr(i);else{if(!c){if(i.stateNode===null)throw Error(a(166));return tt(i),null}if(p=se.current,mi(i))L1(i);else{var S=qc(me.current);switch(p){case 1:p=S.createElementNS("http://www.w3.org/2000/svg",v);break;case 2:p=S.createElementNS("http://www.w3.org/1998/Math/MathML",v);break;default:switch(v){case"svg":p=S.createElementNS("http://www.w3.org/2000/svg",v);break;case"math":p=S.createElementNS("http://www.w3.org/1998/Math/MathML",v);break;case"script":p=S.createElement("div"),p.innerHTML="<script><\/script>",p=p.removeChild(p.firstChild);break;case"select":p=typeof c.is=="string"?S.createElement("select",{is:c.is}):S.createElement("select"),c.multiple?p.multiple=!0:c.size&&(p.size=c.size);break;default:p=typeof c.is=="string"?S.createElement(v,{is:c.is}):S.createElement(v)}}p[Mt]=i,p[Yt]=c;e:for(S=i.child;S!==null;){if(S.tag===5||S.tag===6)p.appendChild(S.stateNode);else if(S.tag!==4&&S.tag!==27&&S.child!==null){S.child.return=S,S=S.child;continue}if(S===i)break e;for(;S.sibling===null;){if(S.return===null||S.return===i)break e;S=S.return}S.sibling.return=S.return,S=S.sibling}i.stateNode=p;e:switch(Rt(p,v,c),v){case"button":case"input":case"select":case"textarea":c=!!c.autoFocus;break e;case"img":c=!0;break e;default:c=!1}c&&fr(i)}}return tt(i),ph(i,i.type,r===null?null:r.memoizedProps,i.pendingProps,l),null;case 6:if(r&&i.stateNode!=null)r.memoizedProps!==c&&fr(i);else{if(typeof c!="string"&&i.stateNode===null)throw Error(a(166));if(r=me.current,mi(i)){if(r=i.stateNode,l=i.memoizedProps,c=null,v=Ct,v!==null)switch(v.tag){case 27:case 5:c=v.memoizedProps}r[Mt]=i,r=!!(r.nodeValue===l||c!==null&&c.suppressHydrationWarning===!0||n_(r.nodeValue,l)),r||Br(i,!0)}else r=qc(r).createTextNode(c),r[Mt]=i,i.stateNode=r}return tt(i),null;case 31:if(l=i.memoizedState,r===null||r.memoizedState!==null){if(c=mi(i),l!==null){if(r===null){if(!c)throw Error(a(318));if(r=i.memoizedState,r=r!==null?r.dehydrated:null,!r)throw Error(a(557));r[Mt]=i}else _a(),(i.flags&128)===0&&(i.memoizedState=null),i.flags|=4;tt(i),r=!1}else l=wd(),r!==null&&r.memoizedState!==null&&(r.memoizedState.hydrationErrors=l),r=!0;if(!r)return i.flags&256?(un(i),i):(un(i),null);if((i.flags&128)!==0)throw Error(a(558))}return tt(i),null;case 13:if(c=i.memoizedState,r===null||r.memoizedState!==null&&r.memoizedState.dehydrated!==null){if(v=mi(i),c!==null&&c.dehydrated!==null){if(r===null){if(!v)throw Error(a(318));if(v=i.memoizedState,v=v!==null?v.dehydrated:null,!v)throw Error(a(317));v[Mt]=i}else _a(),(i.flags&128)===0&&(i.memoizedState=null),i.flags|=4;tt(i),v=!1}else v=wd(),r!==null&&r.memoizedState!==null&&(r.memoizedState.hydrationErrors=v),v=!0;if(!v)return i.flags&256?(un(i),i):(un(i),null)}return un(i),(i.flags&128)!==0?(i.lanes=l,i):(l=c!==null,r=r!==null&&r.memoizedState!==null,l&&(c=i.child,v=null,c.alternate!==null&&c.alternate.memoizedState!==null&&c.alternate.memoizedState.cachePool!==null&&(v=c.alternate.memoizedState.cachePool.pool),p=null,c.memoizedState!==null&&c.memoizedState.cachePool!==null&&(p=c.memoizedState.cachePool.pool),p!==v&&(c.flags|=2048)),l!==r&&l&&(i.child.flags|=8192),xc(i,i.updateQueue),tt(i),null);case 4:return ce(),r===null&&$h(i.stateNode.containerInfo),tt(i),null;case 10:return lr(i.type),tt(i),null;case 19:if(U(st),c=i.memoizedState,c===null)return tt(i),null;if(v=(i.flags&128)!==0,p=c.rendering,p===null)if(v)rl(c,!1);else{if(ot!==0||r!==null&&(r.flags&128)!==0)for(r=i.child;r!==null;){if(p=lc(r),p!==null){for(i.flags|=128,rl(c,!1),r=p.updateQueue,i.updateQueue=r,xc(i,r),i.subtreeFlags=0,r=l,l=i.child;l!==null;)P1(l,r),l=l.sibling;return re(st,st.current&1|2),De&&ir(i,c.treeForkCount),i.child}r=r.sibling}c.tail!==null&&$t()>wc&&(i.flags|=128,v=!0,rl(c,!1),i.lanes=4194304)}else{if(!v)if(r=lc(p),r!==null){if(i.flags|=128,v=!0,r=r.updateQueue,i.updateQueue=r,xc(i,r),rl(c,!0),c.tail===null&&c.tailMode==="hidden"&&!p.alternate&&!De)return tt(i),null}else 2*$t()-c.renderingStartTime>wc&&l!==536870912&&(i.flags|=128,v=!0,rl(c,!1),i.lanes=4194304);c.isBackwards?(p.sibling=i.child,i.child=p):(r=c.last,r!==null?r.sibling=p:i.child=p,c.last=p)}return c.tail!==null?(r=c.tail,c.rendering=r,c.tail=r.sibling,c.renderingStartTime=$t(),r.sibling=null,l=st.current,re(st,v?l&1|2:l&1),De&&ir(i,c.treeForkCount),r):(tt(i),null);case 22:case 23:return un(i),Bd(),c=i.memoizedState!==null,r!==null?r.memoizedState!==null!==c&&(i.flags|=8192):c&&(i.flags|=8192),c?(l&536870912)!==0&&(i.flags&128)===0&&(tt(i),i.subtreeFlags&6&&(i.flags|=8192)):tt(i),l=i.updateQueue,l!==null&&xc(i,l.retryQueue),l=null,r!==null&&r.memoizedState!==null&&r.memoizedState.cachePool!==null&&(l=r.memoizedState.cachePool.pool),c=null,i.memoizedState!==null&&i.memoizedState.cachePool!==null&&(c=i.memoizedState.cachePool.pool),c!==l&&(i.flags|=2048),r!==null&&U(wa),null;case 24:return l=null,r!==null&&(l=r.memoizedState.cache),i.memoizedState.cache!==l&&(i.flags|=2048),lr(dt),tt(i),null;case 25:return null;case 30:return null}throw Error(a(156,i.tag))}function tR(r,i){switch(Od(i),i.tag){case 1:return r=i.flags,r&65536?(i.flags=r&-65537|128,i):null;case 3:return lr(dt),ce(),r=i.flags,(r&65536)!==0&&(r&128)===0?(i.flags=r&-65537|128,i):null;case 26:case 27:case 5:return Se(i),null;case 31:if(i.memoizedState!==null){if(un(i),i.alternate===null)throw Error(a(340));_a()}return r=i.flags,r&65536?(i.flags=r&-65537|128,i):null;case 13:if(un(i),r=i.memoizedState,r!==null&&r.dehydrated!==null){if(i.alternate===null)throw Error(a(340));_a()}return r=i.flags,r&65536?(i.flags=r&-65537|128,i):null;case 19:return U(st),null;case 4:return ce(),null;case 10:return lr(i.type),null;case 22:case 23:return un(i),Bd(),r!==null&&U(wa),r=i.flags,r&65536?(i.flags=r&-65537|128,i):null;case 24:return lr(dt),null;case 25:return null;default:return null}}function lS(r,i){switch(Od(i),i.tag){case 3:lr(dt),ce();break;case 26:case 27:case 5:Se(i);break;case 4:ce();break;case 31:i.memoizedState!==null&&un(i);break;case 13:un(i);break;case 19:U(st);break;case 10:lr(i.type);break;case 22:case 23:un(i),Bd(),r!==null&&U(wa);break;case 24:lr(dt)}}function al(r,i){try{var l=i.updateQueue,c=l!==null?l.lastEffect:null;if(c!==null){var v=c.next;l=v;do{if((l.tag&r)===r){c=void 0;var p=l.create,S=l.inst;c=p(),S.destroy=c}l=l.next}while(l!==v)}}catch(C){Ge(i,i.return,C)}}function Yr(r,i,l){try{var c=i.updateQueue,v=c!==null?c.lastEffect:null;if(v!==null){var p=v.next;c=p;do{if((c.tag&r)===r){var S=c.inst,C=S.destroy;if(C!==void 0){S.destroy=void 0,v=i;var z=l,X=C;try{X()}catch(te){Ge(v,z,te)}}}c=c.next}while(c!==p)}}catch(te){Ge(i,i.return,te)}}function oS(r){var i=r.updateQueue;if(i!==null){var l=r.stateNode;try{W1(i,l)}catch(c){Ge(r,r.return,c)}}}function cS(r,i,l){l.props=Ca(r.type,r.memoizedProps),l.state=r.memoizedState;try{l.componentWillUnmount()}catch(c){Ge(r,i,c)}}function il(r,i){try{var l=r.ref;if(l!==null){switch(r.tag){case 26:case 27:case 5:var c=r.stateNode;break;case 30:c=r.stateNode;break;default:c=r.stateNode}typeof l=="function"?r.refCleanup=l(c):l.current=c}}catch(v){Ge(r,i,v)}}function Gn(r,i){var l=r.ref,c=r.refCleanup;if(l!==null)if(typeof c=="function")try{c()}catch(v){Ge(r,i,v)}finally{r.refCleanup=null,r=r.alternate,r!=null&&(r.refCleanup=null)}else if(typeof l=="function")try{l(null)}catch(v){Ge(r,i,v)}else l.current=null}function sS(r){var i=r.type,l=r.memoizedProps,c=r.stateNode;try{e:switch(i){case"button":case"input":case"select":case"textarea":l.autoFocus&&c.focus();break e;case"img":l.src?c.src=l.src:l.srcSet&&(c.srcset=l.srcSet)}}catch(v){Ge(r,r.return,v)}}function yh(r,i,l){try{var c=r.stateNode;OR(c,r.type,l,i),c[Yt]=i}catch(v){Ge(r,r.return,v)}}function fS(r){return r.tag===5||r.tag===3||r.tag===26||r.tag===27&&Wr(r.type)||r.tag===4}function mh(r){e:for(;;){for(;r.sibling===null;){if(r.return===null||fS(r.return))return null;r=r.return}for(r.sibling.return=r.return,r=r.sibling;r.tag!==5&&r.tag!==6&&r.tag!==18;){if(r.tag===27&&Wr(r.type)||r.flags&2||r.child===null||r.tag===4)continue e;r.child.return=r,r=r.child}if(!(r.flags&2))return r.stateNode}}function gh(r,i,l){var c=r.tag;if(c===5||c===6)r=r.stateNode,i?(l.nodeType===9?l.body:l.nodeName==="HTML"?l.ownerDocument.body:l).insertBefore(r,i):(i=l.nodeType===9?l.body:l.nodeName==="HTML"?l.ownerDocument.body:l,i.appendChild(r),l=l._reactRootContainer,l!=null||i.onclick!==null||(i.onclick=nr));else if(c!==4&&(c===27&&Wr(r.type)&&(l=r.stateNode,i=null),r=r.child,r!==null))for(gh(r,i,l),r=r.sibling;r!==null;)gh(r,i,l),r=r.sibling}function Sc(r,i,l){var c=r.tag;if(c===5||c===6)r=r.stateNode,i?l.insertBefore(r,i):l.appendChild(r);else if(c!==4&&(c===27&&Wr(r.type)&&(l=r.stateNode),r=r.child,r!==null))for(Sc(r,i,l),r=r.sibling;r!==null;)Sc(r,i,l),r=r.sibling}function dS(r){var i=r.stateNode,l=r.memoizedProps;try{for(var c=r.type,v=i.attributes;v.length;)i.removeAttributeNode(v[0]);Rt(i,c,l),i[Mt]=r,i[Yt]=l}catch(p){Ge(r,r.return,p)}}var dr=!1,pt=!1,bh=!1,hS=typeof WeakSet=="function"?WeakSet:Set,wt=null;function nR(r,i){if(r=r.containerInfo,Uh=Hc,r=w1(r),dd(r)){if("selectionStart"in r)var l={start:r.selectionStart,end:r.selectionEnd};else e:{l=(l=r.ownerDocument)&&l.defaultView||window;var c=l.getSelection&&l.getSelection();if(c&&c.rangeCount!==0){l=c.anchorNode;var v=c.anchorOffset,p=c.focusNode;c=c.focusOffset;try{l.nodeType,p.nodeType}catch{l=null;break e}var S=0,C=-1,z=-1,X=0,te=0,ie=r,K=null;t:for(;;){for(var Z;ie!==l||v!==0&&ie.nodeType!==3||(C=S+v),ie!==p||c!==0&&ie.nodeType!==3||(z=S+c),ie.nodeType===3&&(S+=ie.nodeValue.length),(Z=ie.firstChild)!==null;)K=ie,ie=Z;for(;;){if(ie===r)break t;if(K===l&&++X===v&&(C=S),K===p&&++te===c&&(z=S),(Z=ie.nextSibling)!==null)break;ie=K,K=ie.parentNode}ie=Z}l=C===-1||z===-1?null:{start:C,end:z}}else l=null}l=l||{start:0,end:0}}else l=null;for(kh={focusedElem:r,selectionRange:l},Hc=!1,wt=i;wt!==null;)if(i=wt,r=i.child,(i.subtreeFlags&1028)!==0&&r!==null)r.return=i,wt=r;else for(;wt!==null;){switch(i=wt,p=i.alternate,r=i.flags,i.tag){case 0:if((r&4)!==0&&(r=i.updateQueue,r=r!==null?r.events:null,r!==null))for(l=0;l<r.length;l++)v=r[l],v.ref.impl=v.nextImpl;break;case 11:case 15:break;case 1:if((r&1024)!==0&&p!==null){r=void 0,l=i,v=p.memoizedProps,p=p.memoizedState,c=l.stateNode;try{var he=Ca(l.type,v);r=c.getSnapshotBeforeUpdate(he,p),c.__reactInternalSnapshotBeforeUpdate=r}catch(ge){Ge(l,l.return,ge)}}break;case 3:if((r&1024)!==0){if(r=i.stateNode.containerInfo,l=r.nodeType,l===9)Gh(r);else if(l===1)switch(r.nodeName){case"HEAD":case"HTML":case"BODY":Gh(r);break;default:r.textContent=""}}break;case 5:case 26:case 27:case 6:case 4:case 17:break;default:if((r&1024)!==0)throw Error(a(163))}if(r=i.sibling,r!==null){r.return=i.return,wt=r;break}wt=i.return}}function vS(r,i,l){var c=l.flags;switch(l.tag){case 0:case 11:case 15:vr(r,l),c&4&&al(5,l);break;case 1:if(vr(r,l),c&4)if(r=l.stateNode,i===null)try{r.componentDidMount()}catch(S){Ge(l,l.return,S)}else{var v=Ca(l.type,i.memoizedProps);i=i.memoizedState;try{r.componentDidUpdate(v,i,r.__reactInternalSnapshotBeforeUpdate)}catch(S){Ge(l,l.return,S)}}c&64&&oS(l),c&512&&il(l,l.return);break;case 3:if(vr(r,l),c&64&&(r=l.updateQueue,r!==null)){if(i=null,l.child!==null)switch(l.child.tag){case 27:case 5:i=l.child.stateNode;break;case 1:i=l.child.stateNode}try{W1(r,i)}catch(S){Ge(l,l.return,S)}}break;case 27:i===null&&c&4&&dS(l);case 26:case 5:vr(r,l),i===null&&c&4&&sS(l),c&512&&il(l,l.return);break;case 12:vr(r,l);break;case 31:vr(r,l),c&4&&mS(r,l);break;case 13:vr(r,l),c&4&&gS(r,l),c&64&&(r=l.memoizedState,r!==null&&(r=r.dehydrated,r!==null&&(l=fR.bind(null,l),NR(r,l))));break;case 22:if(c=l.memoizedState!==null||dr,!c){i=i!==null&&i.memoizedState!==null||pt,v=dr;var p=pt;dr=c,(pt=i)&&!p?pr(r,l,(l.subtreeFlags&8772)!==0):vr(r,l),dr=v,pt=p}break;case 30:break;default:vr(r,l)}}function pS(r){var i=r.alternate;i!==null&&(r.alternate=null,pS(i)),r.child=null,r.deletions=null,r.sibling=null,r.tag===5&&(i=r.stateNode,i!==null&&Zf(i)),r.stateNode=null,r.return=null,r.dependencies=null,r.memoizedProps=null,r.memoizedState=null,r.pendingProps=null,r.stateNode=null,r.updateQueue=null}var rt=null,Kt=!1;function hr(r,i,l){for(l=l.child;l!==null;)yS(r,i,l),l=l.sibling}function yS(r,i,l){if(en&&typeof en.onCommitFiberUnmount=="function")try{en.onCommitFiberUnmount(Mu,l)}catch{}switch(l.tag){case 26:pt||Gn(l,i),hr(r,i,l),l.memoizedState?l.memoizedState.count--:l.stateNode&&(l=l.stateNode,l.parentNode.removeChild(l));break;case 27:pt||Gn(l,i);var c=rt,v=Kt;Wr(l.type)&&(rt=l.stateNode,Kt=!1),hr(r,i,l),vl(l.stateNode),rt=c,Kt=v;break;case 5:pt||Gn(l,i);case 6:if(c=rt,v=Kt,rt=null,hr(r,i,l),rt=c,Kt=v,rt!==null)if(Kt)try{(rt.nodeType===9?rt.body:rt.nodeName==="HTML"?rt.ownerDocument.body:rt).removeChild(l.stateNode)}catch(p){Ge(l,i,p)}else try{rt.removeChild(l.stateNode)}catch(p){Ge(l,i,p)}break;case 18:rt!==null&&(Kt?(r=rt,o_(r.nodeType===9?r.body:r.nodeName==="HTML"?r.ownerDocument.body:r,l.stateNode),Bi(r)):o_(rt,l.stateNode));break;case 4:c=rt,v=Kt,rt=l.stateNode.containerInfo,Kt=!0,hr(r,i,l),rt=c,Kt=v;break;case 0:case 11:case 14:case 15:Yr(2,l,i),pt||Yr(4,l,i),hr(r,i,l);break;case 1:pt||(Gn(l,i),c=l.stateNode,typeof c.componentWillUnmount=="function"&&cS(l,i,c)),hr(r,i,l);break;case 21:hr(r,i,l);break;case 22:pt=(c=pt)||l.memoizedState!==null,hr(r,i,l),pt=c;break;default:hr(r,i,l)}}function mS(r,i){if(i.memoizedState===null&&(r=i.alternate,r!==null&&(r=r.memoizedState,r!==null))){r=r.dehydrated;try{Bi(r)}catch(l){Ge(i,i.return,l)}}}function gS(r,i){if(i.memoizedState===null&&(r=i.alternate,r!==null&&(r=r.memoizedState,r!==null&&(r=r.dehydrated,r!==null))))try{Bi(r)}catch(l){Ge(i,i.return,l)}}function rR(r){switch(r.tag){case 31:case 13:case 19:var i=r.stateNode;return i===null&&(i=r.stateNode=new hS),i;case 22:return r=r.stateNode,i=r._retryCache,i===null&&(i=r._retryCache=new hS),i;default:throw Error(a(435,r.tag))}}function _c(r,i){var l=rR(r);i.forEach(function(c){if(!l.has(c)){l.add(c);var v=dR.bind(null,r,c);c.then(v,v)}})}function Vt(r,i){var l=i.deletions;if(l!==null)for(var c=0;c<l.length;c++){var v=l[c],p=r,S=i,C=S;e:for(;C!==null;){switch(C.tag){case 27:if(Wr(C.type)){rt=C.stateNode,Kt=!1;break e}break;case 5:rt=C.stateNode,Kt=!1;break e;case 3:case 4:rt=C.stateNode.containerInfo,Kt=!0;break e}C=C.return}if(rt===null)throw Error(a(160));yS(p,S,v),rt=null,Kt=!1,p=v.alternate,p!==null&&(p.return=null),v.return=null}if(i.subtreeFlags&13886)for(i=i.child;i!==null;)bS(i,r),i=i.sibling}var Rn=null;function bS(r,i){var l=r.alternate,c=r.flags;switch(r.tag){case 0:case 11:case 14:case 15:Vt(i,r),Zt(r),c&4&&(Yr(3,r,r.return),al(3,r),Yr(5,r,r.return));break;case 1:Vt(i,r),Zt(r),c&512&&(pt||l===null||Gn(l,l.return)),c&64&&dr&&(r=r.updateQueue,r!==null&&(c=r.callbacks,c!==null&&(l=r.shared.hiddenCallbacks,r.shared.hiddenCallbacks=l===null?c:l.concat(c))));break;case 26:var v=Rn;if(Vt(i,r),Zt(r),c&512&&(pt||l===null||Gn(l,l.return)),c&4){var p=l!==null?l.memoizedState:null;if(c=r.memoizedState,l===null)if(c===null)if(r.stateNode===null){e:{c=r.type,l=r.memoizedProps,v=v.ownerDocument||v;t:switch(c){case"title":p=v.getElementsByTagName("title")[0],(!p||p[Du]||p[Mt]||p.namespaceURI==="http://www.w3.org/2000/svg"||p.hasAttribute("itemprop"))&&(p=v.createElement(c),v.head.insertBefore(p,v.querySelector("head > title"))),Rt(p,c,l),p[Mt]=r,At(p),c=p;break e;case"link":var S=b_("link","href",v).get(c+(l.href||""));if(S){for(var C=0;C<S.length;C++)if(p=S[C],p.getAttribute("href")===(l.href==null||l.href===""?null:l.href)&&p.getAttribute("rel")===(l.rel==null?null:l.rel)&&p.getAttribute("title")===(l.title==null?null:l.title)&&p.getAttribute("crossorigin")===(l.crossOrigin==null?null:l.crossOrigin)){S.splice(C,1);break t}}p=v.createElement(c),Rt(p,c,l),v.head.appendChild(p);break;case"meta":if(S=b_("meta","content",v).get(c+(l.content||""))){for(C=0;C<S.length;C++)if(p=S[C],p.getAttribute("content")===(l.content==null?null:""+l.content)&&p.getAttribute("name")===(l.name==null?null:l.name)&&p.getAttribute("property")===(l.property==null?null:l.property)&&p.getAttribute("http-equiv")===(l.httpEquiv==null?null:l.httpEquiv)&&p.getAttribute("charset")===(l.charSet==null?null:l.charSet)){S.splice(C,1);break t}}p=v.createElement(c),Rt(p,c,l),v.head.appendChild(p);break;default:throw Error(a(468,c))}p[Mt]=r,At(p),c=p}r.stateNode=c}else x_(v,r.type,r.stateNode);else r.stateNode=g_(v,c,r.memoizedProps);else p!==c?(p===null?l.stateNode!==null&&(l=l.stateNode,l.parentNode.removeChild(l)):p.count--,c===null?x_(v,r.type,r.stateNode):g_(v,c,r.memoizedProps)):c===null&&r.stateNode!==null&&yh(r,r.memoizedProps,l.memoizedProps)}break;case 27:Vt(i,r),Zt(r),c&512&&(pt||l===null||Gn(l,l.return)),l!==null&&c&4&&yh(r,r.memoizedProps,l.memoizedProps);break;case 5:if(Vt(i,r),Zt(r),c&512&&(pt||l===null||Gn(l,l.return)),r.flags&32){v=r.stateNode;try{li(v,"")}catch(he){Ge(r,r.return,he)}}c&4&&r.stateNode!=null&&(v=r.memoizedProps,yh(r,v,l!==null?l.memoizedProps:v)),c&1024&&(bh=!0);break;case 6:if(Vt(i,r),Zt(r),c&4){if(r.stateNode===null)throw Error(a(162));c=r.memoizedProps,l=r.stateNode;try{l.nodeValue=c}catch(he){Ge(r,r.return,he)}}break;case 3:if(Bc=null,v=Rn,Rn=zc(i.containerInfo),Vt(i,r),Rn=v,Zt(r),c&4&&l!==null&&l.memoizedState.isDehydrated)try{Bi(i.containerInfo)}catch(he){Ge(r,r.return,he)}bh&&(bh=!1,xS(r));break;case 4:c=Rn,Rn=zc(r.stateNode.containerInfo),Vt(i,r),Zt(r),Rn=c;break;case 12:Vt(i,r),Zt(r);break;case 31:Vt(i,r),Zt(r),c&4&&(c=r.updateQueue,c!==null&&(r.updateQueue=null,_c(r,c)));break;case 13:Vt(i,r),Zt(r),r.child.flags&8192&&r.memoizedState!==null!=(l!==null&&l.memoizedState!==null)&&(Ac=$t()),c&4&&(c=r.updateQueue,c!==null&&(r.updateQueue=null,_c(r,c)));break;case 22:v=r.memoizedState!==null;var z=l!==null&&l.memoizedState!==null,X=dr,te=pt;if(dr=X||v,pt=te||z,Vt(i,r),pt=te,dr=X,Zt(r),c&8192)e:for(i=r.stateNode,i._visibility=v?i._visibility&-2:i._visibility|1,v&&(l===null||z||dr||pt||Na(r)),l=null,i=r;;){if(i.tag===5||i.tag===26){if(l===null){z=l=i;try{if(p=z.stateNode,v)S=p.style,typeof S.setProperty=="function"?S.setProperty("display","none","important"):S.display="none";else{C=z.stateNode;var ie=z.memoizedProps.style,K=ie!=null&&ie.hasOwnProperty("display")?ie.display:null;C.style.display=K==null||typeof K=="boolean"?"":(""+K).trim()}}catch(he){Ge(z,z.return,he)}}}else if(i.tag===6){if(l===null){z=i;try{z.stateNode.nodeValue=v?"":z.memoizedProps}catch(he){Ge(z,z.return,he)}}}else if(i.tag===18){if(l===null){z=i;try{var Z=z.stateNode;v?c_(Z,!0):c_(z.stateNode,!1)}catch(he){Ge(z,z.return,he)}}}else if((i.tag!==22&&i.tag!==23||i.memoizedState===null||i===r)&&i.child!==null){i.child.return=i,i=i.child;continue}if(i===r)break e;for(;i.sibling===null;){if(i.return===null||i.return===r)break e;l===i&&(l=null),i=i.return}l===i&&(l=null),i.sibling.return=i.return,i=i.sibling}c&4&&(c=r.updateQueue,c!==null&&(l=c.retryQueue,l!==null&&(c.retryQueue=null,_c(r,l))));break;case 19:Vt(i,r),Zt(r),c&4&&(c=r.updateQueue,c!==null&&(r.updateQueue=null,_c(r,c)));break;case 30:break;case 21:break;default:Vt(i,r),Zt(r)}}function Zt(r){var i=r.flags;if(i&2){try{for(var l,c=r.return;c!==null;){if(fS(c)){l=c;break}c=c.return}if(l==null)throw Error(a(160));switch(l.tag){case 27:var v=l.stateNode,p=mh(r);Sc(r,p,v);break;case 5:var S=l.stateNode;l.flags&32&&(li(S,""),l.flags&=-33);var C=mh(r);Sc(r,C,S);break;case 3:case 4:var z=l.stateNode.containerInfo,X=mh(r);gh(r,X,z);break;default:throw Error(a(161))}}catch(te){Ge(r,r.return,te)}r.flags&=-3}i&4096&&(r.flags&=-4097)}function xS(r){if(r.subtreeFlags&1024)for(r=r.child;r!==null;){var i=r;xS(i),i.tag===5&&i.flags&1024&&i.stateNode.reset(),r=r.sibling}}function vr(r,i){if(i.subtreeFlags&8772)for(i=i.child;i!==null;)vS(r,i.alternate,i),i=i.sibling}function Na(r){for(r=r.child;r!==null;){var i=r;switch(i.tag){case 0:case 11:case 14:case 15:Yr(4,i,i.return),Na(i);break;case 1:Gn(i,i.return);var l=i.stateNode;typeof l.componentWillUnmount=="function"&&cS(i,i.return,l),Na(i);break;case 27:vl(i.stateNode);case 26:case 5:Gn(i,i.return),Na(i);break;case 22:i.memoizedState===null&&Na(i);break;case 30:Na(i);break;default:Na(i)}r=r.sibling}}function pr(r,i,l){for(l=l&&(i.subtreeFlags&8772)!==0,i=i.child;i!==null;){var c=i.alternate,v=r,p=i,S=p.flags;switch(p.tag){case 0:case 11:case 15:pr(v,p,l),al(4,p);break;case 1:if(pr(v,p,l),c=p,v=c.stateNode,typeof v.componentDidMount=="function")try{v.componentDidMount()}catch(X){Ge(c,c.return,X)}if(c=p,v=c.updateQueue,v!==null){var C=c.stateNode;try{var z=v.shared.hiddenCallbacks;if(z!==null)for(v.shared.hiddenCallbacks=null,v=0;v<z.length;v++)Q1(z[v],C)}catch(X){Ge(c,c.return,X)}}l&&S&64&&oS(p),il(p,p.return);break;case 27:dS(p);case 26:case 5:pr(v,p,l),l&&c===null&&S&4&&sS(p),il(p,p.return);break;case 12:pr(v,p,l);break;case 31:pr(v,p,l),l&&S&4&&mS(v,p);break;case 13:pr(v,p,l),l&&S&4&&gS(v,p);break;case 22:p.memoizedState===null&&pr(v,p,l),il(p,p.return);break;case 30:break;default:pr(v,p,l)}i=i.sibling}}function xh(r,i){var l=null;r!==null&&r.memoizedState!==null&&r.memoizedState.cachePool!==null&&(l=r.memoizedState.cachePool.pool),r=null,i.memoizedState!==null&&i.memoizedState.cachePool!==null&&(r=i.memoizedState.cachePool.pool),r!==l&&(r!=null&&r.refCount++,l!=null&&Yu(l))}function Sh(r,i){r=null,i.alternate!==null&&(r=i.alternate.memoizedState.cache),i=i.memoizedState.cache,i!==r&&(i.refCount++,r!=null&&Yu(r))}function Pn(r,i,l,c){if(i.subtreeFlags&10256)for(i=i.child;i!==null;)SS(r,i,l,c),i=i.sibling}function SS(r,i,l,c){var v=i.flags;switch(i.tag){case 0:case 11:case 15:Pn(r,i,l,c),v&2048&&al(9,i);break;case 1:Pn(r,i,l,c);break;case 3:Pn(r,i,l,c),v&2048&&(r=null,i.alternate!==null&&(r=i.alternate.memoizedState.cache),i=i.memoizedState.cache,i!==r&&(i.refCount++,r!=null&&Yu(r)));break;case 12:if(v&2048){Pn(r,i,l,c),r=i.stateNode;try{var p=i.memoizedProps,S=p.id,C=p.onPostCommit;typeof C=="function"&&C(S,i.alternate===null?"mount":"update",r.passiveEffectDuration,-0)}catch(z){Ge(i,i.return,z)}}else Pn(r,i,l,c);break;case 31:Pn(r,i,l,c);break;case 13:Pn(r,i,l,c);break;case 23:break;case 22:p=i.stateNode,S=i.alternate,i.memoizedState!==null?p._visibility&2?Pn(r,i,l,c):ul(r,i):p._visibility&2?Pn(r,i,l,c):(p._visibility|=2,Ei(r,i,l,c,(i.subtreeFlags&10256)!==0||!1)),v&2048&&xh(S,i);break;case 24:Pn(r,i,l,c),v&2048&&Sh(i.alternate,i);break;default:Pn(r,i,l,c)}}function Ei(r,i,l,c,v){for(v=v&&((i.subtreeFlags&10256)!==0||!1),i=i.child;i!==null;){var p=r,S=i,C=l,z=c,X=S.flags;switch(S.tag){case 0:case 11:case 15:Ei(p,S,C,z,v),al(8,S);break;case 23:break;case 22:var te=S.stateNode;S.memoizedState!==null?te._visibility&2?Ei(p,S,C,z,v):ul(p,S):(te._visibility|=2,Ei(p,S,C,z,v)),v&&X&2048&&xh(S.alternate,S);break;case 24:Ei(p,S,C,z,v),v&&X&2048&&Sh(S.alternate,S);break;default:Ei(p,S,C,z,v)}i=i.sibling}}function ul(r,i){if(i.subtreeFlags&10256)for(i=i.child;i!==null;){var l=r,c=i,v=c.flags;switch(c.tag){case 22:ul(l,c),v&2048&&xh(c.alternate,c);break;case 24:ul(l,c),v&2048&&Sh(c.alternate,c);break;default:ul(l,c)}i=i.sibling}}var ll=8192;function ji(r,i,l){if(r.subtreeFlags&ll)for(r=r.child;r!==null;)_S(r,i,l),r=r.sibling}function _S(r,i,l){switch(r.tag){case 26:ji(r,i,l),r.flags&ll&&r.memoizedState!==null&&IR(l,Rn,r.memoizedState,r.memoizedProps);break;case 5:ji(r,i,l);break;case 3:case 4:var c=Rn;Rn=zc(r.stateNode.containerInfo),ji(r,i,l),Rn=c;break;case 22:r.memoizedState===null&&(c=r.alternate,c!==null&&c.memoizedState!==null?(c=ll,ll=16777216,ji(r,i,l),ll=c):ji(r,i,l));break;default:ji(r,i,l)}}function OS(r){var i=r.alternate;if(i!==null&&(r=i.child,r!==null)){i.child=null;do i=r.sibling,r.sibling=null,r=i;while(r!==null)}}function ol(r){var i=r.deletions;if((r.flags&16)!==0){if(i!==null)for(var l=0;l<i.length;l++){var c=i[l];wt=c,wS(c,r)}OS(r)}if(r.subtreeFlags&10256)for(r=r.child;r!==null;)AS(r),r=r.sibling}function AS(r){switch(r.tag){case 0:case 11:case 15:ol(r),r.flags&2048&&Yr(9,r,r.return);break;case 3:ol(r);break;case 12:ol(r);break;case 22:var i=r.stateNode;r.memoizedState!==null&&i._visibility&2&&(r.return===null||r.return.tag!==13)?(i._visibility&=-3,Oc(r)):ol(r);break;default:ol(r)}}function Oc(r){var i=r.deletions;if((r.flags&16)!==0){if(i!==null)for(var l=0;l<i.length;l++){var c=i[l];wt=c,wS(c,r)}OS(r)}for(r=r.child;r!==null;){switch(i=r,i.tag){case 0:case 11:case 15:Yr(8,i,i.return),Oc(i);break;case 22:l=i.stateNode,l._visibility&2&&(l._visibility&=-3,Oc(i));break;default:Oc(i)}r=r.sibling}}function wS(r,i){for(;wt!==null;){var l=wt;switch(l.tag){case 0:case 11:case 15:Yr(8,l,i);break;case 23:case 22:if(l.memoizedState!==null&&l.memoizedState.cachePool!==null){var c=l.memoizedState.cachePool.pool;c!=null&&c.refCount++}break;case 24:Yu(l.memoizedState.cache)}if(c=l.child,c!==null)c.return=l,wt=c;else e:for(l=r;wt!==null;){c=wt;var v=c.sibling,p=c.return;if(pS(c),c===l){wt=null;break e}if(v!==null){v.return=p,wt=v;break e}wt=p}}}var aR={getCacheForType:function(r){var i=Nt(dt),l=i.data.get(r);return l===void 0&&(l=r(),i.data.set(r,l)),l},cacheSignal:function(){return Nt(dt).controller.signal}},iR=typeof WeakMap=="function"?WeakMap:Map,ze=0,Ze=null,je=null,Ce=0,Ie=0,ln=null,Xr=!1,Mi=!1,_h=!1,yr=0,ot=0,Kr=0,Da=0,Oh=0,on=0,Ci=0,cl=null,Ft=null,Ah=!1,Ac=0,TS=0,wc=1/0,Tc=null,Vr=null,gt=0,Zr=null,Ni=null,mr=0,wh=0,Th=null,ES=null,sl=0,Eh=null;function cn(){return(ze&2)!==0&&Ce!==0?Ce&-Ce:q.T!==null?Rh():Ib()}function jS(){if(on===0)if((Ce&536870912)===0||De){var r=Po;Po<<=1,(Po&3932160)===0&&(Po=262144),on=r}else on=536870912;return r=an.current,r!==null&&(r.flags|=32),on}function Qt(r,i,l){(r===Ze&&(Ie===2||Ie===9)||r.cancelPendingCommit!==null)&&(Di(r,0),Fr(r,Ce,on,!1)),Nu(r,l),((ze&2)===0||r!==Ze)&&(r===Ze&&((ze&2)===0&&(Da|=l),ot===4&&Fr(r,Ce,on,!1)),Yn(r))}function MS(r,i,l){if((ze&6)!==0)throw Error(a(327));var c=!l&&(i&127)===0&&(i&r.expiredLanes)===0||Cu(r,i),v=c?oR(r,i):Mh(r,i,!0),p=c;do{if(v===0){Mi&&!c&&Fr(r,i,0,!1);break}else{if(l=r.current.alternate,p&&!uR(l)){v=Mh(r,i,!1),p=!1;continue}if(v===2){if(p=i,r.errorRecoveryDisabledLanes&p)var S=0;else S=r.pendingLanes&-536870913,S=S!==0?S:S&536870912?536870912:0;if(S!==0){i=S;e:{var C=r;v=cl;var z=C.current.memoizedState.isDehydrated;if(z&&(Di(C,S).flags|=256),S=Mh(C,S,!1),S!==2){if(_h&&!z){C.errorRecoveryDisabledLanes|=p,Da|=p,v=4;break e}p=Ft,Ft=v,p!==null&&(Ft===null?Ft=p:Ft.push.apply(Ft,p))}v=S}if(p=!1,v!==2)continue}}if(v===1){Di(r,0),Fr(r,i,0,!0);break}e:{switch(c=r,p=v,p){case 0:case 1:throw Error(a(345));case 4:if((i&4194048)!==i)break;case 6:Fr(c,i,on,!Xr);break e;case 2:Ft=null;break;case 3:case 5:break;default:throw Error(a(329))}if((i&62914560)===i&&(v=Ac+300-$t(),10<v)){if(Fr(c,i,on,!Xr),zo(c,0,!0)!==0)break e;mr=i,c.timeoutHandle=u_(CS.bind(null,c,l,Ft,Tc,Ah,i,on,Da,Ci,Xr,p,"Throttled",-0,0),v);break e}CS(c,l,Ft,Tc,Ah,i,on,Da,Ci,Xr,p,null,-0,0)}}break}while(!0);Yn(r)}function CS(r,i,l,c,v,p,S,C,z,X,te,ie,K,Z){if(r.timeoutHandle=-1,ie=i.subtreeFlags,ie&8192||(ie&16785408)===16785408){ie={stylesheets:null,count:0,imgCount:0,imgBytes:0,suspenseyImages:[],waitingForImages:!0,waitingForViewTransition:!1,unsuspend:nr},_S(i,p,ie);var he=(p&62914560)===p?Ac-$t():(p&4194048)===p?TS-$t():0;if(he=GR(ie,he),he!==null){mr=p,r.cancelPendingCommit=he(BS.bind(null,r,i,p,l,c,v,S,C,z,te,ie,null,K,Z)),Fr(r,p,S,!X);return}}BS(r,i,p,l,c,v,S,C,z)}function uR(r){for(var i=r;;){var l=i.tag;if((l===0||l===11||l===15)&&i.flags&16384&&(l=i.updateQueue,l!==null&&(l=l.stores,l!==null)))for(var c=0;c<l.length;c++){var v=l[c],p=v.getSnapshot;v=v.value;try{if(!nn(p(),v))return!1}catch{return!1}}if(l=i.child,i.subtreeFlags&16384&&l!==null)l.return=i,i=l;else{if(i===r)break;for(;i.sibling===null;){if(i.return===null||i.return===r)return!0;i=i.return}i.sibling.return=i.return,i=i.sibling}}return!0}function Fr(r,i,l,c){i&=~Oh,i&=~Da,r.suspendedLanes|=i,r.pingedLanes&=~i,c&&(r.warmLanes|=i),c=r.expirationTimes;for(var v=i;0<v;){var p=31-tn(v),S=1<<p;c[p]=-1,v&=~S}l!==0&&Ub(r,l,i)}function Ec(){return(ze&6)===0?(fl(0),!1):!0}function jh(){if(je!==null){if(Ie===0)var r=je.return;else r=je,ur=Oa=null,Gd(r),_i=null,Ku=0,r=je;for(;r!==null;)lS(r.alternate,r),r=r.return;je=null}}function Di(r,i){var l=r.timeoutHandle;l!==-1&&(r.timeoutHandle=-1,TR(l)),l=r.cancelPendingCommit,l!==null&&(r.cancelPendingCommit=null,l()),mr=0,jh(),Ze=r,je=l=ar(r.current,null),Ce=i,Ie=0,ln=null,Xr=!1,Mi=Cu(r,i),_h=!1,Ci=on=Oh=Da=Kr=ot=0,Ft=cl=null,Ah=!1,(i&8)!==0&&(i|=i&32);var c=r.entangledLanes;if(c!==0)for(r=r.entanglements,c&=i;0<c;){var v=31-tn(c),p=1<<v;i|=r[v],c&=~p}return yr=i,Vo(),l}function NS(r,i){Oe=null,q.H=tl,i===Si||i===nc?(i=K1(),Ie=3):i===Dd?(i=K1(),Ie=4):Ie=i===uh?8:i!==null&&typeof i=="object"&&typeof i.then=="function"?6:1,ln=i,je===null&&(ot=1,yc(r,yn(i,r.current)))}function DS(){var r=an.current;return r===null?!0:(Ce&4194048)===Ce?xn===null:(Ce&62914560)===Ce||(Ce&536870912)!==0?r===xn:!1}function RS(){var r=q.H;return q.H=tl,r===null?tl:r}function PS(){var r=q.A;return q.A=aR,r}function jc(){ot=4,Xr||(Ce&4194048)!==Ce&&an.current!==null||(Mi=!0),(Kr&134217727)===0&&(Da&134217727)===0||Ze===null||Fr(Ze,Ce,on,!1)}function Mh(r,i,l){var c=ze;ze|=2;var v=RS(),p=PS();(Ze!==r||Ce!==i)&&(Tc=null,Di(r,i)),i=!1;var S=ot;e:do try{if(Ie!==0&&je!==null){var C=je,z=ln;switch(Ie){case 8:jh(),S=6;break e;case 3:case 2:case 9:case 6:an.current===null&&(i=!0);var X=Ie;if(Ie=0,ln=null,Ri(r,C,z,X),l&&Mi){S=0;break e}break;default:X=Ie,Ie=0,ln=null,Ri(r,C,z,X)}}lR(),S=ot;break}catch(te){NS(r,te)}while(!0);return i&&r.shellSuspendCounter++,ur=Oa=null,ze=c,q.H=v,q.A=p,je===null&&(Ze=null,Ce=0,Vo()),S}function lR(){for(;je!==null;)qS(je)}function oR(r,i){var l=ze;ze|=2;var c=RS(),v=PS();Ze!==r||Ce!==i?(Tc=null,wc=$t()+500,Di(r,i)):Mi=Cu(r,i);e:do try{if(Ie!==0&&je!==null){i=je;var p=ln;t:switch(Ie){case 1:Ie=0,ln=null,Ri(r,i,p,1);break;case 2:case 9:if(Y1(p)){Ie=0,ln=null,zS(i);break}i=function(){Ie!==2&&Ie!==9||Ze!==r||(Ie=7),Yn(r)},p.then(i,i);break e;case 3:Ie=7;break e;case 4:Ie=5;break e;case 7:Y1(p)?(Ie=0,ln=null,zS(i)):(Ie=0,ln=null,Ri(r,i,p,7));break;case 5:var S=null;switch(je.tag){case 26:S=je.memoizedState;case 5:case 27:var C=je;if(S?S_(S):C.stateNode.complete){Ie=0,ln=null;var z=C.sibling;if(z!==null)je=z;else{var X=C.return;X!==null?(je=X,Mc(X)):je=null}break t}}Ie=0,ln=null,Ri(r,i,p,5);break;case 6:Ie=0,ln=null,Ri(r,i,p,6);break;case 8:jh(),ot=6;break e;default:throw Error(a(462))}}cR();break}catch(te){NS(r,te)}while(!0);return ur=Oa=null,q.H=c,q.A=v,ze=l,je!==null?0:(Ze=null,Ce=0,Vo(),ot)}function cR(){for(;je!==null&&!er();)qS(je)}function qS(r){var i=iS(r.alternate,r,yr);r.memoizedProps=r.pendingProps,i===null?Mc(r):je=i}function zS(r){var i=r,l=i.alternate;switch(i.tag){case 15:case 0:i=Jx(l,i,i.pendingProps,i.type,void 0,Ce);break;case 11:i=Jx(l,i,i.pendingProps,i.type.render,i.ref,Ce);break;case 5:Gd(i);default:lS(l,i),i=je=P1(i,yr),i=iS(l,i,yr)}r.memoizedProps=r.pendingProps,i===null?Mc(r):je=i}function Ri(r,i,l,c){ur=Oa=null,Gd(i),_i=null,Ku=0;var v=i.return;try{if(QD(r,v,i,l,Ce)){ot=1,yc(r,yn(l,r.current)),je=null;return}}catch(p){if(v!==null)throw je=v,p;ot=1,yc(r,yn(l,r.current)),je=null;return}i.flags&32768?(De||c===1?r=!0:Mi||(Ce&536870912)!==0?r=!1:(Xr=r=!0,(c===2||c===9||c===3||c===6)&&(c=an.current,c!==null&&c.tag===13&&(c.flags|=16384))),$S(i,r)):Mc(i)}function Mc(r){var i=r;do{if((i.flags&32768)!==0){$S(i,Xr);return}r=i.return;var l=eR(i.alternate,i,yr);if(l!==null){je=l;return}if(i=i.sibling,i!==null){je=i;return}je=i=r}while(i!==null);ot===0&&(ot=5)}function $S(r,i){do{var l=tR(r.alternate,r);if(l!==null){l.flags&=32767,je=l;return}if(l=r.return,l!==null&&(l.flags|=32768,l.subtreeFlags=0,l.deletions=null),!i&&(r=r.sibling,r!==null)){je=r;return}je=r=l}while(r!==null);ot=6,je=null}function BS(r,i,l,c,v,p,S,C,z){r.cancelPendingCommit=null;do Cc();while(gt!==0);if((ze&6)!==0)throw Error(a(327));if(i!==null){if(i===r.current)throw Error(a(177));if(p=i.lanes|i.childLanes,p|=md,HN(r,l,p,S,C,z),r===Ze&&(je=Ze=null,Ce=0),Ni=i,Zr=r,mr=l,wh=p,Th=v,ES=c,(i.subtreeFlags&10256)!==0||(i.flags&10256)!==0?(r.callbackNode=null,r.callbackPriority=0,hR(Do,function(){return IS(),null})):(r.callbackNode=null,r.callbackPriority=0),c=(i.flags&13878)!==0,(i.subtreeFlags&13878)!==0||c){c=q.T,q.T=null,v=I.p,I.p=2,S=ze,ze|=4;try{nR(r,i,l)}finally{ze=S,I.p=v,q.T=c}}gt=1,LS(),US(),kS()}}function LS(){if(gt===1){gt=0;var r=Zr,i=Ni,l=(i.flags&13878)!==0;if((i.subtreeFlags&13878)!==0||l){l=q.T,q.T=null;var c=I.p;I.p=2;var v=ze;ze|=4;try{bS(i,r);var p=kh,S=w1(r.containerInfo),C=p.focusedElem,z=p.selectionRange;if(S!==C&&C&&C.ownerDocument&&A1(C.ownerDocument.documentElement,C)){if(z!==null&&dd(C)){var X=z.start,te=z.end;if(te===void 0&&(te=X),"selectionStart"in C)C.selectionStart=X,C.selectionEnd=Math.min(te,C.value.length);else{var ie=C.ownerDocument||document,K=ie&&ie.defaultView||window;if(K.getSelection){var Z=K.getSelection(),he=C.textContent.length,ge=Math.min(z.start,he),Ke=z.end===void 0?ge:Math.min(z.end,he);!Z.extend&&ge>Ke&&(S=Ke,Ke=ge,ge=S);var k=O1(C,ge),$=O1(C,Ke);if(k&&$&&(Z.rangeCount!==1||Z.anchorNode!==k.node||Z.anchorOffset!==k.offset||Z.focusNode!==$.node||Z.focusOffset!==$.offset)){var Y=ie.createRange();Y.setStart(k.node,k.offset),Z.removeAllRanges(),ge>Ke?(Z.addRange(Y),Z.extend($.node,$.offset)):(Y.setEnd($.node,$.offset),Z.addRange(Y))}}}}for(ie=[],Z=C;Z=Z.parentNode;)Z.nodeType===1&&ie.push({element:Z,left:Z.scrollLeft,top:Z.scrollTop});for(typeof C.focus=="function"&&C.focus(),C=0;C<ie.length;C++){var ae=ie[C];ae.element.scrollLeft=ae.left,ae.element.scrollTop=ae.top}}Hc=!!Uh,kh=Uh=null}finally{ze=v,I.p=c,q.T=l}}r.current=i,gt=2}}function US(){if(gt===2){gt=0;var r=Zr,i=Ni,l=(i.flags&8772)!==0;if((i.subtreeFlags&8772)!==0||l){l=q.T,q.T=null;var c=I.p;I.p=2;var v=ze;ze|=4;try{vS(r,i.alternate,i)}finally{ze=v,I.p=c,q.T=l}}gt=3}}function kS(){if(gt===4||gt===3){gt=0,ju();var r=Zr,i=Ni,l=mr,c=ES;(i.subtreeFlags&10256)!==0||(i.flags&10256)!==0?gt=5:(gt=0,Ni=Zr=null,HS(r,r.pendingLanes));var v=r.pendingLanes;if(v===0&&(Vr=null),Kf(l),i=i.stateNode,en&&typeof en.onCommitFiberRoot=="function")try{en.onCommitFiberRoot(Mu,i,void 0,(i.current.flags&128)===128)}catch{}if(c!==null){i=q.T,v=I.p,I.p=2,q.T=null;try{for(var p=r.onRecoverableError,S=0;S<c.length;S++){var C=c[S];p(C.value,{componentStack:C.stack})}}finally{q.T=i,I.p=v}}(mr&3)!==0&&Cc(),Yn(r),v=r.pendingLanes,(l&261930)!==0&&(v&42)!==0?r===Eh?sl++:(sl=0,Eh=r):sl=0,fl(0)}}function HS(r,i){(r.pooledCacheLanes&=i)===0&&(i=r.pooledCache,i!=null&&(r.pooledCache=null,Yu(i)))}function Cc(){return LS(),US(),kS(),IS()}function IS(){if(gt!==5)return!1;var r=Zr,i=wh;wh=0;var l=Kf(mr),c=q.T,v=I.p;try{I.p=32>l?32:l,q.T=null,l=Th,Th=null;var p=Zr,S=mr;if(gt=0,Ni=Zr=null,mr=0,(ze&6)!==0)throw Error(a(331));var C=ze;if(ze|=4,AS(p.current),SS(p,p.current,S,l),ze=C,fl(0,!1),en&&typeof en.onPostCommitFiberRoot=="function")try{en.onPostCommitFiberRoot(Mu,p)}catch{}return!0}finally{I.p=v,q.T=c,HS(r,i)}}function GS(r,i,l){i=yn(l,i),i=ih(r.stateNode,i,2),r=Hr(r,i,2),r!==null&&(Nu(r,2),Yn(r))}function Ge(r,i,l){if(r.tag===3)GS(r,r,l);else for(;i!==null;){if(i.tag===3){GS(i,r,l);break}else if(i.tag===1){var c=i.stateNode;if(typeof i.type.getDerivedStateFromError=="function"||typeof c.componentDidCatch=="function"&&(Vr===null||!Vr.has(c))){r=yn(l,r),l=Yx(2),c=Hr(i,l,2),c!==null&&(Xx(l,c,i,r),Nu(c,2),Yn(c));break}}i=i.return}}function Ch(r,i,l){var c=r.pingCache;if(c===null){c=r.pingCache=new iR;var v=new Set;c.set(i,v)}else v=c.get(i),v===void 0&&(v=new Set,c.set(i,v));v.has(l)||(_h=!0,v.add(l),r=sR.bind(null,r,i,l),i.then(r,r))}function sR(r,i,l){var c=r.pingCache;c!==null&&c.delete(i),r.pingedLanes|=r.suspendedLanes&l,r.warmLanes&=~l,Ze===r&&(Ce&l)===l&&(ot===4||ot===3&&(Ce&62914560)===Ce&&300>$t()-Ac?(ze&2)===0&&Di(r,0):Oh|=l,Ci===Ce&&(Ci=0)),Yn(r)}function YS(r,i){i===0&&(i=Lb()),r=xa(r,i),r!==null&&(Nu(r,i),Yn(r))}function fR(r){var i=r.memoizedState,l=0;i!==null&&(l=i.retryLane),YS(r,l)}function dR(r,i){var l=0;switch(r.tag){case 31:case 13:var c=r.stateNode,v=r.memoizedState;v!==null&&(l=v.retryLane);break;case 19:c=r.stateNode;break;case 22:c=r.stateNode._retryCache;break;default:throw Error(a(314))}c!==null&&c.delete(i),YS(r,l)}function hR(r,i){return Dr(r,i)}var Nc=null,Pi=null,Nh=!1,Dc=!1,Dh=!1,Qr=0;function Yn(r){r!==Pi&&r.next===null&&(Pi===null?Nc=Pi=r:Pi=Pi.next=r),Dc=!0,Nh||(Nh=!0,pR())}function fl(r,i){if(!Dh&&Dc){Dh=!0;do for(var l=!1,c=Nc;c!==null;){if(r!==0){var v=c.pendingLanes;if(v===0)var p=0;else{var S=c.suspendedLanes,C=c.pingedLanes;p=(1<<31-tn(42|r)+1)-1,p&=v&~(S&~C),p=p&201326741?p&201326741|1:p?p|2:0}p!==0&&(l=!0,ZS(c,p))}else p=Ce,p=zo(c,c===Ze?p:0,c.cancelPendingCommit!==null||c.timeoutHandle!==-1),(p&3)===0||Cu(c,p)||(l=!0,ZS(c,p));c=c.next}while(l);Dh=!1}}function vR(){XS()}function XS(){Dc=Nh=!1;var r=0;Qr!==0&&wR()&&(r=Qr);for(var i=$t(),l=null,c=Nc;c!==null;){var v=c.next,p=KS(c,i);p===0?(c.next=null,l===null?Nc=v:l.next=v,v===null&&(Pi=l)):(l=c,(r!==0||(p&3)!==0)&&(Dc=!0)),c=v}gt!==0&&gt!==5||fl(r),Qr!==0&&(Qr=0)}function KS(r,i){for(var l=r.suspendedLanes,c=r.pingedLanes,v=r.expirationTimes,p=r.pendingLanes&-62914561;0<p;){var S=31-tn(p),C=1<<S,z=v[S];z===-1?((C&l)===0||(C&c)!==0)&&(v[S]=kN(C,i)):z<=i&&(r.expiredLanes|=C),p&=~C}if(i=Ze,l=Ce,l=zo(r,r===i?l:0,r.cancelPendingCommit!==null||r.timeoutHandle!==-1),c=r.callbackNode,l===0||r===i&&(Ie===2||Ie===9)||r.cancelPendingCommit!==null)return c!==null&&c!==null&&Nn(c),r.callbackNode=null,r.callbackPriority=0;if((l&3)===0||Cu(r,l)){if(i=l&-l,i===r.callbackPriority)return i;switch(c!==null&&Nn(c),Kf(l)){case 2:case 8:l=$b;break;case 32:l=Do;break;case 268435456:l=Bb;break;default:l=Do}return c=VS.bind(null,r),l=Dr(l,c),r.callbackPriority=i,r.callbackNode=l,i}return c!==null&&c!==null&&Nn(c),r.callbackPriority=2,r.callbackNode=null,2}function VS(r,i){if(gt!==0&&gt!==5)return r.callbackNode=null,r.callbackPriority=0,null;var l=r.callbackNode;if(Cc()&&r.callbackNode!==l)return null;var c=Ce;return c=zo(r,r===Ze?c:0,r.cancelPendingCommit!==null||r.timeoutHandle!==-1),c===0?null:(MS(r,c,i),KS(r,$t()),r.callbackNode!=null&&r.callbackNode===l?VS.bind(null,r):null)}function ZS(r,i){if(Cc())return null;MS(r,i,!0)}function pR(){ER(function(){(ze&6)!==0?Dr(zb,vR):XS()})}function Rh(){if(Qr===0){var r=bi;r===0&&(r=Ro,Ro<<=1,(Ro&261888)===0&&(Ro=256)),Qr=r}return Qr}function FS(r){return r==null||typeof r=="symbol"||typeof r=="boolean"?null:typeof r=="function"?r:Uo(""+r)}function QS(r,i){var l=i.ownerDocument.createElement("input");return l.name=i.name,l.value=i.value,r.id&&l.setAttribute("form",r.id),i.parentNode.insertBefore(l,i),r=new FormData(r),l.parentNode.removeChild(l),r}function yR(r,i,l,c,v){if(i==="submit"&&l&&l.stateNode===v){var p=FS((v[Yt]||null).action),S=c.submitter;S&&(i=(i=S[Yt]||null)?FS(i.formAction):S.getAttribute("formAction"),i!==null&&(p=i,S=null));var C=new Go("action","action",null,c,v);r.push({event:C,listeners:[{instance:null,listener:function(){if(c.defaultPrevented){if(Qr!==0){var z=S?QS(v,S):new FormData(v);Jd(l,{pending:!0,data:z,method:v.method,action:p},null,z)}}else typeof p=="function"&&(C.preventDefault(),z=S?QS(v,S):new FormData(v),Jd(l,{pending:!0,data:z,method:v.method,action:p},p,z))},currentTarget:v}]})}}for(var Ph=0;Ph<yd.length;Ph++){var qh=yd[Ph],mR=qh.toLowerCase(),gR=qh[0].toUpperCase()+qh.slice(1);Dn(mR,"on"+gR)}Dn(j1,"onAnimationEnd"),Dn(M1,"onAnimationIteration"),Dn(C1,"onAnimationStart"),Dn("dblclick","onDoubleClick"),Dn("focusin","onFocus"),Dn("focusout","onBlur"),Dn(PD,"onTransitionRun"),Dn(qD,"onTransitionStart"),Dn(zD,"onTransitionCancel"),Dn(N1,"onTransitionEnd"),ii("onMouseEnter",["mouseout","mouseover"]),ii("onMouseLeave",["mouseout","mouseover"]),ii("onPointerEnter",["pointerout","pointerover"]),ii("onPointerLeave",["pointerout","pointerover"]),ya("onChange","change click focusin focusout input keydown keyup selectionchange".split(" ")),ya("onSelect","focusout contextmenu dragend focusin keydown keyup mousedown mouseup selectionchange".split(" ")),ya("onBeforeInput",["compositionend","keypress","textInput","paste"]),ya("onCompositionEnd","compositionend focusout keydown keypress keyup mousedown".split(" ")),ya("onCompositionStart","compositionstart focusout keydown keypress keyup mousedown".split(" ")),ya("onCompositionUpdate","compositionupdate focusout keydown keypress keyup mousedown".split(" "));var dl="abort canplay canplaythrough durationchange emptied encrypted ended error loadeddata loadedmetadata loadstart pause play playing progress ratechange resize seeked seeking stalled suspend timeupdate volumechange waiting".split(" "),bR=new Set("beforetoggle cancel close invalid load scroll scrollend toggle".split(" ").concat(dl));function WS(r,i){i=(i&4)!==0;for(var l=0;l<r.length;l++){var c=r[l],v=c.event;c=c.listeners;e:{var p=void 0;if(i)for(var S=c.length-1;0<=S;S--){var C=c[S],z=C.instance,X=C.currentTarget;if(C=C.listener,z!==p&&v.isPropagationStopped())break e;p=C,v.currentTarget=X;try{p(v)}catch(te){Ko(te)}v.currentTarget=null,p=z}else for(S=0;S<c.length;S++){if(C=c[S],z=C.instance,X=C.currentTarget,C=C.listener,z!==p&&v.isPropagationStopped())break e;p=C,v.currentTarget=X;try{p(v)}catch(te){Ko(te)}v.currentTarget=null,p=z}}}}function Me(r,i){var l=i[Vf];l===void 0&&(l=i[Vf]=new Set);var c=r+"__bubble";l.has(c)||(JS(i,r,2,!1),l.add(c))}function zh(r,i,l){var c=0;i&&(c|=4),JS(l,r,c,i)}var Rc="_reactListening"+Math.random().toString(36).slice(2);function $h(r){if(!r[Rc]){r[Rc]=!0,Xb.forEach(function(l){l!=="selectionchange"&&(bR.has(l)||zh(l,!1,r),zh(l,!0,r))});var i=r.nodeType===9?r:r.ownerDocument;i===null||i[Rc]||(i[Rc]=!0,zh("selectionchange",!1,i))}}function JS(r,i,l,c){switch(j_(i)){case 2:var v=KR;break;case 8:v=VR;break;default:v=Wh}l=v.bind(null,i,l,r),v=void 0,!rd||i!=="touchstart"&&i!=="touchmove"&&i!=="wheel"||(v=!0),c?v!==void 0?r.addEventListener(i,l,{capture:!0,passive:v}):r.addEventListener(i,l,!0):v!==void 0?r.addEventListener(i,l,{passive:v}):r.addEventListener(i,l,!1)}function Bh(r,i,l,c,v){var p=c;if((i&1)===0&&(i&2)===0&&c!==null)e:for(;;){if(c===null)return;var S=c.tag;if(S===3||S===4){var C=c.stateNode.containerInfo;if(C===v)break;if(S===4)for(S=c.return;S!==null;){var z=S.tag;if((z===3||z===4)&&S.stateNode.containerInfo===v)return;S=S.return}for(;C!==null;){if(S=ni(C),S===null)return;if(z=S.tag,z===5||z===6||z===26||z===27){c=p=S;continue e}C=C.parentNode}}c=c.return}a1(function(){var X=p,te=td(l),ie=[];e:{var K=D1.get(r);if(K!==void 0){var Z=Go,he=r;switch(r){case"keypress":if(Ho(l)===0)break e;case"keydown":case"keyup":Z=dD;break;case"focusin":he="focus",Z=ld;break;case"focusout":he="blur",Z=ld;break;case"beforeblur":case"afterblur":Z=ld;break;case"click":if(l.button===2)break e;case"auxclick":case"dblclick":case"mousedown":case"mousemove":case"mouseup":case"mouseout":case"mouseover":case"contextmenu":Z=l1;break;case"drag":case"dragend":case"dragenter":case"dragexit":case"dragleave":case"dragover":case"dragstart":case"drop":Z=eD;break;case"touchcancel":case"touchend":case"touchmove":case"touchstart":Z=pD;break;case j1:case M1:case C1:Z=rD;break;case N1:Z=mD;break;case"scroll":case"scrollend":Z=WN;break;case"wheel":Z=bD;break;case"copy":case"cut":case"paste":Z=iD;break;case"gotpointercapture":case"lostpointercapture":case"pointercancel":case"pointerdown":case"pointermove":case"pointerout":case"pointerover":case"pointerup":Z=c1;break;case"toggle":case"beforetoggle":Z=SD}var ge=(i&4)!==0,Ke=!ge&&(r==="scroll"||r==="scrollend"),k=ge?K!==null?K+"Capture":null:K;ge=[];for(var $=X,Y;$!==null;){var ae=$;if(Y=ae.stateNode,ae=ae.tag,ae!==5&&ae!==26&&ae!==27||Y===null||k===null||(ae=Pu($,k),ae!=null&&ge.push(hl($,ae,Y))),Ke)break;$=$.return}0<ge.length&&(K=new Z(K,he,null,l,te),ie.push({event:K,listeners:ge}))}}if((i&7)===0){e:{if(K=r==="mouseover"||r==="pointerover",Z=r==="mouseout"||r==="pointerout",K&&l!==ed&&(he=l.relatedTarget||l.fromElement)&&(ni(he)||he[ti]))break e;if((Z||K)&&(K=te.window===te?te:(K=te.ownerDocument)?K.defaultView||K.parentWindow:window,Z?(he=l.relatedTarget||l.toElement,Z=X,he=he?ni(he):null,he!==null&&(Ke=o(he),ge=he.tag,he!==Ke||ge!==5&&ge!==27&&ge!==6)&&(he=null)):(Z=null,he=X),Z!==he)){if(ge=l1,ae="onMouseLeave",k="onMouseEnter",$="mouse",(r==="pointerout"||r==="pointerover")&&(ge=c1,ae="onPointerLeave",k="onPointerEnter",$="pointer"),Ke=Z==null?K:Ru(Z),Y=he==null?K:Ru(he),K=new ge(ae,$+"leave",Z,l,te),K.target=Ke,K.relatedTarget=Y,ae=null,ni(te)===X&&(ge=new ge(k,$+"enter",he,l,te),ge.target=Y,ge.relatedTarget=Ke,ae=ge),Ke=ae,Z&&he)t:{for(ge=xR,k=Z,$=he,Y=0,ae=k;ae;ae=ge(ae))Y++;ae=0;for(var ye=$;ye;ye=ge(ye))ae++;for(;0<Y-ae;)k=ge(k),Y--;for(;0<ae-Y;)$=ge($),ae--;for(;Y--;){if(k===$||$!==null&&k===$.alternate){ge=k;break t}k=ge(k),$=ge($)}ge=null}else ge=null;Z!==null&&e_(ie,K,Z,ge,!1),he!==null&&Ke!==null&&e_(ie,Ke,he,ge,!0)}}e:{if(K=X?Ru(X):window,Z=K.nodeName&&K.nodeName.toLowerCase(),Z==="select"||Z==="input"&&K.type==="file")var Re=m1;else if(p1(K))if(g1)Re=ND;else{Re=MD;var pe=jD}else Z=K.nodeName,!Z||Z.toLowerCase()!=="input"||K.type!=="checkbox"&&K.type!=="radio"?X&&Jf(X.elementType)&&(Re=m1):Re=CD;if(Re&&(Re=Re(r,X))){y1(ie,Re,l,te);break e}pe&&pe(r,K,X),r==="focusout"&&X&&K.type==="number"&&X.memoizedProps.value!=null&&Wf(K,"number",K.value)}switch(pe=X?Ru(X):window,r){case"focusin":(p1(pe)||pe.contentEditable==="true")&&(fi=pe,hd=X,Hu=null);break;case"focusout":Hu=hd=fi=null;break;case"mousedown":vd=!0;break;case"contextmenu":case"mouseup":case"dragend":vd=!1,T1(ie,l,te);break;case"selectionchange":if(RD)break;case"keydown":case"keyup":T1(ie,l,te)}var we;if(cd)e:{switch(r){case"compositionstart":var Ne="onCompositionStart";break e;case"compositionend":Ne="onCompositionEnd";break e;case"compositionupdate":Ne="onCompositionUpdate";break e}Ne=void 0}else si?h1(r,l)&&(Ne="onCompositionEnd"):r==="keydown"&&l.keyCode===229&&(Ne="onCompositionStart");Ne&&(s1&&l.locale!=="ko"&&(si||Ne!=="onCompositionStart"?Ne==="onCompositionEnd"&&si&&(we=i1()):(qr=te,ad="value"in qr?qr.value:qr.textContent,si=!0)),pe=Pc(X,Ne),0<pe.length&&(Ne=new o1(Ne,r,null,l,te),ie.push({event:Ne,listeners:pe}),we?Ne.data=we:(we=v1(l),we!==null&&(Ne.data=we)))),(we=OD?AD(r,l):wD(r,l))&&(Ne=Pc(X,"onBeforeInput"),0<Ne.length&&(pe=new o1("onBeforeInput","beforeinput",null,l,te),ie.push({event:pe,listeners:Ne}),pe.data=we)),yR(ie,r,X,l,te)}WS(ie,i)})}function hl(r,i,l){return{instance:r,listener:i,currentTarget:l}}function Pc(r,i){for(var l=i+"Capture",c=[];r!==null;){var v=r,p=v.stateNode;if(v=v.tag,v!==5&&v!==26&&v!==27||p===null||(v=Pu(r,l),v!=null&&c.unshift(hl(r,v,p)),v=Pu(r,i),v!=null&&c.push(hl(r,v,p))),r.tag===3)return c;r=r.return}return[]}function xR(r){if(r===null)return null;do r=r.return;while(r&&r.tag!==5&&r.tag!==27);return r||null}function e_(r,i,l,c,v){for(var p=i._reactName,S=[];l!==null&&l!==c;){var C=l,z=C.alternate,X=C.stateNode;if(C=C.tag,z!==null&&z===c)break;C!==5&&C!==26&&C!==27||X===null||(z=X,v?(X=Pu(l,p),X!=null&&S.unshift(hl(l,X,z))):v||(X=Pu(l,p),X!=null&&S.push(hl(l,X,z)))),l=l.return}S.length!==0&&r.push({event:i,listeners:S})}var SR=/\r\n?/g,_R=/\u0000|\uFFFD/g;function t_(r){return(typeof r=="string"?r:""+r).replace(SR,`
-`).replace(_R,"")}function n_(r,i){return i=t_(i),t_(r)===i}function Xe(r,i,l,c,v,p){switch(l){case"children":typeof c=="string"?i==="body"||i==="textarea"&&c===""||li(r,c):(typeof c=="number"||typeof c=="bigint")&&i!=="body"&&li(r,""+c);break;case"className":Bo(r,"class",c);break;case"tabIndex":Bo(r,"tabindex",c);break;case"dir":case"role":case"viewBox":case"width":case"height":Bo(r,l,c);break;case"style":n1(r,c,p);break;case"data":if(i!=="object"){Bo(r,"data",c);break}case"src":case"href":if(c===""&&(i!=="a"||l!=="href")){r.removeAttribute(l);break}if(c==null||typeof c=="function"||typeof c=="symbol"||typeof c=="boolean"){r.removeAttribute(l);break}c=Uo(""+c),r.setAttribute(l,c);break;case"action":case"formAction":if(typeof c=="function"){r.setAttribute(l,"javascript:throw new Error('A React form was unexpectedly submitted. If you called form.submit() manually, consider using form.requestSubmit() instead. If you\\'re trying to use event.stopPropagation() in a submit event handler, consider also calling event.preventDefault().')");break}else typeof p=="function"&&(l==="formAction"?(i!=="input"&&Xe(r,i,"name",v.name,v,null),Xe(r,i,"formEncType",v.formEncType,v,null),Xe(r,i,"formMethod",v.formMethod,v,null),Xe(r,i,"formTarget",v.formTarget,v,null)):(Xe(r,i,"encType",v.encType,v,null),Xe(r,i,"method",v.method,v,null),Xe(r,i,"target",v.target,v,null)));if(c==null||typeof c=="symbol"||typeof c=="boolean"){r.removeAttribute(l);break}c=Uo(""+c),r.setAttribute(l,c);break;case"onClick":c!=null&&(r.onclick=nr);break;case"onScroll":c!=null&&Me("scroll",r);break;case"onScrollEnd":c!=null&&Me("scrollend",r);break;case"dangerouslySetInnerHTML":if(c!=null){if(typeof c!="object"||!("__html"in c))throw Error(a(61));if(l=c.__html,l!=null){if(v.children!=null)throw Error(a(60));r.innerHTML=l}}break;case"multiple":r.multiple=c&&typeof c!="function"&&typeof c!="symbol";break;case"muted":r.muted=c&&typeof c!="function"&&typeof c!="symbol";break;case"suppressContentEditableWarning":case"suppressHydrationWarning":case"defaultValue":case"defaultChecked":case"innerHTML":case"ref":break;case"autoFocus":break;case"xlinkHref":if(c==null||typeof c=="function"||typeof c=="boolean"||typeof c=="symbol"){r.removeAttribute("xlink:href");break}l=Uo(""+c),r.setAttributeNS("http://www.w3.org/1999/xlink","xlink:href",l);break;case"contentEditable":case"spellCheck":case"draggable":case"value":case"autoReverse":case"externalResourcesRequired":case"focusable":case"preserveAlpha":c!=null&&typeof c!="function"&&typeof c!="symbol"?r.setAttribute(l,""+c):r.removeAttribute(l);break;case"inert":case"allowFullScreen":case"async":case"autoPlay":case"controls":case"default":case"defer":case"disabled":case"disablePictureInPicture":case"disableRemotePlayback":case"formNoValidate":case"hidden":case"loop":case"noModule":case"noValidate":case"open":case"playsInline":case"readOnly":case"required":case"reversed":case"scoped":case"seamless":case"itemScope":c&&typeof c!="function"&&typeof c!="symbol"?r.setAttribute(l,""):r.removeAttribute(l);break;case"capture":case"download":c===!0?r.setAttribute(l,""):c!==!1&&c!=null&&typeof c!="function"&&typeof c!="symbol"?r.setAttribute(l,c):r.removeAttribute(l);break;case"cols":case"rows":case"size":case"span":c!=null&&typeof c!="function"&&typeof c!="symbol"&&!isNaN(c)&&1<=c?r.setAttribute(l,c):r.removeAttribute(l);break;case"rowSpan":case"start":c==null||typeof c=="function"||typeof c=="symbol"||isNaN(c)?r.removeAttribute(l):r.setAttribute(l,c);break;case"popover":Me("beforetoggle",r),Me("toggle",r),$o(r,"popover",c);break;case"xlinkActuate":tr(r,"http://www.w3.org/1999/xlink","xlink:actuate",c);break;case"xlinkArcrole":tr(r,"http://www.w3.org/1999/xlink","xlink:arcrole",c);break;case"xlinkRole":tr(r,"http://www.w3.org/1999/xlink","xlink:role",c);break;case"xlinkShow":tr(r,"http://www.w3.org/1999/xlink","xlink:show",c);break;case"xlinkTitle":tr(r,"http://www.w3.org/1999/xlink","xlink:title",c);break;case"xlinkType":tr(r,"http://www.w3.org/1999/xlink","xlink:type",c);break;case"xmlBase":tr(r,"http://www.w3.org/XML/1998/namespace","xml:base",c);break;case"xmlLang":tr(r,"http://www.w3.org/XML/1998/namespace","xml:lang",c);break;case"xmlSpace":tr(r,"http://www.w3.org/XML/1998/namespace","xml:space",c);break;case"is":$o(r,"is",c);break;case"innerText":case"textContent":break;default:(!(2<l.length)||l[0]!=="o"&&l[0]!=="O"||l[1]!=="n"&&l[1]!=="N")&&(l=FN.get(l)||l,$o(r,l,c))}}function Lh(r,i,l,c,v,p){switch(l){case"style":n1(r,c,p);break;case"dangerouslySetInnerHTML":if(c!=null){if(typeof c!="object"||!("__html"in c))throw Error(a(61));if(l=c.__html,l!=null){if(v.children!=null)throw Error(a(60));r.innerHTML=l}}break;case"children":typeof c=="string"?li(r,c):(typeof c=="number"||typeof c=="bigint")&&li(r,""+c);break;case"onScroll":c!=null&&Me("scroll",r);break;case"onScrollEnd":c!=null&&Me("scrollend",r);break;case"onClick":c!=null&&(r.onclick=nr);break;case"suppressContentEditableWarning":case"suppressHydrationWarning":case"innerHTML":case"ref":break;case"innerText":case"textContent":break;default:if(!Kb.hasOwnProperty(l))e:{if(l[0]==="o"&&l[1]==="n"&&(v=l.endsWith("Capture"),i=l.slice(2,v?l.length-7:void 0),p=r[Yt]||null,p=p!=null?p[l]:null,typeof p=="function"&&r.removeEventListener(i,p,v),typeof c=="function")){typeof p!="function"&&p!==null&&(l in r?r[l]=null:r.hasAttribute(l)&&r.removeAttribute(l)),r.addEventListener(i,c,v);break e}l in r?r[l]=c:c===!0?r.setAttribute(l,""):$o(r,l,c)}}}function Rt(r,i,l){switch(i){case"div":case"span":case"svg":case"path":case"a":case"g":case"p":case"li":break;case"img":Me("error",r),Me("load",r);var c=!1,v=!1,p;for(p in l)if(l.hasOwnProperty(p)){var S=l[p];if(S!=null)switch(p){case"src":c=!0;break;case"srcSet":v=!0;break;case"children":case"dangerouslySetInnerHTML":throw Error(a(137,i));default:Xe(r,i,p,S,l,null)}}v&&Xe(r,i,"srcSet",l.srcSet,l,null),c&&Xe(r,i,"src",l.src,l,null);return;case"input":Me("invalid",r);var C=p=S=v=null,z=null,X=null;for(c in l)if(l.hasOwnProperty(c)){var te=l[c];if(te!=null)switch(c){case"name":v=te;break;case"type":S=te;break;case"checked":z=te;break;case"defaultChecked":X=te;break;case"value":p=te;break;case"defaultValue":C=te;break;case"children":case"dangerouslySetInnerHTML":if(te!=null)throw Error(a(137,i));break;default:Xe(r,i,c,te,l,null)}}Wb(r,p,C,z,X,S,v,!1);return;case"select":Me("invalid",r),c=S=p=null;for(v in l)if(l.hasOwnProperty(v)&&(C=l[v],C!=null))switch(v){case"value":p=C;break;case"defaultValue":S=C;break;case"multiple":c=C;default:Xe(r,i,v,C,l,null)}i=p,l=S,r.multiple=!!c,i!=null?ui(r,!!c,i,!1):l!=null&&ui(r,!!c,l,!0);return;case"textarea":Me("invalid",r),p=v=c=null;for(S in l)if(l.hasOwnProperty(S)&&(C=l[S],C!=null))switch(S){case"value":c=C;break;case"defaultValue":v=C;break;case"children":p=C;break;case"dangerouslySetInnerHTML":if(C!=null)throw Error(a(91));break;default:Xe(r,i,S,C,l,null)}e1(r,c,v,p);return;case"option":for(z in l)if(l.hasOwnProperty(z)&&(c=l[z],c!=null))switch(z){case"selected":r.selected=c&&typeof c!="function"&&typeof c!="symbol";break;default:Xe(r,i,z,c,l,null)}return;case"dialog":Me("beforetoggle",r),Me("toggle",r),Me("cancel",r),Me("close",r);break;case"iframe":case"object":Me("load",r);break;case"video":case"audio":for(c=0;c<dl.length;c++)Me(dl[c],r);break;case"image":Me("error",r),Me("load",r);break;case"details":Me("toggle",r);break;case"embed":case"source":case"link":Me("error",r),Me("load",r);case"area":case"base":case"br":case"col":case"hr":case"keygen":case"meta":case"param":case"track":case"wbr":case"menuitem":for(X in l)if(l.hasOwnProperty(X)&&(c=l[X],c!=null))switch(X){case"children":case"dangerouslySetInnerHTML":throw Error(a(137,i));default:Xe(r,i,X,c,l,null)}return;default:if(Jf(i)){for(te in l)l.hasOwnProperty(te)&&(c=l[te],c!==void 0&&Lh(r,i,te,c,l,void 0));return}}for(C in l)l.hasOwnProperty(C)&&(c=l[C],c!=null&&Xe(r,i,C,c,l,null))}function OR(r,i,l,c){switch(i){case"div":case"span":case"svg":case"path":case"a":case"g":case"p":case"li":break;case"input":var v=null,p=null,S=null,C=null,z=null,X=null,te=null;for(Z in l){var ie=l[Z];if(l.hasOwnProperty(Z)&&ie!=null)switch(Z){case"checked":break;case"value":break;case"defaultValue":z=ie;default:c.hasOwnProperty(Z)||Xe(r,i,Z,null,c,ie)}}for(var K in c){var Z=c[K];if(ie=l[K],c.hasOwnProperty(K)&&(Z!=null||ie!=null))switch(K){case"type":p=Z;break;case"name":v=Z;break;case"checked":X=Z;break;case"defaultChecked":te=Z;break;case"value":S=Z;break;case"defaultValue":C=Z;break;case"children":case"dangerouslySetInnerHTML":if(Z!=null)throw Error(a(137,i));break;default:Z!==ie&&Xe(r,i,K,Z,c,ie)}}Qf(r,S,C,z,X,te,p,v);return;case"select":Z=S=C=K=null;for(p in l)if(z=l[p],l.hasOwnProperty(p)&&z!=null)switch(p){case"value":break;case"multiple":Z=z;default:c.hasOwnProperty(p)||Xe(r,i,p,null,c,z)}for(v in c)if(p=c[v],z=l[v],c.hasOwnProperty(v)&&(p!=null||z!=null))switch(v){case"value":K=p;break;case"defaultValue":C=p;break;case"multiple":S=p;default:p!==z&&Xe(r,i,v,p,c,z)}i=C,l=S,c=Z,K!=null?ui(r,!!l,K,!1):!!c!=!!l&&(i!=null?ui(r,!!l,i,!0):ui(r,!!l,l?[]:"",!1));return;case"textarea":Z=K=null;for(C in l)if(v=l[C],l.hasOwnProperty(C)&&v!=null&&!c.hasOwnProperty(C))switch(C){case"value":break;case"children":break;default:Xe(r,i,C,null,c,v)}for(S in c)if(v=c[S],p=l[S],c.hasOwnProperty(S)&&(v!=null||p!=null))switch(S){case"value":K=v;break;case"defaultValue":Z=v;break;case"children":break;case"dangerouslySetInnerHTML":if(v!=null)throw Error(a(91));break;default:v!==p&&Xe(r,i,S,v,c,p)}Jb(r,K,Z);return;case"option":for(var he in l)if(K=l[he],l.hasOwnProperty(he)&&K!=null&&!c.hasOwnProperty(he))switch(he){case"selected":r.selected=!1;break;default:Xe(r,i,he,null,c,K)}for(z in c)if(K=c[z],Z=l[z],c.hasOwnProperty(z)&&K!==Z&&(K!=null||Z!=null))switch(z){case"selected":r.selected=K&&typeof K!="function"&&typeof K!="symbol";break;default:Xe(r,i,z,K,c,Z)}return;case"img":case"link":case"area":case"base":case"br":case"col":case"embed":case"hr":case"keygen":case"meta":case"param":case"source":case"track":case"wbr":case"menuitem":for(var ge in l)K=l[ge],l.hasOwnProperty(ge)&&K!=null&&!c.hasOwnProperty(ge)&&Xe(r,i,ge,null,c,K);for(X in c)if(K=c[X],Z=l[X],c.hasOwnProperty(X)&&K!==Z&&(K!=null||Z!=null))switch(X){case"children":case"dangerouslySetInnerHTML":if(K!=null)throw Error(a(137,i));break;default:Xe(r,i,X,K,c,Z)}return;default:if(Jf(i)){for(var Ke in l)K=l[Ke],l.hasOwnProperty(Ke)&&K!==void 0&&!c.hasOwnProperty(Ke)&&Lh(r,i,Ke,void 0,c,K);for(te in c)K=c[te],Z=l[te],!c.hasOwnProperty(te)||K===Z||K===void 0&&Z===void 0||Lh(r,i,te,K,c,Z);return}}for(var k in l)K=l[k],l.hasOwnProperty(k)&&K!=null&&!c.hasOwnProperty(k)&&Xe(r,i,k,null,c,K);for(ie in c)K=c[ie],Z=l[ie],!c.hasOwnProperty(ie)||K===Z||K==null&&Z==null||Xe(r,i,ie,K,c,Z)}function r_(r){switch(r){case"css":case"script":case"font":case"img":case"image":case"input":case"link":return!0;default:return!1}}function AR(){if(typeof performance.getEntriesByType=="function"){for(var r=0,i=0,l=performance.getEntriesByType("resource"),c=0;c<l.length;c++){var v=l[c],p=v.transferSize,S=v.initiatorType,C=v.duration;if(p&&C&&r_(S)){for(S=0,C=v.responseEnd,c+=1;c<l.length;c++){var z=l[c],X=z.startTime;if(X>C)break;var te=z.transferSize,ie=z.initiatorType;te&&r_(ie)&&(z=z.responseEnd,S+=te*(z<C?1:(C-X)/(z-X)))}if(--c,i+=8*(p+S)/(v.duration/1e3),r++,10<r)break}}if(0<r)return i/r/1e6}return navigator.connection&&(r=navigator.connection.downlink,typeof r=="number")?r:5}var Uh=null,kh=null;function qc(r){return r.nodeType===9?r:r.ownerDocument}function a_(r){switch(r){case"http://www.w3.org/2000/svg":return 1;case"http://www.w3.org/1998/Math/MathML":return 2;default:return 0}}function i_(r,i){if(r===0)switch(i){case"svg":return 1;case"math":return 2;default:return 0}return r===1&&i==="foreignObject"?0:r}function Hh(r,i){return r==="textarea"||r==="noscript"||typeof i.children=="string"||typeof i.children=="number"||typeof i.children=="bigint"||typeof i.dangerouslySetInnerHTML=="object"&&i.dangerouslySetInnerHTML!==null&&i.dangerouslySetInnerHTML.__html!=null}var Ih=null;function wR(){var r=window.event;return r&&r.type==="popstate"?r===Ih?!1:(Ih=r,!0):(Ih=null,!1)}var u_=typeof setTimeout=="function"?setTimeout:void 0,TR=typeof clearTimeout=="function"?clearTimeout:void 0,l_=typeof Promise=="function"?Promise:void 0,ER=typeof queueMicrotask=="function"?queueMicrotask:typeof l_<"u"?function(r){return l_.resolve(null).then(r).catch(jR)}:u_;function jR(r){setTimeout(function(){throw r})}function Wr(r){return r==="head"}function o_(r,i){var l=i,c=0;do{var v=l.nextSibling;if(r.removeChild(l),v&&v.nodeType===8)if(l=v.data,l==="/$"||l==="/&"){if(c===0){r.removeChild(v),Bi(i);return}c--}else if(l==="$"||l==="$?"||l==="$~"||l==="$!"||l==="&")c++;else if(l==="html")vl(r.ownerDocument.documentElement);else if(l==="head"){l=r.ownerDocument.head,vl(l);for(var p=l.firstChild;p;){var S=p.nextSibling,C=p.nodeName;p[Du]||C==="SCRIPT"||C==="STYLE"||C==="LINK"&&p.rel.toLowerCase()==="stylesheet"||l.removeChild(p),p=S}}else l==="body"&&vl(r.ownerDocument.body);l=v}while(l);Bi(i)}function c_(r,i){var l=r;r=0;do{var c=l.nextSibling;if(l.nodeType===1?i?(l._stashedDisplay=l.style.display,l.style.display="none"):(l.style.display=l._stashedDisplay||"",l.getAttribute("style")===""&&l.removeAttribute("style")):l.nodeType===3&&(i?(l._stashedText=l.nodeValue,l.nodeValue=""):l.nodeValue=l._stashedText||""),c&&c.nodeType===8)if(l=c.data,l==="/$"){if(r===0)break;r--}else l!=="$"&&l!=="$?"&&l!=="$~"&&l!=="$!"||r++;l=c}while(l)}function Gh(r){var i=r.firstChild;for(i&&i.nodeType===10&&(i=i.nextSibling);i;){var l=i;switch(i=i.nextSibling,l.nodeName){case"HTML":case"HEAD":case"BODY":Gh(l),Zf(l);continue;case"SCRIPT":case"STYLE":continue;case"LINK":if(l.rel.toLowerCase()==="stylesheet")continue}r.removeChild(l)}}function MR(r,i,l,c){for(;r.nodeType===1;){var v=l;if(r.nodeName.toLowerCase()!==i.toLowerCase()){if(!c&&(r.nodeName!=="INPUT"||r.type!=="hidden"))break}else if(c){if(!r[Du])switch(i){case"meta":if(!r.hasAttribute("itemprop"))break;return r;case"link":if(p=r.getAttribute("rel"),p==="stylesheet"&&r.hasAttribute("data-precedence"))break;if(p!==v.rel||r.getAttribute("href")!==(v.href==null||v.href===""?null:v.href)||r.getAttribute("crossorigin")!==(v.crossOrigin==null?null:v.crossOrigin)||r.getAttribute("title")!==(v.title==null?null:v.title))break;return r;case"style":if(r.hasAttribute("data-precedence"))break;return r;case"script":if(p=r.getAttribute("src"),(p!==(v.src==null?null:v.src)||r.getAttribute("type")!==(v.type==null?null:v.type)||r.getAttribute("crossorigin")!==(v.crossOrigin==null?null:v.crossOrigin))&&p&&r.hasAttribute("async")&&!r.hasAttribute("itemprop"))break;return r;default:return r}}else if(i==="input"&&r.type==="hidden"){var p=v.name==null?null:""+v.name;if(v.type==="hidden"&&r.getAttribute("name")===p)return r}else return r;if(r=Sn(r.nextSibling),r===null)break}return null}function CR(r,i,l){if(i==="")return null;for(;r.nodeType!==3;)if((r.nodeType!==1||r.nodeName!=="INPUT"||r.type!=="hidden")&&!l||(r=Sn(r.nextSibling),r===null))return null;return r}function s_(r,i){for(;r.nodeType!==8;)if((r.nodeType!==1||r.nodeName!=="INPUT"||r.type!=="hidden")&&!i||(r=Sn(r.nextSibling),r===null))return null;return r}function Yh(r){return r.data==="$?"||r.data==="$~"}function Xh(r){return r.data==="$!"||r.data==="$?"&&r.ownerDocument.readyState!=="loading"}function NR(r,i){var l=r.ownerDocument;if(r.data==="$~")r._reactRetry=i;else if(r.data!=="$?"||l.readyState!=="loading")i();else{var c=function(){i(),l.removeEventListener("DOMContentLoaded",c)};l.addEventListener("DOMContentLoaded",c),r._reactRetry=c}}function Sn(r){for(;r!=null;r=r.nextSibling){var i=r.nodeType;if(i===1||i===3)break;if(i===8){if(i=r.data,i==="$"||i==="$!"||i==="$?"||i==="$~"||i==="&"||i==="F!"||i==="F")break;if(i==="/$"||i==="/&")return null}}return r}var Kh=null;function f_(r){r=r.nextSibling;for(var i=0;r;){if(r.nodeType===8){var l=r.data;if(l==="/$"||l==="/&"){if(i===0)return Sn(r.nextSibling);i--}else l!=="$"&&l!=="$!"&&l!=="$?"&&l!=="$~"&&l!=="&"||i++}r=r.nextSibling}return null}function d_(r){r=r.previousSibling;for(var i=0;r;){if(r.nodeType===8){var l=r.data;if(l==="$"||l==="$!"||l==="$?"||l==="$~"||l==="&"){if(i===0)return r;i--}else l!=="/$"&&l!=="/&"||i++}r=r.previousSibling}return null}function h_(r,i,l){switch(i=qc(l),r){case"html":if(r=i.documentElement,!r)throw Error(a(452));return r;case"head":if(r=i.head,!r)throw Error(a(453));return r;case"body":if(r=i.body,!r)throw Error(a(454));return r;default:throw Error(a(451))}}function vl(r){for(var i=r.attributes;i.length;)r.removeAttributeNode(i[0]);Zf(r)}var _n=new Map,v_=new Set;function zc(r){return typeof r.getRootNode=="function"?r.getRootNode():r.nodeType===9?r:r.ownerDocument}var gr=I.d;I.d={f:DR,r:RR,D:PR,C:qR,L:zR,m:$R,X:LR,S:BR,M:UR};function DR(){var r=gr.f(),i=Ec();return r||i}function RR(r){var i=ri(r);i!==null&&i.tag===5&&i.type==="form"?Nx(i):gr.r(r)}var qi=typeof document>"u"?null:document;function p_(r,i,l){var c=qi;if(c&&typeof i=="string"&&i){var v=vn(i);v='link[rel="'+r+'"][href="'+v+'"]',typeof l=="string"&&(v+='[crossorigin="'+l+'"]'),v_.has(v)||(v_.add(v),r={rel:r,crossOrigin:l,href:i},c.querySelector(v)===null&&(i=c.createElement("link"),Rt(i,"link",r),At(i),c.head.appendChild(i)))}}function PR(r){gr.D(r),p_("dns-prefetch",r,null)}function qR(r,i){gr.C(r,i),p_("preconnect",r,i)}function zR(r,i,l){gr.L(r,i,l);var c=qi;if(c&&r&&i){var v='link[rel="preload"][as="'+vn(i)+'"]';i==="image"&&l&&l.imageSrcSet?(v+='[imagesrcset="'+vn(l.imageSrcSet)+'"]',typeof l.imageSizes=="string"&&(v+='[imagesizes="'+vn(l.imageSizes)+'"]')):v+='[href="'+vn(r)+'"]';var p=v;switch(i){case"style":p=zi(r);break;case"script":p=$i(r)}_n.has(p)||(r=y({rel:"preload",href:i==="image"&&l&&l.imageSrcSet?void 0:r,as:i},l),_n.set(p,r),c.querySelector(v)!==null||i==="style"&&c.querySelector(pl(p))||i==="script"&&c.querySelector(yl(p))||(i=c.createElement("link"),Rt(i,"link",r),At(i),c.head.appendChild(i)))}}function $R(r,i){gr.m(r,i);var l=qi;if(l&&r){var c=i&&typeof i.as=="string"?i.as:"script",v='link[rel="modulepreload"][as="'+vn(c)+'"][href="'+vn(r)+'"]',p=v;switch(c){case"audioworklet":case"paintworklet":case"serviceworker":case"sharedworker":case"worker":case"script":p=$i(r)}if(!_n.has(p)&&(r=y({rel:"modulepreload",href:r},i),_n.set(p,r),l.querySelector(v)===null)){switch(c){case"audioworklet":case"paintworklet":case"serviceworker":case"sharedworker":case"worker":case"script":if(l.querySelector(yl(p)))return}c=l.createElement("link"),Rt(c,"link",r),At(c),l.head.appendChild(c)}}}function BR(r,i,l){gr.S(r,i,l);var c=qi;if(c&&r){var v=ai(c).hoistableStyles,p=zi(r);i=i||"default";var S=v.get(p);if(!S){var C={loading:0,preload:null};if(S=c.querySelector(pl(p)))C.loading=5;else{r=y({rel:"stylesheet",href:r,"data-precedence":i},l),(l=_n.get(p))&&Vh(r,l);var z=S=c.createElement("link");At(z),Rt(z,"link",r),z._p=new Promise(function(X,te){z.onload=X,z.onerror=te}),z.addEventListener("load",function(){C.loading|=1}),z.addEventListener("error",function(){C.loading|=2}),C.loading|=4,$c(S,i,c)}S={type:"stylesheet",instance:S,count:1,state:C},v.set(p,S)}}}function LR(r,i){gr.X(r,i);var l=qi;if(l&&r){var c=ai(l).hoistableScripts,v=$i(r),p=c.get(v);p||(p=l.querySelector(yl(v)),p||(r=y({src:r,async:!0},i),(i=_n.get(v))&&Zh(r,i),p=l.createElement("script"),At(p),Rt(p,"link",r),l.head.appendChild(p)),p={type:"script",instance:p,count:1,state:null},c.set(v,p))}}function UR(r,i){gr.M(r,i);var l=qi;if(l&&r){var c=ai(l).hoistableScripts,v=$i(r),p=c.get(v);p||(p=l.querySelector(yl(v)),p||(r=y({src:r,async:!0,type:"module"},i),(i=_n.get(v))&&Zh(r,i),p=l.createElement("script"),At(p),Rt(p,"link",r),l.head.appendChild(p)),p={type:"script",instance:p,count:1,state:null},c.set(v,p))}}function y_(r,i,l,c){var v=(v=me.current)?zc(v):null;if(!v)throw Error(a(446));switch(r){case"meta":case"title":return null;case"style":return typeof l.precedence=="string"&&typeof l.href=="string"?(i=zi(l.href),l=ai(v).hoistableStyles,c=l.get(i),c||(c={type:"style",instance:null,count:0,state:null},l.set(i,c)),c):{type:"void",instance:null,count:0,state:null};case"link":if(l.rel==="stylesheet"&&typeof l.href=="string"&&typeof l.precedence=="string"){r=zi(l.href);var p=ai(v).hoistableStyles,S=p.get(r);if(S||(v=v.ownerDocument||v,S={type:"stylesheet",instance:null,count:0,state:{loading:0,preload:null}},p.set(r,S),(p=v.querySelector(pl(r)))&&!p._p&&(S.instance=p,S.state.loading=5),_n.has(r)||(l={rel:"preload",as:"style",href:l.href,crossOrigin:l.crossOrigin,integrity:l.integrity,media:l.media,hrefLang:l.hrefLang,referrerPolicy:l.referrerPolicy},_n.set(r,l),p||kR(v,r,l,S.state))),i&&c===null)throw Error(a(528,""));return S}if(i&&c!==null)throw Error(a(529,""));return null;case"script":return i=l.async,l=l.src,typeof l=="string"&&i&&typeof i!="function"&&typeof i!="symbol"?(i=$i(l),l=ai(v).hoistableScripts,c=l.get(i),c||(c={type:"script",instance:null,count:0,state:null},l.set(i,c)),c):{type:"void",instance:null,count:0,state:null};default:throw Error(a(444,r))}}function zi(r){return'href="'+vn(r)+'"'}function pl(r){return'link[rel="stylesheet"]['+r+"]"}function m_(r){return y({},r,{"data-precedence":r.precedence,precedence:null})}function kR(r,i,l,c){r.querySelector('link[rel="preload"][as="style"]['+i+"]")?c.loading=1:(i=r.createElement("link"),c.preload=i,i.addEventListener("load",function(){return c.loading|=1}),i.addEventListener("error",function(){return c.loading|=2}),Rt(i,"link",l),At(i),r.head.appendChild(i))}function $i(r){return'[src="'+vn(r)+'"]'}function yl(r){return"script[async]"+r}function g_(r,i,l){if(i.count++,i.instance===null)switch(i.type){case"style":var c=r.querySelector('style[data-href~="'+vn(l.href)+'"]');if(c)return i.instance=c,At(c),c;var v=y({},l,{"data-href":l.href,"data-precedence":l.precedence,href:null,precedence:null});return c=(r.ownerDocument||r).createElement("style"),At(c),Rt(c,"style",v),$c(c,l.precedence,r),i.instance=c;case"stylesheet":v=zi(l.href);var p=r.querySelector(pl(v));if(p)return i.state.loading|=4,i.instance=p,At(p),p;c=m_(l),(v=_n.get(v))&&Vh(c,v),p=(r.ownerDocument||r).createElement("link"),At(p);var S=p;return S._p=new Promise(function(C,z){S.onload=C,S.onerror=z}),Rt(p,"link",c),i.state.loading|=4,$c(p,l.precedence,r),i.instance=p;case"script":return p=$i(l.src),(v=r.querySelector(yl(p)))?(i.instance=v,At(v),v):(c=l,(v=_n.get(p))&&(c=y({},l),Zh(c,v)),r=r.ownerDocument||r,v=r.createElement("script"),At(v),Rt(v,"link",c),r.head.appendChild(v),i.instance=v);case"void":return null;default:throw Error(a(443,i.type))}else i.type==="stylesheet"&&(i.state.loading&4)===0&&(c=i.instance,i.state.loading|=4,$c(c,l.precedence,r));return i.instance}function $c(r,i,l){for(var c=l.querySelectorAll('link[rel="stylesheet"][data-precedence],style[data-precedence]'),v=c.length?c[c.length-1]:null,p=v,S=0;S<c.length;S++){var C=c[S];if(C.dataset.precedence===i)p=C;else if(p!==v)break}p?p.parentNode.insertBefore(r,p.nextSibling):(i=l.nodeType===9?l.head:l,i.insertBefore(r,i.firstChild))}function Vh(r,i){r.crossOrigin==null&&(r.crossOrigin=i.crossOrigin),r.referrerPolicy==null&&(r.referrerPolicy=i.referrerPolicy),r.title==null&&(r.title=i.title)}function Zh(r,i){r.crossOrigin==null&&(r.crossOrigin=i.crossOrigin),r.referrerPolicy==null&&(r.referrerPolicy=i.referrerPolicy),r.integrity==null&&(r.integrity=i.integrity)}var Bc=null;function b_(r,i,l){if(Bc===null){var c=new Map,v=Bc=new Map;v.set(l,c)}else v=Bc,c=v.get(l),c||(c=new Map,v.set(l,c));if(c.has(r))return c;for(c.set(r,null),l=l.getElementsByTagName(r),v=0;v<l.length;v++){var p=l[v];if(!(p[Du]||p[Mt]||r==="link"&&p.getAttribute("rel")==="stylesheet")&&p.namespaceURI!=="http://www.w3.org/2000/svg"){var S=p.getAttribute(i)||"";S=r+S;var C=c.get(S);C?C.push(p):c.set(S,[p])}}return c}function x_(r,i,l){r=r.ownerDocument||r,r.head.insertBefore(l,i==="title"?r.querySelector("head > title"):null)}function HR(r,i,l){if(l===1||i.itemProp!=null)return!1;switch(r){case"meta":case"title":return!0;case"style":if(typeof i.precedence!="string"||typeof i.href!="string"||i.href==="")break;return!0;case"link":if(typeof i.rel!="string"||typeof i.href!="string"||i.href===""||i.onLoad||i.onError)break;switch(i.rel){case"stylesheet":return r=i.disabled,typeof i.precedence=="string"&&r==null;default:return!0}case"script":if(i.async&&typeof i.async!="function"&&typeof i.async!="symbol"&&!i.onLoad&&!i.onError&&i.src&&typeof i.src=="string")return!0}return!1}function S_(r){return!(r.type==="stylesheet"&&(r.state.loading&3)===0)}function IR(r,i,l,c){if(l.type==="stylesheet"&&(typeof c.media!="string"||matchMedia(c.media).matches!==!1)&&(l.state.loading&4)===0){if(l.instance===null){var v=zi(c.href),p=i.querySelector(pl(v));if(p){i=p._p,i!==null&&typeof i=="object"&&typeof i.then=="function"&&(r.count++,r=Lc.bind(r),i.then(r,r)),l.state.loading|=4,l.instance=p,At(p);return}p=i.ownerDocument||i,c=m_(c),(v=_n.get(v))&&Vh(c,v),p=p.createElement("link"),At(p);var S=p;S._p=new Promise(function(C,z){S.onload=C,S.onerror=z}),Rt(p,"link",c),l.instance=p}r.stylesheets===null&&(r.stylesheets=new Map),r.stylesheets.set(l,i),(i=l.state.preload)&&(l.state.loading&3)===0&&(r.count++,l=Lc.bind(r),i.addEventListener("load",l),i.addEventListener("error",l))}}var Fh=0;function GR(r,i){return r.stylesheets&&r.count===0&&kc(r,r.stylesheets),0<r.count||0<r.imgCount?function(l){var c=setTimeout(function(){if(r.stylesheets&&kc(r,r.stylesheets),r.unsuspend){var p=r.unsuspend;r.unsuspend=null,p()}},6e4+i);0<r.imgBytes&&Fh===0&&(Fh=62500*AR());var v=setTimeout(function(){if(r.waitingForImages=!1,r.count===0&&(r.stylesheets&&kc(r,r.stylesheets),r.unsuspend)){var p=r.unsuspend;r.unsuspend=null,p()}},(r.imgBytes>Fh?50:800)+i);return r.unsuspend=l,function(){r.unsuspend=null,clearTimeout(c),clearTimeout(v)}}:null}function Lc(){if(this.count--,this.count===0&&(this.imgCount===0||!this.waitingForImages)){if(this.stylesheets)kc(this,this.stylesheets);else if(this.unsuspend){var r=this.unsuspend;this.unsuspend=null,r()}}}var Uc=null;function kc(r,i){r.stylesheets=null,r.unsuspend!==null&&(r.count++,Uc=new Map,i.forEach(YR,r),Uc=null,Lc.call(r))}function YR(r,i){if(!(i.state.loading&4)){var l=Uc.get(r);if(l)var c=l.get(null);else{l=new Map,Uc.set(r,l);for(var v=r.querySelectorAll("link[data-precedence],style[data-precedence]"),p=0;p<v.length;p++){var S=v[p];(S.nodeName==="LINK"||S.getAttribute("media")!=="not all")&&(l.set(S.dataset.precedence,S),c=S)}c&&l.set(null,c)}v=i.instance,S=v.getAttribute("data-precedence"),p=l.get(S)||c,p===c&&l.set(null,v),l.set(S,v),this.count++,c=Lc.bind(this),v.addEventListener("load",c),v.addEventListener("error",c),p?p.parentNode.insertBefore(v,p.nextSibling):(r=r.nodeType===9?r.head:r,r.insertBefore(v,r.firstChild)),i.state.loading|=4}}var ml={$$typeof:M,Provider:null,Consumer:null,_currentValue:ee,_currentValue2:ee,_threadCount:0};function XR(r,i,l,c,v,p,S,C,z){this.tag=1,this.containerInfo=r,this.pingCache=this.current=this.pendingChildren=null,this.timeoutHandle=-1,this.callbackNode=this.next=this.pendingContext=this.context=this.cancelPendingCommit=null,this.callbackPriority=0,this.expirationTimes=Yf(-1),this.entangledLanes=this.shellSuspendCounter=this.errorRecoveryDisabledLanes=this.expiredLanes=this.warmLanes=this.pingedLanes=this.suspendedLanes=this.pendingLanes=0,this.entanglements=Yf(0),this.hiddenUpdates=Yf(null),this.identifierPrefix=c,this.onUncaughtError=v,this.onCaughtError=p,this.onRecoverableError=S,this.pooledCache=null,this.pooledCacheLanes=0,this.formState=z,this.incompleteTransitions=new Map}function __(r,i,l,c,v,p,S,C,z,X,te,ie){return r=new XR(r,i,l,S,z,X,te,ie,C),i=1,p===!0&&(i|=24),p=rn(3,null,null,i),r.current=p,p.stateNode=r,i=Md(),i.refCount++,r.pooledCache=i,i.refCount++,p.memoizedState={element:c,isDehydrated:l,cache:i},Rd(p),r}function O_(r){return r?(r=vi,r):vi}function A_(r,i,l,c,v,p){v=O_(v),c.context===null?c.context=v:c.pendingContext=v,c=kr(i),c.payload={element:l},p=p===void 0?null:p,p!==null&&(c.callback=p),l=Hr(r,c,i),l!==null&&(Qt(l,r,i),Zu(l,r,i))}function w_(r,i){if(r=r.memoizedState,r!==null&&r.dehydrated!==null){var l=r.retryLane;r.retryLane=l!==0&&l<i?l:i}}function Qh(r,i){w_(r,i),(r=r.alternate)&&w_(r,i)}function T_(r){if(r.tag===13||r.tag===31){var i=xa(r,67108864);i!==null&&Qt(i,r,67108864),Qh(r,67108864)}}function E_(r){if(r.tag===13||r.tag===31){var i=cn();i=Xf(i);var l=xa(r,i);l!==null&&Qt(l,r,i),Qh(r,i)}}var Hc=!0;function KR(r,i,l,c){var v=q.T;q.T=null;var p=I.p;try{I.p=2,Wh(r,i,l,c)}finally{I.p=p,q.T=v}}function VR(r,i,l,c){var v=q.T;q.T=null;var p=I.p;try{I.p=8,Wh(r,i,l,c)}finally{I.p=p,q.T=v}}function Wh(r,i,l,c){if(Hc){var v=Jh(c);if(v===null)Bh(r,i,c,Ic,l),M_(r,c);else if(FR(v,r,i,l,c))c.stopPropagation();else if(M_(r,c),i&4&&-1<ZR.indexOf(r)){for(;v!==null;){var p=ri(v);if(p!==null)switch(p.tag){case 3:if(p=p.stateNode,p.current.memoizedState.isDehydrated){var S=pa(p.pendingLanes);if(S!==0){var C=p;for(C.pendingLanes|=2,C.entangledLanes|=2;S;){var z=1<<31-tn(S);C.entanglements[1]|=z,S&=~z}Yn(p),(ze&6)===0&&(wc=$t()+500,fl(0))}}break;case 31:case 13:C=xa(p,2),C!==null&&Qt(C,p,2),Ec(),Qh(p,2)}if(p=Jh(c),p===null&&Bh(r,i,c,Ic,l),p===v)break;v=p}v!==null&&c.stopPropagation()}else Bh(r,i,c,null,l)}}function Jh(r){return r=td(r),ev(r)}var Ic=null;function ev(r){if(Ic=null,r=ni(r),r!==null){var i=o(r);if(i===null)r=null;else{var l=i.tag;if(l===13){if(r=s(i),r!==null)return r;r=null}else if(l===31){if(r=f(i),r!==null)return r;r=null}else if(l===3){if(i.stateNode.current.memoizedState.isDehydrated)return i.tag===3?i.stateNode.containerInfo:null;r=null}else i!==r&&(r=null)}}return Ic=r,null}function j_(r){switch(r){case"beforetoggle":case"cancel":case"click":case"close":case"contextmenu":case"copy":case"cut":case"auxclick":case"dblclick":case"dragend":case"dragstart":case"drop":case"focusin":case"focusout":case"input":case"invalid":case"keydown":case"keypress":case"keyup":case"mousedown":case"mouseup":case"paste":case"pause":case"play":case"pointercancel":case"pointerdown":case"pointerup":case"ratechange":case"reset":case"resize":case"seeked":case"submit":case"toggle":case"touchcancel":case"touchend":case"touchstart":case"volumechange":case"change":case"selectionchange":case"textInput":case"compositionstart":case"compositionend":case"compositionupdate":case"beforeblur":case"afterblur":case"beforeinput":case"blur":case"fullscreenchange":case"focus":case"hashchange":case"popstate":case"select":case"selectstart":return 2;case"drag":case"dragenter":case"dragexit":case"dragleave":case"dragover":case"mousemove":case"mouseout":case"mouseover":case"pointermove":case"pointerout":case"pointerover":case"scroll":case"touchmove":case"wheel":case"mouseenter":case"mouseleave":case"pointerenter":case"pointerleave":return 8;case"message":switch(No()){case zb:return 2;case $b:return 8;case Do:case qN:return 32;case Bb:return 268435456;default:return 32}default:return 32}}var tv=!1,Jr=null,ea=null,ta=null,gl=new Map,bl=new Map,na=[],ZR="mousedown mouseup touchcancel touchend touchstart auxclick dblclick pointercancel pointerdown pointerup dragend dragstart drop compositionend compositionstart keydown keypress keyup input textInput copy cut paste click change contextmenu reset".split(" ");function M_(r,i){switch(r){case"focusin":case"focusout":Jr=null;break;case"dragenter":case"dragleave":ea=null;break;case"mouseover":case"mouseout":ta=null;break;case"pointerover":case"pointerout":gl.delete(i.pointerId);break;case"gotpointercapture":case"lostpointercapture":bl.delete(i.pointerId)}}function xl(r,i,l,c,v,p){return r===null||r.nativeEvent!==p?(r={blockedOn:i,domEventName:l,eventSystemFlags:c,nativeEvent:p,targetContainers:[v]},i!==null&&(i=ri(i),i!==null&&T_(i)),r):(r.eventSystemFlags|=c,i=r.targetContainers,v!==null&&i.indexOf(v)===-1&&i.push(v),r)}function FR(r,i,l,c,v){switch(i){case"focusin":return Jr=xl(Jr,r,i,l,c,v),!0;case"dragenter":return ea=xl(ea,r,i,l,c,v),!0;case"mouseover":return ta=xl(ta,r,i,l,c,v),!0;case"pointerover":var p=v.pointerId;return gl.set(p,xl(gl.get(p)||null,r,i,l,c,v)),!0;case"gotpointercapture":return p=v.pointerId,bl.set(p,xl(bl.get(p)||null,r,i,l,c,v)),!0}return!1}function C_(r){var i=ni(r.target);if(i!==null){var l=o(i);if(l!==null){if(i=l.tag,i===13){if(i=s(l),i!==null){r.blockedOn=i,Gb(r.priority,function(){E_(l)});return}}else if(i===31){if(i=f(l),i!==null){r.blockedOn=i,Gb(r.priority,function(){E_(l)});return}}else if(i===3&&l.stateNode.current.memoizedState.isDehydrated){r.blockedOn=l.tag===3?l.stateNode.containerInfo:null;return}}}r.blockedOn=null}function Gc(r){if(r.blockedOn!==null)return!1;for(var i=r.targetContainers;0<i.length;){var l=Jh(r.nativeEvent);if(l===null){l=r.nativeEvent;var c=new l.constructor(l.type,l);ed=c,l.target.dispatchEvent(c),ed=null}else return i=ri(l),i!==null&&T_(i),r.blockedOn=l,!1;i.shift()}return!0}function N_(r,i,l){Gc(r)&&l.delete(i)}function QR(){tv=!1,Jr!==null&&Gc(Jr)&&(Jr=null),ea!==null&&Gc(ea)&&(ea=null),ta!==null&&Gc(ta)&&(ta=null),gl.forEach(N_),bl.forEach(N_)}function Yc(r,i){r.blockedOn===i&&(r.blockedOn=null,tv||(tv=!0,e.unstable_scheduleCallback(e.unstable_NormalPriority,QR)))}var Xc=null;function D_(r){Xc!==r&&(Xc=r,e.unstable_scheduleCallback(e.unstable_NormalPriority,function(){Xc===r&&(Xc=null);for(var i=0;i<r.length;i+=3){var l=r[i],c=r[i+1],v=r[i+2];if(typeof c!="function"){if(ev(c||l)===null)continue;break}var p=ri(l);p!==null&&(r.splice(i,3),i-=3,Jd(p,{pending:!0,data:v,method:l.method,action:c},c,v))}}))}function Bi(r){function i(z){return Yc(z,r)}Jr!==null&&Yc(Jr,r),ea!==null&&Yc(ea,r),ta!==null&&Yc(ta,r),gl.forEach(i),bl.forEach(i);for(var l=0;l<na.length;l++){var c=na[l];c.blockedOn===r&&(c.blockedOn=null)}for(;0<na.length&&(l=na[0],l.blockedOn===null);)C_(l),l.blockedOn===null&&na.shift();if(l=(r.ownerDocument||r).$$reactFormReplay,l!=null)for(c=0;c<l.length;c+=3){var v=l[c],p=l[c+1],S=v[Yt]||null;if(typeof p=="function")S||D_(l);else if(S){var C=null;if(p&&p.hasAttribute("formAction")){if(v=p,S=p[Yt]||null)C=S.formAction;else if(ev(v)!==null)continue}else C=S.action;typeof C=="function"?l[c+1]=C:(l.splice(c,3),c-=3),D_(l)}}}function R_(){function r(p){p.canIntercept&&p.info==="react-transition"&&p.intercept({handler:function(){return new Promise(function(S){return v=S})},focusReset:"manual",scroll:"manual"})}function i(){v!==null&&(v(),v=null),c||setTimeout(l,20)}function l(){if(!c&&!navigation.transition){var p=navigation.currentEntry;p&&p.url!=null&&navigation.navigate(p.url,{state:p.getState(),info:"react-transition",history:"replace"})}}if(typeof navigation=="object"){var c=!1,v=null;return navigation.addEventListener("navigate",r),navigation.addEventListener("navigatesuccess",i),navigation.addEventListener("navigateerror",i),setTimeout(l,100),function(){c=!0,navigation.removeEventListener("navigate",r),navigation.removeEventListener("navigatesuccess",i),navigation.removeEventListener("navigateerror",i),v!==null&&(v(),v=null)}}}function nv(r){this._internalRoot=r}Kc.prototype.render=nv.prototype.render=function(r){var i=this._internalRoot;if(i===null)throw Error(a(409));var l=i.current,c=cn();A_(l,c,r,i,null,null)},Kc.prototype.unmount=nv.prototype.unmount=function(){var r=this._internalRoot;if(r!==null){this._internalRoot=null;var i=r.containerInfo;A_(r.current,2,null,r,null,null),Ec(),i[ti]=null}};function Kc(r){this._internalRoot=r}Kc.prototype.unstable_scheduleHydration=function(r){if(r){var i=Ib();r={blockedOn:null,target:r,priority:i};for(var l=0;l<na.length&&i!==0&&i<na[l].priority;l++);na.splice(l,0,r),l===0&&C_(r)}};var P_=t.version;if(P_!=="19.2.4")throw Error(a(527,P_,"19.2.4"));I.findDOMNode=function(r){var i=r._reactInternals;if(i===void 0)throw typeof r.render=="function"?Error(a(188)):(r=Object.keys(r).join(","),Error(a(268,r)));return r=h(i),r=r!==null?m(r):null,r=r===null?null:r.stateNode,r};var WR={bundleType:0,version:"19.2.4",rendererPackageName:"react-dom",currentDispatcherRef:q,reconcilerVersion:"19.2.4"};if(typeof __REACT_DEVTOOLS_GLOBAL_HOOK__<"u"){var Vc=__REACT_DEVTOOLS_GLOBAL_HOOK__;if(!Vc.isDisabled&&Vc.supportsFiber)try{Mu=Vc.inject(WR),en=Vc}catch{}}return _l.createRoot=function(r,i){if(!u(r))throw Error(a(299));var l=!1,c="",v=kx,p=Hx,S=Ix;return i!=null&&(i.unstable_strictMode===!0&&(l=!0),i.identifierPrefix!==void 0&&(c=i.identifierPrefix),i.onUncaughtError!==void 0&&(v=i.onUncaughtError),i.onCaughtError!==void 0&&(p=i.onCaughtError),i.onRecoverableError!==void 0&&(S=i.onRecoverableError)),i=__(r,1,!1,null,null,l,c,null,v,p,S,R_),r[ti]=i.current,$h(r),new nv(i)},_l.hydrateRoot=function(r,i,l){if(!u(r))throw Error(a(299));var c=!1,v="",p=kx,S=Hx,C=Ix,z=null;return l!=null&&(l.unstable_strictMode===!0&&(c=!0),l.identifierPrefix!==void 0&&(v=l.identifierPrefix),l.onUncaughtError!==void 0&&(p=l.onUncaughtError),l.onCaughtError!==void 0&&(S=l.onCaughtError),l.onRecoverableError!==void 0&&(C=l.onRecoverableError),l.formState!==void 0&&(z=l.formState)),i=__(r,1,!0,i,l??null,c,v,z,p,S,C,R_),i.context=O_(null),l=i.current,c=cn(),c=Xf(c),v=kr(c),v.callback=null,Hr(l,v,c),l=c,i.current.lanes=l,Nu(i,l),Yn(i),r[ti]=i.current,$h(r),new Kc(i)},_l.version="19.2.4",_l}var G_;function oP(){if(G_)return iv.exports;G_=1;function e(){if(!(typeof __REACT_DEVTOOLS_GLOBAL_HOOK__>"u"||typeof __REACT_DEVTOOLS_GLOBAL_HOOK__.checkDCE!="function"))try{__REACT_DEVTOOLS_GLOBAL_HOOK__.checkDCE(e)}catch(t){console.error(t)}}return e(),iv.exports=lP(),iv.exports}var cP=oP();const sP=window.location.origin;async function Ol(e,t){const n=new URL(e,sP);if(t)for(const[u,o]of Object.entries(t))o!==void 0&&n.searchParams.set(u,String(o));const a=await fetch(n.toString());if(!a.ok)throw new Error(`${a.status} ${a.statusText}`);return a.json()}const dg={summary:()=>Ol("/api/summary"),sessions:e=>Ol("/api/sessions",e),stats:e=>Ol("/api/stats",e),events:e=>Ol("/api/events",e),errors:e=>Ol("/api/errors",e)};function fP(e){const t=e.getFullYear(),n=String(e.getMonth()+1).padStart(2,"0"),a=String(e.getDate()).padStart(2,"0");return`${t}-${n}-${a}`}function dP(e){if(e==="all")return{};const t=e==="24h"?1:e==="7d"?7:30,n=new Date;return n.setDate(n.getDate()-t),{since:fP(n)}}function hg(e,t=[]){const[n,a]=J.useState(null),[u,o]=J.useState(!0),[s,f]=J.useState(null),d=J.useCallback(()=>{o(!0),f(null),e().then(a).catch(h=>f(h.message)).finally(()=>o(!1))},t);return J.useEffect(()=>{d()},[d]),{data:n,loading:u,error:s,refetch:d}}const Y_=200;function hP(){const[e,t]=J.useState([]),n=J.useRef(!0),a=J.useRef(null),u=J.useRef(null),o=J.useCallback(()=>{if(!n.current)return;const s=`${window.location.origin}/api/events/stream`,f=new EventSource(s);a.current=f,f.onmessage=d=>{try{const h=JSON.parse(d.data);t(m=>{const y=[...m,h];return y.length>Y_?y.slice(-Y_):y})}catch{}},f.onerror=()=>{f.close(),a.current=null,n.current&&(u.current=setTimeout(o,3e3))}},[]);return J.useEffect(()=>(n.current=!0,o(),()=>{n.current=!1,u.current&&(clearTimeout(u.current),u.current=null),a.current&&(a.current.close(),a.current=null)}),[o]),e}let Li=0;function X_({initial:e,min:t,max:n,direction:a,invert:u}){const[o,s]=J.useState(e),f=J.useRef(!1),d=J.useRef(0),h=J.useRef(0),m=J.useCallback(y=>{y.preventDefault(),f.current=!0,Li++,d.current=a==="horizontal"?y.clientX:y.clientY,h.current=o,document.body.style.userSelect="none",queueMicrotask(()=>{Li>=2?document.body.style.cursor="move":Li===1&&(document.body.style.cursor=a==="horizontal"?"col-resize":"row-resize")})},[a,o]);return J.useEffect(()=>{const y=b=>{if(!f.current)return;const O=a==="horizontal"?b.clientX:b.clientY,_=u?d.current-O:O-d.current;s(Math.min(n,Math.max(t,h.current+_)))},g=()=>{f.current&&(f.current=!1,Li=Math.max(0,Li-1),Li===0&&(document.body.style.cursor="",document.body.style.userSelect=""))};return document.addEventListener("mousemove",y),document.addEventListener("mouseup",g),()=>{document.removeEventListener("mousemove",y),document.removeEventListener("mouseup",g)}},[a,t,n,u]),{size:o,onMouseDown:m}}const vP=["24h","7d","30d","all"];function pP({stats:e,sessionCount:t,range:n,onRangeChange:a,onStatClick:u}){const o=e==null?void 0:e.counts;return D.jsx("header",{className:"border-b border-gray-800 bg-gray-900/50 px-4 py-3",children:D.jsxs("div",{className:"flex items-center justify-between",children:[D.jsxs("div",{className:"flex items-center gap-3",children:[D.jsxs("svg",{width:"24",height:"24",viewBox:"0 0 64 64",className:"shrink-0",children:[D.jsx("polygon",{points:"8,10 56,10 32,54",fill:"none",stroke:"#e8e8f4",strokeWidth:"2.5",strokeLinejoin:"round"}),D.jsx("line",{x1:"16",y1:"29",x2:"50",y2:"29",stroke:"#f59e0b",strokeWidth:"2"})]}),D.jsx("h1",{className:"text-lg font-bold text-gray-100",children:"vigilo"}),D.jsx("span",{className:"text-xs text-gray-500",children:"dashboard"})]}),o&&D.jsxs("div",{className:"flex items-center gap-2",children:[D.jsx(Fc,{label:"Sessions",value:String(t),onClick:()=>u==null?void 0:u("sessions")}),D.jsx(Fc,{label:"Calls",value:o.total.toLocaleString(),onClick:()=>u==null?void 0:u("calls")}),D.jsx(Fc,{label:"Cost",value:o.cost_usd>0?`$${o.cost_usd.toFixed(2)}`:"$0.00",color:"text-emerald-400",onClick:()=>u==null?void 0:u("cost")}),D.jsx(Fc,{label:"Errors",value:String(o.errors),color:o.errors>0?"text-red-400":"text-gray-500",onClick:()=>u==null?void 0:u("errors")})]}),D.jsx("div",{className:"flex items-center gap-1 bg-gray-800 rounded p-0.5",children:vP.map(s=>D.jsx("button",{onClick:()=>a(s),className:`px-2.5 py-1 text-xs rounded transition-colors ${n===s?"bg-gray-700 text-gray-100":"text-gray-400 hover:text-gray-200"}`,children:s},s))})]})})}function Fc({label:e,value:t,color:n,onClick:a}){return D.jsxs("button",{onClick:a,className:"bg-gray-800/50 px-4 py-2 rounded hover:bg-gray-700/50 transition-colors text-left cursor-pointer",children:[D.jsx("div",{className:"text-[10px] text-gray-500 uppercase tracking-wider",children:e}),D.jsx("div",{className:`text-lg font-semibold ${n??"text-white"}`,children:t})]})}function yP(e){return e===0?"":e<.01?`$${e.toFixed(4)}`:`$${e.toFixed(2)}`}const mP=J.forwardRef(function({sessions:t,selectedSession:n,onSelect:a,collapsed:u,onToggle:o,width:s},f){return u?D.jsx("div",{className:"w-8 border-r border-gray-800 bg-gray-900/30 flex flex-col items-center pt-3",children:D.jsx("button",{onClick:o,className:"text-gray-500 hover:text-gray-300 text-xs",title:"Expand sessions",children:"»"})}):D.jsxs("aside",{ref:f,style:{width:s},className:"border-r border-gray-800 bg-gray-900/30 flex flex-col overflow-hidden shrink-0 transition-shadow duration-500",children:[D.jsxs("div",{className:"flex items-center justify-between px-3 py-2 border-b border-gray-800",children:[D.jsxs("span",{className:"text-xs font-semibold text-gray-400 uppercase tracking-wider",children:["Sessions ",t?`(${t.length})`:""]}),D.jsxs("div",{className:"flex gap-2",children:[n&&D.jsx("button",{onClick:()=>a(null),className:"text-xs text-gray-500 hover:text-gray-300",children:"clear"}),D.jsx("button",{onClick:o,className:"text-gray-500 hover:text-gray-300 text-xs",title:"Collapse",children:"«"})]})]}),D.jsxs("div",{className:"flex-1 overflow-y-auto",children:[D.jsx("button",{onClick:()=>a(null),className:`w-full text-left px-3 py-2 border-b border-gray-800/30 border-l-2 transition-colors text-xs ${n?"border-l-transparent hover:bg-gray-800/30 text-gray-400":"border-l-cyan-400 bg-gray-800/60 text-gray-200"}`,children:"All Sessions"}),!t||t.length===0?D.jsx("div",{className:"p-3 text-xs text-gray-600",children:"No sessions yet"}):t.slice().reverse().map(d=>{const h=n===d.id,m=d.session_ids&&d.session_ids.length>1,y=d.project&&d.project!=="—",g=[`Session: ${d.id.slice(0,8)}${m?` (+${d.session_ids.length-1} merged)`:""}`,y?`Project: ${d.project}`:null,d.branch?`Branch: ${d.branch}`:null,`Calls: ${d.call_count}`,d.cost_usd>0?`Cost: $${d.cost_usd.toFixed(4)}`:null,d.error_count>0?`Errors: ${d.error_count}`:null].filter(Boolean).join(`
+`).replace(_R,"")}function n_(r,i){return i=t_(i),t_(r)===i}function Xe(r,i,l,c,v,p){switch(l){case"children":typeof c=="string"?i==="body"||i==="textarea"&&c===""||li(r,c):(typeof c=="number"||typeof c=="bigint")&&i!=="body"&&li(r,""+c);break;case"className":Bo(r,"class",c);break;case"tabIndex":Bo(r,"tabindex",c);break;case"dir":case"role":case"viewBox":case"width":case"height":Bo(r,l,c);break;case"style":n1(r,c,p);break;case"data":if(i!=="object"){Bo(r,"data",c);break}case"src":case"href":if(c===""&&(i!=="a"||l!=="href")){r.removeAttribute(l);break}if(c==null||typeof c=="function"||typeof c=="symbol"||typeof c=="boolean"){r.removeAttribute(l);break}c=Uo(""+c),r.setAttribute(l,c);break;case"action":case"formAction":if(typeof c=="function"){r.setAttribute(l,"javascript:throw new Error('A React form was unexpectedly submitted. If you called form.submit() manually, consider using form.requestSubmit() instead. If you\\'re trying to use event.stopPropagation() in a submit event handler, consider also calling event.preventDefault().')");break}else typeof p=="function"&&(l==="formAction"?(i!=="input"&&Xe(r,i,"name",v.name,v,null),Xe(r,i,"formEncType",v.formEncType,v,null),Xe(r,i,"formMethod",v.formMethod,v,null),Xe(r,i,"formTarget",v.formTarget,v,null)):(Xe(r,i,"encType",v.encType,v,null),Xe(r,i,"method",v.method,v,null),Xe(r,i,"target",v.target,v,null)));if(c==null||typeof c=="symbol"||typeof c=="boolean"){r.removeAttribute(l);break}c=Uo(""+c),r.setAttribute(l,c);break;case"onClick":c!=null&&(r.onclick=nr);break;case"onScroll":c!=null&&Me("scroll",r);break;case"onScrollEnd":c!=null&&Me("scrollend",r);break;case"dangerouslySetInnerHTML":if(c!=null){if(typeof c!="object"||!("__html"in c))throw Error(a(61));if(l=c.__html,l!=null){if(v.children!=null)throw Error(a(60));r.innerHTML=l}}break;case"multiple":r.multiple=c&&typeof c!="function"&&typeof c!="symbol";break;case"muted":r.muted=c&&typeof c!="function"&&typeof c!="symbol";break;case"suppressContentEditableWarning":case"suppressHydrationWarning":case"defaultValue":case"defaultChecked":case"innerHTML":case"ref":break;case"autoFocus":break;case"xlinkHref":if(c==null||typeof c=="function"||typeof c=="boolean"||typeof c=="symbol"){r.removeAttribute("xlink:href");break}l=Uo(""+c),r.setAttributeNS("http://www.w3.org/1999/xlink","xlink:href",l);break;case"contentEditable":case"spellCheck":case"draggable":case"value":case"autoReverse":case"externalResourcesRequired":case"focusable":case"preserveAlpha":c!=null&&typeof c!="function"&&typeof c!="symbol"?r.setAttribute(l,""+c):r.removeAttribute(l);break;case"inert":case"allowFullScreen":case"async":case"autoPlay":case"controls":case"default":case"defer":case"disabled":case"disablePictureInPicture":case"disableRemotePlayback":case"formNoValidate":case"hidden":case"loop":case"noModule":case"noValidate":case"open":case"playsInline":case"readOnly":case"required":case"reversed":case"scoped":case"seamless":case"itemScope":c&&typeof c!="function"&&typeof c!="symbol"?r.setAttribute(l,""):r.removeAttribute(l);break;case"capture":case"download":c===!0?r.setAttribute(l,""):c!==!1&&c!=null&&typeof c!="function"&&typeof c!="symbol"?r.setAttribute(l,c):r.removeAttribute(l);break;case"cols":case"rows":case"size":case"span":c!=null&&typeof c!="function"&&typeof c!="symbol"&&!isNaN(c)&&1<=c?r.setAttribute(l,c):r.removeAttribute(l);break;case"rowSpan":case"start":c==null||typeof c=="function"||typeof c=="symbol"||isNaN(c)?r.removeAttribute(l):r.setAttribute(l,c);break;case"popover":Me("beforetoggle",r),Me("toggle",r),$o(r,"popover",c);break;case"xlinkActuate":tr(r,"http://www.w3.org/1999/xlink","xlink:actuate",c);break;case"xlinkArcrole":tr(r,"http://www.w3.org/1999/xlink","xlink:arcrole",c);break;case"xlinkRole":tr(r,"http://www.w3.org/1999/xlink","xlink:role",c);break;case"xlinkShow":tr(r,"http://www.w3.org/1999/xlink","xlink:show",c);break;case"xlinkTitle":tr(r,"http://www.w3.org/1999/xlink","xlink:title",c);break;case"xlinkType":tr(r,"http://www.w3.org/1999/xlink","xlink:type",c);break;case"xmlBase":tr(r,"http://www.w3.org/XML/1998/namespace","xml:base",c);break;case"xmlLang":tr(r,"http://www.w3.org/XML/1998/namespace","xml:lang",c);break;case"xmlSpace":tr(r,"http://www.w3.org/XML/1998/namespace","xml:space",c);break;case"is":$o(r,"is",c);break;case"innerText":case"textContent":break;default:(!(2<l.length)||l[0]!=="o"&&l[0]!=="O"||l[1]!=="n"&&l[1]!=="N")&&(l=FN.get(l)||l,$o(r,l,c))}}function Lh(r,i,l,c,v,p){switch(l){case"style":n1(r,c,p);break;case"dangerouslySetInnerHTML":if(c!=null){if(typeof c!="object"||!("__html"in c))throw Error(a(61));if(l=c.__html,l!=null){if(v.children!=null)throw Error(a(60));r.innerHTML=l}}break;case"children":typeof c=="string"?li(r,c):(typeof c=="number"||typeof c=="bigint")&&li(r,""+c);break;case"onScroll":c!=null&&Me("scroll",r);break;case"onScrollEnd":c!=null&&Me("scrollend",r);break;case"onClick":c!=null&&(r.onclick=nr);break;case"suppressContentEditableWarning":case"suppressHydrationWarning":case"innerHTML":case"ref":break;case"innerText":case"textContent":break;default:if(!Kb.hasOwnProperty(l))e:{if(l[0]==="o"&&l[1]==="n"&&(v=l.endsWith("Capture"),i=l.slice(2,v?l.length-7:void 0),p=r[Yt]||null,p=p!=null?p[l]:null,typeof p=="function"&&r.removeEventListener(i,p,v),typeof c=="function")){typeof p!="function"&&p!==null&&(l in r?r[l]=null:r.hasAttribute(l)&&r.removeAttribute(l)),r.addEventListener(i,c,v);break e}l in r?r[l]=c:c===!0?r.setAttribute(l,""):$o(r,l,c)}}}function Rt(r,i,l){switch(i){case"div":case"span":case"svg":case"path":case"a":case"g":case"p":case"li":break;case"img":Me("error",r),Me("load",r);var c=!1,v=!1,p;for(p in l)if(l.hasOwnProperty(p)){var S=l[p];if(S!=null)switch(p){case"src":c=!0;break;case"srcSet":v=!0;break;case"children":case"dangerouslySetInnerHTML":throw Error(a(137,i));default:Xe(r,i,p,S,l,null)}}v&&Xe(r,i,"srcSet",l.srcSet,l,null),c&&Xe(r,i,"src",l.src,l,null);return;case"input":Me("invalid",r);var C=p=S=v=null,z=null,X=null;for(c in l)if(l.hasOwnProperty(c)){var te=l[c];if(te!=null)switch(c){case"name":v=te;break;case"type":S=te;break;case"checked":z=te;break;case"defaultChecked":X=te;break;case"value":p=te;break;case"defaultValue":C=te;break;case"children":case"dangerouslySetInnerHTML":if(te!=null)throw Error(a(137,i));break;default:Xe(r,i,c,te,l,null)}}Wb(r,p,C,z,X,S,v,!1);return;case"select":Me("invalid",r),c=S=p=null;for(v in l)if(l.hasOwnProperty(v)&&(C=l[v],C!=null))switch(v){case"value":p=C;break;case"defaultValue":S=C;break;case"multiple":c=C;default:Xe(r,i,v,C,l,null)}i=p,l=S,r.multiple=!!c,i!=null?ui(r,!!c,i,!1):l!=null&&ui(r,!!c,l,!0);return;case"textarea":Me("invalid",r),p=v=c=null;for(S in l)if(l.hasOwnProperty(S)&&(C=l[S],C!=null))switch(S){case"value":c=C;break;case"defaultValue":v=C;break;case"children":p=C;break;case"dangerouslySetInnerHTML":if(C!=null)throw Error(a(91));break;default:Xe(r,i,S,C,l,null)}e1(r,c,v,p);return;case"option":for(z in l)if(l.hasOwnProperty(z)&&(c=l[z],c!=null))switch(z){case"selected":r.selected=c&&typeof c!="function"&&typeof c!="symbol";break;default:Xe(r,i,z,c,l,null)}return;case"dialog":Me("beforetoggle",r),Me("toggle",r),Me("cancel",r),Me("close",r);break;case"iframe":case"object":Me("load",r);break;case"video":case"audio":for(c=0;c<dl.length;c++)Me(dl[c],r);break;case"image":Me("error",r),Me("load",r);break;case"details":Me("toggle",r);break;case"embed":case"source":case"link":Me("error",r),Me("load",r);case"area":case"base":case"br":case"col":case"hr":case"keygen":case"meta":case"param":case"track":case"wbr":case"menuitem":for(X in l)if(l.hasOwnProperty(X)&&(c=l[X],c!=null))switch(X){case"children":case"dangerouslySetInnerHTML":throw Error(a(137,i));default:Xe(r,i,X,c,l,null)}return;default:if(Jf(i)){for(te in l)l.hasOwnProperty(te)&&(c=l[te],c!==void 0&&Lh(r,i,te,c,l,void 0));return}}for(C in l)l.hasOwnProperty(C)&&(c=l[C],c!=null&&Xe(r,i,C,c,l,null))}function OR(r,i,l,c){switch(i){case"div":case"span":case"svg":case"path":case"a":case"g":case"p":case"li":break;case"input":var v=null,p=null,S=null,C=null,z=null,X=null,te=null;for(Z in l){var ie=l[Z];if(l.hasOwnProperty(Z)&&ie!=null)switch(Z){case"checked":break;case"value":break;case"defaultValue":z=ie;default:c.hasOwnProperty(Z)||Xe(r,i,Z,null,c,ie)}}for(var K in c){var Z=c[K];if(ie=l[K],c.hasOwnProperty(K)&&(Z!=null||ie!=null))switch(K){case"type":p=Z;break;case"name":v=Z;break;case"checked":X=Z;break;case"defaultChecked":te=Z;break;case"value":S=Z;break;case"defaultValue":C=Z;break;case"children":case"dangerouslySetInnerHTML":if(Z!=null)throw Error(a(137,i));break;default:Z!==ie&&Xe(r,i,K,Z,c,ie)}}Qf(r,S,C,z,X,te,p,v);return;case"select":Z=S=C=K=null;for(p in l)if(z=l[p],l.hasOwnProperty(p)&&z!=null)switch(p){case"value":break;case"multiple":Z=z;default:c.hasOwnProperty(p)||Xe(r,i,p,null,c,z)}for(v in c)if(p=c[v],z=l[v],c.hasOwnProperty(v)&&(p!=null||z!=null))switch(v){case"value":K=p;break;case"defaultValue":C=p;break;case"multiple":S=p;default:p!==z&&Xe(r,i,v,p,c,z)}i=C,l=S,c=Z,K!=null?ui(r,!!l,K,!1):!!c!=!!l&&(i!=null?ui(r,!!l,i,!0):ui(r,!!l,l?[]:"",!1));return;case"textarea":Z=K=null;for(C in l)if(v=l[C],l.hasOwnProperty(C)&&v!=null&&!c.hasOwnProperty(C))switch(C){case"value":break;case"children":break;default:Xe(r,i,C,null,c,v)}for(S in c)if(v=c[S],p=l[S],c.hasOwnProperty(S)&&(v!=null||p!=null))switch(S){case"value":K=v;break;case"defaultValue":Z=v;break;case"children":break;case"dangerouslySetInnerHTML":if(v!=null)throw Error(a(91));break;default:v!==p&&Xe(r,i,S,v,c,p)}Jb(r,K,Z);return;case"option":for(var he in l)if(K=l[he],l.hasOwnProperty(he)&&K!=null&&!c.hasOwnProperty(he))switch(he){case"selected":r.selected=!1;break;default:Xe(r,i,he,null,c,K)}for(z in c)if(K=c[z],Z=l[z],c.hasOwnProperty(z)&&K!==Z&&(K!=null||Z!=null))switch(z){case"selected":r.selected=K&&typeof K!="function"&&typeof K!="symbol";break;default:Xe(r,i,z,K,c,Z)}return;case"img":case"link":case"area":case"base":case"br":case"col":case"embed":case"hr":case"keygen":case"meta":case"param":case"source":case"track":case"wbr":case"menuitem":for(var ge in l)K=l[ge],l.hasOwnProperty(ge)&&K!=null&&!c.hasOwnProperty(ge)&&Xe(r,i,ge,null,c,K);for(X in c)if(K=c[X],Z=l[X],c.hasOwnProperty(X)&&K!==Z&&(K!=null||Z!=null))switch(X){case"children":case"dangerouslySetInnerHTML":if(K!=null)throw Error(a(137,i));break;default:Xe(r,i,X,K,c,Z)}return;default:if(Jf(i)){for(var Ke in l)K=l[Ke],l.hasOwnProperty(Ke)&&K!==void 0&&!c.hasOwnProperty(Ke)&&Lh(r,i,Ke,void 0,c,K);for(te in c)K=c[te],Z=l[te],!c.hasOwnProperty(te)||K===Z||K===void 0&&Z===void 0||Lh(r,i,te,K,c,Z);return}}for(var k in l)K=l[k],l.hasOwnProperty(k)&&K!=null&&!c.hasOwnProperty(k)&&Xe(r,i,k,null,c,K);for(ie in c)K=c[ie],Z=l[ie],!c.hasOwnProperty(ie)||K===Z||K==null&&Z==null||Xe(r,i,ie,K,c,Z)}function r_(r){switch(r){case"css":case"script":case"font":case"img":case"image":case"input":case"link":return!0;default:return!1}}function AR(){if(typeof performance.getEntriesByType=="function"){for(var r=0,i=0,l=performance.getEntriesByType("resource"),c=0;c<l.length;c++){var v=l[c],p=v.transferSize,S=v.initiatorType,C=v.duration;if(p&&C&&r_(S)){for(S=0,C=v.responseEnd,c+=1;c<l.length;c++){var z=l[c],X=z.startTime;if(X>C)break;var te=z.transferSize,ie=z.initiatorType;te&&r_(ie)&&(z=z.responseEnd,S+=te*(z<C?1:(C-X)/(z-X)))}if(--c,i+=8*(p+S)/(v.duration/1e3),r++,10<r)break}}if(0<r)return i/r/1e6}return navigator.connection&&(r=navigator.connection.downlink,typeof r=="number")?r:5}var Uh=null,kh=null;function qc(r){return r.nodeType===9?r:r.ownerDocument}function a_(r){switch(r){case"http://www.w3.org/2000/svg":return 1;case"http://www.w3.org/1998/Math/MathML":return 2;default:return 0}}function i_(r,i){if(r===0)switch(i){case"svg":return 1;case"math":return 2;default:return 0}return r===1&&i==="foreignObject"?0:r}function Hh(r,i){return r==="textarea"||r==="noscript"||typeof i.children=="string"||typeof i.children=="number"||typeof i.children=="bigint"||typeof i.dangerouslySetInnerHTML=="object"&&i.dangerouslySetInnerHTML!==null&&i.dangerouslySetInnerHTML.__html!=null}var Ih=null;function wR(){var r=window.event;return r&&r.type==="popstate"?r===Ih?!1:(Ih=r,!0):(Ih=null,!1)}var u_=typeof setTimeout=="function"?setTimeout:void 0,TR=typeof clearTimeout=="function"?clearTimeout:void 0,l_=typeof Promise=="function"?Promise:void 0,ER=typeof queueMicrotask=="function"?queueMicrotask:typeof l_<"u"?function(r){return l_.resolve(null).then(r).catch(jR)}:u_;function jR(r){setTimeout(function(){throw r})}function Wr(r){return r==="head"}function o_(r,i){var l=i,c=0;do{var v=l.nextSibling;if(r.removeChild(l),v&&v.nodeType===8)if(l=v.data,l==="/$"||l==="/&"){if(c===0){r.removeChild(v),Bi(i);return}c--}else if(l==="$"||l==="$?"||l==="$~"||l==="$!"||l==="&")c++;else if(l==="html")vl(r.ownerDocument.documentElement);else if(l==="head"){l=r.ownerDocument.head,vl(l);for(var p=l.firstChild;p;){var S=p.nextSibling,C=p.nodeName;p[Du]||C==="SCRIPT"||C==="STYLE"||C==="LINK"&&p.rel.toLowerCase()==="stylesheet"||l.removeChild(p),p=S}}else l==="body"&&vl(r.ownerDocument.body);l=v}while(l);Bi(i)}function c_(r,i){var l=r;r=0;do{var c=l.nextSibling;if(l.nodeType===1?i?(l._stashedDisplay=l.style.display,l.style.display="none"):(l.style.display=l._stashedDisplay||"",l.getAttribute("style")===""&&l.removeAttribute("style")):l.nodeType===3&&(i?(l._stashedText=l.nodeValue,l.nodeValue=""):l.nodeValue=l._stashedText||""),c&&c.nodeType===8)if(l=c.data,l==="/$"){if(r===0)break;r--}else l!=="$"&&l!=="$?"&&l!=="$~"&&l!=="$!"||r++;l=c}while(l)}function Gh(r){var i=r.firstChild;for(i&&i.nodeType===10&&(i=i.nextSibling);i;){var l=i;switch(i=i.nextSibling,l.nodeName){case"HTML":case"HEAD":case"BODY":Gh(l),Zf(l);continue;case"SCRIPT":case"STYLE":continue;case"LINK":if(l.rel.toLowerCase()==="stylesheet")continue}r.removeChild(l)}}function MR(r,i,l,c){for(;r.nodeType===1;){var v=l;if(r.nodeName.toLowerCase()!==i.toLowerCase()){if(!c&&(r.nodeName!=="INPUT"||r.type!=="hidden"))break}else if(c){if(!r[Du])switch(i){case"meta":if(!r.hasAttribute("itemprop"))break;return r;case"link":if(p=r.getAttribute("rel"),p==="stylesheet"&&r.hasAttribute("data-precedence"))break;if(p!==v.rel||r.getAttribute("href")!==(v.href==null||v.href===""?null:v.href)||r.getAttribute("crossorigin")!==(v.crossOrigin==null?null:v.crossOrigin)||r.getAttribute("title")!==(v.title==null?null:v.title))break;return r;case"style":if(r.hasAttribute("data-precedence"))break;return r;case"script":if(p=r.getAttribute("src"),(p!==(v.src==null?null:v.src)||r.getAttribute("type")!==(v.type==null?null:v.type)||r.getAttribute("crossorigin")!==(v.crossOrigin==null?null:v.crossOrigin))&&p&&r.hasAttribute("async")&&!r.hasAttribute("itemprop"))break;return r;default:return r}}else if(i==="input"&&r.type==="hidden"){var p=v.name==null?null:""+v.name;if(v.type==="hidden"&&r.getAttribute("name")===p)return r}else return r;if(r=Sn(r.nextSibling),r===null)break}return null}function CR(r,i,l){if(i==="")return null;for(;r.nodeType!==3;)if((r.nodeType!==1||r.nodeName!=="INPUT"||r.type!=="hidden")&&!l||(r=Sn(r.nextSibling),r===null))return null;return r}function s_(r,i){for(;r.nodeType!==8;)if((r.nodeType!==1||r.nodeName!=="INPUT"||r.type!=="hidden")&&!i||(r=Sn(r.nextSibling),r===null))return null;return r}function Yh(r){return r.data==="$?"||r.data==="$~"}function Xh(r){return r.data==="$!"||r.data==="$?"&&r.ownerDocument.readyState!=="loading"}function NR(r,i){var l=r.ownerDocument;if(r.data==="$~")r._reactRetry=i;else if(r.data!=="$?"||l.readyState!=="loading")i();else{var c=function(){i(),l.removeEventListener("DOMContentLoaded",c)};l.addEventListener("DOMContentLoaded",c),r._reactRetry=c}}function Sn(r){for(;r!=null;r=r.nextSibling){var i=r.nodeType;if(i===1||i===3)break;if(i===8){if(i=r.data,i==="$"||i==="$!"||i==="$?"||i==="$~"||i==="&"||i==="F!"||i==="F")break;if(i==="/$"||i==="/&")return null}}return r}var Kh=null;function f_(r){r=r.nextSibling;for(var i=0;r;){if(r.nodeType===8){var l=r.data;if(l==="/$"||l==="/&"){if(i===0)return Sn(r.nextSibling);i--}else l!=="$"&&l!=="$!"&&l!=="$?"&&l!=="$~"&&l!=="&"||i++}r=r.nextSibling}return null}function d_(r){r=r.previousSibling;for(var i=0;r;){if(r.nodeType===8){var l=r.data;if(l==="$"||l==="$!"||l==="$?"||l==="$~"||l==="&"){if(i===0)return r;i--}else l!=="/$"&&l!=="/&"||i++}r=r.previousSibling}return null}function h_(r,i,l){switch(i=qc(l),r){case"html":if(r=i.documentElement,!r)throw Error(a(452));return r;case"head":if(r=i.head,!r)throw Error(a(453));return r;case"body":if(r=i.body,!r)throw Error(a(454));return r;default:throw Error(a(451))}}function vl(r){for(var i=r.attributes;i.length;)r.removeAttributeNode(i[0]);Zf(r)}var _n=new Map,v_=new Set;function zc(r){return typeof r.getRootNode=="function"?r.getRootNode():r.nodeType===9?r:r.ownerDocument}var gr=I.d;I.d={f:DR,r:RR,D:PR,C:qR,L:zR,m:$R,X:LR,S:BR,M:UR};function DR(){var r=gr.f(),i=Ec();return r||i}function RR(r){var i=ri(r);i!==null&&i.tag===5&&i.type==="form"?Nx(i):gr.r(r)}var qi=typeof document>"u"?null:document;function p_(r,i,l){var c=qi;if(c&&typeof i=="string"&&i){var v=vn(i);v='link[rel="'+r+'"][href="'+v+'"]',typeof l=="string"&&(v+='[crossorigin="'+l+'"]'),v_.has(v)||(v_.add(v),r={rel:r,crossOrigin:l,href:i},c.querySelector(v)===null&&(i=c.createElement("link"),Rt(i,"link",r),At(i),c.head.appendChild(i)))}}function PR(r){gr.D(r),p_("dns-prefetch",r,null)}function qR(r,i){gr.C(r,i),p_("preconnect",r,i)}function zR(r,i,l){gr.L(r,i,l);var c=qi;if(c&&r&&i){var v='link[rel="preload"][as="'+vn(i)+'"]';i==="image"&&l&&l.imageSrcSet?(v+='[imagesrcset="'+vn(l.imageSrcSet)+'"]',typeof l.imageSizes=="string"&&(v+='[imagesizes="'+vn(l.imageSizes)+'"]')):v+='[href="'+vn(r)+'"]';var p=v;switch(i){case"style":p=zi(r);break;case"script":p=$i(r)}_n.has(p)||(r=y({rel:"preload",href:i==="image"&&l&&l.imageSrcSet?void 0:r,as:i},l),_n.set(p,r),c.querySelector(v)!==null||i==="style"&&c.querySelector(pl(p))||i==="script"&&c.querySelector(yl(p))||(i=c.createElement("link"),Rt(i,"link",r),At(i),c.head.appendChild(i)))}}function $R(r,i){gr.m(r,i);var l=qi;if(l&&r){var c=i&&typeof i.as=="string"?i.as:"script",v='link[rel="modulepreload"][as="'+vn(c)+'"][href="'+vn(r)+'"]',p=v;switch(c){case"audioworklet":case"paintworklet":case"serviceworker":case"sharedworker":case"worker":case"script":p=$i(r)}if(!_n.has(p)&&(r=y({rel:"modulepreload",href:r},i),_n.set(p,r),l.querySelector(v)===null)){switch(c){case"audioworklet":case"paintworklet":case"serviceworker":case"sharedworker":case"worker":case"script":if(l.querySelector(yl(p)))return}c=l.createElement("link"),Rt(c,"link",r),At(c),l.head.appendChild(c)}}}function BR(r,i,l){gr.S(r,i,l);var c=qi;if(c&&r){var v=ai(c).hoistableStyles,p=zi(r);i=i||"default";var S=v.get(p);if(!S){var C={loading:0,preload:null};if(S=c.querySelector(pl(p)))C.loading=5;else{r=y({rel:"stylesheet",href:r,"data-precedence":i},l),(l=_n.get(p))&&Vh(r,l);var z=S=c.createElement("link");At(z),Rt(z,"link",r),z._p=new Promise(function(X,te){z.onload=X,z.onerror=te}),z.addEventListener("load",function(){C.loading|=1}),z.addEventListener("error",function(){C.loading|=2}),C.loading|=4,$c(S,i,c)}S={type:"stylesheet",instance:S,count:1,state:C},v.set(p,S)}}}function LR(r,i){gr.X(r,i);var l=qi;if(l&&r){var c=ai(l).hoistableScripts,v=$i(r),p=c.get(v);p||(p=l.querySelector(yl(v)),p||(r=y({src:r,async:!0},i),(i=_n.get(v))&&Zh(r,i),p=l.createElement("script"),At(p),Rt(p,"link",r),l.head.appendChild(p)),p={type:"script",instance:p,count:1,state:null},c.set(v,p))}}function UR(r,i){gr.M(r,i);var l=qi;if(l&&r){var c=ai(l).hoistableScripts,v=$i(r),p=c.get(v);p||(p=l.querySelector(yl(v)),p||(r=y({src:r,async:!0,type:"module"},i),(i=_n.get(v))&&Zh(r,i),p=l.createElement("script"),At(p),Rt(p,"link",r),l.head.appendChild(p)),p={type:"script",instance:p,count:1,state:null},c.set(v,p))}}function y_(r,i,l,c){var v=(v=me.current)?zc(v):null;if(!v)throw Error(a(446));switch(r){case"meta":case"title":return null;case"style":return typeof l.precedence=="string"&&typeof l.href=="string"?(i=zi(l.href),l=ai(v).hoistableStyles,c=l.get(i),c||(c={type:"style",instance:null,count:0,state:null},l.set(i,c)),c):{type:"void",instance:null,count:0,state:null};case"link":if(l.rel==="stylesheet"&&typeof l.href=="string"&&typeof l.precedence=="string"){r=zi(l.href);var p=ai(v).hoistableStyles,S=p.get(r);if(S||(v=v.ownerDocument||v,S={type:"stylesheet",instance:null,count:0,state:{loading:0,preload:null}},p.set(r,S),(p=v.querySelector(pl(r)))&&!p._p&&(S.instance=p,S.state.loading=5),_n.has(r)||(l={rel:"preload",as:"style",href:l.href,crossOrigin:l.crossOrigin,integrity:l.integrity,media:l.media,hrefLang:l.hrefLang,referrerPolicy:l.referrerPolicy},_n.set(r,l),p||kR(v,r,l,S.state))),i&&c===null)throw Error(a(528,""));return S}if(i&&c!==null)throw Error(a(529,""));return null;case"script":return i=l.async,l=l.src,typeof l=="string"&&i&&typeof i!="function"&&typeof i!="symbol"?(i=$i(l),l=ai(v).hoistableScripts,c=l.get(i),c||(c={type:"script",instance:null,count:0,state:null},l.set(i,c)),c):{type:"void",instance:null,count:0,state:null};default:throw Error(a(444,r))}}function zi(r){return'href="'+vn(r)+'"'}function pl(r){return'link[rel="stylesheet"]['+r+"]"}function m_(r){return y({},r,{"data-precedence":r.precedence,precedence:null})}function kR(r,i,l,c){r.querySelector('link[rel="preload"][as="style"]['+i+"]")?c.loading=1:(i=r.createElement("link"),c.preload=i,i.addEventListener("load",function(){return c.loading|=1}),i.addEventListener("error",function(){return c.loading|=2}),Rt(i,"link",l),At(i),r.head.appendChild(i))}function $i(r){return'[src="'+vn(r)+'"]'}function yl(r){return"script[async]"+r}function g_(r,i,l){if(i.count++,i.instance===null)switch(i.type){case"style":var c=r.querySelector('style[data-href~="'+vn(l.href)+'"]');if(c)return i.instance=c,At(c),c;var v=y({},l,{"data-href":l.href,"data-precedence":l.precedence,href:null,precedence:null});return c=(r.ownerDocument||r).createElement("style"),At(c),Rt(c,"style",v),$c(c,l.precedence,r),i.instance=c;case"stylesheet":v=zi(l.href);var p=r.querySelector(pl(v));if(p)return i.state.loading|=4,i.instance=p,At(p),p;c=m_(l),(v=_n.get(v))&&Vh(c,v),p=(r.ownerDocument||r).createElement("link"),At(p);var S=p;return S._p=new Promise(function(C,z){S.onload=C,S.onerror=z}),Rt(p,"link",c),i.state.loading|=4,$c(p,l.precedence,r),i.instance=p;case"script":return p=$i(l.src),(v=r.querySelector(yl(p)))?(i.instance=v,At(v),v):(c=l,(v=_n.get(p))&&(c=y({},l),Zh(c,v)),r=r.ownerDocument||r,v=r.createElement("script"),At(v),Rt(v,"link",c),r.head.appendChild(v),i.instance=v);case"void":return null;default:throw Error(a(443,i.type))}else i.type==="stylesheet"&&(i.state.loading&4)===0&&(c=i.instance,i.state.loading|=4,$c(c,l.precedence,r));return i.instance}function $c(r,i,l){for(var c=l.querySelectorAll('link[rel="stylesheet"][data-precedence],style[data-precedence]'),v=c.length?c[c.length-1]:null,p=v,S=0;S<c.length;S++){var C=c[S];if(C.dataset.precedence===i)p=C;else if(p!==v)break}p?p.parentNode.insertBefore(r,p.nextSibling):(i=l.nodeType===9?l.head:l,i.insertBefore(r,i.firstChild))}function Vh(r,i){r.crossOrigin==null&&(r.crossOrigin=i.crossOrigin),r.referrerPolicy==null&&(r.referrerPolicy=i.referrerPolicy),r.title==null&&(r.title=i.title)}function Zh(r,i){r.crossOrigin==null&&(r.crossOrigin=i.crossOrigin),r.referrerPolicy==null&&(r.referrerPolicy=i.referrerPolicy),r.integrity==null&&(r.integrity=i.integrity)}var Bc=null;function b_(r,i,l){if(Bc===null){var c=new Map,v=Bc=new Map;v.set(l,c)}else v=Bc,c=v.get(l),c||(c=new Map,v.set(l,c));if(c.has(r))return c;for(c.set(r,null),l=l.getElementsByTagName(r),v=0;v<l.length;v++){var p=l[v];if(!(p[Du]||p[Mt]||r==="link"&&p.getAttribute("rel")==="stylesheet")&&p.namespaceURI!=="http://www.w3.org/2000/svg"){var S=p.getAttribute(i)||"";S=r+S;var C=c.get(S);C?C.push(p):c.set(S,[p])}}return c}function x_(r,i,l){r=r.ownerDocument||r,r.head.insertBefore(l,i==="title"?r.querySelector("head > title"):null)}function HR(r,i,l){if(l===1||i.itemProp!=null)return!1;switch(r){case"meta":case"title":return!0;case"style":if(typeof i.precedence!="string"||typeof i.href!="string"||i.href==="")break;return!0;case"link":if(typeof i.rel!="string"||typeof i.href!="string"||i.href===""||i.onLoad||i.onError)break;switch(i.rel){case"stylesheet":return r=i.disabled,typeof i.precedence=="string"&&r==null;default:return!0}case"script":if(i.async&&typeof i.async!="function"&&typeof i.async!="symbol"&&!i.onLoad&&!i.onError&&i.src&&typeof i.src=="string")return!0}return!1}function S_(r){return!(r.type==="stylesheet"&&(r.state.loading&3)===0)}function IR(r,i,l,c){if(l.type==="stylesheet"&&(typeof c.media!="string"||matchMedia(c.media).matches!==!1)&&(l.state.loading&4)===0){if(l.instance===null){var v=zi(c.href),p=i.querySelector(pl(v));if(p){i=p._p,i!==null&&typeof i=="object"&&typeof i.then=="function"&&(r.count++,r=Lc.bind(r),i.then(r,r)),l.state.loading|=4,l.instance=p,At(p);return}p=i.ownerDocument||i,c=m_(c),(v=_n.get(v))&&Vh(c,v),p=p.createElement("link"),At(p);var S=p;S._p=new Promise(function(C,z){S.onload=C,S.onerror=z}),Rt(p,"link",c),l.instance=p}r.stylesheets===null&&(r.stylesheets=new Map),r.stylesheets.set(l,i),(i=l.state.preload)&&(l.state.loading&3)===0&&(r.count++,l=Lc.bind(r),i.addEventListener("load",l),i.addEventListener("error",l))}}var Fh=0;function GR(r,i){return r.stylesheets&&r.count===0&&kc(r,r.stylesheets),0<r.count||0<r.imgCount?function(l){var c=setTimeout(function(){if(r.stylesheets&&kc(r,r.stylesheets),r.unsuspend){var p=r.unsuspend;r.unsuspend=null,p()}},6e4+i);0<r.imgBytes&&Fh===0&&(Fh=62500*AR());var v=setTimeout(function(){if(r.waitingForImages=!1,r.count===0&&(r.stylesheets&&kc(r,r.stylesheets),r.unsuspend)){var p=r.unsuspend;r.unsuspend=null,p()}},(r.imgBytes>Fh?50:800)+i);return r.unsuspend=l,function(){r.unsuspend=null,clearTimeout(c),clearTimeout(v)}}:null}function Lc(){if(this.count--,this.count===0&&(this.imgCount===0||!this.waitingForImages)){if(this.stylesheets)kc(this,this.stylesheets);else if(this.unsuspend){var r=this.unsuspend;this.unsuspend=null,r()}}}var Uc=null;function kc(r,i){r.stylesheets=null,r.unsuspend!==null&&(r.count++,Uc=new Map,i.forEach(YR,r),Uc=null,Lc.call(r))}function YR(r,i){if(!(i.state.loading&4)){var l=Uc.get(r);if(l)var c=l.get(null);else{l=new Map,Uc.set(r,l);for(var v=r.querySelectorAll("link[data-precedence],style[data-precedence]"),p=0;p<v.length;p++){var S=v[p];(S.nodeName==="LINK"||S.getAttribute("media")!=="not all")&&(l.set(S.dataset.precedence,S),c=S)}c&&l.set(null,c)}v=i.instance,S=v.getAttribute("data-precedence"),p=l.get(S)||c,p===c&&l.set(null,v),l.set(S,v),this.count++,c=Lc.bind(this),v.addEventListener("load",c),v.addEventListener("error",c),p?p.parentNode.insertBefore(v,p.nextSibling):(r=r.nodeType===9?r.head:r,r.insertBefore(v,r.firstChild)),i.state.loading|=4}}var ml={$$typeof:M,Provider:null,Consumer:null,_currentValue:ee,_currentValue2:ee,_threadCount:0};function XR(r,i,l,c,v,p,S,C,z){this.tag=1,this.containerInfo=r,this.pingCache=this.current=this.pendingChildren=null,this.timeoutHandle=-1,this.callbackNode=this.next=this.pendingContext=this.context=this.cancelPendingCommit=null,this.callbackPriority=0,this.expirationTimes=Yf(-1),this.entangledLanes=this.shellSuspendCounter=this.errorRecoveryDisabledLanes=this.expiredLanes=this.warmLanes=this.pingedLanes=this.suspendedLanes=this.pendingLanes=0,this.entanglements=Yf(0),this.hiddenUpdates=Yf(null),this.identifierPrefix=c,this.onUncaughtError=v,this.onCaughtError=p,this.onRecoverableError=S,this.pooledCache=null,this.pooledCacheLanes=0,this.formState=z,this.incompleteTransitions=new Map}function __(r,i,l,c,v,p,S,C,z,X,te,ie){return r=new XR(r,i,l,S,z,X,te,ie,C),i=1,p===!0&&(i|=24),p=rn(3,null,null,i),r.current=p,p.stateNode=r,i=Md(),i.refCount++,r.pooledCache=i,i.refCount++,p.memoizedState={element:c,isDehydrated:l,cache:i},Rd(p),r}function O_(r){return r?(r=vi,r):vi}function A_(r,i,l,c,v,p){v=O_(v),c.context===null?c.context=v:c.pendingContext=v,c=kr(i),c.payload={element:l},p=p===void 0?null:p,p!==null&&(c.callback=p),l=Hr(r,c,i),l!==null&&(Qt(l,r,i),Zu(l,r,i))}function w_(r,i){if(r=r.memoizedState,r!==null&&r.dehydrated!==null){var l=r.retryLane;r.retryLane=l!==0&&l<i?l:i}}function Qh(r,i){w_(r,i),(r=r.alternate)&&w_(r,i)}function T_(r){if(r.tag===13||r.tag===31){var i=xa(r,67108864);i!==null&&Qt(i,r,67108864),Qh(r,67108864)}}function E_(r){if(r.tag===13||r.tag===31){var i=cn();i=Xf(i);var l=xa(r,i);l!==null&&Qt(l,r,i),Qh(r,i)}}var Hc=!0;function KR(r,i,l,c){var v=q.T;q.T=null;var p=I.p;try{I.p=2,Wh(r,i,l,c)}finally{I.p=p,q.T=v}}function VR(r,i,l,c){var v=q.T;q.T=null;var p=I.p;try{I.p=8,Wh(r,i,l,c)}finally{I.p=p,q.T=v}}function Wh(r,i,l,c){if(Hc){var v=Jh(c);if(v===null)Bh(r,i,c,Ic,l),M_(r,c);else if(FR(v,r,i,l,c))c.stopPropagation();else if(M_(r,c),i&4&&-1<ZR.indexOf(r)){for(;v!==null;){var p=ri(v);if(p!==null)switch(p.tag){case 3:if(p=p.stateNode,p.current.memoizedState.isDehydrated){var S=pa(p.pendingLanes);if(S!==0){var C=p;for(C.pendingLanes|=2,C.entangledLanes|=2;S;){var z=1<<31-tn(S);C.entanglements[1]|=z,S&=~z}Yn(p),(ze&6)===0&&(wc=$t()+500,fl(0))}}break;case 31:case 13:C=xa(p,2),C!==null&&Qt(C,p,2),Ec(),Qh(p,2)}if(p=Jh(c),p===null&&Bh(r,i,c,Ic,l),p===v)break;v=p}v!==null&&c.stopPropagation()}else Bh(r,i,c,null,l)}}function Jh(r){return r=td(r),ev(r)}var Ic=null;function ev(r){if(Ic=null,r=ni(r),r!==null){var i=o(r);if(i===null)r=null;else{var l=i.tag;if(l===13){if(r=s(i),r!==null)return r;r=null}else if(l===31){if(r=f(i),r!==null)return r;r=null}else if(l===3){if(i.stateNode.current.memoizedState.isDehydrated)return i.tag===3?i.stateNode.containerInfo:null;r=null}else i!==r&&(r=null)}}return Ic=r,null}function j_(r){switch(r){case"beforetoggle":case"cancel":case"click":case"close":case"contextmenu":case"copy":case"cut":case"auxclick":case"dblclick":case"dragend":case"dragstart":case"drop":case"focusin":case"focusout":case"input":case"invalid":case"keydown":case"keypress":case"keyup":case"mousedown":case"mouseup":case"paste":case"pause":case"play":case"pointercancel":case"pointerdown":case"pointerup":case"ratechange":case"reset":case"resize":case"seeked":case"submit":case"toggle":case"touchcancel":case"touchend":case"touchstart":case"volumechange":case"change":case"selectionchange":case"textInput":case"compositionstart":case"compositionend":case"compositionupdate":case"beforeblur":case"afterblur":case"beforeinput":case"blur":case"fullscreenchange":case"focus":case"hashchange":case"popstate":case"select":case"selectstart":return 2;case"drag":case"dragenter":case"dragexit":case"dragleave":case"dragover":case"mousemove":case"mouseout":case"mouseover":case"pointermove":case"pointerout":case"pointerover":case"scroll":case"touchmove":case"wheel":case"mouseenter":case"mouseleave":case"pointerenter":case"pointerleave":return 8;case"message":switch(No()){case zb:return 2;case $b:return 8;case Do:case qN:return 32;case Bb:return 268435456;default:return 32}default:return 32}}var tv=!1,Jr=null,ea=null,ta=null,gl=new Map,bl=new Map,na=[],ZR="mousedown mouseup touchcancel touchend touchstart auxclick dblclick pointercancel pointerdown pointerup dragend dragstart drop compositionend compositionstart keydown keypress keyup input textInput copy cut paste click change contextmenu reset".split(" ");function M_(r,i){switch(r){case"focusin":case"focusout":Jr=null;break;case"dragenter":case"dragleave":ea=null;break;case"mouseover":case"mouseout":ta=null;break;case"pointerover":case"pointerout":gl.delete(i.pointerId);break;case"gotpointercapture":case"lostpointercapture":bl.delete(i.pointerId)}}function xl(r,i,l,c,v,p){return r===null||r.nativeEvent!==p?(r={blockedOn:i,domEventName:l,eventSystemFlags:c,nativeEvent:p,targetContainers:[v]},i!==null&&(i=ri(i),i!==null&&T_(i)),r):(r.eventSystemFlags|=c,i=r.targetContainers,v!==null&&i.indexOf(v)===-1&&i.push(v),r)}function FR(r,i,l,c,v){switch(i){case"focusin":return Jr=xl(Jr,r,i,l,c,v),!0;case"dragenter":return ea=xl(ea,r,i,l,c,v),!0;case"mouseover":return ta=xl(ta,r,i,l,c,v),!0;case"pointerover":var p=v.pointerId;return gl.set(p,xl(gl.get(p)||null,r,i,l,c,v)),!0;case"gotpointercapture":return p=v.pointerId,bl.set(p,xl(bl.get(p)||null,r,i,l,c,v)),!0}return!1}function C_(r){var i=ni(r.target);if(i!==null){var l=o(i);if(l!==null){if(i=l.tag,i===13){if(i=s(l),i!==null){r.blockedOn=i,Gb(r.priority,function(){E_(l)});return}}else if(i===31){if(i=f(l),i!==null){r.blockedOn=i,Gb(r.priority,function(){E_(l)});return}}else if(i===3&&l.stateNode.current.memoizedState.isDehydrated){r.blockedOn=l.tag===3?l.stateNode.containerInfo:null;return}}}r.blockedOn=null}function Gc(r){if(r.blockedOn!==null)return!1;for(var i=r.targetContainers;0<i.length;){var l=Jh(r.nativeEvent);if(l===null){l=r.nativeEvent;var c=new l.constructor(l.type,l);ed=c,l.target.dispatchEvent(c),ed=null}else return i=ri(l),i!==null&&T_(i),r.blockedOn=l,!1;i.shift()}return!0}function N_(r,i,l){Gc(r)&&l.delete(i)}function QR(){tv=!1,Jr!==null&&Gc(Jr)&&(Jr=null),ea!==null&&Gc(ea)&&(ea=null),ta!==null&&Gc(ta)&&(ta=null),gl.forEach(N_),bl.forEach(N_)}function Yc(r,i){r.blockedOn===i&&(r.blockedOn=null,tv||(tv=!0,e.unstable_scheduleCallback(e.unstable_NormalPriority,QR)))}var Xc=null;function D_(r){Xc!==r&&(Xc=r,e.unstable_scheduleCallback(e.unstable_NormalPriority,function(){Xc===r&&(Xc=null);for(var i=0;i<r.length;i+=3){var l=r[i],c=r[i+1],v=r[i+2];if(typeof c!="function"){if(ev(c||l)===null)continue;break}var p=ri(l);p!==null&&(r.splice(i,3),i-=3,Jd(p,{pending:!0,data:v,method:l.method,action:c},c,v))}}))}function Bi(r){function i(z){return Yc(z,r)}Jr!==null&&Yc(Jr,r),ea!==null&&Yc(ea,r),ta!==null&&Yc(ta,r),gl.forEach(i),bl.forEach(i);for(var l=0;l<na.length;l++){var c=na[l];c.blockedOn===r&&(c.blockedOn=null)}for(;0<na.length&&(l=na[0],l.blockedOn===null);)C_(l),l.blockedOn===null&&na.shift();if(l=(r.ownerDocument||r).$$reactFormReplay,l!=null)for(c=0;c<l.length;c+=3){var v=l[c],p=l[c+1],S=v[Yt]||null;if(typeof p=="function")S||D_(l);else if(S){var C=null;if(p&&p.hasAttribute("formAction")){if(v=p,S=p[Yt]||null)C=S.formAction;else if(ev(v)!==null)continue}else C=S.action;typeof C=="function"?l[c+1]=C:(l.splice(c,3),c-=3),D_(l)}}}function R_(){function r(p){p.canIntercept&&p.info==="react-transition"&&p.intercept({handler:function(){return new Promise(function(S){return v=S})},focusReset:"manual",scroll:"manual"})}function i(){v!==null&&(v(),v=null),c||setTimeout(l,20)}function l(){if(!c&&!navigation.transition){var p=navigation.currentEntry;p&&p.url!=null&&navigation.navigate(p.url,{state:p.getState(),info:"react-transition",history:"replace"})}}if(typeof navigation=="object"){var c=!1,v=null;return navigation.addEventListener("navigate",r),navigation.addEventListener("navigatesuccess",i),navigation.addEventListener("navigateerror",i),setTimeout(l,100),function(){c=!0,navigation.removeEventListener("navigate",r),navigation.removeEventListener("navigatesuccess",i),navigation.removeEventListener("navigateerror",i),v!==null&&(v(),v=null)}}}function nv(r){this._internalRoot=r}Kc.prototype.render=nv.prototype.render=function(r){var i=this._internalRoot;if(i===null)throw Error(a(409));var l=i.current,c=cn();A_(l,c,r,i,null,null)},Kc.prototype.unmount=nv.prototype.unmount=function(){var r=this._internalRoot;if(r!==null){this._internalRoot=null;var i=r.containerInfo;A_(r.current,2,null,r,null,null),Ec(),i[ti]=null}};function Kc(r){this._internalRoot=r}Kc.prototype.unstable_scheduleHydration=function(r){if(r){var i=Ib();r={blockedOn:null,target:r,priority:i};for(var l=0;l<na.length&&i!==0&&i<na[l].priority;l++);na.splice(l,0,r),l===0&&C_(r)}};var P_=t.version;if(P_!=="19.2.4")throw Error(a(527,P_,"19.2.4"));I.findDOMNode=function(r){var i=r._reactInternals;if(i===void 0)throw typeof r.render=="function"?Error(a(188)):(r=Object.keys(r).join(","),Error(a(268,r)));return r=h(i),r=r!==null?m(r):null,r=r===null?null:r.stateNode,r};var WR={bundleType:0,version:"19.2.4",rendererPackageName:"react-dom",currentDispatcherRef:q,reconcilerVersion:"19.2.4"};if(typeof __REACT_DEVTOOLS_GLOBAL_HOOK__<"u"){var Vc=__REACT_DEVTOOLS_GLOBAL_HOOK__;if(!Vc.isDisabled&&Vc.supportsFiber)try{Mu=Vc.inject(WR),en=Vc}catch{}}return _l.createRoot=function(r,i){if(!u(r))throw Error(a(299));var l=!1,c="",v=kx,p=Hx,S=Ix;return i!=null&&(i.unstable_strictMode===!0&&(l=!0),i.identifierPrefix!==void 0&&(c=i.identifierPrefix),i.onUncaughtError!==void 0&&(v=i.onUncaughtError),i.onCaughtError!==void 0&&(p=i.onCaughtError),i.onRecoverableError!==void 0&&(S=i.onRecoverableError)),i=__(r,1,!1,null,null,l,c,null,v,p,S,R_),r[ti]=i.current,$h(r),new nv(i)},_l.hydrateRoot=function(r,i,l){if(!u(r))throw Error(a(299));var c=!1,v="",p=kx,S=Hx,C=Ix,z=null;return l!=null&&(l.unstable_strictMode===!0&&(c=!0),l.identifierPrefix!==void 0&&(v=l.identifierPrefix),l.onUncaughtError!==void 0&&(p=l.onUncaughtError),l.onCaughtError!==void 0&&(S=l.onCaughtError),l.onRecoverableError!==void 0&&(C=l.onRecoverableError),l.formState!==void 0&&(z=l.formState)),i=__(r,1,!0,i,l??null,c,v,z,p,S,C,R_),i.context=O_(null),l=i.current,c=cn(),c=Xf(c),v=kr(c),v.callback=null,Hr(l,v,c),l=c,i.current.lanes=l,Nu(i,l),Yn(i),r[ti]=i.current,$h(r),new Kc(i)},_l.version="19.2.4",_l}var G_;function oP(){if(G_)return iv.exports;G_=1;function e(){if(!(typeof __REACT_DEVTOOLS_GLOBAL_HOOK__>"u"||typeof __REACT_DEVTOOLS_GLOBAL_HOOK__.checkDCE!="function"))try{__REACT_DEVTOOLS_GLOBAL_HOOK__.checkDCE(e)}catch(t){console.error(t)}}return e(),iv.exports=lP(),iv.exports}var cP=oP();const sP=window.location.origin;async function Ol(e,t){const n=new URL(e,sP);if(t)for(const[u,o]of Object.entries(t))o!==void 0&&n.searchParams.set(u,String(o));const a=await fetch(n.toString());if(!a.ok)throw new Error(`${a.status} ${a.statusText}`);return a.json()}const dg={summary:()=>Ol("/api/summary"),sessions:e=>Ol("/api/sessions",e),stats:e=>Ol("/api/stats",e),events:e=>Vge(e),errors:e=>Ol("/api/errors",e)};async function Vge(e){const t=[];for(;;){const n=await Ol("/api/events",{...e,offset:t.length});if(t.push(...n.events),n.events.length===0||t.length>=n.total)return t}}function fP(e){const t=e.getFullYear(),n=String(e.getMonth()+1).padStart(2,"0"),a=String(e.getDate()).padStart(2,"0");return`${t}-${n}-${a}`}function dP(e){if(e==="all")return{};const t=e==="24h"?1:e==="7d"?7:30,n=new Date;return n.setDate(n.getDate()-t),{since:fP(n)}}function hg(e,t=[]){const[n,a]=J.useState(null),[u,o]=J.useState(!0),[s,f]=J.useState(null),d=J.useCallback(()=>{o(!0),f(null),e().then(a).catch(h=>f(h.message)).finally(()=>o(!1))},t);return J.useEffect(()=>{d()},[d]),{data:n,loading:u,error:s,refetch:d}}const Y_=200;function hP(){const[e,t]=J.useState([]),n=J.useRef(!0),a=J.useRef(null),u=J.useRef(null),o=J.useCallback(()=>{if(!n.current)return;const s=`${window.location.origin}/api/events/stream`,f=new EventSource(s);a.current=f,f.onmessage=d=>{try{const h=JSON.parse(d.data);t(m=>{const y=[...m,h];return y.length>Y_?y.slice(-Y_):y})}catch{}},f.onerror=()=>{f.close(),a.current=null,n.current&&(u.current=setTimeout(o,3e3))}},[]);return J.useEffect(()=>(n.current=!0,o(),()=>{n.current=!1,u.current&&(clearTimeout(u.current),u.current=null),a.current&&(a.current.close(),a.current=null)}),[o]),e}let Li=0;function X_({initial:e,min:t,max:n,direction:a,invert:u}){const[o,s]=J.useState(e),f=J.useRef(!1),d=J.useRef(0),h=J.useRef(0),m=J.useCallback(y=>{y.preventDefault(),f.current=!0,Li++,d.current=a==="horizontal"?y.clientX:y.clientY,h.current=o,document.body.style.userSelect="none",queueMicrotask(()=>{Li>=2?document.body.style.cursor="move":Li===1&&(document.body.style.cursor=a==="horizontal"?"col-resize":"row-resize")})},[a,o]);return J.useEffect(()=>{const y=b=>{if(!f.current)return;const O=a==="horizontal"?b.clientX:b.clientY,_=u?d.current-O:O-d.current;s(Math.min(n,Math.max(t,h.current+_)))},g=()=>{f.current&&(f.current=!1,Li=Math.max(0,Li-1),Li===0&&(document.body.style.cursor="",document.body.style.userSelect=""))};return document.addEventListener("mousemove",y),document.addEventListener("mouseup",g),()=>{document.removeEventListener("mousemove",y),document.removeEventListener("mouseup",g)}},[a,t,n,u]),{size:o,onMouseDown:m}}const vP=["24h","7d","30d","all"];function pP({stats:e,sessionCount:t,range:n,onRangeChange:a,onStatClick:u}){const o=e==null?void 0:e.counts;return D.jsx("header",{className:"border-b border-gray-800 bg-gray-900/50 px-4 py-3",children:D.jsxs("div",{className:"flex items-center justify-between",children:[D.jsxs("div",{className:"flex items-center gap-3",children:[D.jsxs("svg",{width:"24",height:"24",viewBox:"0 0 64 64",className:"shrink-0",children:[D.jsx("polygon",{points:"8,10 56,10 32,54",fill:"none",stroke:"#e8e8f4",strokeWidth:"2.5",strokeLinejoin:"round"}),D.jsx("line",{x1:"16",y1:"29",x2:"50",y2:"29",stroke:"#f59e0b",strokeWidth:"2"})]}),D.jsx("h1",{className:"text-lg font-bold text-gray-100",children:"vigilo"}),D.jsx("span",{className:"text-xs text-gray-500",children:"dashboard"})]}),o&&D.jsxs("div",{className:"flex items-center gap-2",children:[D.jsx(Fc,{label:"Sessions",value:String(t),onClick:()=>u==null?void 0:u("sessions")}),D.jsx(Fc,{label:"Calls",value:o.total.toLocaleString(),onClick:()=>u==null?void 0:u("calls")}),D.jsx(Fc,{label:"Cost",value:o.cost_usd>0?`$${o.cost_usd.toFixed(2)}`:"$0.00",color:"text-emerald-400",onClick:()=>u==null?void 0:u("cost")}),D.jsx(Fc,{label:"Errors",value:String(o.errors),color:o.errors>0?"text-red-400":"text-gray-500",onClick:()=>u==null?void 0:u("errors")})]}),D.jsx("div",{className:"flex items-center gap-1 bg-gray-800 rounded p-0.5",children:vP.map(s=>D.jsx("button",{onClick:()=>a(s),className:`px-2.5 py-1 text-xs rounded transition-colors ${n===s?"bg-gray-700 text-gray-100":"text-gray-400 hover:text-gray-200"}`,children:s},s))})]})})}function Fc({label:e,value:t,color:n,onClick:a}){return D.jsxs("button",{onClick:a,className:"bg-gray-800/50 px-4 py-2 rounded hover:bg-gray-700/50 transition-colors text-left cursor-pointer",children:[D.jsx("div",{className:"text-[10px] text-gray-500 uppercase tracking-wider",children:e}),D.jsx("div",{className:`text-lg font-semibold ${n??"text-white"}`,children:t})]})}function yP(e){return e===0?"":e<.01?`$${e.toFixed(4)}`:`$${e.toFixed(2)}`}const mP=J.forwardRef(function({sessions:t,selectedSession:n,onSelect:a,collapsed:u,onToggle:o,width:s},f){return u?D.jsx("div",{className:"w-8 border-r border-gray-800 bg-gray-900/30 flex flex-col items-center pt-3",children:D.jsx("button",{onClick:o,className:"text-gray-500 hover:text-gray-300 text-xs",title:"Expand sessions",children:"»"})}):D.jsxs("aside",{ref:f,style:{width:s},className:"border-r border-gray-800 bg-gray-900/30 flex flex-col overflow-hidden shrink-0 transition-shadow duration-500",children:[D.jsxs("div",{className:"flex items-center justify-between px-3 py-2 border-b border-gray-800",children:[D.jsxs("span",{className:"text-xs font-semibold text-gray-400 uppercase tracking-wider",children:["Sessions ",t?`(${t.length})`:""]}),D.jsxs("div",{className:"flex gap-2",children:[n&&D.jsx("button",{onClick:()=>a(null),className:"text-xs text-gray-500 hover:text-gray-300",children:"clear"}),D.jsx("button",{onClick:o,className:"text-gray-500 hover:text-gray-300 text-xs",title:"Collapse",children:"«"})]})]}),D.jsxs("div",{className:"flex-1 overflow-y-auto",children:[D.jsx("button",{onClick:()=>a(null),className:`w-full text-left px-3 py-2 border-b border-gray-800/30 border-l-2 transition-colors text-xs ${n?"border-l-transparent hover:bg-gray-800/30 text-gray-400":"border-l-cyan-400 bg-gray-800/60 text-gray-200"}`,children:"All Sessions"}),!t||t.length===0?D.jsx("div",{className:"p-3 text-xs text-gray-600",children:"No sessions yet"}):t.slice().reverse().map(d=>{const h=n===d.id,m=d.session_ids&&d.session_ids.length>1,y=d.project&&d.project!=="—",g=[`Session: ${d.id.slice(0,8)}${m?` (+${d.session_ids.length-1} merged)`:""}`,y?`Project: ${d.project}`:null,d.branch?`Branch: ${d.branch}`:null,`Calls: ${d.call_count}`,d.cost_usd>0?`Cost: $${d.cost_usd.toFixed(4)}`:null,d.error_count>0?`Errors: ${d.error_count}`:null].filter(Boolean).join(`
 `);return D.jsxs("button",{onClick:()=>a(h?null:d.id),title:g,className:`w-full text-left px-3 py-2 border-b border-gray-800/30 border-l-2 transition-colors ${h?"border-l-cyan-400 bg-gray-800/60":"border-l-transparent hover:bg-gray-800/30"}`,children:[D.jsxs("div",{className:"flex items-center gap-1.5",children:[D.jsx(gP,{server:d.server}),y&&D.jsx("span",{className:"text-[11px] text-gray-300 truncate flex-1 font-medium",title:d.project,children:d.project}),m&&D.jsx("span",{className:"text-[9px] bg-gray-700 text-gray-300 px-1 rounded",title:`Merged ${d.session_ids.length} conversation segments`,children:d.session_ids.length})]}),D.jsxs("div",{className:"flex items-center gap-2 mt-0.5",children:[D.jsx("span",{className:"text-[10px] text-gray-500",children:d.date}),d.branch&&D.jsx("span",{className:"text-[10px] text-gray-600 truncate",title:d.branch,children:d.branch})]}),D.jsxs("div",{className:"flex items-center gap-2 mt-0.5 text-[10px]",children:[D.jsxs("span",{className:"text-gray-400",children:[d.call_count," calls"]}),d.cost_usd>0&&D.jsx("span",{className:"text-emerald-400/80",children:yP(d.cost_usd)}),d.error_count>0&&D.jsxs("span",{className:"text-red-400",children:[d.error_count," err"]})]})]},d.id)})]})]})});function gP({server:e}){const n={cursor:{cls:"bg-purple-900/60 text-purple-400",label:"CURSOR",title:"Cursor"},"claude-code":{cls:"bg-blue-900/60 text-blue-400",label:"CLAUDE",title:"Claude Code"},vigilo:{cls:"bg-emerald-900/60 text-emerald-400",label:"MCP",title:"Vigilo MCP Server"}}[e]??{cls:"bg-gray-700/60 text-gray-400",label:e.toUpperCase(),title:e};return D.jsx("span",{className:`text-[9px] font-bold px-1 py-0.5 rounded ${n.cls}`,title:n.title,children:n.label})}function C2(e){var t,n,a="";if(typeof e=="string"||typeof e=="number")a+=e;else if(typeof e=="object")if(Array.isArray(e)){var u=e.length;for(t=0;t<u;t++)e[t]&&(n=C2(e[t]))&&(a&&(a+=" "),a+=n)}else for(n in e)e[n]&&(a&&(a+=" "),a+=n);return a}function Be(){for(var e,t,n=0,a="",u=arguments.length;n<u;n++)(e=arguments[n])&&(t=C2(e))&&(a&&(a+=" "),a+=t);return a}var cv,K_;function Jt(){if(K_)return cv;K_=1;var e=Array.isArray;return cv=e,cv}var sv,V_;function N2(){if(V_)return sv;V_=1;var e=typeof Zc=="object"&&Zc&&Zc.Object===Object&&Zc;return sv=e,sv}var fv,Z_;function Qn(){if(Z_)return fv;Z_=1;var e=N2(),t=typeof self=="object"&&self&&self.Object===Object&&self,n=e||t||Function("return this")();return fv=n,fv}var dv,F_;function _o(){if(F_)return dv;F_=1;var e=Qn(),t=e.Symbol;return dv=t,dv}var hv,Q_;function bP(){if(Q_)return hv;Q_=1;var e=_o(),t=Object.prototype,n=t.hasOwnProperty,a=t.toString,u=e?e.toStringTag:void 0;function o(s){var f=n.call(s,u),d=s[u];try{s[u]=void 0;var h=!0}catch{}var m=a.call(s);return h&&(f?s[u]=d:delete s[u]),m}return hv=o,hv}var vv,W_;function xP(){if(W_)return vv;W_=1;var e=Object.prototype,t=e.toString;function n(a){return t.call(a)}return vv=n,vv}var pv,J_;function Mr(){if(J_)return pv;J_=1;var e=_o(),t=bP(),n=xP(),a="[object Null]",u="[object Undefined]",o=e?e.toStringTag:void 0;function s(f){return f==null?f===void 0?u:a:o&&o in Object(f)?t(f):n(f)}return pv=s,pv}var yv,eO;function Cr(){if(eO)return yv;eO=1;function e(t){return t!=null&&typeof t=="object"}return yv=e,yv}var mv,tO;function xu(){if(tO)return mv;tO=1;var e=Mr(),t=Cr(),n="[object Symbol]";function a(u){return typeof u=="symbol"||t(u)&&e(u)==n}return mv=a,mv}var gv,nO;function C0(){if(nO)return gv;nO=1;var e=Jt(),t=xu(),n=/\.|\[(?:[^[\]]*|(["'])(?:(?!\1)[^\\]|\\.)*?\1)\]/,a=/^\w*$/;function u(o,s){if(e(o))return!1;var f=typeof o;return f=="number"||f=="symbol"||f=="boolean"||o==null||t(o)?!0:a.test(o)||!n.test(o)||s!=null&&o in Object(s)}return gv=u,gv}var bv,rO;function ca(){if(rO)return bv;rO=1;function e(t){var n=typeof t;return t!=null&&(n=="object"||n=="function")}return bv=e,bv}var xv,aO;function N0(){if(aO)return xv;aO=1;var e=Mr(),t=ca(),n="[object AsyncFunction]",a="[object Function]",u="[object GeneratorFunction]",o="[object Proxy]";function s(f){if(!t(f))return!1;var d=e(f);return d==a||d==u||d==n||d==o}return xv=s,xv}var Sv,iO;function SP(){if(iO)return Sv;iO=1;var e=Qn(),t=e["__core-js_shared__"];return Sv=t,Sv}var _v,uO;function _P(){if(uO)return _v;uO=1;var e=SP(),t=(function(){var a=/[^.]+$/.exec(e&&e.keys&&e.keys.IE_PROTO||"");return a?"Symbol(src)_1."+a:""})();function n(a){return!!t&&t in a}return _v=n,_v}var Ov,lO;function D2(){if(lO)return Ov;lO=1;var e=Function.prototype,t=e.toString;function n(a){if(a!=null){try{return t.call(a)}catch{}try{return a+""}catch{}}return""}return Ov=n,Ov}var Av,oO;function OP(){if(oO)return Av;oO=1;var e=N0(),t=_P(),n=ca(),a=D2(),u=/[\\^$.*+?()[\]{}|]/g,o=/^\[object .+?Constructor\]$/,s=Function.prototype,f=Object.prototype,d=s.toString,h=f.hasOwnProperty,m=RegExp("^"+d.call(h).replace(u,"\\$&").replace(/hasOwnProperty|(function).*?(?=\\\()| for .+?(?=\\\])/g,"$1.*?")+"$");function y(g){if(!n(g)||t(g))return!1;var b=e(g)?m:o;return b.test(a(g))}return Av=y,Av}var wv,cO;function AP(){if(cO)return wv;cO=1;function e(t,n){return t==null?void 0:t[n]}return wv=e,wv}var Tv,sO;function Za(){if(sO)return Tv;sO=1;var e=OP(),t=AP();function n(a,u){var o=t(a,u);return e(o)?o:void 0}return Tv=n,Tv}var Ev,fO;function lf(){if(fO)return Ev;fO=1;var e=Za(),t=e(Object,"create");return Ev=t,Ev}var jv,dO;function wP(){if(dO)return jv;dO=1;var e=lf();function t(){this.__data__=e?e(null):{},this.size=0}return jv=t,jv}var Mv,hO;function TP(){if(hO)return Mv;hO=1;function e(t){var n=this.has(t)&&delete this.__data__[t];return this.size-=n?1:0,n}return Mv=e,Mv}var Cv,vO;function EP(){if(vO)return Cv;vO=1;var e=lf(),t="__lodash_hash_undefined__",n=Object.prototype,a=n.hasOwnProperty;function u(o){var s=this.__data__;if(e){var f=s[o];return f===t?void 0:f}return a.call(s,o)?s[o]:void 0}return Cv=u,Cv}var Nv,pO;function jP(){if(pO)return Nv;pO=1;var e=lf(),t=Object.prototype,n=t.hasOwnProperty;function a(u){var o=this.__data__;return e?o[u]!==void 0:n.call(o,u)}return Nv=a,Nv}var Dv,yO;function MP(){if(yO)return Dv;yO=1;var e=lf(),t="__lodash_hash_undefined__";function n(a,u){var o=this.__data__;return this.size+=this.has(a)?0:1,o[a]=e&&u===void 0?t:u,this}return Dv=n,Dv}var Rv,mO;function CP(){if(mO)return Rv;mO=1;var e=wP(),t=TP(),n=EP(),a=jP(),u=MP();function o(s){var f=-1,d=s==null?0:s.length;for(this.clear();++f<d;){var h=s[f];this.set(h[0],h[1])}}return o.prototype.clear=e,o.prototype.delete=t,o.prototype.get=n,o.prototype.has=a,o.prototype.set=u,Rv=o,Rv}var Pv,gO;function NP(){if(gO)return Pv;gO=1;function e(){this.__data__=[],this.size=0}return Pv=e,Pv}var qv,bO;function D0(){if(bO)return qv;bO=1;function e(t,n){return t===n||t!==t&&n!==n}return qv=e,qv}var zv,xO;function of(){if(xO)return zv;xO=1;var e=D0();function t(n,a){for(var u=n.length;u--;)if(e(n[u][0],a))return u;return-1}return zv=t,zv}var $v,SO;function DP(){if(SO)return $v;SO=1;var e=of(),t=Array.prototype,n=t.splice;function a(u){var o=this.__data__,s=e(o,u);if(s<0)return!1;var f=o.length-1;return s==f?o.pop():n.call(o,s,1),--this.size,!0}return $v=a,$v}var Bv,_O;function RP(){if(_O)return Bv;_O=1;var e=of();function t(n){var a=this.__data__,u=e(a,n);return u<0?void 0:a[u][1]}return Bv=t,Bv}var Lv,OO;function PP(){if(OO)return Lv;OO=1;var e=of();function t(n){return e(this.__data__,n)>-1}return Lv=t,Lv}var Uv,AO;function qP(){if(AO)return Uv;AO=1;var e=of();function t(n,a){var u=this.__data__,o=e(u,n);return o<0?(++this.size,u.push([n,a])):u[o][1]=a,this}return Uv=t,Uv}var kv,wO;function cf(){if(wO)return kv;wO=1;var e=NP(),t=DP(),n=RP(),a=PP(),u=qP();function o(s){var f=-1,d=s==null?0:s.length;for(this.clear();++f<d;){var h=s[f];this.set(h[0],h[1])}}return o.prototype.clear=e,o.prototype.delete=t,o.prototype.get=n,o.prototype.has=a,o.prototype.set=u,kv=o,kv}var Hv,TO;function R0(){if(TO)return Hv;TO=1;var e=Za(),t=Qn(),n=e(t,"Map");return Hv=n,Hv}var Iv,EO;function zP(){if(EO)return Iv;EO=1;var e=CP(),t=cf(),n=R0();function a(){this.size=0,this.__data__={hash:new e,map:new(n||t),string:new e}}return Iv=a,Iv}var Gv,jO;function $P(){if(jO)return Gv;jO=1;function e(t){var n=typeof t;return n=="string"||n=="number"||n=="symbol"||n=="boolean"?t!=="__proto__":t===null}return Gv=e,Gv}var Yv,MO;function sf(){if(MO)return Yv;MO=1;var e=$P();function t(n,a){var u=n.__data__;return e(a)?u[typeof a=="string"?"string":"hash"]:u.map}return Yv=t,Yv}var Xv,CO;function BP(){if(CO)return Xv;CO=1;var e=sf();function t(n){var a=e(this,n).delete(n);return this.size-=a?1:0,a}return Xv=t,Xv}var Kv,NO;function LP(){if(NO)return Kv;NO=1;var e=sf();function t(n){return e(this,n).get(n)}return Kv=t,Kv}var Vv,DO;function UP(){if(DO)return Vv;DO=1;var e=sf();function t(n){return e(this,n).has(n)}return Vv=t,Vv}var Zv,RO;function kP(){if(RO)return Zv;RO=1;var e=sf();function t(n,a){var u=e(this,n),o=u.size;return u.set(n,a),this.size+=u.size==o?0:1,this}return Zv=t,Zv}var Fv,PO;function P0(){if(PO)return Fv;PO=1;var e=zP(),t=BP(),n=LP(),a=UP(),u=kP();function o(s){var f=-1,d=s==null?0:s.length;for(this.clear();++f<d;){var h=s[f];this.set(h[0],h[1])}}return o.prototype.clear=e,o.prototype.delete=t,o.prototype.get=n,o.prototype.has=a,o.prototype.set=u,Fv=o,Fv}var Qv,qO;function R2(){if(qO)return Qv;qO=1;var e=P0(),t="Expected a function";function n(a,u){if(typeof a!="function"||u!=null&&typeof u!="function")throw new TypeError(t);var o=function(){var s=arguments,f=u?u.apply(this,s):s[0],d=o.cache;if(d.has(f))return d.get(f);var h=a.apply(this,s);return o.cache=d.set(f,h)||d,h};return o.cache=new(n.Cache||e),o}return n.Cache=e,Qv=n,Qv}var Wv,zO;function HP(){if(zO)return Wv;zO=1;var e=R2(),t=500;function n(a){var u=e(a,function(s){return o.size===t&&o.clear(),s}),o=u.cache;return u}return Wv=n,Wv}var Jv,$O;function IP(){if($O)return Jv;$O=1;var e=HP(),t=/[^.[\]]+|\[(?:(-?\d+(?:\.\d+)?)|(["'])((?:(?!\2)[^\\]|\\.)*?)\2)\]|(?=(?:\.|\[\])(?:\.|\[\]|$))/g,n=/\\(\\)?/g,a=e(function(u){var o=[];return u.charCodeAt(0)===46&&o.push(""),u.replace(t,function(s,f,d,h){o.push(d?h.replace(n,"$1"):f||s)}),o});return Jv=a,Jv}var ep,BO;function q0(){if(BO)return ep;BO=1;function e(t,n){for(var a=-1,u=t==null?0:t.length,o=Array(u);++a<u;)o[a]=n(t[a],a,t);return o}return ep=e,ep}var tp,LO;function GP(){if(LO)return tp;LO=1;var e=_o(),t=q0(),n=Jt(),a=xu(),u=e?e.prototype:void 0,o=u?u.toString:void 0;function s(f){if(typeof f=="string")return f;if(n(f))return t(f,s)+"";if(a(f))return o?o.call(f):"";var d=f+"";return d=="0"&&1/f==-1/0?"-0":d}return tp=s,tp}var np,UO;function P2(){if(UO)return np;UO=1;var e=GP();function t(n){return n==null?"":e(n)}return np=t,np}var rp,kO;function q2(){if(kO)return rp;kO=1;var e=Jt(),t=C0(),n=IP(),a=P2();function u(o,s){return e(o)?o:t(o,s)?[o]:n(a(o))}return rp=u,rp}var ap,HO;function ff(){if(HO)return ap;HO=1;var e=xu();function t(n){if(typeof n=="string"||e(n))return n;var a=n+"";return a=="0"&&1/n==-1/0?"-0":a}return ap=t,ap}var ip,IO;function z0(){if(IO)return ip;IO=1;var e=q2(),t=ff();function n(a,u){u=e(u,a);for(var o=0,s=u.length;a!=null&&o<s;)a=a[t(u[o++])];return o&&o==s?a:void 0}return ip=n,ip}var up,GO;function z2(){if(GO)return up;GO=1;var e=z0();function t(n,a,u){var o=n==null?void 0:e(n,a);return o===void 0?u:o}return up=t,up}var YP=z2();const En=Qe(YP);var lp,YO;function XP(){if(YO)return lp;YO=1;function e(t){return t==null}return lp=e,lp}var KP=XP();const Le=Qe(KP);var op,XO;function VP(){if(XO)return op;XO=1;var e=Mr(),t=Jt(),n=Cr(),a="[object String]";function u(o){return typeof o=="string"||!t(o)&&n(o)&&e(o)==a}return op=u,op}var ZP=VP();const Ga=Qe(ZP);var FP=N0();const Ee=Qe(FP);var QP=ca();const Su=Qe(QP);var cp={exports:{}},ke={};/**
  * @license React
  * react-is.production.min.js
@@ -114,4 +114,4 @@ In order to be iterable, non-array objects must have a [Symbol.iterator]() metho
 In order to be iterable, non-array objects must have a [Symbol.iterator]() method.`)}function UH(e,t){if(e){if(typeof e=="string")return o2(e,t);var n=Object.prototype.toString.call(e).slice(8,-1);if(n==="Object"&&e.constructor&&(n=e.constructor.name),n==="Map"||n==="Set")return Array.from(e);if(n==="Arguments"||/^(?:Ui|I)nt(?:8|16|32)(?:Clamped)?Array$/.test(n))return o2(e,t)}}function o2(e,t){(t==null||t>e.length)&&(t=e.length);for(var n=0,a=new Array(t);n<t;n++)a[n]=e[n];return a}function kH(e,t){var n=e==null?null:typeof Symbol<"u"&&e[Symbol.iterator]||e["@@iterator"];if(n!=null){var a,u,o,s,f=[],d=!0,h=!1;try{if(o=(n=n.call(e)).next,t!==0)for(;!(d=(a=o.call(n)).done)&&(f.push(a.value),f.length!==t);d=!0);}catch(m){h=!0,u=m}finally{try{if(!d&&n.return!=null&&(s=n.return(),Object(s)!==s))return}finally{if(h)throw u}}return f}}function HH(e){if(Array.isArray(e))return e}function v0(){return v0=Object.assign?Object.assign.bind():function(e){for(var t=1;t<arguments.length;t++){var n=arguments[t];for(var a in n)Object.prototype.hasOwnProperty.call(n,a)&&(e[a]=n[a])}return e},v0.apply(this,arguments)}var IH=function(t,n){var a;return G.isValidElement(t)?a=G.cloneElement(t,n):Ee(t)?a=t(n):a=G.createElement("line",v0({},n,{className:"recharts-reference-line-line"})),a},GH=function(t,n,a,u,o,s,f,d,h){var m=o.x,y=o.y,g=o.width,b=o.height;if(a){var O=h.y,_=t.y.apply(O,{position:s});if(Vn(h,"discard")&&!t.y.isInRange(_))return null;var x=[{x:m+g,y:_},{x:m,y:_}];return d==="left"?x.reverse():x}if(n){var T=h.x,j=t.x.apply(T,{position:s});if(Vn(h,"discard")&&!t.x.isInRange(j))return null;var M=[{x:j,y:y+b},{x:j,y}];return f==="top"?M.reverse():M}if(u){var N=h.segment,A=N.map(function(w){return t.apply(w,{position:s})});return Vn(h,"discard")&&z9(A,function(w){return!t.isInRange(w)})?null:A}return null};function YH(e){var t=e.x,n=e.y,a=e.segment,u=e.xAxisId,o=e.yAxisId,s=e.shape,f=e.className,d=e.alwaysShow,h=wH(),m=sN(u),y=fN(o),g=jH();if(!h||!g)return null;Ar(d===void 0,'The alwaysShow prop is deprecated. Please use ifOverflow="extendDomain" instead.');var b=wb({x:m.scale,y:y.scale}),O=St(t),_=St(n),x=a&&a.length===2,T=GH(b,O,_,x,g,e.position,m.orientation,y.orientation,e);if(!T)return null;var j=BH(T,2),M=j[0],N=M.x,A=M.y,w=j[1],E=w.x,P=w.y,B=Vn(e,"hidden")?"url(#".concat(h,")"):void 0,W=l2(l2({clipPath:B},$e(e,!0)),{},{x1:N,y1:A,x2:E,y2:P});return G.createElement(Et,{className:Be("recharts-reference-line",f)},IH(s,W),kt.renderCallByParent(e,hH({x1:N,y1:A,x2:E,y2:P})))}var Nb=(function(e){function t(){return CH(this,t),RH(this,t,arguments)}return zH(t,e),DH(t,[{key:"render",value:function(){return G.createElement(YH,this.props)}}])})(G.Component);Cb(Nb,"displayName","ReferenceLine");Cb(Nb,"defaultProps",{isFront:!1,ifOverflow:"discard",xAxisId:0,yAxisId:0,fill:"none",stroke:"#ccc",fillOpacity:1,strokeWidth:1,position:"middle"});function p0(){return p0=Object.assign?Object.assign.bind():function(e){for(var t=1;t<arguments.length;t++){var n=arguments[t];for(var a in n)Object.prototype.hasOwnProperty.call(n,a)&&(e[a]=n[a])}return e},p0.apply(this,arguments)}function hu(e){"@babel/helpers - typeof";return hu=typeof Symbol=="function"&&typeof Symbol.iterator=="symbol"?function(t){return typeof t}:function(t){return t&&typeof Symbol=="function"&&t.constructor===Symbol&&t!==Symbol.prototype?"symbol":typeof t},hu(e)}function c2(e,t){var n=Object.keys(e);if(Object.getOwnPropertySymbols){var a=Object.getOwnPropertySymbols(e);t&&(a=a.filter(function(u){return Object.getOwnPropertyDescriptor(e,u).enumerable})),n.push.apply(n,a)}return n}function s2(e){for(var t=1;t<arguments.length;t++){var n=arguments[t]!=null?arguments[t]:{};t%2?c2(Object(n),!0).forEach(function(a){Pf(e,a,n[a])}):Object.getOwnPropertyDescriptors?Object.defineProperties(e,Object.getOwnPropertyDescriptors(n)):c2(Object(n)).forEach(function(a){Object.defineProperty(e,a,Object.getOwnPropertyDescriptor(n,a))})}return e}function XH(e,t){if(!(e instanceof t))throw new TypeError("Cannot call a class as a function")}function KH(e,t){for(var n=0;n<t.length;n++){var a=t[n];a.enumerable=a.enumerable||!1,a.configurable=!0,"value"in a&&(a.writable=!0),Object.defineProperty(e,pN(a.key),a)}}function VH(e,t,n){return t&&KH(e.prototype,t),Object.defineProperty(e,"prototype",{writable:!1}),e}function ZH(e,t,n){return t=Js(t),FH(e,vN()?Reflect.construct(t,n||[],Js(e).constructor):t.apply(e,n))}function FH(e,t){if(t&&(hu(t)==="object"||typeof t=="function"))return t;if(t!==void 0)throw new TypeError("Derived constructors may only return object or undefined");return QH(e)}function QH(e){if(e===void 0)throw new ReferenceError("this hasn't been initialised - super() hasn't been called");return e}function vN(){try{var e=!Boolean.prototype.valueOf.call(Reflect.construct(Boolean,[],function(){}))}catch{}return(vN=function(){return!!e})()}function Js(e){return Js=Object.setPrototypeOf?Object.getPrototypeOf.bind():function(n){return n.__proto__||Object.getPrototypeOf(n)},Js(e)}function WH(e,t){if(typeof t!="function"&&t!==null)throw new TypeError("Super expression must either be null or a function");e.prototype=Object.create(t&&t.prototype,{constructor:{value:e,writable:!0,configurable:!0}}),Object.defineProperty(e,"prototype",{writable:!1}),t&&y0(e,t)}function y0(e,t){return y0=Object.setPrototypeOf?Object.setPrototypeOf.bind():function(a,u){return a.__proto__=u,a},y0(e,t)}function Pf(e,t,n){return t=pN(t),t in e?Object.defineProperty(e,t,{value:n,enumerable:!0,configurable:!0,writable:!0}):e[t]=n,e}function pN(e){var t=JH(e,"string");return hu(t)=="symbol"?t:t+""}function JH(e,t){if(hu(e)!="object"||!e)return e;var n=e[Symbol.toPrimitive];if(n!==void 0){var a=n.call(e,t);if(hu(a)!="object")return a;throw new TypeError("@@toPrimitive must return a primitive value.")}return String(e)}var e7=function(t){var n=t.x,a=t.y,u=t.xAxis,o=t.yAxis,s=wb({x:u.scale,y:o.scale}),f=s.apply({x:n,y:a},{bandAware:!0});return Vn(t,"discard")&&!s.isInRange(f)?null:f},qf=(function(e){function t(){return XH(this,t),ZH(this,t,arguments)}return WH(t,e),VH(t,[{key:"render",value:function(){var a=this.props,u=a.x,o=a.y,s=a.r,f=a.alwaysShow,d=a.clipPathId,h=St(u),m=St(o);if(Ar(f===void 0,'The alwaysShow prop is deprecated. Please use ifOverflow="extendDomain" instead.'),!h||!m)return null;var y=e7(this.props);if(!y)return null;var g=y.x,b=y.y,O=this.props,_=O.shape,x=O.className,T=Vn(this.props,"hidden")?"url(#".concat(d,")"):void 0,j=s2(s2({clipPath:T},$e(this.props,!0)),{},{cx:g,cy:b});return G.createElement(Et,{className:Be("recharts-reference-dot",x)},t.renderDot(_,j),kt.renderCallByParent(this.props,{x:g-s,y:b-s,width:2*s,height:2*s}))}}])})(G.Component);Pf(qf,"displayName","ReferenceDot");Pf(qf,"defaultProps",{isFront:!1,ifOverflow:"discard",xAxisId:0,yAxisId:0,r:10,fill:"#fff",stroke:"#ccc",fillOpacity:1,strokeWidth:1});Pf(qf,"renderDot",function(e,t){var n;return G.isValidElement(e)?n=G.cloneElement(e,t):Ee(e)?n=e(t):n=G.createElement(KC,p0({},t,{cx:t.cx,cy:t.cy,className:"recharts-reference-dot-dot"})),n});function m0(){return m0=Object.assign?Object.assign.bind():function(e){for(var t=1;t<arguments.length;t++){var n=arguments[t];for(var a in n)Object.prototype.hasOwnProperty.call(n,a)&&(e[a]=n[a])}return e},m0.apply(this,arguments)}function vu(e){"@babel/helpers - typeof";return vu=typeof Symbol=="function"&&typeof Symbol.iterator=="symbol"?function(t){return typeof t}:function(t){return t&&typeof Symbol=="function"&&t.constructor===Symbol&&t!==Symbol.prototype?"symbol":typeof t},vu(e)}function f2(e,t){var n=Object.keys(e);if(Object.getOwnPropertySymbols){var a=Object.getOwnPropertySymbols(e);t&&(a=a.filter(function(u){return Object.getOwnPropertyDescriptor(e,u).enumerable})),n.push.apply(n,a)}return n}function d2(e){for(var t=1;t<arguments.length;t++){var n=arguments[t]!=null?arguments[t]:{};t%2?f2(Object(n),!0).forEach(function(a){zf(e,a,n[a])}):Object.getOwnPropertyDescriptors?Object.defineProperties(e,Object.getOwnPropertyDescriptors(n)):f2(Object(n)).forEach(function(a){Object.defineProperty(e,a,Object.getOwnPropertyDescriptor(n,a))})}return e}function t7(e,t){if(!(e instanceof t))throw new TypeError("Cannot call a class as a function")}function n7(e,t){for(var n=0;n<t.length;n++){var a=t[n];a.enumerable=a.enumerable||!1,a.configurable=!0,"value"in a&&(a.writable=!0),Object.defineProperty(e,mN(a.key),a)}}function r7(e,t,n){return t&&n7(e.prototype,t),Object.defineProperty(e,"prototype",{writable:!1}),e}function a7(e,t,n){return t=ef(t),i7(e,yN()?Reflect.construct(t,n||[],ef(e).constructor):t.apply(e,n))}function i7(e,t){if(t&&(vu(t)==="object"||typeof t=="function"))return t;if(t!==void 0)throw new TypeError("Derived constructors may only return object or undefined");return u7(e)}function u7(e){if(e===void 0)throw new ReferenceError("this hasn't been initialised - super() hasn't been called");return e}function yN(){try{var e=!Boolean.prototype.valueOf.call(Reflect.construct(Boolean,[],function(){}))}catch{}return(yN=function(){return!!e})()}function ef(e){return ef=Object.setPrototypeOf?Object.getPrototypeOf.bind():function(n){return n.__proto__||Object.getPrototypeOf(n)},ef(e)}function l7(e,t){if(typeof t!="function"&&t!==null)throw new TypeError("Super expression must either be null or a function");e.prototype=Object.create(t&&t.prototype,{constructor:{value:e,writable:!0,configurable:!0}}),Object.defineProperty(e,"prototype",{writable:!1}),t&&g0(e,t)}function g0(e,t){return g0=Object.setPrototypeOf?Object.setPrototypeOf.bind():function(a,u){return a.__proto__=u,a},g0(e,t)}function zf(e,t,n){return t=mN(t),t in e?Object.defineProperty(e,t,{value:n,enumerable:!0,configurable:!0,writable:!0}):e[t]=n,e}function mN(e){var t=o7(e,"string");return vu(t)=="symbol"?t:t+""}function o7(e,t){if(vu(e)!="object"||!e)return e;var n=e[Symbol.toPrimitive];if(n!==void 0){var a=n.call(e,t);if(vu(a)!="object")return a;throw new TypeError("@@toPrimitive must return a primitive value.")}return String(e)}var c7=function(t,n,a,u,o){var s=o.x1,f=o.x2,d=o.y1,h=o.y2,m=o.xAxis,y=o.yAxis;if(!m||!y)return null;var g=wb({x:m.scale,y:y.scale}),b={x:t?g.x.apply(s,{position:"start"}):g.x.rangeMin,y:a?g.y.apply(d,{position:"start"}):g.y.rangeMin},O={x:n?g.x.apply(f,{position:"end"}):g.x.rangeMax,y:u?g.y.apply(h,{position:"end"}):g.y.rangeMax};return Vn(o,"discard")&&(!g.isInRange(b)||!g.isInRange(O))?null:rN(b,O)},$f=(function(e){function t(){return t7(this,t),a7(this,t,arguments)}return l7(t,e),r7(t,[{key:"render",value:function(){var a=this.props,u=a.x1,o=a.x2,s=a.y1,f=a.y2,d=a.className,h=a.alwaysShow,m=a.clipPathId;Ar(h===void 0,'The alwaysShow prop is deprecated. Please use ifOverflow="extendDomain" instead.');var y=St(u),g=St(o),b=St(s),O=St(f),_=this.props.shape;if(!y&&!g&&!b&&!O&&!_)return null;var x=c7(y,g,b,O,this.props);if(!x&&!_)return null;var T=Vn(this.props,"hidden")?"url(#".concat(m,")"):void 0;return G.createElement(Et,{className:Be("recharts-reference-area",d)},t.renderRect(_,d2(d2({clipPath:T},$e(this.props,!0)),x)),kt.renderCallByParent(this.props,x))}}])})(G.Component);zf($f,"displayName","ReferenceArea");zf($f,"defaultProps",{isFront:!1,ifOverflow:"discard",xAxisId:0,yAxisId:0,r:10,fill:"#ccc",fillOpacity:.5,stroke:"none",strokeWidth:1});zf($f,"renderRect",function(e,t){var n;return G.isValidElement(e)?n=G.cloneElement(e,t):Ee(e)?n=e(t):n=G.createElement(Ab,m0({},t,{className:"recharts-reference-area-rect"})),n});function gN(e,t,n){if(t<1)return[];if(t===1&&n===void 0)return e;for(var a=[],u=0;u<e.length;u+=t)a.push(e[u]);return a}function s7(e,t,n){var a={width:e.width+t.width,height:e.height+t.height};return pH(a,n)}function f7(e,t,n){var a=n==="width",u=e.x,o=e.y,s=e.width,f=e.height;return t===1?{start:a?u:o,end:a?u+s:o+f}:{start:a?u+s:o+f,end:a?u:o}}function tf(e,t,n,a,u){if(e*t<e*a||e*t>e*u)return!1;var o=n();return e*(t-e*o/2-a)>=0&&e*(t+e*o/2-u)<=0}function d7(e,t){return gN(e,t+1)}function h7(e,t,n,a,u){for(var o=(a||[]).slice(),s=t.start,f=t.end,d=0,h=1,m=s,y=function(){var O=a==null?void 0:a[d];if(O===void 0)return{v:gN(a,h)};var _=d,x,T=function(){return x===void 0&&(x=n(O,_)),x},j=O.coordinate,M=d===0||tf(e,j,T,m,f);M||(d=0,m=s,h+=1),M&&(m=j+e*(T()/2+u),d+=h)},g;h<=o.length;)if(g=y(),g)return g.v;return[]}function bo(e){"@babel/helpers - typeof";return bo=typeof Symbol=="function"&&typeof Symbol.iterator=="symbol"?function(t){return typeof t}:function(t){return t&&typeof Symbol=="function"&&t.constructor===Symbol&&t!==Symbol.prototype?"symbol":typeof t},bo(e)}function h2(e,t){var n=Object.keys(e);if(Object.getOwnPropertySymbols){var a=Object.getOwnPropertySymbols(e);t&&(a=a.filter(function(u){return Object.getOwnPropertyDescriptor(e,u).enumerable})),n.push.apply(n,a)}return n}function Lt(e){for(var t=1;t<arguments.length;t++){var n=arguments[t]!=null?arguments[t]:{};t%2?h2(Object(n),!0).forEach(function(a){v7(e,a,n[a])}):Object.getOwnPropertyDescriptors?Object.defineProperties(e,Object.getOwnPropertyDescriptors(n)):h2(Object(n)).forEach(function(a){Object.defineProperty(e,a,Object.getOwnPropertyDescriptor(n,a))})}return e}function v7(e,t,n){return t=p7(t),t in e?Object.defineProperty(e,t,{value:n,enumerable:!0,configurable:!0,writable:!0}):e[t]=n,e}function p7(e){var t=y7(e,"string");return bo(t)=="symbol"?t:t+""}function y7(e,t){if(bo(e)!="object"||!e)return e;var n=e[Symbol.toPrimitive];if(n!==void 0){var a=n.call(e,t);if(bo(a)!="object")return a;throw new TypeError("@@toPrimitive must return a primitive value.")}return(t==="string"?String:Number)(e)}function m7(e,t,n,a,u){for(var o=(a||[]).slice(),s=o.length,f=t.start,d=t.end,h=function(g){var b=o[g],O,_=function(){return O===void 0&&(O=n(b,g)),O};if(g===s-1){var x=e*(b.coordinate+e*_()/2-d);o[g]=b=Lt(Lt({},b),{},{tickCoord:x>0?b.coordinate-x*e:b.coordinate})}else o[g]=b=Lt(Lt({},b),{},{tickCoord:b.coordinate});var T=tf(e,b.tickCoord,_,f,d);T&&(d=b.tickCoord-e*(_()/2+u),o[g]=Lt(Lt({},b),{},{isShow:!0}))},m=s-1;m>=0;m--)h(m);return o}function g7(e,t,n,a,u,o){var s=(a||[]).slice(),f=s.length,d=t.start,h=t.end;if(o){var m=a[f-1],y=n(m,f-1),g=e*(m.coordinate+e*y/2-h);s[f-1]=m=Lt(Lt({},m),{},{tickCoord:g>0?m.coordinate-g*e:m.coordinate});var b=tf(e,m.tickCoord,function(){return y},d,h);b&&(h=m.tickCoord-e*(y/2+u),s[f-1]=Lt(Lt({},m),{},{isShow:!0}))}for(var O=o?f-1:f,_=function(j){var M=s[j],N,A=function(){return N===void 0&&(N=n(M,j)),N};if(j===0){var w=e*(M.coordinate-e*A()/2-d);s[j]=M=Lt(Lt({},M),{},{tickCoord:w<0?M.coordinate-w*e:M.coordinate})}else s[j]=M=Lt(Lt({},M),{},{tickCoord:M.coordinate});var E=tf(e,M.tickCoord,A,d,h);E&&(d=M.tickCoord+e*(A()/2+u),s[j]=Lt(Lt({},M),{},{isShow:!0}))},x=0;x<O;x++)_(x);return s}function Db(e,t,n){var a=e.tick,u=e.ticks,o=e.viewBox,s=e.minTickGap,f=e.orientation,d=e.interval,h=e.tickFormatter,m=e.unit,y=e.angle;if(!u||!u.length||!a)return[];if(fe(d)||wo.isSsr)return d7(u,typeof d=="number"&&fe(d)?d:0);var g=[],b=f==="top"||f==="bottom"?"width":"height",O=m&&b==="width"?zl(m,{fontSize:t,letterSpacing:n}):{width:0,height:0},_=function(M,N){var A=Ee(h)?h(M.value,N):M.value;return b==="width"?s7(zl(A,{fontSize:t,letterSpacing:n}),O,y):zl(A,{fontSize:t,letterSpacing:n})[b]},x=u.length>=2?Bn(u[1].coordinate-u[0].coordinate):1,T=f7(o,x,b);return d==="equidistantPreserveStart"?h7(x,T,_,u,s):(d==="preserveStart"||d==="preserveStartEnd"?g=g7(x,T,_,u,s,d==="preserveStartEnd"):g=m7(x,T,_,u,s),g.filter(function(j){return j.isShow}))}var b7=["viewBox"],x7=["viewBox"],S7=["ticks"];function pu(e){"@babel/helpers - typeof";return pu=typeof Symbol=="function"&&typeof Symbol.iterator=="symbol"?function(t){return typeof t}:function(t){return t&&typeof Symbol=="function"&&t.constructor===Symbol&&t!==Symbol.prototype?"symbol":typeof t},pu(e)}function Xi(){return Xi=Object.assign?Object.assign.bind():function(e){for(var t=1;t<arguments.length;t++){var n=arguments[t];for(var a in n)Object.prototype.hasOwnProperty.call(n,a)&&(e[a]=n[a])}return e},Xi.apply(this,arguments)}function v2(e,t){var n=Object.keys(e);if(Object.getOwnPropertySymbols){var a=Object.getOwnPropertySymbols(e);t&&(a=a.filter(function(u){return Object.getOwnPropertyDescriptor(e,u).enumerable})),n.push.apply(n,a)}return n}function bt(e){for(var t=1;t<arguments.length;t++){var n=arguments[t]!=null?arguments[t]:{};t%2?v2(Object(n),!0).forEach(function(a){Rb(e,a,n[a])}):Object.getOwnPropertyDescriptors?Object.defineProperties(e,Object.getOwnPropertyDescriptors(n)):v2(Object(n)).forEach(function(a){Object.defineProperty(e,a,Object.getOwnPropertyDescriptor(n,a))})}return e}function ag(e,t){if(e==null)return{};var n=_7(e,t),a,u;if(Object.getOwnPropertySymbols){var o=Object.getOwnPropertySymbols(e);for(u=0;u<o.length;u++)a=o[u],!(t.indexOf(a)>=0)&&Object.prototype.propertyIsEnumerable.call(e,a)&&(n[a]=e[a])}return n}function _7(e,t){if(e==null)return{};var n={};for(var a in e)if(Object.prototype.hasOwnProperty.call(e,a)){if(t.indexOf(a)>=0)continue;n[a]=e[a]}return n}function O7(e,t){if(!(e instanceof t))throw new TypeError("Cannot call a class as a function")}function p2(e,t){for(var n=0;n<t.length;n++){var a=t[n];a.enumerable=a.enumerable||!1,a.configurable=!0,"value"in a&&(a.writable=!0),Object.defineProperty(e,xN(a.key),a)}}function A7(e,t,n){return t&&p2(e.prototype,t),n&&p2(e,n),Object.defineProperty(e,"prototype",{writable:!1}),e}function w7(e,t,n){return t=nf(t),T7(e,bN()?Reflect.construct(t,n||[],nf(e).constructor):t.apply(e,n))}function T7(e,t){if(t&&(pu(t)==="object"||typeof t=="function"))return t;if(t!==void 0)throw new TypeError("Derived constructors may only return object or undefined");return E7(e)}function E7(e){if(e===void 0)throw new ReferenceError("this hasn't been initialised - super() hasn't been called");return e}function bN(){try{var e=!Boolean.prototype.valueOf.call(Reflect.construct(Boolean,[],function(){}))}catch{}return(bN=function(){return!!e})()}function nf(e){return nf=Object.setPrototypeOf?Object.getPrototypeOf.bind():function(n){return n.__proto__||Object.getPrototypeOf(n)},nf(e)}function j7(e,t){if(typeof t!="function"&&t!==null)throw new TypeError("Super expression must either be null or a function");e.prototype=Object.create(t&&t.prototype,{constructor:{value:e,writable:!0,configurable:!0}}),Object.defineProperty(e,"prototype",{writable:!1}),t&&b0(e,t)}function b0(e,t){return b0=Object.setPrototypeOf?Object.setPrototypeOf.bind():function(a,u){return a.__proto__=u,a},b0(e,t)}function Rb(e,t,n){return t=xN(t),t in e?Object.defineProperty(e,t,{value:n,enumerable:!0,configurable:!0,writable:!0}):e[t]=n,e}function xN(e){var t=M7(e,"string");return pu(t)=="symbol"?t:t+""}function M7(e,t){if(pu(e)!="object"||!e)return e;var n=e[Symbol.toPrimitive];if(n!==void 0){var a=n.call(e,t);if(pu(a)!="object")return a;throw new TypeError("@@toPrimitive must return a primitive value.")}return String(e)}var Tu=(function(e){function t(n){var a;return O7(this,t),a=w7(this,t,[n]),a.state={fontSize:"",letterSpacing:""},a}return j7(t,e),A7(t,[{key:"shouldComponentUpdate",value:function(a,u){var o=a.viewBox,s=ag(a,b7),f=this.props,d=f.viewBox,h=ag(f,x7);return!Vi(o,d)||!Vi(s,h)||!Vi(u,this.state)}},{key:"componentDidMount",value:function(){var a=this.layerReference;if(a){var u=a.getElementsByClassName("recharts-cartesian-axis-tick-value")[0];u&&this.setState({fontSize:window.getComputedStyle(u).fontSize,letterSpacing:window.getComputedStyle(u).letterSpacing})}}},{key:"getTickLineCoord",value:function(a){var u=this.props,o=u.x,s=u.y,f=u.width,d=u.height,h=u.orientation,m=u.tickSize,y=u.mirror,g=u.tickMargin,b,O,_,x,T,j,M=y?-1:1,N=a.tickSize||m,A=fe(a.tickCoord)?a.tickCoord:a.coordinate;switch(h){case"top":b=O=a.coordinate,x=s+ +!y*d,_=x-M*N,j=_-M*g,T=A;break;case"left":_=x=a.coordinate,O=o+ +!y*f,b=O-M*N,T=b-M*g,j=A;break;case"right":_=x=a.coordinate,O=o+ +y*f,b=O+M*N,T=b+M*g,j=A;break;default:b=O=a.coordinate,x=s+ +y*d,_=x+M*N,j=_+M*g,T=A;break}return{line:{x1:b,y1:_,x2:O,y2:x},tick:{x:T,y:j}}}},{key:"getTickTextAnchor",value:function(){var a=this.props,u=a.orientation,o=a.mirror,s;switch(u){case"left":s=o?"start":"end";break;case"right":s=o?"end":"start";break;default:s="middle";break}return s}},{key:"getTickVerticalAnchor",value:function(){var a=this.props,u=a.orientation,o=a.mirror,s="end";switch(u){case"left":case"right":s="middle";break;case"top":s=o?"start":"end";break;default:s=o?"end":"start";break}return s}},{key:"renderAxisLine",value:function(){var a=this.props,u=a.x,o=a.y,s=a.width,f=a.height,d=a.orientation,h=a.mirror,m=a.axisLine,y=bt(bt(bt({},$e(this.props,!1)),$e(m,!1)),{},{fill:"none"});if(d==="top"||d==="bottom"){var g=+(d==="top"&&!h||d==="bottom"&&h);y=bt(bt({},y),{},{x1:u,y1:o+g*f,x2:u+s,y2:o+g*f})}else{var b=+(d==="left"&&!h||d==="right"&&h);y=bt(bt({},y),{},{x1:u+b*s,y1:o,x2:u+b*s,y2:o+f})}return G.createElement("line",Xi({},y,{className:Be("recharts-cartesian-axis-line",En(m,"className"))}))}},{key:"renderTicks",value:function(a,u,o){var s=this,f=this.props,d=f.tickLine,h=f.stroke,m=f.tick,y=f.tickFormatter,g=f.unit,b=Db(bt(bt({},this.props),{},{ticks:a}),u,o),O=this.getTickTextAnchor(),_=this.getTickVerticalAnchor(),x=$e(this.props,!1),T=$e(m,!1),j=bt(bt({},x),{},{fill:"none"},$e(d,!1)),M=b.map(function(N,A){var w=s.getTickLineCoord(N),E=w.line,P=w.tick,B=bt(bt(bt(bt({textAnchor:O,verticalAnchor:_},x),{},{stroke:"none",fill:h},T),P),{},{index:A,payload:N,visibleTicksCount:b.length,tickFormatter:y});return G.createElement(Et,Xi({className:"recharts-cartesian-axis-tick",key:"tick-".concat(N.value,"-").concat(N.coordinate,"-").concat(N.tickCoord)},fs(s.props,N,A)),d&&G.createElement("line",Xi({},j,E,{className:Be("recharts-cartesian-axis-tick-line",En(d,"className"))})),m&&t.renderTickItem(m,B,"".concat(Ee(y)?y(N.value,A):N.value).concat(g||"")))});return G.createElement("g",{className:"recharts-cartesian-axis-ticks"},M)}},{key:"render",value:function(){var a=this,u=this.props,o=u.axisLine,s=u.width,f=u.height,d=u.ticksGenerator,h=u.className,m=u.hide;if(m)return null;var y=this.props,g=y.ticks,b=ag(y,S7),O=g;return Ee(d)&&(O=g&&g.length>0?d(this.props):d(b)),s<=0||f<=0||!O||!O.length?null:G.createElement(Et,{className:Be("recharts-cartesian-axis",h),ref:function(x){a.layerReference=x}},o&&this.renderAxisLine(),this.renderTicks(O,this.state.fontSize,this.state.letterSpacing),kt.renderCallByParent(this.props))}}],[{key:"renderTickItem",value:function(a,u,o){var s,f=Be(u.className,"recharts-cartesian-axis-tick-value");return G.isValidElement(a)?s=G.cloneElement(a,bt(bt({},u),{},{className:f})):Ee(a)?s=a(bt(bt({},u),{},{className:f})):s=G.createElement(_s,Xi({},u,{className:"recharts-cartesian-axis-tick-value"}),o),s}}])})(J.Component);Rb(Tu,"displayName","CartesianAxis");Rb(Tu,"defaultProps",{x:0,y:0,width:0,height:0,viewBox:{x:0,y:0,width:0,height:0},orientation:"bottom",ticks:[],stroke:"#666",tickLine:!0,axisLine:!0,tick:!0,mirror:!1,minTickGap:5,tickSize:6,tickMargin:2,interval:"preserveEnd"});var C7=["x1","y1","x2","y2","key"],N7=["offset"];function Va(e){"@babel/helpers - typeof";return Va=typeof Symbol=="function"&&typeof Symbol.iterator=="symbol"?function(t){return typeof t}:function(t){return t&&typeof Symbol=="function"&&t.constructor===Symbol&&t!==Symbol.prototype?"symbol":typeof t},Va(e)}function y2(e,t){var n=Object.keys(e);if(Object.getOwnPropertySymbols){var a=Object.getOwnPropertySymbols(e);t&&(a=a.filter(function(u){return Object.getOwnPropertyDescriptor(e,u).enumerable})),n.push.apply(n,a)}return n}function Ht(e){for(var t=1;t<arguments.length;t++){var n=arguments[t]!=null?arguments[t]:{};t%2?y2(Object(n),!0).forEach(function(a){D7(e,a,n[a])}):Object.getOwnPropertyDescriptors?Object.defineProperties(e,Object.getOwnPropertyDescriptors(n)):y2(Object(n)).forEach(function(a){Object.defineProperty(e,a,Object.getOwnPropertyDescriptor(n,a))})}return e}function D7(e,t,n){return t=R7(t),t in e?Object.defineProperty(e,t,{value:n,enumerable:!0,configurable:!0,writable:!0}):e[t]=n,e}function R7(e){var t=P7(e,"string");return Va(t)=="symbol"?t:t+""}function P7(e,t){if(Va(e)!="object"||!e)return e;var n=e[Symbol.toPrimitive];if(n!==void 0){var a=n.call(e,t);if(Va(a)!="object")return a;throw new TypeError("@@toPrimitive must return a primitive value.")}return(t==="string"?String:Number)(e)}function Ua(){return Ua=Object.assign?Object.assign.bind():function(e){for(var t=1;t<arguments.length;t++){var n=arguments[t];for(var a in n)Object.prototype.hasOwnProperty.call(n,a)&&(e[a]=n[a])}return e},Ua.apply(this,arguments)}function m2(e,t){if(e==null)return{};var n=q7(e,t),a,u;if(Object.getOwnPropertySymbols){var o=Object.getOwnPropertySymbols(e);for(u=0;u<o.length;u++)a=o[u],!(t.indexOf(a)>=0)&&Object.prototype.propertyIsEnumerable.call(e,a)&&(n[a]=e[a])}return n}function q7(e,t){if(e==null)return{};var n={};for(var a in e)if(Object.prototype.hasOwnProperty.call(e,a)){if(t.indexOf(a)>=0)continue;n[a]=e[a]}return n}var z7=function(t){var n=t.fill;if(!n||n==="none")return null;var a=t.fillOpacity,u=t.x,o=t.y,s=t.width,f=t.height,d=t.ry;return G.createElement("rect",{x:u,y:o,ry:d,width:s,height:f,stroke:"none",fill:n,fillOpacity:a,className:"recharts-cartesian-grid-bg"})};function SN(e,t){var n;if(G.isValidElement(e))n=G.cloneElement(e,t);else if(Ee(e))n=e(t);else{var a=t.x1,u=t.y1,o=t.x2,s=t.y2,f=t.key,d=m2(t,C7),h=$e(d,!1);h.offset;var m=m2(h,N7);n=G.createElement("line",Ua({},m,{x1:a,y1:u,x2:o,y2:s,fill:"none",key:f}))}return n}function $7(e){var t=e.x,n=e.width,a=e.horizontal,u=a===void 0?!0:a,o=e.horizontalPoints;if(!u||!o||!o.length)return null;var s=o.map(function(f,d){var h=Ht(Ht({},e),{},{x1:t,y1:f,x2:t+n,y2:f,key:"line-".concat(d),index:d});return SN(u,h)});return G.createElement("g",{className:"recharts-cartesian-grid-horizontal"},s)}function B7(e){var t=e.y,n=e.height,a=e.vertical,u=a===void 0?!0:a,o=e.verticalPoints;if(!u||!o||!o.length)return null;var s=o.map(function(f,d){var h=Ht(Ht({},e),{},{x1:f,y1:t,x2:f,y2:t+n,key:"line-".concat(d),index:d});return SN(u,h)});return G.createElement("g",{className:"recharts-cartesian-grid-vertical"},s)}function L7(e){var t=e.horizontalFill,n=e.fillOpacity,a=e.x,u=e.y,o=e.width,s=e.height,f=e.horizontalPoints,d=e.horizontal,h=d===void 0?!0:d;if(!h||!t||!t.length)return null;var m=f.map(function(g){return Math.round(g+u-u)}).sort(function(g,b){return g-b});u!==m[0]&&m.unshift(0);var y=m.map(function(g,b){var O=!m[b+1],_=O?u+s-g:m[b+1]-g;if(_<=0)return null;var x=b%t.length;return G.createElement("rect",{key:"react-".concat(b),y:g,x:a,height:_,width:o,stroke:"none",fill:t[x],fillOpacity:n,className:"recharts-cartesian-grid-bg"})});return G.createElement("g",{className:"recharts-cartesian-gridstripes-horizontal"},y)}function U7(e){var t=e.vertical,n=t===void 0?!0:t,a=e.verticalFill,u=e.fillOpacity,o=e.x,s=e.y,f=e.width,d=e.height,h=e.verticalPoints;if(!n||!a||!a.length)return null;var m=h.map(function(g){return Math.round(g+o-o)}).sort(function(g,b){return g-b});o!==m[0]&&m.unshift(0);var y=m.map(function(g,b){var O=!m[b+1],_=O?o+f-g:m[b+1]-g;if(_<=0)return null;var x=b%a.length;return G.createElement("rect",{key:"react-".concat(b),x:g,y:s,width:_,height:d,stroke:"none",fill:a[x],fillOpacity:u,className:"recharts-cartesian-grid-bg"})});return G.createElement("g",{className:"recharts-cartesian-gridstripes-vertical"},y)}var k7=function(t,n){var a=t.xAxis,u=t.width,o=t.height,s=t.offset;return PC(Db(Ht(Ht(Ht({},Tu.defaultProps),a),{},{ticks:_r(a,!0),viewBox:{x:0,y:0,width:u,height:o}})),s.left,s.left+s.width,n)},H7=function(t,n){var a=t.yAxis,u=t.width,o=t.height,s=t.offset;return PC(Db(Ht(Ht(Ht({},Tu.defaultProps),a),{},{ticks:_r(a,!0),viewBox:{x:0,y:0,width:u,height:o}})),s.top,s.top+s.height,n)},Ii={horizontal:!0,vertical:!0,stroke:"#ccc",fill:"none",verticalFill:[],horizontalFill:[]};function va(e){var t,n,a,u,o,s,f=jb(),d=Mb(),h=MH(),m=Ht(Ht({},e),{},{stroke:(t=e.stroke)!==null&&t!==void 0?t:Ii.stroke,fill:(n=e.fill)!==null&&n!==void 0?n:Ii.fill,horizontal:(a=e.horizontal)!==null&&a!==void 0?a:Ii.horizontal,horizontalFill:(u=e.horizontalFill)!==null&&u!==void 0?u:Ii.horizontalFill,vertical:(o=e.vertical)!==null&&o!==void 0?o:Ii.vertical,verticalFill:(s=e.verticalFill)!==null&&s!==void 0?s:Ii.verticalFill,x:fe(e.x)?e.x:h.left,y:fe(e.y)?e.y:h.top,width:fe(e.width)?e.width:h.width,height:fe(e.height)?e.height:h.height}),y=m.x,g=m.y,b=m.width,O=m.height,_=m.syncWithTicks,x=m.horizontalValues,T=m.verticalValues,j=TH(),M=EH();if(!fe(b)||b<=0||!fe(O)||O<=0||!fe(y)||y!==+y||!fe(g)||g!==+g)return null;var N=m.verticalCoordinatesGenerator||k7,A=m.horizontalCoordinatesGenerator||H7,w=m.horizontalPoints,E=m.verticalPoints;if((!w||!w.length)&&Ee(A)){var P=x&&x.length,B=A({yAxis:M?Ht(Ht({},M),{},{ticks:P?x:M.ticks}):void 0,width:f,height:d,offset:h},P?!0:_);Ar(Array.isArray(B),"horizontalCoordinatesGenerator should return Array but instead it returned [".concat(Va(B),"]")),Array.isArray(B)&&(w=B)}if((!E||!E.length)&&Ee(N)){var W=T&&T.length,H=N({xAxis:j?Ht(Ht({},j),{},{ticks:W?T:j.ticks}):void 0,width:f,height:d,offset:h},W?!0:_);Ar(Array.isArray(H),"verticalCoordinatesGenerator should return Array but instead it returned [".concat(Va(H),"]")),Array.isArray(H)&&(E=H)}return G.createElement("g",{className:"recharts-cartesian-grid"},G.createElement(z7,{fill:m.fill,fillOpacity:m.fillOpacity,x:m.x,y:m.y,width:m.width,height:m.height,ry:m.ry}),G.createElement($7,Ua({},m,{offset:h,horizontalPoints:w,xAxis:j,yAxis:M})),G.createElement(B7,Ua({},m,{offset:h,verticalPoints:E,xAxis:j,yAxis:M})),G.createElement(L7,Ua({},m,{horizontalPoints:w})),G.createElement(U7,Ua({},m,{verticalPoints:E})))}va.displayName="CartesianGrid";function yu(e){"@babel/helpers - typeof";return yu=typeof Symbol=="function"&&typeof Symbol.iterator=="symbol"?function(t){return typeof t}:function(t){return t&&typeof Symbol=="function"&&t.constructor===Symbol&&t!==Symbol.prototype?"symbol":typeof t},yu(e)}function I7(e,t){if(!(e instanceof t))throw new TypeError("Cannot call a class as a function")}function G7(e,t){for(var n=0;n<t.length;n++){var a=t[n];a.enumerable=a.enumerable||!1,a.configurable=!0,"value"in a&&(a.writable=!0),Object.defineProperty(e,AN(a.key),a)}}function Y7(e,t,n){return t&&G7(e.prototype,t),Object.defineProperty(e,"prototype",{writable:!1}),e}function X7(e,t,n){return t=rf(t),K7(e,_N()?Reflect.construct(t,n||[],rf(e).constructor):t.apply(e,n))}function K7(e,t){if(t&&(yu(t)==="object"||typeof t=="function"))return t;if(t!==void 0)throw new TypeError("Derived constructors may only return object or undefined");return V7(e)}function V7(e){if(e===void 0)throw new ReferenceError("this hasn't been initialised - super() hasn't been called");return e}function _N(){try{var e=!Boolean.prototype.valueOf.call(Reflect.construct(Boolean,[],function(){}))}catch{}return(_N=function(){return!!e})()}function rf(e){return rf=Object.setPrototypeOf?Object.getPrototypeOf.bind():function(n){return n.__proto__||Object.getPrototypeOf(n)},rf(e)}function Z7(e,t){if(typeof t!="function"&&t!==null)throw new TypeError("Super expression must either be null or a function");e.prototype=Object.create(t&&t.prototype,{constructor:{value:e,writable:!0,configurable:!0}}),Object.defineProperty(e,"prototype",{writable:!1}),t&&x0(e,t)}function x0(e,t){return x0=Object.setPrototypeOf?Object.setPrototypeOf.bind():function(a,u){return a.__proto__=u,a},x0(e,t)}function ON(e,t,n){return t=AN(t),t in e?Object.defineProperty(e,t,{value:n,enumerable:!0,configurable:!0,writable:!0}):e[t]=n,e}function AN(e){var t=F7(e,"string");return yu(t)=="symbol"?t:t+""}function F7(e,t){if(yu(e)!="object"||!e)return e;var n=e[Symbol.toPrimitive];if(n!==void 0){var a=n.call(e,t);if(yu(a)!="object")return a;throw new TypeError("@@toPrimitive must return a primitive value.")}return String(e)}function S0(){return S0=Object.assign?Object.assign.bind():function(e){for(var t=1;t<arguments.length;t++){var n=arguments[t];for(var a in n)Object.prototype.hasOwnProperty.call(n,a)&&(e[a]=n[a])}return e},S0.apply(this,arguments)}function Q7(e){var t=e.xAxisId,n=jb(),a=Mb(),u=sN(t);return u==null?null:J.createElement(Tu,S0({},u,{className:Be("recharts-".concat(u.axisType," ").concat(u.axisType),u.className),viewBox:{x:0,y:0,width:n,height:a},ticksGenerator:function(s){return _r(s,!0)}}))}var Wn=(function(e){function t(){return I7(this,t),X7(this,t,arguments)}return Z7(t,e),Y7(t,[{key:"render",value:function(){return J.createElement(Q7,this.props)}}])})(J.Component);ON(Wn,"displayName","XAxis");ON(Wn,"defaultProps",{allowDecimals:!0,hide:!1,orientation:"bottom",width:0,height:30,mirror:!1,xAxisId:0,tickCount:5,type:"category",padding:{left:0,right:0},allowDataOverflow:!1,scale:"auto",reversed:!1,allowDuplicatedCategory:!0});function mu(e){"@babel/helpers - typeof";return mu=typeof Symbol=="function"&&typeof Symbol.iterator=="symbol"?function(t){return typeof t}:function(t){return t&&typeof Symbol=="function"&&t.constructor===Symbol&&t!==Symbol.prototype?"symbol":typeof t},mu(e)}function W7(e,t){if(!(e instanceof t))throw new TypeError("Cannot call a class as a function")}function J7(e,t){for(var n=0;n<t.length;n++){var a=t[n];a.enumerable=a.enumerable||!1,a.configurable=!0,"value"in a&&(a.writable=!0),Object.defineProperty(e,EN(a.key),a)}}function eI(e,t,n){return t&&J7(e.prototype,t),Object.defineProperty(e,"prototype",{writable:!1}),e}function tI(e,t,n){return t=af(t),nI(e,wN()?Reflect.construct(t,n||[],af(e).constructor):t.apply(e,n))}function nI(e,t){if(t&&(mu(t)==="object"||typeof t=="function"))return t;if(t!==void 0)throw new TypeError("Derived constructors may only return object or undefined");return rI(e)}function rI(e){if(e===void 0)throw new ReferenceError("this hasn't been initialised - super() hasn't been called");return e}function wN(){try{var e=!Boolean.prototype.valueOf.call(Reflect.construct(Boolean,[],function(){}))}catch{}return(wN=function(){return!!e})()}function af(e){return af=Object.setPrototypeOf?Object.getPrototypeOf.bind():function(n){return n.__proto__||Object.getPrototypeOf(n)},af(e)}function aI(e,t){if(typeof t!="function"&&t!==null)throw new TypeError("Super expression must either be null or a function");e.prototype=Object.create(t&&t.prototype,{constructor:{value:e,writable:!0,configurable:!0}}),Object.defineProperty(e,"prototype",{writable:!1}),t&&_0(e,t)}function _0(e,t){return _0=Object.setPrototypeOf?Object.setPrototypeOf.bind():function(a,u){return a.__proto__=u,a},_0(e,t)}function TN(e,t,n){return t=EN(t),t in e?Object.defineProperty(e,t,{value:n,enumerable:!0,configurable:!0,writable:!0}):e[t]=n,e}function EN(e){var t=iI(e,"string");return mu(t)=="symbol"?t:t+""}function iI(e,t){if(mu(e)!="object"||!e)return e;var n=e[Symbol.toPrimitive];if(n!==void 0){var a=n.call(e,t);if(mu(a)!="object")return a;throw new TypeError("@@toPrimitive must return a primitive value.")}return String(e)}function O0(){return O0=Object.assign?Object.assign.bind():function(e){for(var t=1;t<arguments.length;t++){var n=arguments[t];for(var a in n)Object.prototype.hasOwnProperty.call(n,a)&&(e[a]=n[a])}return e},O0.apply(this,arguments)}var uI=function(t){var n=t.yAxisId,a=jb(),u=Mb(),o=fN(n);return o==null?null:J.createElement(Tu,O0({},o,{className:Be("recharts-".concat(o.axisType," ").concat(o.axisType),o.className),viewBox:{x:0,y:0,width:a,height:u},ticksGenerator:function(f){return _r(f,!0)}}))},Jn=(function(e){function t(){return W7(this,t),tI(this,t,arguments)}return aI(t,e),eI(t,[{key:"render",value:function(){return J.createElement(uI,this.props)}}])})(J.Component);TN(Jn,"displayName","YAxis");TN(Jn,"defaultProps",{allowDuplicatedCategory:!0,allowDecimals:!0,hide:!1,orientation:"left",width:60,height:0,mirror:!1,yAxisId:0,tickCount:5,type:"number",padding:{top:0,bottom:0},allowDataOverflow:!1,scale:"auto",reversed:!1});function g2(e){return sI(e)||cI(e)||oI(e)||lI()}function lI(){throw new TypeError(`Invalid attempt to spread non-iterable instance.
 In order to be iterable, non-array objects must have a [Symbol.iterator]() method.`)}function oI(e,t){if(e){if(typeof e=="string")return A0(e,t);var n=Object.prototype.toString.call(e).slice(8,-1);if(n==="Object"&&e.constructor&&(n=e.constructor.name),n==="Map"||n==="Set")return Array.from(e);if(n==="Arguments"||/^(?:Ui|I)nt(?:8|16|32)(?:Clamped)?Array$/.test(n))return A0(e,t)}}function cI(e){if(typeof Symbol<"u"&&e[Symbol.iterator]!=null||e["@@iterator"]!=null)return Array.from(e)}function sI(e){if(Array.isArray(e))return A0(e)}function A0(e,t){(t==null||t>e.length)&&(t=e.length);for(var n=0,a=new Array(t);n<t;n++)a[n]=e[n];return a}var w0=function(t,n,a,u,o){var s=Ln(t,Nb),f=Ln(t,qf),d=[].concat(g2(s),g2(f)),h=Ln(t,$f),m="".concat(u,"Id"),y=u[0],g=n;if(d.length&&(g=d.reduce(function(_,x){if(x.props[m]===a&&Vn(x.props,"extendDomain")&&fe(x.props[y])){var T=x.props[y];return[Math.min(_[0],T),Math.max(_[1],T)]}return _},g)),h.length){var b="".concat(y,"1"),O="".concat(y,"2");g=h.reduce(function(_,x){if(x.props[m]===a&&Vn(x.props,"extendDomain")&&fe(x.props[b])&&fe(x.props[O])){var T=x.props[b],j=x.props[O];return[Math.min(_[0],T,j),Math.max(_[1],T,j)]}return _},g)}return o&&o.length&&(g=o.reduce(function(_,x){return fe(x)?[Math.min(_[0],x),Math.max(_[1],x)]:_},g)),g},ig={exports:{}},b2;function fI(){return b2||(b2=1,(function(e){var t=Object.prototype.hasOwnProperty,n="~";function a(){}Object.create&&(a.prototype=Object.create(null),new a().__proto__||(n=!1));function u(d,h,m){this.fn=d,this.context=h,this.once=m||!1}function o(d,h,m,y,g){if(typeof m!="function")throw new TypeError("The listener must be a function");var b=new u(m,y||d,g),O=n?n+h:h;return d._events[O]?d._events[O].fn?d._events[O]=[d._events[O],b]:d._events[O].push(b):(d._events[O]=b,d._eventsCount++),d}function s(d,h){--d._eventsCount===0?d._events=new a:delete d._events[h]}function f(){this._events=new a,this._eventsCount=0}f.prototype.eventNames=function(){var h=[],m,y;if(this._eventsCount===0)return h;for(y in m=this._events)t.call(m,y)&&h.push(n?y.slice(1):y);return Object.getOwnPropertySymbols?h.concat(Object.getOwnPropertySymbols(m)):h},f.prototype.listeners=function(h){var m=n?n+h:h,y=this._events[m];if(!y)return[];if(y.fn)return[y.fn];for(var g=0,b=y.length,O=new Array(b);g<b;g++)O[g]=y[g].fn;return O},f.prototype.listenerCount=function(h){var m=n?n+h:h,y=this._events[m];return y?y.fn?1:y.length:0},f.prototype.emit=function(h,m,y,g,b,O){var _=n?n+h:h;if(!this._events[_])return!1;var x=this._events[_],T=arguments.length,j,M;if(x.fn){switch(x.once&&this.removeListener(h,x.fn,void 0,!0),T){case 1:return x.fn.call(x.context),!0;case 2:return x.fn.call(x.context,m),!0;case 3:return x.fn.call(x.context,m,y),!0;case 4:return x.fn.call(x.context,m,y,g),!0;case 5:return x.fn.call(x.context,m,y,g,b),!0;case 6:return x.fn.call(x.context,m,y,g,b,O),!0}for(M=1,j=new Array(T-1);M<T;M++)j[M-1]=arguments[M];x.fn.apply(x.context,j)}else{var N=x.length,A;for(M=0;M<N;M++)switch(x[M].once&&this.removeListener(h,x[M].fn,void 0,!0),T){case 1:x[M].fn.call(x[M].context);break;case 2:x[M].fn.call(x[M].context,m);break;case 3:x[M].fn.call(x[M].context,m,y);break;case 4:x[M].fn.call(x[M].context,m,y,g);break;default:if(!j)for(A=1,j=new Array(T-1);A<T;A++)j[A-1]=arguments[A];x[M].fn.apply(x[M].context,j)}}return!0},f.prototype.on=function(h,m,y){return o(this,h,m,y,!1)},f.prototype.once=function(h,m,y){return o(this,h,m,y,!0)},f.prototype.removeListener=function(h,m,y,g){var b=n?n+h:h;if(!this._events[b])return this;if(!m)return s(this,b),this;var O=this._events[b];if(O.fn)O.fn===m&&(!g||O.once)&&(!y||O.context===y)&&s(this,b);else{for(var _=0,x=[],T=O.length;_<T;_++)(O[_].fn!==m||g&&!O[_].once||y&&O[_].context!==y)&&x.push(O[_]);x.length?this._events[b]=x.length===1?x[0]:x:s(this,b)}return this},f.prototype.removeAllListeners=function(h){var m;return h?(m=n?n+h:h,this._events[m]&&s(this,m)):(this._events=new a,this._eventsCount=0),this},f.prototype.off=f.prototype.removeListener,f.prototype.addListener=f.prototype.on,f.prefixed=n,f.EventEmitter=f,e.exports=f})(ig)),ig.exports}var dI=fI();const hI=Qe(dI);var ug=new hI,lg="recharts.syncMouseEvents";function xo(e){"@babel/helpers - typeof";return xo=typeof Symbol=="function"&&typeof Symbol.iterator=="symbol"?function(t){return typeof t}:function(t){return t&&typeof Symbol=="function"&&t.constructor===Symbol&&t!==Symbol.prototype?"symbol":typeof t},xo(e)}function vI(e,t){if(!(e instanceof t))throw new TypeError("Cannot call a class as a function")}function pI(e,t){for(var n=0;n<t.length;n++){var a=t[n];a.enumerable=a.enumerable||!1,a.configurable=!0,"value"in a&&(a.writable=!0),Object.defineProperty(e,jN(a.key),a)}}function yI(e,t,n){return t&&pI(e.prototype,t),Object.defineProperty(e,"prototype",{writable:!1}),e}function og(e,t,n){return t=jN(t),t in e?Object.defineProperty(e,t,{value:n,enumerable:!0,configurable:!0,writable:!0}):e[t]=n,e}function jN(e){var t=mI(e,"string");return xo(t)=="symbol"?t:t+""}function mI(e,t){if(xo(e)!="object"||!e)return e;var n=e[Symbol.toPrimitive];if(n!==void 0){var a=n.call(e,t);if(xo(a)!="object")return a;throw new TypeError("@@toPrimitive must return a primitive value.")}return String(e)}var gI=(function(){function e(){vI(this,e),og(this,"activeIndex",0),og(this,"coordinateList",[]),og(this,"layout","horizontal")}return yI(e,[{key:"setDetails",value:function(n){var a,u=n.coordinateList,o=u===void 0?null:u,s=n.container,f=s===void 0?null:s,d=n.layout,h=d===void 0?null:d,m=n.offset,y=m===void 0?null:m,g=n.mouseHandlerCallback,b=g===void 0?null:g;this.coordinateList=(a=o??this.coordinateList)!==null&&a!==void 0?a:[],this.container=f??this.container,this.layout=h??this.layout,this.offset=y??this.offset,this.mouseHandlerCallback=b??this.mouseHandlerCallback,this.activeIndex=Math.min(Math.max(this.activeIndex,0),this.coordinateList.length-1)}},{key:"focus",value:function(){this.spoofMouse()}},{key:"keyboardEvent",value:function(n){if(this.coordinateList.length!==0)switch(n.key){case"ArrowRight":{if(this.layout!=="horizontal")return;this.activeIndex=Math.min(this.activeIndex+1,this.coordinateList.length-1),this.spoofMouse();break}case"ArrowLeft":{if(this.layout!=="horizontal")return;this.activeIndex=Math.max(this.activeIndex-1,0),this.spoofMouse();break}}}},{key:"setIndex",value:function(n){this.activeIndex=n}},{key:"spoofMouse",value:function(){var n,a;if(this.layout==="horizontal"&&this.coordinateList.length!==0){var u=this.container.getBoundingClientRect(),o=u.x,s=u.y,f=u.height,d=this.coordinateList[this.activeIndex].coordinate,h=((n=window)===null||n===void 0?void 0:n.scrollX)||0,m=((a=window)===null||a===void 0?void 0:a.scrollY)||0,y=o+d+h,g=s+this.offset.top+f/2+m;this.mouseHandlerCallback({pageX:y,pageY:g})}}}])})();function bI(e,t,n){if(n==="number"&&t===!0&&Array.isArray(e)){var a=e==null?void 0:e[0],u=e==null?void 0:e[1];if(a&&u&&fe(a)&&fe(u))return!0}return!1}function xI(e,t,n,a){var u=a/2;return{stroke:"none",fill:"#ccc",x:e==="horizontal"?t.x-u:n.left+.5,y:e==="horizontal"?n.top+.5:t.y-u,width:e==="horizontal"?a:n.width-1,height:e==="horizontal"?n.height-1:a}}function MN(e){var t=e.cx,n=e.cy,a=e.radius,u=e.startAngle,o=e.endAngle,s=qt(t,n,a,u),f=qt(t,n,a,o);return{points:[s,f],cx:t,cy:n,radius:a,startAngle:u,endAngle:o}}function SI(e,t,n){var a,u,o,s;if(e==="horizontal")a=t.x,o=a,u=n.top,s=n.top+n.height;else if(e==="vertical")u=t.y,s=u,a=n.left,o=n.left+n.width;else if(t.cx!=null&&t.cy!=null)if(e==="centric"){var f=t.cx,d=t.cy,h=t.innerRadius,m=t.outerRadius,y=t.angle,g=qt(f,d,h,y),b=qt(f,d,m,y);a=g.x,u=g.y,o=b.x,s=b.y}else return MN(t);return[{x:a,y:u},{x:o,y:s}]}function So(e){"@babel/helpers - typeof";return So=typeof Symbol=="function"&&typeof Symbol.iterator=="symbol"?function(t){return typeof t}:function(t){return t&&typeof Symbol=="function"&&t.constructor===Symbol&&t!==Symbol.prototype?"symbol":typeof t},So(e)}function x2(e,t){var n=Object.keys(e);if(Object.getOwnPropertySymbols){var a=Object.getOwnPropertySymbols(e);t&&(a=a.filter(function(u){return Object.getOwnPropertyDescriptor(e,u).enumerable})),n.push.apply(n,a)}return n}function os(e){for(var t=1;t<arguments.length;t++){var n=arguments[t]!=null?arguments[t]:{};t%2?x2(Object(n),!0).forEach(function(a){_I(e,a,n[a])}):Object.getOwnPropertyDescriptors?Object.defineProperties(e,Object.getOwnPropertyDescriptors(n)):x2(Object(n)).forEach(function(a){Object.defineProperty(e,a,Object.getOwnPropertyDescriptor(n,a))})}return e}function _I(e,t,n){return t=OI(t),t in e?Object.defineProperty(e,t,{value:n,enumerable:!0,configurable:!0,writable:!0}):e[t]=n,e}function OI(e){var t=AI(e,"string");return So(t)=="symbol"?t:t+""}function AI(e,t){if(So(e)!="object"||!e)return e;var n=e[Symbol.toPrimitive];if(n!==void 0){var a=n.call(e,t);if(So(a)!="object")return a;throw new TypeError("@@toPrimitive must return a primitive value.")}return(t==="string"?String:Number)(e)}function wI(e){var t,n,a=e.element,u=e.tooltipEventType,o=e.isActive,s=e.activeCoordinate,f=e.activePayload,d=e.offset,h=e.activeTooltipIndex,m=e.tooltipAxisBandSize,y=e.layout,g=e.chartName,b=(t=a.props.cursor)!==null&&t!==void 0?t:(n=a.type.defaultProps)===null||n===void 0?void 0:n.cursor;if(!a||!b||!o||!s||g!=="ScatterChart"&&u!=="axis")return null;var O,_=ej;if(g==="ScatterChart")O=s,_=zk;else if(g==="BarChart")O=xI(y,s,d,m),_=Ab;else if(y==="radial"){var x=MN(s),T=x.cx,j=x.cy,M=x.radius,N=x.startAngle,A=x.endAngle;O={cx:T,cy:j,startAngle:N,endAngle:A,innerRadius:M,outerRadius:M},_=LC}else O={points:SI(y,s,d)},_=ej;var w=os(os(os(os({stroke:"#ccc",pointerEvents:"none"},d),O),$e(b,!1)),{},{payload:f,payloadIndex:h,className:Be("recharts-tooltip-cursor",b.className)});return J.isValidElement(b)?J.cloneElement(b,w):J.createElement(_,w)}var TI=["item"],EI=["children","className","width","height","style","compact","title","desc"];function gu(e){"@babel/helpers - typeof";return gu=typeof Symbol=="function"&&typeof Symbol.iterator=="symbol"?function(t){return typeof t}:function(t){return t&&typeof Symbol=="function"&&t.constructor===Symbol&&t!==Symbol.prototype?"symbol":typeof t},gu(e)}function Ki(){return Ki=Object.assign?Object.assign.bind():function(e){for(var t=1;t<arguments.length;t++){var n=arguments[t];for(var a in n)Object.prototype.hasOwnProperty.call(n,a)&&(e[a]=n[a])}return e},Ki.apply(this,arguments)}function S2(e,t){return CI(e)||MI(e,t)||NN(e,t)||jI()}function jI(){throw new TypeError(`Invalid attempt to destructure non-iterable instance.
 In order to be iterable, non-array objects must have a [Symbol.iterator]() method.`)}function MI(e,t){var n=e==null?null:typeof Symbol<"u"&&e[Symbol.iterator]||e["@@iterator"];if(n!=null){var a,u,o,s,f=[],d=!0,h=!1;try{if(o=(n=n.call(e)).next,t!==0)for(;!(d=(a=o.call(n)).done)&&(f.push(a.value),f.length!==t);d=!0);}catch(m){h=!0,u=m}finally{try{if(!d&&n.return!=null&&(s=n.return(),Object(s)!==s))return}finally{if(h)throw u}}return f}}function CI(e){if(Array.isArray(e))return e}function _2(e,t){if(e==null)return{};var n=NI(e,t),a,u;if(Object.getOwnPropertySymbols){var o=Object.getOwnPropertySymbols(e);for(u=0;u<o.length;u++)a=o[u],!(t.indexOf(a)>=0)&&Object.prototype.propertyIsEnumerable.call(e,a)&&(n[a]=e[a])}return n}function NI(e,t){if(e==null)return{};var n={};for(var a in e)if(Object.prototype.hasOwnProperty.call(e,a)){if(t.indexOf(a)>=0)continue;n[a]=e[a]}return n}function DI(e,t){if(!(e instanceof t))throw new TypeError("Cannot call a class as a function")}function RI(e,t){for(var n=0;n<t.length;n++){var a=t[n];a.enumerable=a.enumerable||!1,a.configurable=!0,"value"in a&&(a.writable=!0),Object.defineProperty(e,DN(a.key),a)}}function PI(e,t,n){return t&&RI(e.prototype,t),Object.defineProperty(e,"prototype",{writable:!1}),e}function qI(e,t,n){return t=uf(t),zI(e,CN()?Reflect.construct(t,n||[],uf(e).constructor):t.apply(e,n))}function zI(e,t){if(t&&(gu(t)==="object"||typeof t=="function"))return t;if(t!==void 0)throw new TypeError("Derived constructors may only return object or undefined");return $I(e)}function $I(e){if(e===void 0)throw new ReferenceError("this hasn't been initialised - super() hasn't been called");return e}function CN(){try{var e=!Boolean.prototype.valueOf.call(Reflect.construct(Boolean,[],function(){}))}catch{}return(CN=function(){return!!e})()}function uf(e){return uf=Object.setPrototypeOf?Object.getPrototypeOf.bind():function(n){return n.__proto__||Object.getPrototypeOf(n)},uf(e)}function BI(e,t){if(typeof t!="function"&&t!==null)throw new TypeError("Super expression must either be null or a function");e.prototype=Object.create(t&&t.prototype,{constructor:{value:e,writable:!0,configurable:!0}}),Object.defineProperty(e,"prototype",{writable:!1}),t&&T0(e,t)}function T0(e,t){return T0=Object.setPrototypeOf?Object.setPrototypeOf.bind():function(a,u){return a.__proto__=u,a},T0(e,t)}function bu(e){return kI(e)||UI(e)||NN(e)||LI()}function LI(){throw new TypeError(`Invalid attempt to spread non-iterable instance.
-In order to be iterable, non-array objects must have a [Symbol.iterator]() method.`)}function NN(e,t){if(e){if(typeof e=="string")return E0(e,t);var n=Object.prototype.toString.call(e).slice(8,-1);if(n==="Object"&&e.constructor&&(n=e.constructor.name),n==="Map"||n==="Set")return Array.from(e);if(n==="Arguments"||/^(?:Ui|I)nt(?:8|16|32)(?:Clamped)?Array$/.test(n))return E0(e,t)}}function UI(e){if(typeof Symbol<"u"&&e[Symbol.iterator]!=null||e["@@iterator"]!=null)return Array.from(e)}function kI(e){if(Array.isArray(e))return E0(e)}function E0(e,t){(t==null||t>e.length)&&(t=e.length);for(var n=0,a=new Array(t);n<t;n++)a[n]=e[n];return a}function O2(e,t){var n=Object.keys(e);if(Object.getOwnPropertySymbols){var a=Object.getOwnPropertySymbols(e);t&&(a=a.filter(function(u){return Object.getOwnPropertyDescriptor(e,u).enumerable})),n.push.apply(n,a)}return n}function ne(e){for(var t=1;t<arguments.length;t++){var n=arguments[t]!=null?arguments[t]:{};t%2?O2(Object(n),!0).forEach(function(a){be(e,a,n[a])}):Object.getOwnPropertyDescriptors?Object.defineProperties(e,Object.getOwnPropertyDescriptors(n)):O2(Object(n)).forEach(function(a){Object.defineProperty(e,a,Object.getOwnPropertyDescriptor(n,a))})}return e}function be(e,t,n){return t=DN(t),t in e?Object.defineProperty(e,t,{value:n,enumerable:!0,configurable:!0,writable:!0}):e[t]=n,e}function DN(e){var t=HI(e,"string");return gu(t)=="symbol"?t:t+""}function HI(e,t){if(gu(e)!="object"||!e)return e;var n=e[Symbol.toPrimitive];if(n!==void 0){var a=n.call(e,t);if(gu(a)!="object")return a;throw new TypeError("@@toPrimitive must return a primitive value.")}return(t==="string"?String:Number)(e)}var II={xAxis:["bottom","top"],yAxis:["left","right"]},GI={width:"100%",height:"100%"},RN={x:0,y:0};function cs(e){return e}var YI=function(t,n){return n==="horizontal"?t.x:n==="vertical"?t.y:n==="centric"?t.angle:t.radius},XI=function(t,n,a,u){var o=n.find(function(m){return m&&m.index===a});if(o){if(t==="horizontal")return{x:o.coordinate,y:u.y};if(t==="vertical")return{x:u.x,y:o.coordinate};if(t==="centric"){var s=o.coordinate,f=u.radius;return ne(ne(ne({},u),qt(u.cx,u.cy,f,s)),{},{angle:s,radius:f})}var d=o.coordinate,h=u.angle;return ne(ne(ne({},u),qt(u.cx,u.cy,d,h)),{},{angle:h,radius:d})}return RN},Bf=function(t,n){var a=n.graphicalItems,u=n.dataStartIndex,o=n.dataEndIndex,s=(a??[]).reduce(function(f,d){var h=d.props.data;return h&&h.length?[].concat(bu(f),bu(h)):f},[]);return s.length>0?s:t&&t.length&&fe(u)&&fe(o)?t.slice(u,o+1):[]};function PN(e){return e==="number"?[0,"auto"]:void 0}var j0=function(t,n,a,u){var o=t.graphicalItems,s=t.tooltipAxis,f=Bf(n,t);return a<0||!o||!o.length||a>=f.length?null:o.reduce(function(d,h){var m,y=(m=h.props.data)!==null&&m!==void 0?m:n;y&&t.dataStartIndex+t.dataEndIndex!==0&&t.dataEndIndex-t.dataStartIndex>=a&&(y=y.slice(t.dataStartIndex,t.dataEndIndex+1));var g;if(s.dataKey&&!s.allowDuplicatedCategory){var b=y===void 0?f:y;g=vg(b,s.dataKey,u)}else g=y&&y[a]||f[a];return g?[].concat(bu(d),[zC(h,g)]):d},[])},A2=function(t,n,a,u){var o=u||{x:t.chartX,y:t.chartY},s=YI(o,a),f=t.orderedTooltipTicks,d=t.tooltipAxis,h=t.tooltipTicks,m=p8(s,f,h,d);if(m>=0&&h){var y=h[m]&&h[m].value,g=j0(t,n,m,y),b=XI(a,f,m,o);return{activeTooltipIndex:m,activeLabel:y,activePayload:g,activeCoordinate:b}}return null},KI=function(t,n){var a=n.axes,u=n.graphicalItems,o=n.axisType,s=n.axisIdKey,f=n.stackGroups,d=n.dataStartIndex,h=n.dataEndIndex,m=t.layout,y=t.children,g=t.stackOffset,b=RC(m,o);return a.reduce(function(O,_){var x,T=_.type.defaultProps!==void 0?ne(ne({},_.type.defaultProps),_.props):_.props,j=T.type,M=T.dataKey,N=T.allowDataOverflow,A=T.allowDuplicatedCategory,w=T.scale,E=T.ticks,P=T.includeHidden,B=T[s];if(O[B])return O;var W=Bf(t.data,{graphicalItems:u.filter(function(U){var re,se=s in U.props?U.props[s]:(re=U.type.defaultProps)===null||re===void 0?void 0:re[s];return se===B}),dataStartIndex:d,dataEndIndex:h}),H=W.length,L,V,Q;bI(T.domain,N,j)&&(L=Vg(T.domain,null,N),b&&(j==="number"||w!=="auto")&&(Q=Bl(W,M,"category")));var F=PN(j);if(!L||L.length===0){var q,I=(q=T.domain)!==null&&q!==void 0?q:F;if(M){if(L=Bl(W,M,j),j==="category"&&b){var ee=l3(L);A&&ee?(V=L,L=Ks(0,H)):A||(L=UE(I,L,_).reduce(function(U,re){return U.indexOf(re)>=0?U:[].concat(bu(U),[re])},[]))}else if(j==="category")A?L=L.filter(function(U){return U!==""&&!Le(U)}):L=UE(I,L,_).reduce(function(U,re){return U.indexOf(re)>=0||re===""||Le(re)?U:[].concat(bu(U),[re])},[]);else if(j==="number"){var le=x8(W,u.filter(function(U){var re,se,ve=s in U.props?U.props[s]:(re=U.type.defaultProps)===null||re===void 0?void 0:re[s],me="hide"in U.props?U.props.hide:(se=U.type.defaultProps)===null||se===void 0?void 0:se.hide;return ve===B&&(P||!me)}),M,o,m);le&&(L=le)}b&&(j==="number"||w!=="auto")&&(Q=Bl(W,M,"category"))}else b?L=Ks(0,H):f&&f[B]&&f[B].hasStack&&j==="number"?L=g==="expand"?[0,1]:qC(f[B].stackGroups,d,h):L=DC(W,u.filter(function(U){var re=s in U.props?U.props[s]:U.type.defaultProps[s],se="hide"in U.props?U.props.hide:U.type.defaultProps.hide;return re===B&&(P||!se)}),j,m,!0);if(j==="number")L=w0(y,L,B,o,E),I&&(L=Vg(I,L,N));else if(j==="category"&&I){var oe=I,R=L.every(function(U){return oe.indexOf(U)>=0});R&&(L=oe)}}return ne(ne({},O),{},be({},B,ne(ne({},T),{},{axisType:o,domain:L,categoricalDomain:Q,duplicateDomain:V,originalDomain:(x=T.domain)!==null&&x!==void 0?x:F,isCategorical:b,layout:m})))},{})},VI=function(t,n){var a=n.graphicalItems,u=n.Axis,o=n.axisType,s=n.axisIdKey,f=n.stackGroups,d=n.dataStartIndex,h=n.dataEndIndex,m=t.layout,y=t.children,g=Bf(t.data,{graphicalItems:a,dataStartIndex:d,dataEndIndex:h}),b=g.length,O=RC(m,o),_=-1;return a.reduce(function(x,T){var j=T.type.defaultProps!==void 0?ne(ne({},T.type.defaultProps),T.props):T.props,M=j[s],N=PN("number");if(!x[M]){_++;var A;return O?A=Ks(0,b):f&&f[M]&&f[M].hasStack?(A=qC(f[M].stackGroups,d,h),A=w0(y,A,M,o)):(A=Vg(N,DC(g,a.filter(function(w){var E,P,B=s in w.props?w.props[s]:(E=w.type.defaultProps)===null||E===void 0?void 0:E[s],W="hide"in w.props?w.props.hide:(P=w.type.defaultProps)===null||P===void 0?void 0:P.hide;return B===M&&!W}),"number",m),u.defaultProps.allowDataOverflow),A=w0(y,A,M,o)),ne(ne({},x),{},be({},M,ne(ne({axisType:o},u.defaultProps),{},{hide:!0,orientation:En(II,"".concat(o,".").concat(_%2),null),domain:A,originalDomain:N,isCategorical:O,layout:m})))}return x},{})},ZI=function(t,n){var a=n.axisType,u=a===void 0?"xAxis":a,o=n.AxisComp,s=n.graphicalItems,f=n.stackGroups,d=n.dataStartIndex,h=n.dataEndIndex,m=t.children,y="".concat(u,"Id"),g=Ln(m,o),b={};return g&&g.length?b=KI(t,{axes:g,graphicalItems:s,axisType:u,axisIdKey:y,stackGroups:f,dataStartIndex:d,dataEndIndex:h}):s&&s.length&&(b=VI(t,{Axis:o,graphicalItems:s,axisType:u,axisIdKey:y,stackGroups:f,dataStartIndex:d,dataEndIndex:h})),b},FI=function(t){var n=ia(t),a=_r(n,!1,!0);return{tooltipTicks:a,orderedTooltipTicks:F0(a,function(u){return u.coordinate}),tooltipAxis:n,tooltipAxisBandSize:$s(n,a)}},w2=function(t){var n=t.children,a=t.defaultShowTooltip,u=fn(n,su),o=0,s=0;return t.data&&t.data.length!==0&&(s=t.data.length-1),u&&u.props&&(u.props.startIndex>=0&&(o=u.props.startIndex),u.props.endIndex>=0&&(s=u.props.endIndex)),{chartX:0,chartY:0,dataStartIndex:o,dataEndIndex:s,activeTooltipIndex:-1,isTooltipActive:!!a}},QI=function(t){return!t||!t.length?!1:t.some(function(n){var a=Or(n&&n.type);return a&&a.indexOf("Bar")>=0})},T2=function(t){return t==="horizontal"?{numericAxisName:"yAxis",cateAxisName:"xAxis"}:t==="vertical"?{numericAxisName:"xAxis",cateAxisName:"yAxis"}:t==="centric"?{numericAxisName:"radiusAxis",cateAxisName:"angleAxis"}:{numericAxisName:"angleAxis",cateAxisName:"radiusAxis"}},WI=function(t,n){var a=t.props,u=t.graphicalItems,o=t.xAxisMap,s=o===void 0?{}:o,f=t.yAxisMap,d=f===void 0?{}:f,h=a.width,m=a.height,y=a.children,g=a.margin||{},b=fn(y,su),O=fn(y,Zi),_=Object.keys(d).reduce(function(A,w){var E=d[w],P=E.orientation;return!E.mirror&&!E.hide?ne(ne({},A),{},be({},P,A[P]+E.width)):A},{left:g.left||0,right:g.right||0}),x=Object.keys(s).reduce(function(A,w){var E=s[w],P=E.orientation;return!E.mirror&&!E.hide?ne(ne({},A),{},be({},P,En(A,"".concat(P))+E.height)):A},{top:g.top||0,bottom:g.bottom||0}),T=ne(ne({},x),_),j=T.bottom;b&&(T.bottom+=b.props.height||su.defaultProps.height),O&&n&&(T=g8(T,u,a,n));var M=h-T.left-T.right,N=m-T.top-T.bottom;return ne(ne({brushBottom:j},T),{},{width:Math.max(M,0),height:Math.max(N,0)})},JI=function(t,n){if(n==="xAxis")return t[n].width;if(n==="yAxis")return t[n].height},eG=function(t){var n=t.chartName,a=t.GraphicalChild,u=t.defaultTooltipEventType,o=u===void 0?"axis":u,s=t.validateTooltipEventTypes,f=s===void 0?["axis"]:s,d=t.axisComponents,h=t.legendContent,m=t.formatAxisMap,y=t.defaultProps,g=function(T,j){var M=j.graphicalItems,N=j.stackGroups,A=j.offset,w=j.updateId,E=j.dataStartIndex,P=j.dataEndIndex,B=T.barSize,W=T.layout,H=T.barGap,L=T.barCategoryGap,V=T.maxBarSize,Q=T2(W),F=Q.numericAxisName,q=Q.cateAxisName,I=QI(M),ee=[];return M.forEach(function(le,oe){var R=Bf(T.data,{graphicalItems:[le],dataStartIndex:E,dataEndIndex:P}),U=le.type.defaultProps!==void 0?ne(ne({},le.type.defaultProps),le.props):le.props,re=U.dataKey,se=U.maxBarSize,ve=U["".concat(F,"Id")],me=U["".concat(q,"Id")],Te={},Ue=d.reduce(function(Nn,er){var ju=j["".concat(er.axisType,"Map")],$t=U["".concat(er.axisType,"Id")];ju&&ju[$t]||er.axisType==="zAxis"||Ka();var No=ju[$t];return ne(ne({},Nn),{},be(be({},er.axisType,No),"".concat(er.axisType,"Ticks"),_r(No)))},Te),ce=Ue[q],xe=Ue["".concat(q,"Ticks")],Se=N&&N[ve]&&N[ve].hasStack&&D8(le,N[ve].stackGroups),ue=Or(le.type).indexOf("Bar")>=0,Je=$s(ce,xe),Ae=[],it=I&&y8({barSize:B,stackGroups:N,totalSize:JI(Ue,q)});if(ue){var ut,zt,Cn=Le(se)?V:se,kn=(ut=(zt=$s(ce,xe,!0))!==null&&zt!==void 0?zt:Cn)!==null&&ut!==void 0?ut:0;Ae=m8({barGap:H,barCategoryGap:L,bandSize:kn!==Je?kn:Je,sizeList:it[me],maxBarSize:Cn}),kn!==Je&&(Ae=Ae.map(function(Nn){return ne(ne({},Nn),{},{position:ne(ne({},Nn.position),{},{offset:Nn.position.offset-kn/2})})}))}var Dr=le&&le.type&&le.type.getComposedData;Dr&&ee.push({props:ne(ne({},Dr(ne(ne({},Ue),{},{displayedData:R,props:T,dataKey:re,item:le,bandSize:Je,barPosition:Ae,offset:A,stackedData:Se,layout:W,dataStartIndex:E,dataEndIndex:P}))),{},be(be(be({key:le.key||"item-".concat(oe)},F,Ue[F]),q,Ue[q]),"animationId",w)),childIndex:b3(le,T.children),item:le})}),ee},b=function(T,j){var M=T.props,N=T.dataStartIndex,A=T.dataEndIndex,w=T.updateId;if(!tA({props:M}))return null;var E=M.children,P=M.layout,B=M.stackOffset,W=M.data,H=M.reverseStackOrder,L=T2(P),V=L.numericAxisName,Q=L.cateAxisName,F=Ln(E,a),q=M8(W,F,"".concat(V,"Id"),"".concat(Q,"Id"),B,H),I=d.reduce(function(U,re){var se="".concat(re.axisType,"Map");return ne(ne({},U),{},be({},se,ZI(M,ne(ne({},re),{},{graphicalItems:F,stackGroups:re.axisType===V&&q,dataStartIndex:N,dataEndIndex:A}))))},{}),ee=WI(ne(ne({},I),{},{props:M,graphicalItems:F}),j==null?void 0:j.legendBBox);Object.keys(I).forEach(function(U){I[U]=m(M,I[U],ee,U.replace("Map",""),n)});var le=I["".concat(Q,"Map")],oe=FI(le),R=g(M,ne(ne({},I),{},{dataStartIndex:N,dataEndIndex:A,updateId:w,graphicalItems:F,stackGroups:q,offset:ee}));return ne(ne({formattedGraphicalItems:R,graphicalItems:F,offset:ee,stackGroups:q},oe),I)},O=(function(x){function T(j){var M,N,A;return DI(this,T),A=qI(this,T,[j]),be(A,"eventEmitterSymbol",Symbol("rechartsEventEmitter")),be(A,"accessibilityManager",new gI),be(A,"handleLegendBBoxUpdate",function(w){if(w){var E=A.state,P=E.dataStartIndex,B=E.dataEndIndex,W=E.updateId;A.setState(ne({legendBBox:w},b({props:A.props,dataStartIndex:P,dataEndIndex:B,updateId:W},ne(ne({},A.state),{},{legendBBox:w}))))}}),be(A,"handleReceiveSyncEvent",function(w,E,P){if(A.props.syncId===w){if(P===A.eventEmitterSymbol&&typeof A.props.syncMethod!="function")return;A.applySyncEvent(E)}}),be(A,"handleBrushChange",function(w){var E=w.startIndex,P=w.endIndex;if(E!==A.state.dataStartIndex||P!==A.state.dataEndIndex){var B=A.state.updateId;A.setState(function(){return ne({dataStartIndex:E,dataEndIndex:P},b({props:A.props,dataStartIndex:E,dataEndIndex:P,updateId:B},A.state))}),A.triggerSyncEvent({dataStartIndex:E,dataEndIndex:P})}}),be(A,"handleMouseEnter",function(w){var E=A.getMouseInfo(w);if(E){var P=ne(ne({},E),{},{isTooltipActive:!0});A.setState(P),A.triggerSyncEvent(P);var B=A.props.onMouseEnter;Ee(B)&&B(P,w)}}),be(A,"triggeredAfterMouseMove",function(w){var E=A.getMouseInfo(w),P=E?ne(ne({},E),{},{isTooltipActive:!0}):{isTooltipActive:!1};A.setState(P),A.triggerSyncEvent(P);var B=A.props.onMouseMove;Ee(B)&&B(P,w)}),be(A,"handleItemMouseEnter",function(w){A.setState(function(){return{isTooltipActive:!0,activeItem:w,activePayload:w.tooltipPayload,activeCoordinate:w.tooltipPosition||{x:w.cx,y:w.cy}}})}),be(A,"handleItemMouseLeave",function(){A.setState(function(){return{isTooltipActive:!1}})}),be(A,"handleMouseMove",function(w){w.persist(),A.throttleTriggeredAfterMouseMove(w)}),be(A,"handleMouseLeave",function(w){A.throttleTriggeredAfterMouseMove.cancel();var E={isTooltipActive:!1};A.setState(E),A.triggerSyncEvent(E);var P=A.props.onMouseLeave;Ee(P)&&P(E,w)}),be(A,"handleOuterEvent",function(w){var E=g3(w),P=En(A.props,"".concat(E));if(E&&Ee(P)){var B,W;/.*touch.*/i.test(E)?W=A.getMouseInfo(w.changedTouches[0]):W=A.getMouseInfo(w),P((B=W)!==null&&B!==void 0?B:{},w)}}),be(A,"handleClick",function(w){var E=A.getMouseInfo(w);if(E){var P=ne(ne({},E),{},{isTooltipActive:!0});A.setState(P),A.triggerSyncEvent(P);var B=A.props.onClick;Ee(B)&&B(P,w)}}),be(A,"handleMouseDown",function(w){var E=A.props.onMouseDown;if(Ee(E)){var P=A.getMouseInfo(w);E(P,w)}}),be(A,"handleMouseUp",function(w){var E=A.props.onMouseUp;if(Ee(E)){var P=A.getMouseInfo(w);E(P,w)}}),be(A,"handleTouchMove",function(w){w.changedTouches!=null&&w.changedTouches.length>0&&A.throttleTriggeredAfterMouseMove(w.changedTouches[0])}),be(A,"handleTouchStart",function(w){w.changedTouches!=null&&w.changedTouches.length>0&&A.handleMouseDown(w.changedTouches[0])}),be(A,"handleTouchEnd",function(w){w.changedTouches!=null&&w.changedTouches.length>0&&A.handleMouseUp(w.changedTouches[0])}),be(A,"handleDoubleClick",function(w){var E=A.props.onDoubleClick;if(Ee(E)){var P=A.getMouseInfo(w);E(P,w)}}),be(A,"handleContextMenu",function(w){var E=A.props.onContextMenu;if(Ee(E)){var P=A.getMouseInfo(w);E(P,w)}}),be(A,"triggerSyncEvent",function(w){A.props.syncId!==void 0&&ug.emit(lg,A.props.syncId,w,A.eventEmitterSymbol)}),be(A,"applySyncEvent",function(w){var E=A.props,P=E.layout,B=E.syncMethod,W=A.state.updateId,H=w.dataStartIndex,L=w.dataEndIndex;if(w.dataStartIndex!==void 0||w.dataEndIndex!==void 0)A.setState(ne({dataStartIndex:H,dataEndIndex:L},b({props:A.props,dataStartIndex:H,dataEndIndex:L,updateId:W},A.state)));else if(w.activeTooltipIndex!==void 0){var V=w.chartX,Q=w.chartY,F=w.activeTooltipIndex,q=A.state,I=q.offset,ee=q.tooltipTicks;if(!I)return;if(typeof B=="function")F=B(ee,w);else if(B==="value"){F=-1;for(var le=0;le<ee.length;le++)if(ee[le].value===w.activeLabel){F=le;break}}var oe=ne(ne({},I),{},{x:I.left,y:I.top}),R=Math.min(V,oe.x+oe.width),U=Math.min(Q,oe.y+oe.height),re=ee[F]&&ee[F].value,se=j0(A.state,A.props.data,F),ve=ee[F]?{x:P==="horizontal"?ee[F].coordinate:R,y:P==="horizontal"?U:ee[F].coordinate}:RN;A.setState(ne(ne({},w),{},{activeLabel:re,activeCoordinate:ve,activePayload:se,activeTooltipIndex:F}))}else A.setState(w)}),be(A,"renderCursor",function(w){var E,P=A.state,B=P.isTooltipActive,W=P.activeCoordinate,H=P.activePayload,L=P.offset,V=P.activeTooltipIndex,Q=P.tooltipAxisBandSize,F=A.getTooltipEventType(),q=(E=w.props.active)!==null&&E!==void 0?E:B,I=A.props.layout,ee=w.key||"_recharts-cursor";return G.createElement(wI,{key:ee,activeCoordinate:W,activePayload:H,activeTooltipIndex:V,chartName:n,element:w,isActive:q,layout:I,offset:L,tooltipAxisBandSize:Q,tooltipEventType:F})}),be(A,"renderPolarAxis",function(w,E,P){var B=En(w,"type.axisType"),W=En(A.state,"".concat(B,"Map")),H=w.type.defaultProps,L=H!==void 0?ne(ne({},H),w.props):w.props,V=W&&W[L["".concat(B,"Id")]];return J.cloneElement(w,ne(ne({},V),{},{className:Be(B,V.className),key:w.key||"".concat(E,"-").concat(P),ticks:_r(V,!0)}))}),be(A,"renderPolarGrid",function(w){var E=w.props,P=E.radialLines,B=E.polarAngles,W=E.polarRadius,H=A.state,L=H.radiusAxisMap,V=H.angleAxisMap,Q=ia(L),F=ia(V),q=F.cx,I=F.cy,ee=F.innerRadius,le=F.outerRadius;return J.cloneElement(w,{polarAngles:Array.isArray(B)?B:_r(F,!0).map(function(oe){return oe.coordinate}),polarRadius:Array.isArray(W)?W:_r(Q,!0).map(function(oe){return oe.coordinate}),cx:q,cy:I,innerRadius:ee,outerRadius:le,key:w.key||"polar-grid",radialLines:P})}),be(A,"renderLegend",function(){var w=A.state.formattedGraphicalItems,E=A.props,P=E.children,B=E.width,W=E.height,H=A.props.margin||{},L=B-(H.left||0)-(H.right||0),V=CC({children:P,formattedGraphicalItems:w,legendWidth:L,legendContent:h});if(!V)return null;var Q=V.item,F=_2(V,TI);return J.cloneElement(Q,ne(ne({},F),{},{chartWidth:B,chartHeight:W,margin:H,onBBoxUpdate:A.handleLegendBBoxUpdate}))}),be(A,"renderTooltip",function(){var w,E=A.props,P=E.children,B=E.accessibilityLayer,W=fn(P,Ut);if(!W)return null;var H=A.state,L=H.isTooltipActive,V=H.activeCoordinate,Q=H.activePayload,F=H.activeLabel,q=H.offset,I=(w=W.props.active)!==null&&w!==void 0?w:L;return J.cloneElement(W,{viewBox:ne(ne({},q),{},{x:q.left,y:q.top}),active:I,label:F,payload:I?Q:[],coordinate:V,accessibilityLayer:B})}),be(A,"renderBrush",function(w){var E=A.props,P=E.margin,B=E.data,W=A.state,H=W.offset,L=W.dataStartIndex,V=W.dataEndIndex,Q=W.updateId;return J.cloneElement(w,{key:w.key||"_recharts-brush",onChange:as(A.handleBrushChange,w.props.onChange),data:B,x:fe(w.props.x)?w.props.x:H.left,y:fe(w.props.y)?w.props.y:H.top+H.height+H.brushBottom-(P.bottom||0),width:fe(w.props.width)?w.props.width:H.width,startIndex:L,endIndex:V,updateId:"brush-".concat(Q)})}),be(A,"renderReferenceElement",function(w,E,P){if(!w)return null;var B=A,W=B.clipPathId,H=A.state,L=H.xAxisMap,V=H.yAxisMap,Q=H.offset,F=w.type.defaultProps||{},q=w.props,I=q.xAxisId,ee=I===void 0?F.xAxisId:I,le=q.yAxisId,oe=le===void 0?F.yAxisId:le;return J.cloneElement(w,{key:w.key||"".concat(E,"-").concat(P),xAxis:L[ee],yAxis:V[oe],viewBox:{x:Q.left,y:Q.top,width:Q.width,height:Q.height},clipPathId:W})}),be(A,"renderActivePoints",function(w){var E=w.item,P=w.activePoint,B=w.basePoint,W=w.childIndex,H=w.isRange,L=[],V=E.props.key,Q=E.item.type.defaultProps!==void 0?ne(ne({},E.item.type.defaultProps),E.item.props):E.item.props,F=Q.activeDot,q=Q.dataKey,I=ne(ne({index:W,dataKey:q,cx:P.x,cy:P.y,r:4,fill:Ob(E.item),strokeWidth:2,stroke:"#fff",payload:P.payload,value:P.value},$e(F,!1)),ss(F));return L.push(T.renderActiveDot(F,I,"".concat(V,"-activePoint-").concat(W))),B?L.push(T.renderActiveDot(F,ne(ne({},I),{},{cx:B.x,cy:B.y}),"".concat(V,"-basePoint-").concat(W))):H&&L.push(null),L}),be(A,"renderGraphicChild",function(w,E,P){var B=A.filterFormatItem(w,E,P);if(!B)return null;var W=A.getTooltipEventType(),H=A.state,L=H.isTooltipActive,V=H.tooltipAxis,Q=H.activeTooltipIndex,F=H.activeLabel,q=A.props.children,I=fn(q,Ut),ee=B.props,le=ee.points,oe=ee.isRange,R=ee.baseLine,U=B.item.type.defaultProps!==void 0?ne(ne({},B.item.type.defaultProps),B.item.props):B.item.props,re=U.activeDot,se=U.hide,ve=U.activeBar,me=U.activeShape,Te=!!(!se&&L&&I&&(re||ve||me)),Ue={};W!=="axis"&&I&&I.props.trigger==="click"?Ue={onClick:as(A.handleItemMouseEnter,w.props.onClick)}:W!=="axis"&&(Ue={onMouseLeave:as(A.handleItemMouseLeave,w.props.onMouseLeave),onMouseEnter:as(A.handleItemMouseEnter,w.props.onMouseEnter)});var ce=J.cloneElement(w,ne(ne({},B.props),Ue));function xe(er){return typeof V.dataKey=="function"?V.dataKey(er.payload):null}if(Te)if(Q>=0){var Se,ue;if(V.dataKey&&!V.allowDuplicatedCategory){var Je=typeof V.dataKey=="function"?xe:"payload.".concat(V.dataKey.toString());Se=vg(le,Je,F),ue=oe&&R&&vg(R,Je,F)}else Se=le==null?void 0:le[Q],ue=oe&&R&&R[Q];if(me||ve){var Ae=w.props.activeIndex!==void 0?w.props.activeIndex:Q;return[J.cloneElement(w,ne(ne(ne({},B.props),Ue),{},{activeIndex:Ae})),null,null]}if(!Le(Se))return[ce].concat(bu(A.renderActivePoints({item:B,activePoint:Se,basePoint:ue,childIndex:Q,isRange:oe})))}else{var it,ut=(it=A.getItemByXY(A.state.activeCoordinate))!==null&&it!==void 0?it:{graphicalItem:ce},zt=ut.graphicalItem,Cn=zt.item,kn=Cn===void 0?w:Cn,Dr=zt.childIndex,Nn=ne(ne(ne({},B.props),Ue),{},{activeIndex:Dr});return[J.cloneElement(kn,Nn),null,null]}return oe?[ce,null,null]:[ce,null]}),be(A,"renderCustomized",function(w,E,P){return J.cloneElement(w,ne(ne({key:"recharts-customized-".concat(P)},A.props),A.state))}),be(A,"renderMap",{CartesianGrid:{handler:cs,once:!0},ReferenceArea:{handler:A.renderReferenceElement},ReferenceLine:{handler:cs},ReferenceDot:{handler:A.renderReferenceElement},XAxis:{handler:cs},YAxis:{handler:cs},Brush:{handler:A.renderBrush,once:!0},Bar:{handler:A.renderGraphicChild},Line:{handler:A.renderGraphicChild},Area:{handler:A.renderGraphicChild},Radar:{handler:A.renderGraphicChild},RadialBar:{handler:A.renderGraphicChild},Scatter:{handler:A.renderGraphicChild},Pie:{handler:A.renderGraphicChild},Funnel:{handler:A.renderGraphicChild},Tooltip:{handler:A.renderCursor,once:!0},PolarGrid:{handler:A.renderPolarGrid,once:!0},PolarAngleAxis:{handler:A.renderPolarAxis},PolarRadiusAxis:{handler:A.renderPolarAxis},Customized:{handler:A.renderCustomized}}),A.clipPathId="".concat((M=j.id)!==null&&M!==void 0?M:df("recharts"),"-clip"),A.throttleTriggeredAfterMouseMove=CM(A.triggeredAfterMouseMove,(N=j.throttleDelay)!==null&&N!==void 0?N:1e3/60),A.state={},A}return BI(T,x),PI(T,[{key:"componentDidMount",value:function(){var M,N;this.addListener(),this.accessibilityManager.setDetails({container:this.container,offset:{left:(M=this.props.margin.left)!==null&&M!==void 0?M:0,top:(N=this.props.margin.top)!==null&&N!==void 0?N:0},coordinateList:this.state.tooltipTicks,mouseHandlerCallback:this.triggeredAfterMouseMove,layout:this.props.layout}),this.displayDefaultTooltip()}},{key:"displayDefaultTooltip",value:function(){var M=this.props,N=M.children,A=M.data,w=M.height,E=M.layout,P=fn(N,Ut);if(P){var B=P.props.defaultIndex;if(!(typeof B!="number"||B<0||B>this.state.tooltipTicks.length-1)){var W=this.state.tooltipTicks[B]&&this.state.tooltipTicks[B].value,H=j0(this.state,A,B,W),L=this.state.tooltipTicks[B].coordinate,V=(this.state.offset.top+w)/2,Q=E==="horizontal",F=Q?{x:L,y:V}:{y:L,x:V},q=this.state.formattedGraphicalItems.find(function(ee){var le=ee.item;return le.type.name==="Scatter"});q&&(F=ne(ne({},F),q.props.points[B].tooltipPosition),H=q.props.points[B].tooltipPayload);var I={activeTooltipIndex:B,isTooltipActive:!0,activeLabel:W,activePayload:H,activeCoordinate:F};this.setState(I),this.renderCursor(P),this.accessibilityManager.setIndex(B)}}}},{key:"getSnapshotBeforeUpdate",value:function(M,N){if(!this.props.accessibilityLayer)return null;if(this.state.tooltipTicks!==N.tooltipTicks&&this.accessibilityManager.setDetails({coordinateList:this.state.tooltipTicks}),this.props.layout!==M.layout&&this.accessibilityManager.setDetails({layout:this.props.layout}),this.props.margin!==M.margin){var A,w;this.accessibilityManager.setDetails({offset:{left:(A=this.props.margin.left)!==null&&A!==void 0?A:0,top:(w=this.props.margin.top)!==null&&w!==void 0?w:0}})}return null}},{key:"componentDidUpdate",value:function(M){yg([fn(M.children,Ut)],[fn(this.props.children,Ut)])||this.displayDefaultTooltip()}},{key:"componentWillUnmount",value:function(){this.removeListener(),this.throttleTriggeredAfterMouseMove.cancel()}},{key:"getTooltipEventType",value:function(){var M=fn(this.props.children,Ut);if(M&&typeof M.props.shared=="boolean"){var N=M.props.shared?"axis":"item";return f.indexOf(N)>=0?N:o}return o}},{key:"getMouseInfo",value:function(M){if(!this.container)return null;var N=this.container,A=N.getBoundingClientRect(),w=i$(A),E={chartX:Math.round(M.pageX-w.left),chartY:Math.round(M.pageY-w.top)},P=A.width/N.offsetWidth||1,B=this.inRange(E.chartX,E.chartY,P);if(!B)return null;var W=this.state,H=W.xAxisMap,L=W.yAxisMap,V=this.getTooltipEventType(),Q=A2(this.state,this.props.data,this.props.layout,B);if(V!=="axis"&&H&&L){var F=ia(H).scale,q=ia(L).scale,I=F&&F.invert?F.invert(E.chartX):null,ee=q&&q.invert?q.invert(E.chartY):null;return ne(ne({},E),{},{xValue:I,yValue:ee},Q)}return Q?ne(ne({},E),Q):null}},{key:"inRange",value:function(M,N){var A=arguments.length>2&&arguments[2]!==void 0?arguments[2]:1,w=this.props.layout,E=M/A,P=N/A;if(w==="horizontal"||w==="vertical"){var B=this.state.offset,W=E>=B.left&&E<=B.left+B.width&&P>=B.top&&P<=B.top+B.height;return W?{x:E,y:P}:null}var H=this.state,L=H.angleAxisMap,V=H.radiusAxisMap;if(L&&V){var Q=ia(L);return IE({x:E,y:P},Q)}return null}},{key:"parseEventsOfWrapper",value:function(){var M=this.props.children,N=this.getTooltipEventType(),A=fn(M,Ut),w={};A&&N==="axis"&&(A.props.trigger==="click"?w={onClick:this.handleClick}:w={onMouseEnter:this.handleMouseEnter,onDoubleClick:this.handleDoubleClick,onMouseMove:this.handleMouseMove,onMouseLeave:this.handleMouseLeave,onTouchMove:this.handleTouchMove,onTouchStart:this.handleTouchStart,onTouchEnd:this.handleTouchEnd,onContextMenu:this.handleContextMenu});var E=ss(this.props,this.handleOuterEvent);return ne(ne({},E),w)}},{key:"addListener",value:function(){ug.on(lg,this.handleReceiveSyncEvent)}},{key:"removeListener",value:function(){ug.removeListener(lg,this.handleReceiveSyncEvent)}},{key:"filterFormatItem",value:function(M,N,A){for(var w=this.state.formattedGraphicalItems,E=0,P=w.length;E<P;E++){var B=w[E];if(B.item===M||B.props.key===M.key||N===Or(B.item.type)&&A===B.childIndex)return B}return null}},{key:"renderClipPath",value:function(){var M=this.clipPathId,N=this.state.offset,A=N.left,w=N.top,E=N.height,P=N.width;return G.createElement("defs",null,G.createElement("clipPath",{id:M},G.createElement("rect",{x:A,y:w,height:E,width:P})))}},{key:"getXScales",value:function(){var M=this.state.xAxisMap;return M?Object.entries(M).reduce(function(N,A){var w=S2(A,2),E=w[0],P=w[1];return ne(ne({},N),{},be({},E,P.scale))},{}):null}},{key:"getYScales",value:function(){var M=this.state.yAxisMap;return M?Object.entries(M).reduce(function(N,A){var w=S2(A,2),E=w[0],P=w[1];return ne(ne({},N),{},be({},E,P.scale))},{}):null}},{key:"getXScaleByAxisId",value:function(M){var N;return(N=this.state.xAxisMap)===null||N===void 0||(N=N[M])===null||N===void 0?void 0:N.scale}},{key:"getYScaleByAxisId",value:function(M){var N;return(N=this.state.yAxisMap)===null||N===void 0||(N=N[M])===null||N===void 0?void 0:N.scale}},{key:"getItemByXY",value:function(M){var N=this.state,A=N.formattedGraphicalItems,w=N.activeItem;if(A&&A.length)for(var E=0,P=A.length;E<P;E++){var B=A[E],W=B.props,H=B.item,L=H.type.defaultProps!==void 0?ne(ne({},H.type.defaultProps),H.props):H.props,V=Or(H.type);if(V==="Bar"){var Q=(W.data||[]).find(function(ee){return Tk(M,ee)});if(Q)return{graphicalItem:B,payload:Q}}else if(V==="RadialBar"){var F=(W.data||[]).find(function(ee){return IE(M,ee)});if(F)return{graphicalItem:B,payload:F}}else if(Nf(B,w)||Df(B,w)||po(B,w)){var q=y9({graphicalItem:B,activeTooltipItem:w,itemData:L.data}),I=L.activeIndex===void 0?q:L.activeIndex;return{graphicalItem:ne(ne({},B),{},{childIndex:I}),payload:po(B,w)?L.data[q]:B.props.data[q]}}}return null}},{key:"render",value:function(){var M=this;if(!tA(this))return null;var N=this.props,A=N.children,w=N.className,E=N.width,P=N.height,B=N.style,W=N.compact,H=N.title,L=N.desc,V=_2(N,EI),Q=$e(V,!1);if(W)return G.createElement(i2,{state:this.state,width:this.props.width,height:this.props.height,clipPathId:this.clipPathId},G.createElement(gg,Ki({},Q,{width:E,height:P,title:H,desc:L}),this.renderClipPath(),rA(A,this.renderMap)));if(this.props.accessibilityLayer){var F,q;Q.tabIndex=(F=this.props.tabIndex)!==null&&F!==void 0?F:0,Q.role=(q=this.props.role)!==null&&q!==void 0?q:"application",Q.onKeyDown=function(ee){M.accessibilityManager.keyboardEvent(ee)},Q.onFocus=function(){M.accessibilityManager.focus()}}var I=this.parseEventsOfWrapper();return G.createElement(i2,{state:this.state,width:this.props.width,height:this.props.height,clipPathId:this.clipPathId},G.createElement("div",Ki({className:Be("recharts-wrapper",w),style:ne({position:"relative",cursor:"default",width:E,height:P},B)},I,{ref:function(le){M.container=le}}),G.createElement(gg,Ki({},Q,{width:E,height:P,title:H,desc:L,style:GI}),this.renderClipPath(),rA(A,this.renderMap)),this.renderLegend(),this.renderTooltip()))}}])})(J.Component);be(O,"displayName",n),be(O,"defaultProps",ne({layout:"horizontal",stackOffset:"none",barCategoryGap:"10%",barGap:4,margin:{top:5,right:5,bottom:5,left:5},reverseStackOrder:!1,syncMethod:"index"},y)),be(O,"getDerivedStateFromProps",function(x,T){var j=x.dataKey,M=x.data,N=x.children,A=x.width,w=x.height,E=x.layout,P=x.stackOffset,B=x.margin,W=T.dataStartIndex,H=T.dataEndIndex;if(T.updateId===void 0){var L=w2(x);return ne(ne(ne({},L),{},{updateId:0},b(ne(ne({props:x},L),{},{updateId:0}),T)),{},{prevDataKey:j,prevData:M,prevWidth:A,prevHeight:w,prevLayout:E,prevStackOffset:P,prevMargin:B,prevChildren:N})}if(j!==T.prevDataKey||M!==T.prevData||A!==T.prevWidth||w!==T.prevHeight||E!==T.prevLayout||P!==T.prevStackOffset||!Vi(B,T.prevMargin)){var V=w2(x),Q={chartX:T.chartX,chartY:T.chartY,isTooltipActive:T.isTooltipActive},F=ne(ne({},A2(T,M,E)),{},{updateId:T.updateId+1}),q=ne(ne(ne({},V),Q),F);return ne(ne(ne({},q),b(ne({props:x},q),T)),{},{prevDataKey:j,prevData:M,prevWidth:A,prevHeight:w,prevLayout:E,prevStackOffset:P,prevMargin:B,prevChildren:N})}if(!yg(N,T.prevChildren)){var I,ee,le,oe,R=fn(N,su),U=R&&(I=(ee=R.props)===null||ee===void 0?void 0:ee.startIndex)!==null&&I!==void 0?I:W,re=R&&(le=(oe=R.props)===null||oe===void 0?void 0:oe.endIndex)!==null&&le!==void 0?le:H,se=U!==W||re!==H,ve=!Le(M),me=ve&&!se?T.updateId:T.updateId+1;return ne(ne({updateId:me},b(ne(ne({props:x},T),{},{updateId:me,dataStartIndex:U,dataEndIndex:re}),T)),{},{prevChildren:N,dataStartIndex:U,dataEndIndex:re})}return null}),be(O,"renderActiveDot",function(x,T,j){var M;return J.isValidElement(x)?M=J.cloneElement(x,T):Ee(x)?M=x(T):M=G.createElement(KC,T),G.createElement(Et,{className:"recharts-active-dot",key:j},M)});var _=J.forwardRef(function(T,j){return G.createElement(O,Ki({},T,{ref:j}))});return _.displayName=O.displayName,_},ei=eG({chartName:"BarChart",GraphicalChild:_t,defaultTooltipEventType:"axis",validateTooltipEventTypes:["axis","item"],axisComponents:[{axisType:"xAxis",AxisComp:Wn},{axisType:"yAxis",AxisComp:Jn}],formatAxisMap:dH});const tG=[{key:"cost",label:"Cost"},{key:"tokens",label:"Tokens"},{key:"calls",label:"Calls"},{key:"errors",label:"Errors"}];function nG(e){return e.slice(5).replace("-","/")}const Lf={contentStyle:{backgroundColor:"#111827",border:"1px solid #374151",borderRadius:4,fontSize:11},labelStyle:{color:"#9ca3af"}},Uf={dataKey:"date",tick:{fontSize:10,fill:"#6b7280"},axisLine:{stroke:"#374151"},tickLine:!1},kf={tick:{fontSize:10,fill:"#6b7280"},axisLine:!1,tickLine:!1,width:50},Hf={top:4,right:4,bottom:0,left:0},If={strokeDasharray:"3 3",stroke:"#1f2937",vertical:!1};function rG({timeline:e,activeTab:t,onTabChange:n}){const[a,u]=J.useState("cost"),o=t??a,s=d=>{u(d),n==null||n(d)};if(!e||e.length===0)return D.jsx(oG,{});const f=e.map(d=>({...d,date:nG(d.date),total_calls:d.reads+d.writes+d.execs}));return D.jsxs("div",{className:"bg-gray-900/80 rounded shadow-lg shadow-black/25 p-5",children:[D.jsx("div",{className:"flex items-center gap-1 mb-4",children:tG.map(d=>D.jsx("button",{onClick:()=>s(d.key),className:`px-2.5 py-1 text-xs rounded transition-colors ${o===d.key?"bg-gray-700 text-gray-100":"text-gray-500 hover:text-gray-300"}`,children:d.label},d.key))}),o==="cost"&&D.jsx(aG,{data:f}),o==="tokens"&&D.jsx(iG,{data:f}),o==="calls"&&D.jsx(uG,{data:f}),o==="errors"&&D.jsx(lG,{data:f})]})}function aG({data:e}){return D.jsx(Fa,{width:"100%",height:220,children:D.jsxs(ei,{data:e,margin:Hf,children:[D.jsx(va,{...If}),D.jsx(Wn,{...Uf}),D.jsx(Jn,{...kf,tickFormatter:t=>`$${t}`}),D.jsx(Ut,{...Lf}),D.jsx(_t,{dataKey:"cost_usd",name:"Cost ($)",fill:"#3B82F6",radius:[3,3,0,0]})]})})}function iG({data:e}){return D.jsx(Fa,{width:"100%",height:220,children:D.jsxs(ei,{data:e,margin:Hf,children:[D.jsx(va,{...If}),D.jsx(Wn,{...Uf}),D.jsx(Jn,{...kf}),D.jsx(Ut,{...Lf}),D.jsx(_t,{dataKey:"input_tokens",name:"Input",fill:"#3B82F6",stackId:"tok",radius:[0,0,0,0]}),D.jsx(_t,{dataKey:"output_tokens",name:"Output",fill:"#8B5CF6",stackId:"tok",radius:[3,3,0,0]})]})})}function uG({data:e}){return D.jsx(Fa,{width:"100%",height:220,children:D.jsxs(ei,{data:e,margin:Hf,children:[D.jsx(va,{...If}),D.jsx(Wn,{...Uf}),D.jsx(Jn,{...kf}),D.jsx(Ut,{...Lf}),D.jsx(_t,{dataKey:"reads",name:"Read",fill:"#06b6d4",stackId:"risk"}),D.jsx(_t,{dataKey:"writes",name:"Write",fill:"#3B82F6",stackId:"risk"}),D.jsx(_t,{dataKey:"execs",name:"Exec",fill:"#ef4444",stackId:"risk",radius:[3,3,0,0]})]})})}function lG({data:e}){return D.jsx(Fa,{width:"100%",height:220,children:D.jsxs(ei,{data:e,margin:Hf,children:[D.jsx(va,{...If}),D.jsx(Wn,{...Uf}),D.jsx(Jn,{...kf}),D.jsx(Ut,{...Lf}),D.jsx(_t,{dataKey:"errors",name:"Errors",fill:"#ef4444",radius:[3,3,0,0]})]})})}function oG(){return D.jsx("div",{className:"bg-gray-900/80 rounded shadow-lg shadow-black/25 p-5 flex items-center justify-center h-[280px]",children:D.jsx("span",{className:"text-gray-600 text-sm",children:"No timeline data yet"})})}function E2(e){return e>=1e6?`${(e/1e6).toFixed(1)}M`:e>=1e3?`${Math.floor(e/1e3)}K`:String(e)}const Eu="bg-gray-900/80 rounded shadow-lg shadow-black/25 p-5",Co="text-sm font-medium text-gray-400 uppercase tracking-wider mb-3",Pb={contentStyle:{backgroundColor:"#111827",border:"1px solid #374151",borderRadius:4,fontSize:11}},qb={strokeDasharray:"3 3",stroke:"#1f2937",vertical:!1};function cG({stats:e}){return e?D.jsxs("div",{className:"grid grid-cols-2 gap-4",children:[D.jsx(sG,{files:e.files}),D.jsx(fG,{models:e.models}),D.jsx(dG,{tools:e.tools}),D.jsx(hG,{projects:e.projects})]}):D.jsx("div",{className:`${Eu} flex items-center justify-center h-48`,children:D.jsx("span",{className:"text-gray-600 text-sm",children:"No data yet"})})}function sG({files:e}){const t=e.slice(0,8);return t.length===0?D.jsx(Gf,{label:"Most Edited Files"}):D.jsxs("div",{className:Eu,children:[D.jsx("h3",{className:Co,children:"Most Edited Files"}),D.jsx(Fa,{width:"100%",height:220,children:D.jsxs(ei,{data:t,layout:"vertical",margin:{left:80,right:30,top:0,bottom:0},children:[D.jsx(va,{...qb}),D.jsx(Wn,{type:"number",tick:{fontSize:10,fill:"#6b7280"},axisLine:!1,tickLine:!1}),D.jsx(Jn,{type:"category",dataKey:"file",tick:{fontSize:10,fill:"#9ca3af"},axisLine:!1,tickLine:!1,width:78}),D.jsx(Ut,{...Pb}),D.jsx(_t,{dataKey:"count",name:"Touches",fill:"#06b6d4",radius:[0,3,3,0],label:{position:"right",fontSize:10,fill:"#9ca3af"}})]})})]})}function fG({models:e}){if(e.length===0)return D.jsx(Gf,{label:"Model Efficiency"});const t=Math.max(...e.map(n=>n.cost_usd));return D.jsxs("div",{className:Eu,children:[D.jsx("h3",{className:Co,children:"Model Efficiency"}),D.jsx("div",{className:"overflow-x-auto",children:D.jsxs("table",{className:"w-full text-xs",children:[D.jsx("thead",{children:D.jsxs("tr",{className:"text-gray-500 border-b border-gray-800",children:[D.jsx("th",{className:"text-left py-1 pr-2",children:"Model"}),D.jsx("th",{className:"text-right py-1 px-2",children:"Calls"}),D.jsx("th",{className:"text-right py-1 px-2",children:"In"}),D.jsx("th",{className:"text-right py-1 px-2",children:"Out"}),D.jsx("th",{className:"text-right py-1 px-2",children:"Cache"}),D.jsx("th",{className:"text-right py-1 pl-2",children:"Cost"})]})}),D.jsx("tbody",{children:e.map(n=>{const a=n.input_tokens>0?(n.cache_read_tokens/(n.input_tokens+n.cache_read_tokens)*100).toFixed(0):"—",u=n.cost_usd===t&&n.cost_usd>0,o=n.cost_usd===0;return D.jsxs("tr",{className:`border-b border-gray-800/50 ${u?"border-l-2 border-l-emerald-400":""} ${o?"text-gray-600":"text-gray-300"}`,children:[D.jsx("td",{className:"py-1 pr-2 truncate max-w-[120px]",children:n.model}),D.jsx("td",{className:"text-right py-1 px-2 font-semibold",children:n.calls}),D.jsx("td",{className:"text-right py-1 px-2 text-gray-400",children:E2(n.input_tokens)}),D.jsx("td",{className:"text-right py-1 px-2 text-gray-400",children:E2(n.output_tokens)}),D.jsxs("td",{className:`text-right py-1 px-2 ${a==="100"?"text-emerald-400":a==="—"?"text-gray-600":"text-gray-500"}`,children:[a,"%"]}),D.jsxs("td",{className:`text-right py-1 pl-2 ${u?"text-emerald-400":o?"text-gray-600":"text-gray-400"}`,children:["$",n.cost_usd.toFixed(2)]})]},n.model)})})]})})]})}function dG({tools:e}){const t=e.slice(0,10).map(n=>({...n,ok_count:n.count-n.error_count}));return t.length===0?D.jsx(Gf,{label:"Tool Breakdown"}):D.jsxs("div",{className:Eu,children:[D.jsx("h3",{className:Co,children:"Tool Breakdown"}),D.jsx(Fa,{width:"100%",height:180,children:D.jsxs(ei,{data:t,layout:"vertical",margin:{left:70,right:8,top:0,bottom:0},children:[D.jsx(va,{...qb}),D.jsx(Wn,{type:"number",tick:{fontSize:10,fill:"#6b7280"},axisLine:!1,tickLine:!1}),D.jsx(Jn,{type:"category",dataKey:"tool",tick:{fontSize:10,fill:"#9ca3af"},axisLine:!1,tickLine:!1,width:68}),D.jsx(Ut,{...Pb}),D.jsx(_t,{dataKey:"ok_count",name:"OK",fill:"#3B82F6",stackId:"s"}),D.jsx(_t,{dataKey:"error_count",name:"Errors",fill:"#ef4444",stackId:"s",radius:[0,3,3,0]})]})})]})}function hG({projects:e}){const t=e.slice(0,8);return t.length===0?D.jsx(Gf,{label:"Risk by Project"}):D.jsxs("div",{className:Eu,children:[D.jsx("h3",{className:Co,children:"Risk by Project"}),D.jsx(Fa,{width:"100%",height:180,children:D.jsxs(ei,{data:t,layout:"vertical",margin:{left:70,right:8,top:0,bottom:0},children:[D.jsx(va,{...qb}),D.jsx(Wn,{type:"number",tick:{fontSize:10,fill:"#6b7280"},axisLine:!1,tickLine:!1}),D.jsx(Jn,{type:"category",dataKey:"name",tick:{fontSize:10,fill:"#9ca3af"},axisLine:!1,tickLine:!1,width:68}),D.jsx(Ut,{...Pb}),D.jsx(_t,{dataKey:"reads",name:"Read",fill:"#06b6d4",stackId:"risk"}),D.jsx(_t,{dataKey:"writes",name:"Write",fill:"#3B82F6",stackId:"risk"}),D.jsx(_t,{dataKey:"execs",name:"Exec",fill:"#ef4444",stackId:"risk",radius:[0,3,3,0]})]})})]})}function Gf({label:e}){return D.jsxs("div",{className:Eu,children:[D.jsx("h3",{className:Co,children:e}),D.jsx("div",{className:"flex items-center justify-center h-[180px]",children:D.jsx("span",{className:"text-gray-600 text-sm",children:"No data yet"})})]})}function Gi(e){return e>=1e6?`${(e/1e6).toFixed(1)}M`:e>=1e3?`${Math.floor(e/1e3)}K`:String(e)}function vG({stats:e}){if(!e||e.counts.input_tokens===0)return D.jsx("div",{className:"bg-gray-900/80 rounded shadow-lg shadow-black/25 p-5 flex items-center justify-center h-24",children:D.jsx("span",{className:"text-gray-600 text-sm",children:"No token data yet"})});const{input_tokens:t,output_tokens:n,cache_read_tokens:a,cost_usd:u}=e.counts,o=t+n+a,s=o>0?t/o*100:0,f=o>0?n/o*100:0,d=o>0?a/o*100:0;return D.jsxs("div",{className:"bg-gray-900/80 rounded shadow-lg shadow-black/25 p-5",children:[D.jsxs("div",{className:"flex items-center justify-between mb-3",children:[D.jsx("h3",{className:"text-sm font-medium text-gray-400 uppercase tracking-wider",children:"Token Breakdown"}),D.jsxs("span",{className:"text-sm text-emerald-400 font-semibold",children:["$",u.toFixed(2)]})]}),D.jsxs("div",{className:"flex h-12 rounded overflow-hidden mb-3",children:[s>0&&D.jsx("div",{className:"bg-blue-500 flex items-center justify-center text-xs text-white font-bold",style:{width:`${Math.max(s,1.5)}%`},title:`Input: ${Gi(t)} (${s.toFixed(1)}%)`,children:s>6?"IN":""}),f>0&&D.jsx("div",{className:"bg-purple-500 flex items-center justify-center text-xs text-white font-bold",style:{width:`${Math.max(f,1.5)}%`},title:`Output: ${Gi(n)} (${f.toFixed(1)}%)`,children:f>6?"OUT":""}),d>0&&D.jsx("div",{className:"bg-gray-700 flex items-center justify-center text-xs text-gray-400 font-bold",style:{width:`${d}%`},title:`Cache: ${Gi(a)} (${d.toFixed(1)}%)`,children:d>10?"CACHE":""})]}),d>50&&D.jsxs("p",{className:"text-sm text-gray-400 italic mb-3",children:[d.toFixed(1),"% of your tokens are cache — you're paying mostly for context, not your prompts"]}),D.jsxs("div",{className:"flex gap-4 text-xs",children:[D.jsx(cg,{color:"bg-blue-500",label:"Input",value:Gi(t)}),D.jsx(cg,{color:"bg-purple-500",label:"Output",value:Gi(n)}),a>0&&D.jsx(cg,{color:"bg-gray-700",label:"Cache",value:Gi(a)})]})]})}function cg({color:e,label:t,value:n}){return D.jsxs("div",{className:"flex items-center gap-1.5",children:[D.jsx("div",{className:`w-2 h-2 rounded-sm ${e}`}),D.jsx("span",{className:"text-gray-500",children:t}),D.jsx("span",{className:"text-gray-300 font-semibold",children:n})]})}const pG={read:"bg-cyan-900/50 text-cyan-400",write:"bg-yellow-900/50 text-yellow-400",exec:"bg-red-900/50 text-red-400"},yG={cursor:"text-purple-400","claude-code":"text-blue-400",vigilo:"text-emerald-400"};function mG(e){return e.slice(11,19)||e}function gG(e){return e<1e3?`${e}µs`:e<1e6?`${(e/1e3).toFixed(0)}ms`:`${(e/1e6).toFixed(1)}s`}function j2(e){return e>=1e6?`${(e/1e6).toFixed(1)}M`:e>=1e3?`${Math.floor(e/1e3)}K`:String(e)}function bG(e){return e==="claude-code"?"claude":e==="vigilo"?"mcp":e}const sg=[null,"read","write","exec"],xG=[{key:"all",label:"All",color:"text-gray-500",activeColor:"bg-gray-700 text-gray-200"},{key:"claude-code",label:"Claude",color:"text-blue-400/60",activeColor:"bg-blue-500/20 text-blue-400"},{key:"cursor",label:"Cursor",color:"text-purple-400/60",activeColor:"bg-purple-500/20 text-purple-400"},{key:"vigilo",label:"MCP",color:"text-emerald-400/60",activeColor:"bg-emerald-500/20 text-emerald-400"}];function SG(e,t,n){switch(n){case"time":return e.timestamp.localeCompare(t.timestamp);case"tokens":{const a=(e.input_tokens??0)+(e.output_tokens??0),u=(t.input_tokens??0)+(t.output_tokens??0);return a-u}case"duration":return e.duration_us-t.duration_us}}function _G({events:e,height:t}){const[n,a]=J.useState(!1),[u,o]=J.useState("all"),[s,f]=J.useState(null),[d,h]=J.useState(null),[m,y]=J.useState(null),[g,b]=J.useState("desc"),O=J.useRef(null),_=J.useMemo(()=>{const E=new Set(e.map(P=>P.tool));return Array.from(E).sort()},[e]),x=()=>{const E=sg.indexOf(s);f(sg[(E+1)%sg.length])},T=()=>{if(_.length!==0)if(d===null)h(_[0]);else{const E=_.indexOf(d);E===-1||E===_.length-1?h(null):h(_[E+1])}},j=J.useMemo(()=>{let E=e;return u!=="all"&&(E=E.filter(P=>P.server===u)),s&&(E=E.filter(P=>P.risk===s)),d&&(E=E.filter(P=>P.tool===d)),E},[e,u,s,d]),M=J.useMemo(()=>{if(!m)return j;const E=j.slice().sort((P,B)=>SG(P,B,m));return g==="desc"?E.reverse():E},[j,m,g]),N=E=>{m===E?g==="desc"?b("asc"):(y(null),b("desc")):(y(E),b("desc"))};J.useEffect(()=>{O.current&&!m&&(O.current.scrollTop=O.current.scrollHeight)},[j,m]);const A=s||d,w=j.length;return D.jsxs("div",{className:"border-t border-gray-800 bg-gray-900/50",children:[D.jsxs("div",{className:"flex items-center justify-between px-4 py-1.5",children:[D.jsxs("button",{onClick:()=>a(!n),className:"flex items-center gap-2 text-xs text-gray-400 hover:text-gray-200 transition-colors",children:[D.jsxs("span",{className:"flex items-center gap-2 font-semibold uppercase tracking-wider",children:[D.jsx("span",{className:"inline-block w-1.5 h-1.5 rounded-full bg-emerald-400 animate-pulse"}),"Live Feed",j.length>0&&D.jsx("span",{className:"bg-gray-700 text-gray-300 text-xs px-1.5 py-0.5 rounded-full font-normal",children:w})]}),D.jsx("span",{className:"text-gray-500",children:n?"▲":"▼"})]}),!n&&D.jsxs("div",{className:"flex items-center gap-2",children:[A&&D.jsx("button",{onClick:()=>{f(null),h(null)},className:"text-[10px] text-gray-500 hover:text-gray-300 transition-colors",children:"clear filters"}),D.jsx("div",{className:"flex items-center gap-0.5 bg-gray-800/50 rounded p-0.5",children:xG.map(E=>D.jsx("button",{onClick:()=>o(E.key),className:`px-2 py-0.5 text-[10px] font-semibold rounded transition-colors ${u===E.key?E.activeColor:`${E.color} hover:text-gray-300`}`,children:E.label},E.key))})]})]}),!n&&D.jsxs(D.Fragment,{children:[D.jsxs("div",{className:"flex items-center gap-2 px-4 py-1 text-[9px] font-mono uppercase tracking-wider border-b border-gray-800/50 select-none",children:[D.jsx(fg,{k:"time",label:"Time",className:"w-16 shrink-0",sortKey:m,sortDir:g,onSort:N}),D.jsx("span",{className:"w-12 shrink-0 text-gray-600",children:"Server"}),D.jsx(M2,{label:"Risk",value:(s==null?void 0:s.toUpperCase())??null,className:"w-16 shrink-0",onClick:x}),D.jsx(M2,{label:"Tool",value:d,className:"w-24 shrink-0",onClick:T}),D.jsx("span",{className:"flex-1 min-w-0 text-gray-600",children:"Argument"}),D.jsx("span",{className:"w-28 shrink-0 text-right text-gray-600",children:"Model"}),D.jsx(fg,{k:"tokens",label:"Tokens",className:"w-20 shrink-0 text-right",sortKey:m,sortDir:g,onSort:N}),D.jsx(fg,{k:"duration",label:"Dur",className:"w-16 shrink-0 text-right",sortKey:m,sortDir:g,onSort:N})]}),D.jsx("div",{ref:O,style:{height:t},className:"overflow-y-auto px-4 pb-2",children:M.length===0?D.jsx("div",{className:"flex items-center justify-center h-full text-gray-600 text-xs",children:e.length===0?"Waiting for events...":"No events match filter"}):M.map(E=>D.jsxs("div",{className:"flex items-center gap-2 py-0.5 text-[11px] font-mono w-full",children:[D.jsx("span",{className:"text-gray-600 w-16 shrink-0",children:mG(E.timestamp)}),D.jsx("span",{className:`w-12 shrink-0 font-bold ${yG[E.server]??"text-gray-500"}`,children:bG(E.server)}),D.jsx("span",{className:"w-16 shrink-0",children:D.jsx("span",{className:`font-bold px-2 py-0.5 rounded text-[10px] font-mono ${pG[E.risk]??"text-gray-500"}`,children:E.risk.toUpperCase()})}),D.jsxs("span",{className:"text-gray-300 font-semibold w-24 shrink-0 truncate",children:[E.is_error&&D.jsx("span",{className:"text-red-500 mr-1",children:"ERR"}),E.tool]}),D.jsx("span",{className:"text-gray-500 truncate flex-1 min-w-0",children:E.arg_display}),D.jsx("span",{className:"w-28 shrink-0 text-gray-600 truncate text-right",children:E.model??D.jsx("span",{className:"text-gray-700",children:"—"})}),D.jsx("span",{className:"w-20 shrink-0 text-right",children:E.input_tokens!=null||E.output_tokens!=null?D.jsxs(D.Fragment,{children:[D.jsx("span",{className:"text-cyan-400/70",children:E.input_tokens!=null?j2(E.input_tokens):"—"}),D.jsx("span",{className:"text-gray-600",children:"↑"}),D.jsx("span",{className:"text-purple-400/70",children:E.output_tokens!=null?j2(E.output_tokens):"—"}),D.jsx("span",{className:"text-gray-600",children:"↓"})]}):D.jsx("span",{className:"text-gray-700",children:"—"})}),D.jsx("span",{className:"w-16 shrink-0 text-right",children:E.duration_us>0?D.jsx("span",{className:"text-gray-600",children:gG(E.duration_us)}):D.jsx("span",{className:"text-gray-700",children:"—"})})]},E.id))})]})]})}function fg({k:e,label:t,className:n,sortKey:a,sortDir:u,onSort:o}){const s=a===e,f=s?u==="desc"?" ▾":" ▴":"";return D.jsxs("button",{onClick:()=>o(e),className:`${n} text-left hover:text-gray-300 transition-colors ${s?"text-cyan-400":"text-gray-600"}`,children:[t,f]})}function M2({label:e,value:t,className:n,onClick:a}){return D.jsx("button",{onClick:a,className:`${n} text-left hover:text-gray-300 transition-colors ${t?"text-amber-400":"text-gray-600"}`,children:t??e})}function OG(){const[e,t]=J.useState("24h"),[n,a]=J.useState(null),[u,o]=J.useState(!1),s=dP(e),{data:f,refetch:d}=hg(()=>dg.sessions(s),[e]),h=J.useMemo(()=>{if(!n||!f)return null;const w=f.find(E=>E.id===n);return(w==null?void 0:w.session_ids)??null},[n,f]),m=h?h.join(","):void 0,y=m?{...s,session:m}:s,{data:g}=hg(()=>dg.stats(y),[e,m]),b=hP(),O=X_({initial:256,min:180,max:480,direction:"horizontal"}),_=X_({initial:192,min:60,max:500,direction:"vertical",invert:!0}),[x,T]=J.useState("cost"),j=J.useRef(null),M=J.useRef(null),N=J.useCallback(w=>{var E;w==="sessions"?(u&&o(!1),M.current&&(M.current.classList.add("ring-1","ring-cyan-400/60"),setTimeout(()=>{var P;(P=M.current)==null||P.classList.remove("ring-1","ring-cyan-400/60")},1200))):(T({calls:"calls",cost:"cost",errors:"errors"}[w]),(E=j.current)==null||E.scrollIntoView({behavior:"smooth",block:"start"}))},[u]);J.useEffect(()=>{const w=setInterval(()=>{d()},15e3);return()=>clearInterval(w)},[d]);const A=b.length>0?b[b.length-1].id:null;return J.useEffect(()=>{A&&d()},[A,d]),D.jsxs("div",{className:"h-screen flex flex-col bg-gray-950",children:[D.jsx(pP,{stats:g,sessionCount:(f==null?void 0:f.length)??0,range:e,onRangeChange:t,onStatClick:N}),D.jsxs("div",{className:"flex flex-1 overflow-hidden",children:[D.jsx(mP,{ref:M,sessions:f,selectedSession:n,onSelect:a,collapsed:u,onToggle:()=>o(!u),width:O.size}),!u&&D.jsx("div",{onMouseDown:O.onMouseDown,className:"w-1 hover:bg-cyan-500/40 cursor-col-resize shrink-0 transition-colors"}),D.jsxs("main",{className:"flex-1 overflow-y-auto p-5 space-y-5",children:[n&&h&&D.jsx(wG,{sessionIds:h,stats:g}),D.jsx("div",{ref:j,children:D.jsx(rG,{timeline:(g==null?void 0:g.timeline)??null,activeTab:x,onTabChange:T})}),D.jsx(vG,{stats:g}),D.jsx(cG,{stats:g})]})]}),D.jsxs("div",{className:"flex shrink-0",children:[!u&&D.jsxs(D.Fragment,{children:[D.jsx("div",{onMouseDown:_.onMouseDown,style:{width:O.size},className:"h-1.5 cursor-row-resize shrink-0 hover:bg-cyan-500/40 transition-colors"}),D.jsx("div",{onMouseDown:w=>{O.onMouseDown(w),_.onMouseDown(w)},className:"w-2 h-1.5 cursor-move shrink-0 hover:bg-cyan-500/60 bg-gray-700/50 transition-colors rounded-sm"})]}),D.jsx("div",{onMouseDown:_.onMouseDown,className:"flex-1 h-1.5 hover:bg-cyan-500/40 cursor-row-resize shrink-0 transition-colors"})]}),D.jsx(_G,{events:b,height:_.size})]})}function AG({stats:e}){if(!e)return null;const t=e.counts,n=e.models.length>0?e.models[0].model:null;return D.jsxs("div",{className:"grid grid-cols-2 sm:grid-cols-4 lg:grid-cols-7 gap-2",children:[D.jsx(Pa,{label:"Calls",value:String(t.total)}),D.jsx(Pa,{label:"Cost",value:t.cost_usd>0?`$${t.cost_usd.toFixed(2)}`:"$0.00",color:"text-emerald-400",accent:"border-t-2 border-emerald-400"}),D.jsx(Pa,{label:"Input",value:Wi(t.input_tokens)}),D.jsx(Pa,{label:"Output",value:Wi(t.output_tokens)}),D.jsx(Pa,{label:"Cache",value:Wi(t.cache_read_tokens)}),D.jsx(Pa,{label:"Errors",value:String(t.errors),color:t.errors>0?"text-red-400":"text-gray-500",accent:t.errors>0?"border-t-2 border-red-400":""}),n&&D.jsx(Pa,{label:"Model",value:n,accent:"border-t-2 border-blue-400"})]})}function Pa({label:e,value:t,color:n,accent:a}){return D.jsxs("div",{className:`bg-gray-800/50 rounded px-3 py-2 ${a??""}`,children:[D.jsx("div",{className:"text-[10px] text-gray-500 uppercase tracking-wider",children:e}),D.jsx("div",{className:`text-sm font-semibold truncate ${n??"text-gray-200"}`,title:t,children:t})]})}function wG({sessionIds:e,stats:t}){const n=e.join(","),{data:a,loading:u}=hg(()=>dg.events({session:n}),[n]);if(u)return D.jsx("div",{className:"flex items-center justify-center h-32 text-gray-500 text-sm",children:"Loading session..."});if(!a||a.length===0)return D.jsx("div",{className:"flex items-center justify-center h-32 text-gray-600 text-sm",children:"No events for this session"});const o=a.some(s=>s.input_tokens!=null||s.model!=null);return D.jsxs("div",{className:"space-y-3",children:[D.jsxs("div",{className:"flex items-center gap-2",children:[D.jsxs("h2",{className:"text-xs font-semibold text-gray-400",children:["Session — ",a.length," events"]}),e.length>1&&D.jsxs("span",{className:"text-[10px] bg-gray-700/50 text-gray-400 px-1.5 py-0.5 rounded",title:`Merged from ${e.length} segments: ${e.join(", ")}`,children:[e.length," segments merged"]})]}),D.jsx(AG,{stats:t}),D.jsx("div",{className:"bg-gray-900/80 rounded shadow-lg shadow-black/25 overflow-hidden",children:D.jsx("div",{className:"overflow-y-auto max-h-[400px]",children:D.jsxs("table",{className:"w-full text-xs",children:[D.jsx("thead",{className:"sticky top-0 bg-gray-900 z-10",children:D.jsxs("tr",{className:"text-gray-500 border-b border-gray-800",children:[D.jsx("th",{className:"text-left py-1.5 px-3",children:"Time"}),D.jsx("th",{className:"text-left py-1.5 px-2",children:"Server"}),D.jsx("th",{className:"text-left py-1.5 px-2",children:"Risk"}),D.jsx("th",{className:"text-left py-1.5 px-2",children:"Tool"}),D.jsx("th",{className:"text-left py-1.5 px-2",children:"Argument"}),o&&D.jsxs(D.Fragment,{children:[D.jsx("th",{className:"text-left py-1.5 px-2",children:"Model"}),D.jsx("th",{className:"text-right py-1.5 px-2",children:"In"}),D.jsx("th",{className:"text-right py-1.5 px-2",children:"Out"}),D.jsx("th",{className:"text-right py-1.5 px-2",children:"Cache"})]}),D.jsx("th",{className:"text-right py-1.5 px-2",children:"Duration"}),D.jsx("th",{className:"text-left py-1.5 px-3",children:"Status"})]})}),D.jsx("tbody",{children:a.map((s,f)=>D.jsxs("tr",{className:`border-b border-gray-800/50 ${s.is_error?"bg-red-950/20":f%2===1?"bg-gray-900/30":"hover:bg-gray-800/30"}`,children:[D.jsx("td",{className:"py-1 px-3 text-gray-500 font-mono whitespace-nowrap",children:s.timestamp.slice(11,19)}),D.jsx("td",{className:"py-1 px-2",children:D.jsx(TG,{server:s.server})}),D.jsx("td",{className:"py-1 px-2",children:D.jsx(EG,{risk:s.risk})}),D.jsx("td",{className:"py-1 px-2 text-gray-300 font-semibold whitespace-nowrap",children:s.tool}),D.jsx("td",{className:"py-1 px-2 text-gray-400 truncate max-w-[250px]",title:s.arg_display,children:s.arg_display}),o&&D.jsxs(D.Fragment,{children:[D.jsx("td",{className:"py-1 px-2 text-gray-500 truncate max-w-[100px]",title:s.model??void 0,children:s.model??""}),D.jsx("td",{className:"py-1 px-2 text-right text-cyan-400/70 whitespace-nowrap",children:s.input_tokens!=null?Wi(s.input_tokens):""}),D.jsx("td",{className:"py-1 px-2 text-right text-purple-400/70 whitespace-nowrap",children:s.output_tokens!=null?Wi(s.output_tokens):""}),D.jsx("td",{className:"py-1 px-2 text-right text-gray-500 whitespace-nowrap",children:s.cache_read_tokens!=null?Wi(s.cache_read_tokens):""})]}),D.jsx("td",{className:"py-1 px-2 text-right text-gray-500 whitespace-nowrap",children:s.duration_us>0?jG(s.duration_us):"—"}),D.jsx("td",{className:"py-1 px-3",children:s.is_error?D.jsx("span",{className:"text-red-400",title:s.error_message??void 0,children:"ERR"}):D.jsx("span",{className:"text-green-500",children:"OK"})})]},s.id))})]})})})]})}function TG({server:e}){const t={cursor:"bg-purple-500/20 text-purple-400","claude-code":"bg-blue-500/20 text-blue-400",vigilo:"bg-emerald-500/20 text-emerald-400"},a={"claude-code":"claude",vigilo:"mcp"}[e]??e;return D.jsx("span",{className:`text-[10px] font-bold px-1.5 py-0.5 rounded ${t[e]??"bg-gray-500/20 text-gray-400"}`,children:a})}function EG({risk:e}){const t={read:"bg-cyan-900/50 text-cyan-400",write:"bg-yellow-900/50 text-yellow-400",exec:"bg-red-900/50 text-red-400"};return D.jsx("span",{className:`text-[10px] font-bold font-mono px-2 py-0.5 rounded ${t[e]??"text-gray-500"}`,children:e.toUpperCase()})}function Wi(e){return e>=1e6?`${(e/1e6).toFixed(1)}M`:e>=1e3?`${Math.floor(e/1e3)}K`:String(e)}function jG(e){return e<1e3?`${e}µs`:e<1e6?`${(e/1e3).toFixed(1)}ms`:`${(e/1e6).toFixed(1)}s`}cP.createRoot(document.getElementById("root")).render(D.jsx(J.StrictMode,{children:D.jsx(OG,{})}));
+In order to be iterable, non-array objects must have a [Symbol.iterator]() method.`)}function NN(e,t){if(e){if(typeof e=="string")return E0(e,t);var n=Object.prototype.toString.call(e).slice(8,-1);if(n==="Object"&&e.constructor&&(n=e.constructor.name),n==="Map"||n==="Set")return Array.from(e);if(n==="Arguments"||/^(?:Ui|I)nt(?:8|16|32)(?:Clamped)?Array$/.test(n))return E0(e,t)}}function UI(e){if(typeof Symbol<"u"&&e[Symbol.iterator]!=null||e["@@iterator"]!=null)return Array.from(e)}function kI(e){if(Array.isArray(e))return E0(e)}function E0(e,t){(t==null||t>e.length)&&(t=e.length);for(var n=0,a=new Array(t);n<t;n++)a[n]=e[n];return a}function O2(e,t){var n=Object.keys(e);if(Object.getOwnPropertySymbols){var a=Object.getOwnPropertySymbols(e);t&&(a=a.filter(function(u){return Object.getOwnPropertyDescriptor(e,u).enumerable})),n.push.apply(n,a)}return n}function ne(e){for(var t=1;t<arguments.length;t++){var n=arguments[t]!=null?arguments[t]:{};t%2?O2(Object(n),!0).forEach(function(a){be(e,a,n[a])}):Object.getOwnPropertyDescriptors?Object.defineProperties(e,Object.getOwnPropertyDescriptors(n)):O2(Object(n)).forEach(function(a){Object.defineProperty(e,a,Object.getOwnPropertyDescriptor(n,a))})}return e}function be(e,t,n){return t=DN(t),t in e?Object.defineProperty(e,t,{value:n,enumerable:!0,configurable:!0,writable:!0}):e[t]=n,e}function DN(e){var t=HI(e,"string");return gu(t)=="symbol"?t:t+""}function HI(e,t){if(gu(e)!="object"||!e)return e;var n=e[Symbol.toPrimitive];if(n!==void 0){var a=n.call(e,t);if(gu(a)!="object")return a;throw new TypeError("@@toPrimitive must return a primitive value.")}return(t==="string"?String:Number)(e)}var II={xAxis:["bottom","top"],yAxis:["left","right"]},GI={width:"100%",height:"100%"},RN={x:0,y:0};function cs(e){return e}var YI=function(t,n){return n==="horizontal"?t.x:n==="vertical"?t.y:n==="centric"?t.angle:t.radius},XI=function(t,n,a,u){var o=n.find(function(m){return m&&m.index===a});if(o){if(t==="horizontal")return{x:o.coordinate,y:u.y};if(t==="vertical")return{x:u.x,y:o.coordinate};if(t==="centric"){var s=o.coordinate,f=u.radius;return ne(ne(ne({},u),qt(u.cx,u.cy,f,s)),{},{angle:s,radius:f})}var d=o.coordinate,h=u.angle;return ne(ne(ne({},u),qt(u.cx,u.cy,d,h)),{},{angle:h,radius:d})}return RN},Bf=function(t,n){var a=n.graphicalItems,u=n.dataStartIndex,o=n.dataEndIndex,s=(a??[]).reduce(function(f,d){var h=d.props.data;return h&&h.length?[].concat(bu(f),bu(h)):f},[]);return s.length>0?s:t&&t.length&&fe(u)&&fe(o)?t.slice(u,o+1):[]};function PN(e){return e==="number"?[0,"auto"]:void 0}var j0=function(t,n,a,u){var o=t.graphicalItems,s=t.tooltipAxis,f=Bf(n,t);return a<0||!o||!o.length||a>=f.length?null:o.reduce(function(d,h){var m,y=(m=h.props.data)!==null&&m!==void 0?m:n;y&&t.dataStartIndex+t.dataEndIndex!==0&&t.dataEndIndex-t.dataStartIndex>=a&&(y=y.slice(t.dataStartIndex,t.dataEndIndex+1));var g;if(s.dataKey&&!s.allowDuplicatedCategory){var b=y===void 0?f:y;g=vg(b,s.dataKey,u)}else g=y&&y[a]||f[a];return g?[].concat(bu(d),[zC(h,g)]):d},[])},A2=function(t,n,a,u){var o=u||{x:t.chartX,y:t.chartY},s=YI(o,a),f=t.orderedTooltipTicks,d=t.tooltipAxis,h=t.tooltipTicks,m=p8(s,f,h,d);if(m>=0&&h){var y=h[m]&&h[m].value,g=j0(t,n,m,y),b=XI(a,f,m,o);return{activeTooltipIndex:m,activeLabel:y,activePayload:g,activeCoordinate:b}}return null},KI=function(t,n){var a=n.axes,u=n.graphicalItems,o=n.axisType,s=n.axisIdKey,f=n.stackGroups,d=n.dataStartIndex,h=n.dataEndIndex,m=t.layout,y=t.children,g=t.stackOffset,b=RC(m,o);return a.reduce(function(O,_){var x,T=_.type.defaultProps!==void 0?ne(ne({},_.type.defaultProps),_.props):_.props,j=T.type,M=T.dataKey,N=T.allowDataOverflow,A=T.allowDuplicatedCategory,w=T.scale,E=T.ticks,P=T.includeHidden,B=T[s];if(O[B])return O;var W=Bf(t.data,{graphicalItems:u.filter(function(U){var re,se=s in U.props?U.props[s]:(re=U.type.defaultProps)===null||re===void 0?void 0:re[s];return se===B}),dataStartIndex:d,dataEndIndex:h}),H=W.length,L,V,Q;bI(T.domain,N,j)&&(L=Vg(T.domain,null,N),b&&(j==="number"||w!=="auto")&&(Q=Bl(W,M,"category")));var F=PN(j);if(!L||L.length===0){var q,I=(q=T.domain)!==null&&q!==void 0?q:F;if(M){if(L=Bl(W,M,j),j==="category"&&b){var ee=l3(L);A&&ee?(V=L,L=Ks(0,H)):A||(L=UE(I,L,_).reduce(function(U,re){return U.indexOf(re)>=0?U:[].concat(bu(U),[re])},[]))}else if(j==="category")A?L=L.filter(function(U){return U!==""&&!Le(U)}):L=UE(I,L,_).reduce(function(U,re){return U.indexOf(re)>=0||re===""||Le(re)?U:[].concat(bu(U),[re])},[]);else if(j==="number"){var le=x8(W,u.filter(function(U){var re,se,ve=s in U.props?U.props[s]:(re=U.type.defaultProps)===null||re===void 0?void 0:re[s],me="hide"in U.props?U.props.hide:(se=U.type.defaultProps)===null||se===void 0?void 0:se.hide;return ve===B&&(P||!me)}),M,o,m);le&&(L=le)}b&&(j==="number"||w!=="auto")&&(Q=Bl(W,M,"category"))}else b?L=Ks(0,H):f&&f[B]&&f[B].hasStack&&j==="number"?L=g==="expand"?[0,1]:qC(f[B].stackGroups,d,h):L=DC(W,u.filter(function(U){var re=s in U.props?U.props[s]:U.type.defaultProps[s],se="hide"in U.props?U.props.hide:U.type.defaultProps.hide;return re===B&&(P||!se)}),j,m,!0);if(j==="number")L=w0(y,L,B,o,E),I&&(L=Vg(I,L,N));else if(j==="category"&&I){var oe=I,R=L.every(function(U){return oe.indexOf(U)>=0});R&&(L=oe)}}return ne(ne({},O),{},be({},B,ne(ne({},T),{},{axisType:o,domain:L,categoricalDomain:Q,duplicateDomain:V,originalDomain:(x=T.domain)!==null&&x!==void 0?x:F,isCategorical:b,layout:m})))},{})},VI=function(t,n){var a=n.graphicalItems,u=n.Axis,o=n.axisType,s=n.axisIdKey,f=n.stackGroups,d=n.dataStartIndex,h=n.dataEndIndex,m=t.layout,y=t.children,g=Bf(t.data,{graphicalItems:a,dataStartIndex:d,dataEndIndex:h}),b=g.length,O=RC(m,o),_=-1;return a.reduce(function(x,T){var j=T.type.defaultProps!==void 0?ne(ne({},T.type.defaultProps),T.props):T.props,M=j[s],N=PN("number");if(!x[M]){_++;var A;return O?A=Ks(0,b):f&&f[M]&&f[M].hasStack?(A=qC(f[M].stackGroups,d,h),A=w0(y,A,M,o)):(A=Vg(N,DC(g,a.filter(function(w){var E,P,B=s in w.props?w.props[s]:(E=w.type.defaultProps)===null||E===void 0?void 0:E[s],W="hide"in w.props?w.props.hide:(P=w.type.defaultProps)===null||P===void 0?void 0:P.hide;return B===M&&!W}),"number",m),u.defaultProps.allowDataOverflow),A=w0(y,A,M,o)),ne(ne({},x),{},be({},M,ne(ne({axisType:o},u.defaultProps),{},{hide:!0,orientation:En(II,"".concat(o,".").concat(_%2),null),domain:A,originalDomain:N,isCategorical:O,layout:m})))}return x},{})},ZI=function(t,n){var a=n.axisType,u=a===void 0?"xAxis":a,o=n.AxisComp,s=n.graphicalItems,f=n.stackGroups,d=n.dataStartIndex,h=n.dataEndIndex,m=t.children,y="".concat(u,"Id"),g=Ln(m,o),b={};return g&&g.length?b=KI(t,{axes:g,graphicalItems:s,axisType:u,axisIdKey:y,stackGroups:f,dataStartIndex:d,dataEndIndex:h}):s&&s.length&&(b=VI(t,{Axis:o,graphicalItems:s,axisType:u,axisIdKey:y,stackGroups:f,dataStartIndex:d,dataEndIndex:h})),b},FI=function(t){var n=ia(t),a=_r(n,!1,!0);return{tooltipTicks:a,orderedTooltipTicks:F0(a,function(u){return u.coordinate}),tooltipAxis:n,tooltipAxisBandSize:$s(n,a)}},w2=function(t){var n=t.children,a=t.defaultShowTooltip,u=fn(n,su),o=0,s=0;return t.data&&t.data.length!==0&&(s=t.data.length-1),u&&u.props&&(u.props.startIndex>=0&&(o=u.props.startIndex),u.props.endIndex>=0&&(s=u.props.endIndex)),{chartX:0,chartY:0,dataStartIndex:o,dataEndIndex:s,activeTooltipIndex:-1,isTooltipActive:!!a}},QI=function(t){return!t||!t.length?!1:t.some(function(n){var a=Or(n&&n.type);return a&&a.indexOf("Bar")>=0})},T2=function(t){return t==="horizontal"?{numericAxisName:"yAxis",cateAxisName:"xAxis"}:t==="vertical"?{numericAxisName:"xAxis",cateAxisName:"yAxis"}:t==="centric"?{numericAxisName:"radiusAxis",cateAxisName:"angleAxis"}:{numericAxisName:"angleAxis",cateAxisName:"radiusAxis"}},WI=function(t,n){var a=t.props,u=t.graphicalItems,o=t.xAxisMap,s=o===void 0?{}:o,f=t.yAxisMap,d=f===void 0?{}:f,h=a.width,m=a.height,y=a.children,g=a.margin||{},b=fn(y,su),O=fn(y,Zi),_=Object.keys(d).reduce(function(A,w){var E=d[w],P=E.orientation;return!E.mirror&&!E.hide?ne(ne({},A),{},be({},P,A[P]+E.width)):A},{left:g.left||0,right:g.right||0}),x=Object.keys(s).reduce(function(A,w){var E=s[w],P=E.orientation;return!E.mirror&&!E.hide?ne(ne({},A),{},be({},P,En(A,"".concat(P))+E.height)):A},{top:g.top||0,bottom:g.bottom||0}),T=ne(ne({},x),_),j=T.bottom;b&&(T.bottom+=b.props.height||su.defaultProps.height),O&&n&&(T=g8(T,u,a,n));var M=h-T.left-T.right,N=m-T.top-T.bottom;return ne(ne({brushBottom:j},T),{},{width:Math.max(M,0),height:Math.max(N,0)})},JI=function(t,n){if(n==="xAxis")return t[n].width;if(n==="yAxis")return t[n].height},eG=function(t){var n=t.chartName,a=t.GraphicalChild,u=t.defaultTooltipEventType,o=u===void 0?"axis":u,s=t.validateTooltipEventTypes,f=s===void 0?["axis"]:s,d=t.axisComponents,h=t.legendContent,m=t.formatAxisMap,y=t.defaultProps,g=function(T,j){var M=j.graphicalItems,N=j.stackGroups,A=j.offset,w=j.updateId,E=j.dataStartIndex,P=j.dataEndIndex,B=T.barSize,W=T.layout,H=T.barGap,L=T.barCategoryGap,V=T.maxBarSize,Q=T2(W),F=Q.numericAxisName,q=Q.cateAxisName,I=QI(M),ee=[];return M.forEach(function(le,oe){var R=Bf(T.data,{graphicalItems:[le],dataStartIndex:E,dataEndIndex:P}),U=le.type.defaultProps!==void 0?ne(ne({},le.type.defaultProps),le.props):le.props,re=U.dataKey,se=U.maxBarSize,ve=U["".concat(F,"Id")],me=U["".concat(q,"Id")],Te={},Ue=d.reduce(function(Nn,er){var ju=j["".concat(er.axisType,"Map")],$t=U["".concat(er.axisType,"Id")];ju&&ju[$t]||er.axisType==="zAxis"||Ka();var No=ju[$t];return ne(ne({},Nn),{},be(be({},er.axisType,No),"".concat(er.axisType,"Ticks"),_r(No)))},Te),ce=Ue[q],xe=Ue["".concat(q,"Ticks")],Se=N&&N[ve]&&N[ve].hasStack&&D8(le,N[ve].stackGroups),ue=Or(le.type).indexOf("Bar")>=0,Je=$s(ce,xe),Ae=[],it=I&&y8({barSize:B,stackGroups:N,totalSize:JI(Ue,q)});if(ue){var ut,zt,Cn=Le(se)?V:se,kn=(ut=(zt=$s(ce,xe,!0))!==null&&zt!==void 0?zt:Cn)!==null&&ut!==void 0?ut:0;Ae=m8({barGap:H,barCategoryGap:L,bandSize:kn!==Je?kn:Je,sizeList:it[me],maxBarSize:Cn}),kn!==Je&&(Ae=Ae.map(function(Nn){return ne(ne({},Nn),{},{position:ne(ne({},Nn.position),{},{offset:Nn.position.offset-kn/2})})}))}var Dr=le&&le.type&&le.type.getComposedData;Dr&&ee.push({props:ne(ne({},Dr(ne(ne({},Ue),{},{displayedData:R,props:T,dataKey:re,item:le,bandSize:Je,barPosition:Ae,offset:A,stackedData:Se,layout:W,dataStartIndex:E,dataEndIndex:P}))),{},be(be(be({key:le.key||"item-".concat(oe)},F,Ue[F]),q,Ue[q]),"animationId",w)),childIndex:b3(le,T.children),item:le})}),ee},b=function(T,j){var M=T.props,N=T.dataStartIndex,A=T.dataEndIndex,w=T.updateId;if(!tA({props:M}))return null;var E=M.children,P=M.layout,B=M.stackOffset,W=M.data,H=M.reverseStackOrder,L=T2(P),V=L.numericAxisName,Q=L.cateAxisName,F=Ln(E,a),q=M8(W,F,"".concat(V,"Id"),"".concat(Q,"Id"),B,H),I=d.reduce(function(U,re){var se="".concat(re.axisType,"Map");return ne(ne({},U),{},be({},se,ZI(M,ne(ne({},re),{},{graphicalItems:F,stackGroups:re.axisType===V&&q,dataStartIndex:N,dataEndIndex:A}))))},{}),ee=WI(ne(ne({},I),{},{props:M,graphicalItems:F}),j==null?void 0:j.legendBBox);Object.keys(I).forEach(function(U){I[U]=m(M,I[U],ee,U.replace("Map",""),n)});var le=I["".concat(Q,"Map")],oe=FI(le),R=g(M,ne(ne({},I),{},{dataStartIndex:N,dataEndIndex:A,updateId:w,graphicalItems:F,stackGroups:q,offset:ee}));return ne(ne({formattedGraphicalItems:R,graphicalItems:F,offset:ee,stackGroups:q},oe),I)},O=(function(x){function T(j){var M,N,A;return DI(this,T),A=qI(this,T,[j]),be(A,"eventEmitterSymbol",Symbol("rechartsEventEmitter")),be(A,"accessibilityManager",new gI),be(A,"handleLegendBBoxUpdate",function(w){if(w){var E=A.state,P=E.dataStartIndex,B=E.dataEndIndex,W=E.updateId;A.setState(ne({legendBBox:w},b({props:A.props,dataStartIndex:P,dataEndIndex:B,updateId:W},ne(ne({},A.state),{},{legendBBox:w}))))}}),be(A,"handleReceiveSyncEvent",function(w,E,P){if(A.props.syncId===w){if(P===A.eventEmitterSymbol&&typeof A.props.syncMethod!="function")return;A.applySyncEvent(E)}}),be(A,"handleBrushChange",function(w){var E=w.startIndex,P=w.endIndex;if(E!==A.state.dataStartIndex||P!==A.state.dataEndIndex){var B=A.state.updateId;A.setState(function(){return ne({dataStartIndex:E,dataEndIndex:P},b({props:A.props,dataStartIndex:E,dataEndIndex:P,updateId:B},A.state))}),A.triggerSyncEvent({dataStartIndex:E,dataEndIndex:P})}}),be(A,"handleMouseEnter",function(w){var E=A.getMouseInfo(w);if(E){var P=ne(ne({},E),{},{isTooltipActive:!0});A.setState(P),A.triggerSyncEvent(P);var B=A.props.onMouseEnter;Ee(B)&&B(P,w)}}),be(A,"triggeredAfterMouseMove",function(w){var E=A.getMouseInfo(w),P=E?ne(ne({},E),{},{isTooltipActive:!0}):{isTooltipActive:!1};A.setState(P),A.triggerSyncEvent(P);var B=A.props.onMouseMove;Ee(B)&&B(P,w)}),be(A,"handleItemMouseEnter",function(w){A.setState(function(){return{isTooltipActive:!0,activeItem:w,activePayload:w.tooltipPayload,activeCoordinate:w.tooltipPosition||{x:w.cx,y:w.cy}}})}),be(A,"handleItemMouseLeave",function(){A.setState(function(){return{isTooltipActive:!1}})}),be(A,"handleMouseMove",function(w){w.persist(),A.throttleTriggeredAfterMouseMove(w)}),be(A,"handleMouseLeave",function(w){A.throttleTriggeredAfterMouseMove.cancel();var E={isTooltipActive:!1};A.setState(E),A.triggerSyncEvent(E);var P=A.props.onMouseLeave;Ee(P)&&P(E,w)}),be(A,"handleOuterEvent",function(w){var E=g3(w),P=En(A.props,"".concat(E));if(E&&Ee(P)){var B,W;/.*touch.*/i.test(E)?W=A.getMouseInfo(w.changedTouches[0]):W=A.getMouseInfo(w),P((B=W)!==null&&B!==void 0?B:{},w)}}),be(A,"handleClick",function(w){var E=A.getMouseInfo(w);if(E){var P=ne(ne({},E),{},{isTooltipActive:!0});A.setState(P),A.triggerSyncEvent(P);var B=A.props.onClick;Ee(B)&&B(P,w)}}),be(A,"handleMouseDown",function(w){var E=A.props.onMouseDown;if(Ee(E)){var P=A.getMouseInfo(w);E(P,w)}}),be(A,"handleMouseUp",function(w){var E=A.props.onMouseUp;if(Ee(E)){var P=A.getMouseInfo(w);E(P,w)}}),be(A,"handleTouchMove",function(w){w.changedTouches!=null&&w.changedTouches.length>0&&A.throttleTriggeredAfterMouseMove(w.changedTouches[0])}),be(A,"handleTouchStart",function(w){w.changedTouches!=null&&w.changedTouches.length>0&&A.handleMouseDown(w.changedTouches[0])}),be(A,"handleTouchEnd",function(w){w.changedTouches!=null&&w.changedTouches.length>0&&A.handleMouseUp(w.changedTouches[0])}),be(A,"handleDoubleClick",function(w){var E=A.props.onDoubleClick;if(Ee(E)){var P=A.getMouseInfo(w);E(P,w)}}),be(A,"handleContextMenu",function(w){var E=A.props.onContextMenu;if(Ee(E)){var P=A.getMouseInfo(w);E(P,w)}}),be(A,"triggerSyncEvent",function(w){A.props.syncId!==void 0&&ug.emit(lg,A.props.syncId,w,A.eventEmitterSymbol)}),be(A,"applySyncEvent",function(w){var E=A.props,P=E.layout,B=E.syncMethod,W=A.state.updateId,H=w.dataStartIndex,L=w.dataEndIndex;if(w.dataStartIndex!==void 0||w.dataEndIndex!==void 0)A.setState(ne({dataStartIndex:H,dataEndIndex:L},b({props:A.props,dataStartIndex:H,dataEndIndex:L,updateId:W},A.state)));else if(w.activeTooltipIndex!==void 0){var V=w.chartX,Q=w.chartY,F=w.activeTooltipIndex,q=A.state,I=q.offset,ee=q.tooltipTicks;if(!I)return;if(typeof B=="function")F=B(ee,w);else if(B==="value"){F=-1;for(var le=0;le<ee.length;le++)if(ee[le].value===w.activeLabel){F=le;break}}var oe=ne(ne({},I),{},{x:I.left,y:I.top}),R=Math.min(V,oe.x+oe.width),U=Math.min(Q,oe.y+oe.height),re=ee[F]&&ee[F].value,se=j0(A.state,A.props.data,F),ve=ee[F]?{x:P==="horizontal"?ee[F].coordinate:R,y:P==="horizontal"?U:ee[F].coordinate}:RN;A.setState(ne(ne({},w),{},{activeLabel:re,activeCoordinate:ve,activePayload:se,activeTooltipIndex:F}))}else A.setState(w)}),be(A,"renderCursor",function(w){var E,P=A.state,B=P.isTooltipActive,W=P.activeCoordinate,H=P.activePayload,L=P.offset,V=P.activeTooltipIndex,Q=P.tooltipAxisBandSize,F=A.getTooltipEventType(),q=(E=w.props.active)!==null&&E!==void 0?E:B,I=A.props.layout,ee=w.key||"_recharts-cursor";return G.createElement(wI,{key:ee,activeCoordinate:W,activePayload:H,activeTooltipIndex:V,chartName:n,element:w,isActive:q,layout:I,offset:L,tooltipAxisBandSize:Q,tooltipEventType:F})}),be(A,"renderPolarAxis",function(w,E,P){var B=En(w,"type.axisType"),W=En(A.state,"".concat(B,"Map")),H=w.type.defaultProps,L=H!==void 0?ne(ne({},H),w.props):w.props,V=W&&W[L["".concat(B,"Id")]];return J.cloneElement(w,ne(ne({},V),{},{className:Be(B,V.className),key:w.key||"".concat(E,"-").concat(P),ticks:_r(V,!0)}))}),be(A,"renderPolarGrid",function(w){var E=w.props,P=E.radialLines,B=E.polarAngles,W=E.polarRadius,H=A.state,L=H.radiusAxisMap,V=H.angleAxisMap,Q=ia(L),F=ia(V),q=F.cx,I=F.cy,ee=F.innerRadius,le=F.outerRadius;return J.cloneElement(w,{polarAngles:Array.isArray(B)?B:_r(F,!0).map(function(oe){return oe.coordinate}),polarRadius:Array.isArray(W)?W:_r(Q,!0).map(function(oe){return oe.coordinate}),cx:q,cy:I,innerRadius:ee,outerRadius:le,key:w.key||"polar-grid",radialLines:P})}),be(A,"renderLegend",function(){var w=A.state.formattedGraphicalItems,E=A.props,P=E.children,B=E.width,W=E.height,H=A.props.margin||{},L=B-(H.left||0)-(H.right||0),V=CC({children:P,formattedGraphicalItems:w,legendWidth:L,legendContent:h});if(!V)return null;var Q=V.item,F=_2(V,TI);return J.cloneElement(Q,ne(ne({},F),{},{chartWidth:B,chartHeight:W,margin:H,onBBoxUpdate:A.handleLegendBBoxUpdate}))}),be(A,"renderTooltip",function(){var w,E=A.props,P=E.children,B=E.accessibilityLayer,W=fn(P,Ut);if(!W)return null;var H=A.state,L=H.isTooltipActive,V=H.activeCoordinate,Q=H.activePayload,F=H.activeLabel,q=H.offset,I=(w=W.props.active)!==null&&w!==void 0?w:L;return J.cloneElement(W,{viewBox:ne(ne({},q),{},{x:q.left,y:q.top}),active:I,label:F,payload:I?Q:[],coordinate:V,accessibilityLayer:B})}),be(A,"renderBrush",function(w){var E=A.props,P=E.margin,B=E.data,W=A.state,H=W.offset,L=W.dataStartIndex,V=W.dataEndIndex,Q=W.updateId;return J.cloneElement(w,{key:w.key||"_recharts-brush",onChange:as(A.handleBrushChange,w.props.onChange),data:B,x:fe(w.props.x)?w.props.x:H.left,y:fe(w.props.y)?w.props.y:H.top+H.height+H.brushBottom-(P.bottom||0),width:fe(w.props.width)?w.props.width:H.width,startIndex:L,endIndex:V,updateId:"brush-".concat(Q)})}),be(A,"renderReferenceElement",function(w,E,P){if(!w)return null;var B=A,W=B.clipPathId,H=A.state,L=H.xAxisMap,V=H.yAxisMap,Q=H.offset,F=w.type.defaultProps||{},q=w.props,I=q.xAxisId,ee=I===void 0?F.xAxisId:I,le=q.yAxisId,oe=le===void 0?F.yAxisId:le;return J.cloneElement(w,{key:w.key||"".concat(E,"-").concat(P),xAxis:L[ee],yAxis:V[oe],viewBox:{x:Q.left,y:Q.top,width:Q.width,height:Q.height},clipPathId:W})}),be(A,"renderActivePoints",function(w){var E=w.item,P=w.activePoint,B=w.basePoint,W=w.childIndex,H=w.isRange,L=[],V=E.props.key,Q=E.item.type.defaultProps!==void 0?ne(ne({},E.item.type.defaultProps),E.item.props):E.item.props,F=Q.activeDot,q=Q.dataKey,I=ne(ne({index:W,dataKey:q,cx:P.x,cy:P.y,r:4,fill:Ob(E.item),strokeWidth:2,stroke:"#fff",payload:P.payload,value:P.value},$e(F,!1)),ss(F));return L.push(T.renderActiveDot(F,I,"".concat(V,"-activePoint-").concat(W))),B?L.push(T.renderActiveDot(F,ne(ne({},I),{},{cx:B.x,cy:B.y}),"".concat(V,"-basePoint-").concat(W))):H&&L.push(null),L}),be(A,"renderGraphicChild",function(w,E,P){var B=A.filterFormatItem(w,E,P);if(!B)return null;var W=A.getTooltipEventType(),H=A.state,L=H.isTooltipActive,V=H.tooltipAxis,Q=H.activeTooltipIndex,F=H.activeLabel,q=A.props.children,I=fn(q,Ut),ee=B.props,le=ee.points,oe=ee.isRange,R=ee.baseLine,U=B.item.type.defaultProps!==void 0?ne(ne({},B.item.type.defaultProps),B.item.props):B.item.props,re=U.activeDot,se=U.hide,ve=U.activeBar,me=U.activeShape,Te=!!(!se&&L&&I&&(re||ve||me)),Ue={};W!=="axis"&&I&&I.props.trigger==="click"?Ue={onClick:as(A.handleItemMouseEnter,w.props.onClick)}:W!=="axis"&&(Ue={onMouseLeave:as(A.handleItemMouseLeave,w.props.onMouseLeave),onMouseEnter:as(A.handleItemMouseEnter,w.props.onMouseEnter)});var ce=J.cloneElement(w,ne(ne({},B.props),Ue));function xe(er){return typeof V.dataKey=="function"?V.dataKey(er.payload):null}if(Te)if(Q>=0){var Se,ue;if(V.dataKey&&!V.allowDuplicatedCategory){var Je=typeof V.dataKey=="function"?xe:"payload.".concat(V.dataKey.toString());Se=vg(le,Je,F),ue=oe&&R&&vg(R,Je,F)}else Se=le==null?void 0:le[Q],ue=oe&&R&&R[Q];if(me||ve){var Ae=w.props.activeIndex!==void 0?w.props.activeIndex:Q;return[J.cloneElement(w,ne(ne(ne({},B.props),Ue),{},{activeIndex:Ae})),null,null]}if(!Le(Se))return[ce].concat(bu(A.renderActivePoints({item:B,activePoint:Se,basePoint:ue,childIndex:Q,isRange:oe})))}else{var it,ut=(it=A.getItemByXY(A.state.activeCoordinate))!==null&&it!==void 0?it:{graphicalItem:ce},zt=ut.graphicalItem,Cn=zt.item,kn=Cn===void 0?w:Cn,Dr=zt.childIndex,Nn=ne(ne(ne({},B.props),Ue),{},{activeIndex:Dr});return[J.cloneElement(kn,Nn),null,null]}return oe?[ce,null,null]:[ce,null]}),be(A,"renderCustomized",function(w,E,P){return J.cloneElement(w,ne(ne({key:"recharts-customized-".concat(P)},A.props),A.state))}),be(A,"renderMap",{CartesianGrid:{handler:cs,once:!0},ReferenceArea:{handler:A.renderReferenceElement},ReferenceLine:{handler:cs},ReferenceDot:{handler:A.renderReferenceElement},XAxis:{handler:cs},YAxis:{handler:cs},Brush:{handler:A.renderBrush,once:!0},Bar:{handler:A.renderGraphicChild},Line:{handler:A.renderGraphicChild},Area:{handler:A.renderGraphicChild},Radar:{handler:A.renderGraphicChild},RadialBar:{handler:A.renderGraphicChild},Scatter:{handler:A.renderGraphicChild},Pie:{handler:A.renderGraphicChild},Funnel:{handler:A.renderGraphicChild},Tooltip:{handler:A.renderCursor,once:!0},PolarGrid:{handler:A.renderPolarGrid,once:!0},PolarAngleAxis:{handler:A.renderPolarAxis},PolarRadiusAxis:{handler:A.renderPolarAxis},Customized:{handler:A.renderCustomized}}),A.clipPathId="".concat((M=j.id)!==null&&M!==void 0?M:df("recharts"),"-clip"),A.throttleTriggeredAfterMouseMove=CM(A.triggeredAfterMouseMove,(N=j.throttleDelay)!==null&&N!==void 0?N:1e3/60),A.state={},A}return BI(T,x),PI(T,[{key:"componentDidMount",value:function(){var M,N;this.addListener(),this.accessibilityManager.setDetails({container:this.container,offset:{left:(M=this.props.margin.left)!==null&&M!==void 0?M:0,top:(N=this.props.margin.top)!==null&&N!==void 0?N:0},coordinateList:this.state.tooltipTicks,mouseHandlerCallback:this.triggeredAfterMouseMove,layout:this.props.layout}),this.displayDefaultTooltip()}},{key:"displayDefaultTooltip",value:function(){var M=this.props,N=M.children,A=M.data,w=M.height,E=M.layout,P=fn(N,Ut);if(P){var B=P.props.defaultIndex;if(!(typeof B!="number"||B<0||B>this.state.tooltipTicks.length-1)){var W=this.state.tooltipTicks[B]&&this.state.tooltipTicks[B].value,H=j0(this.state,A,B,W),L=this.state.tooltipTicks[B].coordinate,V=(this.state.offset.top+w)/2,Q=E==="horizontal",F=Q?{x:L,y:V}:{y:L,x:V},q=this.state.formattedGraphicalItems.find(function(ee){var le=ee.item;return le.type.name==="Scatter"});q&&(F=ne(ne({},F),q.props.points[B].tooltipPosition),H=q.props.points[B].tooltipPayload);var I={activeTooltipIndex:B,isTooltipActive:!0,activeLabel:W,activePayload:H,activeCoordinate:F};this.setState(I),this.renderCursor(P),this.accessibilityManager.setIndex(B)}}}},{key:"getSnapshotBeforeUpdate",value:function(M,N){if(!this.props.accessibilityLayer)return null;if(this.state.tooltipTicks!==N.tooltipTicks&&this.accessibilityManager.setDetails({coordinateList:this.state.tooltipTicks}),this.props.layout!==M.layout&&this.accessibilityManager.setDetails({layout:this.props.layout}),this.props.margin!==M.margin){var A,w;this.accessibilityManager.setDetails({offset:{left:(A=this.props.margin.left)!==null&&A!==void 0?A:0,top:(w=this.props.margin.top)!==null&&w!==void 0?w:0}})}return null}},{key:"componentDidUpdate",value:function(M){yg([fn(M.children,Ut)],[fn(this.props.children,Ut)])||this.displayDefaultTooltip()}},{key:"componentWillUnmount",value:function(){this.removeListener(),this.throttleTriggeredAfterMouseMove.cancel()}},{key:"getTooltipEventType",value:function(){var M=fn(this.props.children,Ut);if(M&&typeof M.props.shared=="boolean"){var N=M.props.shared?"axis":"item";return f.indexOf(N)>=0?N:o}return o}},{key:"getMouseInfo",value:function(M){if(!this.container)return null;var N=this.container,A=N.getBoundingClientRect(),w=i$(A),E={chartX:Math.round(M.pageX-w.left),chartY:Math.round(M.pageY-w.top)},P=A.width/N.offsetWidth||1,B=this.inRange(E.chartX,E.chartY,P);if(!B)return null;var W=this.state,H=W.xAxisMap,L=W.yAxisMap,V=this.getTooltipEventType(),Q=A2(this.state,this.props.data,this.props.layout,B);if(V!=="axis"&&H&&L){var F=ia(H).scale,q=ia(L).scale,I=F&&F.invert?F.invert(E.chartX):null,ee=q&&q.invert?q.invert(E.chartY):null;return ne(ne({},E),{},{xValue:I,yValue:ee},Q)}return Q?ne(ne({},E),Q):null}},{key:"inRange",value:function(M,N){var A=arguments.length>2&&arguments[2]!==void 0?arguments[2]:1,w=this.props.layout,E=M/A,P=N/A;if(w==="horizontal"||w==="vertical"){var B=this.state.offset,W=E>=B.left&&E<=B.left+B.width&&P>=B.top&&P<=B.top+B.height;return W?{x:E,y:P}:null}var H=this.state,L=H.angleAxisMap,V=H.radiusAxisMap;if(L&&V){var Q=ia(L);return IE({x:E,y:P},Q)}return null}},{key:"parseEventsOfWrapper",value:function(){var M=this.props.children,N=this.getTooltipEventType(),A=fn(M,Ut),w={};A&&N==="axis"&&(A.props.trigger==="click"?w={onClick:this.handleClick}:w={onMouseEnter:this.handleMouseEnter,onDoubleClick:this.handleDoubleClick,onMouseMove:this.handleMouseMove,onMouseLeave:this.handleMouseLeave,onTouchMove:this.handleTouchMove,onTouchStart:this.handleTouchStart,onTouchEnd:this.handleTouchEnd,onContextMenu:this.handleContextMenu});var E=ss(this.props,this.handleOuterEvent);return ne(ne({},E),w)}},{key:"addListener",value:function(){ug.on(lg,this.handleReceiveSyncEvent)}},{key:"removeListener",value:function(){ug.removeListener(lg,this.handleReceiveSyncEvent)}},{key:"filterFormatItem",value:function(M,N,A){for(var w=this.state.formattedGraphicalItems,E=0,P=w.length;E<P;E++){var B=w[E];if(B.item===M||B.props.key===M.key||N===Or(B.item.type)&&A===B.childIndex)return B}return null}},{key:"renderClipPath",value:function(){var M=this.clipPathId,N=this.state.offset,A=N.left,w=N.top,E=N.height,P=N.width;return G.createElement("defs",null,G.createElement("clipPath",{id:M},G.createElement("rect",{x:A,y:w,height:E,width:P})))}},{key:"getXScales",value:function(){var M=this.state.xAxisMap;return M?Object.entries(M).reduce(function(N,A){var w=S2(A,2),E=w[0],P=w[1];return ne(ne({},N),{},be({},E,P.scale))},{}):null}},{key:"getYScales",value:function(){var M=this.state.yAxisMap;return M?Object.entries(M).reduce(function(N,A){var w=S2(A,2),E=w[0],P=w[1];return ne(ne({},N),{},be({},E,P.scale))},{}):null}},{key:"getXScaleByAxisId",value:function(M){var N;return(N=this.state.xAxisMap)===null||N===void 0||(N=N[M])===null||N===void 0?void 0:N.scale}},{key:"getYScaleByAxisId",value:function(M){var N;return(N=this.state.yAxisMap)===null||N===void 0||(N=N[M])===null||N===void 0?void 0:N.scale}},{key:"getItemByXY",value:function(M){var N=this.state,A=N.formattedGraphicalItems,w=N.activeItem;if(A&&A.length)for(var E=0,P=A.length;E<P;E++){var B=A[E],W=B.props,H=B.item,L=H.type.defaultProps!==void 0?ne(ne({},H.type.defaultProps),H.props):H.props,V=Or(H.type);if(V==="Bar"){var Q=(W.data||[]).find(function(ee){return Tk(M,ee)});if(Q)return{graphicalItem:B,payload:Q}}else if(V==="RadialBar"){var F=(W.data||[]).find(function(ee){return IE(M,ee)});if(F)return{graphicalItem:B,payload:F}}else if(Nf(B,w)||Df(B,w)||po(B,w)){var q=y9({graphicalItem:B,activeTooltipItem:w,itemData:L.data}),I=L.activeIndex===void 0?q:L.activeIndex;return{graphicalItem:ne(ne({},B),{},{childIndex:I}),payload:po(B,w)?L.data[q]:B.props.data[q]}}}return null}},{key:"render",value:function(){var M=this;if(!tA(this))return null;var N=this.props,A=N.children,w=N.className,E=N.width,P=N.height,B=N.style,W=N.compact,H=N.title,L=N.desc,V=_2(N,EI),Q=$e(V,!1);if(W)return G.createElement(i2,{state:this.state,width:this.props.width,height:this.props.height,clipPathId:this.clipPathId},G.createElement(gg,Ki({},Q,{width:E,height:P,title:H,desc:L}),this.renderClipPath(),rA(A,this.renderMap)));if(this.props.accessibilityLayer){var F,q;Q.tabIndex=(F=this.props.tabIndex)!==null&&F!==void 0?F:0,Q.role=(q=this.props.role)!==null&&q!==void 0?q:"application",Q.onKeyDown=function(ee){M.accessibilityManager.keyboardEvent(ee)},Q.onFocus=function(){M.accessibilityManager.focus()}}var I=this.parseEventsOfWrapper();return G.createElement(i2,{state:this.state,width:this.props.width,height:this.props.height,clipPathId:this.clipPathId},G.createElement("div",Ki({className:Be("recharts-wrapper",w),style:ne({position:"relative",cursor:"default",width:E,height:P},B)},I,{ref:function(le){M.container=le}}),G.createElement(gg,Ki({},Q,{width:E,height:P,title:H,desc:L,style:GI}),this.renderClipPath(),rA(A,this.renderMap)),this.renderLegend(),this.renderTooltip()))}}])})(J.Component);be(O,"displayName",n),be(O,"defaultProps",ne({layout:"horizontal",stackOffset:"none",barCategoryGap:"10%",barGap:4,margin:{top:5,right:5,bottom:5,left:5},reverseStackOrder:!1,syncMethod:"index"},y)),be(O,"getDerivedStateFromProps",function(x,T){var j=x.dataKey,M=x.data,N=x.children,A=x.width,w=x.height,E=x.layout,P=x.stackOffset,B=x.margin,W=T.dataStartIndex,H=T.dataEndIndex;if(T.updateId===void 0){var L=w2(x);return ne(ne(ne({},L),{},{updateId:0},b(ne(ne({props:x},L),{},{updateId:0}),T)),{},{prevDataKey:j,prevData:M,prevWidth:A,prevHeight:w,prevLayout:E,prevStackOffset:P,prevMargin:B,prevChildren:N})}if(j!==T.prevDataKey||M!==T.prevData||A!==T.prevWidth||w!==T.prevHeight||E!==T.prevLayout||P!==T.prevStackOffset||!Vi(B,T.prevMargin)){var V=w2(x),Q={chartX:T.chartX,chartY:T.chartY,isTooltipActive:T.isTooltipActive},F=ne(ne({},A2(T,M,E)),{},{updateId:T.updateId+1}),q=ne(ne(ne({},V),Q),F);return ne(ne(ne({},q),b(ne({props:x},q),T)),{},{prevDataKey:j,prevData:M,prevWidth:A,prevHeight:w,prevLayout:E,prevStackOffset:P,prevMargin:B,prevChildren:N})}if(!yg(N,T.prevChildren)){var I,ee,le,oe,R=fn(N,su),U=R&&(I=(ee=R.props)===null||ee===void 0?void 0:ee.startIndex)!==null&&I!==void 0?I:W,re=R&&(le=(oe=R.props)===null||oe===void 0?void 0:oe.endIndex)!==null&&le!==void 0?le:H,se=U!==W||re!==H,ve=!Le(M),me=ve&&!se?T.updateId:T.updateId+1;return ne(ne({updateId:me},b(ne(ne({props:x},T),{},{updateId:me,dataStartIndex:U,dataEndIndex:re}),T)),{},{prevChildren:N,dataStartIndex:U,dataEndIndex:re})}return null}),be(O,"renderActiveDot",function(x,T,j){var M;return J.isValidElement(x)?M=J.cloneElement(x,T):Ee(x)?M=x(T):M=G.createElement(KC,T),G.createElement(Et,{className:"recharts-active-dot",key:j},M)});var _=J.forwardRef(function(T,j){return G.createElement(O,Ki({},T,{ref:j}))});return _.displayName=O.displayName,_},ei=eG({chartName:"BarChart",GraphicalChild:_t,defaultTooltipEventType:"axis",validateTooltipEventTypes:["axis","item"],axisComponents:[{axisType:"xAxis",AxisComp:Wn},{axisType:"yAxis",AxisComp:Jn}],formatAxisMap:dH});const tG=[{key:"cost",label:"Cost"},{key:"tokens",label:"Tokens"},{key:"calls",label:"Calls"},{key:"errors",label:"Errors"}];function nG(e){return e.slice(5).replace("-","/")}const Lf={contentStyle:{backgroundColor:"#111827",border:"1px solid #374151",borderRadius:4,fontSize:11},labelStyle:{color:"#9ca3af"}},Uf={dataKey:"date",tick:{fontSize:10,fill:"#6b7280"},axisLine:{stroke:"#374151"},tickLine:!1},kf={tick:{fontSize:10,fill:"#6b7280"},axisLine:!1,tickLine:!1,width:50},Hf={top:4,right:4,bottom:0,left:0},If={strokeDasharray:"3 3",stroke:"#1f2937",vertical:!1};function rG({timeline:e,activeTab:t,onTabChange:n}){const[a,u]=J.useState("cost"),o=t??a,s=d=>{u(d),n==null||n(d)};if(!e||e.length===0)return D.jsx(oG,{});const f=e.map(d=>({...d,date:nG(d.date),total_calls:d.reads+d.writes+d.execs}));return D.jsxs("div",{className:"bg-gray-900/80 rounded shadow-lg shadow-black/25 p-5",children:[D.jsx("div",{className:"flex items-center gap-1 mb-4",children:tG.map(d=>D.jsx("button",{onClick:()=>s(d.key),className:`px-2.5 py-1 text-xs rounded transition-colors ${o===d.key?"bg-gray-700 text-gray-100":"text-gray-500 hover:text-gray-300"}`,children:d.label},d.key))}),o==="cost"&&D.jsx(aG,{data:f}),o==="tokens"&&D.jsx(iG,{data:f}),o==="calls"&&D.jsx(uG,{data:f}),o==="errors"&&D.jsx(lG,{data:f})]})}function aG({data:e}){return D.jsx(Fa,{width:"100%",height:220,children:D.jsxs(ei,{data:e,margin:Hf,children:[D.jsx(va,{...If}),D.jsx(Wn,{...Uf}),D.jsx(Jn,{...kf,tickFormatter:t=>`$${t}`}),D.jsx(Ut,{...Lf}),D.jsx(_t,{dataKey:"cost_usd",name:"Cost ($)",fill:"#3B82F6",radius:[3,3,0,0]})]})})}function iG({data:e}){return D.jsx(Fa,{width:"100%",height:220,children:D.jsxs(ei,{data:e,margin:Hf,children:[D.jsx(va,{...If}),D.jsx(Wn,{...Uf}),D.jsx(Jn,{...kf}),D.jsx(Ut,{...Lf}),D.jsx(_t,{dataKey:"input_tokens",name:"Input",fill:"#3B82F6",stackId:"tok",radius:[0,0,0,0]}),D.jsx(_t,{dataKey:"output_tokens",name:"Output",fill:"#8B5CF6",stackId:"tok",radius:[3,3,0,0]})]})})}function uG({data:e}){return D.jsx(Fa,{width:"100%",height:220,children:D.jsxs(ei,{data:e,margin:Hf,children:[D.jsx(va,{...If}),D.jsx(Wn,{...Uf}),D.jsx(Jn,{...kf}),D.jsx(Ut,{...Lf}),D.jsx(_t,{dataKey:"reads",name:"Read",fill:"#06b6d4",stackId:"risk"}),D.jsx(_t,{dataKey:"writes",name:"Write",fill:"#3B82F6",stackId:"risk"}),D.jsx(_t,{dataKey:"execs",name:"Exec",fill:"#ef4444",stackId:"risk",radius:[3,3,0,0]})]})})}function lG({data:e}){return D.jsx(Fa,{width:"100%",height:220,children:D.jsxs(ei,{data:e,margin:Hf,children:[D.jsx(va,{...If}),D.jsx(Wn,{...Uf}),D.jsx(Jn,{...kf}),D.jsx(Ut,{...Lf}),D.jsx(_t,{dataKey:"errors",name:"Errors",fill:"#ef4444",radius:[3,3,0,0]})]})})}function oG(){return D.jsx("div",{className:"bg-gray-900/80 rounded shadow-lg shadow-black/25 p-5 flex items-center justify-center h-[280px]",children:D.jsx("span",{className:"text-gray-600 text-sm",children:"No timeline data yet"})})}function E2(e){return e>=1e6?`${(e/1e6).toFixed(1)}M`:e>=1e3?`${Math.floor(e/1e3)}K`:String(e)}const Eu="bg-gray-900/80 rounded shadow-lg shadow-black/25 p-5",Co="text-sm font-medium text-gray-400 uppercase tracking-wider mb-3",Pb={contentStyle:{backgroundColor:"#111827",border:"1px solid #374151",borderRadius:4,fontSize:11}},qb={strokeDasharray:"3 3",stroke:"#1f2937",vertical:!1};function cG({stats:e}){return e?D.jsxs("div",{className:"grid grid-cols-2 gap-4",children:[D.jsx(sG,{files:e.files}),D.jsx(fG,{models:e.models}),D.jsx(dG,{tools:e.tools}),D.jsx(hG,{projects:e.projects})]}):D.jsx("div",{className:`${Eu} flex items-center justify-center h-48`,children:D.jsx("span",{className:"text-gray-600 text-sm",children:"No data yet"})})}function sG({files:e}){const t=e.slice(0,8);return t.length===0?D.jsx(Gf,{label:"Most Edited Files"}):D.jsxs("div",{className:Eu,children:[D.jsx("h3",{className:Co,children:"Most Edited Files"}),D.jsx(Fa,{width:"100%",height:220,children:D.jsxs(ei,{data:t,layout:"vertical",margin:{left:80,right:30,top:0,bottom:0},children:[D.jsx(va,{...qb}),D.jsx(Wn,{type:"number",tick:{fontSize:10,fill:"#6b7280"},axisLine:!1,tickLine:!1}),D.jsx(Jn,{type:"category",dataKey:"file",tick:{fontSize:10,fill:"#9ca3af"},axisLine:!1,tickLine:!1,width:78}),D.jsx(Ut,{...Pb}),D.jsx(_t,{dataKey:"count",name:"Touches",fill:"#06b6d4",radius:[0,3,3,0],label:{position:"right",fontSize:10,fill:"#9ca3af"}})]})})]})}function fG({models:e}){if(e.length===0)return D.jsx(Gf,{label:"Model Efficiency"});const t=Math.max(...e.map(n=>n.cost_usd));return D.jsxs("div",{className:Eu,children:[D.jsx("h3",{className:Co,children:"Model Efficiency"}),D.jsx("div",{className:"overflow-x-auto",children:D.jsxs("table",{className:"w-full text-xs",children:[D.jsx("thead",{children:D.jsxs("tr",{className:"text-gray-500 border-b border-gray-800",children:[D.jsx("th",{className:"text-left py-1 pr-2",children:"Model"}),D.jsx("th",{className:"text-right py-1 px-2",children:"Calls"}),D.jsx("th",{className:"text-right py-1 px-2",children:"In"}),D.jsx("th",{className:"text-right py-1 px-2",children:"Out"}),D.jsx("th",{className:"text-right py-1 px-2",children:"Cache"}),D.jsx("th",{className:"text-right py-1 pl-2",children:"Cost"})]})}),D.jsx("tbody",{children:e.map(n=>{const a=n.input_tokens>0?(n.cache_read_tokens/(n.input_tokens+n.cache_read_tokens)*100).toFixed(0):"—",u=n.cost_usd===t&&n.cost_usd>0,o=n.cost_usd===0;return D.jsxs("tr",{className:`border-b border-gray-800/50 ${u?"border-l-2 border-l-emerald-400":""} ${o?"text-gray-600":"text-gray-300"}`,children:[D.jsx("td",{className:"py-1 pr-2 truncate max-w-[120px]",children:n.model}),D.jsx("td",{className:"text-right py-1 px-2 font-semibold",children:n.calls}),D.jsx("td",{className:"text-right py-1 px-2 text-gray-400",children:E2(n.input_tokens)}),D.jsx("td",{className:"text-right py-1 px-2 text-gray-400",children:E2(n.output_tokens)}),D.jsxs("td",{className:`text-right py-1 px-2 ${a==="100"?"text-emerald-400":a==="—"?"text-gray-600":"text-gray-500"}`,children:[a,"%"]}),D.jsxs("td",{className:`text-right py-1 pl-2 ${u?"text-emerald-400":o?"text-gray-600":"text-gray-400"}`,children:["$",n.cost_usd.toFixed(2)]})]},n.model)})})]})})]})}function dG({tools:e}){const t=e.slice(0,10).map(n=>({...n,ok_count:n.count-n.error_count}));return t.length===0?D.jsx(Gf,{label:"Tool Breakdown"}):D.jsxs("div",{className:Eu,children:[D.jsx("h3",{className:Co,children:"Tool Breakdown"}),D.jsx(Fa,{width:"100%",height:180,children:D.jsxs(ei,{data:t,layout:"vertical",margin:{left:70,right:8,top:0,bottom:0},children:[D.jsx(va,{...qb}),D.jsx(Wn,{type:"number",tick:{fontSize:10,fill:"#6b7280"},axisLine:!1,tickLine:!1}),D.jsx(Jn,{type:"category",dataKey:"tool",tick:{fontSize:10,fill:"#9ca3af"},axisLine:!1,tickLine:!1,width:68}),D.jsx(Ut,{...Pb}),D.jsx(_t,{dataKey:"ok_count",name:"OK",fill:"#3B82F6",stackId:"s"}),D.jsx(_t,{dataKey:"error_count",name:"Errors",fill:"#ef4444",stackId:"s",radius:[0,3,3,0]})]})})]})}function hG({projects:e}){const t=e.slice(0,8);return t.length===0?D.jsx(Gf,{label:"Risk by Project"}):D.jsxs("div",{className:Eu,children:[D.jsx("h3",{className:Co,children:"Risk by Project"}),D.jsx(Fa,{width:"100%",height:180,children:D.jsxs(ei,{data:t,layout:"vertical",margin:{left:70,right:8,top:0,bottom:0},children:[D.jsx(va,{...qb}),D.jsx(Wn,{type:"number",tick:{fontSize:10,fill:"#6b7280"},axisLine:!1,tickLine:!1}),D.jsx(Jn,{type:"category",dataKey:"name",tick:{fontSize:10,fill:"#9ca3af"},axisLine:!1,tickLine:!1,width:68}),D.jsx(Ut,{...Pb}),D.jsx(_t,{dataKey:"reads",name:"Read",fill:"#06b6d4",stackId:"risk"}),D.jsx(_t,{dataKey:"writes",name:"Write",fill:"#3B82F6",stackId:"risk"}),D.jsx(_t,{dataKey:"execs",name:"Exec",fill:"#ef4444",stackId:"risk",radius:[0,3,3,0]})]})})]})}function Gf({label:e}){return D.jsxs("div",{className:Eu,children:[D.jsx("h3",{className:Co,children:e}),D.jsx("div",{className:"flex items-center justify-center h-[180px]",children:D.jsx("span",{className:"text-gray-600 text-sm",children:"No data yet"})})]})}function Gi(e){return e>=1e6?`${(e/1e6).toFixed(1)}M`:e>=1e3?`${Math.floor(e/1e3)}K`:String(e)}function vG({stats:e}){if(!e||e.counts.input_tokens===0)return D.jsx("div",{className:"bg-gray-900/80 rounded shadow-lg shadow-black/25 p-5 flex items-center justify-center h-24",children:D.jsx("span",{className:"text-gray-600 text-sm",children:"No token data yet"})});const{input_tokens:t,output_tokens:n,cache_read_tokens:a,cost_usd:u}=e.counts,o=t+n+a,s=o>0?t/o*100:0,f=o>0?n/o*100:0,d=o>0?a/o*100:0;return D.jsxs("div",{className:"bg-gray-900/80 rounded shadow-lg shadow-black/25 p-5",children:[D.jsxs("div",{className:"flex items-center justify-between mb-3",children:[D.jsx("h3",{className:"text-sm font-medium text-gray-400 uppercase tracking-wider",children:"Token Breakdown"}),D.jsxs("span",{className:"text-sm text-emerald-400 font-semibold",children:["$",u.toFixed(2)]})]}),D.jsxs("div",{className:"flex h-12 rounded overflow-hidden mb-3",children:[s>0&&D.jsx("div",{className:"bg-blue-500 flex items-center justify-center text-xs text-white font-bold",style:{width:`${Math.max(s,1.5)}%`},title:`Input: ${Gi(t)} (${s.toFixed(1)}%)`,children:s>6?"IN":""}),f>0&&D.jsx("div",{className:"bg-purple-500 flex items-center justify-center text-xs text-white font-bold",style:{width:`${Math.max(f,1.5)}%`},title:`Output: ${Gi(n)} (${f.toFixed(1)}%)`,children:f>6?"OUT":""}),d>0&&D.jsx("div",{className:"bg-gray-700 flex items-center justify-center text-xs text-gray-400 font-bold",style:{width:`${d}%`},title:`Cache: ${Gi(a)} (${d.toFixed(1)}%)`,children:d>10?"CACHE":""})]}),d>50&&D.jsxs("p",{className:"text-sm text-gray-400 italic mb-3",children:[d.toFixed(1),"% of your tokens are cache — you're paying mostly for context, not your prompts"]}),D.jsxs("div",{className:"flex gap-4 text-xs",children:[D.jsx(cg,{color:"bg-blue-500",label:"Input",value:Gi(t)}),D.jsx(cg,{color:"bg-purple-500",label:"Output",value:Gi(n)}),a>0&&D.jsx(cg,{color:"bg-gray-700",label:"Cache",value:Gi(a)})]})]})}function cg({color:e,label:t,value:n}){return D.jsxs("div",{className:"flex items-center gap-1.5",children:[D.jsx("div",{className:`w-2 h-2 rounded-sm ${e}`}),D.jsx("span",{className:"text-gray-500",children:t}),D.jsx("span",{className:"text-gray-300 font-semibold",children:n})]})}const pG={read:"bg-cyan-900/50 text-cyan-400",write:"bg-yellow-900/50 text-yellow-400",exec:"bg-red-900/50 text-red-400"},Sq9={info:"",warn:"bg-yellow-900/20",danger:"bg-red-950/20"},yG={cursor:"text-purple-400","claude-code":"text-blue-400",vigilo:"text-emerald-400"};function mG(e){return e.slice(11,19)||e}function gG(e){return e<1e3?`${e}µs`:e<1e6?`${(e/1e3).toFixed(0)}ms`:`${(e/1e6).toFixed(1)}s`}function j2(e){return e>=1e6?`${(e/1e6).toFixed(1)}M`:e>=1e3?`${Math.floor(e/1e3)}K`:String(e)}function bG(e){return e==="claude-code"?"claude":e==="vigilo"?"mcp":e}const sg=[null,"read","write","exec"],xG=[{key:"all",label:"All",color:"text-gray-500",activeColor:"bg-gray-700 text-gray-200"},{key:"claude-code",label:"Claude",color:"text-blue-400/60",activeColor:"bg-blue-500/20 text-blue-400"},{key:"cursor",label:"Cursor",color:"text-purple-400/60",activeColor:"bg-purple-500/20 text-purple-400"},{key:"vigilo",label:"MCP",color:"text-emerald-400/60",activeColor:"bg-emerald-500/20 text-emerald-400"}];function SG(e,t,n){switch(n){case"time":return e.timestamp.localeCompare(t.timestamp);case"tokens":{const a=(e.input_tokens??0)+(e.output_tokens??0),u=(t.input_tokens??0)+(t.output_tokens??0);return a-u}case"duration":return e.duration_us-t.duration_us}}function _G({events:e,height:t}){const[n,a]=J.useState(!1),[u,o]=J.useState("all"),[s,f]=J.useState(null),[d,h]=J.useState(null),[m,y]=J.useState(null),[g,b]=J.useState("desc"),O=J.useRef(null),_=J.useMemo(()=>{const E=new Set(e.map(P=>P.tool));return Array.from(E).sort()},[e]),x=()=>{const E=sg.indexOf(s);f(sg[(E+1)%sg.length])},T=()=>{if(_.length!==0)if(d===null)h(_[0]);else{const E=_.indexOf(d);E===-1||E===_.length-1?h(null):h(_[E+1])}},j=J.useMemo(()=>{let E=e;return u!=="all"&&(E=E.filter(P=>P.server===u)),s&&(E=E.filter(P=>P.risk===s)),d&&(E=E.filter(P=>P.tool===d)),E},[e,u,s,d]),M=J.useMemo(()=>{if(!m)return j;const E=j.slice().sort((P,B)=>SG(P,B,m));return g==="desc"?E.reverse():E},[j,m,g]),N=E=>{m===E?g==="desc"?b("asc"):(y(null),b("desc")):(y(E),b("desc"))};J.useEffect(()=>{O.current&&!m&&(O.current.scrollTop=O.current.scrollHeight)},[j,m]);const A=s||d,w=j.length;return D.jsxs("div",{className:"border-t border-gray-800 bg-gray-900/50",children:[D.jsxs("div",{className:"flex items-center justify-between px-4 py-1.5",children:[D.jsxs("button",{onClick:()=>a(!n),className:"flex items-center gap-2 text-xs text-gray-400 hover:text-gray-200 transition-colors",children:[D.jsxs("span",{className:"flex items-center gap-2 font-semibold uppercase tracking-wider",children:[D.jsx("span",{className:"inline-block w-1.5 h-1.5 rounded-full bg-emerald-400 animate-pulse"}),"Live Feed",j.length>0&&D.jsx("span",{className:"bg-gray-700 text-gray-300 text-xs px-1.5 py-0.5 rounded-full font-normal",children:w})]}),D.jsx("span",{className:"text-gray-500",children:n?"▲":"▼"})]}),!n&&D.jsxs("div",{className:"flex items-center gap-2",children:[A&&D.jsx("button",{onClick:()=>{f(null),h(null)},className:"text-[10px] text-gray-500 hover:text-gray-300 transition-colors",children:"clear filters"}),D.jsx("div",{className:"flex items-center gap-0.5 bg-gray-800/50 rounded p-0.5",children:xG.map(E=>D.jsx("button",{onClick:()=>o(E.key),className:`px-2 py-0.5 text-[10px] font-semibold rounded transition-colors ${u===E.key?E.activeColor:`${E.color} hover:text-gray-300`}`,children:E.label},E.key))})]})]}),!n&&D.jsxs(D.Fragment,{children:[D.jsxs("div",{className:"flex items-center gap-2 px-4 py-1 text-[9px] font-mono uppercase tracking-wider border-b border-gray-800/50 select-none",children:[D.jsx(fg,{k:"time",label:"Time",className:"w-16 shrink-0",sortKey:m,sortDir:g,onSort:N}),D.jsx("span",{className:"w-12 shrink-0 text-gray-600",children:"Server"}),D.jsx(M2,{label:"Risk",value:(s==null?void 0:s.toUpperCase())??null,className:"w-16 shrink-0",onClick:x}),D.jsx(M2,{label:"Tool",value:d,className:"w-24 shrink-0",onClick:T}),D.jsx("span",{className:"flex-1 min-w-0 text-gray-600",children:"Argument"}),D.jsx("span",{className:"w-28 shrink-0 text-right text-gray-600",children:"Model"}),D.jsx(fg,{k:"tokens",label:"Tokens",className:"w-20 shrink-0 text-right",sortKey:m,sortDir:g,onSort:N}),D.jsx(fg,{k:"duration",label:"Dur",className:"w-16 shrink-0 text-right",sortKey:m,sortDir:g,onSort:N})]}),D.jsx("div",{ref:O,style:{height:t},className:"overflow-y-auto px-4 pb-2",children:M.length===0?D.jsx("div",{className:"flex items-center justify-center h-full text-gray-600 text-xs",children:e.length===0?"Waiting for events...":"No events match filter"}):M.map(E=>D.jsxs("div",{className:`flex items-center gap-2 py-0.5 text-[11px] font-mono w-full ${Sq9[E.severity]}`,children:[D.jsx("span",{className:"text-gray-600 w-16 shrink-0",children:mG(E.timestamp)}),D.jsx("span",{className:`w-12 shrink-0 font-bold ${yG[E.server]??"text-gray-500"}`,children:bG(E.server)}),D.jsx("span",{className:"w-16 shrink-0",children:D.jsx("span",{className:`font-bold px-2 py-0.5 rounded text-[10px] font-mono ${pG[E.risk]??"text-gray-500"}`,children:E.risk.toUpperCase()})}),D.jsxs("span",{className:"text-gray-300 font-semibold w-24 shrink-0 truncate",children:[E.is_error&&D.jsx("span",{className:"text-red-500 mr-1",children:"ERR"}),E.tool]}),D.jsx("span",{className:"text-gray-500 truncate flex-1 min-w-0",children:E.arg_display}),D.jsx("span",{className:"w-28 shrink-0 text-gray-600 truncate text-right",children:E.model??D.jsx("span",{className:"text-gray-700",children:"—"})}),D.jsx("span",{className:"w-20 shrink-0 text-right",children:E.input_tokens!=null||E.output_tokens!=null?D.jsxs(D.Fragment,{children:[D.jsx("span",{className:"text-cyan-400/70",children:E.input_tokens!=null?j2(E.input_tokens):"—"}),D.jsx("span",{className:"text-gray-600",children:"↑"}),D.jsx("span",{className:"text-purple-400/70",children:E.output_tokens!=null?j2(E.output_tokens):"—"}),D.jsx("span",{className:"text-gray-600",children:"↓"})]}):D.jsx("span",{className:"text-gray-700",children:"—"})}),D.jsx("span",{className:"w-16 shrink-0 text-right",children:E.duration_us>0?D.jsx("span",{className:"text-gray-600",children:gG(E.duration_us)}):D.jsx("span",{className:"text-gray-700",children:"—"})})]},E.id))})]})]})}function fg({k:e,label:t,className:n,sortKey:a,sortDir:u,onSort:o}){const s=a===e,f=s?u==="desc"?" ▾":" ▴":"";return D.jsxs("button",{onClick:()=>o(e),className:`${n} text-left hover:text-gray-300 transition-colors ${s?"text-cyan-400":"text-gray-600"}`,children:[t,f]})}function M2({label:e,value:t,className:n,onClick:a}){return D.jsx("button",{onClick:a,className:`${n} text-left hover:text-gray-300 transition-colors ${t?"text-amber-400":"text-gray-600"}`,children:t??e})}function OG(){const[e,t]=J.useState("24h"),[n,a]=J.useState(null),[u,o]=J.useState(!1),s=dP(e),{data:f,refetch:d}=hg(()=>dg.sessions(s),[e]),h=J.useMemo(()=>{if(!n||!f)return null;const w=f.find(E=>E.id===n);return(w==null?void 0:w.session_ids)??null},[n,f]),m=h?h.join(","):void 0,y=m?{...s,session:m}:s,{data:g}=hg(()=>dg.stats(y),[e,m]),b=hP(),O=X_({initial:256,min:180,max:480,direction:"horizontal"}),_=X_({initial:192,min:60,max:500,direction:"vertical",invert:!0}),[x,T]=J.useState("cost"),j=J.useRef(null),M=J.useRef(null),N=J.useCallback(w=>{var E;w==="sessions"?(u&&o(!1),M.current&&(M.current.classList.add("ring-1","ring-cyan-400/60"),setTimeout(()=>{var P;(P=M.current)==null||P.classList.remove("ring-1","ring-cyan-400/60")},1200))):(T({calls:"calls",cost:"cost",errors:"errors"}[w]),(E=j.current)==null||E.scrollIntoView({behavior:"smooth",block:"start"}))},[u]);J.useEffect(()=>{const w=setInterval(()=>{d()},15e3);return()=>clearInterval(w)},[d]);const A=b.length>0?b[b.length-1].id:null;return J.useEffect(()=>{A&&d()},[A,d]),D.jsxs("div",{className:"h-screen flex flex-col bg-gray-950",children:[D.jsx(pP,{stats:g,sessionCount:(f==null?void 0:f.length)??0,range:e,onRangeChange:t,onStatClick:N}),D.jsxs("div",{className:"flex flex-1 overflow-hidden",children:[D.jsx(mP,{ref:M,sessions:f,selectedSession:n,onSelect:a,collapsed:u,onToggle:()=>o(!u),width:O.size}),!u&&D.jsx("div",{onMouseDown:O.onMouseDown,className:"w-1 hover:bg-cyan-500/40 cursor-col-resize shrink-0 transition-colors"}),D.jsxs("main",{className:"flex-1 overflow-y-auto p-5 space-y-5",children:[n&&h&&D.jsx(wG,{sessionIds:h,stats:g}),D.jsx("div",{ref:j,children:D.jsx(rG,{timeline:(g==null?void 0:g.timeline)??null,activeTab:x,onTabChange:T})}),D.jsx(vG,{stats:g}),D.jsx(cG,{stats:g})]})]}),D.jsxs("div",{className:"flex shrink-0",children:[!u&&D.jsxs(D.Fragment,{children:[D.jsx("div",{onMouseDown:_.onMouseDown,style:{width:O.size},className:"h-1.5 cursor-row-resize shrink-0 hover:bg-cyan-500/40 transition-colors"}),D.jsx("div",{onMouseDown:w=>{O.onMouseDown(w),_.onMouseDown(w)},className:"w-2 h-1.5 cursor-move shrink-0 hover:bg-cyan-500/60 bg-gray-700/50 transition-colors rounded-sm"})]}),D.jsx("div",{onMouseDown:_.onMouseDown,className:"flex-1 h-1.5 hover:bg-cyan-500/40 cursor-row-resize shrink-0 transition-colors"})]}),D.jsx(_G,{events:b,height:_.size})]})}function AG({stats:e}){if(!e)return null;const t=e.counts,n=e.models.length>0?e.models[0].model:null;return D.jsxs("div",{className:"grid grid-cols-2 sm:grid-cols-4 lg:grid-cols-7 gap-2",children:[D.jsx(Pa,{label:"Calls",value:String(t.total)}),D.jsx(Pa,{label:"Cost",value:t.cost_usd>0?`$${t.cost_usd.toFixed(2)}`:"$0.00",color:"text-emerald-400",accent:"border-t-2 border-emerald-400"}),D.jsx(Pa,{label:"Input",value:Wi(t.input_tokens)}),D.jsx(Pa,{label:"Output",value:Wi(t.output_tokens)}),D.jsx(Pa,{label:"Cache",value:Wi(t.cache_read_tokens)}),D.jsx(Pa,{label:"Errors",value:String(t.errors),color:t.errors>0?"text-red-400":"text-gray-500",accent:t.errors>0?"border-t-2 border-red-400":""}),n&&D.jsx(Pa,{label:"Model",value:n,accent:"border-t-2 border-blue-400"})]})}function Pa({label:e,value:t,color:n,accent:a}){return D.jsxs("div",{className:`bg-gray-800/50 rounded px-3 py-2 ${a??""}`,children:[D.jsx("div",{className:"text-[10px] text-gray-500 uppercase tracking-wider",children:e}),D.jsx("div",{className:`text-sm font-semibold truncate ${n??"text-gray-200"}`,title:t,children:t})]})}function wG({sessionIds:e,stats:t}){const n=e.join(","),{data:a,loading:u}=hg(()=>dg.events({session:n}),[n]);if(u)return D.jsx("div",{className:"flex items-center justify-center h-32 text-gray-500 text-sm",children:"Loading session..."});if(!a||a.length===0)return D.jsx("div",{className:"flex items-center justify-center h-32 text-gray-600 text-sm",children:"No events for this session"});const o=a.some(s=>s.input_tokens!=null||s.model!=null);return D.jsxs("div",{className:"space-y-3",children:[D.jsxs("div",{className:"flex items-center gap-2",children:[D.jsxs("h2",{className:"text-xs font-semibold text-gray-400",children:["Session — ",a.length," events"]}),e.length>1&&D.jsxs("span",{className:"text-[10px] bg-gray-700/50 text-gray-400 px-1.5 py-0.5 rounded",title:`Merged from ${e.length} segments: ${e.join(", ")}`,children:[e.length," segments merged"]})]}),D.jsx(AG,{stats:t}),D.jsx("div",{className:"bg-gray-900/80 rounded shadow-lg shadow-black/25 overflow-hidden",children:D.jsx("div",{className:"overflow-y-auto max-h-[400px]",children:D.jsxs("table",{className:"w-full text-xs",children:[D.jsx("thead",{className:"sticky top-0 bg-gray-900 z-10",children:D.jsxs("tr",{className:"text-gray-500 border-b border-gray-800",children:[D.jsx("th",{className:"text-left py-1.5 px-3",children:"Time"}),D.jsx("th",{className:"text-left py-1.5 px-2",children:"Server"}),D.jsx("th",{className:"text-left py-1.5 px-2",children:"Risk"}),D.jsx("th",{className:"text-left py-1.5 px-2",children:"Tool"}),D.jsx("th",{className:"text-left py-1.5 px-2",children:"Argument"}),o&&D.jsxs(D.Fragment,{children:[D.jsx("th",{className:"text-left py-1.5 px-2",children:"Model"}),D.jsx("th",{className:"text-right py-1.5 px-2",children:"In"}),D.jsx("th",{className:"text-right py-1.5 px-2",children:"Out"}),D.jsx("th",{className:"text-right py-1.5 px-2",children:"Cache"})]}),D.jsx("th",{className:"text-right py-1.5 px-2",children:"Duration"}),D.jsx("th",{className:"text-left py-1.5 px-3",children:"Status"})]})}),D.jsx("tbody",{children:a.map((s,f)=>D.jsxs("tr",{className:`border-b border-gray-800/50 ${s.is_error?"bg-red-950/20":f%2===1?"bg-gray-900/30":"hover:bg-gray-800/30"}`,children:[D.jsx("td",{className:"py-1 px-3 text-gray-500 font-mono whitespace-nowrap",children:s.timestamp.slice(11,19)}),D.jsx("td",{className:"py-1 px-2",children:D.jsx(TG,{server:s.server})}),D.jsx("td",{className:"py-1 px-2",children:D.jsx(EG,{risk:s.risk})}),D.jsx("td",{className:"py-1 px-2 text-gray-300 font-semibold whitespace-nowrap",children:s.tool}),D.jsx("td",{className:"py-1 px-2 text-gray-400 truncate max-w-[250px]",title:s.arg_display,children:s.arg_display}),o&&D.jsxs(D.Fragment,{children:[D.jsx("td",{className:"py-1 px-2 text-gray-500 truncate max-w-[100px]",title:s.model??void 0,children:s.model??""}),D.jsx("td",{className:"py-1 px-2 text-right text-cyan-400/70 whitespace-nowrap",children:s.input_tokens!=null?Wi(s.input_tokens):""}),D.jsx("td",{className:"py-1 px-2 text-right text-purple-400/70 whitespace-nowrap",children:s.output_tokens!=null?Wi(s.output_tokens):""}),D.jsx("td",{className:"py-1 px-2 text-right text-gray-500 whitespace-nowrap",children:s.cache_read_tokens!=null?Wi(s.cache_read_tokens):""})]}),D.jsx("td",{className:"py-1 px-2 text-right text-gray-500 whitespace-nowrap",children:s.duration_us>0?jG(s.duration_us):"—"}),D.jsx("td",{className:"py-1 px-3",children:s.is_error?D.jsx("span",{className:"text-red-400",title:s.error_message??void 0,children:"ERR"}):D.jsx("span",{className:"text-green-500",children:"OK"})})]},s.id))})]})})})]})}function TG({server:e}){const t={cursor:"bg-purple-500/20 text-purple-400","claude-code":"bg-blue-500/20 text-blue-400",vigilo:"bg-emerald-500/20 text-emerald-400"},a={"claude-code":"claude",vigilo:"mcp"}[e]??e;return D.jsx("span",{className:`text-[10px] font-bold px-1.5 py-0.5 rounded ${t[e]??"bg-gray-500/20 text-gray-400"}`,children:a})}function EG({risk:e}){const t={read:"bg-cyan-900/50 text-cyan-400",write:"bg-yellow-900/50 text-yellow-400",exec:"bg-red-900/50 text-red-400"};return D.jsx("span",{className:`text-[10px] font-bold font-mono px-2 py-0.5 rounded ${t[e]??"text-gray-500"}`,children:e.toUpperCase()})}function Wi(e){return e>=1e6?`${(e/1e6).toFixed(1)}M`:e>=1e3?`${Math.floor(e/1e3)}K`:String(e)}function jG(e){return e<1e3?`${e}µs`:e<1e6?`${(e/1e3).toFixed(1)}ms`:`${(e/1e6).toFixed(1)}s`}cP.createRoot(document.getElementById("root")).render(D.jsx(J.StrictMode,{children:D.jsx(OG,{})}));
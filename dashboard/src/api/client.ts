@@ -2,6 +2,7 @@ import type {
   SummaryResponse,
   SessionItem,
   StatsResponse,
+  EventsPage,
   EventItem,
   ErrorsResponse,
 } from '@/types'
@@ -26,10 +27,20 @@ export const api = {
   summary: () => get<SummaryResponse>('/api/summary'),
   sessions: (params?: Params) => get<SessionItem[]>('/api/sessions', params),
   stats: (params?: Params) => get<StatsResponse>('/api/stats', params),
-  events: (params?: Params) => get<EventItem[]>('/api/events', params),
+  events: (params?: Params) => allEvents(params),
   errors: (params?: Params) => get<ErrorsResponse>('/api/errors', params),
 }
 
+// /api/events is paginated; follow it to the end so callers see every match
+async function allEvents(params?: Params): Promise<EventItem[]> {
+  const events: EventItem[] = []
+  for (;;) {
+    const page = await get<EventsPage>('/api/events', { ...params, offset: events.length })
+    events.push(...page.events)
+    if (page.events.length === 0 || events.length >= page.total) return events
+  }
+}
+
 function localDateString(d: Date): string {
   const y = d.getFullYear()
   const m = String(d.getMonth() + 1).padStart(2, '0')
@@ -7,6 +7,12 @@ const RISK_PILL: Record<string, string> = {
   exec: 'bg-red-900/50 text-red-400',
 }
 
+const SEVERITY_ROW: Record<EventItem['severity'], string> = {
+  info: '',
+  warn: 'bg-yellow-900/20',
+  danger: 'bg-red-950/20',
+}
+
 const SERVER_COLORS: Record<string, string> = {
   cursor: 'text-purple-400',
   'claude-code': 'text-blue-400',
@@ -199,7 +205,7 @@ export default function LiveFeed({ events, height }: Props) {
             sorted.map((e) => (
               <div
                 key={e.id}
-                className="flex items-center gap-2 py-0.5 text-[11px] font-mono w-full"
+                className={`flex items-center gap-2 py-0.5 text-[11px] font-mono w-full ${SEVERITY_ROW[e.severity]}`}
               >
                 <span className="text-gray-600 w-16 shrink-0">{fmtTime(e.timestamp)}</span>
                 <span className={`w-12 shrink-0 font-bold ${SERVER_COLORS[e.server] ?? 'text-gray-500'}`}>
@@ -105,6 +105,14 @@ export interface EventItem {
   cache_write_tokens: number | null
   model: string | null
   error_message: string | null
+  severity: 'info' | 'warn' | 'danger'
+}
+
+export interface EventsPage {
+  total: number
+  offset: number
+  limit: number
+  events: EventItem[]
 }
 
 export interface ErrorsResponse {
@@ -0,0 +1,147 @@
+use crate::models::{McpEvent, Outcome, ProjectContext, VIGILO_TOOLS};
+use crate::view::data::{load_sessions, LoadFilter};
+use anyhow::Result;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+use std::time::{Duration, Instant};
+use uuid::Uuid;
+
+const EVENTS_PER_SESSION: usize = 100;
+/// Full loads timed per run; the fastest gives the peak rate.
+const LOAD_ROUNDS: usize = 5;
+
+pub fn run(n: usize) -> Result<()> {
+    let dir = std::env::temp_dir().join(format!("vigilo-bench-{}", std::process::id()));
+    std::fs::create_dir_all(&dir)?;
+    let result = bench_in(&dir, n);
+    let _ = std::fs::remove_dir_all(&dir);
+    let r = result?;
+
+    println!(
+        "generated {} events ({} sessions) in {:.2?}",
+        r.events, r.sessions, r.generate
+    );
+    println!(
+        "load_sessions: {:.2?} mean over {LOAD_ROUNDS} loads · {:.0} events/s · peak {:.0} lines/s",
+        r.mean_load(),
+        per_sec(r.events, r.mean_load()),
+        per_sec(r.lines, r.fastest_load()),
+    );
+    Ok(())
+}
+
+struct BenchResult {
+    events: usize,
+    lines: usize,
+    sessions: usize,
+    generate: Duration,
+    loads: Vec<Duration>,
+}
+
+impl BenchResult {
+    fn mean_load(&self) -> Duration {
+        self.loads.iter().sum::<Duration>() / self.loads.len().max(1) as u32
+    }
+
+    fn fastest_load(&self) -> Duration {
+        self.loads.iter().copied().min().unwrap_or_default()
+    }
+}
+
+fn per_sec(count: usize, elapsed: Duration) -> f64 {
+    count as f64 / elapsed.as_secs_f64().max(f64::EPSILON)
+}
+
+fn bench_in(dir: &Path, n: usize) -> Result<BenchResult> {
+    let ledger = dir.join("events.jsonl");
+
+    let started = Instant::now();
+    write_synthetic_ledger(&ledger, n)?;
+    let generate = started.elapsed();
+
+    let mut loads = Vec::with_capacity(LOAD_ROUNDS);
+    let mut sessions = Vec::new();
+    for _ in 0..LOAD_ROUNDS {
+        let started = Instant::now();
+        sessions = load_sessions(&ledger, &LoadFilter::default())?;
+        loads.push(started.elapsed());
+    }
+
+    Ok(BenchResult {
+        events: sessions.iter().map(|(_, e)| e.len()).sum(),
+        lines: n,
+        sessions: sessions.len(),
+        generate,
+        loads,
+    })
+}
+
+fn write_synthetic_ledger(path: &Path, n: usize) -> Result<()> {
+    let mut w = BufWriter::new(std::fs::File::create(path)?);
+    let mut session_id = Uuid::new_v4();
+    for i in 0..n {
+        if i > 0 && i % EVENTS_PER_SESSION == 0 {
+            session_id = Uuid::new_v4();
+        }
+        serde_json::to_writer(&mut w, &synthetic_event(i, session_id))?;
+        w.write_all(b"\n")?;
+    }
+    w.flush()?;
+    Ok(())
+}
+
+fn synthetic_event(i: usize, session_id: Uuid) -> McpEvent {
+    let (tool, risk) = VIGILO_TOOLS[i % VIGILO_TOOLS.len()];
+    let base = chrono::DateTime::parse_from_rfc3339("2026-01-01T00:00:00Z")
+        .map(|d| d.with_timezone(&chrono::Utc))
+        .unwrap_or_default();
+    let timestamp = (base + chrono::Duration::seconds(i as i64)).to_rfc3339();
+    McpEvent {
+        id: Uuid::new_v4(),
+        timestamp,
+        session_id,
+        server: "vigilo".to_string(),
+        tool: tool.to_string(),
+        arguments: serde_json::json!({ "path": format!("/bench/src/file_{}.rs", i % 50) }),
+        outcome: Outcome::Ok {
+            result: serde_json::json!("ok"),
+        },
+        duration_us: 100 + (i as u64 % 900),
+        risk,
+        project: ProjectContext {
+            root: Some("/bench".to_string()),
+            name: Some("bench".to_string()),
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn synthetic_ledger_has_n_parseable_events() {
+        let dir = tempfile::tempdir().unwrap();
+        let ledger = dir.path().join("events.jsonl");
+        write_synthetic_ledger(&ledger, 250).unwrap();
+
+        let content = std::fs::read_to_string(&ledger).unwrap();
+        let parsed: Vec<McpEvent> = content
+            .lines()
+            .map(|l| serde_json::from_str(l).unwrap())
+            .collect();
+        assert_eq!(parsed.len(), 250);
+    }
+
+    #[test]
+    fn bench_loads_every_generated_event() {
+        let dir = tempfile::tempdir().unwrap();
+        let r = bench_in(dir.path(), 250).unwrap();
+        assert_eq!(r.events, 250);
+        assert_eq!(r.sessions, 3);
+        assert_eq!(r.loads.len(), LOAD_ROUNDS);
+        assert!(r.fastest_load() <= r.mean_load());
+    }
+}
@@ -10,59 +10,93 @@ pub fn print_help() {
 fn print_usage() {
     println!("USAGE:");
     println!("  vigilo                          MCP server mode (reads stdio)");
-    println!("  vigilo summary                  Today at a glance");
+    println!("  vigilo summary  [--period p]    Today at a glance (p: today | week | month)");
     println!("  vigilo sessions [OPTIONS]       List all sessions (one line each)");
+    println!("  vigilo session  <id-prefix>     One session in full: timeline, tools, files");
     println!("  vigilo tail     [-n N | --last N]  Last N events flat (default: 20)");
     println!("  vigilo view     [OPTIONS]       View ledger grouped by session");
-    println!("  vigilo watch                    Live tail of incoming events");
+    println!("  vigilo watch    [OPTIONS]       Live tail of incoming events (--json: NDJSON)");
     println!("  vigilo stats    [OPTIONS]       Aggregate stats across all sessions");
     println!("  vigilo errors   [OPTIONS]       Show errors (--expand for full details)");
+    println!("  vigilo cost     [OPTIONS]       Estimated cost per day (--weekly: per week)");
+    println!("  vigilo audit    [OPTIONS]       Every write/exec call by project, for archival");
     println!("  vigilo diff     [OPTIONS]       Show file diffs grouped by session");
     println!("  vigilo query    [OPTIONS]       Filter events across all sessions");
-    println!("  vigilo export   [OPTIONS]       Export events as CSV or JSON");
+    println!(
+        "  vigilo export   [OPTIONS]       Export events as CSV, JSON, JSONL, HTML or Markdown"
+    );
     println!("  vigilo cursor-usage [OPTIONS]   Fetch real token usage from cursor.com");
     println!("  vigilo dashboard [OPTIONS]      Launch web dashboard (default port: 7847)");
-    println!("  vigilo prune    [OPTIONS]       Delete old rotated ledger files");
+    println!("  vigilo prune    [OPTIONS]       Delete old rotated ledger and overflow files");
     println!("  vigilo doctor                   Check configuration and dependencies");
     println!("  vigilo setup                    Interactive setup wizard");
+    println!("  vigilo uninstall                Remove vigilo from editor configs");
     println!("  vigilo generate-key             Generate a base64 AES-256 encryption key");
+    println!("  vigilo decrypt --out <path>     Write a plaintext copy of the whole ledger");
+    println!("  vigilo rekey --old-key <b64>    Re-encrypt the whole ledger under a new key");
     println!("  vigilo completions <shell>      Print shell completions (bash|zsh|fish)");
     println!("  vigilo help | --help | -h       Show this message");
     println!("  vigilo --version | -V           Show version\n");
     println!("INTERNAL:");
+    println!("  vigilo hook                     Process a hook event from stdin (used by editors)");
     println!(
-        "  vigilo hook                     Process a hook event from stdin (used by editors)\n"
+        "    --client <name>               Force the payload format: cursor | claude | gemini\n"
     );
 }
 
 fn print_options() {
     println!("VIEW / STATS / QUERY OPTIONS:");
-    println!("  --since <expr>    From date  (today, yesterday, 7d, 2w, 1m, YYYY-MM-DD)");
+    println!(
+        "  --since <expr>    From date  (today, yesterday, 7d, 2w, 1m, 6h, YYYY-MM-DD [HH:MM])"
+    );
     println!("  --until <expr>    To date    (same formats as --since)");
     println!("  --risk <level>    Filter by risk level: read | write | exec");
     println!("  --tool <name>     Filter by tool name (view and query)");
+    println!("  --tool-regex <re> Filter by tool name regex, e.g. 'git_.*' (query)");
     println!("  --session <pfx>   Filter by session UUID prefix");
+    println!("  --project <name>  Filter by project name (view, sessions, diff, query, export)");
+    println!("  --tag <tag>       Filter by event tag (same commands as --project)");
+    println!("  --generation <id> Filter by Cursor generation id prefix (query)");
+    println!("  --sort <key>      Order results: time (default) | duration | cost (query)");
+    println!("  --min-duration <d> Only calls at least this slow: 500ms, 2s, 1m (query)");
+    println!("  --min-cost <usd>  Only calls costing at least this much, e.g. 0.05 (query)");
+    println!("  --exit-code <n>   Only run_command calls that exited with this code (query)");
+    println!("  --contains <text> Path or command contains text, case-insensitive (query)");
+    println!("  --flagged         Only commands flagged as destructive (query)");
     println!("  --last <n>        Show only the last N sessions");
+    println!("  --top <n>         Rows per tools/files/models/projects breakdown (stats)");
     println!("  --expand          Show all events / full error details");
+    println!("  --reverse         Newest session first (view)");
+    println!("  --all             Don't mark events NEW since the last view (view)");
+    println!("  --dedup           Show a call logged by both server and hook once (view, query)");
+    println!("  --json            Print JSON instead of a table (stats, errors)");
     println!("  --no-color        Disable colored output (also respects NO_COLOR env)\n");
     println!("EXPORT OPTIONS:");
-    println!("  --format <fmt>    Output format: csv (default) | json");
-    println!("  --output <path>   Write to file (default: ~/.vigilo/export.<ext>)\n");
+    println!("  --format <fmt>    Output format: csv (default) | json | jsonl | ndjson | html | markdown");
+    println!("  --output <path>   Write to file (default: ~/.vigilo/export.<ext>)");
+    println!("  --summary         Wrap JSON as {{summary, events}} with totals");
+    println!("  --anonymize       Hash paths, keep only program names, drop contents and roots\n");
+    println!("WATCH OPTIONS:");
+    println!("  --risk, --tool, --session, --project  Only print matching events");
+    println!("  --json            One JSON object per event\n");
     println!("PRUNE OPTIONS:");
     println!("  --older-than <n>  Days threshold (default: 30)\n");
     println!("CURSOR-USAGE OPTIONS:");
     println!("  --since-days <n>  Number of days to look back (default: 30)");
     println!("  --sync            Fetch and cache token data without printing\n");
     println!("DASHBOARD OPTIONS:");
-    println!("  --port <n>        Port to listen on (default: 7847)\n");
+    println!("  --port <n>        Port to listen on (default: 7847)");
+    println!("  --bind <addr>     Interface to listen on (default: 127.0.0.1)\n");
     println!("ENVIRONMENT:");
     println!("  VIGILO_LEDGER           Path to ledger file (default: ~/.vigilo/events.jsonl)");
     println!("  VIGILO_ENCRYPTION_KEY   Base64 AES-256 key — encrypts arguments and results\n");
     println!("TOOLS (Risk level):");
-    println!("  read    read_file, list_directory, search_files, get_file_info, git_status, git_diff, git_log");
+    println!("  read    read_file, list_directory, search_files, get_file_info,");
+    println!("          git_status, git_diff, git_log, git_branch, git_blame");
     println!(
-        "  write   write_file, create_directory, delete_file, move_file, patch_file, git_commit"
+        "  write   write_file, append_file, create_directory, delete_file, move_file, copy_file,"
     );
+    println!("          patch_file, git_commit, git_stash, git_stash_pop");
     println!("  exec    run_command");
 }
 
@@ -81,7 +115,7 @@ pub fn parse_view_args(args: &[String]) -> view::ViewArgs {
                 },
                 None => eprintln!("vigilo: --last requires a value"),
             },
-            "--risk" | "--tool" | "--session" | "--since" | "--until" => {
+            "--risk" | "--tool" | "--session" | "--project" | "--tag" | "--since" | "--until" => {
                 let flag = args[i].as_str();
                 match args.get(i + 1) {
                     Some(val) => {
@@ -89,6 +123,8 @@ pub fn parse_view_args(args: &[String]) -> view::ViewArgs {
                             "--risk" => out.risk = Some(val.clone()),
                             "--tool" => out.tool = Some(val.clone()),
                             "--session" => out.session = Some(val.clone()),
+                            "--project" => out.project = Some(val.clone()),
+                            "--tag" => out.tag = Some(val.clone()),
                             "--since" => out.since = Some(parse_date(val)),
                             "--until" => out.until = Some(parse_date(val)),
                             _ => {}
@@ -99,6 +135,9 @@ pub fn parse_view_args(args: &[String]) -> view::ViewArgs {
                 }
             }
             "--expand" => out.expand = true,
+            "--reverse" => out.reverse = true,
+            "--all" => out.all = true,
+            "--dedup" => out.dedup = true,
             other if other.starts_with("--") => {
                 eprintln!("vigilo: unknown option '{other}'");
             }
@@ -130,10 +169,41 @@ pub fn filter_flags(args: &[String], flags: &[&str]) -> Vec<String> {
     out
 }
 
+/// Parses `250us`, `500ms`, `2s`, `1.5s` or `1m` into microseconds.
+pub fn parse_duration_us(expr: &str) -> Option<u64> {
+    let expr = expr.trim();
+    let split = expr.find(|c: char| c.is_ascii_alphabetic())?;
+    let (num, unit) = expr.split_at(split);
+    let value: f64 = num.parse().ok().filter(|v: &f64| *v >= 0.0)?;
+    let scale = match unit {
+        "us" => 1.0,
+        "ms" => 1_000.0,
+        "s" => 1_000_000.0,
+        "m" => 60_000_000.0,
+        _ => return None,
+    };
+    Some((value * scale).round() as u64)
+}
+
 pub fn parse_date(expr: &str) -> String {
-    use chrono::{Duration, Local};
+    if let Some(instant) = parse_duration_hours(expr, chrono::Utc::now()) {
+        return instant;
+    }
+    parse_date_from(expr, chrono::Local::now().date_naive())
+}
+
+/// `Nh` → the UTC instant N hours before `now`, as RFC 3339. Unlike the
+/// day-based forms this carries a time, so filters compare whole timestamps.
+fn parse_duration_hours(s: &str, now: chrono::DateTime<chrono::Utc>) -> Option<String> {
+    let n: u64 = s.strip_suffix('h')?.parse().ok()?;
+    let hours = chrono::Duration::try_hours(i64::try_from(n).ok()?)?;
+    let instant = now.checked_sub_signed(hours)?;
+    Some(instant.to_rfc3339_opts(chrono::SecondsFormat::Secs, true))
+}
 
-    let today = Local::now().date_naive();
+/// `parse_date` against a fixed `today`.
+pub fn parse_date_from(expr: &str, today: chrono::NaiveDate) -> String {
+    use chrono::Duration;
 
     match expr {
         "today" => today.format("%Y-%m-%d").to_string(),
@@ -205,11 +275,14 @@ pub fn completions(shell: Option<&str>) -> anyhow::Result<()> {
 const SUBCOMMANDS: &[&str] = &[
     "summary",
     "sessions",
+    "session",
     "tail",
     "view",
     "watch",
     "stats",
     "errors",
+    "cost",
+    "audit",
     "diff",
     "query",
     "export",
@@ -218,7 +291,10 @@ const SUBCOMMANDS: &[&str] = &[
     "prune",
     "doctor",
     "setup",
+    "uninstall",
     "generate-key",
+    "decrypt",
+    "rekey",
     "completions",
     "help",
 ];
@@ -239,10 +315,12 @@ fn bash_completions() -> String {
 
     case "$prev" in
         --risk) COMPREPLY=( $(compgen -W "read write exec" -- "$cur") ) ;;
-        --format) COMPREPLY=( $(compgen -W "csv json" -- "$cur") ) ;;
+        --period) COMPREPLY=( $(compgen -W "today week month" -- "$cur") ) ;;
+        --sort) COMPREPLY=( $(compgen -W "time duration cost" -- "$cur") ) ;;
+        --format) COMPREPLY=( $(compgen -W "csv json jsonl ndjson html markdown" -- "$cur") ) ;;
         completions) COMPREPLY=( $(compgen -W "bash zsh fish" -- "$cur") ) ;;
-        --since|--until|--tool|--session|--last|--older-than|--since-days|--output|-n) ;;
-        *) COMPREPLY=( $(compgen -W "--since --until --risk --tool --session --last --expand --no-color --format --output" -- "$cur") ) ;;
+        --since|--until|--tool|--tool-regex|--min-duration|--min-cost|--exit-code|--contains|--session|--project|--tag|--generation|--last|--top|--older-than|--since-days|--output|--out|--old-key|--port|--bind|-n) ;;
+        *) COMPREPLY=( $(compgen -W "--since --until --risk --tool --session --project --tag --last --top --expand --reverse --all --dedup --flagged --json --weekly --no-color --format --output --summary --anonymize" -- "$cur") ) ;;
     esac
     return 0
 }}
@@ -274,8 +352,36 @@ _vigilo() {{
                         '--risk[Risk level]:level:(read write exec)' \
                         '--tool[Tool name]:tool:' \
                         '--session[Session prefix]:prefix:' \
+                        '--project[Project name]:name:' \
+                        '--tag[Event tag]:tag:' \
+                        '--generation[Cursor generation prefix]:id:' \
+                        '--tool-regex[Tool name regex (query)]:regex:' \
+                        '--sort[Result order (query)]:key:(time duration cost)' \
+                        '--min-duration[Minimum duration (query)]:duration:' \
+                        '--min-cost[Minimum cost in USD (query)]:usd:' \
+                        '--exit-code[run_command exit code (query)]:code:' \
+                        '--contains[Substring of the path or command (query)]:text:' \
+                        '--flagged[Only destructive commands (query)]' \
                         '--last[Last N sessions]:count:' \
+                        '--top[Rows per breakdown (stats)]:count:' \
                         '--expand[Show all events]' \
+                        '--reverse[Newest session first]' \
+                        '--all[Ignore the last-viewed marker]' \
+                        '--dedup[Drop repeated session/tool/timestamp events (view, query)]' \
+                        '--json[Print JSON (stats, errors)]' \
+                        '--no-color[Disable colors]'
+                    ;;
+                audit)
+                    _arguments \
+                        '--since[From date]:date:' \
+                        '--until[To date]:date:' \
+                        '--format[Output format]:format:(text json)'
+                    ;;
+                cost)
+                    _arguments \
+                        '--since[From date]:date:' \
+                        '--until[To date]:date:' \
+                        '--weekly[One row per week]' \
                         '--no-color[Disable colors]'
                     ;;
                 tail)
@@ -285,17 +391,38 @@ _vigilo() {{
                     ;;
                 export)
                     _arguments \
-                        '--format[Output format]:format:(csv json)' \
+                        '--format[Output format]:format:(csv json jsonl ndjson html markdown)' \
                         '--output[Output file]:file:_files' \
+                        '--summary[Include totals (json)]' \
+                        '--anonymize[Hash paths and strip command arguments]' \
                         '--since[From date]:date:' \
                         '--until[To date]:date:'
                     ;;
+                watch)
+                    _arguments \
+                        '--json[Emit NDJSON]' \
+                        '--risk[Risk level]:level:(read write exec)' \
+                        '--tool[Tool name]:tool:' \
+                        '--session[Session prefix]:prefix:' \
+                        '--project[Project name]:name:'
+                    ;;
+                summary)
+                    _arguments '--period[Date range]:period:(today week month)'
+                    ;;
                 dashboard)
-                    _arguments '--port[Listen port]:port:'
+                    _arguments \
+                        '--port[Listen port]:port:' \
+                        '--bind[Listen address]:address:'
                     ;;
                 prune)
                     _arguments '--older-than[Days threshold]:days:'
                     ;;
+                decrypt)
+                    _arguments '--out[Output file]:file:_files'
+                    ;;
+                rekey)
+                    _arguments '--old-key[Current base64 key]:key:'
+                    ;;
                 cursor-usage)
                     _arguments \
                         '--since-days[Lookback days]:days:' \
@@ -328,13 +455,46 @@ complete -c vigilo -n '__fish_seen_subcommand_from view sessions stats errors di
 complete -c vigilo -n '__fish_seen_subcommand_from view sessions stats errors diff query' -l risk -xa 'read write exec'
 complete -c vigilo -n '__fish_seen_subcommand_from view sessions stats errors diff query' -l tool -x
 complete -c vigilo -n '__fish_seen_subcommand_from view sessions stats errors diff query' -l session -x
+complete -c vigilo -n '__fish_seen_subcommand_from view sessions diff query export' -l project -x
+complete -c vigilo -n '__fish_seen_subcommand_from view sessions diff query export' -l tag -x
 complete -c vigilo -n '__fish_seen_subcommand_from view sessions stats errors diff query' -l last -x
+complete -c vigilo -n '__fish_seen_subcommand_from query' -l generation -x
+complete -c vigilo -n '__fish_seen_subcommand_from query' -l tool-regex -x
+complete -c vigilo -n '__fish_seen_subcommand_from query' -l sort -xa 'time duration cost'
+complete -c vigilo -n '__fish_seen_subcommand_from query' -l min-duration -x
+complete -c vigilo -n '__fish_seen_subcommand_from query' -l min-cost -x
+complete -c vigilo -n '__fish_seen_subcommand_from query' -l exit-code -x
+complete -c vigilo -n '__fish_seen_subcommand_from query' -l contains -x
+complete -c vigilo -n '__fish_seen_subcommand_from query' -l flagged
 complete -c vigilo -n '__fish_seen_subcommand_from view sessions stats errors diff query' -l expand
+complete -c vigilo -n '__fish_seen_subcommand_from view' -l reverse
+complete -c vigilo -n '__fish_seen_subcommand_from view' -l all
+complete -c vigilo -n '__fish_seen_subcommand_from view query' -l dedup
+complete -c vigilo -n '__fish_seen_subcommand_from stats errors' -l json
+complete -c vigilo -n '__fish_seen_subcommand_from stats' -l top -x
+complete -c vigilo -n '__fish_seen_subcommand_from audit' -l since -x
+complete -c vigilo -n '__fish_seen_subcommand_from audit' -l until -x
+complete -c vigilo -n '__fish_seen_subcommand_from audit' -l format -xa 'text json'
+complete -c vigilo -n '__fish_seen_subcommand_from cost' -l since -x
+complete -c vigilo -n '__fish_seen_subcommand_from cost' -l until -x
+complete -c vigilo -n '__fish_seen_subcommand_from cost' -l weekly
 complete -c vigilo -n '__fish_seen_subcommand_from tail' -s n -x
 complete -c vigilo -n '__fish_seen_subcommand_from tail' -l last -x
-complete -c vigilo -n '__fish_seen_subcommand_from export' -l format -xa 'csv json'
+complete -c vigilo -n '__fish_seen_subcommand_from export' -l format -xa 'csv json jsonl ndjson html markdown'
 complete -c vigilo -n '__fish_seen_subcommand_from export' -l output -rF
+complete -c vigilo -n '__fish_seen_subcommand_from export' -l summary
+complete -c vigilo -n '__fish_seen_subcommand_from export' -l anonymize
+complete -c vigilo -n '__fish_seen_subcommand_from watch' -l json
+complete -c vigilo -n '__fish_seen_subcommand_from watch' -l risk -xa 'read write exec'
+complete -c vigilo -n '__fish_seen_subcommand_from watch' -l tool -x
+complete -c vigilo -n '__fish_seen_subcommand_from watch' -l session -x
+complete -c vigilo -n '__fish_seen_subcommand_from watch' -l project -x
+complete -c vigilo -n '__fish_seen_subcommand_from summary' -l period -xa 'today week month'
+complete -c vigilo -n '__fish_seen_subcommand_from dashboard' -l port -x
+complete -c vigilo -n '__fish_seen_subcommand_from dashboard' -l bind -x
 complete -c vigilo -n '__fish_seen_subcommand_from prune' -l older-than -x
+complete -c vigilo -n '__fish_seen_subcommand_from decrypt' -l out -rF
+complete -c vigilo -n '__fish_seen_subcommand_from rekey' -l old-key -x
 complete -c vigilo -n '__fish_seen_subcommand_from cursor-usage' -l since-days -x
 complete -c vigilo -n '__fish_seen_subcommand_from cursor-usage' -l sync
 complete -c vigilo -n '__fish_seen_subcommand_from completions' -xa 'bash zsh fish'
@@ -373,6 +533,37 @@ mod tests {
         assert!(result.len() == 10 && result.contains('-'));
     }
 
+    #[test]
+    fn parse_duration_us_handles_units() {
+        assert_eq!(parse_duration_us("250us"), Some(250));
+        assert_eq!(parse_duration_us("500ms"), Some(500_000));
+        assert_eq!(parse_duration_us("2s"), Some(2_000_000));
+        assert_eq!(parse_duration_us("1.5s"), Some(1_500_000));
+        assert_eq!(parse_duration_us("1m"), Some(60_000_000));
+        assert_eq!(parse_duration_us("500"), None);
+        assert_eq!(parse_duration_us("2h"), None);
+        assert_eq!(parse_duration_us("-1s"), None);
+    }
+
+    #[test]
+    fn parse_date_hours_is_an_instant() {
+        let now = chrono::DateTime::parse_from_rfc3339("2026-03-02T04:30:15Z")
+            .unwrap()
+            .to_utc();
+        assert_eq!(
+            parse_duration_hours("6h", now).unwrap(),
+            "2026-03-01T22:30:15Z"
+        );
+        assert_eq!(
+            parse_duration_hours("0h", now).unwrap(),
+            "2026-03-02T04:30:15Z"
+        );
+        assert!(parse_duration_hours("xh", now).is_none());
+        assert!(parse_duration_hours("-6h", now).is_none());
+        assert!(parse_duration_hours("6d", now).is_none());
+        assert_eq!(parse_date("6h").len(), 20);
+    }
+
     #[test]
     fn parse_date_passthrough() {
         assert_eq!(parse_date("2026-02-01"), "2026-02-01");
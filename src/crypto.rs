@@ -8,6 +8,91 @@ use zeroize::{Zeroize, ZeroizeOnDrop};
 
 const PREFIX: &str = "enc:v1:";
 
+/// Ledger line fields that get encrypted, and whether the plaintext is JSON
+/// (serialized before encrypting) or a bare string.
+const LEDGER_FIELDS: [(&str, bool); 4] = [
+    ("/arguments", true),
+    ("/outcome/result", true),
+    ("/diff", false),
+    ("/cwd", false),
+];
+
+/// Which event fields get encrypted, from `ENCRYPT_FIELDS` — a comma-separated
+/// subset of `arguments`, `result`, `diff`. Defaults to all three.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct EncryptFields {
+    pub arguments: bool,
+    pub result: bool,
+    pub diff: bool,
+}
+
+impl Default for EncryptFields {
+    fn default() -> Self {
+        Self {
+            arguments: true,
+            result: true,
+            diff: true,
+        }
+    }
+}
+
+impl EncryptFields {
+    pub fn from_config(config: &std::collections::HashMap<String, String>) -> Self {
+        crate::models::config_setting(config, "ENCRYPT_FIELDS")
+            .map(|v| Self::parse(&v))
+            .unwrap_or_default()
+    }
+
+    pub fn parse(list: &str) -> Self {
+        let mut fields = Self {
+            arguments: false,
+            result: false,
+            diff: false,
+        };
+        for name in list.split(',').map(str::trim).filter(|s| !s.is_empty()) {
+            match name {
+                "arguments" => fields.arguments = true,
+                "result" => fields.result = true,
+                "diff" => fields.diff = true,
+                other => eprintln!("[vigilo] warning: unknown ENCRYPT_FIELDS entry '{other}'"),
+            }
+        }
+        fields
+    }
+
+    /// Every field either set encrypts.
+    pub fn union(self, other: Self) -> Self {
+        Self {
+            arguments: self.arguments || other.arguments,
+            result: self.result || other.result,
+            diff: self.diff || other.diff,
+        }
+    }
+
+    /// The fields a raw ledger line currently holds encrypted.
+    pub fn sealed_in(event: &serde_json::Value) -> Self {
+        let sealed = |pointer: &str| {
+            event
+                .pointer(pointer)
+                .and_then(|v| v.as_str())
+                .is_some_and(is_encrypted)
+        };
+        Self {
+            arguments: sealed("/arguments") || sealed("/cwd"),
+            result: sealed("/outcome/result"),
+            diff: sealed("/diff"),
+        }
+    }
+
+    fn covers(&self, pointer: &str) -> bool {
+        match pointer {
+            "/arguments" | "/cwd" => self.arguments,
+            "/outcome/result" => self.result,
+            _ => self.diff,
+        }
+    }
+}
+
 /// AES-256 key wrapper that zeroizes memory on drop.
 #[derive(Zeroize, ZeroizeOnDrop)]
 pub struct EncryptionKey([u8; 32]);
@@ -27,14 +112,142 @@ pub fn key_file_path() -> std::path::PathBuf {
     crate::models::vigilo_path("encryption.key")
 }
 
-/// Try loading key from: env var → key file → None.
+/// Returns the path to the Argon2 salt for password-derived keys: `~/.vigilo/encryption.salt`
+pub fn salt_file_path() -> std::path::PathBuf {
+    crate::models::vigilo_path("encryption.salt")
+}
+
+/// Returns the path to the secret keying `export --anonymize` tokens: `~/.vigilo/anonymize.key`
+pub fn anonymize_secret_path() -> std::path::PathBuf {
+    crate::models::vigilo_path("anonymize.key")
+}
+
+/// The per-install secret for anonymized tokens, created on first use. Without
+/// it a token could be reversed by hashing guessed paths.
+pub fn anonymize_secret() -> std::io::Result<[u8; 32]> {
+    let secret = load_or_create_secret(&anonymize_secret_path(), 32)?;
+    secret
+        .try_into()
+        .map_err(|_| std::io::Error::new(std::io::ErrorKind::InvalidData, "bad anonymize.key"))
+}
+
+/// Reads a base64 secret, or creates one with `len` random bytes. The file is
+/// opened with `create_new`, so when two processes race the loser re-reads the
+/// winner's secret instead of replacing it.
+fn load_or_create_secret(path: &std::path::Path, len: usize) -> std::io::Result<Vec<u8>> {
+    match read_secret(path) {
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+        read => return read,
+    }
+    let mut secret = vec![0u8; len];
+    OsRng.fill_bytes(&mut secret);
+    match create_private(path, &STANDARD.encode(&secret)) {
+        Ok(()) => Ok(secret),
+        Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => {
+            // The winner may still be writing: an empty file isn't final yet.
+            for _ in 0..50 {
+                match read_secret(path) {
+                    Ok(s) if !s.is_empty() => return Ok(s),
+                    _ => std::thread::sleep(std::time::Duration::from_millis(10)),
+                }
+            }
+            read_secret(path)
+        }
+        Err(e) => Err(e),
+    }
+}
+
+fn read_secret(path: &std::path::Path) -> std::io::Result<Vec<u8>> {
+    let raw = std::fs::read_to_string(path)?;
+    STANDARD
+        .decode(raw.trim())
+        .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))
+}
+
+/// Creates `path` readable only by the owner from the first byte on; fails
+/// with `AlreadyExists` rather than replacing an existing file.
+pub(crate) fn create_private(path: &std::path::Path, contents: &str) -> std::io::Result<()> {
+    use std::io::Write;
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    options
+        .open(path)?
+        .write_all(format!("{contents}\n").as_bytes())
+}
+
+/// HMAC-SHA256 (RFC 2104) of `message` under `key`.
+pub fn hmac_sha256(key: &[u8], message: &[u8]) -> [u8; 32] {
+    use hmac::{Hmac, Mac};
+    let mut mac =
+        <Hmac<sha2::Sha256> as Mac>::new_from_slice(key).expect("HMAC accepts keys of any length");
+    mac.update(message);
+    mac.finalize().into_bytes().into()
+}
+
+/// Try loading key from: env var → password → key file → None.
 pub fn load_key() -> Option<EncryptionKey> {
     if let Some(key) = load_key_from_env() {
         return Some(key);
     }
+    if let Some(key) = load_key_from_password() {
+        return Some(key);
+    }
     load_key_from_file()
 }
 
+/// Derive the key from `VIGILO_ENCRYPTION_PASSWORD`, creating the salt file on first use.
+pub fn load_key_from_password() -> Option<EncryptionKey> {
+    let password = std::env::var("VIGILO_ENCRYPTION_PASSWORD").ok()?;
+    match load_or_create_secret(&salt_file_path(), 16) {
+        Ok(salt) => derive_key_cached(&password, &salt),
+        Err(e) => {
+            eprintln!("[vigilo] warning: could not read or create encryption salt: {e}");
+            None
+        }
+    }
+}
+
+/// Password and salt digest with the key they derived.
+type DerivedKey = ([u8; 32], [u8; 32]);
+
+/// Argon2 is deliberately slow, so a process derives each password's key once
+/// however many times it calls `load_key`.
+fn derive_key_cached(password: &str, salt: &[u8]) -> Option<EncryptionKey> {
+    use sha2::{Digest, Sha256};
+    static DERIVED: std::sync::Mutex<Option<DerivedKey>> = std::sync::Mutex::new(None);
+    let id: [u8; 32] = Sha256::new()
+        .chain_update(salt)
+        .chain_update(password.as_bytes())
+        .finalize()
+        .into();
+    let mut cached = DERIVED.lock().unwrap_or_else(|e| e.into_inner());
+    if let Some((cached_id, key)) = cached.as_ref() {
+        if *cached_id == id {
+            return Some(EncryptionKey::new(*key));
+        }
+    }
+    let key = derive_key(password, salt)?;
+    *cached = Some((id, *key.as_bytes()));
+    Some(key)
+}
+
+/// Argon2id with the crate's default cost parameters; the salt must be at least 8 bytes.
+pub fn derive_key(password: &str, salt: &[u8]) -> Option<EncryptionKey> {
+    let mut key = [0u8; 32];
+    argon2::Argon2::default()
+        .hash_password_into(password.as_bytes(), salt, &mut key)
+        .ok()?;
+    Some(EncryptionKey::new(key))
+}
+
 /// Load key, or auto-generate and persist one if none exists.
 /// Used by the MCP server to ensure encryption is always active.
 pub fn load_or_create_key() -> Option<EncryptionKey> {
@@ -57,41 +270,51 @@ pub fn load_or_create_key() -> Option<EncryptionKey> {
     }
 }
 
-fn load_key_from_env() -> Option<EncryptionKey> {
-    let raw = std::env::var("VIGILO_ENCRYPTION_KEY").ok()?;
-    let bytes = STANDARD.decode(raw.trim()).ok()?;
-    let arr: [u8; 32] = bytes.try_into().ok()?;
-    Some(EncryptionKey::new(arr))
+pub fn load_key_from_env() -> Option<EncryptionKey> {
+    key_from_b64(&std::env::var("VIGILO_ENCRYPTION_KEY").ok()?)
 }
 
 /// Load key from `~/.vigilo/encryption.key`.
 pub fn load_key_from_file() -> Option<EncryptionKey> {
-    let path = key_file_path();
-    let raw = std::fs::read_to_string(&path).ok()?;
+    key_from_b64(&std::fs::read_to_string(key_file_path()).ok()?)
+}
+
+/// Parse a base64-encoded 32-byte key, as printed by `generate-key`.
+pub fn key_from_b64(raw: &str) -> Option<EncryptionKey> {
     let bytes = STANDARD.decode(raw.trim()).ok()?;
     let arr: [u8; 32] = bytes.try_into().ok()?;
     Some(EncryptionKey::new(arr))
 }
 
-/// Generate a new AES-256 key, save it to `~/.vigilo/encryption.key` with mode 600.
-pub fn generate_and_save_key() -> std::io::Result<EncryptionKey> {
+pub fn generate_key() -> EncryptionKey {
     let mut key = [0u8; 32];
     OsRng.fill_bytes(&mut key);
-    let b64 = STANDARD.encode(key);
+    EncryptionKey::new(key)
+}
 
-    let path = key_file_path();
-    if let Some(parent) = path.parent() {
-        std::fs::create_dir_all(parent)?;
-    }
-    std::fs::write(&path, format!("{b64}\n"))?;
+/// Generate a new AES-256 key, save it to `~/.vigilo/encryption.key` with mode 600.
+pub fn generate_and_save_key() -> std::io::Result<EncryptionKey> {
+    let key = generate_key();
+    save_key(&key)?;
+    Ok(key)
+}
 
-    #[cfg(unix)]
-    {
-        use std::os::unix::fs::PermissionsExt;
-        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o600))?;
-    }
+/// Write `key` to `~/.vigilo/encryption.key` with mode 600, replacing any existing key.
+pub fn save_key(key: &EncryptionKey) -> std::io::Result<()> {
+    write_private(&key_file_path(), &STANDARD.encode(key.as_bytes()))
+}
 
-    Ok(EncryptionKey::new(key))
+/// Writes a fresh owner-only file and renames it over `path`, so the secret is
+/// never readable by others, not even briefly or through an older file's mode.
+fn write_private(path: &std::path::Path, b64: &str) -> std::io::Result<()> {
+    let mut tmp = path.as_os_str().to_owned();
+    tmp.push(format!(".{}.tmp", std::process::id()));
+    let tmp = std::path::PathBuf::from(tmp);
+    let _ = std::fs::remove_file(&tmp);
+    create_private(&tmp, b64)?;
+    std::fs::rename(&tmp, path).inspect_err(|_| {
+        let _ = std::fs::remove_file(&tmp);
+    })
 }
 
 pub fn encrypt(key: &EncryptionKey, plaintext: &str) -> Result<String, aes_gcm::Error> {
@@ -119,6 +342,7 @@ pub fn decrypt(key: &EncryptionKey, ciphertext: &str) -> Option<String> {
 
 pub fn encrypt_for_ledger(
     encryption_key: Option<&EncryptionKey>,
+    fields: EncryptFields,
     arguments: &serde_json::Value,
     outcome: &crate::models::Outcome,
     diff: &Option<String>,
@@ -127,20 +351,79 @@ pub fn encrypt_for_ledger(
         Some(k) => k,
         None => return Ok((arguments.clone(), outcome.clone(), diff.clone())),
     };
-    let enc_args = serde_json::json!(encrypt(key, &arguments.to_string())?);
+    let enc_args = match fields.arguments {
+        true => serde_json::json!(encrypt(key, &arguments.to_string())?),
+        false => arguments.clone(),
+    };
     let enc_outcome = match outcome {
-        crate::models::Outcome::Ok { result } => crate::models::Outcome::Ok {
+        crate::models::Outcome::Ok { result } if fields.result => crate::models::Outcome::Ok {
             result: serde_json::json!(encrypt(key, &result.to_string())?),
         },
-        crate::models::Outcome::Err { .. } => outcome.clone(),
+        _ => outcome.clone(),
     };
     let enc_diff = match diff.as_deref() {
-        Some(d) => Some(encrypt(key, d)?),
-        None => None,
+        Some(d) if fields.diff => Some(encrypt(key, d)?),
+        _ => diff.clone(),
     };
     Ok((enc_args, enc_outcome, enc_diff))
 }
 
+/// An event's `cwd` names the same directories as its path arguments, so it is
+/// encrypted whenever they are.
+pub fn encrypt_cwd(
+    encryption_key: Option<&EncryptionKey>,
+    fields: EncryptFields,
+    cwd: Option<String>,
+) -> Result<Option<String>, aes_gcm::Error> {
+    match (encryption_key, cwd) {
+        (Some(key), Some(dir)) if fields.arguments => Ok(Some(encrypt(key, &dir)?)),
+        (_, cwd) => Ok(cwd),
+    }
+}
+
+/// Reverses `encrypt_for_ledger` on a raw ledger line, in place. Plaintext
+/// fields are left alone; `None` if an encrypted field won't open with `key`.
+pub fn decrypt_ledger_line(key: &EncryptionKey, event: &mut serde_json::Value) -> Option<()> {
+    for (pointer, is_json) in LEDGER_FIELDS {
+        let Some(slot) = event.pointer_mut(pointer) else {
+            continue;
+        };
+        let Some(ct) = slot.as_str().filter(|s| is_encrypted(s)) else {
+            continue;
+        };
+        let plain = decrypt(key, ct)?;
+        *slot = match is_json {
+            true => serde_json::from_str(&plain).unwrap_or(serde_json::Value::String(plain)),
+            false => serde_json::Value::String(plain),
+        };
+    }
+    Some(())
+}
+
+/// Encrypts the same fields as `encrypt_for_ledger` on a raw ledger line, in place.
+/// Expects plaintext input, e.g. the output of `decrypt_ledger_line`.
+pub fn encrypt_ledger_line(
+    key: &EncryptionKey,
+    fields: EncryptFields,
+    event: &mut serde_json::Value,
+) -> Result<(), aes_gcm::Error> {
+    for (pointer, is_json) in LEDGER_FIELDS {
+        if !fields.covers(pointer) {
+            continue;
+        }
+        let Some(slot) = event.pointer_mut(pointer) else {
+            continue;
+        };
+        let plain = match (is_json, slot.as_str()) {
+            (true, _) => slot.to_string(),
+            (false, Some(s)) => s.to_string(),
+            (false, None) => continue,
+        };
+        *slot = serde_json::Value::String(encrypt(key, &plain)?);
+    }
+    Ok(())
+}
+
 pub fn is_encrypted(s: &str) -> bool {
     s.starts_with(PREFIX)
 }
@@ -159,6 +442,42 @@ mod tests {
         EncryptionKey::new([42u8; 32])
     }
 
+    #[test]
+    fn hmac_sha256_matches_rfc4231() {
+        let mac = hmac_sha256(b"Jefe", b"what do ya want for nothing?");
+        let hex: String = mac.iter().map(|b| format!("{b:02x}")).collect();
+        assert_eq!(
+            hex,
+            "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+        );
+
+        let long_key = [0xaau8; 131];
+        let mac = hmac_sha256(
+            &long_key,
+            b"Test Using Larger Than Block-Size Key - Hash Key First",
+        );
+        let hex: String = mac.iter().map(|b| format!("{b:02x}")).collect();
+        assert_eq!(
+            hex,
+            "60e431591ee0b67f0d8a26aacbf5b77f8e0bc6213728c5140546040f0ee37f54"
+        );
+    }
+
+    #[test]
+    fn racing_secret_creation_agrees_on_one_value() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("secret");
+        let handles: Vec<_> = (0..8)
+            .map(|_| {
+                let path = path.clone();
+                std::thread::spawn(move || load_or_create_secret(&path, 16).unwrap())
+            })
+            .collect();
+        let secrets: Vec<Vec<u8>> = handles.into_iter().map(|h| h.join().unwrap()).collect();
+        assert!(secrets.iter().all(|s| s == &secrets[0]));
+        assert_eq!(read_secret(&path).unwrap(), secrets[0]);
+    }
+
     #[test]
     fn round_trip() {
         let key = test_key();
@@ -277,6 +596,64 @@ mod tests {
         assert!(result.is_none());
     }
 
+    #[test]
+    fn encrypt_fields_parse_selects_listed_fields() {
+        let f = EncryptFields::parse("result, diff");
+        assert!(!f.arguments && f.result && f.diff);
+        assert_eq!(
+            EncryptFields::parse("arguments,result,diff"),
+            EncryptFields::default()
+        );
+    }
+
+    #[test]
+    fn derive_key_is_deterministic_per_password_and_salt() {
+        let salt = [3u8; 16];
+        let a = derive_key("correct horse", &salt).unwrap();
+        let b = derive_key("correct horse", &salt).unwrap();
+        let c = derive_key("battery staple", &salt).unwrap();
+        assert_eq!(a.as_bytes(), b.as_bytes());
+        assert_ne!(a.as_bytes(), c.as_bytes());
+
+        let ct = encrypt(&a, "secret").unwrap();
+        assert_eq!(decrypt(&b, &ct).unwrap(), "secret");
+        assert!(decrypt(&c, &ct).is_none());
+    }
+
+    #[test]
+    fn cached_derivation_matches_and_tracks_the_password() {
+        let salt = [5u8; 16];
+        let a = derive_key_cached("correct horse", &salt).unwrap();
+        let b = derive_key_cached("correct horse", &salt).unwrap();
+        let c = derive_key_cached("battery staple", &salt).unwrap();
+        assert_eq!(a.as_bytes(), b.as_bytes());
+        assert_eq!(
+            a.as_bytes(),
+            derive_key("correct horse", &salt).unwrap().as_bytes()
+        );
+        assert_ne!(a.as_bytes(), c.as_bytes());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn write_private_replaces_with_an_owner_only_file() {
+        use std::os::unix::fs::PermissionsExt;
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("encryption.key");
+        std::fs::write(&path, "old\n").unwrap();
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o644)).unwrap();
+
+        write_private(&path, "bmV3").unwrap();
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "bmV3\n");
+        let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+    }
+
+    #[test]
+    fn derive_key_rejects_short_salt() {
+        assert!(derive_key("pw", &[1u8; 4]).is_none());
+    }
+
     #[test]
     fn load_or_create_key_generates_when_missing() {
         let dir = tempfile::tempdir().unwrap();
@@ -1,20 +1,29 @@
 use anyhow::{Context, Result};
+use std::time::Duration;
 
 use super::credentials::{auth_cookie, Credentials};
-use crate::view::fmt::{ceprint, DIM, RESET};
+use crate::view::fmt::{ceprint, DIM, RESET, YELLOW};
 
 const SUMMARY_URL: &str = "https://cursor.com/api/usage-summary";
 const EVENTS_URL: &str = "https://cursor.com/api/dashboard/get-filtered-usage-events";
 
+const MAX_ATTEMPTS: u32 = 3;
+const BASE_DELAY: Duration = Duration::from_millis(500);
+const MAX_RETRY_AFTER: Duration = Duration::from_secs(30);
+const PAGE_SIZE: u32 = 100;
+const MAX_PAGES: u32 = 1000;
+
 pub(super) async fn fetch_summary(
     client: &reqwest::Client,
     creds: &Credentials,
 ) -> Result<serde_json::Value> {
-    let resp = client
-        .get(SUMMARY_URL)
-        .header("Cookie", auth_cookie(creds))
-        .header("User-Agent", concat!("vigilo/", env!("CARGO_PKG_VERSION")))
-        .send()
+    let request = || {
+        client
+            .get(SUMMARY_URL)
+            .header("Cookie", auth_cookie(creds))
+            .header("User-Agent", concat!("vigilo/", env!("CARGO_PKG_VERSION")))
+    };
+    let resp = send_with_retry(request, BASE_DELAY)
         .await
         .context("failed to reach cursor.com/api/usage-summary")?;
 
@@ -42,14 +51,16 @@ pub(super) async fn fetch_events(
         "pageSize": page_size,
     });
 
-    let resp = client
-        .post(EVENTS_URL)
-        .header("Cookie", auth_cookie(creds))
-        .header("User-Agent", concat!("vigilo/", env!("CARGO_PKG_VERSION")))
-        .header("Origin", "https://cursor.com")
-        .header("Referer", "https://cursor.com/settings")
-        .json(&body)
-        .send()
+    let request = || {
+        client
+            .post(EVENTS_URL)
+            .header("Cookie", auth_cookie(creds))
+            .header("User-Agent", concat!("vigilo/", env!("CARGO_PKG_VERSION")))
+            .header("Origin", "https://cursor.com")
+            .header("Referer", "https://cursor.com/settings")
+            .json(&body)
+    };
+    let resp = send_with_retry(request, BASE_DELAY)
         .await
         .context("failed to reach cursor.com/api/dashboard/get-filtered-usage-events")?;
 
@@ -65,38 +76,98 @@ pub(super) async fn fetch_events(
         .context("invalid JSON from filtered-usage-events")
 }
 
+/// Sends the request from `build`, retrying 429/502/503/504 responses and
+/// connect or timeout errors up to `MAX_ATTEMPTS` times with exponential
+/// backoff (or the server's `Retry-After`). Anything else, 401/403 included,
+/// is returned on the first attempt.
+async fn send_with_retry(
+    build: impl Fn() -> reqwest::RequestBuilder,
+    base_delay: Duration,
+) -> reqwest::Result<reqwest::Response> {
+    let mut attempt = 1;
+    loop {
+        let backoff = base_delay * 2u32.pow(attempt - 1);
+        match build().send().await {
+            Ok(resp) if attempt < MAX_ATTEMPTS && is_retryable(resp.status()) => {
+                tokio::time::sleep(retry_after(&resp).unwrap_or(backoff)).await;
+            }
+            Err(e) if attempt < MAX_ATTEMPTS && (e.is_connect() || e.is_timeout()) => {
+                tokio::time::sleep(backoff).await;
+            }
+            result => return result,
+        }
+        attempt += 1;
+    }
+}
+
+fn is_retryable(status: reqwest::StatusCode) -> bool {
+    matches!(status.as_u16(), 429 | 502 | 503 | 504)
+}
+
+/// Only the delay-seconds form of `Retry-After`, capped so a hostile value
+/// can't stall the sync.
+fn retry_after(resp: &reqwest::Response) -> Option<Duration> {
+    let secs: u64 = resp
+        .headers()
+        .get(reqwest::header::RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse()
+        .ok()?;
+    Some(Duration::from_secs(secs).min(MAX_RETRY_AFTER))
+}
+
 pub(super) async fn fetch_all_events(
     client: &reqwest::Client,
     creds: &Credentials,
     start_ms: i64,
     end_ms: i64,
 ) -> Result<Vec<serde_json::Value>> {
+    collect_pages(|page| fetch_events(client, creds, start_ms, end_ms, page, PAGE_SIZE)).await
+}
+
+/// Pages until the reported total is reached or a page comes back empty.
+/// Stops early, with a warning, after `MAX_PAGES` or when the API returns
+/// the same page twice — a bad `totalUsageEventsCount` can't loop forever.
+async fn collect_pages<F, Fut>(mut fetch_page: F) -> Result<Vec<serde_json::Value>>
+where
+    F: FnMut(u32) -> Fut,
+    Fut: std::future::Future<Output = Result<serde_json::Value>>,
+{
     let mut all = Vec::new();
-    let mut page = 1u32;
-    let page_size = 100u32;
+    let mut previous: Option<Vec<serde_json::Value>> = None;
     let frames = ['⠋', '⠙', '⠹', '⠸', '⠼', '⠴', '⠦', '⠧', '⠇', '⠏'];
 
     ceprint!("  {DIM}{} fetching usage data...{RESET}", frames[0]);
 
-    loop {
-        let data = fetch_events(client, creds, start_ms, end_ms, page, page_size).await?;
-        let events = data["usageEventsDisplay"].as_array();
-        match events {
-            Some(arr) if !arr.is_empty() => {
-                all.extend(arr.iter().cloned());
-                let total = data["totalUsageEventsCount"].as_u64().unwrap_or(0);
-                let frame = frames[page as usize % frames.len()];
-                ceprint!(
-                    "\r  {DIM}{frame} fetching usage data... {}/{total}{RESET}  ",
-                    all.len()
-                );
-                if all.len() as u64 >= total {
-                    break;
-                }
-                page += 1;
-            }
+    for page in 1..=MAX_PAGES {
+        let data = fetch_page(page).await?;
+        let events = match data["usageEventsDisplay"].as_array() {
+            Some(arr) if !arr.is_empty() => arr,
             _ => break,
+        };
+        if previous.as_ref() == Some(events) {
+            ceprint!("\r  {YELLOW}⚠ cursor.com repeated page {page}; stopping{RESET}\n");
+            break;
+        }
+        all.extend(events.iter().cloned());
+        let total = data["totalUsageEventsCount"].as_u64().unwrap_or(0);
+        let frame = frames[page as usize % frames.len()];
+        ceprint!(
+            "\r  {DIM}{frame} fetching usage data... {}/{total}{RESET}  ",
+            all.len()
+        );
+        if all.len() as u64 >= total {
+            break;
         }
+        if page == MAX_PAGES {
+            ceprint!(
+                "\r  {YELLOW}⚠ stopped after {MAX_PAGES} pages ({} of {total} events){RESET}\n",
+                all.len()
+            );
+        }
+        previous = Some(events.clone());
     }
     ceprint!(
         "\r  {DIM}✓ fetched {} events{RESET}              \n",
@@ -104,3 +175,101 @@ pub(super) async fn fetch_all_events(
     );
     Ok(all)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    /// Serves `statuses` in order, one per connection, then repeats the last.
+    async fn mock_server(statuses: &'static [u16]) -> (String, Arc<AtomicUsize>) {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/", listener.local_addr().unwrap());
+        let hits = Arc::new(AtomicUsize::new(0));
+        let counter = hits.clone();
+        tokio::spawn(async move {
+            while let Ok((mut sock, _)) = listener.accept().await {
+                let n = counter.fetch_add(1, Ordering::SeqCst);
+                let status = statuses[n.min(statuses.len() - 1)];
+                let mut buf = [0u8; 4096];
+                let _ = sock.read(&mut buf).await;
+                let body = "{\"ok\":true}";
+                let reply = format!(
+                    "HTTP/1.1 {status} X\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{body}",
+                    body.len()
+                );
+                let _ = sock.write_all(reply.as_bytes()).await;
+            }
+        });
+        (url, hits)
+    }
+
+    #[tokio::test]
+    async fn retries_503_until_success() {
+        let (url, hits) = mock_server(&[503, 503, 200]).await;
+        let client = reqwest::Client::new();
+        let resp = send_with_retry(|| client.get(&url), Duration::from_millis(1))
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), 200);
+        assert_eq!(hits.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn unauthorized_fails_without_retry() {
+        let (url, hits) = mock_server(&[401, 200]).await;
+        let client = reqwest::Client::new();
+        let resp = send_with_retry(|| client.get(&url), Duration::from_millis(1))
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), 401);
+        assert_eq!(hits.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn gives_up_after_max_attempts() {
+        let (url, hits) = mock_server(&[502]).await;
+        let client = reqwest::Client::new();
+        let resp = send_with_retry(|| client.get(&url), Duration::from_millis(1))
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), 502);
+        assert_eq!(hits.load(Ordering::SeqCst), MAX_ATTEMPTS as usize);
+    }
+
+    fn full_page(page: u32, distinct: bool) -> serde_json::Value {
+        let id = if distinct { page } else { 0 };
+        let events: Vec<_> = (0..PAGE_SIZE)
+            .map(|i| serde_json::json!({ "page": id, "i": i }))
+            .collect();
+        serde_json::json!({ "usageEventsDisplay": events, "totalUsageEventsCount": u64::MAX })
+    }
+
+    #[tokio::test]
+    async fn repeated_page_stops_pagination() {
+        let mut calls = 0;
+        let all = collect_pages(|page| {
+            calls += 1;
+            async move { Ok(full_page(page, false)) }
+        })
+        .await
+        .unwrap();
+        assert_eq!(calls, 2);
+        assert_eq!(all.len(), PAGE_SIZE as usize);
+    }
+
+    #[tokio::test]
+    async fn inflated_total_stops_at_page_cap() {
+        let mut calls = 0;
+        let all = collect_pages(|page| {
+            calls += 1;
+            async move { Ok(full_page(page, true)) }
+        })
+        .await
+        .unwrap();
+        assert_eq!(calls, MAX_PAGES);
+        assert_eq!(all.len(), (MAX_PAGES * PAGE_SIZE) as usize);
+    }
+}
@@ -4,7 +4,7 @@ use std::path::Path;
 
 use crate::view::fmt::normalize_model;
 
-const CACHE_STALE_SECS: u64 = 3600;
+const DEFAULT_CACHE_TTL_SECS: u64 = 3600;
 
 fn cache_path() -> String {
     crate::models::vigilo_path("cursor-tokens.jsonl")
@@ -112,17 +112,45 @@ pub fn aggregate_cached_tokens(events: &[CachedTokenEvent]) -> Option<CachedSess
 }
 
 pub fn is_cache_stale() -> bool {
-    let path = cache_path();
-    match std::fs::metadata(&path) {
-        Ok(meta) => {
-            let age = meta
-                .modified()
-                .ok()
-                .and_then(|t| t.elapsed().ok())
-                .map(|d| d.as_secs())
-                .unwrap_or(u64::MAX);
-            age > CACHE_STALE_SECS
-        }
-        Err(_) => true,
+    let modified = std::fs::metadata(cache_path()).and_then(|m| m.modified());
+    is_stale(modified.ok(), cache_ttl_secs(&crate::models::load_config()))
+}
+
+/// Stale once older than `ttl_secs`; a missing or unreadable mtime is stale.
+fn is_stale(modified: Option<std::time::SystemTime>, ttl_secs: u64) -> bool {
+    let age = modified
+        .and_then(|t| t.elapsed().ok())
+        .map_or(u64::MAX, |d| d.as_secs());
+    age > ttl_secs
+}
+
+/// `CURSOR_CACHE_TTL_SECS`, defaulting to an hour.
+fn cache_ttl_secs(config: &HashMap<String, String>) -> u64 {
+    crate::models::config_setting(config, "CURSOR_CACHE_TTL_SECS")
+        .and_then(|v| v.trim().parse().ok())
+        .unwrap_or(DEFAULT_CACHE_TTL_SECS)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cache_ttl_comes_from_config() {
+        let config =
+            |v: &str| HashMap::from([("CURSOR_CACHE_TTL_SECS".to_string(), v.to_string())]);
+        assert_eq!(cache_ttl_secs(&HashMap::new()), DEFAULT_CACHE_TTL_SECS);
+        assert_eq!(cache_ttl_secs(&config("86400")), 86_400);
+        assert_eq!(cache_ttl_secs(&config("soon")), DEFAULT_CACHE_TTL_SECS);
+    }
+
+    #[test]
+    fn cache_goes_stale_only_past_the_ttl() {
+        let ttl = 600;
+        let aged = |secs| Some(std::time::SystemTime::now() - std::time::Duration::from_secs(secs));
+        assert!(!is_stale(aged(ttl - 1), ttl));
+        assert!(!is_stale(aged(ttl), ttl));
+        assert!(is_stale(aged(ttl + 1), ttl));
+        assert!(is_stale(None, ttl));
     }
 }
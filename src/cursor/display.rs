@@ -117,6 +117,7 @@ pub(super) fn print_events(events: &[serde_json::Value], since_days: u32) {
 
 fn print_model_breakdown(by_model: HashMap<String, TokenTotals>) {
     let mut models: Vec<(String, TokenTotals)> = by_model.into_iter().collect();
+    #[allow(clippy::unnecessary_sort_by)]
     models.sort_by(|a, b| b.1.count.cmp(&a.1.count));
 
     println!();
@@ -19,13 +19,29 @@ pub fn has_cursor_db() -> bool {
     resolve_db_path().is_ok()
 }
 
+/// `CURSOR_PROXY`; without it reqwest still honours `HTTPS_PROXY` /
+/// `HTTP_PROXY` / `NO_PROXY`.
+fn cursor_proxy() -> Option<String> {
+    crate::models::config_setting(&crate::models::load_config(), "CURSOR_PROXY")
+        .filter(|p| !p.trim().is_empty())
+}
+
+fn build_client(proxy: Option<&str>) -> Result<reqwest::Client> {
+    let mut builder = reqwest::Client::builder().timeout(std::time::Duration::from_secs(10));
+    if let Some(url) = proxy {
+        let proxy = reqwest::Proxy::all(url.trim())
+            .map_err(|e| anyhow::anyhow!("invalid CURSOR_PROXY {url}: {e}"))?
+            .no_proxy(reqwest::NoProxy::from_env());
+        builder = builder.proxy(proxy);
+    }
+    Ok(builder.build()?)
+}
+
 pub async fn sync(since_days: u32) -> Result<()> {
     let db_path = resolve_db_path()?;
     let creds = credentials::read_credentials(&db_path)?;
 
-    let client = reqwest::Client::builder()
-        .timeout(std::time::Duration::from_secs(10))
-        .build()?;
+    let client = build_client(cursor_proxy().as_deref())?;
 
     let now_ms = chrono::Utc::now().timestamp_millis();
     let start_ms = now_ms - (since_days as i64 * MS_PER_DAY);
@@ -51,9 +67,7 @@ pub async fn run(since_days: u32) -> Result<()> {
     println!();
     cprintln!(" {badge}  {BOLD}{email}{RESET}  {DIM}({membership}){RESET}");
 
-    let client = reqwest::Client::builder()
-        .timeout(std::time::Duration::from_secs(10))
-        .build()?;
+    let client = build_client(cursor_proxy().as_deref())?;
 
     crate::view::fmt::ceprint!("  {DIM}⠋ connecting to cursor.com...{RESET}");
     match api::fetch_summary(&client, &creds).await {
@@ -317,4 +331,11 @@ mod tests {
         assert_eq!(parsed.input_tokens, event.input_tokens);
         assert_eq!(parsed.output_tokens, event.output_tokens);
     }
+
+    #[test]
+    fn client_builds_with_configured_proxy() {
+        assert!(build_client(None).is_ok());
+        assert!(build_client(Some("http://proxy.internal:3128")).is_ok());
+        assert!(build_client(Some("not a url")).is_err());
+    }
 }
@@ -0,0 +1,177 @@
+use axum::extract::State;
+use axum::http::{header, HeaderMap, HeaderValue, Request, StatusCode};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use std::sync::Arc;
+
+const COOKIE: &str = "vigilo_token";
+
+pub(super) fn token_from_config() -> Option<String> {
+    crate::models::config_setting(&crate::models::load_config(), "DASHBOARD_TOKEN")
+        .map(|t| t.trim().to_string())
+        .filter(|t| !t.is_empty())
+}
+
+/// The dashboard token and whether it is served over TLS, which decides if the
+/// cookie is marked `Secure`.
+#[derive(Clone)]
+pub(super) struct TokenGate {
+    token: Arc<str>,
+    secure: bool,
+}
+
+impl TokenGate {
+    pub(super) fn new(token: Arc<str>, secure: bool) -> Self {
+        Self { token, secure }
+    }
+}
+
+/// `/api/*` needs `Authorization: Bearer <token>` or the cookie set by opening
+/// the dashboard once as `/?token=<token>`. EventSource can't send headers, so
+/// the cookie is what keeps the live feed working. The query form is honoured
+/// only on the landing page, so API URLs never carry the token into logs or
+/// history. Static files stay open.
+pub(super) async fn require_token(
+    State(TokenGate { token, secure }): State<TokenGate>,
+    req: Request<axum::body::Body>,
+    next: Next,
+) -> Response {
+    let landing = matches!(req.uri().path(), "/" | "/index.html");
+    let via_query = landing && query_token(req.uri().query()).is_some_and(|t| ct_eq(t, &token));
+    let authorized = via_query || presented_token(req.headers()).is_some_and(|t| ct_eq(t, &token));
+    if req.uri().path().starts_with("/api/") && !authorized {
+        return (
+            StatusCode::UNAUTHORIZED,
+            [(header::WWW_AUTHENTICATE, "Bearer")],
+        )
+            .into_response();
+    }
+    let mut response = next.run(req).await;
+    if via_query {
+        let secure = if secure { "; Secure" } else { "" };
+        let cookie = format!("{COOKIE}={token}; Path=/; HttpOnly; SameSite=Strict{secure}");
+        if let Ok(value) = HeaderValue::from_str(&cookie) {
+            response.headers_mut().append(header::SET_COOKIE, value);
+        }
+    }
+    response
+}
+
+fn presented_token(headers: &HeaderMap) -> Option<&str> {
+    let bearer = headers
+        .get(header::AUTHORIZATION)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.strip_prefix("Bearer "));
+    bearer.or_else(|| {
+        headers
+            .get_all(header::COOKIE)
+            .iter()
+            .filter_map(|v| v.to_str().ok())
+            .flat_map(|v| v.split(';'))
+            .find_map(|pair| pair.trim().strip_prefix(COOKIE)?.strip_prefix('='))
+    })
+}
+
+fn query_token(query: Option<&str>) -> Option<&str> {
+    query?
+        .split('&')
+        .find_map(|pair| pair.strip_prefix("token="))
+}
+
+/// Compares without short-circuiting on the first differing byte.
+fn ct_eq(a: &str, b: &str) -> bool {
+    a.len() == b.len()
+        && a.bytes()
+            .zip(b.bytes())
+            .fold(0u8, |acc, (x, y)| acc | (x ^ y))
+            == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::routing::get;
+    use axum::Router;
+
+    async fn serve(token: &str) -> String {
+        serve_gate(TokenGate::new(Arc::from(token), false)).await
+    }
+
+    async fn serve_gate(gate: TokenGate) -> String {
+        let app = Router::new()
+            .route("/api/summary", get(|| async { "ok" }))
+            .fallback(|| async { "index" })
+            .layer(axum::middleware::from_fn_with_state(gate, require_token));
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move { axum::serve(listener, app).await });
+        url
+    }
+
+    #[tokio::test]
+    async fn api_requires_bearer_token() {
+        let url = serve("s3cret").await;
+        let client = reqwest::Client::new();
+        let get = |path: &str, auth: Option<&str>| {
+            let mut req = client.get(format!("{url}{path}"));
+            if let Some(a) = auth {
+                req = req.header("Authorization", a);
+            }
+            req.send()
+        };
+
+        assert_eq!(get("/api/summary", None).await.unwrap().status(), 401);
+        let wrong = get("/api/summary", Some("Bearer nope")).await.unwrap();
+        assert_eq!(wrong.status(), 401);
+        let ok = get("/api/summary", Some("Bearer s3cret")).await.unwrap();
+        assert_eq!(ok.status(), 200);
+        assert_eq!(ok.text().await.unwrap(), "ok");
+        assert_eq!(get("/", None).await.unwrap().status(), 200);
+    }
+
+    #[tokio::test]
+    async fn token_query_sets_cookie_accepted_by_api() {
+        let url = serve("s3cret").await;
+        let client = reqwest::Client::new();
+        let landing = client
+            .get(format!("{url}/?token=s3cret"))
+            .send()
+            .await
+            .unwrap();
+        let cookie = landing.headers()[header::SET_COOKIE].to_str().unwrap();
+        assert!(cookie.starts_with("vigilo_token=s3cret;"));
+        assert!(cookie.contains("SameSite=Strict"));
+        assert!(!cookie.contains("Secure"));
+
+        let api = client
+            .get(format!("{url}/api/summary"))
+            .header("Cookie", "theme=dark; vigilo_token=s3cret")
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(api.status(), 200);
+    }
+
+    #[tokio::test]
+    async fn token_query_is_ignored_outside_the_landing_page() {
+        let url = serve("s3cret").await;
+        let api = reqwest::get(format!("{url}/api/summary?token=s3cret"))
+            .await
+            .unwrap();
+        assert_eq!(api.status(), 401);
+        assert!(api.headers().get(header::SET_COOKIE).is_none());
+
+        let asset = reqwest::get(format!("{url}/assets/app.js?token=s3cret"))
+            .await
+            .unwrap();
+        assert!(asset.headers().get(header::SET_COOKIE).is_none());
+    }
+
+    #[tokio::test]
+    async fn cookie_is_secure_when_served_over_tls() {
+        let url = serve_gate(TokenGate::new(Arc::from("s3cret"), true)).await;
+        let landing = reqwest::get(format!("{url}/?token=s3cret")).await.unwrap();
+        let cookie = landing.headers()[header::SET_COOKIE].to_str().unwrap();
+        assert!(cookie.ends_with("; Secure"), "{cookie}");
+    }
+}
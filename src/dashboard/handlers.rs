@@ -9,8 +9,12 @@ use super::AppState;
 use crate::crypto::{self, EncryptionKey};
 use crate::models::{McpEvent, Outcome, Risk};
 use crate::view::counts::{collect_active_projects, EventCounts};
-use crate::view::data::{cursor_session_tokens, load_sessions, LoadFilter};
-use crate::view::fmt::{event_cost_usd, fmt_arg, normalize_model, risk_label, session_cost_usd};
+use crate::view::data::{
+    cursor_session_tokens, load_sessions, merge_sessions, session_merge_gap_secs, ts_to_epoch,
+    LoadFilter,
+};
+use crate::view::fmt::{event_cost_usd, normalize_model, risk_label, session_cost_usd};
+use crate::view::item::event_to_item;
 
 #[derive(serde::Deserialize, Default)]
 pub struct DateRangeParams {
@@ -28,37 +32,12 @@ pub struct EventFilterParams {
     pub tool: Option<String>,
     pub risk: Option<String>,
     pub last: Option<usize>,
+    pub offset: Option<usize>,
+    pub limit: Option<usize>,
 }
 
-fn event_to_item(e: &McpEvent, key: Option<&EncryptionKey>) -> EventItem {
-    let is_error = matches!(e.outcome, Outcome::Err { .. });
-    let project_root = e.project.root.as_deref();
-    let arg_display = fmt_arg(e, key, project_root);
-    let error_message = match &e.outcome {
-        Outcome::Err { message, .. } => Some(message.clone()),
-        _ => None,
-    };
-
-    EventItem {
-        id: e.id.to_string(),
-        timestamp: e.timestamp.clone(),
-        session_id: e.session_id.to_string(),
-        server: e.server.clone(),
-        tool: e.tool.clone(),
-        risk: e.risk,
-        duration_us: e.duration_us,
-        is_error,
-        project: e.project.name.clone(),
-        branch: e.project.branch.clone(),
-        arg_display,
-        input_tokens: e.input_tokens(),
-        output_tokens: e.output_tokens(),
-        cache_read_tokens: e.cache_read_tokens(),
-        cache_write_tokens: e.cache_write_tokens(),
-        model: e.model().map(|m| normalize_model(m).to_string()),
-        error_message,
-    }
-}
+const EVENTS_PAGE_DEFAULT: usize = 500;
+const EVENTS_PAGE_MAX: usize = 5000;
 
 pub(super) struct TodaySummary {
     pub sessions: Vec<(String, Vec<McpEvent>)>,
@@ -118,126 +97,64 @@ pub async fn sessions(
     };
 
     let sessions = load_sessions(&*state.ledger_path, &filter).unwrap_or_default();
-    let items = build_merged_session_list(&sessions);
+    let items = build_merged_session_list(&sessions, session_merge_gap_secs());
     Json(items)
 }
 
-/// Parse an RFC 3339 timestamp to epoch seconds.
-fn ts_to_epoch(ts: &str) -> Option<i64> {
-    chrono::DateTime::parse_from_rfc3339(ts)
-        .or_else(|_| chrono::DateTime::parse_from_rfc3339(&format!("{ts}Z")))
-        .ok()
-        .map(|dt| dt.timestamp())
-}
-
-const MERGE_GAP_SECS: u64 = 7200; // 2 hours
-
 /// Build a session list, merging consecutive sessions that belong to the same
-/// conversation (same server + same project + time gap < 2 hours).
+/// conversation (same server + same project + time gap < `gap_secs`).
 pub(super) fn build_merged_session_list(
     sessions: &[(String, Vec<McpEvent>)],
+    gap_secs: u64,
 ) -> Vec<SessionListItem> {
-    if sessions.is_empty() {
-        return Vec::new();
-    }
-
-    // Build individual session metadata
-    struct SessionMeta {
-        id: String,
-        id_prefix: String,
-        server: String,
-        project: Option<String>,
-        branch: Option<String>,
-        date: String,
-        call_count: usize,
-        duration_us: u64,
-        cost_usd: f64,
-        error_count: usize,
-        first_epoch: i64,
-        last_epoch: i64,
-    }
-
-    let metas: Vec<SessionMeta> = sessions
-        .iter()
-        .filter_map(|(sid, events)| {
-            let first = events.first()?;
-            let last = events.last()?;
-            let mut cost = session_cost_usd(events);
-            if let Some(ct) = cursor_session_tokens(events) {
-                cost += ct.cost_usd;
-            }
-            let error_count = events
-                .iter()
-                .filter(|e| matches!(e.outcome, Outcome::Err { .. }))
-                .count();
-
-            Some(SessionMeta {
-                id: sid.clone(),
-                id_prefix: sid[..8.min(sid.len())].to_string(),
-                server: first.server.clone(),
-                project: first.project.name.clone(),
-                branch: first.project.branch.clone(),
-                date: first
-                    .timestamp
-                    .get(..10)
-                    .unwrap_or(&first.timestamp)
-                    .to_string(),
-                call_count: events.len(),
-                duration_us: events.iter().map(|e| e.duration_us).sum(),
-                cost_usd: cost,
-                error_count,
-                first_epoch: ts_to_epoch(&first.timestamp).unwrap_or(0),
-                last_epoch: ts_to_epoch(&last.timestamp).unwrap_or(0),
-            })
-        })
-        .collect();
-
-    // Merge sessions with same server + project + gap < threshold.
-    // HashMap lookup for candidate groups by (server, project) key.
+    let slots = merge_sessions(sessions, gap_secs);
     let mut groups: Vec<(SessionListItem, i64)> = Vec::new();
-    let mut group_index: HashMap<(String, Option<String>), Vec<usize>> = HashMap::new();
-
-    for meta in &metas {
-        let key = (meta.server.clone(), meta.project.clone());
-        let mut merged = false;
-
-        if let Some(indices) = group_index.get(&key) {
-            for &idx in indices.iter().rev() {
-                let (_, last_epoch) = &groups[idx];
-                if meta.first_epoch.abs_diff(*last_epoch) < MERGE_GAP_SECS {
-                    let (group, last_epoch) = &mut groups[idx];
-                    group.call_count += meta.call_count;
-                    group.duration_us += meta.duration_us;
-                    group.cost_usd += meta.cost_usd;
-                    group.error_count += meta.error_count;
-                    group.session_ids.push(meta.id_prefix.clone());
-                    if meta.last_epoch > *last_epoch {
-                        *last_epoch = meta.last_epoch;
-                    }
-                    merged = true;
-                    break;
-                }
-            }
+    for ((sid, events), slot) in sessions.iter().zip(slots) {
+        let (Some((group, _)), Some(first), Some(last)) = (slot, events.first(), events.last())
+        else {
+            continue;
+        };
+        let mut cost_usd = session_cost_usd(events);
+        if let Some(ct) = cursor_session_tokens(events) {
+            cost_usd += ct.cost_usd;
         }
-
-        if !merged {
-            let idx = groups.len();
-            groups.push((
+        let call_count = events.len();
+        let duration_us: u64 = events.iter().map(|e| e.duration_us).sum();
+        let error_count = events
+            .iter()
+            .filter(|e| matches!(e.outcome, Outcome::Err { .. }))
+            .count();
+        let id_prefix = sid[..8.min(sid.len())].to_string();
+        let last_epoch = ts_to_epoch(&last.timestamp).unwrap_or(0);
+
+        match groups.get_mut(group) {
+            Some((group, group_end)) => {
+                group.call_count += call_count;
+                group.duration_us += duration_us;
+                group.cost_usd += cost_usd;
+                group.error_count += error_count;
+                group.session_ids.push(id_prefix);
+                *group_end = (*group_end).max(last_epoch);
+            }
+            None => groups.push((
                 SessionListItem {
-                    id: meta.id.clone(),
-                    server: meta.server.clone(),
-                    date: meta.date.clone(),
-                    project: meta.project.clone(),
-                    branch: meta.branch.clone(),
-                    call_count: meta.call_count,
-                    duration_us: meta.duration_us,
-                    cost_usd: meta.cost_usd,
-                    error_count: meta.error_count,
-                    session_ids: vec![meta.id_prefix.clone()],
+                    id: sid.clone(),
+                    server: first.server.clone(),
+                    date: first
+                        .timestamp
+                        .get(..10)
+                        .unwrap_or(&first.timestamp)
+                        .to_string(),
+                    project: first.project.name.clone(),
+                    branch: first.project.branch.clone(),
+                    call_count,
+                    duration_us,
+                    cost_usd,
+                    error_count,
+                    session_ids: vec![id_prefix],
                 },
-                meta.last_epoch,
-            ));
-            group_index.entry(key).or_default().push(idx);
+                last_epoch,
+            )),
         }
     }
 
@@ -317,6 +234,7 @@ pub async fn stats(
             cost_usd: a.cost_usd,
         })
         .collect();
+    #[allow(clippy::unnecessary_sort_by)]
     models.sort_by(|a, b| b.calls.cmp(&a.calls));
 
     // Tool breakdown
@@ -336,6 +254,7 @@ pub async fn stats(
             error_count: a.error_count,
         })
         .collect();
+    #[allow(clippy::unnecessary_sort_by)]
     tools.sort_by(|a, b| b.count.cmp(&a.count));
 
     // File breakdown
@@ -362,6 +281,7 @@ pub async fn stats(
         .into_iter()
         .map(|(file, count)| FileCount { file, count })
         .collect();
+    #[allow(clippy::unnecessary_sort_by)]
     files.sort_by(|a, b| b.count.cmp(&a.count));
 
     // Project breakdown
@@ -393,6 +313,7 @@ pub async fn stats(
             execs: a.execs,
         })
         .collect();
+    #[allow(clippy::unnecessary_sort_by)]
     projects.sort_by(|a, b| b.count.cmp(&a.count));
 
     // Timeline (group by date)
@@ -465,7 +386,7 @@ pub async fn stats(
 pub async fn events(
     State(state): State<AppState>,
     Query(params): Query<EventFilterParams>,
-) -> Json<Vec<EventItem>> {
+) -> Json<EventsPage> {
     let key = state.encryption_key.as_deref();
 
     let filter = LoadFilter {
@@ -473,18 +394,166 @@ pub async fn events(
         until: params.until.as_deref(),
         session: params.session.as_deref(),
         last: params.last,
+        ..Default::default()
     };
 
     let sessions = load_sessions(&*state.ledger_path, &filter).unwrap_or_default();
-    let items: Vec<EventItem> = sessions
+    let matching: Vec<&McpEvent> = sessions
         .iter()
         .flat_map(|(_, evts)| evts)
         .filter(|e| params.tool.as_ref().is_none_or(|t| &e.tool == t))
         .filter(|e| params.risk.as_ref().is_none_or(|r| risk_label(e.risk) == r))
-        .map(|e| event_to_item(e, key))
         .collect();
 
-    Json(items)
+    let offset = params.offset.unwrap_or(0);
+    let limit = params
+        .limit
+        .unwrap_or(EVENTS_PAGE_DEFAULT)
+        .min(EVENTS_PAGE_MAX);
+    Json(EventsPage {
+        total: matching.len(),
+        offset,
+        limit,
+        events: matching
+            .iter()
+            .skip(offset)
+            .take(limit)
+            .map(|e| event_to_item(e, key))
+            .collect(),
+    })
+}
+
+/// File diffs grouped per session and file, as `vigilo diff` shows them.
+pub async fn diffs(
+    State(state): State<AppState>,
+    Query(params): Query<DateRangeParams>,
+) -> Json<Vec<DiffSessionJson>> {
+    let filter = LoadFilter {
+        since: params.since.as_deref(),
+        until: params.until.as_deref(),
+        session: params.session.as_deref(),
+        last: params.last,
+        ..Default::default()
+    };
+    let sessions = load_sessions(&*state.ledger_path, &filter).unwrap_or_default();
+    let key = state.encryption_key.as_deref();
+    Json(
+        sessions
+            .iter()
+            .filter_map(|(sid, events)| diff_session(sid, events, key))
+            .collect(),
+    )
+}
+
+fn diff_session(
+    sid: &str,
+    events: &[McpEvent],
+    key: Option<&EncryptionKey>,
+) -> Option<DiffSessionJson> {
+    let by_file = crate::view::group_edits_by_file(events, key);
+    if by_file.is_empty() {
+        return None;
+    }
+    let first = events.first()?;
+    let files: Vec<DiffFileJson> = by_file
+        .into_iter()
+        .map(|(path, edits)| {
+            let changes: Vec<DiffEditJson> = edits.iter().map(|e| diff_edit(e, key)).collect();
+            DiffFileJson {
+                path,
+                edits: changes.len(),
+                added: changes.iter().map(|c| c.added).sum(),
+                removed: changes.iter().map(|c| c.removed).sum(),
+                new_file: edits.iter().any(|e| e.diff.as_deref() == Some("new file")),
+                changes,
+            }
+        })
+        .collect();
+    Some(DiffSessionJson {
+        session_id: sid.to_string(),
+        server: first.server.clone(),
+        project: first.project.name.clone(),
+        edits: files.iter().map(|f| f.edits).sum(),
+        added: files.iter().map(|f| f.added).sum(),
+        removed: files.iter().map(|f| f.removed).sum(),
+        files,
+    })
+}
+
+fn diff_edit(e: &McpEvent, key: Option<&EncryptionKey>) -> DiffEditJson {
+    let raw = e.diff.as_deref().unwrap_or("");
+    let text = match key {
+        Some(k) if crypto::is_encrypted(raw) => crypto::decrypt(k, raw),
+        _ if crypto::is_encrypted(raw) => None,
+        _ => Some(raw.to_string()),
+    };
+    let (added, removed) = match text.as_deref() {
+        Some(d) if d != "new file" => crate::view::fmt::diff_summary(d),
+        _ => (0, 0),
+    };
+    DiffEditJson {
+        id: e.id.to_string(),
+        timestamp: e.timestamp.clone(),
+        tool: e.tool.clone(),
+        added,
+        removed,
+        diff: text,
+    }
+}
+
+#[derive(serde::Deserialize, Default)]
+pub struct ExportParams {
+    pub format: Option<String>,
+    pub since: Option<String>,
+    pub until: Option<String>,
+    pub session: Option<String>,
+}
+
+/// The CLI's export formats, decrypted with the dashboard's key, as a download.
+pub async fn export(
+    State(state): State<AppState>,
+    Query(params): Query<ExportParams>,
+) -> axum::response::Response {
+    use axum::http::{header, StatusCode};
+    use axum::response::IntoResponse;
+
+    let format = params.format.as_deref().unwrap_or("csv");
+    let (content_type, ext) = match format {
+        "csv" => ("text/csv; charset=utf-8", "csv"),
+        "json" => ("application/json", "json"),
+        "ndjson" => ("application/x-ndjson", "ndjson"),
+        _ => {
+            return (
+                StatusCode::BAD_REQUEST,
+                "format must be csv, json or ndjson",
+            )
+                .into_response()
+        }
+    };
+    let filter = LoadFilter {
+        since: params.since.as_deref(),
+        until: params.until.as_deref(),
+        session: params.session.as_deref(),
+        ..Default::default()
+    };
+    let sessions = match crate::view::data::load_sessions_complete(&*state.ledger_path, &filter) {
+        Ok(sessions) => sessions,
+        Err(e) => return (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    };
+    match crate::view::render_export(&sessions, format, false, state.encryption_key.as_deref()) {
+        Ok(body) => (
+            [
+                (header::CONTENT_TYPE, content_type.to_string()),
+                (
+                    header::CONTENT_DISPOSITION,
+                    format!("attachment; filename=\"vigilo-export.{ext}\""),
+                ),
+            ],
+            body,
+        )
+            .into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
 }
 
 #[derive(serde::Deserialize, Default)]
@@ -528,6 +597,7 @@ pub async fn errors(
             count,
         })
         .collect();
+    #[allow(clippy::unnecessary_sort_by)]
     by_tool.sort_by(|a, b| b.count.cmp(&a.count));
 
     let recent_errors: Vec<EventItem> = err_events
@@ -554,7 +624,7 @@ pub async fn event_stream(
 
     let stream = async_stream::stream! {
         use notify::{RecursiveMode, Watcher, EventKind};
-        use std::io::{BufRead, BufReader, Seek, SeekFrom};
+        use std::io::{BufReader, Seek, SeekFrom};
 
         let path: std::path::PathBuf = (*ledger_path).clone();
 
@@ -609,21 +679,20 @@ pub async fn event_stream(
                     return (current_pos, Vec::new());
                 }
 
-                let mut items = Vec::new();
-                let mut line = String::new();
-                while reader.read_line(&mut line).unwrap_or(0) > 0 {
-                    let trimmed = line.trim();
-                    if !trimmed.is_empty() {
-                        if let Ok(event) = serde_json::from_str::<McpEvent>(trimmed) {
-                            let item = event_to_item(&event, read_key.as_deref());
-                            if let Ok(json) = serde_json::to_string(&item) {
-                                items.push(json);
-                            }
-                        }
-                    }
-                    line.clear();
-                }
-                (meta_len, items)
+                // A half-written last line is left for the next pass
+                let Ok((lines, consumed)) = crate::view::data::read_complete_lines(&mut reader)
+                else {
+                    return (current_pos, Vec::new());
+                };
+                let items = lines
+                    .iter()
+                    .filter(|l| !l.trim().is_empty())
+                    .filter_map(|l| serde_json::from_str::<McpEvent>(l.trim()).ok())
+                    .filter_map(|event| {
+                        serde_json::to_string(&event_to_item(&event, read_key.as_deref())).ok()
+                    })
+                    .collect();
+                (current_pos + consumed, items)
             }).await;
 
             if let Ok((new_pos, items)) = result {
@@ -637,3 +706,34 @@ pub async fn event_stream(
 
     Sse::new(stream).keep_alive(KeepAlive::default())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn session_at(id: &str, timestamp: &str) -> (String, Vec<McpEvent>) {
+        let e = McpEvent {
+            timestamp: timestamp.to_string(),
+            server: "vigilo".to_string(),
+            tool: "read_file".to_string(),
+            ..Default::default()
+        };
+        (id.to_string(), vec![e])
+    }
+
+    #[test]
+    fn merge_gap_decides_whether_borderline_sessions_merge() {
+        // 30 minutes apart
+        let sessions = vec![
+            session_at("aaaaaaaa-1", "2026-01-01T10:00:00Z"),
+            session_at("bbbbbbbb-2", "2026-01-01T10:30:00Z"),
+        ];
+
+        let merged = build_merged_session_list(&sessions, 3600);
+        assert_eq!(merged.len(), 1);
+        assert_eq!(merged[0].session_ids, vec!["aaaaaaaa", "bbbbbbbb"]);
+
+        let split = build_merged_session_list(&sessions, 1800);
+        assert_eq!(split.len(), 2);
+    }
+}
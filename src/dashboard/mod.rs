@@ -1,5 +1,7 @@
+mod auth;
 mod handlers;
 mod static_files;
+mod tls;
 mod types;
 
 use anyhow::Result;
@@ -9,6 +11,7 @@ use axum::response::{IntoResponse, Response};
 use axum::Router;
 use tower_http::cors::CorsLayer;
 
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
@@ -18,7 +21,8 @@ pub struct AppState {
     pub encryption_key: Option<Arc<crate::crypto::EncryptionKey>>,
 }
 
-pub async fn run(ledger_path: String, port: u16) -> Result<()> {
+pub async fn run(ledger_path: String, port: u16, bind: Option<String>) -> Result<()> {
+    let bind = resolve_bind(bind)?;
     if crate::cursor::has_cursor_db() && crate::cursor::is_cache_stale() {
         tokio::spawn(async {
             if let Err(e) = crate::cursor::sync(7).await {
@@ -37,13 +41,100 @@ pub async fn run(ledger_path: String, port: u16) -> Result<()> {
         encryption_key: encryption_key.map(Arc::new),
     };
 
-    let (listener, actual_port) = bind_with_fallback(port).await?;
+    let tls = match tls::paths_from_config()? {
+        Some((cert, key)) => Some(tls::server_config(&cert, &key)?),
+        None => None,
+    };
+    let scheme = if tls.is_some() { "https" } else { "http" };
+
+    let token = auth::token_from_config();
+    check_exposure(bind, token.is_some())?;
+    let (listener, actual_port) = bind_with_fallback(bind, port).await?;
+    let origin = Origin {
+        scheme,
+        bind,
+        port: actual_port,
+    };
+    let app = router(state, origin, token.as_deref().map(Arc::from));
+
+    let addr = SocketAddr::new(display_host(bind), actual_port);
+    print_banner(&ledger_path, origin.url(addr), encrypted, token.as_deref());
+    if !bind.is_loopback() {
+        warn_exposed(bind, tls.is_some());
+    }
+
+    match tls {
+        Some(config) => {
+            let config = axum_server::tls_rustls::RustlsConfig::from_config(Arc::new(config));
+            axum_server::from_tcp_rustls(listener.into_std()?, config)
+                .serve(app.into_make_service())
+                .await?
+        }
+        None => axum::serve(listener, app).await?,
+    }
+    Ok(())
+}
+
+/// Where the dashboard is served from, for CORS origins and the banner URL.
+#[derive(Clone, Copy)]
+struct Origin {
+    scheme: &'static str,
+    bind: IpAddr,
+    port: u16,
+}
+
+impl Origin {
+    fn url(&self, addr: SocketAddr) -> String {
+        format!("{}://{addr}", self.scheme)
+    }
+}
+
+/// `--bind`, then the `DASHBOARD_BIND` setting, then loopback.
+fn resolve_bind(flag: Option<String>) -> Result<IpAddr> {
+    let value = flag
+        .or_else(|| crate::models::config_setting(&crate::models::load_config(), "DASHBOARD_BIND"));
+    match value {
+        Some(v) => v
+            .trim()
+            .trim_matches(|c| c == '[' || c == ']')
+            .parse()
+            .map_err(|_| anyhow::anyhow!("invalid bind address: {v}")),
+        None => Ok(IpAddr::V4(Ipv4Addr::LOCALHOST)),
+    }
+}
 
+/// Off loopback the Host check can't tell a rebinding attack from a real
+/// client (any name passes on `0.0.0.0` / `::`), so the token is required.
+fn check_exposure(bind: IpAddr, has_token: bool) -> Result<()> {
+    if bind.is_loopback() || has_token {
+        return Ok(());
+    }
+    anyhow::bail!(
+        "refusing to listen on {bind} without a token — set VIGILO_DASHBOARD_TOKEN, or bind to 127.0.0.1"
+    )
+}
+
+fn warn_exposed(bind: IpAddr, has_tls: bool) {
+    use crate::view::fmt::{ceprintln, BOLD, RESET, YELLOW};
+    ceprintln!("  {YELLOW}{BOLD}⚠ listening on {bind} — the dashboard is reachable from other machines.{RESET}");
+    ceprintln!("  {YELLOW}  Anyone with the token can read your ledger.{RESET}");
+    if !has_tls {
+        ceprintln!("  {YELLOW}  Traffic is plain HTTP — set VIGILO_DASHBOARD_CERT and VIGILO_DASHBOARD_KEY to serve HTTPS.{RESET}");
+    }
+    eprintln!();
+}
+
+fn router(state: AppState, origin: Origin, token: Option<Arc<str>>) -> Router {
+    let Origin { scheme, bind, port } = origin;
+    let mut origins = vec![
+        format!("{scheme}://127.0.0.1:{port}").parse().unwrap(),
+        format!("{scheme}://localhost:{port}").parse().unwrap(),
+    ];
+    if !bind.is_loopback() && !bind.is_unspecified() {
+        origins.push(origin.url(SocketAddr::new(bind, port)).parse().unwrap());
+    }
     let cors = CorsLayer::new()
-        .allow_origin([
-            format!("http://127.0.0.1:{actual_port}").parse().unwrap(),
-            format!("http://localhost:{actual_port}").parse().unwrap(),
-        ])
+        .allow_origin(origins)
         .allow_methods([Method::GET])
         .allow_headers([header::CONTENT_TYPE, header::ACCEPT]);
 
@@ -53,37 +144,59 @@ pub async fn run(ledger_path: String, port: u16) -> Result<()> {
         .route("/api/stats", axum::routing::get(handlers::stats))
         .route("/api/events", axum::routing::get(handlers::events))
         .route("/api/errors", axum::routing::get(handlers::errors))
+        .route("/api/export", axum::routing::get(handlers::export))
+        .route("/api/diffs", axum::routing::get(handlers::diffs))
         .route(
             "/api/events/stream",
             axum::routing::get(handlers::event_stream),
         );
 
-    let app = api
-        .fallback(static_files::serve)
-        .layer(cors)
+    let mut app = api.fallback(static_files::serve);
+    if let Some(token) = token {
+        let gate = auth::TokenGate::new(token, scheme == "https");
+        app = app.layer(middleware::from_fn_with_state(gate, auth::require_token));
+    }
+    app.layer(cors)
         .layer(middleware::from_fn(security_headers))
-        .layer(middleware::from_fn(validate_host))
-        .with_state(state);
-
-    print_banner(&ledger_path, actual_port, encrypted);
-
-    axum::serve(listener, app).await?;
-    Ok(())
+        .layer(middleware::from_fn_with_state(bind, validate_host))
+        .with_state(state)
 }
 
-async fn validate_host(req: Request<axum::body::Body>, next: Next) -> Response {
+/// Guards against DNS rebinding on loopback. Bound to one LAN address, that
+/// address is accepted too; bound to `0.0.0.0` / `::` the names clients use
+/// can't be known, so any host passes and the token `check_exposure` insisted
+/// on is the guard.
+async fn validate_host(
+    axum::extract::State(bind): axum::extract::State<IpAddr>,
+    req: Request<axum::body::Body>,
+    next: Next,
+) -> Response {
     let host = req
         .headers()
         .get(header::HOST)
         .and_then(|v| v.to_str().ok())
         .unwrap_or("");
-    let hostname = host.split(':').next().unwrap_or("");
-    if !matches!(hostname, "127.0.0.1" | "localhost" | "[::1]" | "") {
+    if !host_allowed(host_name(host), bind) {
         return StatusCode::FORBIDDEN.into_response();
     }
     next.run(req).await
 }
 
+fn host_allowed(hostname: &str, bind: IpAddr) -> bool {
+    if matches!(hostname, "127.0.0.1" | "localhost" | "::1" | "") || bind.is_unspecified() {
+        return true;
+    }
+    !bind.is_loopback() && hostname.parse::<IpAddr>() == Ok(bind)
+}
+
+/// `Host` without the port; IPv6 literals lose their brackets.
+fn host_name(host: &str) -> &str {
+    match host.strip_prefix('[') {
+        Some(rest) => rest.split(']').next().unwrap_or(""),
+        None => host.split(':').next().unwrap_or(""),
+    }
+}
+
 async fn security_headers(req: Request<axum::body::Body>, next: Next) -> Response {
     let is_api = req.uri().path().starts_with("/api/");
     let mut response = next.run(req).await;
@@ -104,8 +217,8 @@ async fn security_headers(req: Request<axum::body::Body>, next: Next) -> Respons
 
 /// Try to bind to the requested port. If it's taken, ask the user whether to
 /// pick the next available port.
-async fn bind_with_fallback(port: u16) -> Result<(tokio::net::TcpListener, u16)> {
-    match tokio::net::TcpListener::bind(format!("127.0.0.1:{port}")).await {
+async fn bind_with_fallback(bind: IpAddr, port: u16) -> Result<(tokio::net::TcpListener, u16)> {
+    match tokio::net::TcpListener::bind(SocketAddr::new(bind, port)).await {
         Ok(listener) => Ok((listener, port)),
         Err(e) if e.kind() == std::io::ErrorKind::AddrInUse => {
             eprintln!("[vigilo] port {port} is already in use.");
@@ -118,7 +231,7 @@ async fn bind_with_fallback(port: u16) -> Result<(tokio::net::TcpListener, u16)>
                 anyhow::bail!("port {port} is already in use — pass --port <N> to choose another");
             }
 
-            let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await?;
+            let listener = tokio::net::TcpListener::bind(SocketAddr::new(bind, 0)).await?;
             let actual_port = listener.local_addr()?.port();
             Ok((listener, actual_port))
         }
@@ -126,12 +239,25 @@ async fn bind_with_fallback(port: u16) -> Result<(tokio::net::TcpListener, u16)>
     }
 }
 
-fn print_banner(ledger_path: &str, port: u16, encrypted: bool) {
+/// Where a browser on this machine should point: the bind address unless
+/// that's the wildcard, which isn't itself connectable.
+fn display_host(bind: IpAddr) -> IpAddr {
+    if bind.is_unspecified() {
+        IpAddr::V4(Ipv4Addr::LOCALHOST)
+    } else {
+        bind
+    }
+}
+
+fn print_banner(ledger_path: &str, base_url: String, encrypted: bool, token: Option<&str>) {
     use crate::models::shorten_home;
 
     let ledger = Path::new(ledger_path);
     let version = env!("CARGO_PKG_VERSION");
-    let url = format!("http://127.0.0.1:{port}");
+    let url = match token {
+        Some(t) => format!("{base_url}/?token={t}"),
+        None => base_url,
+    };
     let ledger_display = shorten_home(ledger_path);
     let encryption_status = if encrypted {
         "enabled (AES-256-GCM)"
@@ -150,16 +276,22 @@ fn print_banner(ledger_path: &str, port: u16, encrypted: bool) {
     // Count active MCP servers
     let mcp_servers = count_mcp_servers();
 
-    let merged = handlers::build_merged_session_list(&ts.sessions);
+    let merged = handlers::build_merged_session_list(
+        &ts.sessions,
+        crate::view::data::session_merge_gap_secs(),
+    );
 
-    // Dim / bold escape codes
-    let dim = "\x1b[2m";
-    let bold = "\x1b[1m";
-    let cyan = "\x1b[36m";
-    let yellow = "\x1b[33m";
-    let green = "\x1b[32m";
-    let red = "\x1b[31m";
-    let reset = "\x1b[0m";
+    // Escape codes, blank when stderr isn't a terminal or NO_COLOR is set
+    use crate::view::fmt::{BOLD, CYAN, DIM, GREEN, RED, RESET, YELLOW};
+    let color = crate::view::fmt::use_color_stderr();
+    let paint = |code: &'static str| if color { code } else { "" };
+    let dim = paint(DIM);
+    let bold = paint(BOLD);
+    let cyan = paint(CYAN);
+    let yellow = paint(YELLOW);
+    let green = paint(GREEN);
+    let red = paint(RED);
+    let reset = paint(RESET);
 
     // Header
     let width: usize = 72;
@@ -205,6 +337,13 @@ fn print_banner(ledger_path: &str, port: u16, encrypted: bool) {
         "",
         pad = label_w - 10
     );
+    if token.is_some() {
+        eprintln!(
+            "  {dim}API auth{reset}{:>pad$}bearer token",
+            "",
+            pad = label_w - 8
+        );
+    }
     if mcp_servers > 0 {
         eprintln!(
             "  {dim}MCP servers{reset}{:>pad$}{green}{mcp_servers} active{reset}",
@@ -291,10 +430,12 @@ fn fmt_bytes(bytes: u64) -> String {
 }
 
 fn format_cost(usd: f64) -> String {
-    if usd < 0.01 {
-        format!("${usd:.4}")
+    let cur = crate::view::fmt::currency();
+    let (sym, v) = (cur.symbol(), cur.convert(usd));
+    if v < 0.01 {
+        format!("{sym}{v:.4}")
     } else {
-        format!("${usd:.2}")
+        format!("{sym}{v:.2}")
     }
 }
 
@@ -329,3 +470,222 @@ fn count_mcp_servers() -> usize {
         0
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn serve(bind: IpAddr, token: Option<&str>) -> (String, tempfile::TempDir) {
+        let dir = tempfile::tempdir().unwrap();
+        let state = AppState {
+            ledger_path: Arc::new(dir.path().join("events.jsonl")),
+            encryption_key: None,
+        };
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let origin = Origin {
+            scheme: "http",
+            bind,
+            port,
+        };
+        let app = router(state, origin, token.map(Arc::from));
+        tokio::spawn(async move { axum::serve(listener, app).await });
+        (format!("http://127.0.0.1:{port}"), dir)
+    }
+
+    #[tokio::test]
+    async fn lan_bind_accepts_lan_host_but_still_requires_token() {
+        let (url, _dir) = serve("0.0.0.0".parse().unwrap(), Some("s3cret")).await;
+        let client = reqwest::Client::new();
+        let summary = |auth: Option<&str>| {
+            let mut req = client
+                .get(format!("{url}/api/summary"))
+                .header("Host", "192.168.1.20:7847");
+            if let Some(a) = auth {
+                req = req.header("Authorization", a);
+            }
+            req.send()
+        };
+        assert_eq!(summary(None).await.unwrap().status(), 401);
+        assert_eq!(summary(Some("Bearer s3cret")).await.unwrap().status(), 200);
+    }
+
+    #[tokio::test]
+    async fn loopback_bind_rejects_foreign_host() {
+        let (url, _dir) = serve(IpAddr::V4(Ipv4Addr::LOCALHOST), None).await;
+        let resp = reqwest::Client::new()
+            .get(format!("{url}/api/summary"))
+            .header("Host", "evil.example:7847")
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), 403);
+    }
+
+    #[tokio::test]
+    async fn export_endpoint_downloads_csv() {
+        let (url, dir) = serve(IpAddr::V4(Ipv4Addr::LOCALHOST), None).await;
+        let event = crate::models::McpEvent {
+            timestamp: "2026-03-01T10:00:00Z".to_string(),
+            server: "vigilo".to_string(),
+            tool: "read_file".to_string(),
+            arguments: serde_json::json!({ "path": "src/main.rs" }),
+            ..Default::default()
+        };
+        std::fs::write(
+            dir.path().join("events.jsonl"),
+            format!("{}\n", serde_json::to_string(&event).unwrap()),
+        )
+        .unwrap();
+
+        let resp = reqwest::get(format!("{url}/api/export?format=csv"))
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), 200);
+        assert_eq!(
+            resp.headers()[header::CONTENT_TYPE],
+            "text/csv; charset=utf-8"
+        );
+        assert!(resp.headers()[header::CONTENT_DISPOSITION]
+            .to_str()
+            .unwrap()
+            .starts_with("attachment;"));
+        let body = resp.text().await.unwrap();
+        let mut lines = body.lines();
+        assert!(lines
+            .next()
+            .unwrap()
+            .starts_with("timestamp,session,server,"));
+        assert!(lines.next().unwrap().contains("read_file"));
+
+        let bad = reqwest::get(format!("{url}/api/export?format=xml"))
+            .await
+            .unwrap();
+        assert_eq!(bad.status(), 400);
+    }
+
+    #[tokio::test]
+    async fn export_endpoint_fails_when_the_ledger_is_unreadable() {
+        let (url, dir) = serve(IpAddr::V4(Ipv4Addr::LOCALHOST), None).await;
+        std::fs::create_dir(dir.path().join("events.jsonl")).unwrap();
+
+        let resp = reqwest::get(format!("{url}/api/export?format=json"))
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), 500);
+        assert!(resp.text().await.unwrap().contains("events.jsonl"));
+    }
+
+    #[tokio::test]
+    async fn diffs_endpoint_groups_edits_with_line_counts() {
+        let (url, dir) = serve(IpAddr::V4(Ipv4Addr::LOCALHOST), None).await;
+        let session_id = uuid::Uuid::new_v4();
+        let write = |path: &str, diff: Option<&str>| crate::models::McpEvent {
+            id: uuid::Uuid::new_v4(),
+            session_id,
+            timestamp: "2026-03-01T10:00:00Z".to_string(),
+            server: "vigilo".to_string(),
+            tool: "write_file".to_string(),
+            arguments: serde_json::json!({ "path": path }),
+            diff: diff.map(str::to_string),
+            ..Default::default()
+        };
+        let lines: Vec<String> = [
+            write("a.rs", Some("@@ -1,2 +1,3 @@\n-old\n+new\n+more\n ctx")),
+            write("a.rs", Some("+again")),
+            write("b.rs", None),
+        ]
+        .iter()
+        .map(|e| serde_json::to_string(e).unwrap())
+        .collect();
+        std::fs::write(dir.path().join("events.jsonl"), lines.join("\n") + "\n").unwrap();
+
+        let body: serde_json::Value = reqwest::get(format!("{url}/api/diffs"))
+            .await
+            .unwrap()
+            .json()
+            .await
+            .unwrap();
+        let session = &body[0];
+        assert_eq!(session["session_id"], session_id.to_string());
+        assert_eq!(session["files"].as_array().unwrap().len(), 1);
+        let file = &session["files"][0];
+        assert_eq!(file["path"], "a.rs");
+        assert_eq!(
+            (file["edits"].as_u64(), file["added"].as_u64()),
+            (Some(2), Some(3))
+        );
+        assert_eq!(file["removed"], 1);
+        assert_eq!(file["changes"][1]["diff"], "+again");
+    }
+
+    #[tokio::test]
+    async fn events_endpoint_pages_with_total() {
+        let (url, dir) = serve(IpAddr::V4(Ipv4Addr::LOCALHOST), None).await;
+        let session_id = uuid::Uuid::new_v4();
+        let lines: Vec<String> = (0..4)
+            .map(|i| {
+                let e = crate::models::McpEvent {
+                    id: uuid::Uuid::new_v4(),
+                    session_id,
+                    timestamp: format!("2026-03-01T10:00:0{i}Z"),
+                    server: "vigilo".to_string(),
+                    tool: format!("tool_{i}"),
+                    ..Default::default()
+                };
+                serde_json::to_string(&e).unwrap()
+            })
+            .collect();
+        std::fs::write(dir.path().join("events.jsonl"), lines.join("\n") + "\n").unwrap();
+
+        let get = |query: &'static str| {
+            let url = url.clone();
+            async move {
+                reqwest::get(format!("{url}/api/events?{query}"))
+                    .await
+                    .unwrap()
+                    .json::<serde_json::Value>()
+                    .await
+                    .unwrap()
+            }
+        };
+        let page = get("limit=2&offset=1").await;
+        assert_eq!(page["total"], 4);
+        let tools: Vec<&str> = page["events"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|e| e["tool"].as_str().unwrap())
+            .collect();
+        assert_eq!(tools, ["tool_1", "tool_2"]);
+
+        assert_eq!(get("limit=999999").await["limit"], 5000);
+        let filtered = get("tool=tool_3").await;
+        assert_eq!(filtered["total"], 1);
+        assert_eq!(filtered["events"][0]["tool"], "tool_3");
+    }
+
+    #[test]
+    fn exposed_binds_need_a_token() {
+        let lan: IpAddr = "192.168.1.20".parse().unwrap();
+        assert!(check_exposure(IpAddr::V4(Ipv4Addr::LOCALHOST), false).is_ok());
+        assert!(check_exposure("::1".parse().unwrap(), false).is_ok());
+        assert!(check_exposure(lan, false).is_err());
+        assert!(check_exposure("0.0.0.0".parse().unwrap(), false).is_err());
+        assert!(check_exposure(lan, true).is_ok());
+    }
+
+    #[test]
+    fn host_checks_follow_bind_address() {
+        let lan: IpAddr = "192.168.1.20".parse().unwrap();
+        let loopback = IpAddr::V4(Ipv4Addr::LOCALHOST);
+        assert!(host_allowed(host_name("[::1]:7847"), loopback));
+        assert!(!host_allowed(host_name("192.168.1.20:7847"), loopback));
+        assert!(host_allowed(host_name("192.168.1.20:7847"), lan));
+        assert!(!host_allowed(host_name("evil.example"), lan));
+        assert!(host_allowed(
+            host_name("devbox.local"),
+            "::".parse().unwrap()
+        ));
+    }
+}
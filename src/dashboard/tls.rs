@@ -0,0 +1,77 @@
+use anyhow::{Context, Result};
+use rustls::pki_types::pem::PemObject;
+use rustls::pki_types::{CertificateDer, PrivateKeyDer};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// `DASHBOARD_CERT` and `DASHBOARD_KEY`, both or neither: one without the
+/// other is an error rather than a silent fall back to plain HTTP.
+pub(super) fn paths_from_config() -> Result<Option<(PathBuf, PathBuf)>> {
+    let config = crate::models::load_config();
+    let setting = |key| {
+        crate::models::config_setting(&config, key)
+            .map(|v| v.trim().to_string())
+            .filter(|v| !v.is_empty())
+    };
+    match (setting("DASHBOARD_CERT"), setting("DASHBOARD_KEY")) {
+        (Some(cert), Some(key)) => Ok(Some((cert.into(), key.into()))),
+        (None, None) => Ok(None),
+        _ => anyhow::bail!("TLS needs both VIGILO_DASHBOARD_CERT and VIGILO_DASHBOARD_KEY"),
+    }
+}
+
+/// Reads a PEM certificate chain and private key into a server config using
+/// the ring provider, so no process-wide default provider has to be installed.
+pub(super) fn server_config(cert: &Path, key: &Path) -> Result<rustls::ServerConfig> {
+    let chain = CertificateDer::pem_file_iter(cert)
+        .and_then(|certs| certs.collect::<Result<Vec<_>, _>>())
+        .with_context(|| format!("reading certificate {}", cert.display()))?;
+    if chain.is_empty() {
+        anyhow::bail!("no certificate found in {}", cert.display());
+    }
+    let private = PrivateKeyDer::from_pem_file(key)
+        .with_context(|| format!("reading private key {}", key.display()))?;
+
+    let mut config = rustls::ServerConfig::builder_with_provider(Arc::new(
+        rustls::crypto::ring::default_provider(),
+    ))
+    .with_safe_default_protocol_versions()?
+    .with_no_client_auth()
+    .with_single_cert(chain, private)
+    .context("certificate and private key don't match")?;
+    config.alpn_protocols = vec![b"h2".to_vec(), b"http/1.1".to_vec()];
+    Ok(config)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn self_signed(dir: &Path) -> (PathBuf, PathBuf) {
+        let cert = rcgen::generate_simple_self_signed(vec!["localhost".to_string()]).unwrap();
+        let cert_path = dir.join("cert.pem");
+        let key_path = dir.join("key.pem");
+        std::fs::write(&cert_path, cert.cert.pem()).unwrap();
+        std::fs::write(&key_path, cert.signing_key.serialize_pem()).unwrap();
+        (cert_path, key_path)
+    }
+
+    #[test]
+    fn builds_config_from_self_signed_pem() {
+        let dir = tempfile::tempdir().unwrap();
+        let (cert, key) = self_signed(dir.path());
+        let config = server_config(&cert, &key).unwrap();
+        assert!(config.alpn_protocols.contains(&b"http/1.1".to_vec()));
+    }
+
+    #[test]
+    fn mismatched_or_missing_pem_is_an_error() {
+        let dir = tempfile::tempdir().unwrap();
+        let (cert, _) = self_signed(dir.path());
+        let other = tempfile::tempdir().unwrap();
+        let (_, other_key) = self_signed(other.path());
+        assert!(server_config(&cert, &other_key).is_err());
+        assert!(server_config(&dir.path().join("missing.pem"), &other_key).is_err());
+        assert!(server_config(&other_key, &other_key).is_err());
+    }
+}
@@ -1,5 +1,7 @@
 use serde::Serialize;
 
+pub(crate) use crate::view::item::EventItem;
+
 #[derive(Serialize)]
 pub struct SummaryResponse {
     pub sessions: usize,
@@ -31,6 +33,38 @@ pub struct SessionListItem {
     pub session_ids: Vec<String>,
 }
 
+#[derive(Serialize)]
+pub struct DiffSessionJson {
+    pub session_id: String,
+    pub server: String,
+    pub project: Option<String>,
+    pub edits: usize,
+    pub added: usize,
+    pub removed: usize,
+    pub files: Vec<DiffFileJson>,
+}
+
+#[derive(Serialize)]
+pub struct DiffFileJson {
+    pub path: String,
+    pub edits: usize,
+    pub added: usize,
+    pub removed: usize,
+    pub new_file: bool,
+    pub changes: Vec<DiffEditJson>,
+}
+
+#[derive(Serialize)]
+pub struct DiffEditJson {
+    pub id: String,
+    pub timestamp: String,
+    pub tool: String,
+    pub added: usize,
+    pub removed: usize,
+    /// Unified diff text; `None` when it is encrypted and no key is loaded.
+    pub diff: Option<String>,
+}
+
 #[derive(Serialize)]
 pub struct StatsResponse {
     pub counts: CountsJson,
@@ -108,25 +142,13 @@ impl TimelineDay {
     }
 }
 
+/// One page of `/api/events`; `total` counts every match before paging.
 #[derive(Serialize)]
-pub struct EventItem {
-    pub id: String,
-    pub timestamp: String,
-    pub session_id: String,
-    pub server: String,
-    pub tool: String,
-    pub risk: crate::models::Risk,
-    pub duration_us: u64,
-    pub is_error: bool,
-    pub project: Option<String>,
-    pub branch: Option<String>,
-    pub arg_display: String,
-    pub input_tokens: Option<u64>,
-    pub output_tokens: Option<u64>,
-    pub cache_read_tokens: Option<u64>,
-    pub cache_write_tokens: Option<u64>,
-    pub model: Option<String>,
-    pub error_message: Option<String>,
+pub struct EventsPage {
+    pub total: usize,
+    pub offset: usize,
+    pub limit: usize,
+    pub events: Vec<EventItem>,
 }
 
 #[derive(Serialize)]
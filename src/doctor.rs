@@ -16,6 +16,7 @@ pub fn run(ledger_path: &str) {
     check_claude_mcp(&mut pass, &mut fail);
     check_claude_hook(&mut pass, &mut fail);
     check_cursor_mcp(&mut pass, &mut fail);
+    check_cursor_hooks(&mut pass, &mut fail);
     check_cursor_db(&mut pass, &mut fail);
     check_mcp_session(&mut pass);
 
@@ -34,16 +35,29 @@ fn check_ledger(ledger_path: &str, pass: &mut u32, fail: &mut u32) {
     if path.exists() {
         let size = std::fs::metadata(path).map(|m| m.len()).unwrap_or(0);
         let display = format_size(size);
-        ok(&format!("ledger exists ({display})"), pass);
+        if ledger_writable(path) {
+            ok(&format!("ledger exists and is writable ({display})"), pass);
+        } else {
+            err(
+                &format!("ledger exists but is not writable ({display})"),
+                fail,
+            );
+            hint(&format!(
+                "check the permissions on {}",
+                short_path(ledger_path)
+            ));
+        }
         check_ledger_event_count(ledger_path);
     } else if let Some(parent) = path.parent() {
         if parent.exists() || std::fs::create_dir_all(parent).is_ok() {
             ok("ledger directory writable (no events yet)", pass);
         } else {
             err("ledger directory not writable", fail);
+            hint("set VIGILO_LEDGER to a path you can write to");
         }
     } else {
         err("ledger path invalid", fail);
+        hint("set VIGILO_LEDGER to a file path, e.g. ~/.vigilo/events.jsonl");
     }
 
     let (rotated, rotated_size) = count_rotated_files(ledger_path);
@@ -156,15 +170,36 @@ fn check_encryption_key(pass: &mut u32, fail: &mut u32) {
                     &format!("encryption key wrong size ({} bytes, need 32)", bytes.len()),
                     fail,
                 );
+                hint("generate a valid key with `vigilo generate-key`");
                 return;
             }
             Err(_) => {
                 err("encryption key is not valid base64", fail);
+                hint("generate a valid key with `vigilo generate-key`");
                 return;
             }
         }
     }
 
+    if std::env::var_os("VIGILO_ENCRYPTION_PASSWORD").is_some() {
+        let salt = crate::models::shorten_home(&crate::crypto::salt_file_path().to_string_lossy());
+        if crate::crypto::load_key_from_password().is_some() {
+            ok(
+                &format!(
+                    "encryption key derived from VIGILO_ENCRYPTION_PASSWORD (Argon2id, salt {salt})"
+                ),
+                pass,
+            );
+        } else {
+            err(
+                &format!("could not derive a key from VIGILO_ENCRYPTION_PASSWORD (salt {salt})"),
+                fail,
+            );
+            hint("check that the salt file is readable and holds base64 of at least 8 bytes");
+        }
+        return;
+    }
+
     // Check key file
     let key_path = crate::crypto::key_file_path();
     if key_path.exists() {
@@ -178,6 +213,7 @@ fn check_encryption_key(pass: &mut u32, fail: &mut u32) {
             );
         } else {
             err("encryption key file exists but contains invalid key", fail);
+            hint("restore the key from a backup, or move the file aside to start a new one");
         }
     } else {
         cprintln!(
@@ -203,6 +239,9 @@ fn check_config(pass: &mut u32, _fail: &mut u32) {
         ok(&format!("config loaded ({})", keys.join(", ")), pass);
 
         for key in config.keys() {
+            if key.starts_with("TIMEOUT_") {
+                continue;
+            }
             if !matches!(
                 key.as_str(),
                 "TAG"
@@ -211,6 +250,31 @@ fn check_config(pass: &mut u32, _fail: &mut u32) {
                     | "STORE_RESPONSE"
                     | "HOOK_STORE_RESPONSE"
                     | "LEDGER"
+                    | "APPROVE_EXEC"
+                    | "APPROVAL_TIMEOUT_SECS"
+                    | "SESSION_MERGE_GAP_SECS"
+                    | "SEARCH_RESULT_CAP_BYTES"
+                    | "SEARCH_CONCURRENCY"
+                    | "SEARCH_MAX_FILE_BYTES"
+                    | "CAPTURE_DIFF"
+                    | "TOOLS_ALLOW"
+                    | "TOOLS_DENY"
+                    | "DRY_RUN"
+                    | "MAX_OUTPUT_BYTES"
+                    | "COMPRESS_ROTATED"
+                    | "FSYNC"
+                    | "MAX_DIFF_BYTES"
+                    | "DANGER_PATTERNS"
+                    | "ENCRYPT_FIELDS"
+                    | "CURRENCY"
+                    | "USD_RATE"
+                    | "DAILY_BUDGET_USD"
+                    | "CURSOR_PROXY"
+                    | "CURSOR_CACHE_TTL_SECS"
+                    | "DASHBOARD_TOKEN"
+                    | "DASHBOARD_BIND"
+                    | "DASHBOARD_CERT"
+                    | "DASHBOARD_KEY"
             ) {
                 cprintln!("  {CYAN}i{RESET}  unknown config key: {key}");
             }
@@ -226,10 +290,11 @@ fn check_claude_mcp(pass: &mut u32, fail: &mut u32) {
             cprintln!("  {DIM}-{RESET}  ~/.claude.json not found");
         }
         Some(val) => {
-            if val["mcpServers"]["vigilo"].is_object() {
+            if mcp_registered(&val) {
                 ok("Claude Code MCP server registered", pass);
             } else {
                 err("vigilo not in ~/.claude.json mcpServers", fail);
+                hint("run `vigilo setup`");
             }
         }
     }
@@ -246,10 +311,8 @@ fn check_claude_hook(pass: &mut u32, fail: &mut u32) {
             if crate::setup::is_vigilo_hook_present(&val["hooks"]["PostToolUse"]) {
                 ok("Claude Code PostToolUse hook installed", pass);
             } else {
-                err(
-                    "vigilo hook not in ~/.claude/settings.json — run 'vigilo setup'",
-                    fail,
-                );
+                err("vigilo hook not in ~/.claude/settings.json", fail);
+                hint("run `vigilo setup`");
             }
         }
     }
@@ -263,15 +326,60 @@ fn check_cursor_mcp(pass: &mut u32, fail: &mut u32) {
             cprintln!("  {DIM}-{RESET}  ~/.cursor/mcp.json not found (optional)");
         }
         Some(val) => {
-            if val["mcpServers"]["vigilo"].is_object() {
+            if mcp_registered(&val) {
                 ok("Cursor MCP server registered", pass);
             } else {
                 err("vigilo not in ~/.cursor/mcp.json mcpServers", fail);
+                hint("run `vigilo setup`");
             }
         }
     }
 }
 
+fn check_cursor_hooks(pass: &mut u32, fail: &mut u32) {
+    let Some(val) = read_json(crate::models::home_dir().join(".cursor/hooks.json")) else {
+        cprintln!("  {DIM}-{RESET}  ~/.cursor/hooks.json not found (optional)");
+        return;
+    };
+    let missing = missing_cursor_hooks(&val);
+    if missing.is_empty() {
+        ok("Cursor hooks installed", pass);
+    } else {
+        err(
+            &format!("vigilo hook missing from Cursor {}", missing.join(", ")),
+            fail,
+        );
+        hint("run `vigilo setup`");
+    }
+}
+
+fn mcp_registered(config: &serde_json::Value) -> bool {
+    config["mcpServers"]["vigilo"].is_object()
+}
+
+fn missing_cursor_hooks(config: &serde_json::Value) -> Vec<&'static str> {
+    use crate::setup::{CURSOR_HOOK_TYPES, VIGILO_HOOK_COMMAND};
+    CURSOR_HOOK_TYPES
+        .iter()
+        .copied()
+        .filter(|hook_type| {
+            !config["hooks"][*hook_type]
+                .as_array()
+                .is_some_and(|entries| {
+                    entries
+                        .iter()
+                        .any(|h| h["command"].as_str() == Some(VIGILO_HOOK_COMMAND))
+                })
+        })
+        .collect()
+}
+
+/// Opens for append without writing, so a read-only file or directory shows up
+/// here rather than as a silently dropped event later.
+fn ledger_writable(path: &Path) -> bool {
+    std::fs::OpenOptions::new().append(true).open(path).is_ok()
+}
+
 fn check_cursor_db(pass: &mut u32, _fail: &mut u32) {
     match crate::cursor::resolve_db_path() {
         Ok(path) => ok(&format!("cursor DB found ({})", short_path(&path)), pass),
@@ -319,3 +427,53 @@ fn err(msg: &str, fail: &mut u32) {
     cprintln!("  {RED}✗{RESET}  {msg}");
     *fail += 1;
 }
+
+fn hint(msg: &str) {
+    cprintln!("  {DIM}   → {msg}{RESET}");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ledger_writable_detects_unopenable_path() {
+        let dir = tempfile::tempdir().unwrap();
+        let ledger = dir.path().join("events.jsonl");
+        std::fs::write(&ledger, "").unwrap();
+        assert!(ledger_writable(&ledger));
+        assert!(!ledger_writable(&dir.path().join("missing.jsonl")));
+        assert!(!ledger_writable(dir.path()));
+    }
+
+    #[test]
+    fn mcp_registered_looks_for_vigilo_server() {
+        let config = serde_json::json!({ "mcpServers": { "vigilo": { "command": "vigilo" } } });
+        assert!(mcp_registered(&config));
+        let other = serde_json::json!({ "mcpServers": { "github": {} } });
+        assert!(!mcp_registered(&other));
+        assert!(!mcp_registered(&serde_json::json!({})));
+    }
+
+    #[test]
+    fn missing_cursor_hooks_lists_unregistered_events() {
+        let partial = serde_json::json!({
+            "version": 1,
+            "hooks": {
+                "beforeShellExecution": [{ "command": "vigilo hook" }],
+                "afterFileEdit": [{ "command": "other-tool" }, { "command": "vigilo hook" }],
+                "beforeReadFile": [{ "command": "other-tool" }],
+            }
+        });
+        assert_eq!(
+            missing_cursor_hooks(&partial),
+            ["beforeReadFile", "beforeMCPExecution"]
+        );
+
+        let mut full = serde_json::json!({ "hooks": {} });
+        for hook_type in crate::setup::CURSOR_HOOK_TYPES {
+            full["hooks"][*hook_type] = serde_json::json!([{ "command": "vigilo hook" }]);
+        }
+        assert!(missing_cursor_hooks(&full).is_empty());
+    }
+}
@@ -13,16 +13,54 @@ use uuid::Uuid;
 enum HookClient {
     Cursor,
     ClaudeCode,
+    Gemini,
 }
 
 fn detect_client(payload: &serde_json::Value) -> HookClient {
     if payload.get("conversation_id").is_some() {
         return HookClient::Cursor;
     }
+    // Gemini CLI's hook events are named `BeforeTool` / `AfterTool` / …,
+    // where Claude Code says `PreToolUse` / `PostToolUse`.
+    if matches!(
+        payload["hook_event_name"].as_str(),
+        Some(
+            "BeforeTool"
+                | "AfterTool"
+                | "BeforeModel"
+                | "AfterModel"
+                | "BeforeAgent"
+                | "AfterAgent"
+                | "BeforeToolSelection"
+        )
+    ) {
+        return HookClient::Gemini;
+    }
     HookClient::ClaudeCode
 }
 
-pub async fn run(ledger_path: &str) -> Result<()> {
+fn parse_client(name: &str) -> Result<HookClient> {
+    match name {
+        "cursor" => Ok(HookClient::Cursor),
+        "claude" | "claude-code" => Ok(HookClient::ClaudeCode),
+        "gemini" => Ok(HookClient::Gemini),
+        other => anyhow::bail!("unknown hook client '{other}' (expected cursor | claude | gemini)"),
+    }
+}
+
+fn resolve_client(payload: &serde_json::Value, forced: Option<&str>) -> Result<HookClient> {
+    match forced {
+        Some(name) => parse_client(name),
+        None => Ok(detect_client(payload)),
+    }
+}
+
+type HookEncryption<'a> = (
+    Option<&'a crate::crypto::EncryptionKey>,
+    crate::crypto::EncryptFields,
+);
+
+pub async fn run(ledger_path: &str, forced_client: Option<&str>) -> Result<()> {
     use std::io::Read;
     let mut input = String::new();
     std::io::stdin().read_to_string(&mut input)?;
@@ -31,53 +69,118 @@ pub async fn run(ledger_path: &str) -> Result<()> {
         return Ok(());
     };
 
+    let client = resolve_client(&payload, forced_client)?;
     let encryption_key = crate::crypto::load_or_create_key();
+    let config = crate::models::load_config();
+    let fields = crate::crypto::EncryptFields::from_config(&config);
+    let encryption = (encryption_key.as_ref(), fields);
+    let danger = crate::danger::DangerPatterns::from_config(&config);
 
-    match detect_client(&payload) {
-        HookClient::Cursor => {
-            handle_cursor_hook(&payload, ledger_path, encryption_key.as_ref()).await
-        }
+    match client {
+        HookClient::Cursor => handle_cursor_hook(&payload, ledger_path, encryption, &danger).await,
         HookClient::ClaudeCode => {
-            handle_claude_hook(&payload, ledger_path, encryption_key.as_ref()).await
+            handle_tool_hook(&CLAUDE_HOOK, &payload, ledger_path, encryption, &danger).await
+        }
+        HookClient::Gemini => {
+            handle_tool_hook(&GEMINI_HOOK, &payload, ledger_path, encryption, &danger).await
         }
     }
 }
 
-async fn handle_claude_hook(
+/// What differs between the Claude Code and Gemini CLI hooks; the pairing of
+/// before/after calls and the event built from a call are shared.
+struct ToolHook {
+    server: &'static str,
+    /// The hook event that carries the outcome, when other events are dropped.
+    /// A Gemini call configured for both the before and after hooks lands in
+    /// the ledger once, from this one; Claude Code payloads are all recorded.
+    after_event: Option<&'static str>,
+    /// Prefixes of vigilo's own MCP tools, already logged by the server.
+    own_prefixes: &'static [&'static str],
+    parse_tool: fn(&serde_json::Value) -> (String, serde_json::Value),
+    outcome: fn(&serde_json::Value) -> Outcome,
+    /// Whether `transcript_path` is a Claude Code transcript to read tokens from.
+    reads_transcript: bool,
+}
+
+const CLAUDE_HOOK: ToolHook = ToolHook {
+    server: "claude-code",
+    after_event: None,
+    own_prefixes: &["mcp__vigilo__"],
+    parse_tool: parse_claude_tool,
+    outcome: build_claude_outcome,
+    reads_transcript: true,
+};
+
+const GEMINI_HOOK: ToolHook = ToolHook {
+    server: "gemini",
+    after_event: Some("AfterTool"),
+    own_prefixes: &["vigilo__", "mcp_vigilo_"],
+    parse_tool: parse_gemini_tool,
+    outcome: build_gemini_outcome,
+    reads_transcript: false,
+};
+
+async fn handle_tool_hook(
+    hook: &ToolHook,
     payload: &serde_json::Value,
     ledger_path: &str,
-    encryption_key: Option<&crate::crypto::EncryptionKey>,
+    (encryption_key, fields): HookEncryption<'_>,
+    danger: &crate::danger::DangerPatterns,
 ) -> Result<()> {
-    let (tool_name, arguments) = parse_claude_tool(payload);
-    if tool_name.starts_with("mcp__vigilo__") {
+    if !records_event(hook, payload) {
+        return Ok(());
+    }
+    let (tool_name, arguments) = (hook.parse_tool)(payload);
+    if hook.own_prefixes.iter().any(|p| tool_name.starts_with(p)) {
         return Ok(());
     }
 
-    let outcome = build_claude_outcome(&payload["tool_response"]);
+    let outcome = (hook.outcome)(&payload["tool_response"]);
     let risk = Risk::classify(&tool_name);
-    let session_id = claude_session_id(payload);
+    let session_id = hook_session_id(payload);
     let diff = compute_edit_diff(&tool_name, &arguments);
-
-    let encrypted =
-        match crate::crypto::encrypt_for_ledger(encryption_key, &arguments, &outcome, &diff) {
-            Ok(v) => v,
-            Err(e) => {
-                eprintln!("[vigilo] encryption failed, skipping hook event: {e}");
-                return Ok(());
-            }
-        };
+    let flagged = danger.flag_call(&tool_name, &arguments);
+
+    let encrypted = match crate::crypto::encrypt_for_ledger(
+        encryption_key,
+        fields,
+        &arguments,
+        &outcome,
+        &diff,
+    ) {
+        Ok(v) => v,
+        Err(e) => {
+            eprintln!("[vigilo] encryption failed, skipping hook event: {e}");
+            return Ok(());
+        }
+    };
 
     let cwd = payload["cwd"].as_str().unwrap_or(".");
     let git_dir = resolve_git_dir(&tool_name, &arguments, cwd);
     let project = build_project(&git_dir).await;
 
-    let event = build_claude_event(payload, tool_name, encrypted, risk, session_id, project);
+    let mut event = build_hook_event(
+        hook, payload, tool_name, encrypted, risk, session_id, project,
+    );
+    event.cwd = hook_cwd(cwd, (encryption_key, fields));
+    event.flagged = flagged;
 
     write_hook_event(&event, ledger_path);
     Ok(())
 }
 
-fn build_claude_event(
+/// A payload without an event name is taken to be the after hook.
+fn records_event(hook: &ToolHook, payload: &serde_json::Value) -> bool {
+    hook.after_event.is_none_or(|after| {
+        payload["hook_event_name"]
+            .as_str()
+            .is_none_or(|name| name == after)
+    })
+}
+
+fn build_hook_event(
+    hook: &ToolHook,
     payload: &serde_json::Value,
     tool_name: String,
     encrypted: (serde_json::Value, Outcome, Option<String>),
@@ -93,6 +196,7 @@ fn build_claude_event(
     let tool_use_id_str = payload["tool_use_id"].as_str();
     let tmeta = payload["transcript_path"]
         .as_str()
+        .filter(|_| hook.reads_transcript)
         .map(|p| read_transcript_meta(p, tool_use_id_str))
         .unwrap_or_default();
 
@@ -100,7 +204,7 @@ fn build_claude_event(
         id: Uuid::new_v4(),
         timestamp: Utc::now().to_rfc3339(),
         session_id,
-        server: "claude-code".to_string(),
+        server: hook.server.to_string(),
         tool: tool_name,
         arguments,
         outcome,
@@ -140,13 +244,13 @@ fn parse_claude_tool(payload: &serde_json::Value) -> (String, serde_json::Value)
     (tool_name, arguments)
 }
 
-fn claude_session_id(payload: &serde_json::Value) -> Uuid {
+fn hook_session_id(payload: &serde_json::Value) -> Uuid {
     if let Some(id) = read_mcp_session_id() {
         return id;
     }
     // Prefer session_id over transcript_path: transcript_path changes on
     // context compression (new transcript file), while session_id stays
-    // stable for the entire Claude Code conversation.
+    // stable for the entire conversation.
     payload["session_id"]
         .as_str()
         .or_else(|| payload["transcript_path"].as_str())
@@ -182,10 +286,58 @@ fn build_claude_outcome(response: &serde_json::Value) -> Outcome {
     }
 }
 
+/// Maps Gemini CLI's built-in tool names onto the Claude Code names the views
+/// and `Risk::classify` already know. Anything else (MCP tools) passes through.
+fn parse_gemini_tool(payload: &serde_json::Value) -> (String, serde_json::Value) {
+    let raw = payload["tool_name"].as_str().unwrap_or("unknown");
+    let canonical = match raw {
+        "run_shell_command" => "Bash",
+        "read_file" | "read_many_files" => "Read",
+        "write_file" => "Write",
+        "replace" => "Edit",
+        "list_directory" | "glob" => "Glob",
+        "search_file_content" => "Grep",
+        "web_fetch" => "WebFetch",
+        "google_web_search" => "WebSearch",
+        other => other,
+    };
+    let mut arguments = payload["tool_input"].clone();
+    if let Some(obj) = arguments.as_object_mut() {
+        obj.remove("content");
+        if !obj.contains_key("file_path") {
+            if let Some(path) = obj.remove("absolute_path") {
+                obj.insert("file_path".to_string(), path);
+            }
+        }
+    }
+    (canonical.to_string(), arguments)
+}
+
+/// Gemini reports a failed tool as a `tool_response.error` string or object.
+fn build_gemini_outcome(response: &serde_json::Value) -> Outcome {
+    let error = &response["error"];
+    if !error.is_null() {
+        let message = error
+            .as_str()
+            .or_else(|| error["message"].as_str())
+            .unwrap_or("error")
+            .to_string();
+        return Outcome::Err { code: -1, message };
+    }
+    Outcome::Ok {
+        result: if hook_store_response() {
+            response.clone()
+        } else {
+            serde_json::Value::Null
+        },
+    }
+}
+
 async fn handle_cursor_hook(
     payload: &serde_json::Value,
     ledger_path: &str,
-    encryption_key: Option<&crate::crypto::EncryptionKey>,
+    (encryption_key, fields): HookEncryption<'_>,
+    danger: &crate::danger::DangerPatterns,
 ) -> Result<()> {
     let hook_event = payload["hook_event_name"].as_str().unwrap_or("PostToolUse");
     if matches!(hook_event, "stop" | "beforeSubmitPrompt") {
@@ -205,20 +357,26 @@ async fn handle_cursor_hook(
         return Ok(());
     }
 
+    let flagged = danger.flag_call(&tool_name, &arguments);
     let outcome: Outcome = Default::default();
-    let (enc_arguments, _, enc_diff) =
-        match crate::crypto::encrypt_for_ledger(encryption_key, &arguments, &outcome, &diff) {
-            Ok(v) => v,
-            Err(e) => {
-                eprintln!("[vigilo] encryption failed, skipping hook event: {e}");
-                return Ok(());
-            }
-        };
+    let (enc_arguments, _, enc_diff) = match crate::crypto::encrypt_for_ledger(
+        encryption_key,
+        fields,
+        &arguments,
+        &outcome,
+        &diff,
+    ) {
+        Ok(v) => v,
+        Err(e) => {
+            eprintln!("[vigilo] encryption failed, skipping hook event: {e}");
+            return Ok(());
+        }
+    };
 
     let git_dir = resolve_git_dir(&tool_name, &arguments, &cwd);
     let project = build_project(&git_dir).await;
 
-    let event = build_cursor_event(
+    let mut event = build_cursor_event(
         payload,
         tool_name,
         enc_arguments,
@@ -227,6 +385,8 @@ async fn handle_cursor_hook(
         session_id,
         project,
     );
+    event.cwd = hook_cwd(&cwd, (encryption_key, fields));
+    event.flagged = flagged;
 
     write_hook_event(&event, ledger_path);
     Ok(())
@@ -279,6 +439,14 @@ fn build_cursor_event(
     }
 }
 
+/// The payload's working directory, encrypted like the arguments. `.` means
+/// the client sent none, and a cwd that fails to encrypt is dropped rather
+/// than stored in the clear.
+fn hook_cwd(cwd: &str, (encryption_key, fields): HookEncryption<'_>) -> Option<String> {
+    let cwd = Some(cwd.to_string()).filter(|c| c != ".");
+    crate::crypto::encrypt_cwd(encryption_key, fields, cwd).unwrap_or_default()
+}
+
 fn cursor_cwd(payload: &serde_json::Value) -> String {
     payload["cwd"]
         .as_str()
@@ -335,6 +503,7 @@ fn parse_cursor_file_edit(
 ) -> (String, serde_json::Value, Risk, Option<String>) {
     let file_path = payload["file_path"].as_str().unwrap_or("");
     let diff = payload["edits"].as_array().and_then(|edits| {
+        let max_bytes = crate::hook_helpers::max_diff_bytes();
         let mut out = String::new();
         for edit in edits {
             let old = edit
@@ -345,7 +514,7 @@ fn parse_cursor_file_edit(
                 .get("new_string")
                 .and_then(|v| v.as_str())
                 .unwrap_or("");
-            if let Some(d) = crate::hook_helpers::compute_unified_diff(old, new) {
+            if let Some(d) = crate::hook_helpers::compute_unified_diff(old, new, max_bytes) {
                 out.push_str(&d);
             }
         }
@@ -366,7 +535,11 @@ fn parse_cursor_post_tool_use(
     let tool = raw_tool.strip_prefix("MCP:").unwrap_or(raw_tool);
     let canonical = match tool {
         "Shell" => "Bash",
-        "Write" => "Edit",
+        "Write" | "SearchReplace" | "StrReplace" | "EditFile" => "Edit",
+        "ReadFile" => "Read",
+        "ListDir" | "GlobFileSearch" => "Glob",
+        "Codebase" | "CodebaseSearch" | "GrepSearch" => "Grep",
+        "Fetch" => "WebFetch",
         other => other,
     };
     let mut args = payload["tool_input"].clone();
@@ -392,7 +565,8 @@ fn resolve_cursor_model(payload: &serde_json::Value, raw_conv_id: &str) -> Optio
         .map(|s| normalize_cursor_model(&s))
 }
 
-fn read_mcp_session_id() -> Option<Uuid> {
+/// The running MCP server's session, if its session file names a live process.
+pub(crate) fn read_mcp_session_id() -> Option<Uuid> {
     let content = std::fs::read_to_string(crate::models::mcp_session_path()).ok()?;
     let mut lines = content.lines();
     let uuid_str = lines.next()?;
@@ -417,12 +591,7 @@ fn is_process_alive(pid: u32) -> bool {
 }
 
 fn hook_store_response() -> bool {
-    let config = crate::models::load_config();
-    let val = std::env::var("VIGILO_HOOK_STORE_RESPONSE")
-        .ok()
-        .or_else(|| config.get("HOOK_STORE_RESPONSE").cloned())
-        .unwrap_or_default();
-    matches!(val.to_lowercase().as_str(), "true" | "1" | "yes")
+    crate::models::config_flag(&crate::models::load_config(), "HOOK_STORE_RESPONSE")
 }
 
 fn read_cursor_model_from_db(conversation_id: &str) -> Option<String> {
@@ -498,6 +667,142 @@ mod tests {
         assert!(matches!(detect_client(&payload), HookClient::ClaudeCode));
     }
 
+    #[test]
+    fn resolve_client_override_bypasses_detection() {
+        let cursor_payload =
+            serde_json::json!({"conversation_id": "abc", "hook_event_name": "afterFileEdit"});
+        assert!(matches!(
+            resolve_client(&cursor_payload, Some("claude")).unwrap(),
+            HookClient::ClaudeCode
+        ));
+
+        let claude_payload = serde_json::json!({"session_id": "abc", "tool_name": "Read"});
+        assert!(matches!(
+            resolve_client(&claude_payload, Some("cursor")).unwrap(),
+            HookClient::Cursor
+        ));
+        assert!(matches!(
+            resolve_client(&claude_payload, None).unwrap(),
+            HookClient::ClaudeCode
+        ));
+    }
+
+    #[test]
+    fn detect_client_gemini_from_hook_event_name() {
+        let payload = serde_json::json!({
+            "session_id": "g-1",
+            "hook_event_name": "AfterTool",
+            "tool_name": "read_file",
+        });
+        assert!(matches!(detect_client(&payload), HookClient::Gemini));
+
+        let claude = serde_json::json!({"session_id": "c-1", "hook_event_name": "PostToolUse"});
+        assert!(matches!(detect_client(&claude), HookClient::ClaudeCode));
+        assert!(matches!(
+            resolve_client(&claude, Some("gemini")).unwrap(),
+            HookClient::Gemini
+        ));
+    }
+
+    #[test]
+    fn parse_gemini_tool_event_maps_to_canonical_names() {
+        let payload = serde_json::json!({
+            "session_id": "g-1",
+            "hook_event_name": "AfterTool",
+            "tool_name": "replace",
+            "tool_input": {
+                "file_path": "/tmp/a.rs",
+                "old_string": "old",
+                "new_string": "new",
+            },
+            "tool_response": { "llmContent": "ok", "returnDisplay": "ok" },
+        });
+        let (tool, args) = parse_gemini_tool(&payload);
+        assert_eq!(tool, "Edit");
+        assert_eq!(Risk::classify(&tool), Risk::Write);
+        assert!(compute_edit_diff(&tool, &args).is_some());
+        assert!(matches!(
+            build_gemini_outcome(&payload["tool_response"]),
+            Outcome::Ok { .. }
+        ));
+
+        let read = serde_json::json!({
+            "tool_name": "read_file",
+            "tool_input": { "absolute_path": "/tmp/a.rs" },
+        });
+        let (tool, args) = parse_gemini_tool(&read);
+        assert_eq!((tool.as_str(), Risk::classify(&tool)), ("Read", Risk::Read));
+        assert_eq!(args["file_path"], "/tmp/a.rs");
+
+        let shell = serde_json::json!({
+            "tool_name": "run_shell_command",
+            "tool_input": { "command": "ls" },
+        });
+        assert_eq!(parse_gemini_tool(&shell).0, "Bash");
+
+        let write = serde_json::json!({
+            "tool_name": "write_file",
+            "tool_input": { "file_path": "/tmp/a.rs", "content": "secret" },
+        });
+        let (tool, args) = parse_gemini_tool(&write);
+        assert_eq!(tool, "Write");
+        assert!(args.get("content").is_none());
+    }
+
+    #[test]
+    fn only_gemini_drops_events_other_than_after_tool() {
+        let named = |name: &str| serde_json::json!({ "hook_event_name": name });
+        assert!(records_event(&GEMINI_HOOK, &named("AfterTool")));
+        assert!(!records_event(&GEMINI_HOOK, &named("BeforeTool")));
+        assert!(records_event(&GEMINI_HOOK, &serde_json::json!({})));
+        assert!(records_event(&CLAUDE_HOOK, &named("PostToolUse")));
+        assert!(records_event(&CLAUDE_HOOK, &named("PreToolUse")));
+        assert!(records_event(&CLAUDE_HOOK, &serde_json::json!({})));
+    }
+
+    #[test]
+    fn hook_event_is_labelled_by_client() {
+        let payload = serde_json::json!({ "tool_use_id": "t-1" });
+        let event = |hook| {
+            build_hook_event(
+                hook,
+                &payload,
+                "Read".to_string(),
+                (
+                    serde_json::json!({}),
+                    Outcome::Ok {
+                        result: serde_json::Value::Null,
+                    },
+                    None,
+                ),
+                Risk::Read,
+                Uuid::new_v4(),
+                Default::default(),
+            )
+        };
+        assert_eq!(event(&GEMINI_HOOK).server, "gemini");
+        assert_eq!(event(&CLAUDE_HOOK).server, "claude-code");
+        assert_eq!(
+            event(&GEMINI_HOOK).hook_context.tool_use_id.as_deref(),
+            Some("t-1")
+        );
+    }
+
+    #[test]
+    fn build_gemini_outcome_error_object() {
+        let response = serde_json::json!({ "error": { "message": "no such file" } });
+        match build_gemini_outcome(&response) {
+            Outcome::Err { message, .. } => assert_eq!(message, "no such file"),
+            _ => panic!("expected Err"),
+        }
+    }
+
+    #[test]
+    fn resolve_client_rejects_unknown_override() {
+        let payload = serde_json::json!({});
+        assert!(resolve_client(&payload, Some("vscode")).is_err());
+    }
+
     #[test]
     fn parse_claude_tool_extracts_name_and_args() {
         let payload = serde_json::json!({
@@ -532,30 +837,30 @@ mod tests {
     }
 
     #[test]
-    fn claude_session_id_from_transcript_path() {
+    fn hook_session_id_from_transcript_path() {
         let payload = serde_json::json!({ "transcript_path": "transcripts/session.jsonl" });
-        let id1 = claude_session_id(&payload);
-        let id2 = claude_session_id(&payload);
+        let id1 = hook_session_id(&payload);
+        let id2 = hook_session_id(&payload);
         assert_eq!(id1, id2);
     }
 
     #[test]
-    fn claude_session_id_from_session_id_field() {
+    fn hook_session_id_from_session_id_field() {
         let payload = serde_json::json!({ "session_id": "my-session" });
-        let id = claude_session_id(&payload);
+        let id = hook_session_id(&payload);
         assert_ne!(id, Uuid::nil());
     }
 
     #[test]
-    fn claude_session_id_prefers_session_id_over_transcript_path() {
+    fn hook_session_id_prefers_session_id_over_transcript_path() {
         let payload = serde_json::json!({
             "session_id": "stable-session",
             "transcript_path": "transcripts/file1.jsonl"
         });
-        let id_with_both = claude_session_id(&payload);
+        let id_with_both = hook_session_id(&payload);
 
         let payload_session_only = serde_json::json!({ "session_id": "stable-session" });
-        let id_session_only = claude_session_id(&payload_session_only);
+        let id_session_only = hook_session_id(&payload_session_only);
 
         // Should use session_id, not transcript_path
         assert_eq!(id_with_both, id_session_only);
@@ -565,7 +870,7 @@ mod tests {
             "session_id": "stable-session",
             "transcript_path": "transcripts/file2.jsonl"
         });
-        let id_diff_transcript = claude_session_id(&payload_diff_transcript);
+        let id_diff_transcript = hook_session_id(&payload_diff_transcript);
         assert_eq!(id_with_both, id_diff_transcript);
     }
 
@@ -833,6 +1138,29 @@ mod tests {
         }
     }
 
+    #[test]
+    fn parse_cursor_post_tool_use_maps_builtin_names() {
+        let cases = [
+            ("ReadFile", "Read", Risk::Read),
+            ("ListDir", "Glob", Risk::Read),
+            ("GlobFileSearch", "Glob", Risk::Read),
+            ("Codebase", "Grep", Risk::Read),
+            ("CodebaseSearch", "Grep", Risk::Read),
+            ("Grep", "Grep", Risk::Read),
+            ("GrepSearch", "Grep", Risk::Read),
+            ("SearchReplace", "Edit", Risk::Write),
+            ("StrReplace", "Edit", Risk::Write),
+            ("EditFile", "Edit", Risk::Write),
+            ("Fetch", "WebFetch", Risk::Read),
+            ("MCP:ReadFile", "Read", Risk::Read),
+        ];
+        for (raw, canonical, risk) in cases {
+            let payload = serde_json::json!({ "tool_name": raw, "tool_input": {} });
+            let (tool, _, r, _) = parse_cursor_post_tool_use(&payload);
+            assert_eq!((tool.as_str(), r), (canonical, risk), "{raw}");
+        }
+    }
+
     #[test]
     fn parse_cursor_post_tool_use_write_becomes_edit() {
         let payload = serde_json::json!({
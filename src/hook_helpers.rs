@@ -12,7 +12,24 @@ pub fn stable_uuid(s: &str) -> Uuid {
     Uuid::new_v5(&SESSION_NAMESPACE, s.as_bytes())
 }
 
+/// `VIGILO_PROJECT_ROOT` pins the project for a session; otherwise the git dir is
+/// inferred from the tool's path, falling back to `cwd`.
 pub fn resolve_git_dir(tool: &str, args: &serde_json::Value, cwd: &str) -> String {
+    let pinned = std::env::var("VIGILO_PROJECT_ROOT")
+        .ok()
+        .filter(|p| !p.trim().is_empty());
+    resolve_git_dir_with(tool, args, cwd, pinned.as_deref())
+}
+
+fn resolve_git_dir_with(
+    tool: &str,
+    args: &serde_json::Value,
+    cwd: &str,
+    pinned: Option<&str>,
+) -> String {
+    if let Some(root) = pinned {
+        return root.to_string();
+    }
     let path_str = match tool {
         "Read" | "Edit" | "Write" | "MultiEdit" | "NotebookEdit" => {
             args.get("file_path").and_then(|v| v.as_str())
@@ -40,11 +57,23 @@ pub fn resolve_git_dir(tool: &str, args: &serde_json::Value, cwd: &str) -> Strin
     }
 }
 
-const MAX_DIFF_BYTES: usize = 10_000;
+const DEFAULT_MAX_DIFF_BYTES: usize = 10_000;
 const TRANSCRIPT_USAGE_TAIL: u64 = 64 * 1024;
 const TRANSCRIPT_DURATION_TAIL: u64 = 512 * 1024;
 
-pub fn compute_unified_diff(old: &str, new: &str) -> Option<String> {
+/// Size cap for stored diffs: `MAX_DIFF_BYTES`, defaulting to 10,000 bytes.
+pub fn max_diff_bytes() -> usize {
+    max_diff_bytes_from(&crate::models::load_config())
+}
+
+pub(crate) fn max_diff_bytes_from(config: &std::collections::HashMap<String, String>) -> usize {
+    crate::models::config_setting(config, "MAX_DIFF_BYTES")
+        .and_then(|v| v.trim().parse().ok())
+        .unwrap_or(DEFAULT_MAX_DIFF_BYTES)
+}
+
+/// Unified diff of `old` → `new`, cut to `max_bytes` (on a char boundary).
+pub fn compute_unified_diff(old: &str, new: &str, max_bytes: usize) -> Option<String> {
     use similar::{ChangeTag, TextDiff};
 
     let diff = TextDiff::from_lines(old, new);
@@ -63,8 +92,12 @@ pub fn compute_unified_diff(old: &str, new: &str) -> Option<String> {
         }
         out.push('\n');
     }
-    if out.len() > MAX_DIFF_BYTES {
-        out.truncate(MAX_DIFF_BYTES);
+    if out.len() > max_bytes {
+        let cut = (0..=max_bytes)
+            .rev()
+            .find(|&i| out.is_char_boundary(i))
+            .unwrap_or(0);
+        out.truncate(cut);
         out.push_str("... (truncated)\n");
     }
     if out.trim().is_empty() {
@@ -80,7 +113,7 @@ pub fn compute_edit_diff(tool: &str, args: &serde_json::Value) -> Option<String>
     }
     let old = args.get("old_string").and_then(|v| v.as_str())?;
     let new = args.get("new_string").and_then(|v| v.as_str())?;
-    compute_unified_diff(old, new)
+    compute_unified_diff(old, new, max_diff_bytes())
 }
 
 pub fn extract_error_message(response: &serde_json::Value) -> String {
@@ -347,6 +380,7 @@ fn scan_for_duration(path: &str, id: &str) -> Option<u64> {
         let Some(ts) = parse_timestamp_micros(&v) else {
             continue;
         };
+        #[allow(clippy::collapsible_match)]
         match v["type"].as_str() {
             Some("assistant") => {
                 if has_tool_use_id(&v["message"]["content"], id) {
@@ -429,6 +463,20 @@ mod tests {
         assert_eq!(dir, tmp.path().to_str().unwrap());
     }
 
+    #[test]
+    fn resolve_git_dir_prefers_pinned_project_root() {
+        let tmp = tempfile::tempdir().unwrap();
+        let file = tmp.path().join("scratch.txt");
+        std::fs::write(&file, "").unwrap();
+        let args = serde_json::json!({ "file_path": file.to_str().unwrap() });
+
+        let pinned = resolve_git_dir_with("Write", &args, "/fallback", Some("/work/repo"));
+        assert_eq!(pinned, "/work/repo");
+
+        let inferred = resolve_git_dir_with("Write", &args, "/fallback", None);
+        assert_eq!(inferred, tmp.path().to_str().unwrap());
+    }
+
     #[test]
     fn resolve_git_dir_from_path_for_grep() {
         let tmp = tempfile::tempdir().unwrap();
@@ -855,7 +903,7 @@ mod tests {
 
     #[test]
     fn compute_unified_diff_returns_diff_for_changes() {
-        let diff = compute_unified_diff("hello\n", "world\n");
+        let diff = compute_unified_diff("hello\n", "world\n", DEFAULT_MAX_DIFF_BYTES);
         assert!(diff.is_some());
         let d = diff.unwrap();
         assert!(d.contains("-hello"));
@@ -864,12 +912,35 @@ mod tests {
 
     #[test]
     fn compute_unified_diff_returns_none_for_identical() {
-        assert!(compute_unified_diff("same\n", "same\n").is_none());
+        assert!(compute_unified_diff("same\n", "same\n", DEFAULT_MAX_DIFF_BYTES).is_none());
+    }
+
+    #[test]
+    fn small_diff_limit_truncates_what_the_default_keeps() {
+        let old = "a\n".repeat(50);
+        let new = "b\n".repeat(50);
+        let full = compute_unified_diff(&old, &new, DEFAULT_MAX_DIFF_BYTES).unwrap();
+        assert!(!full.contains("(truncated)"));
+
+        let cut = compute_unified_diff(&old, &new, 40).unwrap();
+        assert!(cut.ends_with("... (truncated)\n"));
+        assert!(cut.len() < full.len());
+
+        let wide = compute_unified_diff("", &"é".repeat(40), 11).unwrap();
+        assert!(wide.starts_with("+é"));
+
+        let config =
+            std::collections::HashMap::from([("MAX_DIFF_BYTES".to_string(), "2048".to_string())]);
+        assert_eq!(max_diff_bytes_from(&config), 2048);
+        assert_eq!(
+            max_diff_bytes_from(&Default::default()),
+            DEFAULT_MAX_DIFF_BYTES
+        );
     }
 
     #[test]
     fn compute_unified_diff_handles_empty_strings() {
-        let diff = compute_unified_diff("", "new content\n");
+        let diff = compute_unified_diff("", "new content\n", DEFAULT_MAX_DIFF_BYTES);
         assert!(diff.is_some());
         assert!(diff.unwrap().contains("+new content"));
     }
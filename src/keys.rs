@@ -0,0 +1,329 @@
+use anyhow::{bail, Context, Result};
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+
+use crate::{crypto, view::data};
+
+/// Writes every event from the active and rotated ledger files to `out` as
+/// plaintext JSONL. Fails rather than emit a partial copy if any encrypted
+/// field doesn't open with the current key.
+pub fn decrypt(ledger_path: &str, out: &str) -> Result<()> {
+    let key = crypto::load_key()
+        .context("no encryption key found (VIGILO_ENCRYPTION_KEY or ~/.vigilo/encryption.key)")?;
+    let mut writer = std::io::BufWriter::new(create_private(out)?);
+    let mut total = 0;
+    for path in data::all_ledger_files(ledger_path) {
+        let reader = match data::open_ledger_reader(&path) {
+            Ok(r) => r,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => continue,
+            Err(e) => return Err(e).with_context(|| format!("reading {}", path.display())),
+        };
+        total += decrypt_lines(&key, BufReader::new(reader), &mut writer)
+            .with_context(|| path.display().to_string())?;
+    }
+    writer.flush()?;
+    println!("decrypted {total} events → {out}");
+    Ok(())
+}
+
+/// Lines that aren't JSON (a half-written tail) are dropped, as the views do.
+fn decrypt_lines(
+    key: &crypto::EncryptionKey,
+    reader: impl BufRead,
+    writer: &mut impl Write,
+) -> Result<usize> {
+    let mut count = 0;
+    for (i, line) in reader.lines().enumerate() {
+        let line = line?;
+        let Ok(mut event) = serde_json::from_str::<serde_json::Value>(&line) else {
+            continue;
+        };
+        if crypto::decrypt_ledger_line(key, &mut event).is_none() {
+            bail!(
+                "line {}: encrypted field does not open with this key",
+                i + 1
+            );
+        }
+        serde_json::to_writer(&mut *writer, &event)?;
+        writeln!(writer)?;
+        count += 1;
+    }
+    Ok(count)
+}
+
+/// Re-encrypts every ledger file, active and rotated, under a new key: the one
+/// in `VIGILO_ENCRYPTION_KEY` if it differs from the old key, otherwise a fresh
+/// key saved to the key file. Each file is rewritten to a sibling temp file;
+/// nothing is swapped in until every line of every file has decrypted. The
+/// ledger lock is held throughout, so appends wait and land in the new file.
+pub fn rekey(ledger_path: &str, old_key_b64: &str) -> Result<()> {
+    let old = crypto::key_from_b64(old_key_b64)
+        .context("--old-key must be a base64-encoded 32-byte key")?;
+    if std::env::var_os("VIGILO_ENCRYPTION_PASSWORD").is_some() {
+        bail!("VIGILO_ENCRYPTION_PASSWORD is set and would override the new key — unset it and use --old-key with the derived key");
+    }
+    if let Some(session) = crate::hook::read_mcp_session_id() {
+        bail!(
+            "the vigilo MCP server is running (session {}) and would keep encrypting with the old key — stop it first",
+            crate::view::fmt::short_id(&session.to_string())
+        );
+    }
+    let env_key = crypto::load_key_from_env().filter(|k| k.as_bytes() != old.as_bytes());
+    let new_from_env = env_key.is_some();
+    let new = env_key.unwrap_or_else(crypto::generate_key);
+
+    let keys = Rekey {
+        old: &old,
+        new: &new,
+        fields: crypto::EncryptFields::from_config(&crate::models::load_config()),
+    };
+    let lock = crate::ledger::lock(Path::new(ledger_path))?;
+    let (staged, total) = stage_rekey(ledger_path, &keys)?;
+    if !new_from_env {
+        if let Err(e) = crypto::save_key(&new) {
+            discard(&staged);
+            return Err(e).context("saving new key");
+        }
+    }
+    for file in &staged {
+        std::fs::rename(&file.tmp, &file.dest)
+            .with_context(|| format!("replacing {}", file.dest.display()))?;
+    }
+    drop(lock);
+
+    println!("rekeyed {total} events in {} file(s)", staged.len());
+    if new_from_env {
+        println!("new key: VIGILO_ENCRYPTION_KEY");
+    } else {
+        println!("new key saved to {}", crypto::key_file_path().display());
+        if std::env::var_os("VIGILO_ENCRYPTION_KEY").is_some() {
+            eprintln!("warning: VIGILO_ENCRYPTION_KEY still holds the old key and overrides the key file — unset it");
+        }
+    }
+    Ok(())
+}
+
+/// Opens each field with `old` and seals it with `new` if it was sealed before
+/// or `ENCRYPT_FIELDS` covers it, so a narrower setting never turns existing
+/// ciphertext into plaintext.
+struct Rekey<'a> {
+    old: &'a crypto::EncryptionKey,
+    new: &'a crypto::EncryptionKey,
+    fields: crypto::EncryptFields,
+}
+
+struct Staged {
+    tmp: PathBuf,
+    dest: PathBuf,
+}
+
+fn stage_rekey(ledger_path: &str, keys: &Rekey) -> Result<(Vec<Staged>, usize)> {
+    let mut staged = Vec::new();
+    let mut total = 0;
+    for dest in data::all_ledger_files(ledger_path) {
+        if !dest.exists() {
+            continue;
+        }
+        let mut tmp = dest.clone().into_os_string();
+        tmp.push(".rekey");
+        let tmp = PathBuf::from(tmp);
+        match rekey_file(&dest, &tmp, keys) {
+            Ok(n) => total += n,
+            Err(e) => {
+                let _ = std::fs::remove_file(&tmp);
+                discard(&staged);
+                return Err(e.context(dest.display().to_string()));
+            }
+        }
+        staged.push(Staged { tmp, dest });
+    }
+    Ok((staged, total))
+}
+
+fn discard(staged: &[Staged]) {
+    for file in staged {
+        let _ = std::fs::remove_file(&file.tmp);
+    }
+}
+
+/// Gzipped rotated files stay gzipped.
+fn rekey_file(src: &Path, tmp: &Path, keys: &Rekey) -> Result<usize> {
+    let reader = BufReader::new(data::open_ledger_reader(src)?);
+    let file = std::fs::File::create(tmp)?;
+    if src.extension().is_some_and(|ext| ext == "gz") {
+        let mut enc = flate2::write::GzEncoder::new(file, flate2::Compression::default());
+        let n = rekey_lines(keys, reader, &mut enc)?;
+        enc.finish()?.sync_all()?;
+        Ok(n)
+    } else {
+        let mut writer = std::io::BufWriter::new(file);
+        let n = rekey_lines(keys, reader, &mut writer)?;
+        writer.into_inner()?.sync_all()?;
+        Ok(n)
+    }
+}
+
+/// Lines that aren't JSON are copied through untouched rather than dropped.
+fn rekey_lines(keys: &Rekey, reader: impl BufRead, writer: &mut impl Write) -> Result<usize> {
+    let mut count = 0;
+    for (i, line) in reader.lines().enumerate() {
+        let line = line?;
+        let Ok(mut event) = serde_json::from_str::<serde_json::Value>(&line) else {
+            writeln!(writer, "{line}")?;
+            continue;
+        };
+        let fields = keys.fields.union(crypto::EncryptFields::sealed_in(&event));
+        if crypto::decrypt_ledger_line(keys.old, &mut event).is_none() {
+            bail!(
+                "line {}: encrypted field does not open with the old key",
+                i + 1
+            );
+        }
+        crypto::encrypt_ledger_line(keys.new, fields, &mut event)
+            .map_err(|_| anyhow::anyhow!("line {}: encryption failed", i + 1))?;
+        serde_json::to_writer(&mut *writer, &event)?;
+        writeln!(writer)?;
+        count += 1;
+    }
+    Ok(count)
+}
+
+/// Plaintext output holds everything the ledger encrypted, so keep it owner-only.
+fn create_private(path: &str) -> Result<std::fs::File> {
+    let mut opts = std::fs::OpenOptions::new();
+    opts.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        opts.mode(0o600);
+    }
+    opts.open(path).with_context(|| format!("creating {path}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Outcome;
+
+    fn encrypted_line(key: &crypto::EncryptionKey) -> serde_json::Value {
+        let args = serde_json::json!({"path": "/tmp/a.txt", "content": "secret"});
+        let outcome = Outcome::Ok {
+            result: serde_json::json!("wrote 6 bytes"),
+        };
+        let diff = Some("+secret".to_string());
+        let (args, outcome, diff) =
+            crypto::encrypt_for_ledger(Some(key), Default::default(), &args, &outcome, &diff)
+                .unwrap();
+        serde_json::json!({
+            "tool": "write_file",
+            "arguments": args,
+            "outcome": outcome,
+            "diff": diff,
+        })
+    }
+
+    fn decrypted(key: &crypto::EncryptionKey, text: &str) -> serde_json::Value {
+        let mut out = Vec::new();
+        decrypt_lines(key, text.as_bytes(), &mut out).unwrap();
+        serde_json::from_slice(&out).unwrap()
+    }
+
+    #[test]
+    fn decrypt_round_trips_encrypted_fields() {
+        let key = crypto::EncryptionKey::new([7u8; 32]);
+        let input = format!("{}\n{{\"tool\":\"trunc", encrypted_line(&key));
+
+        let mut out = Vec::new();
+        let n = decrypt_lines(&key, input.as_bytes(), &mut out).unwrap();
+        assert_eq!(n, 1);
+        let event: serde_json::Value = serde_json::from_slice(&out).unwrap();
+        assert_eq!(event["arguments"]["content"], "secret");
+        assert_eq!(event["outcome"]["result"], "wrote 6 bytes");
+        assert_eq!(event["diff"], "+secret");
+
+        let wrong = crypto::EncryptionKey::new([8u8; 32]);
+        assert!(decrypt_lines(&wrong, input.as_bytes(), &mut Vec::new()).is_err());
+    }
+
+    #[test]
+    fn rekey_preserves_plaintext_across_key_change() {
+        let old = crypto::EncryptionKey::new([7u8; 32]);
+        let new = crypto::EncryptionKey::new([9u8; 32]);
+        let dir = tempfile::tempdir().unwrap();
+        let active = dir.path().join("events.jsonl");
+        let rotated = dir.path().join("events.100.jsonl.gz");
+        std::fs::write(&active, format!("{}\n", encrypted_line(&old))).unwrap();
+        let mut gz = flate2::write::GzEncoder::new(
+            std::fs::File::create(&rotated).unwrap(),
+            flate2::Compression::default(),
+        );
+        writeln!(gz, "{}", encrypted_line(&old)).unwrap();
+        gz.finish().unwrap();
+
+        let keys = Rekey {
+            old: &old,
+            new: &new,
+            fields: Default::default(),
+        };
+        let (staged, total) = stage_rekey(active.to_str().unwrap(), &keys).unwrap();
+        assert_eq!((staged.len(), total), (2, 2));
+        for file in &staged {
+            std::fs::rename(&file.tmp, &file.dest).unwrap();
+        }
+
+        let mut rotated_text = String::new();
+        std::io::Read::read_to_string(
+            &mut data::open_ledger_reader(&rotated).unwrap(),
+            &mut rotated_text,
+        )
+        .unwrap();
+        for text in [std::fs::read_to_string(&active).unwrap(), rotated_text] {
+            let event = decrypted(&new, &text);
+            assert_eq!(event["arguments"]["content"], "secret");
+            assert_eq!(event["outcome"]["result"], "wrote 6 bytes");
+            assert_eq!(event["diff"], "+secret");
+            assert!(decrypt_lines(&old, text.as_bytes(), &mut Vec::new()).is_err());
+        }
+    }
+
+    #[test]
+    fn rekey_with_wrong_old_key_leaves_ledger_untouched() {
+        let key = crypto::EncryptionKey::new([7u8; 32]);
+        let wrong = crypto::EncryptionKey::new([8u8; 32]);
+        let dir = tempfile::tempdir().unwrap();
+        let active = dir.path().join("events.jsonl");
+        let original = format!("{}\n", encrypted_line(&key));
+        std::fs::write(&active, &original).unwrap();
+
+        let keys = Rekey {
+            old: &wrong,
+            new: &key,
+            fields: Default::default(),
+        };
+        let err = stage_rekey(active.to_str().unwrap(), &keys).err().unwrap();
+        assert!(format!("{err:#}").contains("line 1"));
+        assert_eq!(std::fs::read_to_string(&active).unwrap(), original);
+        assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 1);
+    }
+
+    #[test]
+    fn narrower_fields_keep_existing_ciphertext_sealed() {
+        let old = crypto::EncryptionKey::new([7u8; 32]);
+        let new = crypto::EncryptionKey::new([9u8; 32]);
+        let keys = Rekey {
+            old: &old,
+            new: &new,
+            fields: crypto::EncryptFields::parse("result"),
+        };
+        let input = format!("{}\n", encrypted_line(&old));
+        let mut out = Vec::new();
+        rekey_lines(&keys, input.as_bytes(), &mut out).unwrap();
+
+        let text = String::from_utf8(out).unwrap();
+        assert!(!text.contains("secret"));
+        let event: serde_json::Value = serde_json::from_str(text.trim()).unwrap();
+        assert!(crypto::is_encrypted(event["arguments"].as_str().unwrap()));
+        assert!(crypto::is_encrypted(event["diff"].as_str().unwrap()));
+        assert_eq!(decrypted(&new, &text)["arguments"]["content"], "secret");
+    }
+}
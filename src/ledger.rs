@@ -4,6 +4,7 @@ use serde::Serialize;
 use std::fs::{self, OpenOptions};
 use std::io::Write;
 use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
 use std::time::{SystemTime, UNIX_EPOCH};
 
 const MAX_SIZE: u64 = 10 * 1024 * 1024;
@@ -16,6 +17,29 @@ pub(crate) fn ledger_stem(path: &Path) -> &str {
         .unwrap_or("events")
 }
 
+/// Rotation timestamp of `<stem>.<ts>.jsonl` or `<stem>.<ts>.jsonl.gz`.
+pub(crate) fn rotated_ts(name: &str, stem: &str) -> Option<u128> {
+    let rest = name.strip_prefix(stem)?.strip_prefix('.')?;
+    let rest = rest.strip_suffix(".gz").unwrap_or(rest);
+    rest.strip_suffix(".jsonl")?.parse().ok()
+}
+
+/// Gzip rotated files when `COMPRESS_ROTATED` is set; read once per process.
+fn compress_rotated() -> bool {
+    static COMPRESS: OnceLock<bool> = OnceLock::new();
+    *COMPRESS.get_or_init(|| {
+        crate::models::config_flag(&crate::models::load_config(), "COMPRESS_ROTATED")
+    })
+}
+
+/// `fsync` after every append when `FSYNC` is set, so an event survives a
+/// crash or power loss at the cost of a disk flush per call.
+/// Read once per process, not on every append.
+fn fsync_appends() -> bool {
+    static FSYNC: OnceLock<bool> = OnceLock::new();
+    *FSYNC.get_or_init(|| crate::models::config_flag(&crate::models::load_config(), "FSYNC"))
+}
+
 pub fn append_event(event: &impl Serialize, ledger_path: impl AsRef<Path>) -> Result<()> {
     let path = ledger_path.as_ref();
 
@@ -31,35 +55,72 @@ pub fn append_event(event: &impl Serialize, ledger_path: impl AsRef<Path>) -> Re
         s
     };
 
-    let mut file = OpenOptions::new()
-        .create(true)
-        .append(true)
-        .open(path)
-        .context("opening ledger file")?;
-
-    file.lock_exclusive().context("locking ledger file")?;
+    // One write of the whole line under the lock: concurrent server and hook
+    // processes can't interleave partial lines.
+    let mut file = lock(path)?;
 
     file.write_all(line.as_bytes())?;
     file.flush()?;
+    if fsync_appends() {
+        file.sync_data().context("syncing ledger file")?;
+    }
 
-    if let Ok(meta) = file.metadata() {
-        if meta.len() > MAX_SIZE {
-            // still holding lock — safe to rotate
-            drop(file); // releases lock + handle
-            if let Err(e) = rotate_and_cleanup(&path.to_path_buf(), MAX_ROTATED) {
-                eprintln!("[vigilo] ledger rotation failed: {e}");
+    // The rename happens under the lock; appenders waiting on it notice the
+    // swap and reopen. Compression and pruning run after it is released.
+    let rotated = match file.metadata() {
+        Ok(meta) if meta.len() > MAX_SIZE => Some(rotate(path)),
+        _ => None,
+    };
+    file.unlock().ok();
+    drop(file);
+    match rotated {
+        Some(Ok(rotated)) => {
+            if let Err(e) = finish_rotation(path, &rotated, MAX_ROTATED, compress_rotated()) {
+                eprintln!("[vigilo] ledger rotation cleanup failed: {e}");
             }
-        } else {
-            file.unlock().ok();
         }
-    } else {
-        file.unlock().ok();
+        Some(Err(e)) => eprintln!("[vigilo] ledger rotation failed: {e}"),
+        None => {}
     }
 
     Ok(())
 }
 
-fn rotate_and_cleanup(ledger_path: &PathBuf, keep: usize) -> std::io::Result<()> {
+/// Opens the ledger for appending and takes its exclusive lock. A file swapped
+/// in while waiting (by `rekey` or rotation) is reopened, so the caller never
+/// holds the lock on a file that is no longer at `path`.
+pub(crate) fn lock(path: &Path) -> Result<fs::File> {
+    loop {
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .context("opening ledger file")?;
+        file.lock_exclusive().context("locking ledger file")?;
+        if is_current(&file, path) {
+            return Ok(file);
+        }
+        file.unlock().ok();
+    }
+}
+
+#[cfg(unix)]
+fn is_current(file: &fs::File, path: &Path) -> bool {
+    use std::os::unix::fs::MetadataExt;
+    match (file.metadata(), fs::metadata(path)) {
+        (Ok(held), Ok(now)) => held.ino() == now.ino() && held.dev() == now.dev(),
+        _ => false,
+    }
+}
+
+#[cfg(not(unix))]
+fn is_current(_file: &fs::File, _path: &Path) -> bool {
+    true
+}
+
+/// Moves the full ledger aside as `<stem>.<ts>.jsonl` and starts a new one.
+/// An appender may create the new file first, so it is never truncated.
+fn rotate(ledger_path: &Path) -> std::io::Result<PathBuf> {
     let parent = ledger_path.parent().unwrap_or_else(|| Path::new("."));
     let stem = ledger_stem(ledger_path);
 
@@ -68,30 +129,45 @@ fn rotate_and_cleanup(ledger_path: &PathBuf, keep: usize) -> std::io::Result<()>
         .map(|d| d.as_millis())
         .unwrap_or_default();
 
-    let rotated_name = format!("{stem}.{ts}.jsonl");
-    fs::rename(ledger_path, parent.join(rotated_name))?;
+    let rotated_path = parent.join(format!("{stem}.{ts}.jsonl"));
+    fs::rename(ledger_path, &rotated_path)?;
 
     OpenOptions::new()
         .create(true)
-        .write(true)
-        .truncate(true)
+        .append(true)
         .open(ledger_path)?;
+    Ok(rotated_path)
+}
+
+/// Compresses the file `rotate` set aside, clears what an interrupted
+/// compression left behind and keeps the newest `keep` rotated files.
+fn finish_rotation(
+    ledger_path: &Path,
+    rotated_path: &Path,
+    keep: usize,
+    compress: bool,
+) -> std::io::Result<()> {
+    let parent = ledger_path.parent().unwrap_or_else(|| Path::new("."));
+    let stem = ledger_stem(ledger_path);
+
+    if compress {
+        if let Err(e) = gzip_file(rotated_path) {
+            eprintln!("[vigilo] failed to compress rotated ledger {rotated_path:?}: {e}");
+        }
+    }
+    remove_interrupted(parent, stem);
 
     let mut rotated: Vec<(PathBuf, SystemTime)> = fs::read_dir(parent)?
         .filter_map(|e| e.ok())
         .filter_map(|e| {
             let name = e.file_name().into_string().ok()?;
-            let matches = name.starts_with(stem)
-                && name.ends_with(".jsonl")
-                && name != ledger_path.file_name()?.to_str()?;
-            if !matches {
-                return None;
-            }
+            rotated_ts(&name, stem)?;
             let modified = e.metadata().ok()?.modified().ok()?;
             Some((e.path(), modified))
         })
         .collect();
 
+    #[allow(clippy::unnecessary_sort_by)]
     rotated.sort_by(|a, b| b.1.cmp(&a.1));
 
     for (path, _) in rotated.into_iter().skip(keep) {
@@ -103,13 +179,71 @@ fn rotate_and_cleanup(ledger_path: &PathBuf, keep: usize) -> std::io::Result<()>
     Ok(())
 }
 
-/// Delete rotated ledger files older than `older_than_days` days.
-/// Returns the number of files removed.
-pub fn prune(ledger_path: impl AsRef<Path>, older_than_days: u32) -> Result<usize> {
+const GZIP_PARTIAL: &str = ".gz.partial";
+/// A partial gzip this old belongs to a compression that died.
+const GZIP_PARTIAL_STALE: std::time::Duration = std::time::Duration::from_secs(3600);
+
+/// Replaces `path` with `path.gz`. The archive is written under a partial
+/// name and renamed into place once complete, so readers only ever see a
+/// whole `.gz`; the plain file is removed last.
+fn gzip_file(path: &Path) -> std::io::Result<()> {
+    let mut gz_name = path.as_os_str().to_owned();
+    gz_name.push(".gz");
+    let mut partial = path.as_os_str().to_owned();
+    partial.push(GZIP_PARTIAL);
+    let mut input = fs::File::open(path)?;
+    let output = fs::File::create(&partial)?;
+    let mut encoder = flate2::write::GzEncoder::new(output, flate2::Compression::default());
+    let written = std::io::copy(&mut input, &mut encoder)
+        .and_then(|_| encoder.finish())
+        .and_then(|f| f.sync_all())
+        .and_then(|()| fs::rename(&partial, &gz_name));
+    if let Err(e) = written {
+        let _ = fs::remove_file(&partial);
+        return Err(e);
+    }
+    fs::remove_file(path)
+}
+
+/// A crash between the rename and the removal leaves both `X.jsonl` and a
+/// complete `X.jsonl.gz`: the plain copy goes. Partial archives are removed
+/// once they are old enough that no compression can still be writing them.
+fn remove_interrupted(parent: &Path, stem: &str) {
+    let Ok(entries) = fs::read_dir(parent) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let Ok(name) = entry.file_name().into_string() else {
+            continue;
+        };
+        let leftover = if let Some(source) = name.strip_suffix(GZIP_PARTIAL) {
+            rotated_ts(source, stem).is_some()
+                && entry
+                    .metadata()
+                    .and_then(|m| m.modified())
+                    .is_ok_and(|t| t.elapsed().is_ok_and(|age| age > GZIP_PARTIAL_STALE))
+        } else {
+            !name.ends_with(".gz")
+                && rotated_ts(&name, stem).is_some()
+                && parent.join(format!("{name}.gz")).exists()
+        };
+        if leftover {
+            let _ = fs::remove_file(entry.path());
+        }
+    }
+}
+
+/// Delete rotated ledger files and spilled `search_files` results in
+/// `overflow_dir` older than `older_than_days` days.
+/// Returns how many ledger files and overflow files were removed.
+pub fn prune(
+    ledger_path: impl AsRef<Path>,
+    overflow_dir: &Path,
+    older_than_days: u32,
+) -> Result<(usize, usize)> {
     let path = ledger_path.as_ref();
     let parent = path.parent().unwrap_or_else(|| Path::new("."));
     let stem = ledger_stem(path);
-    let active_name = path.file_name().and_then(|s| s.to_str()).unwrap_or("");
     let cutoff =
         SystemTime::now() - std::time::Duration::from_secs(u64::from(older_than_days) * 86_400);
 
@@ -117,7 +251,7 @@ pub fn prune(ledger_path: impl AsRef<Path>, older_than_days: u32) -> Result<usiz
     for entry in fs::read_dir(parent)?.flatten() {
         let name = entry.file_name();
         let name_str = name.to_string_lossy();
-        if !name_str.starts_with(stem) || !name_str.ends_with(".jsonl") || name_str == active_name {
+        if rotated_ts(&name_str, stem).is_none() {
             continue;
         }
         let modified = entry
@@ -129,6 +263,32 @@ pub fn prune(ledger_path: impl AsRef<Path>, older_than_days: u32) -> Result<usiz
             removed += 1;
         }
     }
+    Ok((removed, prune_overflow(overflow_dir, cutoff)?))
+}
+
+/// Overflow files are named after their event, so one last modified before
+/// the cutoff belongs to an event at least that old.
+fn prune_overflow(dir: &Path, cutoff: SystemTime) -> Result<usize> {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(0),
+        Err(e) => return Err(e.into()),
+    };
+    let mut removed = 0;
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.extension().is_none_or(|ext| ext != "txt") {
+            continue;
+        }
+        let modified = entry
+            .metadata()
+            .and_then(|m| m.modified())
+            .unwrap_or(SystemTime::now());
+        if modified < cutoff {
+            fs::remove_file(&path)?;
+            removed += 1;
+        }
+    }
     Ok(removed)
 }
 
@@ -163,6 +323,71 @@ mod tests {
         assert_eq!(parsed["data"], "hello");
     }
 
+    #[cfg(unix)]
+    #[test]
+    fn append_waiting_on_lock_lands_in_replaced_file() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let path = dir.path().join("events.jsonl");
+        let held = lock(&path).unwrap();
+
+        let writer_path = path.clone();
+        let writer = std::thread::spawn(move || {
+            let event = TestEvent {
+                id: "late".into(),
+                data: "x".into(),
+            };
+            append_event(&event, &writer_path).unwrap();
+        });
+        std::thread::sleep(std::time::Duration::from_millis(100));
+        let replacement = dir.path().join("events.jsonl.rekey");
+        fs::write(&replacement, "{\"id\":\"rekeyed\",\"data\":\"\"}\n").unwrap();
+        fs::rename(&replacement, &path).unwrap();
+        drop(held);
+        writer.join().unwrap();
+
+        let ids: Vec<String> = fs::read_to_string(&path)
+            .unwrap()
+            .lines()
+            .map(|l| serde_json::from_str::<serde_json::Value>(l).unwrap()["id"].to_string())
+            .collect();
+        assert_eq!(ids, ["\"rekeyed\"", "\"late\""]);
+    }
+
+    #[test]
+    fn concurrent_appenders_never_tear_lines() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let path = dir.path().join("events.jsonl");
+        let writers: Vec<_> = ["a", "b"]
+            .into_iter()
+            .map(|name| {
+                let path = path.clone();
+                std::thread::spawn(move || {
+                    for i in 0..200 {
+                        let event = TestEvent {
+                            id: format!("{name}{i}"),
+                            data: name.repeat(4096),
+                        };
+                        append_event(&event, &path).expect("append should succeed");
+                    }
+                })
+            })
+            .collect();
+        for w in writers {
+            w.join().unwrap();
+        }
+
+        let contents = fs::read_to_string(&path).expect("read file");
+        let ids: Vec<String> = contents
+            .lines()
+            .map(|l| {
+                let v: serde_json::Value = serde_json::from_str(l).expect("every line parses");
+                v["id"].as_str().unwrap().to_string()
+            })
+            .collect();
+        assert_eq!(ids.len(), 400);
+        assert_eq!(ids.iter().filter(|id| id.starts_with('a')).count(), 200);
+    }
+
     #[test]
     fn append_event_returns_error_for_directory_path() {
         let dir = tempfile::tempdir().expect("temp dir");
@@ -207,6 +432,55 @@ mod tests {
         assert!(!rotated.is_empty(), "expected at least 1 rotated file");
     }
 
+    #[test]
+    fn rotated_ts_accepts_plain_and_gzipped_names() {
+        assert_eq!(rotated_ts("events.123.jsonl", "events"), Some(123));
+        assert_eq!(rotated_ts("events.123.jsonl.gz", "events"), Some(123));
+        assert_eq!(rotated_ts("events.jsonl", "events"), None);
+        assert_eq!(rotated_ts("other.123.jsonl", "events"), None);
+    }
+
+    #[test]
+    fn rotate_with_compress_leaves_only_gz_file() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let path = dir.path().join("events.jsonl");
+        fs::write(&path, "{\"id\":\"1\"}\n").unwrap();
+
+        let rotated = rotate(&path).expect("rotate");
+        finish_rotation(&path, &rotated, MAX_ROTATED, true).expect("finish");
+
+        let names: Vec<String> = fs::read_dir(dir.path())
+            .unwrap()
+            .filter_map(|e| e.ok())
+            .map(|e| e.file_name().to_string_lossy().to_string())
+            .filter(|n| n != "events.jsonl")
+            .collect();
+        assert_eq!(names.len(), 1);
+        assert!(names[0].ends_with(".jsonl.gz"));
+        assert_eq!(fs::metadata(&path).unwrap().len(), 0);
+    }
+
+    #[test]
+    fn interrupted_compression_leaves_no_duplicate() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let path = dir.path().join("events.jsonl");
+        fs::write(dir.path().join("events.100.jsonl"), "{\"id\":\"1\"}\n").unwrap();
+        let plain = dir.path().join("events.100.jsonl");
+        gzip_file(&plain).unwrap();
+        // as if the process died before removing the source
+        fs::write(&plain, "{\"id\":\"1\"}\n").unwrap();
+        fs::write(dir.path().join("events.200.jsonl.gz.partial"), "junk").unwrap();
+
+        let files = crate::view::data::all_ledger_files(&path);
+        assert!(!files.contains(&plain), "{files:?}");
+
+        remove_interrupted(dir.path(), "events");
+        assert!(!plain.exists());
+        assert!(dir.path().join("events.100.jsonl.gz").exists());
+        // too fresh to be abandoned: another process may still be writing it
+        assert!(dir.path().join("events.200.jsonl.gz.partial").exists());
+    }
+
     #[test]
     fn prune_removes_old_rotated_files() {
         let dir = tempfile::tempdir().expect("temp dir");
@@ -231,7 +505,7 @@ mod tests {
         let recent = dir.path().join("events.9999999.jsonl");
         fs::write(&recent, "recent\n").unwrap();
 
-        let removed = prune(&active, 30).unwrap();
+        let (removed, _) = prune(&active, &dir.path().join("overflow"), 30).unwrap();
         assert_eq!(removed, 1);
         assert!(!old.exists(), "old file should be deleted");
         assert!(recent.exists(), "recent file should remain");
@@ -244,7 +518,33 @@ mod tests {
         let active = dir.path().join("events.jsonl");
         fs::write(&active, "active\n").unwrap();
 
-        let removed = prune(&active, 30).unwrap();
-        assert_eq!(removed, 0);
+        let removed = prune(&active, &dir.path().join("overflow"), 30).unwrap();
+        assert_eq!(removed, (0, 0));
+    }
+
+    #[test]
+    fn prune_removes_old_overflow_files() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let active = dir.path().join("events.jsonl");
+        fs::write(&active, "active\n").unwrap();
+        let overflow = dir.path().join("overflow");
+        fs::create_dir(&overflow).unwrap();
+        let old = overflow.join(format!("{}.txt", uuid::Uuid::new_v4()));
+        let recent = overflow.join(format!("{}.txt", uuid::Uuid::new_v4()));
+        fs::write(&old, "old result").unwrap();
+        fs::write(&recent, "recent result").unwrap();
+        let old_time = filetime::FileTime::from_unix_time(
+            (SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap()
+                .as_secs()
+                - 60 * 86400) as i64,
+            0,
+        );
+        filetime::set_file_mtime(&old, old_time).unwrap();
+
+        assert_eq!(prune(&active, &overflow, 30).unwrap(), (0, 1));
+        assert!(!old.exists(), "old overflow file should be deleted");
+        assert!(recent.exists(), "recent overflow file should remain");
     }
 }
@@ -1,12 +1,15 @@
+mod bench;
 mod cli;
 mod crypto;
 mod cursor;
+mod danger;
 #[cfg(feature = "dashboard")]
 mod dashboard;
 mod doctor;
 mod git;
 mod hook;
 mod hook_helpers;
+mod keys;
 mod ledger;
 mod models;
 mod server;
@@ -72,7 +75,19 @@ async fn main() -> Result<()> {
 async fn dispatch_subcommand(args: &[String], ledger_path: &str) -> Option<Result<()>> {
     if matches!(
         args.first().map(|s| s.as_str()),
-        Some("view" | "sessions" | "stats" | "errors" | "summary" | "tail" | "diff" | "query")
+        Some(
+            "view"
+                | "session"
+                | "sessions"
+                | "stats"
+                | "errors"
+                | "cost"
+                | "audit"
+                | "summary"
+                | "tail"
+                | "diff"
+                | "query"
+        )
     ) {
         auto_sync_cursor_cache().await;
     }
@@ -80,16 +95,27 @@ async fn dispatch_subcommand(args: &[String], ledger_path: &str) -> Option<Resul
     match args.first().map(|s| s.as_str()) {
         Some("view") => Some(view::run(ledger_path, parse_view_args(&args[1..]))),
         Some("generate-key") => Some(generate_key()),
+        Some("decrypt") => Some(dispatch_decrypt(&args[1..], ledger_path)),
+        Some("rekey") => Some(dispatch_rekey(&args[1..], ledger_path)),
         Some("stats") => Some(dispatch_stats(&args[1..], ledger_path)),
         Some("errors") => Some(dispatch_errors(&args[1..], ledger_path)),
+        Some("cost") => Some(dispatch_cost(&args[1..], ledger_path)),
+        Some("audit") => Some(dispatch_audit(&args[1..], ledger_path)),
         Some("query") => Some(dispatch_query(&args[1..], ledger_path)),
         Some("diff") => Some(view::diff(ledger_path, &parse_view_args(&args[1..]))),
         Some("cursor-usage") => Some(dispatch_cursor_usage(&args[1..]).await),
-        Some("hook") => Some(hook::run(ledger_path).await),
+        Some("hook") => {
+            Some(hook::run(ledger_path, get_flag(&args[1..], "--client").as_deref()).await)
+        }
         Some("setup") => Some(setup::run().await),
-        Some("watch") => Some(view::watch(ledger_path).await),
-        Some("summary") => Some(view::summary(ledger_path)),
+        Some("uninstall") => Some(setup::uninstall()),
+        Some("watch") => Some(dispatch_watch(&args[1..], ledger_path).await),
+        Some("summary") => Some(dispatch_summary(&args[1..], ledger_path)),
         Some("sessions") => Some(view::sessions(ledger_path, parse_view_args(&args[1..]))),
+        Some("session") => Some(match args.get(1) {
+            Some(prefix) => view::session(ledger_path, prefix),
+            None => Err(anyhow::anyhow!("usage: vigilo session <id-prefix>")),
+        }),
         Some("tail") => Some(dispatch_tail(&args[1..], ledger_path)),
         Some("export") => Some(dispatch_export(&args[1..], ledger_path)),
         Some("prune") => Some(dispatch_prune(&args[1..], ledger_path)),
@@ -99,6 +125,7 @@ async fn dispatch_subcommand(args: &[String], ledger_path: &str) -> Option<Resul
             Some(Ok(()))
         }
         Some("completions") => Some(cli::completions(args.get(1).map(|s| s.as_str()))),
+        Some("bench") => Some(dispatch_bench(&args[1..])),
         _ => None,
     }
 }
@@ -108,32 +135,131 @@ fn generate_key() -> Result<()> {
     Ok(())
 }
 
+fn dispatch_decrypt(args: &[String], ledger_path: &str) -> Result<()> {
+    let Some(out) = get_flag(args, "--out") else {
+        anyhow::bail!("usage: vigilo decrypt --out <path>");
+    };
+    keys::decrypt(ledger_path, &out)
+}
+
+fn dispatch_rekey(args: &[String], ledger_path: &str) -> Result<()> {
+    let Some(old_key) = get_flag(args, "--old-key") else {
+        anyhow::bail!("usage: vigilo rekey --old-key <base64>");
+    };
+    keys::rekey(ledger_path, &old_key)
+}
+
 fn dispatch_stats(args: &[String], ledger_path: &str) -> Result<()> {
     let since = get_flag(args, "--since").map(|s| parse_date(&s));
     let until = get_flag(args, "--until").map(|s| parse_date(&s));
-    view::stats_filtered(ledger_path, since.as_deref(), until.as_deref())
+    let json = args.iter().any(|a| a == "--json");
+    let top = match get_flag(args, "--top") {
+        Some(s) => Some(
+            s.parse::<usize>()
+                .map_err(|_| anyhow::anyhow!("--top expects a number of rows, got '{s}'"))?,
+        ),
+        None => None,
+    };
+    view::stats_filtered(ledger_path, since.as_deref(), until.as_deref(), json, top)
+}
+
+fn dispatch_cost(args: &[String], ledger_path: &str) -> Result<()> {
+    let since = get_flag(args, "--since").map(|s| parse_date(&s));
+    let until = get_flag(args, "--until").map(|s| parse_date(&s));
+    let weekly = args.iter().any(|a| a == "--weekly");
+    view::cost(ledger_path, since.as_deref(), until.as_deref(), weekly)
+}
+
+fn dispatch_audit(args: &[String], ledger_path: &str) -> Result<()> {
+    let since = get_flag(args, "--since").map(|s| parse_date(&s));
+    let until = get_flag(args, "--until").map(|s| parse_date(&s));
+    let json = match get_flag(args, "--format").as_deref() {
+        None | Some("text") => false,
+        Some("json") => true,
+        Some(other) => anyhow::bail!("unknown audit format '{other}' (expected text | json)"),
+    };
+    view::audit(ledger_path, since.as_deref(), until.as_deref(), json)
+}
+
+fn dispatch_summary(args: &[String], ledger_path: &str) -> Result<()> {
+    let period = match get_flag(args, "--period") {
+        Some(p) => view::Period::parse(&p)?,
+        None => view::Period::Today,
+    };
+    view::summary(ledger_path, period)
 }
 
 fn dispatch_errors(args: &[String], ledger_path: &str) -> Result<()> {
     let since = get_flag(args, "--since").map(|s| parse_date(&s));
     let until = get_flag(args, "--until").map(|s| parse_date(&s));
+    let session = get_flag(args, "--session");
     let expand = args.iter().any(|a| a == "--expand");
-    view::errors(ledger_path, since.as_deref(), until.as_deref(), expand)
+    let json = args.iter().any(|a| a == "--json");
+    view::errors(
+        ledger_path,
+        since.as_deref(),
+        until.as_deref(),
+        session.as_deref(),
+        expand,
+        json,
+    )
 }
 
 fn dispatch_query(args: &[String], ledger_path: &str) -> Result<()> {
     let since = get_flag(args, "--since").map(|s| parse_date(&s));
     let until = get_flag(args, "--until").map(|s| parse_date(&s));
     let tool = get_flag(args, "--tool");
+    let tool_regex = get_flag(args, "--tool-regex");
     let risk = get_flag(args, "--risk");
     let session = get_flag(args, "--session");
+    let project = get_flag(args, "--project");
+    let tag = get_flag(args, "--tag");
+    let generation = get_flag(args, "--generation");
+    let contains = get_flag(args, "--contains");
+    let min_duration_us = match get_flag(args, "--min-duration") {
+        Some(s) => Some(cli::parse_duration_us(&s).ok_or_else(|| {
+            anyhow::anyhow!("--min-duration expects a duration like 500ms or 2s, got '{s}'")
+        })?),
+        None => None,
+    };
+    let min_cost = match get_flag(args, "--min-cost") {
+        Some(s) => Some(
+            s.parse::<f64>()
+                .map_err(|_| anyhow::anyhow!("--min-cost expects a USD amount, got '{s}'"))?,
+        ),
+        None => None,
+    };
+    let exit_code = match get_flag(args, "--exit-code") {
+        Some(s) => Some(
+            s.parse::<i32>()
+                .map_err(|_| anyhow::anyhow!("--exit-code expects an integer, got '{s}'"))?,
+        ),
+        None => None,
+    };
+    let sort = match get_flag(args, "--sort") {
+        Some(s) => view::QuerySort::parse(&s)?,
+        None => view::QuerySort::Time,
+    };
     view::query(
         ledger_path,
-        since.as_deref(),
-        until.as_deref(),
-        tool.as_deref(),
-        risk.as_deref(),
-        session.as_deref(),
+        &view::QueryArgs {
+            since: since.as_deref(),
+            until: until.as_deref(),
+            tool: tool.as_deref(),
+            tool_regex: tool_regex.as_deref(),
+            risk: risk.as_deref(),
+            session: session.as_deref(),
+            project: project.as_deref(),
+            tag: tag.as_deref(),
+            generation: generation.as_deref(),
+            min_duration_us,
+            min_cost,
+            exit_code,
+            contains: contains.as_deref(),
+            flagged: args.iter().any(|a| a == "--flagged"),
+            sort,
+            dedup: args.iter().any(|a| a == "--dedup"),
+        },
     )
 }
 
@@ -151,6 +277,20 @@ async fn dispatch_cursor_usage(args: &[String]) -> Result<()> {
     }
 }
 
+async fn dispatch_watch(args: &[String], ledger_path: &str) -> Result<()> {
+    let risk = get_flag(args, "--risk");
+    let tool = get_flag(args, "--tool");
+    let session = get_flag(args, "--session");
+    let project = get_flag(args, "--project");
+    let filter = view::WatchFilter {
+        risk: risk.as_deref(),
+        tool: tool.as_deref(),
+        session: session.as_deref(),
+        project: project.as_deref(),
+    };
+    view::watch(ledger_path, args.iter().any(|a| a == "--json"), &filter).await
+}
+
 fn dispatch_tail(args: &[String], ledger_path: &str) -> Result<()> {
     let n = get_flag(args, "-n")
         .or_else(|| get_flag(args, "--last"))
@@ -163,21 +303,47 @@ fn dispatch_prune(args: &[String], ledger_path: &str) -> Result<()> {
     let days: u32 = get_flag(args, "--older-than")
         .and_then(|s| s.parse().ok())
         .unwrap_or(30);
-    let removed = ledger::prune(ledger_path, days)?;
+    let overflow_dir = models::vigilo_path("overflow");
+    let (removed, spilled) = ledger::prune(ledger_path, &overflow_dir, days)?;
     if removed > 0 {
         println!("pruned {removed} rotated ledger file(s) older than {days} days");
     } else {
         println!("no rotated ledger files older than {days} days");
     }
+    if spilled > 0 {
+        println!("pruned {spilled} overflow result file(s) older than {days} days");
+    }
     Ok(())
 }
 
 fn dispatch_export(args: &[String], ledger_path: &str) -> Result<()> {
     let format = get_flag(args, "--format").unwrap_or_else(|| "csv".to_string());
-    let output = get_flag(args, "--output");
-    let filtered: Vec<String> = filter_flags(args, &["--format", "--output"]);
+    let output = get_flag(args, "--output").or_else(|| get_flag(args, "--out"));
+    let summary = args.iter().any(|a| a == "--summary");
+    let anonymize = args.iter().any(|a| a == "--anonymize");
+    let filtered: Vec<String> = filter_flags(args, &["--format", "--output", "--out"])
+        .into_iter()
+        .filter(|a| a != "--summary" && a != "--anonymize")
+        .collect();
     let view_args = parse_view_args(&filtered);
-    view::export(ledger_path, &format, &view_args, output.as_deref())
+    view::export(
+        ledger_path,
+        &format,
+        &view_args,
+        output.as_deref(),
+        summary,
+        anonymize,
+    )
+}
+
+fn dispatch_bench(args: &[String]) -> Result<()> {
+    let n = match get_flag(args, "--events") {
+        Some(s) => s
+            .parse::<usize>()
+            .map_err(|_| anyhow::anyhow!("--events expects a number of events, got '{s}'"))?,
+        None => 100_000,
+    };
+    bench::run(n)
 }
 
 async fn dispatch_dashboard(args: &[String], ledger_path: &str) -> Result<()> {
@@ -186,7 +352,7 @@ async fn dispatch_dashboard(args: &[String], ledger_path: &str) -> Result<()> {
         let port: u16 = cli::get_flag(args, "--port")
             .and_then(|s| s.parse().ok())
             .unwrap_or(7847);
-        dashboard::run(ledger_path.to_string(), port).await
+        dashboard::run(ledger_path.to_string(), port, cli::get_flag(args, "--bind")).await
     }
     #[cfg(not(feature = "dashboard"))]
     {
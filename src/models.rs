@@ -51,6 +51,19 @@ pub fn load_config() -> HashMap<String, String> {
         .collect()
 }
 
+/// `VIGILO_<KEY>` from the environment, then `<KEY>` from `~/.vigilo/config`.
+pub(crate) fn config_setting(config: &HashMap<String, String>, key: &str) -> Option<String> {
+    std::env::var(format!("VIGILO_{key}"))
+        .ok()
+        .or_else(|| config.get(key).cloned())
+}
+
+/// [`config_setting`] read as an opt-in flag: `true`, `1` or `yes`.
+pub(crate) fn config_flag(config: &HashMap<String, String>, key: &str) -> bool {
+    config_setting(config, key)
+        .is_some_and(|v| matches!(v.to_lowercase().as_str(), "true" | "1" | "yes"))
+}
+
 #[derive(Serialize, Deserialize, Default)]
 pub struct McpEvent {
     pub id: Uuid,
@@ -69,8 +82,27 @@ pub struct McpEvent {
     pub tag: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub diff: Option<String>,
+    /// Full result file when the inline result was truncated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub overflow: Option<String>,
     #[serde(default)]
     pub timed_out: bool,
+    /// Write or exec call that `VIGILO_DRY_RUN` logged without performing.
+    #[serde(default)]
+    pub dry_run: bool,
+    /// `run_command` exit status; absent for other tools and timed-out runs.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub exit_code: Option<i32>,
+    /// Why a shell command was flagged as destructive, e.g. "force push".
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub flagged: Option<String>,
+    /// Directory the call ran in: a `run_command` cwd, a path argument's
+    /// directory, the hook payload's cwd, or the server's own working directory.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cwd: Option<String>,
+    /// `<algo>:<hex>` digest from a `get_file_info` call that asked for a hash.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_hash: Option<String>,
 
     // Token/model metadata (flattened for backward-compatible JSONL)
     #[serde(default, flatten)]
@@ -140,6 +172,9 @@ impl McpEvent {
     pub fn cache_write_tokens(&self) -> Option<u64> {
         self.token_usage.cache_write_tokens
     }
+    pub fn generation_id(&self) -> Option<&str> {
+        self.cursor_meta.generation_id.as_deref()
+    }
 }
 
 #[derive(Serialize, Deserialize, Clone, Default)]
@@ -156,6 +191,7 @@ pub struct ProjectContext {
 pub enum Outcome {
     Ok { result: serde_json::Value },
     Err { code: i32, message: String },
+    Blocked { reason: String },
 }
 
 impl Default for Outcome {
@@ -181,10 +217,12 @@ pub enum Risk {
 pub const VIGILO_TOOLS: &[(&str, Risk)] = &[
     ("read_file", Risk::Read),
     ("write_file", Risk::Write),
+    ("append_file", Risk::Write),
     ("list_directory", Risk::Read),
     ("create_directory", Risk::Write),
     ("delete_file", Risk::Write),
     ("move_file", Risk::Write),
+    ("copy_file", Risk::Write),
     ("search_files", Risk::Read),
     ("run_command", Risk::Exec),
     ("get_file_info", Risk::Read),
@@ -192,7 +230,11 @@ pub const VIGILO_TOOLS: &[(&str, Risk)] = &[
     ("git_status", Risk::Read),
     ("git_diff", Risk::Read),
     ("git_log", Risk::Read),
+    ("git_branch", Risk::Read),
+    ("git_blame", Risk::Read),
     ("git_commit", Risk::Write),
+    ("git_stash", Risk::Write),
+    ("git_stash_pop", Risk::Write),
 ];
 
 impl Risk {
@@ -228,14 +270,16 @@ mod tests {
     use super::*;
 
     #[test]
-    fn is_vigilo_mcp_tool_matches_all_14_tools() {
+    fn is_vigilo_mcp_tool_matches_all_20_tools() {
         let tools = [
             "read_file",
             "write_file",
+            "append_file",
             "list_directory",
             "create_directory",
             "delete_file",
             "move_file",
+            "copy_file",
             "search_files",
             "run_command",
             "get_file_info",
@@ -243,7 +287,11 @@ mod tests {
             "git_status",
             "git_diff",
             "git_log",
+            "git_branch",
+            "git_blame",
             "git_commit",
+            "git_stash",
+            "git_stash_pop",
         ];
         for tool in tools {
             assert!(is_vigilo_mcp_tool(tool), "{tool} should match");
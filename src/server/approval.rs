@@ -0,0 +1,159 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+const POLL_INTERVAL_MS: u64 = 250;
+const DEFAULT_TIMEOUT_SECS: u64 = 120;
+
+/// Holds write and exec calls until a supervisor answers. Each request is
+/// written owner-only to `<dir>/<id>.pending`; the supervisor writes `approve` or `deny` into
+/// `<dir>/<id>.decision`. No answer within the timeout counts as a denial.
+pub(crate) struct ApprovalGate {
+    pub dir: PathBuf,
+    pub timeout: Duration,
+}
+
+#[derive(Debug, PartialEq)]
+pub(super) enum Decision {
+    Approved,
+    Denied,
+    TimedOut,
+}
+
+impl ApprovalGate {
+    /// Enabled by `VIGILO_APPROVE_EXEC` / `APPROVE_EXEC`; waits up to
+    /// `APPROVAL_TIMEOUT_SECS` (default 120) for a decision.
+    pub(super) fn from_config(config: &HashMap<String, String>) -> Option<Self> {
+        if !crate::models::config_flag(config, "APPROVE_EXEC") {
+            return None;
+        }
+        let timeout_secs = crate::models::config_setting(config, "APPROVAL_TIMEOUT_SECS")
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(DEFAULT_TIMEOUT_SECS);
+        Some(Self {
+            dir: crate::models::vigilo_dir().join("approvals"),
+            timeout: Duration::from_secs(timeout_secs),
+        })
+    }
+
+    pub(super) async fn request(
+        &self,
+        id: &str,
+        tool: &str,
+        arguments: &serde_json::Value,
+    ) -> Decision {
+        let pending = self.dir.join(format!("{id}.pending"));
+        let decision = self.dir.join(format!("{id}.decision"));
+        let request = serde_json::json!({
+            "id": id,
+            "tool": tool,
+            "arguments": arguments,
+            "requested_at": chrono::Utc::now().to_rfc3339(),
+        });
+        if let Err(e) = crate::crypto::create_private(&pending, &request.to_string()) {
+            eprintln!("[vigilo] approval request failed: {e}");
+            return Decision::Denied;
+        }
+        eprintln!(
+            "[vigilo] awaiting approval for {tool} — write approve|deny to {}",
+            decision.display()
+        );
+
+        let outcome = tokio::time::timeout(self.timeout, wait_for_decision(&decision)).await;
+        let _ = tokio::fs::remove_file(&pending).await;
+        let _ = tokio::fs::remove_file(&decision).await;
+        outcome.unwrap_or(Decision::TimedOut)
+    }
+}
+
+async fn wait_for_decision(path: &Path) -> Decision {
+    loop {
+        if let Ok(token) = tokio::fs::read_to_string(path).await {
+            match token.trim() {
+                "approve" => return Decision::Approved,
+                "deny" => return Decision::Denied,
+                _ => {}
+            }
+        }
+        tokio::time::sleep(Duration::from_millis(POLL_INTERVAL_MS)).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn gate(dir: &Path, timeout_ms: u64) -> ApprovalGate {
+        ApprovalGate {
+            dir: dir.to_path_buf(),
+            timeout: Duration::from_millis(timeout_ms),
+        }
+    }
+
+    async fn answer(dir: PathBuf, id: &str, token: &'static str) {
+        let pending = dir.join(format!("{id}.pending"));
+        while !pending.exists() {
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+        tokio::fs::write(dir.join(format!("{id}.decision")), token)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn approve_token_releases_request() {
+        let dir = tempfile::tempdir().unwrap();
+        let args = serde_json::json!({ "command": "ls" });
+        let gate = gate(dir.path(), 5_000);
+        let (decision, _) = tokio::join!(
+            gate.request("req-1", "run_command", &args),
+            answer(dir.path().to_path_buf(), "req-1", "approve\n"),
+        );
+        assert_eq!(decision, Decision::Approved);
+        assert!(!dir.path().join("req-1.pending").exists());
+    }
+
+    #[tokio::test]
+    async fn deny_token_refuses_request() {
+        let dir = tempfile::tempdir().unwrap();
+        let args = serde_json::json!({ "command": "rm -rf /" });
+        let gate = gate(dir.path(), 5_000);
+        let (decision, _) = tokio::join!(
+            gate.request("req-2", "run_command", &args),
+            answer(dir.path().to_path_buf(), "req-2", "deny"),
+        );
+        assert_eq!(decision, Decision::Denied);
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn pending_request_is_owner_only() {
+        use std::os::unix::fs::PermissionsExt;
+        let dir = tempfile::tempdir().unwrap();
+        let gate = gate(dir.path(), 5_000);
+        let args = serde_json::json!({});
+        let check = async {
+            let pending = dir.path().join("req-4.pending");
+            while !pending.exists() {
+                tokio::time::sleep(Duration::from_millis(20)).await;
+            }
+            let mode = std::fs::metadata(&pending).unwrap().permissions().mode();
+            tokio::fs::write(dir.path().join("req-4.decision"), "deny")
+                .await
+                .unwrap();
+            mode & 0o777
+        };
+        let (_, mode) = tokio::join!(gate.request("req-4", "write_file", &args), check);
+        assert_eq!(mode, 0o600);
+    }
+
+    #[tokio::test]
+    async fn missing_answer_times_out() {
+        let dir = tempfile::tempdir().unwrap();
+        let gate = gate(dir.path(), 300);
+        let decision = gate
+            .request("req-3", "run_command", &serde_json::json!({}))
+            .await;
+        assert_eq!(decision, Decision::TimedOut);
+    }
+}
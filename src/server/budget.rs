@@ -0,0 +1,199 @@
+use crate::models::McpEvent;
+use crate::view::data::{load_sessions, LoadFilter};
+use std::collections::HashMap;
+use std::io::{Read, Seek, SeekFrom};
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// `DAILY_BUDGET_USD`: warns on stderr, once per session, when today's
+/// estimated cost across the whole ledger goes over the limit.
+pub(crate) struct DailyBudget {
+    pub limit_usd: f64,
+    warned: AtomicBool,
+    tally: tokio::sync::Mutex<Option<Tally>>,
+}
+
+/// Today's running cost and how far into the active ledger it has read. Hooks
+/// append from other processes, so each check reads only what they added since.
+struct Tally {
+    day: String,
+    spent_usd: f64,
+    offset: u64,
+}
+
+impl DailyBudget {
+    pub(super) fn from_config(config: &HashMap<String, String>) -> Option<Self> {
+        let raw = crate::models::config_setting(config, "DAILY_BUDGET_USD")?;
+        match raw.trim().parse::<f64>() {
+            Ok(limit) if limit.is_finite() && limit > 0.0 => Some(Self::new(limit)),
+            _ => {
+                eprintln!("[vigilo] ignoring invalid DAILY_BUDGET_USD={raw}");
+                None
+            }
+        }
+    }
+
+    fn new(limit_usd: f64) -> Self {
+        Self {
+            limit_usd,
+            warned: AtomicBool::new(false),
+            tally: tokio::sync::Mutex::new(None),
+        }
+    }
+
+    pub(super) async fn check(&self, ledger_path: &Path) {
+        if self.warned.load(Ordering::Relaxed) {
+            return;
+        }
+        let mut slot = self.tally.lock().await;
+        let previous = slot.take();
+        let path = ledger_path.to_path_buf();
+        let tally = tokio::task::spawn_blocking(move || advance(previous, &path))
+            .await
+            .ok()
+            .flatten();
+        let spent = tally.as_ref().map_or(0.0, |t| t.spent_usd);
+        *slot = tally;
+        drop(slot);
+        if let Some(warning) = self.crossed(spent) {
+            eprintln!("[vigilo] {warning}");
+        }
+    }
+
+    /// The warning to print, the first time `spent` is over the limit.
+    fn crossed(&self, spent: f64) -> Option<String> {
+        if spent <= self.limit_usd || self.warned.swap(true, Ordering::Relaxed) {
+            return None;
+        }
+        Some(format!(
+            "⚠ daily budget exceeded ({} of {})",
+            crate::view::fmt::fmt_cost(spent),
+            crate::view::fmt::fmt_cost(self.limit_usd)
+        ))
+    }
+}
+
+/// Brings `tally` up to date with the ledger. The first call, a new day or a
+/// rotation (the active file shrank) loads today's events in full; otherwise
+/// only the complete lines appended since the last call are read.
+fn advance(tally: Option<Tally>, ledger_path: &Path) -> Option<Tally> {
+    let today = crate::cli::parse_date("today");
+    let len = std::fs::metadata(ledger_path).map_or(0, |m| m.len());
+    match tally {
+        Some(mut t) if t.day == today && t.offset <= len => {
+            let (cost, read) = tail_cost(ledger_path, t.offset, &today).ok()?;
+            t.spent_usd += cost;
+            t.offset += read;
+            Some(t)
+        }
+        _ => seed(ledger_path, today),
+    }
+}
+
+/// Holds the ledger lock while loading so no append lands between the totals
+/// and the offset they end at.
+fn seed(ledger_path: &Path, today: String) -> Option<Tally> {
+    let lock = crate::ledger::lock(ledger_path).ok()?;
+    let filter = LoadFilter {
+        since: Some(&today),
+        until: Some(&today),
+        ..Default::default()
+    };
+    let spent_usd = load_sessions(ledger_path, &filter)
+        .ok()?
+        .iter()
+        .map(|(_, events)| crate::view::fmt::session_cost_usd(events))
+        .sum();
+    let offset = lock.metadata().ok()?.len();
+    drop(lock);
+    Some(Tally {
+        day: today,
+        spent_usd,
+        offset,
+    })
+}
+
+/// Cost of today's events in the complete lines after `offset`, and how many
+/// bytes those lines span. A line still being written is left for next time.
+fn tail_cost(ledger_path: &Path, offset: u64, today: &str) -> std::io::Result<(f64, u64)> {
+    let mut file = std::fs::File::open(ledger_path)?;
+    file.seek(SeekFrom::Start(offset))?;
+    let mut buf = Vec::new();
+    file.read_to_end(&mut buf)?;
+    let complete = buf.iter().rposition(|&b| b == b'\n').map_or(0, |i| i + 1);
+    let cost = buf[..complete]
+        .split(|&b| b == b'\n')
+        .filter_map(|line| serde_json::from_slice::<McpEvent>(line).ok())
+        .filter(|e| e.timestamp.get(..10) == Some(today))
+        .filter_map(|e| crate::view::fmt::event_cost_usd(&e))
+        .sum();
+    Ok((cost, complete as u64))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn warns_once_when_spend_crosses_budget() {
+        let budget = DailyBudget::new(5.0);
+        assert!(budget.crossed(4.99).is_none());
+        assert!(budget.crossed(5.0).is_none());
+
+        let warning = budget.crossed(5.25).unwrap();
+        assert_eq!(warning, "⚠ daily budget exceeded ($5.25 of $5.00)");
+        assert!(budget.crossed(7.0).is_none());
+    }
+
+    fn costed_line(day: &str) -> String {
+        let event = McpEvent {
+            timestamp: format!("{day}T12:00:00Z"),
+            token_usage: crate::models::TokenUsage {
+                model: Some("claude-sonnet-4".to_string()),
+                input_tokens: Some(1_000_000),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        format!("{}\n", serde_json::to_string(&event).unwrap())
+    }
+
+    #[test]
+    fn tally_reads_only_new_complete_lines() {
+        use std::io::Write;
+        let dir = tempfile::tempdir().unwrap();
+        let ledger = dir.path().join("events.jsonl");
+        let today = crate::cli::parse_date("today");
+        let line = costed_line(&today);
+        let one = crate::view::fmt::event_cost_usd(&serde_json::from_str(&line).unwrap()).unwrap();
+        std::fs::write(&ledger, format!("{line}{}", costed_line("2000-01-01"))).unwrap();
+
+        let tally = advance(None, &ledger).unwrap();
+        assert!((tally.spent_usd - one).abs() < 1e-9);
+
+        let mut file = std::fs::OpenOptions::new()
+            .append(true)
+            .open(&ledger)
+            .unwrap();
+        file.write_all(line.as_bytes()).unwrap();
+        file.write_all(&line.as_bytes()[..20]).unwrap();
+        let tally = advance(Some(tally), &ledger).unwrap();
+        assert!((tally.spent_usd - 2.0 * one).abs() < 1e-9);
+
+        file.write_all(&line.as_bytes()[20..]).unwrap();
+        let tally = advance(Some(tally), &ledger).unwrap();
+        assert!((tally.spent_usd - 3.0 * one).abs() < 1e-9);
+        assert_eq!(tally.offset, std::fs::metadata(&ledger).unwrap().len());
+    }
+
+    #[test]
+    fn invalid_budget_is_ignored() {
+        let config = |v: &str| HashMap::from([("DAILY_BUDGET_USD".to_string(), v.to_string())]);
+        assert!(DailyBudget::from_config(&config("abc")).is_none());
+        assert!(DailyBudget::from_config(&config("-1")).is_none());
+        assert_eq!(
+            DailyBudget::from_config(&config("2.5")).unwrap().limit_usd,
+            2.5
+        );
+    }
+}
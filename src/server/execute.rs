@@ -7,42 +7,199 @@ use std::time::Instant;
 use uuid::Uuid;
 
 const JSONRPC_INTERNAL_ERROR: i32 = -32603;
+/// Server-defined: the tool exists but the policy or the supervisor refused it.
+const JSONRPC_TOOL_BLOCKED: i32 = -32000;
 
 pub(super) async fn on_tool_call(
     msg: &serde_json::Value,
     ctx: &super::ServerContext,
+    progress: Option<&super::Progress>,
 ) -> serde_json::Value {
     let (tool, arguments) = parse_tool_call(msg);
-    let before_content = capture_before_content(&tool, &arguments).await;
+    if !ctx.tool_policy.permits(&tool) {
+        return on_blocked_call(msg, ctx, &tool, &arguments).await;
+    }
+    let before_content = if ctx.capture_diff {
+        capture_before_content(&tool, &arguments).await
+    } else {
+        None
+    };
+    let risk = Risk::classify(&tool);
 
-    let (exec, timed_out) = execute_with_timeout(&tool, &arguments, ctx.timeout_secs).await;
+    let dry_run = ctx.dry_run && matches!(risk, Risk::Write | Risk::Exec);
+    let mut refused = false;
+    let (exec, timed_out) = if dry_run {
+        ((Ok(dry_run_result(&tool, &arguments)), 0, None), false)
+    } else {
+        match await_approval(ctx, &tool, risk, &arguments).await {
+            Ok(()) => {
+                execute_with_timeout(
+                    &tool,
+                    &arguments,
+                    ctx.timeout_for(&tool),
+                    progress,
+                    &ctx.tool_limits,
+                )
+                .await
+            }
+            Err(refusal) => {
+                refused = true;
+                ((Err(refusal), 0, None), false)
+            }
+        }
+    };
     let duration_us = exec.1;
     let is_error = exec.0.is_err();
-    let risk = Risk::classify(&tool);
-    let diff = compute_write_diff(&tool, &arguments, &before_content, exec.0.is_ok());
+    let exit_code = exec.2;
+    let flagged = ctx.danger.flag_call(&tool, &arguments);
+    let content_hash = content_hash(&tool, &arguments, &exec.0);
+    let diff = if ctx.capture_diff {
+        compute_write_diff(
+            &tool,
+            &arguments,
+            &before_content,
+            exec.0.is_ok(),
+            ctx.max_diff_bytes,
+        )
+    } else {
+        None
+    };
 
-    let (outcome, response) = build_response(msg, exec.0);
+    let (outcome, response) = build_response(msg, exec.0, refused.then_some("approval"));
+    let outcome = structured_outcome(&tool, &arguments, outcome);
     super::log_event(&tool, risk, duration_us, is_error);
 
-    match encrypt_for_ledger(ctx.encryption_key.as_ref(), &arguments, &outcome, &diff) {
+    let event_id = Uuid::new_v4();
+    let spill_key = ctx
+        .encryption_key
+        .as_ref()
+        .filter(|_| ctx.encrypt_fields.result);
+    let (outcome, overflow) = ctx
+        .overflow
+        .spill(&tool, &event_id, &outcome, spill_key)
+        .await;
+
+    let event = McpEvent {
+        id: event_id,
+        tool: tool.to_string(),
+        duration_us,
+        risk,
+        overflow,
+        timed_out,
+        dry_run,
+        exit_code,
+        flagged,
+        content_hash,
+        ..Default::default()
+    };
+    append_to_ledger(ctx, event, &arguments, &outcome, &diff).await;
+    if let Some(budget) = &ctx.daily_budget {
+        budget.check(&ctx.ledger_path).await;
+    }
+
+    response
+}
+
+/// The digest `get_file_info` appended to its output as `<algo>: <hex>`.
+fn content_hash(
+    tool: &str,
+    arguments: &serde_json::Value,
+    result: &Result<String, String>,
+) -> Option<String> {
+    if tool != "get_file_info" {
+        return None;
+    }
+    let algo = arguments.get("hash")?.as_str()?;
+    let output = result.as_ref().ok()?;
+    let digest = output
+        .lines()
+        .rev()
+        .find_map(|l| l.strip_prefix(&format!("{algo}: ")))?;
+    Some(format!("{algo}:{digest}"))
+}
+
+/// A tool disabled by `TOOLS_ALLOW` / `TOOLS_DENY` never runs, but the attempt
+/// is still recorded so audits show what the agent tried.
+async fn on_blocked_call(
+    msg: &serde_json::Value,
+    ctx: &super::ServerContext,
+    tool: &str,
+    arguments: &serde_json::Value,
+) -> serde_json::Value {
+    let reason = format!("tool '{tool}' disabled by policy");
+    let risk = Risk::classify(tool);
+    super::log_event(tool, risk, 0, true);
+
+    let event = McpEvent {
+        id: Uuid::new_v4(),
+        tool: tool.to_string(),
+        risk,
+        flagged: ctx.danger.flag_call(tool, arguments),
+        ..Default::default()
+    };
+    let outcome = Outcome::Blocked {
+        reason: reason.clone(),
+    };
+    append_to_ledger(ctx, event, arguments, &outcome, &None).await;
+
+    serde_json::json!({
+        "jsonrpc": "2.0",
+        "id": msg["id"],
+        "error": blocked_error(&reason, "policy"),
+    })
+}
+
+/// `data.blocked` names what refused the call: `policy` or `approval`.
+fn blocked_error(reason: &str, blocked_by: &str) -> serde_json::Value {
+    serde_json::json!({
+        "code": JSONRPC_TOOL_BLOCKED,
+        "message": reason,
+        "data": { "blocked": blocked_by },
+    })
+}
+
+/// Fills in the session fields, encrypts arguments/outcome/diff when a key is
+/// set, and appends the event. Failures are reported, never propagated.
+async fn append_to_ledger(
+    ctx: &super::ServerContext,
+    event: McpEvent,
+    arguments: &serde_json::Value,
+    outcome: &Outcome,
+    diff: &Option<String>,
+) {
+    match encrypt_for_ledger(
+        ctx.encryption_key.as_ref(),
+        ctx.encrypt_fields,
+        arguments,
+        outcome,
+        diff,
+    ) {
         Ok((ledger_arguments, ledger_outcome, ledger_diff)) => {
-            let project = resolve_project(&arguments, &ctx.project_root, &ctx.project_name).await;
+            let dir = tool_dir(arguments, ctx.project_root.as_deref());
+            let project =
+                resolve_project(dir.as_deref(), &ctx.project_root, &ctx.project_name).await;
+            let cwd = dir.or_else(|| {
+                std::env::current_dir()
+                    .ok()
+                    .map(|d| d.to_string_lossy().into_owned())
+            });
+            let Ok(cwd) = crypto::encrypt_cwd(ctx.encryption_key.as_ref(), ctx.encrypt_fields, cwd)
+            else {
+                eprintln!("[vigilo] encryption failed, skipping ledger write");
+                return;
+            };
 
             let event = McpEvent {
-                id: Uuid::new_v4(),
                 timestamp: Utc::now().to_rfc3339(),
                 session_id: ctx.session_id,
                 server: "vigilo".to_string(),
-                tool: tool.to_string(),
                 arguments: ledger_arguments,
                 outcome: ledger_outcome,
-                duration_us,
-                risk,
                 project,
                 tag: ctx.tag.clone(),
                 diff: ledger_diff,
-                timed_out,
-                ..Default::default()
+                cwd,
+                ..event
             };
 
             if let Err(e) = ledger::append_event(&event, &ctx.ledger_path) {
@@ -55,8 +212,6 @@ pub(super) async fn on_tool_call(
             eprintln!("[vigilo] encryption failed, skipping ledger write: {e}");
         }
     }
-
-    response
 }
 
 fn parse_tool_call(msg: &serde_json::Value) -> (String, serde_json::Value) {
@@ -74,24 +229,73 @@ fn parse_tool_call(msg: &serde_json::Value) -> (String, serde_json::Value) {
     (tool, arguments)
 }
 
+/// Stand-in result for a write/exec call skipped by `DRY_RUN`.
+fn dry_run_result(tool: &str, arguments: &serde_json::Value) -> String {
+    let arg = |key| arguments.get(key).and_then(|v| v.as_str()).unwrap_or("?");
+    match tool {
+        "write_file" | "append_file" => {
+            let bytes = arguments
+                .get("content")
+                .and_then(|v| v.as_str())
+                .map_or(0, str::len);
+            format!("[dry-run] would write {bytes} bytes to {}", arg("path"))
+        }
+        "run_command" => format!("[dry-run] would run: {}", arg("command")),
+        _ => format!("[dry-run] would call {tool}"),
+    }
+}
+
+async fn await_approval(
+    ctx: &super::ServerContext,
+    tool: &str,
+    risk: Risk,
+    arguments: &serde_json::Value,
+) -> Result<(), String> {
+    let gated = matches!(risk, Risk::Write | Risk::Exec);
+    let Some(gate) = ctx.approval.as_ref().filter(|_| gated) else {
+        return Ok(());
+    };
+    // The request sits on disk next to the ledger, so it's sealed the same way.
+    let arguments = match ctx.encryption_key.as_ref() {
+        Some(key) if ctx.encrypt_fields.arguments => crypto::encrypt(key, &arguments.to_string())
+            .map(serde_json::Value::String)
+            .map_err(|_| format!("{tool} denied: could not encrypt the approval request"))?,
+        _ => arguments.clone(),
+    };
+    let id = Uuid::new_v4().to_string();
+    match gate.request(&id, tool, &arguments).await {
+        super::approval::Decision::Approved => Ok(()),
+        super::approval::Decision::Denied => Err(format!("{tool} denied by supervisor")),
+        super::approval::Decision::TimedOut => Err(format!(
+            "{tool} denied: no approval within {}s",
+            gate.timeout.as_secs()
+        )),
+    }
+}
+
 async fn execute_with_timeout(
     tool: &str,
     arguments: &serde_json::Value,
     timeout_secs: u64,
-) -> ((Result<String, String>, u64), bool) {
+    progress: Option<&super::Progress>,
+    limits: &super::tools::ToolLimits,
+) -> ((Result<String, String>, u64, Option<i32>), bool) {
     let started = Instant::now();
     let timeout_dur = std::time::Duration::from_secs(timeout_secs);
-    let (exec, timed_out) =
-        match tokio::time::timeout(timeout_dur, super::tools::execute(tool, arguments)).await {
-            Ok(result) => (result, false),
-            Err(_) => (Err(format!("{tool} timed out after {timeout_secs}s")), true),
-        };
+    let run = super::tools::execute_with_progress(tool, arguments, progress, limits);
+    let ((exec, exit_code), timed_out) = match tokio::time::timeout(timeout_dur, run).await {
+        Ok(result) => (result, false),
+        Err(_) => (
+            (Err(format!("{tool} timed out after {timeout_secs}s")), None),
+            true,
+        ),
+    };
     let duration_us = started.elapsed().as_micros() as u64;
-    ((exec, duration_us), timed_out)
+    ((exec, duration_us, exit_code), timed_out)
 }
 
 async fn capture_before_content(tool: &str, arguments: &serde_json::Value) -> Option<String> {
-    if tool != "write_file" {
+    if !matches!(tool, "write_file" | "append_file") {
         return None;
     }
     let path = arguments.get("path").and_then(|v| v.as_str())?;
@@ -103,23 +307,31 @@ fn compute_write_diff(
     arguments: &serde_json::Value,
     before_content: &Option<String>,
     success: bool,
+    max_bytes: usize,
 ) -> Option<String> {
-    if tool != "write_file" || !success {
+    if !matches!(tool, "write_file" | "append_file") || !success {
         return None;
     }
-    let after = arguments
+    let content = arguments
         .get("content")
         .and_then(|v| v.as_str())
         .unwrap_or("");
     match before_content {
-        Some(before) => crate::hook_helpers::compute_unified_diff(before, after),
+        Some(before) if tool == "append_file" => crate::hook_helpers::compute_unified_diff(
+            before,
+            &format!("{before}{content}"),
+            max_bytes,
+        ),
+        Some(before) => crate::hook_helpers::compute_unified_diff(before, content, max_bytes),
         None => Some("new file".to_string()),
     }
 }
 
+/// `blocked_by` is set when the call was refused rather than failed.
 fn build_response(
     msg: &serde_json::Value,
     exec: Result<String, String>,
+    blocked_by: Option<&str>,
 ) -> (Outcome, serde_json::Value) {
     match exec {
         Ok(text) => (
@@ -132,50 +344,91 @@ fn build_response(
                 "result": { "content": [{ "type": "text", "text": text }] },
             }),
         ),
-        Err(e) => (
-            Outcome::Err {
-                code: JSONRPC_INTERNAL_ERROR,
-                message: e.clone(),
-            },
-            serde_json::json!({
-                "jsonrpc": "2.0",
-                "id": msg["id"],
-                "error": { "code": JSONRPC_INTERNAL_ERROR, "message": e },
-            }),
-        ),
+        Err(e) => {
+            let (code, error) = match blocked_by {
+                Some(by) => (JSONRPC_TOOL_BLOCKED, blocked_error(&e, by)),
+                None => (
+                    JSONRPC_INTERNAL_ERROR,
+                    serde_json::json!({ "code": JSONRPC_INTERNAL_ERROR, "message": e }),
+                ),
+            };
+            (
+                Outcome::Err { code, message: e },
+                serde_json::json!({
+                    "jsonrpc": "2.0",
+                    "id": msg["id"],
+                    "error": error,
+                }),
+            )
+        }
+    }
+}
+
+/// `run_command` with `parse_json: true` records JSON stdout as a value rather
+/// than a string; output that doesn't parse is kept as-is.
+fn structured_outcome(tool: &str, arguments: &serde_json::Value, outcome: Outcome) -> Outcome {
+    let wants_json = tool == "run_command"
+        && arguments
+            .get("parse_json")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+    match outcome {
+        Outcome::Ok { result } if wants_json => {
+            let parsed = result
+                .as_str()
+                .and_then(|text| serde_json::from_str(text).ok())
+                .unwrap_or(result);
+            Outcome::Ok { result: parsed }
+        }
+        other => other,
     }
 }
 
 fn encrypt_for_ledger(
     encryption_key: Option<&crypto::EncryptionKey>,
+    fields: crypto::EncryptFields,
     arguments: &serde_json::Value,
     outcome: &Outcome,
     diff: &Option<String>,
 ) -> Result<(serde_json::Value, Outcome, Option<String>), aes_gcm::Error> {
-    crypto::encrypt_for_ledger(encryption_key, arguments, outcome, diff)
+    crypto::encrypt_for_ledger(encryption_key, fields, arguments, outcome, diff)
+}
+
+/// The directory a call acted in: an explicit `cwd`, else a `path` argument
+/// (its parent when it isn't a directory). Relative paths are resolved
+/// against the project root, or the server's directory without one. A path
+/// whose parent doesn't exist gives nothing rather than a guess.
+fn tool_dir(arguments: &serde_json::Value, project_root: Option<&str>) -> Option<String> {
+    let resolve = |key: &str| {
+        let raw = std::path::Path::new(arguments.get(key)?.as_str()?);
+        if raw.is_absolute() {
+            return Some(raw.to_path_buf());
+        }
+        let base = match project_root {
+            Some(root) => std::path::PathBuf::from(root),
+            None => std::env::current_dir().ok()?,
+        };
+        Some(base.join(raw))
+    };
+    let dir = match resolve("cwd") {
+        Some(cwd) => cwd,
+        None => {
+            let path = resolve("path")?;
+            if path.is_dir() {
+                path
+            } else {
+                path.parent().filter(|d| d.is_dir())?.to_path_buf()
+            }
+        }
+    };
+    Some(dir.to_string_lossy().into_owned())
 }
 
 async fn resolve_project(
-    arguments: &serde_json::Value,
+    git_dir: Option<&str>,
     project_root: &Option<String>,
     project_name: &Option<String>,
 ) -> ProjectContext {
-    let tool_dir: Option<String> = arguments
-        .get("path")
-        .or_else(|| arguments.get("cwd"))
-        .and_then(|v| v.as_str())
-        .map(|p| {
-            let path = std::path::Path::new(p);
-            if path.is_dir() {
-                p.to_string()
-            } else {
-                path.parent()
-                    .and_then(|d| d.to_str())
-                    .unwrap_or(p)
-                    .to_string()
-            }
-        });
-    let git_dir = tool_dir.as_deref();
     let (branch, commit, dirty) = match git_dir {
         Some(d) => tokio::join!(git::branch_in(d), git::commit_in(d), git::dirty_in(d),),
         None => tokio::join!(git::branch(), git::commit(), git::dirty()),
@@ -196,11 +449,19 @@ async fn resolve_project(
 
 #[cfg(test)]
 mod tests {
-    use super::super::tools::{arg_str, execute};
+    use super::super::tools::{
+        apply_patch, arg_str, cap_output, read_capped, retarget_headers, search, SearchOptions,
+        ToolLimits,
+    };
+    use super::tool_dir;
     use crate::models::Risk;
     use serde_json::json;
     use tempfile::tempdir;
 
+    async fn execute(tool: &str, args: &serde_json::Value) -> Result<String, String> {
+        super::super::tools::execute(tool, args, &ToolLimits::default()).await
+    }
+
     #[test]
     fn arg_str_returns_value() {
         let args = json!({ "path": "some/file.txt" });
@@ -221,15 +482,21 @@ mod tests {
         assert_eq!(Risk::classify("git_status"), Risk::Read);
         assert_eq!(Risk::classify("git_diff"), Risk::Read);
         assert_eq!(Risk::classify("git_log"), Risk::Read);
+        assert_eq!(Risk::classify("git_branch"), Risk::Read);
+        assert_eq!(Risk::classify("git_blame"), Risk::Read);
     }
 
     #[test]
     fn risk_classify_write_tools() {
         assert_eq!(Risk::classify("write_file"), Risk::Write);
+        assert_eq!(Risk::classify("append_file"), Risk::Write);
         assert_eq!(Risk::classify("create_directory"), Risk::Write);
         assert_eq!(Risk::classify("delete_file"), Risk::Write);
         assert_eq!(Risk::classify("move_file"), Risk::Write);
+        assert_eq!(Risk::classify("copy_file"), Risk::Write);
         assert_eq!(Risk::classify("git_commit"), Risk::Write);
+        assert_eq!(Risk::classify("git_stash"), Risk::Write);
+        assert_eq!(Risk::classify("git_stash_pop"), Risk::Write);
         assert_eq!(Risk::classify("patch_file"), Risk::Write);
     }
 
@@ -248,6 +515,57 @@ mod tests {
         assert_eq!(result.unwrap(), "hello");
     }
 
+    #[tokio::test]
+    async fn execute_read_file_binary_needs_allow_binary() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("icon.bin");
+        tokio::fs::write(&path, [0xFF, 0xFE]).await.unwrap();
+        let path = path.to_str().unwrap();
+
+        let refused = execute("read_file", &json!({ "path": path })).await;
+        assert!(refused.unwrap_err().contains("UTF-8"));
+
+        let result = execute("read_file", &json!({ "path": path, "allow_binary": true }))
+            .await
+            .unwrap();
+        let payload: serde_json::Value = serde_json::from_str(&result).unwrap();
+        assert_eq!(payload, json!({ "encoding": "base64", "data": "//4=" }));
+    }
+
+    #[tokio::test]
+    async fn execute_read_file_max_bytes_reads_head_or_tail() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("app.log");
+        tokio::fs::write(&path, "first line\nlast line\n")
+            .await
+            .unwrap();
+        let path = path.to_str().unwrap();
+
+        let head = execute("read_file", &json!({ "path": path, "max_bytes": 5 })).await;
+        assert_eq!(head.unwrap(), "first");
+
+        let tail = execute(
+            "read_file",
+            &json!({ "path": path, "max_bytes": 10, "from_end": true }),
+        )
+        .await;
+        assert_eq!(tail.unwrap(), "last line\n");
+
+        let whole = execute(
+            "read_file",
+            &json!({ "path": path, "max_bytes": 1000, "from_end": true }),
+        )
+        .await;
+        assert_eq!(whole.unwrap(), "first line\nlast line\n");
+
+        let mixed = execute(
+            "read_file",
+            &json!({ "path": path, "max_bytes": 5, "start_line": 2 }),
+        )
+        .await;
+        assert!(mixed.unwrap_err().contains("can't be combined"));
+    }
+
     #[tokio::test]
     async fn execute_write_file_creates_and_writes() {
         let dir = tempdir().unwrap();
@@ -263,6 +581,33 @@ mod tests {
         assert_eq!(tokio::fs::read_to_string(&path).await.unwrap(), "world");
     }
 
+    #[tokio::test]
+    async fn execute_append_file_keeps_existing_content() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("log.txt");
+        tokio::fs::write(&path, "one\n").await.unwrap();
+
+        let result = execute(
+            "append_file",
+            &json!({ "path": path.to_str().unwrap(), "content": "two\n" }),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(result, format!("appended 4 bytes to {}", path.display()));
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "one\ntwo\n");
+    }
+
+    #[test]
+    fn append_diff_shows_only_appended_lines() {
+        let args = json!({ "content": "two\n" });
+        let diff =
+            super::compute_write_diff("append_file", &args, &Some("one\n".into()), true, 10_000)
+                .unwrap();
+        assert!(diff.contains("+two"));
+        assert!(!diff.contains("-one"));
+    }
+
     #[tokio::test]
     async fn execute_list_directory_returns_sorted_names() {
         let dir = tempdir().unwrap();
@@ -348,6 +693,85 @@ mod tests {
         assert!(to.exists());
     }
 
+    #[tokio::test]
+    async fn execute_move_file_refuses_existing_destination() {
+        let dir = tempdir().unwrap();
+        let from = dir.path().join("old.txt");
+        let to = dir.path().join("new.txt");
+        tokio::fs::write(&from, "content").await.unwrap();
+        tokio::fs::write(&to, "keep me").await.unwrap();
+
+        let result = execute(
+            "move_file",
+            &json!({ "from": from.to_str().unwrap(), "to": to.to_str().unwrap() }),
+        )
+        .await;
+
+        assert!(result.unwrap_err().contains("destination exists"));
+        assert!(from.exists());
+        assert_eq!(tokio::fs::read_to_string(&to).await.unwrap(), "keep me");
+    }
+
+    #[tokio::test]
+    async fn execute_move_file_overwrite_replaces_destination() {
+        let dir = tempdir().unwrap();
+        let from = dir.path().join("old.txt");
+        let to = dir.path().join("new.txt");
+        tokio::fs::write(&from, "content").await.unwrap();
+        tokio::fs::write(&to, "stale").await.unwrap();
+
+        execute(
+            "move_file",
+            &json!({
+                "from": from.to_str().unwrap(),
+                "to": to.to_str().unwrap(),
+                "overwrite": true,
+            }),
+        )
+        .await
+        .unwrap();
+
+        assert!(!from.exists());
+        assert_eq!(tokio::fs::read_to_string(&to).await.unwrap(), "content");
+    }
+
+    #[tokio::test]
+    async fn execute_copy_file_keeps_source() {
+        let dir = tempdir().unwrap();
+        let from = dir.path().join("a.txt");
+        let to = dir.path().join("b.txt");
+        tokio::fs::write(&from, "content").await.unwrap();
+
+        execute(
+            "copy_file",
+            &json!({ "from": from.to_str().unwrap(), "to": to.to_str().unwrap() }),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(tokio::fs::read_to_string(&from).await.unwrap(), "content");
+        assert_eq!(tokio::fs::read_to_string(&to).await.unwrap(), "content");
+    }
+
+    #[tokio::test]
+    async fn execute_copy_file_refuses_existing_destination_unless_overwrite() {
+        let dir = tempdir().unwrap();
+        let from = dir.path().join("a.txt");
+        let to = dir.path().join("b.txt");
+        tokio::fs::write(&from, "content").await.unwrap();
+        tokio::fs::write(&to, "keep me").await.unwrap();
+        let args = json!({ "from": from.to_str().unwrap(), "to": to.to_str().unwrap() });
+
+        let result = execute("copy_file", &args).await;
+        assert!(result.unwrap_err().contains("destination exists"));
+        assert_eq!(tokio::fs::read_to_string(&to).await.unwrap(), "keep me");
+
+        let mut args = args;
+        args["overwrite"] = json!(true);
+        execute("copy_file", &args).await.unwrap();
+        assert_eq!(tokio::fs::read_to_string(&to).await.unwrap(), "content");
+    }
+
     #[tokio::test]
     async fn execute_search_files_finds_matches() {
         let dir = tempdir().unwrap();
@@ -420,6 +844,38 @@ mod tests {
         assert!(result.contains("5 bytes"));
     }
 
+    #[tokio::test]
+    async fn execute_get_file_info_appends_sha256() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("info.txt");
+        tokio::fs::write(&path, "hello").await.unwrap();
+
+        let result = execute(
+            "get_file_info",
+            &json!({ "path": path.to_str().unwrap(), "hash": "sha256" }),
+        )
+        .await
+        .unwrap();
+
+        assert!(result
+            .contains("sha256: 2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824"));
+    }
+
+    #[tokio::test]
+    async fn execute_get_file_info_unsupported_hash_returns_err() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("info.txt");
+        tokio::fs::write(&path, "hello").await.unwrap();
+
+        let result = execute(
+            "get_file_info",
+            &json!({ "path": path.to_str().unwrap(), "hash": "md5" }),
+        )
+        .await;
+
+        assert!(result.unwrap_err().contains("unsupported hash 'md5'"));
+    }
+
     #[tokio::test]
     async fn execute_get_file_info_on_directory() {
         let dir = tempdir().unwrap();
@@ -446,6 +902,97 @@ mod tests {
             .ends_with(dir.path().file_name().unwrap().to_str().unwrap()));
     }
 
+    #[tokio::test]
+    async fn execute_run_command_passes_env() {
+        let result = execute(
+            "run_command",
+            &json!({ "command": "echo $VIGILO_TEST_ENV", "env": { "VIGILO_TEST_ENV": "test" } }),
+        )
+        .await
+        .unwrap();
+        assert_eq!(result.trim(), "test");
+    }
+
+    #[tokio::test]
+    async fn execute_run_command_pipes_stdin() {
+        let result = execute(
+            "run_command",
+            &json!({ "command": "sort", "stdin": "world\nhello" }),
+        )
+        .await
+        .unwrap();
+        assert_eq!(result, "hello\nworld\n");
+    }
+
+    #[tokio::test]
+    async fn execute_run_command_large_stdin_does_not_deadlock() {
+        let input = "x".repeat(1 << 20);
+        let result = tokio::time::timeout(
+            std::time::Duration::from_secs(10),
+            execute("run_command", &json!({ "command": "cat", "stdin": input })),
+        )
+        .await
+        .expect("run_command deadlocked")
+        .unwrap();
+        assert_eq!(result.len(), 1 << 20);
+    }
+
+    #[test]
+    fn cap_output_truncates_past_cap() {
+        assert_eq!(cap_output(b"short", 16), "short");
+        assert_eq!(
+            cap_output(b"0123456789", 4),
+            "0123\n... (output truncated at 4 bytes)"
+        );
+    }
+
+    #[tokio::test]
+    async fn execute_run_command_truncates_large_output() {
+        let result = execute(
+            "run_command",
+            &json!({ "command": "head -c 2000000 /dev/zero | tr '\\0' x" }),
+        )
+        .await
+        .unwrap();
+        assert!(result.ends_with("... (output truncated at 1048576 bytes)"));
+        assert!(result.len() < 1_048_576 + 64);
+    }
+
+    #[tokio::test]
+    async fn run_command_output_cap_comes_from_config() {
+        let config = [("MAX_OUTPUT_BYTES".to_string(), "8".to_string())].into();
+        let limits = ToolLimits::from_config(&config);
+        let result = super::super::tools::execute(
+            "run_command",
+            &json!({ "command": "echo 0123456789abcdef" }),
+            &limits,
+        )
+        .await
+        .unwrap();
+        assert_eq!(result, "01234567\n... (output truncated at 8 bytes)");
+    }
+
+    #[tokio::test]
+    async fn read_capped_drains_but_keeps_one_byte_past_the_cap() {
+        let big = vec![b'x'; 100_000];
+        let kept = read_capped(Some(&big[..]), 10, None).await.unwrap();
+        assert_eq!(kept.len(), 11);
+        assert!(cap_output(&kept, 10).ends_with("... (output truncated at 10 bytes)"));
+
+        let small = read_capped(Some(&b"short\n"[..]), 10, None).await.unwrap();
+        assert_eq!(cap_output(&small, 10), "short\n");
+    }
+
+    #[tokio::test]
+    async fn execute_run_command_rejects_non_string_env() {
+        let result = execute(
+            "run_command",
+            &json!({ "command": "true", "env": { "PORT": 8080 } }),
+        )
+        .await;
+        assert!(result.unwrap_err().contains("PORT"));
+    }
+
     #[test]
     fn tool_names_match_vigilo_tools() {
         use super::super::tools::TOOL_NAMES;
@@ -480,4 +1027,400 @@ mod tests {
         assert!(result.contains("text.txt"));
         assert!(!result.contains("binary.bin"));
     }
+
+    #[tokio::test]
+    async fn execute_search_files_sniffs_nul_past_first_block() {
+        let dir = tempdir().unwrap();
+        let mut bytes = b"findme\n".to_vec();
+        bytes.extend(std::iter::repeat_n(b'a', 4_000));
+        bytes.push(0);
+        std::fs::write(dir.path().join("late.bin"), bytes).unwrap();
+        std::fs::write(dir.path().join("text.txt"), "findme").unwrap();
+
+        let result = execute(
+            "search_files",
+            &json!({ "path": dir.path().to_str().unwrap(), "pattern": "findme" }),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(result.lines().count(), 1);
+        assert!(result.contains("text.txt"));
+    }
+
+    #[tokio::test]
+    async fn search_deep_tree_with_single_handle_finds_every_match() {
+        let dir = tempdir().unwrap();
+        let mut level = dir.path().to_path_buf();
+        for depth in 0..8 {
+            level = level.join(format!("d{depth}"));
+            std::fs::create_dir_all(&level).unwrap();
+            for f in 0..3 {
+                std::fs::write(level.join(format!("f{f}.txt")), "needle\nhay\n").unwrap();
+            }
+        }
+        let root = dir.path().to_str().unwrap();
+
+        let opts = |handles| SearchOptions {
+            open_handles: std::sync::Arc::new(tokio::sync::Semaphore::new(handles)),
+            ..Default::default()
+        };
+
+        let bounded = search(root, "needle", &opts(1)).await.unwrap();
+        let unbounded = search(root, "needle", &opts(64)).await.unwrap();
+        assert_eq!(bounded.lines().count(), 24);
+        assert_eq!(bounded, unbounded);
+    }
+
+    #[tokio::test]
+    async fn search_waits_while_other_calls_hold_every_handle() {
+        let dir = tempdir().unwrap();
+        std::fs::write(dir.path().join("a.txt"), "needle\n").unwrap();
+        let root = dir.path().to_str().unwrap().to_string();
+        let handles = std::sync::Arc::new(tokio::sync::Semaphore::new(2));
+        let held = handles.clone().acquire_many_owned(2).await.unwrap();
+
+        let opts = SearchOptions {
+            open_handles: handles,
+            ..Default::default()
+        };
+        let waiting = tokio::spawn(async move { search(&root, "needle", &opts).await });
+        tokio::time::sleep(std::time::Duration::from_millis(200)).await;
+        assert!(
+            !waiting.is_finished(),
+            "search ran past a full handle budget"
+        );
+
+        drop(held);
+        let found = tokio::time::timeout(std::time::Duration::from_secs(5), waiting)
+            .await
+            .unwrap()
+            .unwrap()
+            .unwrap();
+        assert!(found.contains("a.txt:1: needle"));
+    }
+
+    #[tokio::test]
+    async fn execute_search_files_skips_gitignored_paths() {
+        let dir = tempdir().unwrap();
+        std::fs::write(dir.path().join(".gitignore"), "generated/\n").unwrap();
+        std::fs::create_dir(dir.path().join("generated")).unwrap();
+        std::fs::write(dir.path().join("generated/out.txt"), "needle").unwrap();
+        std::fs::write(dir.path().join("src.txt"), "needle").unwrap();
+        let root = dir.path().to_str().unwrap();
+
+        let result = execute(
+            "search_files",
+            &json!({ "path": root, "pattern": "needle" }),
+        )
+        .await
+        .unwrap();
+        assert_eq!(result.lines().count(), 1);
+        assert!(result.contains("src.txt"));
+
+        let all = execute(
+            "search_files",
+            &json!({ "path": root, "pattern": "needle", "include_ignored": true }),
+        )
+        .await
+        .unwrap();
+        assert!(all.contains("generated"));
+    }
+
+    #[tokio::test]
+    async fn search_stops_at_max_results() {
+        let dir = tempdir().unwrap();
+        std::fs::write(dir.path().join("a.txt"), "needle\n".repeat(10)).unwrap();
+        let root = dir.path().to_str().unwrap();
+
+        let result = execute(
+            "search_files",
+            &json!({ "path": root, "pattern": "needle", "max_results": 3 }),
+        )
+        .await
+        .unwrap();
+        let lines: Vec<&str> = result.lines().collect();
+        assert_eq!(lines.len(), 4);
+        assert_eq!(lines[3], "... (truncated, 3+ matches)");
+
+        let exact = execute(
+            "search_files",
+            &json!({ "path": root, "pattern": "needle", "max_results": 10 }),
+        )
+        .await
+        .unwrap();
+        assert_eq!(exact.lines().count(), 10);
+        assert!(!exact.contains("truncated"));
+    }
+
+    #[tokio::test]
+    async fn search_skips_files_over_size_threshold() {
+        let dir = tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("big.txt"),
+            format!("needle\n{}", "x".repeat(64)),
+        )
+        .unwrap();
+        std::fs::write(dir.path().join("small.txt"), "needle\n").unwrap();
+        let root = dir.path().to_str().unwrap();
+        let opts = SearchOptions {
+            max_file_bytes: 32,
+            ..Default::default()
+        };
+
+        let result = search(root, "needle", &opts).await.unwrap();
+        assert!(result.contains("small.txt"));
+        assert!(!result.contains("big.txt"));
+    }
+
+    fn git(dir: &std::path::Path, args: &[&str]) {
+        let status = std::process::Command::new("git")
+            .args(args)
+            .current_dir(dir)
+            .output()
+            .unwrap()
+            .status;
+        assert!(status.success(), "git {args:?} failed");
+    }
+
+    fn git_stdout(dir: &std::path::Path, args: &[&str]) -> String {
+        let out = std::process::Command::new("git")
+            .args(args)
+            .current_dir(dir)
+            .output()
+            .unwrap();
+        String::from_utf8(out.stdout).unwrap()
+    }
+
+    fn init_repo(dir: &std::path::Path) {
+        git(dir, &["init", "-q"]);
+        git(dir, &["config", "user.email", "t@example.com"]);
+        git(dir, &["config", "user.name", "t"]);
+    }
+
+    #[tokio::test]
+    async fn git_stash_hides_changes_and_pop_restores_them() {
+        let dir = tempdir().unwrap();
+        let file = dir.path().join("a.txt");
+        init_repo(dir.path());
+        std::fs::write(&file, "v1\n").unwrap();
+        git(dir.path(), &["add", "-A"]);
+        git(dir.path(), &["commit", "-qm", "init"]);
+        std::fs::write(&file, "v2\n").unwrap();
+        let path = dir.path().to_str().unwrap();
+
+        let stashed = execute("git_stash", &json!({ "path": path, "message": "wip" }))
+            .await
+            .unwrap();
+        assert!(stashed.starts_with("stash@{0}"), "{stashed}");
+        assert!(stashed.contains("wip"));
+        assert_eq!(std::fs::read_to_string(&file).unwrap(), "v1\n");
+
+        let popped = execute("git_stash_pop", &json!({ "path": path }))
+            .await
+            .unwrap();
+        assert!(popped.contains("stash@{0}"), "{popped}");
+        assert_eq!(std::fs::read_to_string(&file).unwrap(), "v2\n");
+
+        let empty = execute("git_stash_pop", &json!({ "path": path })).await;
+        assert!(empty.is_err());
+
+        git(dir.path(), &["checkout", "-q", "--", "a.txt"]);
+        let nothing = execute("git_stash", &json!({ "path": path }))
+            .await
+            .unwrap();
+        assert_eq!(nothing, "no local changes to stash");
+    }
+
+    #[tokio::test]
+    async fn git_commit_sets_author_and_signoff() {
+        let dir = tempdir().unwrap();
+        init_repo(dir.path());
+        std::fs::write(dir.path().join("a.txt"), "v1\n").unwrap();
+        let path = dir.path().to_str().unwrap();
+
+        execute(
+            "git_commit",
+            &json!({
+                "path": path,
+                "message": "add a",
+                "author": "Ada Lovelace <ada@example.com>",
+                "signoff": true,
+            }),
+        )
+        .await
+        .unwrap();
+        let log = git_stdout(dir.path(), &["log", "-1", "--format=%an <%ae>%n%b"]);
+        let mut lines = log.lines();
+        assert_eq!(lines.next(), Some("Ada Lovelace <ada@example.com>"));
+        assert_eq!(lines.next(), Some("Signed-off-by: t <t@example.com>"));
+    }
+
+    #[tokio::test]
+    async fn git_diff_between_refs() {
+        let dir = tempdir().unwrap();
+        let file = dir.path().join("a.txt");
+        init_repo(dir.path());
+        std::fs::write(&file, "v1\n").unwrap();
+        git(dir.path(), &["add", "-A"]);
+        git(dir.path(), &["commit", "-qm", "one"]);
+        std::fs::write(&file, "v2\n").unwrap();
+        git(dir.path(), &["commit", "-qam", "two"]);
+        std::fs::write(&file, "v3\n").unwrap();
+        let path = dir.path().to_str().unwrap();
+
+        let between = execute(
+            "git_diff",
+            &json!({ "path": path, "from": "HEAD~1", "to": "HEAD" }),
+        )
+        .await
+        .unwrap();
+        assert!(between.contains("-v1\n+v2"), "{between}");
+
+        let working = execute("git_diff", &json!({ "path": path })).await.unwrap();
+        assert!(working.contains("-v2\n+v3"), "{working}");
+
+        let injected = execute("git_diff", &json!({ "path": path, "from": "--output=x" })).await;
+        assert!(injected.is_err());
+    }
+
+    #[tokio::test]
+    async fn git_branch_lists_branches_and_marks_current() {
+        let dir = tempdir().unwrap();
+        init_repo(dir.path());
+        std::fs::write(dir.path().join("a.txt"), "v1\n").unwrap();
+        git(dir.path(), &["add", "-A"]);
+        git(dir.path(), &["commit", "-qm", "init"]);
+        git(dir.path(), &["branch", "-M", "main"]);
+        git(dir.path(), &["branch", "feature"]);
+        let path = dir.path().to_str().unwrap();
+
+        let branches = execute("git_branch", &json!({ "path": path }))
+            .await
+            .unwrap();
+        let lines: Vec<&str> = branches.lines().collect();
+        assert_eq!(lines, ["  feature", "* main"]);
+    }
+
+    #[tokio::test]
+    async fn git_blame_reports_committing_author() {
+        let dir = tempdir().unwrap();
+        let file = dir.path().join("a.txt");
+        init_repo(dir.path());
+        std::fs::write(&file, "one\ntwo\nthree\n").unwrap();
+        git(dir.path(), &["add", "-A"]);
+        git(
+            dir.path(),
+            &[
+                "commit",
+                "-qm",
+                "init",
+                "--author",
+                "Ada Lovelace <ada@example.com>",
+            ],
+        );
+        let path = file.to_str().unwrap();
+
+        let blame = execute(
+            "git_blame",
+            &json!({ "path": path, "start_line": 2, "end_line": 3 }),
+        )
+        .await
+        .unwrap();
+        let lines: Vec<&str> = blame.lines().collect();
+        assert_eq!(lines.len(), 2, "{blame}");
+        assert!(lines[0].ends_with(" Ada Lovelace 2: two"), "{blame}");
+        assert!(lines[1].ends_with(" Ada Lovelace 3: three"), "{blame}");
+    }
+
+    #[tokio::test]
+    async fn patch_falls_back_to_git_apply_without_patch_binary() {
+        let dir = tempdir().unwrap();
+        init_repo(dir.path());
+        std::fs::create_dir(dir.path().join("src")).unwrap();
+        let file = dir.path().join("src").join("a.txt");
+        std::fs::write(&file, "one\ntwo\nthree\n").unwrap();
+        let diff = "--- a.txt.orig\n+++ a.txt\n@@ -1,3 +1,3 @@\n one\n-two\n+TWO\n three\n";
+
+        let result = apply_patch(file.to_str().unwrap(), diff, "vigilo-no-such-patch")
+            .await
+            .unwrap();
+        assert!(result.ends_with("(git apply)"), "{result}");
+        assert_eq!(std::fs::read_to_string(&file).unwrap(), "one\nTWO\nthree\n");
+
+        let stale = apply_patch(file.to_str().unwrap(), diff, "vigilo-no-such-patch").await;
+        assert!(stale.unwrap_err().contains("git apply:"));
+    }
+
+    #[test]
+    fn retarget_headers_leaves_hunk_bodies_and_crlf_alone() {
+        let diff = "--- old/a.txt\r\n+++ new/a.txt\r\n@@ -1,2 +1,2 @@\r\n--- dashes\r\n+++ pluses\r\n keep\r\n";
+        assert_eq!(
+            retarget_headers(diff, "a.txt"),
+            "--- a/a.txt\r\n+++ b/a.txt\r\n@@ -1,2 +1,2 @@\r\n--- dashes\r\n+++ pluses\r\n keep\r\n"
+        );
+
+        let two = "--- x\n+++ y\n@@ -1 +1 @@\n-a\n+b\n--- x\n+++ y\n@@ -3 +3 @@\n-c\n+d";
+        assert_eq!(
+            retarget_headers(two, "f"),
+            "--- a/f\n+++ b/f\n@@ -1 +1 @@\n-a\n+b\n--- a/f\n+++ b/f\n@@ -3 +3 @@\n-c\n+d\n"
+        );
+    }
+
+    #[tokio::test]
+    async fn git_apply_keeps_removed_lines_that_look_like_headers() {
+        let dir = tempdir().unwrap();
+        init_repo(dir.path());
+        let file = dir.path().join("notes.md");
+        std::fs::write(&file, "title\n-- signature\nend\n").unwrap();
+        let diff = "--- notes.md\n+++ notes.md\n@@ -1,3 +1,2 @@\n title\n--- signature\n end\n";
+
+        let result = apply_patch(file.to_str().unwrap(), diff, "vigilo-no-such-patch")
+            .await
+            .unwrap();
+        assert!(result.ends_with("(git apply)"), "{result}");
+        assert_eq!(std::fs::read_to_string(&file).unwrap(), "title\nend\n");
+    }
+
+    #[tokio::test]
+    async fn rejected_patch_does_not_fall_back_or_touch_the_file() {
+        if std::process::Command::new("patch")
+            .arg("--version")
+            .output()
+            .is_err()
+        {
+            return;
+        }
+        let dir = tempdir().unwrap();
+        let file = dir.path().join("a.txt");
+        std::fs::write(&file, "one\ntwo\nthree\nfour\nfive\nsix\nseven\n").unwrap();
+        let diff = "--- a.txt\n+++ a.txt\n@@ -1,2 +1,2 @@\n-one\n+ONE\n two\n@@ -6,2 +6,2 @@\n-nope\n+NOPE\n seven\n";
+
+        let err = apply_patch(file.to_str().unwrap(), diff, "patch")
+            .await
+            .unwrap_err();
+        assert!(!err.contains("git apply:"), "{err}");
+        assert_eq!(
+            std::fs::read_to_string(&file).unwrap(),
+            "one\ntwo\nthree\nfour\nfive\nsix\nseven\n"
+        );
+    }
+
+    #[test]
+    fn tool_dir_prefers_cwd_and_resolves_relative_paths() {
+        let dir = tempdir().unwrap();
+        let root = dir.path().to_str().unwrap();
+        std::fs::create_dir(dir.path().join("src")).unwrap();
+        std::fs::write(dir.path().join("src/lib.rs"), "").unwrap();
+        let src = dir.path().join("src").to_string_lossy().into_owned();
+
+        let both = json!({ "cwd": "src", "path": "/elsewhere/file.rs" });
+        assert_eq!(tool_dir(&both, Some(root)), Some(src.clone()));
+        let file = json!({ "path": "src/lib.rs" });
+        assert_eq!(tool_dir(&file, Some(root)), Some(src.clone()));
+        let new_file = json!({ "path": "src/new.rs" });
+        assert_eq!(tool_dir(&new_file, Some(root)), Some(src));
+        let orphan = json!({ "path": "missing/dir/new.rs" });
+        assert_eq!(tool_dir(&orphan, Some(root)), None);
+    }
 }
@@ -0,0 +1,211 @@
+use std::path::{Path, PathBuf};
+
+/// One pattern line from a `.gitignore`, compiled to a regex over paths
+/// relative to the directory that holds the file. A rule from above the
+/// search root is based at the root, with `prefix` the root's path below the
+/// file's directory.
+#[derive(Clone)]
+pub(super) struct IgnoreRule {
+    base: PathBuf,
+    prefix: String,
+    re: regex::Regex,
+    negate: bool,
+    dir_only: bool,
+    anchored: bool,
+}
+
+/// Reads `<dir>/.gitignore`; a missing or unreadable file yields no rules.
+pub(super) async fn load_rules(dir: &Path) -> Vec<IgnoreRule> {
+    match tokio::fs::read_to_string(dir.join(".gitignore")).await {
+        Ok(text) => parse(dir, &text),
+        Err(_) => Vec::new(),
+    }
+}
+
+/// Rules from the `.gitignore` files between the repository root (the nearest
+/// ancestor holding `.git`) and `root`'s parent, outermost first. Outside a
+/// repository, or at its root, there are none.
+pub(super) async fn load_ancestor_rules(root: &Path) -> Vec<IgnoreRule> {
+    let is_repo_root = |dir: &Path| std::fs::exists(dir.join(".git")).unwrap_or(false);
+    let Ok(abs) = std::path::absolute(root) else {
+        return Vec::new();
+    };
+    if is_repo_root(&abs) {
+        return Vec::new();
+    }
+    let Some(top) = abs.ancestors().skip(1).position(is_repo_root) else {
+        return Vec::new();
+    };
+    let mut rules = Vec::new();
+    for dir in abs
+        .ancestors()
+        .skip(1)
+        .take(top + 1)
+        .collect::<Vec<_>>()
+        .into_iter()
+        .rev()
+    {
+        let prefix = abs
+            .strip_prefix(dir)
+            .map(|p| p.to_string_lossy().replace('\\', "/"))
+            .unwrap_or_default();
+        rules.extend(load_rules(dir).await.into_iter().map(|rule| IgnoreRule {
+            base: root.to_path_buf(),
+            prefix: prefix.clone(),
+            ..rule
+        }));
+    }
+    rules
+}
+
+pub(super) fn parse(base: &Path, text: &str) -> Vec<IgnoreRule> {
+    text.lines()
+        .filter_map(|line| parse_line(base, line))
+        .collect()
+}
+
+fn parse_line(base: &Path, line: &str) -> Option<IgnoreRule> {
+    let line = line.trim_end();
+    if line.is_empty() || line.starts_with('#') {
+        return None;
+    }
+    let (negate, pattern) = match line.strip_prefix('!') {
+        Some(rest) => (true, rest),
+        None => (false, line.strip_prefix('\\').unwrap_or(line)),
+    };
+    let (dir_only, pattern) = match pattern.strip_suffix('/') {
+        Some(rest) => (true, rest),
+        None => (false, pattern),
+    };
+    let anchored = pattern.contains('/');
+    let pattern = pattern.strip_prefix('/').unwrap_or(pattern);
+    if pattern.is_empty() {
+        return None;
+    }
+    let re = regex::Regex::new(&glob_to_regex(pattern)).ok()?;
+    Some(IgnoreRule {
+        base: base.to_path_buf(),
+        prefix: String::new(),
+        re,
+        negate,
+        dir_only,
+        anchored,
+    })
+}
+
+fn glob_to_regex(glob: &str) -> String {
+    let mut out = String::from("^");
+    let mut chars = glob.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '*' if chars.peek() == Some(&'*') => {
+                chars.next();
+                if chars.peek() == Some(&'/') {
+                    chars.next();
+                    out.push_str("(?:.*/)?");
+                } else {
+                    out.push_str(".*");
+                }
+            }
+            '*' => out.push_str("[^/]*"),
+            '?' => out.push_str("[^/]"),
+            '[' => {
+                let class: String = chars.by_ref().take_while(|&c| c != ']').collect();
+                out.push('[');
+                match class.strip_prefix('!') {
+                    Some(rest) => {
+                        out.push('^');
+                        out.push_str(rest);
+                    }
+                    None => out.push_str(&class),
+                }
+                out.push(']');
+            }
+            c => out.push_str(&regex::escape(&c.to_string())),
+        }
+    }
+    out.push('$');
+    out
+}
+
+/// Last matching rule wins, so a later `!pattern` re-includes a path.
+pub(super) fn is_ignored(rules: &[IgnoreRule], path: &Path, is_dir: bool) -> bool {
+    let mut ignored = false;
+    for rule in rules {
+        if rule.dir_only && !is_dir {
+            continue;
+        }
+        let Ok(rel) = path.strip_prefix(&rule.base) else {
+            continue;
+        };
+        let candidate = if rule.anchored {
+            let rel = rel.to_string_lossy().replace('\\', "/");
+            match rule.prefix.as_str() {
+                "" => rel,
+                prefix => format!("{prefix}/{rel}"),
+            }
+        } else {
+            path.file_name()
+                .map(|n| n.to_string_lossy().into_owned())
+                .unwrap_or_default()
+        };
+        if rule.re.is_match(&candidate) {
+            ignored = !rule.negate;
+        }
+    }
+    ignored
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ignored(text: &str, rel: &str, is_dir: bool) -> bool {
+        let base = Path::new("/repo");
+        is_ignored(&parse(base, text), &base.join(rel), is_dir)
+    }
+
+    #[test]
+    fn unanchored_pattern_matches_name_at_any_depth() {
+        assert!(ignored("*.log\n", "a/b/debug.log", false));
+        assert!(!ignored("*.log\n", "a/b/debug.txt", false));
+    }
+
+    #[test]
+    fn anchored_and_dir_only_patterns() {
+        assert!(ignored("/generated/\n", "generated", true));
+        assert!(!ignored("/generated/\n", "src/generated", true));
+        assert!(!ignored("generated/\n", "generated", false));
+        assert!(ignored("docs/**/*.tmp\n", "docs/a/b/x.tmp", false));
+    }
+
+    #[tokio::test]
+    async fn rules_above_the_root_apply_up_to_the_repository() {
+        let dir = tempfile::tempdir().unwrap();
+        let repo = dir.path().join("repo");
+        let sub = repo.join("pkg/sub");
+        std::fs::create_dir_all(repo.join(".git")).unwrap();
+        std::fs::create_dir_all(&sub).unwrap();
+        std::fs::write(dir.path().join(".gitignore"), "*.rs\n").unwrap();
+        std::fs::write(repo.join(".gitignore"), "*.log\n/pkg/sub/out/\n").unwrap();
+        std::fs::write(repo.join("pkg/.gitignore"), "!keep.log\n").unwrap();
+
+        let rules = load_ancestor_rules(&sub).await;
+        assert!(is_ignored(&rules, &sub.join("debug.log"), false));
+        assert!(!is_ignored(&rules, &sub.join("keep.log"), false));
+        assert!(is_ignored(&rules, &sub.join("out"), true));
+        assert!(!is_ignored(&rules, &sub.join("lib/out"), true));
+        // above the repository root
+        assert!(!is_ignored(&rules, &sub.join("main.rs"), false));
+
+        assert!(load_ancestor_rules(&repo).await.is_empty());
+        assert!(load_ancestor_rules(dir.path()).await.is_empty());
+    }
+
+    #[test]
+    fn negation_re_includes_path() {
+        let text = "# build output\n*.log\n!keep.log\n";
+        assert!(ignored(text, "other.log", false));
+        assert!(!ignored(text, "keep.log", false));
+    }
+}
@@ -0,0 +1,161 @@
+use crate::models::Outcome;
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+const DEFAULT_CAP_BYTES: usize = 65_536;
+
+/// Keeps oversized `search_files` results out of the ledger: the full text goes
+/// to `<dir>/<event-id>.txt` and the event keeps a preview plus that path.
+/// With a key the file holds the result encrypted, like the ledger would.
+pub(crate) struct OverflowStore {
+    pub dir: PathBuf,
+    pub cap_bytes: usize,
+}
+
+impl OverflowStore {
+    /// `SEARCH_RESULT_CAP_BYTES` (default 64 KiB) bounds what stays inline.
+    pub(super) fn from_config(config: &HashMap<String, String>) -> Self {
+        let cap_bytes = crate::models::config_setting(config, "SEARCH_RESULT_CAP_BYTES")
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(DEFAULT_CAP_BYTES);
+        Self {
+            dir: crate::models::vigilo_dir().join("overflow"),
+            cap_bytes,
+        }
+    }
+
+    /// Returns the outcome to record in the ledger and, when the result was
+    /// spilled, the overflow file path.
+    pub(super) async fn spill(
+        &self,
+        tool: &str,
+        event_id: &uuid::Uuid,
+        outcome: &Outcome,
+        key: Option<&crate::crypto::EncryptionKey>,
+    ) -> (Outcome, Option<String>) {
+        let text = match outcome {
+            Outcome::Ok { result } if tool == "search_files" => result.as_str().unwrap_or(""),
+            _ => return (outcome.clone(), None),
+        };
+        if text.len() <= self.cap_bytes {
+            return (outcome.clone(), None);
+        }
+        let path = self.dir.join(format!("{event_id}.txt"));
+        let stored = match key.map(|k| crate::crypto::encrypt(k, text)) {
+            None => text.to_string(),
+            Some(Ok(ciphertext)) => ciphertext,
+            Some(Err(e)) => {
+                eprintln!("[vigilo] overflow encryption failed, keeping result inline: {e}");
+                return (outcome.clone(), None);
+            }
+        };
+        if let Err(e) = write_private(&path, &stored).await {
+            eprintln!("[vigilo] overflow write failed, keeping result inline: {e}");
+            return (outcome.clone(), None);
+        }
+        let mut end = self.cap_bytes;
+        while !text.is_char_boundary(end) {
+            end -= 1;
+        }
+        let omitted = text.len() - end;
+        let preview = format!(
+            "{}\n\n[result truncated — {omitted} bytes in {}]",
+            &text[..end],
+            path.display()
+        );
+        (
+            Outcome::Ok {
+                result: serde_json::json!(preview),
+            },
+            Some(path.to_string_lossy().into_owned()),
+        )
+    }
+}
+
+async fn write_private(path: &std::path::Path, text: &str) -> std::io::Result<()> {
+    use tokio::io::AsyncWriteExt;
+    if let Some(parent) = path.parent() {
+        tokio::fs::create_dir_all(parent).await?;
+    }
+    let mut options = tokio::fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    options.mode(0o600);
+    let mut file = options.open(path).await?;
+    file.write_all(text.as_bytes()).await?;
+    file.flush().await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ok(text: &str) -> Outcome {
+        Outcome::Ok {
+            result: serde_json::json!(text),
+        }
+    }
+
+    #[tokio::test]
+    async fn large_search_result_spills_to_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = OverflowStore {
+            dir: dir.path().to_path_buf(),
+            cap_bytes: 16,
+        };
+        let id = uuid::Uuid::new_v4();
+        let full = "src/a.rs:1: match\n".repeat(10);
+
+        let (outcome, path) = store.spill("search_files", &id, &ok(&full), None).await;
+        let path = path.unwrap();
+        assert!(path.ends_with(&format!("{id}.txt")));
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), full);
+        let Outcome::Ok { result } = outcome else {
+            panic!("expected ok outcome");
+        };
+        let preview = result.as_str().unwrap();
+        assert!(preview.starts_with("src/a.rs:1: matc"));
+        assert!(preview.contains(&path));
+    }
+
+    #[tokio::test]
+    async fn encrypted_spill_never_holds_plaintext() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = OverflowStore {
+            dir: dir.path().to_path_buf(),
+            cap_bytes: 16,
+        };
+        let key = crate::crypto::EncryptionKey::new([9u8; 32]);
+        let full = "src/secret.rs:1: api_key = 42\n".repeat(10);
+
+        let (_, path) = store
+            .spill(
+                "search_files",
+                &uuid::Uuid::new_v4(),
+                &ok(&full),
+                Some(&key),
+            )
+            .await;
+        let stored = std::fs::read_to_string(path.unwrap()).unwrap();
+        assert!(!stored.contains("api_key"));
+        assert_eq!(crate::crypto::decrypt(&key, &stored).unwrap(), full);
+    }
+
+    #[tokio::test]
+    async fn small_or_other_results_stay_inline() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = OverflowStore {
+            dir: dir.path().to_path_buf(),
+            cap_bytes: 16,
+        };
+        let id = uuid::Uuid::new_v4();
+
+        let (_, path) = store.spill("search_files", &id, &ok("short"), None).await;
+        assert!(path.is_none());
+        let (_, path) = store
+            .spill("read_file", &id, &ok(&"x".repeat(100)), None)
+            .await;
+        assert!(path.is_none());
+        assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 0);
+    }
+}
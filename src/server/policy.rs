@@ -0,0 +1,67 @@
+use std::collections::{HashMap, HashSet};
+
+/// Which tools the server will run. `TOOLS_ALLOW`, when set, limits calls to
+/// the listed tools; `TOOLS_DENY` always wins. Both are comma-separated.
+#[derive(Default)]
+pub(crate) struct ToolPolicy {
+    allow: Option<HashSet<String>>,
+    deny: HashSet<String>,
+}
+
+impl ToolPolicy {
+    pub(super) fn from_config(config: &HashMap<String, String>) -> Self {
+        let list = |key| crate::models::config_setting(config, key).map(|v| parse_list(&v));
+        Self {
+            allow: list("TOOLS_ALLOW"),
+            deny: list("TOOLS_DENY").unwrap_or_default(),
+        }
+    }
+
+    pub(super) fn permits(&self, tool: &str) -> bool {
+        !self.deny.contains(tool) && self.allow.as_ref().is_none_or(|a| a.contains(tool))
+    }
+
+    pub(super) fn is_restricted(&self) -> bool {
+        self.allow.is_some() || !self.deny.is_empty()
+    }
+}
+
+fn parse_list(value: &str) -> HashSet<String> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn policy(pairs: &[(&str, &str)]) -> ToolPolicy {
+        let config = pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        ToolPolicy::from_config(&config)
+    }
+
+    #[test]
+    fn default_policy_permits_everything() {
+        let p = policy(&[]);
+        assert!(p.permits("run_command"));
+        assert!(!p.is_restricted());
+    }
+
+    #[test]
+    fn deny_wins_over_allow() {
+        let p = policy(&[
+            ("TOOLS_ALLOW", "read_file, run_command"),
+            ("TOOLS_DENY", "run_command,delete_file"),
+        ]);
+        assert!(p.permits("read_file"));
+        assert!(!p.permits("run_command"));
+        assert!(!p.permits("write_file"));
+    }
+}
@@ -0,0 +1,88 @@
+use crate::view::data::{load_sessions_complete, LoadFilter};
+
+const JSONRPC_INVALID_PARAMS: i32 = -32602;
+const JSONRPC_INTERNAL_ERROR: i32 = -32603;
+
+const TODAY_URI: &str = "vigilo://ledger/today";
+const SESSION_URI: &str = "vigilo://ledger/session";
+
+pub(super) fn on_resources_list(msg: &serde_json::Value) -> serde_json::Value {
+    serde_json::json!({
+        "jsonrpc": "2.0",
+        "id": msg["id"],
+        "result": {
+            "resources": [
+                {
+                    "uri": TODAY_URI,
+                    "name": "Today's ledger",
+                    "description": "All events recorded today, across sessions",
+                    "mimeType": "application/json",
+                },
+                {
+                    "uri": SESSION_URI,
+                    "name": "Current session",
+                    "description": "Events recorded by this MCP session",
+                    "mimeType": "application/json",
+                },
+            ],
+        },
+    })
+}
+
+/// Events are returned as stored: encrypted arguments and results stay encrypted.
+/// The ledger is read off the async runtime; a ledger that can't be read is an
+/// internal error, not a bad request.
+pub(super) async fn on_resources_read(
+    msg: &serde_json::Value,
+    ctx: &super::ServerContext,
+) -> serde_json::Value {
+    let uri = msg["params"]["uri"].as_str().unwrap_or("");
+    let today = crate::cli::parse_date("today");
+    let session = ctx.session_id.to_string();
+    let (since, session) = match uri {
+        TODAY_URI => (Some(today), None),
+        SESSION_URI => (None, Some(session)),
+        _ => {
+            return error(
+                msg,
+                JSONRPC_INVALID_PARAMS,
+                &format!("unknown resource: {uri}"),
+            )
+        }
+    };
+    let ledger_path = ctx.ledger_path.clone();
+    let loaded = tokio::task::spawn_blocking(move || {
+        let filter = LoadFilter {
+            since: since.as_deref(),
+            until: since.as_deref(),
+            session: session.as_deref(),
+            ..Default::default()
+        };
+        load_sessions_complete(&ledger_path, &filter)
+    })
+    .await;
+    let events: Vec<_> = match loaded {
+        Ok(Ok(sessions)) => sessions
+            .into_iter()
+            .flat_map(|(_, events)| events)
+            .collect(),
+        Ok(Err(e)) => return error(msg, JSONRPC_INTERNAL_ERROR, &e.to_string()),
+        Err(e) => return error(msg, JSONRPC_INTERNAL_ERROR, &e.to_string()),
+    };
+    let text = serde_json::to_string(&events).unwrap_or_else(|_| "[]".to_string());
+    serde_json::json!({
+        "jsonrpc": "2.0",
+        "id": msg["id"],
+        "result": {
+            "contents": [{ "uri": uri, "mimeType": "application/json", "text": text }],
+        },
+    })
+}
+
+fn error(msg: &serde_json::Value, code: i32, message: &str) -> serde_json::Value {
+    serde_json::json!({
+        "jsonrpc": "2.0",
+        "id": msg["id"],
+        "error": { "code": code, "message": message },
+    })
+}
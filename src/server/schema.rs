@@ -20,13 +20,16 @@ fn read_tools() -> Vec<serde_json::Value> {
     vec![
         serde_json::json!({
             "name": "read_file",
-            "description": "Read the contents of a file, optionally limited to a line range",
+            "description": "Read the contents of a file, optionally limited to a line range or byte count",
             "inputSchema": {
                 "type": "object",
                 "properties": {
                     "path": { "type": "string" },
                     "start_line": { "type": "number", "description": "First line to read (1-indexed, inclusive)" },
                     "end_line": { "type": "number", "description": "Last line to read (1-indexed, inclusive)" },
+                    "max_bytes": { "type": "number", "description": "Return at most this many bytes instead of whole lines; not with start_line / end_line" },
+                    "from_end": { "type": "boolean", "description": "With max_bytes, read the last bytes of the file (like tail -c)" },
+                    "allow_binary": { "type": "boolean", "description": "Return non-UTF-8 files as {\"encoding\":\"base64\",\"data\":...} instead of failing" },
                 },
                 "required": ["path"],
             },
@@ -57,6 +60,18 @@ fn write_tools() -> Vec<serde_json::Value> {
                 "required": ["path", "content"],
             },
         }),
+        serde_json::json!({
+            "name": "append_file",
+            "description": "Append content to the end of a file, creating it if it does not exist",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "path": { "type": "string" },
+                    "content": { "type": "string" },
+                },
+                "required": ["path", "content"],
+            },
+        }),
         serde_json::json!({
             "name": "create_directory",
             "description": "Create a directory and any missing parent directories",
@@ -88,6 +103,20 @@ fn search_info_tools() -> Vec<serde_json::Value> {
                 "properties": {
                     "from": { "type": "string" },
                     "to": { "type": "string" },
+                    "overwrite": { "type": "boolean", "description": "Replace the destination if it exists (default: false)" },
+                },
+                "required": ["from", "to"],
+            },
+        }),
+        serde_json::json!({
+            "name": "copy_file",
+            "description": "Copy a file to a new path",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "from": { "type": "string" },
+                    "to": { "type": "string" },
+                    "overwrite": { "type": "boolean", "description": "Replace the destination if it exists (default: false)" },
                 },
                 "required": ["from", "to"],
             },
@@ -101,16 +130,21 @@ fn search_info_tools() -> Vec<serde_json::Value> {
                     "path": { "type": "string" },
                     "pattern": { "type": "string" },
                     "regex": { "type": "boolean", "description": "Treat pattern as a regular expression" },
+                    "max_results": { "type": "integer", "description": "Stop after this many matching lines (default: 1000)" },
+                    "include_ignored": { "type": "boolean", "description": "Also search hidden and .gitignore'd paths (default: false)" },
                 },
                 "required": ["path", "pattern"],
             },
         }),
         serde_json::json!({
             "name": "get_file_info",
-            "description": "Get metadata for a file or directory (size, type, modified time)",
+            "description": "Get metadata for a file or directory (size, type, modified time), optionally with a content hash",
             "inputSchema": {
                 "type": "object",
-                "properties": { "path": { "type": "string" } },
+                "properties": {
+                    "path": { "type": "string" },
+                    "hash": { "type": "string", "enum": ["sha256", "blake3"], "description": "Append a digest of the file contents" },
+                },
                 "required": ["path"],
             },
         }),
@@ -138,6 +172,13 @@ fn command_tools() -> Vec<serde_json::Value> {
             "properties": {
                 "command": { "type": "string" },
                 "cwd": { "type": "string" },
+                "env": {
+                    "type": "object",
+                    "additionalProperties": { "type": "string" },
+                    "description": "Extra environment variables for the command",
+                },
+                "stdin": { "type": "string", "description": "Data piped to the command's standard input" },
+                "parse_json": { "type": "boolean", "description": "Record JSON stdout as a structured result in the ledger" },
             },
             "required": ["command"],
         },
@@ -157,12 +198,14 @@ fn git_tools() -> Vec<serde_json::Value> {
         }),
         serde_json::json!({
             "name": "git_diff",
-            "description": "Show unstaged changes in a git repository",
+            "description": "Show unstaged changes in a git repository, or the diff between two refs",
             "inputSchema": {
                 "type": "object",
                 "properties": {
                     "path": { "type": "string" },
                     "staged": { "type": "boolean" },
+                    "from": { "type": "string", "description": "Base ref; alone, diffs it against the working tree" },
+                    "to": { "type": "string", "description": "Target ref, diffed as from..to" },
                 },
                 "required": ["path"],
             },
@@ -179,6 +222,28 @@ fn git_tools() -> Vec<serde_json::Value> {
                 "required": ["path"],
             },
         }),
+        serde_json::json!({
+            "name": "git_branch",
+            "description": "List local and remote branches in a git repository, marking the current one",
+            "inputSchema": {
+                "type": "object",
+                "properties": { "path": { "type": "string" } },
+                "required": ["path"],
+            },
+        }),
+        serde_json::json!({
+            "name": "git_blame",
+            "description": "Show the commit and author of each line of a file, optionally limited to a line range",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "path": { "type": "string" },
+                    "start_line": { "type": "number" },
+                    "end_line": { "type": "number" },
+                },
+                "required": ["path"],
+            },
+        }),
         serde_json::json!({
             "name": "git_commit",
             "description": "Stage all changes and create a git commit with the given message",
@@ -187,9 +252,32 @@ fn git_tools() -> Vec<serde_json::Value> {
                 "properties": {
                     "path": { "type": "string" },
                     "message": { "type": "string" },
+                    "author": { "type": "string", "description": "Commit author as 'Name <email>'" },
+                    "signoff": { "type": "boolean", "description": "Add a Signed-off-by trailer" },
                 },
                 "required": ["path", "message"],
             },
         }),
+        serde_json::json!({
+            "name": "git_stash",
+            "description": "Stash uncommitted changes in a git repository and report the stash ref",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "path": { "type": "string" },
+                    "message": { "type": "string" },
+                },
+                "required": ["path"],
+            },
+        }),
+        serde_json::json!({
+            "name": "git_stash_pop",
+            "description": "Restore the most recent stash in a git repository",
+            "inputSchema": {
+                "type": "object",
+                "properties": { "path": { "type": "string" } },
+                "required": ["path"],
+            },
+        }),
     ]
 }
@@ -3,17 +3,23 @@
 pub(crate) const TOOL_NAMES: &[&str] = &[
     "read_file",
     "write_file",
+    "append_file",
     "list_directory",
     "create_directory",
     "delete_file",
     "move_file",
+    "copy_file",
     "search_files",
     "run_command",
     "get_file_info",
     "git_status",
     "git_diff",
     "git_log",
+    "git_branch",
+    "git_blame",
     "git_commit",
+    "git_stash",
+    "git_stash_pop",
     "patch_file",
 ];
 
@@ -23,31 +29,74 @@ pub(super) fn arg_str<'a>(args: &'a serde_json::Value, key: &str) -> Result<&'a
         .ok_or_else(|| format!("missing '{key}'"))
 }
 
-pub(super) async fn execute(tool: &str, args: &serde_json::Value) -> Result<String, String> {
+pub(super) async fn execute(
+    tool: &str,
+    args: &serde_json::Value,
+    limits: &ToolLimits,
+) -> Result<String, String> {
     match tool {
         "read_file" => execute_read_file(args).await,
         "write_file" => execute_write_file(args).await,
+        "append_file" => execute_append_file(args).await,
         "list_directory" => execute_list_directory(args).await,
         "create_directory" => execute_create_directory(args).await,
         "delete_file" => execute_delete_file(args).await,
         "move_file" => execute_move_file(args).await,
-        "search_files" => execute_search_files(args).await,
-        "run_command" => execute_run_command(args).await,
+        "copy_file" => execute_copy_file(args).await,
+        "search_files" => execute_search_files(args, limits).await,
+        "run_command" => run_command(args, None, limits).await.0,
         "get_file_info" => execute_get_file_info(args).await,
         "git_status" => execute_git_status(args).await,
         "git_diff" => execute_git_diff(args).await,
         "git_log" => execute_git_log(args).await,
+        "git_branch" => execute_git_branch(args).await,
+        "git_blame" => execute_git_blame(args).await,
         "git_commit" => execute_git_commit(args).await,
+        "git_stash" => execute_git_stash(args).await,
+        "git_stash_pop" => execute_git_stash_pop(args).await,
         "patch_file" => execute_patch_file(args).await,
         _ => Err(format!("unknown tool: {tool}")),
     }
 }
 
+/// Like [`execute`], but `run_command` reports its stdout through `progress`
+/// while it runs and hands back its exit code, which is `None` for every other
+/// tool and for a command that never exited normally (spawn failure, signal).
+pub(super) async fn execute_with_progress(
+    tool: &str,
+    args: &serde_json::Value,
+    progress: Option<&super::Progress>,
+    limits: &ToolLimits,
+) -> (Result<String, String>, Option<i32>) {
+    match tool {
+        "run_command" => run_command(args, progress, limits).await,
+        _ => (execute(tool, args, limits).await, None),
+    }
+}
+
 async fn execute_read_file(args: &serde_json::Value) -> Result<String, String> {
     let path = arg_str(args, "path")?;
-    let content = tokio::fs::read_to_string(path)
-        .await
-        .map_err(|e| e.to_string())?;
+    let from_end = args
+        .get("from_end")
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false);
+    let line_range = args.get("start_line").is_some() || args.get("end_line").is_some();
+    match args.get("max_bytes").and_then(|v| v.as_u64()) {
+        Some(_) if line_range => {
+            return Err("max_bytes can't be combined with start_line / end_line".to_string())
+        }
+        Some(max_bytes) => return read_byte_range(path, max_bytes, from_end).await,
+        None if from_end => return Err("from_end requires max_bytes".to_string()),
+        None => {}
+    }
+    let bytes = tokio::fs::read(path).await.map_err(|e| e.to_string())?;
+    let content = match String::from_utf8(bytes) {
+        Ok(text) => text,
+        Err(e) if args.get("allow_binary").and_then(|v| v.as_bool()) == Some(true) => {
+            return Ok(base64_payload(e.as_bytes()));
+        }
+        Err(_) => return Err("stream did not contain valid UTF-8".to_string()),
+    };
     let start = args.get("start_line").and_then(|v| v.as_u64()).unwrap_or(1) as usize;
     let end = args.get("end_line").and_then(|v| v.as_u64());
     if start == 1 && end.is_none() {
@@ -66,6 +115,33 @@ async fn execute_read_file(args: &serde_json::Value) -> Result<String, String> {
     Ok(selected.join("\n"))
 }
 
+fn base64_payload(bytes: &[u8]) -> String {
+    use base64::{engine::general_purpose::STANDARD, Engine};
+    serde_json::json!({ "encoding": "base64", "data": STANDARD.encode(bytes) }).to_string()
+}
+
+/// Reads at most `max_bytes` from the start (or, with `from_end`, the end) of
+/// the file without loading the rest.
+async fn read_byte_range(path: &str, max_bytes: u64, from_end: bool) -> Result<String, String> {
+    use tokio::io::{AsyncReadExt, AsyncSeekExt};
+    let mut file = tokio::fs::File::open(path)
+        .await
+        .map_err(|e| e.to_string())?;
+    if from_end {
+        let len = file.metadata().await.map_err(|e| e.to_string())?.len();
+        let offset = max_bytes.min(len) as i64;
+        file.seek(std::io::SeekFrom::End(-offset))
+            .await
+            .map_err(|e| e.to_string())?;
+    }
+    let mut buf = Vec::new();
+    file.take(max_bytes)
+        .read_to_end(&mut buf)
+        .await
+        .map_err(|e| e.to_string())?;
+    Ok(String::from_utf8_lossy(&buf).into_owned())
+}
+
 async fn execute_write_file(args: &serde_json::Value) -> Result<String, String> {
     let path = arg_str(args, "path")?;
     let content = arg_str(args, "content")?;
@@ -80,6 +156,25 @@ async fn execute_write_file(args: &serde_json::Value) -> Result<String, String>
     Ok(format!("wrote {} bytes to {path}", content.len()))
 }
 
+async fn execute_append_file(args: &serde_json::Value) -> Result<String, String> {
+    use tokio::io::AsyncWriteExt;
+    let path = arg_str(args, "path")?;
+    let content = arg_str(args, "content")?;
+    let mut file = tokio::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .await
+        .map_err(|e| e.to_string())?;
+    file.write_all(content.as_bytes())
+        .await
+        .map_err(|e| e.to_string())?;
+    // tokio hands the write to a blocking thread; flushing waits for it, so
+    // the file holds the content before the call is reported done
+    file.flush().await.map_err(|e| e.to_string())?;
+    Ok(format!("appended {} bytes to {path}", content.len()))
+}
+
 async fn execute_list_directory(args: &serde_json::Value) -> Result<String, String> {
     let path = arg_str(args, "path")?;
     let mut entries = tokio::fs::read_dir(path).await.map_err(|e| e.to_string())?;
@@ -110,45 +205,264 @@ async fn execute_delete_file(args: &serde_json::Value) -> Result<String, String>
 async fn execute_move_file(args: &serde_json::Value) -> Result<String, String> {
     let from = arg_str(args, "from")?;
     let to = arg_str(args, "to")?;
-    tokio::fs::rename(from, to)
-        .await
-        .map_err(|e| e.to_string())?;
+    let overwrite = args
+        .get("overwrite")
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false);
+    if !overwrite && tokio::fs::try_exists(to).await.unwrap_or(false) {
+        return Err(format!("destination exists: {to} (pass overwrite: true)"));
+    }
+    match tokio::fs::rename(from, to).await {
+        Ok(()) => {}
+        Err(e) if e.kind() == std::io::ErrorKind::CrossesDevices => {
+            copy_then_remove(from, to).await?;
+        }
+        Err(e) => return Err(e.to_string()),
+    }
     Ok(format!("moved {from} → {to}"))
 }
 
-async fn execute_search_files(args: &serde_json::Value) -> Result<String, String> {
+async fn execute_copy_file(args: &serde_json::Value) -> Result<String, String> {
+    let from = arg_str(args, "from")?;
+    let to = arg_str(args, "to")?;
+    let overwrite = args
+        .get("overwrite")
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false);
+    if !overwrite && tokio::fs::try_exists(to).await.unwrap_or(false) {
+        return Err(format!("destination exists: {to} (pass overwrite: true)"));
+    }
+    let bytes = tokio::fs::copy(from, to).await.map_err(|e| e.to_string())?;
+    Ok(format!("copied {from} → {to} ({bytes} bytes)"))
+}
+
+/// Fallback for renames across filesystems: copy the file, then remove the source.
+async fn copy_then_remove(from: &str, to: &str) -> Result<(), String> {
+    let meta = tokio::fs::metadata(from).await.map_err(|e| e.to_string())?;
+    if !meta.is_file() {
+        return Err(format!("cannot move directory across filesystems: {from}"));
+    }
+    tokio::fs::copy(from, to).await.map_err(|e| e.to_string())?;
+    tokio::fs::remove_file(from)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+async fn execute_search_files(
+    args: &serde_json::Value,
+    limits: &ToolLimits,
+) -> Result<String, String> {
     let path = arg_str(args, "path")?;
     let pattern = arg_str(args, "pattern")?;
-    let use_regex = args.get("regex").and_then(|v| v.as_bool()).unwrap_or(false);
-    search(path, pattern, use_regex).await
+    search(path, pattern, &SearchOptions::from_args(args, limits)).await
+}
+
+const DEFAULT_MAX_OUTPUT_BYTES: usize = 1_048_576;
+
+/// Tool limits read from the config once, when the server starts.
+pub(crate) struct ToolLimits {
+    /// Cap on captured `run_command` stdout/stderr.
+    pub max_output_bytes: usize,
+    /// `search_files` skips files larger than this.
+    pub search_max_file_bytes: u64,
+    /// Handle budget shared by every `search_files` call.
+    pub search_handles: std::sync::Arc<tokio::sync::Semaphore>,
+}
+
+impl Default for ToolLimits {
+    fn default() -> Self {
+        Self {
+            max_output_bytes: DEFAULT_MAX_OUTPUT_BYTES,
+            search_max_file_bytes: DEFAULT_MAX_FILE_BYTES,
+            search_handles: std::sync::Arc::new(tokio::sync::Semaphore::new(
+                DEFAULT_SEARCH_CONCURRENCY,
+            )),
+        }
+    }
+}
+
+impl ToolLimits {
+    /// `MAX_OUTPUT_BYTES` (default 1 MiB), `SEARCH_MAX_FILE_BYTES` and
+    /// `SEARCH_CONCURRENCY`.
+    pub(super) fn from_config(config: &std::collections::HashMap<String, String>) -> Self {
+        let setting = |key| {
+            crate::models::config_setting(config, key)
+                .and_then(|s| s.parse::<u64>().ok())
+                .filter(|&n| n > 0)
+        };
+        let mut limits = Self::default();
+        if let Some(n) = setting("MAX_OUTPUT_BYTES") {
+            limits.max_output_bytes = n as usize;
+        }
+        if let Some(n) = setting("SEARCH_MAX_FILE_BYTES") {
+            limits.search_max_file_bytes = n;
+        }
+        if let Some(n) = setting("SEARCH_CONCURRENCY") {
+            limits.search_handles = std::sync::Arc::new(tokio::sync::Semaphore::new(n as usize));
+        }
+        limits
+    }
 }
 
-const MAX_OUTPUT_BYTES: usize = 1_048_576;
+/// The command's result alongside its exit code.
+async fn run_command(
+    args: &serde_json::Value,
+    progress: Option<&super::Progress>,
+    limits: &ToolLimits,
+) -> (Result<String, String>, Option<i32>) {
+    let cap = limits.max_output_bytes;
+    match run_command_output(args, progress, cap).await {
+        Ok(output) => {
+            let code = output.status.code();
+            let result = if output.status.success() {
+                Ok(cap_output(&output.stdout, cap))
+            } else {
+                let status = match code {
+                    Some(code) => format!("exit {code}"),
+                    None => output.status.to_string(),
+                };
+                Err(format!("{status}\n{}", cap_output(&output.stderr, cap)))
+            };
+            (result, code)
+        }
+        Err(e) => (Err(e), None),
+    }
+}
 
-async fn execute_run_command(args: &serde_json::Value) -> Result<String, String> {
+async fn run_command_output(
+    args: &serde_json::Value,
+    progress: Option<&super::Progress>,
+    cap: usize,
+) -> Result<std::process::Output, String> {
     let command = arg_str(args, "command")?;
-    let mut cmd = tokio::process::Command::new("sh");
-    cmd.args(["-c", command]);
+    let mut cmd = shell_command(command);
     if let Some(cwd) = args.get("cwd").and_then(|v| v.as_str()) {
         cmd.current_dir(cwd);
     }
-    let output = cmd.output().await.map_err(|e| e.to_string())?;
-    let stderr = String::from_utf8_lossy(&output.stderr);
-    let exit_code = output.status.code().unwrap_or(-1);
-    if output.status.success() {
-        Ok(cap_output(&output.stdout))
+    if let Some(env) = args.get("env").and_then(|v| v.as_object()) {
+        for (k, v) in env {
+            let value = v
+                .as_str()
+                .ok_or_else(|| format!("env value for '{k}' must be a string"))?;
+            cmd.env(k, value);
+        }
+    }
+    let stdin = args.get("stdin").and_then(|v| v.as_str());
+    collect_output(cmd, stdin, progress, cap).await
+}
+
+/// `cmd.exe` parses its own command line, so on Windows the command is passed
+/// verbatim; the usual argv quoting would wrap it in quotes cmd doesn't strip.
+#[cfg(windows)]
+fn shell_command(command: &str) -> tokio::process::Command {
+    let mut cmd = tokio::process::Command::new("cmd");
+    cmd.arg("/C").raw_arg(command);
+    cmd
+}
+
+#[cfg(not(windows))]
+fn shell_command(command: &str) -> tokio::process::Command {
+    let mut cmd = tokio::process::Command::new("sh");
+    cmd.args(["-c", command]);
+    cmd
+}
+
+/// Runs the child like `Command::output`, but reads stdout and stderr as they
+/// arrive and keeps at most `cap` bytes of each, so a chatty command can't
+/// grow the server's memory. `input` is written alongside, so a payload larger
+/// than the pipe buffer can't deadlock against a full stdout. With `progress`,
+/// each stdout line is reported as it arrives.
+async fn collect_output(
+    mut cmd: tokio::process::Command,
+    input: Option<&str>,
+    progress: Option<&super::Progress>,
+    cap: usize,
+) -> Result<std::process::Output, String> {
+    let stdin = if input.is_some() {
+        std::process::Stdio::piped()
     } else {
-        Err(format!("exit {exit_code}\n{stderr}"))
+        std::process::Stdio::null()
+    };
+    cmd.stdin(stdin);
+    cmd.stdout(std::process::Stdio::piped());
+    cmd.stderr(std::process::Stdio::piped());
+    cmd.kill_on_drop(true);
+    let mut child = cmd.spawn().map_err(|e| e.to_string())?;
+    let write = feed_stdin(child.stdin.take(), input.unwrap_or_default());
+    let read_stdout = read_capped(child.stdout.take(), cap, progress);
+    let read_stderr = read_capped(child.stderr.take(), cap, None);
+    let (written, stdout, stderr) = tokio::join!(write, read_stdout, read_stderr);
+    written.map_err(|e| e.to_string())?;
+    let status = child.wait().await.map_err(|e| e.to_string())?;
+    Ok(std::process::Output {
+        status,
+        stdout: stdout.map_err(|e| e.to_string())?,
+        stderr: stderr.map_err(|e| e.to_string())?,
+    })
+}
+
+/// A child that stops reading early closes the pipe; that isn't an error.
+async fn feed_stdin(stdin: Option<tokio::process::ChildStdin>, input: &str) -> std::io::Result<()> {
+    use tokio::io::AsyncWriteExt;
+    if let Some(mut stdin) = stdin {
+        match stdin.write_all(input.as_bytes()).await {
+            Err(e) if e.kind() != std::io::ErrorKind::BrokenPipe => return Err(e),
+            _ => {}
+        }
     }
+    Ok(())
 }
 
-fn cap_output(bytes: &[u8]) -> String {
-    if bytes.len() <= MAX_OUTPUT_BYTES {
+/// Drains `pipe` to the end so the child never blocks on it, keeping the
+/// first `cap + 1` bytes: the one past the cap is how `cap_output` knows to
+/// say the output was cut. Reported lines are cut at `cap` too.
+pub(super) async fn read_capped(
+    pipe: Option<impl tokio::io::AsyncRead + Unpin>,
+    cap: usize,
+    progress: Option<&super::Progress>,
+) -> std::io::Result<Vec<u8>> {
+    use tokio::io::AsyncBufReadExt;
+    let mut kept = Vec::new();
+    let Some(pipe) = pipe else {
+        return Ok(kept);
+    };
+    let mut reader = tokio::io::BufReader::new(pipe);
+    let mut line = Vec::new();
+    loop {
+        let chunk = reader.fill_buf().await?;
+        if chunk.is_empty() {
+            break;
+        }
+        let room = (cap + 1).saturating_sub(kept.len());
+        kept.extend_from_slice(&chunk[..chunk.len().min(room)]);
+        if let Some(progress) = progress {
+            for piece in chunk.split_inclusive(|&b| b == b'\n') {
+                let room = cap.saturating_sub(line.len());
+                line.extend_from_slice(&piece[..piece.len().min(room)]);
+                if piece.ends_with(b"\n") {
+                    progress.report(String::from_utf8_lossy(&line).trim_end());
+                    line.clear();
+                }
+            }
+        }
+        let n = chunk.len();
+        reader.consume(n);
+    }
+    if let Some(progress) = progress {
+        if !line.is_empty() {
+            progress.report(String::from_utf8_lossy(&line).trim_end());
+        }
+        progress.flush();
+    }
+    Ok(kept)
+}
+
+pub(super) fn cap_output(bytes: &[u8], cap: usize) -> String {
+    if bytes.len() <= cap {
         return String::from_utf8_lossy(bytes).into_owned();
     }
-    let truncated = String::from_utf8_lossy(&bytes[..MAX_OUTPUT_BYTES]).into_owned();
-    let omitted = bytes.len() - MAX_OUTPUT_BYTES;
-    format!("{truncated}\n\n[output truncated — {omitted} bytes omitted]")
+    let truncated = String::from_utf8_lossy(&bytes[..cap]).into_owned();
+    format!("{truncated}\n... (output truncated at {cap} bytes)")
 }
 
 async fn execute_get_file_info(args: &serde_json::Value) -> Result<String, String> {
@@ -167,10 +481,60 @@ async fn execute_get_file_info(args: &serde_json::Value) -> Result<String, Strin
         .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
         .map(|d| d.as_secs().to_string())
         .unwrap_or_else(|| "unknown".to_string());
-    Ok(format!(
+    let mut info = format!(
         "path: {path}\ntype: {kind}\nsize: {} bytes\nmodified: {modified}",
         meta.len()
-    ))
+    );
+    if let Some(algo) = args.get("hash").and_then(|v| v.as_str()) {
+        if !meta.is_file() {
+            return Err(format!("cannot hash {kind}: {path}"));
+        }
+        let digest = hash_file(path, algo).await?;
+        info.push_str(&format!("\n{algo}: {digest}"));
+    }
+    Ok(info)
+}
+
+const HASH_CHUNK_BYTES: usize = 64 * 1024;
+
+async fn hash_file(path: &str, algo: &str) -> Result<String, String> {
+    use sha2::Digest;
+    use tokio::io::AsyncReadExt;
+
+    enum Hasher {
+        Sha256(sha2::Sha256),
+        Blake3(Box<blake3::Hasher>),
+    }
+    let mut hasher = match algo {
+        "sha256" => Hasher::Sha256(sha2::Sha256::new()),
+        "blake3" => Hasher::Blake3(Box::new(blake3::Hasher::new())),
+        other => {
+            return Err(format!(
+                "unsupported hash '{other}' (expected sha256 | blake3)"
+            ))
+        }
+    };
+
+    let mut file = tokio::fs::File::open(path)
+        .await
+        .map_err(|e| e.to_string())?;
+    let mut buf = vec![0u8; HASH_CHUNK_BYTES];
+    loop {
+        let n = file.read(&mut buf).await.map_err(|e| e.to_string())?;
+        if n == 0 {
+            break;
+        }
+        match &mut hasher {
+            Hasher::Sha256(h) => h.update(&buf[..n]),
+            Hasher::Blake3(h) => {
+                h.update(&buf[..n]);
+            }
+        }
+    }
+    Ok(match hasher {
+        Hasher::Sha256(h) => format!("{:x}", h.finalize()),
+        Hasher::Blake3(h) => h.finalize().to_hex().to_string(),
+    })
 }
 
 async fn execute_git_status(args: &serde_json::Value) -> Result<String, String> {
@@ -195,11 +559,30 @@ async fn execute_git_diff(args: &serde_json::Value) -> Result<String, String> {
         .get("staged")
         .and_then(|v| v.as_bool())
         .unwrap_or(false);
+    let from = args.get("from").and_then(|v| v.as_str());
+    let to = args.get("to").and_then(|v| v.as_str());
+    if let Some(r) = [from, to]
+        .into_iter()
+        .flatten()
+        .find(|r| r.starts_with('-'))
+    {
+        return Err(format!("invalid ref '{r}'"));
+    }
     let mut cmd = tokio::process::Command::new("git");
     cmd.arg("diff");
     if staged {
         cmd.arg("--staged");
     }
+    match (from, to) {
+        (Some(from), Some(to)) => {
+            cmd.arg(format!("{from}..{to}"));
+        }
+        (Some(from), None) => {
+            cmd.arg(from);
+        }
+        (None, Some(_)) => return Err("'to' requires 'from'".to_string()),
+        (None, None) => {}
+    }
     let out = cmd
         .current_dir(path)
         .output()
@@ -230,6 +613,79 @@ async fn execute_git_log(args: &serde_json::Value) -> Result<String, String> {
     })
 }
 
+/// Local and remote-tracking branches, one per line, the checked-out one
+/// prefixed with `* `.
+async fn execute_git_branch(args: &serde_json::Value) -> Result<String, String> {
+    let path = arg_str(args, "path")?;
+    let out = run_git(
+        path,
+        &["branch", "--all", "--format=%(HEAD) %(refname:short)"],
+    )
+    .await?;
+    let text = String::from_utf8_lossy(&out).into_owned();
+    Ok(if text.trim().is_empty() {
+        "no branches".to_string()
+    } else {
+        text
+    })
+}
+
+/// `git blame --line-porcelain` for a file, optionally limited to
+/// `start_line..=end_line`, summarised as `<commit> <author> <line>: <text>`.
+async fn execute_git_blame(args: &serde_json::Value) -> Result<String, String> {
+    let path = std::path::Path::new(arg_str(args, "path")?);
+    let dir = match path.parent() {
+        Some(d) if !d.as_os_str().is_empty() => d,
+        _ => std::path::Path::new("."),
+    };
+    let file = path
+        .file_name()
+        .and_then(|f| f.to_str())
+        .ok_or_else(|| format!("not a file: {}", path.display()))?;
+    let start = args.get("start_line").and_then(|v| v.as_u64());
+    let end = args.get("end_line").and_then(|v| v.as_u64());
+    let range = match (start, end) {
+        (None, None) => None,
+        (start, end) => Some(format!(
+            "{},{}",
+            start.unwrap_or(1),
+            end.map(|e| e.to_string()).unwrap_or_default()
+        )),
+    };
+    let mut blame_args = vec!["blame", "--line-porcelain"];
+    if let Some(range) = &range {
+        blame_args.extend(["-L", range]);
+    }
+    blame_args.extend(["--", file]);
+    let dir = dir.to_str().ok_or("path is not valid UTF-8")?;
+    let out = run_git(dir, &blame_args).await?;
+    Ok(blame_summary(&String::from_utf8_lossy(&out)))
+}
+
+/// One line per blamed line from `--line-porcelain` output: each record is a
+/// `<sha> <orig> <final> [<count>]` header, `key value` fields, then the
+/// tab-prefixed source line.
+fn blame_summary(porcelain: &str) -> String {
+    let mut out = String::new();
+    let (mut commit, mut line_no, mut author) = ("", "", "");
+    for line in porcelain.lines() {
+        if let Some(text) = line.strip_prefix('\t') {
+            out.push_str(&format!("{commit} {author} {line_no}: {text}\n"));
+        } else if let Some(name) = line.strip_prefix("author ") {
+            author = name;
+        } else {
+            let mut fields = line.split(' ');
+            if let (Some(sha), Some(_), Some(n)) = (fields.next(), fields.next(), fields.next()) {
+                if sha.len() >= 40 && sha.bytes().all(|b| b.is_ascii_hexdigit()) {
+                    commit = &sha[..8];
+                    line_no = n;
+                }
+            }
+        }
+    }
+    out
+}
+
 async fn execute_git_commit(args: &serde_json::Value) -> Result<String, String> {
     let path = arg_str(args, "path")?;
     let message = arg_str(args, "message")?;
@@ -242,8 +698,15 @@ async fn execute_git_commit(args: &serde_json::Value) -> Result<String, String>
     if !add.status.success() {
         return Err(String::from_utf8_lossy(&add.stderr).into_owned());
     }
+    let mut commit_args = vec!["commit", "-m", message];
+    if let Some(author) = args.get("author").and_then(|v| v.as_str()) {
+        commit_args.extend(["--author", author]);
+    }
+    if args.get("signoff").and_then(|v| v.as_bool()) == Some(true) {
+        commit_args.push("-s");
+    }
     let out = tokio::process::Command::new("git")
-        .args(["commit", "-m", message])
+        .args(&commit_args)
         .current_dir(path)
         .output()
         .await
@@ -255,61 +718,286 @@ async fn execute_git_commit(args: &serde_json::Value) -> Result<String, String>
     }
 }
 
+/// Whether anything was stashed is read from `refs/stash` rather than git's
+/// (translatable) messages.
+async fn execute_git_stash(args: &serde_json::Value) -> Result<String, String> {
+    let path = arg_str(args, "path")?;
+    let mut stash_args = vec!["stash", "push"];
+    if let Some(message) = args.get("message").and_then(|v| v.as_str()) {
+        stash_args.extend(["-m", message]);
+    }
+    let before = stash_head(path).await;
+    run_git(path, &stash_args).await?;
+    if stash_head(path).await == before {
+        return Ok("no local changes to stash".to_string());
+    }
+    top_stash(path).await
+}
+
+async fn execute_git_stash_pop(args: &serde_json::Value) -> Result<String, String> {
+    let path = arg_str(args, "path")?;
+    let top = top_stash(path).await?;
+    run_git(path, &["stash", "pop"]).await?;
+    Ok(format!("popped {top}"))
+}
+
+async fn stash_head(path: &str) -> Option<Vec<u8>> {
+    run_git(path, &["rev-parse", "-q", "--verify", "refs/stash"])
+        .await
+        .ok()
+}
+
+/// The newest stash as `stash@{0} (<hash>): <subject>`.
+async fn top_stash(path: &str) -> Result<String, String> {
+    let top = run_git(
+        path,
+        &["stash", "list", "-n", "1", "--format=%gd (%h): %gs"],
+    )
+    .await?;
+    let top = String::from_utf8_lossy(&top).trim().to_string();
+    if top.is_empty() {
+        return Err("no stash entries".to_string());
+    }
+    Ok(top)
+}
+
+/// Runs git in `path`, returning stdout on success and stderr as the error.
+/// `LC_ALL=C` keeps git's output in English whatever the user's locale.
+async fn run_git(path: &str, args: &[&str]) -> Result<Vec<u8>, String> {
+    let out = tokio::process::Command::new("git")
+        .args(args)
+        .env("LC_ALL", "C")
+        .current_dir(path)
+        .output()
+        .await
+        .map_err(|e| e.to_string())?;
+    if out.status.success() {
+        Ok(out.stdout)
+    } else {
+        Err(String::from_utf8_lossy(&out.stderr).trim().to_string())
+    }
+}
+
 async fn execute_patch_file(args: &serde_json::Value) -> Result<String, String> {
     let path = arg_str(args, "path")?;
     let patch = arg_str(args, "patch")?;
-    let mut cmd = tokio::process::Command::new("patch");
-    cmd.args(["-u", path]);
+    apply_patch(path, patch, "patch").await
+}
+
+/// Applies with the `patch` binary, falling back to `git apply` only when
+/// `patch` is not installed. `patch` checks the whole diff with `--dry-run`
+/// first, so a rejected hunk never leaves the file half patched. The result
+/// names the applier that worked.
+pub(super) async fn apply_patch(
+    path: &str,
+    patch: &str,
+    patch_bin: &str,
+) -> Result<String, String> {
+    let patch_cmd = |dry_run: bool| {
+        let mut cmd = tokio::process::Command::new(patch_bin);
+        if dry_run {
+            cmd.arg("--dry-run");
+        }
+        cmd.args(["-u", path]);
+        cmd
+    };
+    let patch_err = match pipe_to(patch_cmd(true), patch).await {
+        Ok(()) => {
+            return pipe_to(patch_cmd(false), patch)
+                .await
+                .map(|()| format!("patched {path} (patch)"))
+                .map_err(|e| format!("patch: {e}"));
+        }
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => e,
+        Err(e) => return Err(format!("patch: {e}")),
+    };
+    match git_apply(path, patch).await {
+        Ok(()) => Ok(format!("patched {path} (git apply)")),
+        Err(git_err) => Err(format!("patch: {patch_err}\ngit apply: {git_err}")),
+    }
+}
+
+/// `patch -u <file>` ignores the diff's own file names; `git apply` doesn't,
+/// so the headers are pointed at the target and git runs from its directory
+/// with repository discovery stopped there.
+async fn git_apply(path: &str, patch: &str) -> Result<(), String> {
+    let target = std::path::Path::new(path);
+    let name = target
+        .file_name()
+        .and_then(|n| n.to_str())
+        .ok_or_else(|| format!("not a file: {path}"))?;
+    let dir = match target.parent() {
+        Some(d) if !d.as_os_str().is_empty() => d,
+        _ => std::path::Path::new("."),
+    };
+    let mut cmd = tokio::process::Command::new("git");
+    cmd.args(["apply", "--unsafe-paths", "-"]).current_dir(dir);
+    let ceiling = std::fs::canonicalize(dir)
+        .ok()
+        .and_then(|d| d.parent().map(|p| p.to_path_buf()));
+    if let Some(ceiling) = ceiling {
+        cmd.env("GIT_CEILING_DIRECTORIES", ceiling);
+    }
+    pipe_to(cmd, &retarget_headers(patch, name))
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Points each file section's `---` / `+++` header at `name`. Hunk bodies are
+/// skipped by their `@@` line counts, so a removed line that happens to start
+/// with `-- ` is left alone, and line endings (CRLF included) are kept.
+pub(super) fn retarget_headers(patch: &str, name: &str) -> String {
+    let mut out = String::with_capacity(patch.len());
+    let (mut old_left, mut new_left) = (0u64, 0u64);
+    for line in patch.split_inclusive('\n') {
+        let body = line.trim_end_matches(['\r', '\n']);
+        let ending = &line[body.len()..];
+        if old_left > 0 || new_left > 0 {
+            match body.as_bytes().first() {
+                Some(b'-') => old_left = old_left.saturating_sub(1),
+                Some(b'+') => new_left = new_left.saturating_sub(1),
+                Some(b'\\') => {}
+                _ => {
+                    old_left = old_left.saturating_sub(1);
+                    new_left = new_left.saturating_sub(1);
+                }
+            }
+            out.push_str(line);
+        } else if body.starts_with("--- ") {
+            out.push_str(&format!("--- a/{name}{ending}"));
+        } else if body.starts_with("+++ ") {
+            out.push_str(&format!("+++ b/{name}{ending}"));
+        } else {
+            if let Some((old, new)) = hunk_counts(body) {
+                (old_left, new_left) = (old, new);
+            }
+            out.push_str(line);
+        }
+    }
+    if !out.is_empty() && !out.ends_with('\n') {
+        out.push('\n');
+    }
+    out
+}
+
+/// Line counts of an `@@ -l[,s] +l[,s] @@` hunk header; a missing count is 1.
+fn hunk_counts(line: &str) -> Option<(u64, u64)> {
+    let ranges = line.strip_prefix("@@ -")?.split(" @@").next()?;
+    let (old, new) = ranges.split_once(" +")?;
+    let count = |range: &str| match range.split_once(',') {
+        Some((_, n)) => n.parse().ok(),
+        None => range.parse::<u64>().ok().map(|_| 1),
+    };
+    Some((count(old)?, count(new)?))
+}
+
+/// Runs `cmd` with `input` on stdin; stderr becomes the error on failure. A
+/// command that cannot be started keeps its `io::ErrorKind`.
+async fn pipe_to(mut cmd: tokio::process::Command, input: &str) -> std::io::Result<()> {
+    use tokio::io::AsyncWriteExt;
     cmd.stdin(std::process::Stdio::piped());
     cmd.stdout(std::process::Stdio::piped());
     cmd.stderr(std::process::Stdio::piped());
-    let mut child = cmd.spawn().map_err(|e| e.to_string())?;
-    if let Some(stdin) = child.stdin.take() {
-        use tokio::io::AsyncWriteExt;
-        let mut stdin = stdin;
-        stdin
-            .write_all(patch.as_bytes())
-            .await
-            .map_err(|e| e.to_string())?;
+    let mut child = cmd.spawn()?;
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin.write_all(input.as_bytes()).await?;
     }
-    let out = child.wait_with_output().await.map_err(|e| e.to_string())?;
+    let out = child.wait_with_output().await?;
     if out.status.success() {
-        Ok(format!("patched {path}"))
+        Ok(())
     } else {
-        Err(String::from_utf8_lossy(&out.stderr).into_owned())
+        // `patch` reports failed hunks on stdout
+        let report = match String::from_utf8_lossy(&out.stderr).trim() {
+            "" => String::from_utf8_lossy(&out.stdout).trim().to_string(),
+            stderr => stderr.to_string(),
+        };
+        Err(std::io::Error::other(report))
     }
 }
 
-async fn is_binary_file(path: &std::path::Path) -> bool {
-    let mut buf = [0u8; 512];
-    let Ok(mut file) = tokio::fs::File::open(path).await else {
-        return false;
-    };
-    use tokio::io::AsyncReadExt;
-    let n = file.read(&mut buf).await.unwrap_or(0);
-    buf[..n].contains(&0)
+const MAX_SEARCH_DEPTH: u32 = 12;
+const BINARY_SNIFF_BYTES: u64 = 8_192;
+const DEFAULT_MAX_RESULTS: usize = 1_000;
+const DEFAULT_MAX_FILE_BYTES: u64 = 1_048_576;
+const DEFAULT_SEARCH_CONCURRENCY: usize = 16;
+
+pub(super) struct SearchOptions {
+    pub use_regex: bool,
+    /// Also descend into hidden entries and paths matched by a `.gitignore`.
+    pub include_ignored: bool,
+    pub max_results: usize,
+    /// Files larger than this are not read at all.
+    pub max_file_bytes: u64,
+    /// Directory/file handles a search may hold open; shared by every
+    /// search the server runs, so concurrent calls stay within it.
+    pub open_handles: std::sync::Arc<tokio::sync::Semaphore>,
 }
 
-const MAX_SEARCH_DEPTH: u32 = 12;
-const MAX_SEARCH_MATCHES: usize = 5_000;
+impl Default for SearchOptions {
+    fn default() -> Self {
+        Self {
+            use_regex: false,
+            include_ignored: false,
+            max_results: DEFAULT_MAX_RESULTS,
+            max_file_bytes: DEFAULT_MAX_FILE_BYTES,
+            open_handles: std::sync::Arc::new(tokio::sync::Semaphore::new(
+                DEFAULT_SEARCH_CONCURRENCY,
+            )),
+        }
+    }
+}
+
+impl SearchOptions {
+    /// Tool arguments override the defaults; file size and handle limits are
+    /// the server's.
+    fn from_args(args: &serde_json::Value, limits: &ToolLimits) -> Self {
+        let defaults = Self::default();
+        let flag = |key| args.get(key).and_then(|v| v.as_bool()).unwrap_or(false);
+        Self {
+            use_regex: flag("regex"),
+            include_ignored: flag("include_ignored"),
+            max_results: args
+                .get("max_results")
+                .and_then(|v| v.as_u64())
+                .filter(|&n| n > 0)
+                .map_or(defaults.max_results, |n| n as usize),
+            max_file_bytes: limits.search_max_file_bytes,
+            open_handles: limits.search_handles.clone(),
+        }
+    }
+}
+
+struct SearchQuery<'a> {
+    pattern: &'a str,
+    re: Option<regex::Regex>,
+    opts: &'a SearchOptions,
+}
 
-async fn search(root: &str, pattern: &str, use_regex: bool) -> Result<String, String> {
-    let re = if use_regex {
+pub(super) async fn search(
+    root: &str,
+    pattern: &str,
+    opts: &SearchOptions,
+) -> Result<String, String> {
+    let re = if opts.use_regex {
         Some(regex::Regex::new(pattern).map_err(|e| format!("invalid regex: {e}"))?)
     } else {
         None
     };
+    let query = SearchQuery { pattern, re, opts };
     let mut matches = Vec::new();
-    search_dir(root, pattern, &re, &mut matches, 0).await?;
+    let inherited = match opts.include_ignored {
+        true => Vec::new(),
+        false => super::gitignore::load_ancestor_rules(std::path::Path::new(root)).await,
+    };
+    search_dir(root, &query, &inherited, &mut matches, 0).await?;
     if matches.is_empty() {
         Ok(format!("no matches for '{pattern}'"))
     } else {
-        let truncated = matches.len() >= MAX_SEARCH_MATCHES;
+        let truncated = matches.len() > opts.max_results;
+        matches.truncate(opts.max_results);
         let mut result = matches.join("\n");
         if truncated {
-            result.push_str(&format!(
-                "\n... (truncated at {MAX_SEARCH_MATCHES} matches)"
-            ));
+            result.push_str(&format!("\n... (truncated, {}+ matches)", opts.max_results));
         }
         Ok(result)
     }
@@ -329,44 +1017,90 @@ const SKIP_DIRS: &[&str] = &[
     ".cache",
 ];
 
+async fn list_entries(
+    dir: &str,
+    query: &SearchQuery<'_>,
+) -> Result<Vec<(std::path::PathBuf, std::fs::Metadata)>, String> {
+    let _permit = query
+        .opts
+        .open_handles
+        .acquire()
+        .await
+        .map_err(|e| e.to_string())?;
+    let mut entries = tokio::fs::read_dir(dir).await.map_err(|e| e.to_string())?;
+    let mut out = Vec::new();
+    while let Some(entry) = entries.next_entry().await.map_err(|e| e.to_string())? {
+        let meta = entry.metadata().await.map_err(|e| e.to_string())?;
+        out.push((entry.path(), meta));
+    }
+    Ok(out)
+}
+
+/// Reads the first 8 KiB and gives up on a NUL byte, so binaries are never
+/// read in full; otherwise the rest is read from the same handle.
+async fn read_text_file(path: &std::path::Path, query: &SearchQuery<'_>) -> Option<String> {
+    use tokio::io::AsyncReadExt;
+    let _permit = query.opts.open_handles.acquire().await.ok()?;
+    let mut file = tokio::fs::File::open(path).await.ok()?;
+    let mut buf = Vec::new();
+    (&mut file)
+        .take(BINARY_SNIFF_BYTES)
+        .read_to_end(&mut buf)
+        .await
+        .ok()?;
+    if buf.contains(&0) {
+        return None;
+    }
+    file.read_to_end(&mut buf).await.ok()?;
+    String::from_utf8(buf).ok()
+}
+
 async fn search_dir(
     dir: &str,
-    pattern: &str,
-    re: &Option<regex::Regex>,
+    query: &SearchQuery<'_>,
+    inherited: &[super::gitignore::IgnoreRule],
     matches: &mut Vec<String>,
     depth: u32,
 ) -> Result<(), String> {
-    if depth > MAX_SEARCH_DEPTH || matches.len() >= MAX_SEARCH_MATCHES {
+    // One match past the cap tells a search that overflowed from one that hit it exactly.
+    let full = |matches: &Vec<String>| matches.len() > query.opts.max_results;
+    if depth > MAX_SEARCH_DEPTH || full(matches) {
         return Ok(());
     }
-    let mut entries = tokio::fs::read_dir(dir).await.map_err(|e| e.to_string())?;
-    while let Some(entry) = entries.next_entry().await.map_err(|e| e.to_string())? {
-        if matches.len() >= MAX_SEARCH_MATCHES {
+    let respect_ignore = !query.opts.include_ignored;
+    let mut rules = inherited.to_vec();
+    if respect_ignore {
+        rules.extend(super::gitignore::load_rules(std::path::Path::new(dir)).await);
+    }
+    // The directory handle is closed before recursing, so open handles stay
+    // bounded by the semaphore rather than by tree depth.
+    for (path, meta) in list_entries(dir, query).await? {
+        if full(matches) {
             return Ok(());
         }
-        let path = entry.path();
-        let meta = entry.metadata().await.map_err(|e| e.to_string())?;
+        if respect_ignore && is_hidden_or_ignored(&rules, &path, meta.is_dir()) {
+            continue;
+        }
         if meta.is_dir() {
-            let name = entry.file_name();
-            let name_str = name.to_string_lossy();
-            if SKIP_DIRS.contains(&name_str.as_ref()) {
+            let name = path.file_name().unwrap_or_default().to_string_lossy();
+            if SKIP_DIRS.contains(&name.as_ref()) {
                 continue;
             }
             if let Some(p) = path.to_str() {
-                Box::pin(search_dir(p, pattern, re, matches, depth + 1)).await?;
+                Box::pin(search_dir(p, query, &rules, matches, depth + 1)).await?;
             }
-        } else if meta.is_file() {
-            if is_binary_file(&path).await {
-                continue;
-            }
-            if let Ok(content) = tokio::fs::read_to_string(&path).await {
+        } else if meta.is_file() && meta.len() <= query.opts.max_file_bytes {
+            if let Some(content) = read_text_file(&path, query).await {
                 for (i, line) in content.lines().enumerate() {
-                    let hit = match re {
+                    let hit = match &query.re {
                         Some(r) => r.is_match(line),
-                        None => line.contains(pattern),
+                        None => line.contains(query.pattern),
                     };
                     if hit {
                         matches.push(format!("{}:{}: {}", path.display(), i + 1, line.trim()));
+                        if full(matches) {
+                            return Ok(());
+                        }
                     }
                 }
             }
@@ -374,3 +1108,14 @@ async fn search_dir(
     }
     Ok(())
 }
+
+fn is_hidden_or_ignored(
+    rules: &[super::gitignore::IgnoreRule],
+    path: &std::path::Path,
+    is_dir: bool,
+) -> bool {
+    let hidden = path
+        .file_name()
+        .is_some_and(|n| n.to_string_lossy().starts_with('.'));
+    hidden || super::gitignore::is_ignored(rules, path, is_dir)
+}
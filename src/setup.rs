@@ -6,14 +6,15 @@ pub async fn run() -> Result<()> {
 
     let has_claude = detect_claude();
     let has_cursor = detect_cursor();
+    let has_windsurf = detect_windsurf();
 
-    print_detection(has_claude, has_cursor);
+    print_detection(has_claude, has_cursor, has_windsurf);
 
     let default_ledger = crate::models::vigilo_path("events.jsonl")
         .to_string_lossy()
         .into_owned();
     let ledger = prompt(
-        &format!("[1/4] Ledger path [{}]", default_ledger),
+        &format!("[1/5] Ledger path [{}]", default_ledger),
         &default_ledger,
     )?;
 
@@ -24,6 +25,7 @@ pub async fn run() -> Result<()> {
     let encryption_key = setup_encryption()?;
     setup_claude_if_detected(has_claude, &ledger)?;
     let cursor_db = setup_cursor_if_detected(has_cursor, &ledger)?;
+    setup_windsurf_if_detected(has_windsurf, &ledger)?;
 
     write_config(&ledger, encryption_key.as_deref(), cursor_db.as_deref())?;
 
@@ -35,6 +37,102 @@ pub async fn run() -> Result<()> {
     Ok(())
 }
 
+type ConfigEdit = fn(&mut serde_json::Value) -> bool;
+
+/// Undoes `setup`: drops the vigilo MCP server and hook entries from the
+/// Claude Code, Cursor and Windsurf configs, leaving everything else in those files as it
+/// was, then offers to delete `~/.vigilo`.
+pub fn uninstall() -> Result<()> {
+    println!("\nvigilo uninstall\n");
+    let home = crate::models::home_dir();
+    let edits: [(&str, ConfigEdit); 5] = [
+        (".claude.json", remove_mcp_entry),
+        (".claude/settings.json", remove_claude_hook),
+        (".cursor/mcp.json", remove_mcp_entry),
+        (".cursor/hooks.json", remove_cursor_hooks),
+        (WINDSURF_MCP_CONFIG, remove_mcp_entry),
+    ];
+    for (rel, edit) in edits {
+        let path = home.join(rel).to_string_lossy().into_owned();
+        if !std::path::Path::new(&path).exists() {
+            continue;
+        }
+        let mut config = read_json_or_empty(&path);
+        if edit(&mut config) {
+            write_json(&path, &config)?;
+            println!("  ✓ removed vigilo from ~/{rel}");
+        } else {
+            println!("  - ~/{rel}: nothing to remove");
+        }
+    }
+
+    let dir = crate::models::vigilo_dir();
+    if dir.exists() {
+        println!();
+        println!(
+            "  {} holds the ledger, encryption key and config.",
+            dir.display()
+        );
+        if prompt_yn("Delete it? This cannot be undone", false)? {
+            std::fs::remove_dir_all(&dir)?;
+            println!("  ✓ deleted {}", dir.display());
+        } else {
+            println!("  kept {}", dir.display());
+        }
+    }
+    println!(
+        "\n  Done. Remove the binary itself with your package manager or `rm $(which vigilo)`.\n"
+    );
+    Ok(())
+}
+
+fn remove_mcp_entry(config: &mut serde_json::Value) -> bool {
+    config["mcpServers"]
+        .as_object_mut()
+        .is_some_and(|servers| servers.remove("vigilo").is_some())
+}
+
+/// Drops `vigilo hook` from each PostToolUse matcher, and the matcher itself
+/// once it has no hooks left.
+fn remove_claude_hook(config: &mut serde_json::Value) -> bool {
+    let Some(entries) = config["hooks"]["PostToolUse"].as_array_mut() else {
+        return false;
+    };
+    let before = entries.len();
+    let mut changed = false;
+    for entry in entries.iter_mut() {
+        if let Some(hooks) = entry["hooks"].as_array_mut() {
+            let n = hooks.len();
+            hooks.retain(|h| h["command"].as_str() != Some(VIGILO_HOOK_COMMAND));
+            changed |= hooks.len() != n;
+        }
+    }
+    entries.retain(|e| !e["hooks"].as_array().is_some_and(|h| h.is_empty()));
+    changed |= entries.len() != before;
+    prune_empty_hook_lists(config);
+    changed
+}
+
+fn remove_cursor_hooks(config: &mut serde_json::Value) -> bool {
+    let Some(hooks) = config["hooks"].as_object_mut() else {
+        return false;
+    };
+    let mut changed = false;
+    for entries in hooks.values_mut().filter_map(|v| v.as_array_mut()) {
+        let n = entries.len();
+        entries.retain(|h| h["command"].as_str() != Some(VIGILO_HOOK_COMMAND));
+        changed |= entries.len() != n;
+    }
+    prune_empty_hook_lists(config);
+    changed
+}
+
+fn prune_empty_hook_lists(config: &mut serde_json::Value) {
+    if let Some(hooks) = config["hooks"].as_object_mut() {
+        hooks.retain(|_, v| !v.as_array().is_some_and(|a| a.is_empty()));
+    }
+}
+
 async fn sync_cursor_usage() {
     println!("\n      Syncing Cursor token usage...");
     match crate::cursor::sync(30).await {
@@ -43,22 +141,25 @@ async fn sync_cursor_usage() {
     }
 }
 
-fn print_detection(has_claude: bool, has_cursor: bool) {
+fn print_detection(has_claude: bool, has_cursor: bool, has_windsurf: bool) {
     if has_claude {
         println!("  Claude Code detected ✓");
     }
     if has_cursor {
         println!("  Cursor detected       ✓");
     }
-    if !has_claude && !has_cursor {
-        println!("  Neither Claude Code nor Cursor detected.");
+    if has_windsurf {
+        println!("  Windsurf detected     ✓");
+    }
+    if !has_claude && !has_cursor && !has_windsurf {
+        println!("  No Claude Code, Cursor or Windsurf install detected.");
         println!("  You can still set up vigilo manually — see README.");
     }
     println!();
 }
 
 fn setup_encryption() -> Result<Option<String>> {
-    println!("\n[2/4] Encryption");
+    println!("\n[2/5] Encryption");
 
     let existing = crate::crypto::load_key_from_file().is_some()
         || std::env::var("VIGILO_ENCRYPTION_KEY").is_ok();
@@ -97,7 +198,7 @@ fn setup_encryption() -> Result<Option<String>> {
 
 fn setup_claude_if_detected(has_claude: bool, ledger: &str) -> Result<()> {
     if has_claude {
-        println!("\n[3/4] Claude Code integration");
+        println!("\n[3/5] Claude Code integration");
         println!("      Sets up MCP server in ~/.claude.json");
         println!("      Sets up PostToolUse hook in ~/.claude/settings.json");
         if prompt_yn("      Configure Claude Code?", true)? {
@@ -106,17 +207,17 @@ fn setup_claude_if_detected(has_claude: bool, ledger: &str) -> Result<()> {
             }
         }
     } else {
-        println!("\n[3/4] Claude Code — not detected, skipping");
+        println!("\n[3/5] Claude Code — not detected, skipping");
     }
     Ok(())
 }
 
 fn setup_cursor_if_detected(has_cursor: bool, ledger: &str) -> Result<Option<String>> {
     if !has_cursor {
-        println!("\n[4/4] Cursor — not detected, skipping");
+        println!("\n[4/5] Cursor — not detected, skipping");
         return Ok(None);
     }
-    println!("\n[4/4] Cursor integration");
+    println!("\n[4/5] Cursor integration");
     println!("      Sets up MCP server in ~/.cursor/mcp.json");
     println!("      Sets up lifecycle hooks in ~/.cursor/hooks.json");
     if !prompt_yn("      Configure Cursor?", true)? {
@@ -128,6 +229,21 @@ fn setup_cursor_if_detected(has_cursor: bool, ledger: &str) -> Result<Option<Str
     Ok(discover_cursor_db())
 }
 
+fn setup_windsurf_if_detected(has_windsurf: bool, ledger: &str) -> Result<()> {
+    if !has_windsurf {
+        println!("\n[5/5] Windsurf — not detected, skipping");
+        return Ok(());
+    }
+    println!("\n[5/5] Windsurf integration");
+    println!("      Sets up MCP server in ~/.codeium/windsurf/mcp_config.json");
+    if prompt_yn("      Configure Windsurf?", true)? {
+        if let Err(e) = setup_windsurf_mcp(ledger) {
+            eprintln!("      ! Error: {e}");
+        }
+    }
+    Ok(())
+}
+
 fn print_completion(encryption_key: Option<&str>) {
     println!("\n  Done.\n");
     println!("  Use your AI editor to make some tool calls, then run:");
@@ -208,6 +324,16 @@ pub(crate) fn is_vigilo_hook_present(post_tool_use: &serde_json::Value) -> bool
         .unwrap_or(false)
 }
 
+pub(crate) const VIGILO_HOOK_COMMAND: &str = "vigilo hook";
+
+/// The `~/.cursor/hooks.json` events `setup` registers `vigilo hook` for.
+pub(crate) const CURSOR_HOOK_TYPES: &[&str] = &[
+    "beforeShellExecution",
+    "afterFileEdit",
+    "beforeReadFile",
+    "beforeMCPExecution",
+];
+
 fn setup_cursor(ledger: &str) -> Result<()> {
     setup_cursor_mcp(ledger)?;
     setup_cursor_hooks()?;
@@ -219,8 +345,27 @@ fn setup_cursor_mcp(ledger: &str) -> Result<()> {
         .join(".cursor/mcp.json")
         .to_string_lossy()
         .into_owned();
-    let mut config: serde_json::Value = read_json_or_empty(&path);
+    upsert_mcp_server(&path, ledger)?;
+    println!("      ✓ ~/.cursor/mcp.json");
+    Ok(())
+}
 
+const WINDSURF_MCP_CONFIG: &str = ".codeium/windsurf/mcp_config.json";
+
+fn setup_windsurf_mcp(ledger: &str) -> Result<()> {
+    let path = crate::models::home_dir()
+        .join(WINDSURF_MCP_CONFIG)
+        .to_string_lossy()
+        .into_owned();
+    upsert_mcp_server(&path, ledger)?;
+    println!("      ✓ ~/{WINDSURF_MCP_CONFIG}");
+    Ok(())
+}
+
+/// Adds or replaces the `mcpServers.vigilo` entry, keeping other servers.
+/// Cursor and Windsurf share this config shape.
+fn upsert_mcp_server(path: &str, ledger: &str) -> Result<()> {
+    let mut config = read_json_or_empty(path);
     if config["mcpServers"].is_null() {
         config["mcpServers"] = serde_json::json!({});
     }
@@ -228,10 +373,7 @@ fn setup_cursor_mcp(ledger: &str) -> Result<()> {
         "command": binary_path(),
         "env": { "VIGILO_LEDGER": ledger }
     });
-
-    write_json(&path, &config)?;
-    println!("      ✓ ~/.cursor/mcp.json");
-    Ok(())
+    write_json(path, &config)
 }
 
 fn setup_cursor_hooks() -> Result<()> {
@@ -248,14 +390,8 @@ fn setup_cursor_hooks() -> Result<()> {
         config["hooks"] = serde_json::json!({});
     }
 
-    let our_command = "vigilo hook";
-    for hook_type in [
-        "beforeShellExecution",
-        "afterFileEdit",
-        "beforeReadFile",
-        "beforeMCPExecution",
-    ] {
-        ensure_hook_entry(&mut config["hooks"], hook_type, our_command);
+    for hook_type in CURSOR_HOOK_TYPES {
+        ensure_hook_entry(&mut config["hooks"], hook_type, VIGILO_HOOK_COMMAND);
     }
 
     write_json(&path, &config)?;
@@ -322,6 +458,10 @@ fn detect_claude() -> bool {
     crate::models::home_dir().join(".claude").exists() || which("claude").is_some()
 }
 
+fn detect_windsurf() -> bool {
+    crate::models::home_dir().join(".codeium/windsurf").exists() || which("windsurf").is_some()
+}
+
 fn detect_cursor() -> bool {
     crate::models::home_dir().join(".cursor").exists()
         || which("cursor").is_some()
@@ -418,6 +558,123 @@ mod tests {
         assert!(!is_vigilo_hook_present(&val));
     }
 
+    #[test]
+    fn remove_mcp_entry_keeps_other_servers() {
+        let mut config = serde_json::json!({
+            "theme": "dark",
+            "mcpServers": {
+                "vigilo": { "command": "vigilo" },
+                "github": { "command": "gh-mcp" }
+            }
+        });
+        assert!(remove_mcp_entry(&mut config));
+        assert_eq!(
+            config,
+            serde_json::json!({
+                "theme": "dark",
+                "mcpServers": { "github": { "command": "gh-mcp" } }
+            })
+        );
+        assert!(!remove_mcp_entry(&mut config));
+    }
+
+    #[test]
+    fn remove_claude_hook_keeps_unrelated_hooks() {
+        let mut config = serde_json::json!({
+            "model": "opus",
+            "hooks": {
+                "PostToolUse": [
+                    {
+                        "matcher": ".*",
+                        "hooks": [{ "type": "command", "command": "vigilo hook" }]
+                    },
+                    {
+                        "matcher": "Bash",
+                        "hooks": [
+                            { "type": "command", "command": "vigilo hook" },
+                            { "type": "command", "command": "fmt-check" }
+                        ]
+                    }
+                ],
+                "Stop": [{ "hooks": [{ "type": "command", "command": "notify" }] }]
+            }
+        });
+        assert!(remove_claude_hook(&mut config));
+        assert_eq!(config["model"], "opus");
+        assert_eq!(
+            config["hooks"]["PostToolUse"],
+            serde_json::json!([{
+                "matcher": "Bash",
+                "hooks": [{ "type": "command", "command": "fmt-check" }]
+            }])
+        );
+        assert_eq!(config["hooks"]["Stop"][0]["hooks"][0]["command"], "notify");
+        assert!(!is_vigilo_hook_present(&config["hooks"]["PostToolUse"]));
+
+        let mut only_vigilo = serde_json::json!({
+            "hooks": { "PostToolUse": [{ "hooks": [{ "command": "vigilo hook" }] }] }
+        });
+        assert!(remove_claude_hook(&mut only_vigilo));
+        assert_eq!(only_vigilo, serde_json::json!({ "hooks": {} }));
+    }
+
+    #[test]
+    fn remove_cursor_hooks_keeps_unrelated_entries() {
+        let mut config = serde_json::json!({
+            "version": 1,
+            "hooks": {
+                "beforeShellExecution": [{ "command": "vigilo hook" }, { "command": "audit.sh" }],
+                "afterFileEdit": [{ "command": "vigilo hook" }],
+                "stop": [{ "command": "notify" }]
+            }
+        });
+        assert!(remove_cursor_hooks(&mut config));
+        assert_eq!(
+            config,
+            serde_json::json!({
+                "version": 1,
+                "hooks": {
+                    "beforeShellExecution": [{ "command": "audit.sh" }],
+                    "stop": [{ "command": "notify" }]
+                }
+            })
+        );
+        assert!(!remove_cursor_hooks(&mut config));
+    }
+
+    #[test]
+    fn upsert_mcp_server_adds_entry_and_keeps_others() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("windsurf/mcp_config.json");
+        let path = path.to_str().unwrap();
+        write_json(
+            path,
+            &serde_json::json!({ "mcpServers": { "github": { "command": "gh-mcp" } } }),
+        )
+        .unwrap();
+
+        upsert_mcp_server(path, "/data/events.jsonl").unwrap();
+        upsert_mcp_server(path, "/data/events.jsonl").unwrap();
+        let config = read_json_or_empty(path);
+        let servers = config["mcpServers"].as_object().unwrap();
+        assert_eq!(servers.len(), 2);
+        assert_eq!(servers["github"]["command"], "gh-mcp");
+        assert_eq!(
+            servers["vigilo"]["env"]["VIGILO_LEDGER"],
+            "/data/events.jsonl"
+        );
+        assert!(servers["vigilo"]["command"].is_string());
+    }
+
+    #[test]
+    fn upsert_mcp_server_creates_missing_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(".codeium/windsurf/mcp_config.json");
+        upsert_mcp_server(path.to_str().unwrap(), "/tmp/l.jsonl").unwrap();
+        let config = read_json_or_empty(path.to_str().unwrap());
+        assert!(config["mcpServers"]["vigilo"].is_object());
+    }
+
     #[test]
     fn ensure_hook_entry_adds_new_entry() {
         let mut hooks = serde_json::json!({});
@@ -0,0 +1,184 @@
+use super::fmt::{decrypt_args, maybe_decrypt};
+use crate::crypto::{hmac_sha256, EncryptionKey};
+use crate::models::{McpEvent, Outcome};
+
+/// Argument keys whose values are file system paths.
+const PATH_KEYS: &[&str] = &[
+    "file_path",
+    "path",
+    "notebook_path",
+    "from",
+    "to",
+    "source",
+    "destination",
+    "cwd",
+    "directory",
+];
+
+const REDACTED: &str = "[redacted]";
+
+/// Rewrites events for `export --anonymize`: paths, working directories,
+/// branches and tags become stable tokens keyed with `secret`, commands keep
+/// only the program name, other argument text, results, error messages, diffs
+/// and project roots are dropped. Tool names, risk, timings and token counts
+/// are untouched.
+pub(super) fn anonymize_sessions(
+    sessions: &mut [(String, Vec<McpEvent>)],
+    key: Option<&EncryptionKey>,
+    secret: &[u8; 32],
+) {
+    for e in sessions.iter_mut().flat_map(|(_, events)| events) {
+        anonymize_event(e, key, secret);
+    }
+}
+
+fn anonymize_event(e: &mut McpEvent, key: Option<&EncryptionKey>, secret: &[u8; 32]) {
+    e.arguments = match decrypt_args(key, &e.arguments) {
+        serde_json::Value::Object(args) => args
+            .into_iter()
+            .map(|(k, v)| {
+                let v = anonymize_arg(&k, &v, key, secret);
+                (k, v)
+            })
+            .collect(),
+        _ => serde_json::Value::Null,
+    };
+    match &mut e.outcome {
+        Outcome::Ok { result } => *result = serde_json::Value::Null,
+        Outcome::Err { message, .. } => *message = REDACTED.to_string(),
+        Outcome::Blocked { .. } => {}
+    }
+    e.diff = None;
+    e.overflow = None;
+    e.project.root = None;
+    e.cwd = e.cwd.take().map(|cwd| {
+        let plain = maybe_decrypt(key, &serde_json::Value::String(cwd));
+        token(secret, "path", &plain)
+    });
+    e.project.branch = e.project.branch.take().map(|b| token(secret, "branch", &b));
+    e.tag = e.tag.take().map(|t| token(secret, "tag", &t));
+}
+
+fn anonymize_arg(
+    name: &str,
+    value: &serde_json::Value,
+    key: Option<&EncryptionKey>,
+    secret: &[u8; 32],
+) -> serde_json::Value {
+    use serde_json::Value;
+    match value {
+        Value::String(_) => {
+            let plain = maybe_decrypt(key, value);
+            let anonymized = if PATH_KEYS.contains(&name) {
+                token(secret, "path", &plain)
+            } else if name == "command" {
+                program_name(&plain)
+            } else {
+                REDACTED.to_string()
+            };
+            Value::String(anonymized)
+        }
+        Value::Number(_) | Value::Bool(_) | Value::Null => value.clone(),
+        Value::Array(_) | Value::Object(_) => Value::String(REDACTED.to_string()),
+    }
+}
+
+/// `<kind>-` plus the first 12 hex digits of the value's HMAC under the
+/// install's secret, so the same file always maps to the same token and
+/// per-file counts still add up, but tokens can't be matched against hashes
+/// of guessed values.
+fn token(secret: &[u8; 32], kind: &str, value: &str) -> String {
+    let mac = hmac_sha256(secret, value.as_bytes());
+    let hex: String = mac.iter().take(6).map(|b| format!("{b:02x}")).collect();
+    format!("{kind}-{hex}")
+}
+
+/// The program a command runs, without its arguments or directory: leading
+/// `VAR=value` assignments are skipped.
+fn program_name(command: &str) -> String {
+    command
+        .split_whitespace()
+        .find(|word| !word.contains('='))
+        .map(|program| program.rsplit('/').next().unwrap_or(program).to_string())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::ProjectContext;
+
+    const SECRET: [u8; 32] = [7u8; 32];
+
+    fn call(tool: &str, arguments: serde_json::Value) -> McpEvent {
+        McpEvent {
+            tool: tool.to_string(),
+            arguments,
+            duration_us: 1_500,
+            outcome: Outcome::Ok {
+                result: serde_json::json!("secret output"),
+            },
+            project: ProjectContext {
+                root: Some("/home/alice/acme".to_string()),
+                name: Some("acme".to_string()),
+                branch: Some("feature/globex-migration".to_string()),
+                ..Default::default()
+            },
+            tag: Some("feature/globex-migration".to_string()),
+            cwd: Some("/home/alice/acme/api".to_string()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn paths_hash_stably_and_raw_values_never_leak() {
+        let mut sessions = vec![(
+            "s1".to_string(),
+            vec![
+                call(
+                    "read_file",
+                    serde_json::json!({ "path": "/home/alice/acme/.env" }),
+                ),
+                call(
+                    "write_file",
+                    serde_json::json!({ "path": "/home/alice/acme/.env", "content": "KEY=1" }),
+                ),
+                call(
+                    "run_command",
+                    serde_json::json!({ "command": "RUST_LOG=debug /usr/bin/git push origin main" }),
+                ),
+            ],
+        )];
+        anonymize_sessions(&mut sessions, None, &SECRET);
+
+        let events = &sessions[0].1;
+        assert_eq!(events[0].arguments["path"], events[1].arguments["path"]);
+        assert_eq!(
+            events[0].arguments["path"],
+            token(&SECRET, "path", "/home/alice/acme/.env")
+        );
+        assert_ne!(token(&SECRET, "path", "/a"), token(&SECRET, "path", "/b"));
+        assert_ne!(
+            token(&SECRET, "path", "/a"),
+            token(&[8u8; 32], "path", "/a")
+        );
+        assert_eq!(events[0].project.branch, events[1].project.branch);
+        assert_eq!(events[1].arguments["content"], REDACTED);
+        assert_eq!(events[2].arguments["command"], "git");
+        assert_eq!(events[2].duration_us, 1_500);
+        assert_eq!(events[2].project.name.as_deref(), Some("acme"));
+
+        let out = super::super::search::render_export(&sessions, "json", false, None).unwrap();
+        for raw in [
+            "/home/alice",
+            "globex",
+            ".env",
+            "KEY=1",
+            "push origin",
+            "secret output",
+        ] {
+            assert!(!out.contains(raw), "{raw} leaked into the export");
+        }
+        assert!(out.contains("read_file"));
+    }
+}
@@ -0,0 +1,186 @@
+use super::data::{load_sessions, LoadFilter};
+use super::fmt::{primary_arg_text, risk_label, short_id};
+use crate::{
+    crypto,
+    models::{McpEvent, Outcome, Risk},
+};
+use anyhow::Result;
+use std::collections::BTreeMap;
+use std::io::Write;
+
+/// One write or exec call as it appears in the audit report.
+#[derive(serde::Serialize)]
+struct AuditEntry {
+    timestamp: String,
+    session_id: String,
+    server: String,
+    tool: String,
+    risk: &'static str,
+    arg: String,
+    status: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    exit_code: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    flagged: Option<String>,
+}
+
+/// Every write and exec call in the range, grouped by project, with arguments
+/// decrypted and no colors so the output can be archived as-is.
+pub fn audit(
+    ledger_path: &str,
+    since: Option<&str>,
+    until: Option<&str>,
+    json: bool,
+) -> Result<()> {
+    let filter = LoadFilter {
+        since,
+        until,
+        ..Default::default()
+    };
+    let sessions = load_sessions(ledger_path, &filter)?;
+    let key = crypto::load_key();
+    let report = audit_entries(&sessions, key.as_ref());
+
+    let mut out = std::io::stdout().lock();
+    if json {
+        let projects: Vec<_> = report
+            .iter()
+            .map(|(project, events)| serde_json::json!({ "project": project, "events": events }))
+            .collect();
+        writeln!(out, "{}", serde_json::to_string_pretty(&projects)?)?;
+    } else {
+        write_audit_text(&mut out, &report, since, until)?;
+    }
+    Ok(())
+}
+
+fn audit_entries(
+    sessions: &[(String, Vec<McpEvent>)],
+    key: Option<&crypto::EncryptionKey>,
+) -> BTreeMap<String, Vec<AuditEntry>> {
+    let mut report: BTreeMap<String, Vec<AuditEntry>> = BTreeMap::new();
+    for e in sessions.iter().flat_map(|(_, events)| events) {
+        let risk = match e.risk {
+            Risk::Unknown => Risk::classify(&e.tool),
+            r => r,
+        };
+        if !matches!(risk, Risk::Write | Risk::Exec) {
+            continue;
+        }
+        let project = e
+            .project
+            .name
+            .clone()
+            .or_else(|| e.project.root.clone())
+            .unwrap_or_else(|| "—".to_string());
+        report.entry(project).or_default().push(AuditEntry {
+            timestamp: e.timestamp.clone(),
+            session_id: e.session_id.to_string(),
+            server: e.server.clone(),
+            tool: e.tool.clone(),
+            risk: risk_label(risk),
+            arg: primary_arg_text(e, key),
+            status: status(e),
+            exit_code: e.exit_code,
+            flagged: e.flagged.clone(),
+        });
+    }
+    report
+}
+
+fn status(e: &McpEvent) -> &'static str {
+    match e.outcome {
+        _ if e.timed_out => "timeout",
+        Outcome::Ok { .. } => "ok",
+        Outcome::Err { .. } => "error",
+        Outcome::Blocked { .. } => "blocked",
+    }
+}
+
+fn write_audit_text(
+    w: &mut impl Write,
+    report: &BTreeMap<String, Vec<AuditEntry>>,
+    since: Option<&str>,
+    until: Option<&str>,
+) -> std::io::Result<()> {
+    let total: usize = report.values().map(Vec::len).sum();
+    writeln!(
+        w,
+        "vigilo audit · {} to {} · {total} write/exec calls",
+        since.unwrap_or("start"),
+        until.unwrap_or("now")
+    )?;
+    for (project, entries) in report {
+        writeln!(w)?;
+        writeln!(w, "## {project} ({} calls)", entries.len())?;
+        for a in entries {
+            let exit = a
+                .exit_code
+                .map(|c| format!(" exit={c}"))
+                .unwrap_or_default();
+            let flagged = a
+                .flagged
+                .as_deref()
+                .map(|r| format!(" flagged={r}"))
+                .unwrap_or_default();
+            writeln!(
+                w,
+                "{}  {}  {:<7} {:<5} {:<16} {}{exit}{flagged}  {}",
+                a.timestamp,
+                short_id(&a.session_id),
+                a.status,
+                a.risk,
+                a.tool,
+                a.arg,
+                a.server
+            )?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::ProjectContext;
+
+    fn call(tool: &str, project: &str, arg: &str) -> McpEvent {
+        McpEvent {
+            timestamp: "2026-03-01T10:00:00Z".to_string(),
+            server: "vigilo".to_string(),
+            tool: tool.to_string(),
+            arguments: serde_json::json!({ "path": arg, "command": arg }),
+            project: ProjectContext {
+                name: Some(project.to_string()),
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn reads_are_excluded_from_the_audit() {
+        let sessions = vec![(
+            "s1".to_string(),
+            vec![
+                call("read_file", "api", "src/lib.rs"),
+                call("write_file", "api", "src/lib.rs"),
+                call("run_command", "web", "npm test"),
+                call("list_directory", "web", "."),
+            ],
+        )];
+        let report = audit_entries(&sessions, None);
+        let tools: Vec<(&str, &str)> = report
+            .iter()
+            .flat_map(|(p, entries)| entries.iter().map(move |a| (p.as_str(), a.tool.as_str())))
+            .collect();
+        assert_eq!(tools, [("api", "write_file"), ("web", "run_command")]);
+
+        let mut text = Vec::new();
+        write_audit_text(&mut text, &report, None, None).unwrap();
+        let text = String::from_utf8(text).unwrap();
+        assert!(text.contains("2 write/exec calls"));
+        assert!(!text.contains("read_file"));
+        assert!(text.contains("ok      exec  run_command      npm test"));
+    }
+}
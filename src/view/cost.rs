@@ -0,0 +1,169 @@
+use super::data::{cursor_session_tokens, load_sessions, LoadFilter};
+use super::fmt::{cprintln, event_cost_usd, fmt_cost, fmt_tokens, BOLD, DIM, RESET};
+use crate::models::McpEvent;
+use anyhow::Result;
+use chrono::Datelike;
+use std::collections::BTreeMap;
+
+#[derive(Default)]
+struct CostRow {
+    calls: usize,
+    input: u64,
+    output: u64,
+    cost: f64,
+}
+
+/// Per-day (or per-week, keyed by its Monday) cost and token totals, oldest first.
+pub fn cost(
+    ledger_path: &str,
+    since: Option<&str>,
+    until: Option<&str>,
+    weekly: bool,
+) -> Result<()> {
+    let filter = LoadFilter {
+        since,
+        until,
+        ..Default::default()
+    };
+    let sessions = load_sessions(ledger_path, &filter)?;
+    if sessions.is_empty() {
+        println!("no events recorded yet.");
+        return Ok(());
+    }
+
+    let rows = cost_rows(&sessions, weekly);
+    let total = rows.values().fold(CostRow::default(), |mut t, r| {
+        t.calls += r.calls;
+        t.input += r.input;
+        t.output += r.output;
+        t.cost += r.cost;
+        t
+    });
+
+    println!();
+    cprintln!("{DIM}── vigilo cost ─────────────────────────────────{RESET}");
+    println!();
+    let label = if weekly { "week of" } else { "day" };
+    cprintln!(
+        "  {DIM}{label:<12} {:>7} {:>9} {:>9} {:>12}{RESET}",
+        "calls",
+        "in",
+        "out",
+        "cost"
+    );
+    for (period, r) in &rows {
+        println!("  {}", fmt_row(period, r));
+    }
+    cprintln!("  {DIM}{}{RESET}", "─".repeat(53));
+    cprintln!("  {BOLD}{}{RESET}", fmt_row("total", &total));
+    println!();
+    Ok(())
+}
+
+fn fmt_row(label: &str, r: &CostRow) -> String {
+    format!(
+        "{label:<12} {:>7} {:>9} {:>9} {:>12}",
+        r.calls,
+        fmt_tokens(r.input),
+        fmt_tokens(r.output),
+        format!("~{}", fmt_cost(r.cost))
+    )
+}
+
+/// Cursor sessions without per-event tokens are charged to the day they started.
+fn cost_rows(sessions: &[(String, Vec<McpEvent>)], weekly: bool) -> BTreeMap<String, CostRow> {
+    let mut rows: BTreeMap<String, CostRow> = BTreeMap::new();
+    for (_, events) in sessions {
+        for e in events.iter().filter(|e| !e.dry_run) {
+            let Some(key) = bucket(&e.timestamp, weekly) else {
+                continue;
+            };
+            let row = rows.entry(key).or_default();
+            row.calls += 1;
+            row.input += e.input_tokens().unwrap_or(0);
+            row.output += e.output_tokens().unwrap_or(0);
+            row.cost += event_cost_usd(e).unwrap_or(0.0);
+        }
+        let start = events.first().and_then(|e| bucket(&e.timestamp, weekly));
+        if let (Some(ct), Some(key)) = (cursor_session_tokens(events), start) {
+            let row = rows.entry(key).or_default();
+            row.input += ct.input_tokens;
+            row.output += ct.output_tokens;
+            row.cost += ct.cost_usd;
+        }
+    }
+    rows
+}
+
+fn bucket(timestamp: &str, weekly: bool) -> Option<String> {
+    let day = timestamp.get(..10)?;
+    if !weekly {
+        return Some(day.to_string());
+    }
+    let date = chrono::NaiveDate::parse_from_str(day, "%Y-%m-%d").ok()?;
+    let monday = date - chrono::Duration::days(date.weekday().num_days_from_monday().into());
+    Some(monday.format("%Y-%m-%d").to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn priced(timestamp: &str, input: u64, output: u64) -> McpEvent {
+        let mut e = McpEvent {
+            timestamp: timestamp.to_string(),
+            server: "vigilo".to_string(),
+            tool: "read_file".to_string(),
+            ..Default::default()
+        };
+        e.token_usage.model = Some("claude-sonnet-4-5".to_string());
+        e.token_usage.input_tokens = Some(input);
+        e.token_usage.output_tokens = Some(output);
+        e
+    }
+
+    #[test]
+    fn events_on_different_days_get_separate_rows() {
+        let a = priced("2026-03-01T10:00:00Z", 1_000_000, 0);
+        let b = priced("2026-03-01T18:00:00Z", 0, 1_000_000);
+        let c = priced("2026-03-02T09:00:00Z", 2_000_000, 0);
+        let sessions = vec![("s1".to_string(), vec![a, b, c])];
+
+        let rows = cost_rows(&sessions, false);
+        assert_eq!(rows.len(), 2);
+        let first = &rows["2026-03-01"];
+        assert_eq!(
+            (first.calls, first.input, first.output),
+            (2, 1_000_000, 1_000_000)
+        );
+        assert!((first.cost - 18.0).abs() < 1e-9);
+        let second = &rows["2026-03-02"];
+        assert_eq!((second.calls, second.input), (1, 2_000_000));
+        assert!((second.cost - 6.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn dry_run_events_are_not_counted_or_costed() {
+        let mut dry = priced("2026-03-01T10:00:00Z", 1_000_000, 0);
+        dry.dry_run = true;
+        let real = priced("2026-03-01T11:00:00Z", 1_000_000, 0);
+        let sessions = vec![("s1".to_string(), vec![dry, real])];
+
+        let day = &cost_rows(&sessions, false)["2026-03-01"];
+        assert_eq!((day.calls, day.input), (1, 1_000_000));
+        assert!((day.cost - 3.0).abs() < 1e-9);
+        let refs: Vec<&McpEvent> = sessions[0].1.iter().collect();
+        assert_eq!(
+            crate::view::counts::EventCounts::from_events(&refs).total,
+            1
+        );
+    }
+
+    #[test]
+    fn weekly_buckets_key_on_monday() {
+        assert_eq!(bucket("2026-03-04T10:00:00Z", true).unwrap(), "2026-03-02");
+        assert_eq!(bucket("2026-03-02T00:00:00Z", true).unwrap(), "2026-03-02");
+        assert_eq!(bucket("2026-03-08T23:59:59Z", true).unwrap(), "2026-03-02");
+        assert!(bucket("bad", false).is_none());
+    }
+}
@@ -1,7 +1,7 @@
 use super::data::cursor_session_tokens;
 use super::fmt::{
-    client_badge, cprintln, event_cost_usd, fmt_arg, fmt_cost, fmt_tokens, normalize_model, trunc,
-    BOLD, BRIGHT_RED, DIM, RED, RESET,
+    client_badge, cprintln, event_cost_usd, fmt_arg, fmt_cache, fmt_cost, fmt_tokens,
+    normalize_model, trunc, BOLD, BRIGHT_RED, DIM, RED, RESET,
 };
 use crate::{
     crypto,
@@ -19,11 +19,14 @@ pub(crate) struct EventCounts {
     pub total_in: u64,
     pub total_out: u64,
     pub total_cr: u64,
+    pub total_cw: u64,
     pub total_cost: f64,
 }
 
 impl EventCounts {
+    /// Dry-run calls are left out: nothing was actually read, written or run.
     pub fn from_events(events: &[&McpEvent]) -> Self {
+        let events: Vec<&McpEvent> = events.iter().copied().filter(|e| !e.dry_run).collect();
         Self {
             total: events.len(),
             reads: events
@@ -46,6 +49,7 @@ impl EventCounts {
             total_in: events.iter().filter_map(|e| e.input_tokens()).sum(),
             total_out: events.iter().filter_map(|e| e.output_tokens()).sum(),
             total_cr: events.iter().filter_map(|e| e.cache_read_tokens()).sum(),
+            total_cw: events.iter().filter_map(|e| e.cache_write_tokens()).sum(),
             total_cost: events.iter().filter_map(|e| event_cost_usd(e)).sum(),
         }
     }
@@ -67,13 +71,18 @@ impl EventCounts {
     }
 }
 
-pub(super) fn print_tool_file_table(events: &[&McpEvent]) {
+pub(super) fn print_tool_file_table(events: &[&McpEvent], max_rows: usize) {
     let tools = count_tools(events);
     let files = count_files(events);
-    print_two_column_table(&tools, &files);
+    println!();
+    cprintln!("  {BOLD}tools{RESET}                    {BOLD}files{RESET}");
+    cprintln!("  {DIM}─────                    ─────{RESET}");
+    for row in two_column_rows(&tools, &files, max_rows) {
+        cprintln!("{row}");
+    }
 }
 
-fn count_tools(events: &[&McpEvent]) -> Vec<(String, usize)> {
+pub(super) fn count_tools(events: &[&McpEvent]) -> Vec<(String, usize)> {
     let mut counts: HashMap<&str, usize> = HashMap::new();
     for e in events {
         *counts.entry(&e.tool).or_default() += 1;
@@ -82,11 +91,12 @@ fn count_tools(events: &[&McpEvent]) -> Vec<(String, usize)> {
         .into_iter()
         .map(|(k, v)| (k.to_string(), v))
         .collect();
+    #[allow(clippy::unnecessary_sort_by)]
     sorted.sort_by(|a, b| b.1.cmp(&a.1));
     sorted
 }
 
-fn count_files(events: &[&McpEvent]) -> Vec<(String, usize)> {
+pub(super) fn count_files(events: &[&McpEvent]) -> Vec<(String, usize)> {
     let mut counts: HashMap<String, usize> = HashMap::new();
     for e in events {
         if let Some(path) = e
@@ -102,17 +112,19 @@ fn count_files(events: &[&McpEvent]) -> Vec<(String, usize)> {
         }
     }
     let mut sorted: Vec<(String, usize)> = counts.into_iter().collect();
+    #[allow(clippy::unnecessary_sort_by)]
     sorted.sort_by(|a, b| b.1.cmp(&a.1));
     sorted
 }
 
-fn print_two_column_table(tools: &[(String, usize)], files: &[(String, usize)]) {
-    println!();
-    cprintln!("  {BOLD}tools{RESET}                    {BOLD}files{RESET}");
-    cprintln!("  {DIM}─────                    ─────{RESET}");
-
-    let max_rows = super::MAX_TABLE_ROWS;
-    for i in 0..max_rows {
+/// Tools and files side by side, at most `max_rows` lines.
+fn two_column_rows(
+    tools: &[(String, usize)],
+    files: &[(String, usize)],
+    max_rows: usize,
+) -> Vec<String> {
+    let mut rows = Vec::new();
+    for i in 0..max_rows.min(tools.len().max(files.len())) {
         let tool_col = if i < tools.len() {
             format!("  {BOLD}{:>4}×{RESET} {:<20}", tools[i].1, tools[i].0)
         } else {
@@ -123,22 +135,26 @@ fn print_two_column_table(tools: &[(String, usize)], files: &[(String, usize)])
         } else {
             String::new()
         };
-        if i < tools.len() || i < files.len() {
-            cprintln!("{tool_col}{file_col}");
-        }
+        rows.push(format!("{tool_col}{file_col}"));
     }
+    rows
 }
 
 #[derive(Default)]
-struct ModelStats {
-    calls: usize,
-    input: u64,
-    output: u64,
-    cache_read: u64,
-    cost: f64,
+pub(super) struct ModelStats {
+    pub calls: usize,
+    pub input: u64,
+    pub output: u64,
+    pub cache_read: u64,
+    pub cache_write: u64,
+    pub cost: f64,
 }
 
-pub(super) fn print_models_section(events: &[&McpEvent], sessions: &[(String, Vec<McpEvent>)]) {
+/// Per-model call and token totals, Cursor session tokens included, most-called first.
+pub(super) fn count_models(
+    events: &[&McpEvent],
+    sessions: &[(String, Vec<McpEvent>)],
+) -> Vec<(String, ModelStats)> {
     let mut model_counts: HashMap<String, ModelStats> = HashMap::new();
     for e in events {
         if let Some(m) = e.model() {
@@ -149,6 +165,7 @@ pub(super) fn print_models_section(events: &[&McpEvent], sessions: &[(String, Ve
             entry.input += e.input_tokens().unwrap_or(0);
             entry.output += e.output_tokens().unwrap_or(0);
             entry.cache_read += e.cache_read_tokens().unwrap_or(0);
+            entry.cache_write += e.cache_write_tokens().unwrap_or(0);
             if let Some(c) = event_cost_usd(e) {
                 entry.cost += c;
             }
@@ -163,16 +180,26 @@ pub(super) fn print_models_section(events: &[&McpEvent], sessions: &[(String, Ve
             entry.cost += ct.cost_usd;
         }
     }
-    if model_counts.is_empty() {
-        return;
-    }
     let mut models: Vec<_> = model_counts.into_iter().collect();
+    #[allow(clippy::unnecessary_sort_by)]
     models.sort_by(|a, b| b.1.calls.cmp(&a.1.calls));
+    models
+}
+
+pub(super) fn print_models_section(
+    events: &[&McpEvent],
+    sessions: &[(String, Vec<McpEvent>)],
+    max_rows: usize,
+) {
+    let models = count_models(events, sessions);
+    if models.is_empty() {
+        return;
+    }
     println!();
     cprintln!("  {BOLD}models{RESET}");
     cprintln!("  {DIM}──────{RESET}");
-    for (model, s) in &models {
-        let tok_str = format_model_tokens(s.input, s.output, s.cache_read);
+    for (model, s) in models.iter().take(max_rows) {
+        let tok_str = format_model_tokens(s);
         let cost_str = if s.cost > 0.0 {
             format!(" · ~{}", fmt_cost(s.cost))
         } else {
@@ -183,19 +210,15 @@ pub(super) fn print_models_section(events: &[&McpEvent], sessions: &[(String, Ve
     print_model_totals(&models);
 }
 
-fn format_model_tokens(inp: u64, out: u64, cr: u64) -> String {
-    if inp == 0 && out == 0 && cr == 0 {
+fn format_model_tokens(s: &ModelStats) -> String {
+    if s.input == 0 && s.output == 0 && s.cache_read == 0 && s.cache_write == 0 {
         return String::new();
     }
-    let cache_part = if cr > 0 {
-        format!(" · cache:{}", fmt_tokens(cr))
-    } else {
-        String::new()
-    };
     format!(
-        "     {DIM}{} in · {} out{cache_part}{RESET}",
-        fmt_tokens(inp),
-        fmt_tokens(out)
+        "     {DIM}{} in · {} out{}{RESET}",
+        fmt_tokens(s.input),
+        fmt_tokens(s.output),
+        fmt_cache(s.cache_read, s.cache_write)
     )
 }
 
@@ -204,15 +227,12 @@ fn print_model_totals(models: &[(String, ModelStats)]) {
     let total_in: u64 = models.iter().map(|(_, s)| s.input).sum();
     let total_out: u64 = models.iter().map(|(_, s)| s.output).sum();
     let total_cr: u64 = models.iter().map(|(_, s)| s.cache_read).sum();
+    let total_cw: u64 = models.iter().map(|(_, s)| s.cache_write).sum();
     if total_cost == 0.0 && total_in == 0 && total_out == 0 {
         return;
     }
     println!();
-    let cache_part = if total_cr > 0 {
-        format!(" · cache: {} read", fmt_tokens(total_cr))
-    } else {
-        String::new()
-    };
+    let cache_part = fmt_cache(total_cr, total_cw);
     cprintln!(
         "  {DIM}total: {} in · {} out{cache_part}{RESET}",
         fmt_tokens(total_in),
@@ -223,7 +243,16 @@ fn print_model_totals(models: &[(String, ModelStats)]) {
     }
 }
 
-pub(super) fn print_projects_section(events: &[&McpEvent]) {
+pub(super) struct ProjectCounts {
+    pub name: String,
+    pub calls: usize,
+    pub reads: usize,
+    pub writes: usize,
+    pub execs: usize,
+}
+
+/// Calls per project with a read/write/exec breakdown, busiest first.
+pub(super) fn count_projects(events: &[&McpEvent]) -> Vec<ProjectCounts> {
     let mut project_counts: HashMap<String, usize> = HashMap::new();
     let mut project_risk: HashMap<String, (usize, usize, usize)> = HashMap::new();
 
@@ -245,28 +274,53 @@ pub(super) fn print_projects_section(events: &[&McpEvent]) {
         }
     }
 
-    let mut projects: Vec<(String, usize)> = project_counts.into_iter().collect();
-    projects.sort_by(|a, b| b.1.cmp(&a.1));
+    let mut projects: Vec<ProjectCounts> = project_counts
+        .into_iter()
+        .map(|(name, calls)| {
+            let (reads, writes, execs) = project_risk.get(&name).copied().unwrap_or((0, 0, 0));
+            ProjectCounts {
+                name,
+                calls,
+                reads,
+                writes,
+                execs,
+            }
+        })
+        .collect();
+    projects.sort_by_key(|p| std::cmp::Reverse(p.calls));
+    projects
+}
+
+pub(super) fn print_projects_section(events: &[&McpEvent], max_rows: usize) {
     println!();
     cprintln!("  {BOLD}projects{RESET}");
     cprintln!("  {DIM}────────{RESET}");
-    for (name, count) in &projects {
-        let (r, w, e) = project_risk
-            .get(name.as_str())
-            .copied()
-            .unwrap_or((0, 0, 0));
-        cprintln!("  {BOLD}{count:>4}×{RESET} {name}  {DIM}r:{r} w:{w} e:{e}{RESET}");
+    for p in count_projects(events).into_iter().take(max_rows) {
+        cprintln!(
+            "  {BOLD}{:>4}×{RESET} {}  {DIM}r:{} w:{} e:{}{RESET}",
+            p.calls,
+            p.name,
+            p.reads,
+            p.writes,
+            p.execs
+        );
     }
 }
 
-pub(super) fn print_error_chart(err_events: &[&McpEvent]) {
-    let err_count = err_events.len();
+pub(super) fn count_error_tools<'a>(err_events: &[&'a McpEvent]) -> Vec<(&'a str, usize)> {
     let mut by_tool: HashMap<&str, usize> = HashMap::new();
     for e in err_events {
         *by_tool.entry(e.tool.as_str()).or_default() += 1;
     }
     let mut tool_list: Vec<(&str, usize)> = by_tool.into_iter().collect();
+    #[allow(clippy::unnecessary_sort_by)]
     tool_list.sort_by(|a, b| b.1.cmp(&a.1));
+    tool_list
+}
+
+pub(super) fn print_error_chart(err_events: &[&McpEvent]) {
+    let err_count = err_events.len();
+    let tool_list = count_error_tools(err_events);
 
     println!();
     cprintln!("  {BOLD}by tool{RESET}");
@@ -375,3 +429,24 @@ pub(crate) fn collect_active_projects(sessions: &[(String, Vec<McpEvent>)]) -> V
     }
     active
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn top_limits_tool_rows() {
+        let named = |names: &[&str]| -> Vec<(String, usize)> {
+            names.iter().map(|n| (n.to_string(), 1)).collect()
+        };
+        let tools = named(&["Read", "Edit", "Bash", "Grep", "Glob"]);
+        let files = named(&["main.rs", "lib.rs"]);
+
+        let rows = two_column_rows(&tools, &files, 3);
+        assert_eq!(rows.len(), 3);
+        assert!(rows[2].contains("Bash"));
+        assert!(!rows.iter().any(|r| r.contains("Grep")));
+
+        assert_eq!(two_column_rows(&tools, &files, 8).len(), 5);
+    }
+}
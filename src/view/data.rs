@@ -5,30 +5,33 @@ use crate::{
 use anyhow::Result;
 use std::collections::HashMap;
 use std::fs::File;
-use std::io::{BufRead, BufReader};
+use std::io::{BufRead, BufReader, Read};
 
 #[derive(Default)]
 pub(crate) struct LoadFilter<'a> {
     pub since: Option<&'a str>,
     pub until: Option<&'a str>,
     pub session: Option<&'a str>,
+    pub project: Option<&'a str>,
+    pub tag: Option<&'a str>,
     pub last: Option<usize>,
 }
 
 impl LoadFilter<'_> {
-    pub(crate) fn matches_date(&self, timestamp: &str) -> bool {
-        let date = timestamp.get(..10).unwrap_or("");
-        if let Some(since) = self.since {
-            if date < since {
-                return false;
-            }
-        }
-        if let Some(until) = self.until {
-            if date > until {
-                return false;
-            }
+    fn date_range(&self) -> DateRange {
+        DateRange {
+            since: self.since.map(DateBound::parse),
+            until: self.until.map(DateBound::parse),
         }
-        true
+    }
+
+    fn matches_project(&self, event: &McpEvent) -> bool {
+        self.project
+            .is_none_or(|p| event.project.name.as_deref() == Some(p))
+    }
+
+    fn matches_tag(&self, event: &McpEvent) -> bool {
+        self.tag.is_none_or(|t| event.tag.as_deref() == Some(t))
     }
 
     fn matches_session(&self, session_id: &str) -> bool {
@@ -41,6 +44,91 @@ impl LoadFilter<'_> {
     }
 }
 
+/// A `--since` / `--until` value: a whole calendar day (`2026-02-01`, `7d`),
+/// or an instant when it carries a time (`6h`, `2026-02-01 09:00`).
+#[derive(Debug, Clone, PartialEq)]
+enum DateBound {
+    Day(String),
+    Instant(chrono::DateTime<chrono::Utc>),
+}
+
+impl DateBound {
+    /// Clock times without an offset are read in the local time zone.
+    fn parse(s: &str) -> Self {
+        Self::parse_in(s, &chrono::Local)
+    }
+
+    fn parse_in<Tz: chrono::TimeZone>(s: &str, tz: &Tz) -> Self {
+        let s = s.trim();
+        if let Some(instant) = parse_instant(s) {
+            return DateBound::Instant(instant);
+        }
+        for fmt in [
+            "%Y-%m-%d %H:%M",
+            "%Y-%m-%d %H:%M:%S",
+            "%Y-%m-%dT%H:%M",
+            "%Y-%m-%dT%H:%M:%S",
+        ] {
+            let Ok(naive) = chrono::NaiveDateTime::parse_from_str(s, fmt) else {
+                continue;
+            };
+            if let Some(local) = tz.from_local_datetime(&naive).earliest() {
+                return DateBound::Instant(local.to_utc());
+            }
+        }
+        DateBound::Day(s.to_string())
+    }
+
+    /// Where `timestamp` falls relative to this bound.
+    fn cmp_event(&self, timestamp: &str) -> std::cmp::Ordering {
+        let date = timestamp.get(..10).unwrap_or("");
+        match self {
+            DateBound::Day(day) => date.cmp(day.as_str()),
+            DateBound::Instant(bound) => match parse_instant(timestamp) {
+                Some(ts) => ts.cmp(bound),
+                None => date.cmp(bound.format("%Y-%m-%d").to_string().as_str()),
+            },
+        }
+    }
+
+    /// Start of the bound in epoch milliseconds (start of day UTC for a date).
+    fn epoch_ms(&self) -> Option<u128> {
+        let ms = match self {
+            DateBound::Instant(instant) => instant.timestamp_millis(),
+            DateBound::Day(day) => chrono::NaiveDate::parse_from_str(day, "%Y-%m-%d")
+                .ok()?
+                .and_hms_opt(0, 0, 0)?
+                .and_utc()
+                .timestamp_millis(),
+        };
+        u128::try_from(ms).ok()
+    }
+}
+
+/// Both bounds parsed once per load rather than once per event.
+struct DateRange {
+    since: Option<DateBound>,
+    until: Option<DateBound>,
+}
+
+impl DateRange {
+    fn contains(&self, timestamp: &str) -> bool {
+        self.since
+            .as_ref()
+            .is_none_or(|b| b.cmp_event(timestamp).is_ge())
+            && self
+                .until
+                .as_ref()
+                .is_none_or(|b| b.cmp_event(timestamp).is_le())
+    }
+}
+
+fn parse_instant(s: &str) -> Option<chrono::DateTime<chrono::Utc>> {
+    chrono::DateTime::parse_from_rfc3339(s)
+        .ok()
+        .map(|dt| dt.to_utc())
+}
+
 /// Returns (path, rotation_timestamp_ms) for rotated files, sorted oldest first,
 /// with the active ledger file appended last (timestamp = u128::MAX).
 fn all_ledger_files_with_ts(ledger_path: &std::path::Path) -> Vec<(std::path::PathBuf, u128)> {
@@ -58,16 +146,13 @@ fn all_ledger_files_with_ts(ledger_path: &std::path::Path) -> Vec<(std::path::Pa
             if name == active_name {
                 return None;
             }
-            if name.starts_with(stem) && name.ends_with(".jsonl") {
-                let ts: u128 = name
-                    .strip_prefix(&format!("{stem}."))?
-                    .strip_suffix(".jsonl")?
-                    .parse()
-                    .ok()?;
-                Some((e.path(), ts))
-            } else {
-                None
+            let ts = crate::ledger::rotated_ts(&name, stem)?;
+            // a plain copy beside its finished `.gz` is a compression that
+            // crashed before removing it
+            if !name.ends_with(".gz") && parent.join(format!("{name}.gz")).exists() {
+                return None;
             }
+            Some((e.path(), ts))
         })
         .collect();
 
@@ -85,24 +170,116 @@ pub(crate) fn all_ledger_files(
         .collect()
 }
 
-/// Convert "YYYY-MM-DD" to epoch milliseconds (start of day UTC).
-fn date_to_epoch_ms(date: &str) -> Option<u128> {
-    let dt = chrono::NaiveDate::parse_from_str(date, "%Y-%m-%d").ok()?;
-    let ts = dt.and_hms_opt(0, 0, 0)?.and_utc().timestamp_millis();
-    Some(ts as u128)
+/// A ledger file that exists but could not be read, with the reason.
+type SkippedFile = (std::path::PathBuf, std::io::Error);
+
+fn report_skipped(skipped: &[SkippedFile]) {
+    for (path, err) in skipped {
+        eprintln!("vigilo: skipped {}: {err}", path.display());
+    }
+}
+
+/// Opens a ledger file for reading, decompressing rotated `.gz` files.
+pub(crate) fn open_ledger_reader(path: &std::path::Path) -> std::io::Result<Box<dyn Read>> {
+    let f = File::open(path)?;
+    if path.extension().is_some_and(|ext| ext == "gz") {
+        return Ok(Box::new(flate2::read::GzDecoder::new(f)));
+    }
+    Ok(Box::new(f))
+}
+
+/// Missing files are expected (rotation races, fresh installs) and stay silent.
+fn open_ledger_file(
+    path: &std::path::Path,
+    skipped: &mut Vec<SkippedFile>,
+) -> Option<Box<dyn Read>> {
+    match open_ledger_reader(path) {
+        Ok(r) => Some(r),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => None,
+        Err(e) => {
+            skipped.push((path.to_path_buf(), e));
+            None
+        }
+    }
+}
+
+/// Yields lines until the first I/O error, which is recorded; undecodable lines are skipped.
+fn ledger_lines<'a>(
+    path: &'a std::path::Path,
+    file: impl Read + 'a,
+    skipped: &'a mut Vec<SkippedFile>,
+) -> impl Iterator<Item = String> + 'a {
+    BufReader::new(file)
+        .lines()
+        .map_while(move |line| match line {
+            Ok(l) => Some(Some(l)),
+            Err(e) if e.kind() == std::io::ErrorKind::InvalidData => Some(None),
+            Err(e) => {
+                skipped.push((path.to_path_buf(), e));
+                None
+            }
+        })
+        .flatten()
+}
+
+/// Reads newline-terminated lines and returns them with the number of bytes
+/// consumed. A trailing line without `\n` may still be mid-write, so it is
+/// left unread; the caller resumes from the returned offset once it completes.
+pub(crate) fn read_complete_lines(
+    reader: &mut impl BufRead,
+) -> std::io::Result<(Vec<String>, u64)> {
+    let mut lines = Vec::new();
+    let mut consumed = 0u64;
+    let mut buf = Vec::new();
+    loop {
+        buf.clear();
+        let n = reader.read_until(b'\n', &mut buf)?;
+        if n == 0 || buf.last() != Some(&b'\n') {
+            break;
+        }
+        consumed += n as u64;
+        lines.push(String::from_utf8_lossy(&buf).trim_end().to_string());
+    }
+    Ok((lines, consumed))
 }
 
 pub(crate) fn load_sessions(
     ledger_path: impl AsRef<std::path::Path>,
     filter: &LoadFilter,
 ) -> Result<Vec<(String, Vec<McpEvent>)>> {
-    let files = all_ledger_files_with_ts(ledger_path.as_ref());
+    let mut skipped = Vec::new();
+    let sessions = load_sessions_into(ledger_path.as_ref(), filter, &mut skipped);
+    report_skipped(&skipped);
+    sessions
+}
+
+/// Like [`load_sessions`], but a ledger file that exists and can't be read is
+/// an error rather than a warning, for exports that must not come out partial.
+pub(crate) fn load_sessions_complete(
+    ledger_path: impl AsRef<std::path::Path>,
+    filter: &LoadFilter,
+) -> Result<Vec<(String, Vec<McpEvent>)>> {
+    let mut skipped = Vec::new();
+    let sessions = load_sessions_into(ledger_path.as_ref(), filter, &mut skipped)?;
+    match skipped.into_iter().next() {
+        Some((path, err)) => Err(anyhow::anyhow!("could not read {}: {err}", path.display())),
+        None => Ok(sessions),
+    }
+}
+
+fn load_sessions_into(
+    ledger_path: &std::path::Path,
+    filter: &LoadFilter,
+    skipped: &mut Vec<SkippedFile>,
+) -> Result<Vec<(String, Vec<McpEvent>)>> {
+    let files = all_ledger_files_with_ts(ledger_path);
     let any_exists = files.iter().any(|(f, _)| f.exists());
     if !any_exists {
         return Ok(Vec::new());
     }
 
-    let since_ms = filter.since.and_then(date_to_epoch_ms);
+    let range = filter.date_range();
+    let since_ms = range.since.as_ref().and_then(DateBound::epoch_ms);
 
     let mut map: HashMap<String, Vec<McpEvent>> = HashMap::new();
 
@@ -120,11 +297,10 @@ pub(crate) fn load_sessions(
                 continue;
             }
         }
-        let Ok(file) = File::open(file_path) else {
+        let Some(file) = open_ledger_file(file_path, skipped) else {
             continue;
         };
-        for line in BufReader::new(file).lines() {
-            let Ok(line) = line else { continue };
+        for line in ledger_lines(file_path, file, skipped) {
             if line.trim().is_empty() {
                 continue;
             }
@@ -136,7 +312,10 @@ pub(crate) fn load_sessions(
                 if !filter.matches_session(&sid) {
                     continue;
                 }
-                if !filter.matches_date(&event.timestamp) {
+                if !range.contains(&event.timestamp)
+                    || !filter.matches_project(&event)
+                    || !filter.matches_tag(&event)
+                {
                     continue;
                 }
                 map.entry(sid).or_default().push(event);
@@ -177,14 +356,13 @@ pub(crate) fn load_tail_events(
     }
 
     let mut events: Vec<McpEvent> = Vec::new();
+    let mut skipped = Vec::new();
 
     for file_path in files.iter().rev() {
-        let Ok(file) = File::open(file_path) else {
+        let Some(file) = open_ledger_file(file_path, &mut skipped) else {
             continue;
         };
-        let mut batch: Vec<McpEvent> = BufReader::new(file)
-            .lines()
-            .map_while(Result::ok)
+        let mut batch: Vec<McpEvent> = ledger_lines(file_path, file, &mut skipped)
             .filter(|l| !l.trim().is_empty())
             .filter_map(|l| {
                 let mut e: McpEvent = serde_json::from_str(&l).ok()?;
@@ -203,6 +381,7 @@ pub(crate) fn load_tail_events(
         }
     }
 
+    report_skipped(&skipped);
     events.sort_by(|a, b| a.timestamp.cmp(&b.timestamp));
     let skip = events.len().saturating_sub(n);
     events.drain(..skip);
@@ -223,6 +402,86 @@ pub(crate) fn cursor_session_tokens(events: &[McpEvent]) -> Option<cursor::Cache
     cursor::aggregate_cached_tokens(&cached)
 }
 
+/// Drops repeats of the same `(session_id, tool, timestamp)`, keeping the first:
+/// the MCP server and a PostToolUse hook can both log one underlying call.
+pub(crate) fn dedup_sessions(sessions: &mut [(String, Vec<McpEvent>)]) {
+    for (_, events) in sessions {
+        let mut seen = std::collections::HashSet::new();
+        events.retain(|e| seen.insert((e.session_id, e.tool.clone(), e.timestamp.clone())));
+    }
+}
+
+/// Merges sessions with the same server and project that start less than
+/// `gap_secs` after such a group last saw activity. Each session gets its
+/// group, numbered in order of first appearance, and the idle seconds since
+/// the group's previous session when it joined an existing one. Empty
+/// sessions get `None`.
+pub(crate) fn merge_sessions(
+    sessions: &[(String, Vec<McpEvent>)],
+    gap_secs: u64,
+) -> Vec<Option<(usize, Option<i64>)>> {
+    let mut group_ends: Vec<i64> = Vec::new();
+    let mut by_key: HashMap<(&str, Option<&str>), Vec<usize>> = HashMap::new();
+    sessions
+        .iter()
+        .map(|(_, events)| {
+            let (first, last) = (events.first()?, events.last()?);
+            let start = ts_to_epoch(&first.timestamp).unwrap_or(0);
+            let end = ts_to_epoch(&last.timestamp).unwrap_or(0);
+            let candidates = by_key
+                .entry((first.server.as_str(), first.project.name.as_deref()))
+                .or_default();
+            let joined = candidates
+                .iter()
+                .rev()
+                .copied()
+                .find(|&g| start.abs_diff(group_ends[g]) < gap_secs);
+            Some(match joined {
+                Some(group) => {
+                    let idle_secs = (start - group_ends[group]).max(0);
+                    group_ends[group] = group_ends[group].max(end);
+                    (group, Some(idle_secs))
+                }
+                None => {
+                    let group = group_ends.len();
+                    group_ends.push(end);
+                    candidates.push(group);
+                    (group, None)
+                }
+            })
+        })
+        .collect()
+}
+
+/// Parse an RFC 3339 timestamp to epoch seconds.
+pub(crate) fn ts_to_epoch(ts: &str) -> Option<i64> {
+    chrono::DateTime::parse_from_rfc3339(ts)
+        .or_else(|_| chrono::DateTime::parse_from_rfc3339(&format!("{ts}Z")))
+        .ok()
+        .map(|dt| dt.timestamp())
+}
+
+const DEFAULT_MERGE_GAP_SECS: u64 = 7200; // 2 hours
+
+/// Idle gap below which consecutive sessions coalesce: `SESSION_MERGE_GAP_SECS`,
+/// defaulting to 2 hours. Read once, so the views and every dashboard request
+/// share one value.
+pub(crate) fn session_merge_gap_secs() -> u64 {
+    static GAP: std::sync::OnceLock<u64> = std::sync::OnceLock::new();
+    *GAP.get_or_init(|| {
+        crate::models::config_setting(&crate::models::load_config(), "SESSION_MERGE_GAP_SECS")
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(DEFAULT_MERGE_GAP_SECS)
+    })
+}
+
+/// Wall-clock seconds from a session's first event to its last.
+pub(crate) fn session_span_secs(events: &[McpEvent]) -> Option<i64> {
+    let first = ts_to_epoch(&events.first()?.timestamp)?;
+    let last = ts_to_epoch(&events.last()?.timestamp)?;
+    Some((last - first).max(0))
+}
+
 fn session_time_range_ms(events: &[McpEvent]) -> Option<(i64, i64)> {
     let parse_ts = |ts: &str| -> Option<i64> {
         chrono::DateTime::parse_from_rfc3339(ts)
@@ -275,6 +534,64 @@ mod tests {
         }
     }
 
+    #[test]
+    fn unreadable_rotated_file_is_reported_while_active_ledger_loads() {
+        let dir = tempfile::tempdir().unwrap();
+        let ledger = dir.path().join("events.jsonl");
+        // A directory where a rotated file should be: opens, but every read fails
+        let broken = dir.path().join("events.1700000000000.jsonl");
+        std::fs::create_dir(&broken).unwrap();
+        let sid = Uuid::new_v4();
+        write_events(
+            ledger.to_str().unwrap(),
+            &[make_event(sid, "read_file", "2026-02-19T10:00:00Z")],
+        );
+
+        let mut skipped = Vec::new();
+        let sessions = load_sessions_into(&ledger, &LoadFilter::default(), &mut skipped).unwrap();
+        assert_eq!(sessions.len(), 1);
+        assert_eq!(sessions[0].1.len(), 1);
+        assert_eq!(skipped.len(), 1);
+        assert_eq!(skipped[0].0, broken);
+    }
+
+    #[test]
+    fn gzipped_rotated_file_loads_with_active_ledger() {
+        let dir = tempfile::tempdir().unwrap();
+        let ledger = dir.path().join("events.jsonl");
+        let sid = Uuid::new_v4();
+        let old = make_event(sid, "read_file", "2026-02-18T10:00:00Z");
+        let gz = std::fs::File::create(dir.path().join("events.1700000000000.jsonl.gz")).unwrap();
+        let mut encoder = flate2::write::GzEncoder::new(gz, flate2::Compression::default());
+        writeln!(encoder, "{}", serde_json::to_string(&old).unwrap()).unwrap();
+        encoder.finish().unwrap();
+        write_events(
+            ledger.to_str().unwrap(),
+            &[make_event(sid, "write_file", "2026-02-19T10:00:00Z")],
+        );
+
+        let sessions = load_sessions(&ledger, &LoadFilter::default()).unwrap();
+        let tools: Vec<&str> = sessions[0].1.iter().map(|e| e.tool.as_str()).collect();
+        assert_eq!(tools, ["read_file", "write_file"]);
+
+        let tail = load_tail_events(&ledger, 10).unwrap();
+        assert_eq!(tail.len(), 2);
+    }
+
+    #[test]
+    fn read_complete_lines_defers_partial_trailing_line() {
+        let partial = b"{\"a\":1}\n{\"b\":";
+        let (lines, consumed) = read_complete_lines(&mut &partial[..]).unwrap();
+        assert_eq!(lines, ["{\"a\":1}"]);
+        assert_eq!(consumed, 8);
+
+        let completed = b"{\"a\":1}\n{\"b\":2}\n";
+        let mut rest = &completed[consumed as usize..];
+        let (lines, consumed) = read_complete_lines(&mut rest).unwrap();
+        assert_eq!(lines, ["{\"b\":2}"]);
+        assert_eq!(consumed, 8);
+    }
+
     #[test]
     fn load_tail_events_returns_last_n() {
         let dir = tempfile::tempdir().unwrap();
@@ -363,6 +680,173 @@ mod tests {
         assert_eq!(total_events, 1);
     }
 
+    #[test]
+    fn hour_bound_compares_full_timestamps() {
+        let filter = LoadFilter {
+            since: Some("2026-02-19T06:00:00Z"),
+            ..Default::default()
+        };
+        assert!(!filter
+            .date_range()
+            .contains("2026-02-19T05:59:59.999+00:00"));
+        assert!(filter.date_range().contains("2026-02-19T06:00:00+00:00"));
+        assert!(filter.date_range().contains("2026-02-19T09:00:00+02:00"));
+        assert!(!filter.date_range().contains("2026-02-19T07:00:00+02:00"));
+
+        let until = LoadFilter {
+            until: Some("2026-02-19T06:00:00Z"),
+            ..Default::default()
+        };
+        assert!(until.date_range().contains("2026-02-18T23:00:00Z"));
+        assert!(!until.date_range().contains("2026-02-19T06:00:01Z"));
+
+        let day = LoadFilter {
+            since: Some("2026-02-19"),
+            until: Some("2026-02-19"),
+            ..Default::default()
+        };
+        assert!(day.date_range().contains("2026-02-19T23:59:59Z"));
+        assert!(!day.date_range().contains("2026-02-20T00:00:00Z"));
+    }
+
+    #[test]
+    fn clock_time_bounds_split_a_day() {
+        let utc = chrono::Utc;
+        assert_eq!(
+            DateBound::parse_in("2026-02-19", &utc),
+            DateBound::Day("2026-02-19".to_string())
+        );
+        let since = DateBound::parse_in("2026-02-19 09:00", &utc);
+        let until = DateBound::parse_in("2026-02-19T17:30:00", &utc);
+        assert!(matches!(since, DateBound::Instant(_)));
+        let window = DateRange {
+            since: Some(since),
+            until: Some(until),
+        };
+        assert!(!window.contains("2026-02-19T08:59:59+00:00"));
+        assert!(window.contains("2026-02-19T09:00:00+00:00"));
+        assert!(window.contains("2026-02-19T12:00:00.5+00:00"));
+        assert!(window.contains("2026-02-19T17:30:00Z"));
+        assert!(!window.contains("2026-02-19T17:30:01Z"));
+        assert!(!window.contains("2026-02-20T10:00:00Z"));
+
+        let tokyo = chrono::FixedOffset::east_opt(9 * 3600).unwrap();
+        assert_eq!(
+            DateBound::parse_in("2026-02-19 09:00", &tokyo),
+            DateBound::parse_in("2026-02-19T00:00:00Z", &utc)
+        );
+    }
+
+    #[test]
+    fn load_sessions_clock_time_window() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("events.jsonl");
+        let sid = Uuid::new_v4();
+        write_events(
+            path.to_str().unwrap(),
+            &[
+                make_event(sid, "early", "2026-02-19T08:00:00Z"),
+                make_event(sid, "inside", "2026-02-19T10:00:00Z"),
+                make_event(sid, "late", "2026-02-19T20:00:00Z"),
+            ],
+        );
+        let filter = LoadFilter {
+            since: Some("2026-02-19T09:00:00Z"),
+            until: Some("2026-02-19T18:00:00Z"),
+            ..Default::default()
+        };
+        let sessions = load_sessions(&path, &filter).unwrap();
+        let tools: Vec<&str> = sessions[0].1.iter().map(|e| e.tool.as_str()).collect();
+        assert_eq!(tools, ["inside"]);
+    }
+
+    #[test]
+    fn dedup_shows_a_double_logged_call_once() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("events.jsonl");
+        let sid = Uuid::new_v4();
+        let call = make_event(sid, "Bash", "2026-02-19T10:00:00Z");
+        let first_id = call.id;
+        write_events(
+            path.to_str().unwrap(),
+            &[
+                call,
+                make_event(sid, "Bash", "2026-02-19T10:00:00Z"),
+                make_event(sid, "Bash", "2026-02-19T10:00:05Z"),
+            ],
+        );
+        let mut sessions = load_sessions(&path, &LoadFilter::default()).unwrap();
+        assert_eq!(sessions[0].1.len(), 3);
+        dedup_sessions(&mut sessions);
+        let kept: Vec<_> = sessions[0].1.iter().map(|e| e.id).collect();
+        assert_eq!(kept.len(), 2);
+        assert_eq!(kept[0], first_id);
+    }
+
+    #[test]
+    fn load_sessions_project_filter_excludes_other_projects() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("events.jsonl");
+        let in_project = |name: &str| McpEvent {
+            project: ProjectContext {
+                name: Some(name.to_string()),
+                ..Default::default()
+            },
+            ..make_event(Uuid::new_v4(), "read_file", "2026-02-20T10:00:00Z")
+        };
+        write_events(
+            path.to_str().unwrap(),
+            &[in_project("api"), in_project("web"), in_project("api")],
+        );
+
+        let filter = LoadFilter {
+            project: Some("api"),
+            ..Default::default()
+        };
+        let sessions = load_sessions(&path, &filter).unwrap();
+        let events: Vec<&McpEvent> = sessions.iter().flat_map(|(_, e)| e).collect();
+        assert_eq!(events.len(), 2);
+        assert!(events
+            .iter()
+            .all(|e| e.project.name.as_deref() == Some("api")));
+    }
+
+    #[test]
+    fn load_sessions_tag_filter_isolates_tags() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("events.jsonl");
+        let tagged = |tool: &str, tag: &str| McpEvent {
+            tag: Some(tag.to_string()),
+            ..make_event(Uuid::new_v4(), tool, "2026-02-20T10:00:00Z")
+        };
+        write_events(
+            path.to_str().unwrap(),
+            &[
+                tagged("read_file", "feature-a"),
+                tagged("write_file", "feature-b"),
+                tagged("run_command", "feature-a"),
+                make_event(Uuid::new_v4(), "list_directory", "2026-02-20T10:00:00Z"),
+            ],
+        );
+
+        let tools = |tag: &str| -> Vec<String> {
+            let filter = LoadFilter {
+                tag: Some(tag),
+                ..Default::default()
+            };
+            let mut tools: Vec<String> = load_sessions(&path, &filter)
+                .unwrap()
+                .into_iter()
+                .flat_map(|(_, events)| events)
+                .map(|e| e.tool)
+                .collect();
+            tools.sort();
+            tools
+        };
+        assert_eq!(tools("feature-a"), ["read_file", "run_command"]);
+        assert_eq!(tools("feature-b"), ["write_file"]);
+    }
+
     #[test]
     fn all_ledger_files_finds_rotated_files() {
         let dir = tempfile::tempdir().unwrap();
@@ -370,6 +854,7 @@ mod tests {
         std::fs::write(dir.path().join("events.100.jsonl"), "").unwrap();
         std::fs::write(dir.path().join("events.200.jsonl"), "").unwrap();
 
+        #[allow(clippy::needless_borrows_for_generic_args)]
         let files = all_ledger_files(&dir.path().join("events.jsonl"));
         assert_eq!(files.len(), 3);
         // Active file should be last
@@ -7,16 +7,39 @@ use std::sync::OnceLock;
 
 static FORCE_NO_COLOR: AtomicBool = AtomicBool::new(false);
 static COLOR: OnceLock<bool> = OnceLock::new();
+static COLOR_STDERR: OnceLock<bool> = OnceLock::new();
 
 pub(crate) fn disable_color() {
     FORCE_NO_COLOR.store(true, Ordering::Relaxed);
 }
 
+/// Whether stdout gets ANSI colors: off for `--no-color`, a non-empty
+/// `NO_COLOR`, or when stdout isn't a terminal.
 pub(crate) fn use_color() -> bool {
+    color_for(&COLOR, atty::Stream::Stdout)
+}
+
+/// Same as `use_color`, for stderr — which often stays a terminal when stdout is piped.
+pub(crate) fn use_color_stderr() -> bool {
+    color_for(&COLOR_STDERR, atty::Stream::Stderr)
+}
+
+fn color_for(cache: &OnceLock<bool>, stream: atty::Stream) -> bool {
     if FORCE_NO_COLOR.load(Ordering::Relaxed) {
         return false;
     }
-    *COLOR.get_or_init(|| std::env::var("NO_COLOR").is_err() && atty::is(atty::Stream::Stdout))
+    *cache.get_or_init(|| {
+        let no_color = std::env::var("NO_COLOR").is_ok_and(|v| !v.is_empty());
+        !no_color && atty::is(stream)
+    })
+}
+
+pub(crate) fn render(s: &str, color: bool) -> std::borrow::Cow<'_, str> {
+    if color {
+        std::borrow::Cow::Borrowed(s)
+    } else {
+        std::borrow::Cow::Owned(strip_ansi(s))
+    }
 }
 
 pub(crate) fn strip_ansi(s: &str) -> String {
@@ -40,11 +63,7 @@ macro_rules! cprintln {
     () => { println!() };
     ($($arg:tt)*) => {{
         let s = format!($($arg)*);
-        if $crate::view::fmt::use_color() {
-            println!("{s}");
-        } else {
-            println!("{}", $crate::view::fmt::strip_ansi(&s));
-        }
+        println!("{}", $crate::view::fmt::render(&s, $crate::view::fmt::use_color()));
     }};
 }
 pub(crate) use cprintln;
@@ -53,11 +72,7 @@ macro_rules! ceprintln {
     () => { eprintln!() };
     ($($arg:tt)*) => {{
         let s = format!($($arg)*);
-        if $crate::view::fmt::use_color() {
-            eprintln!("{s}");
-        } else {
-            eprintln!("{}", $crate::view::fmt::strip_ansi(&s));
-        }
+        eprintln!("{}", $crate::view::fmt::render(&s, $crate::view::fmt::use_color_stderr()));
     }};
 }
 pub(crate) use ceprintln;
@@ -65,11 +80,7 @@ pub(crate) use ceprintln;
 macro_rules! ceprint {
     ($($arg:tt)*) => {{
         let s = format!($($arg)*);
-        if $crate::view::fmt::use_color() {
-            eprint!("{s}");
-        } else {
-            eprint!("{}", $crate::view::fmt::strip_ansi(&s));
-        }
+        eprint!("{}", $crate::view::fmt::render(&s, $crate::view::fmt::use_color_stderr()));
     }};
 }
 pub(crate) use ceprint;
@@ -85,10 +96,12 @@ pub(crate) const BRIGHT_RED: &str = "\x1b[91m";
 pub(crate) const WHITE: &str = "\x1b[97m";
 pub(crate) const BG_BLUE: &str = "\x1b[44m";
 pub(crate) const BG_MAGENTA: &str = "\x1b[45m";
+pub(crate) const BG_GREEN: &str = "\x1b[42m";
 
 pub(crate) fn client_badge(server: &str) -> String {
     match server {
         "cursor" => format!("{BG_MAGENTA}{BOLD}{WHITE} CURSOR {RESET}"),
+        "gemini" => format!("{BG_GREEN}{BOLD}{WHITE} GEMINI {RESET}"),
         _ => format!("{BG_BLUE}{BOLD}{WHITE} CLAUDE {RESET}"),
     }
 }
@@ -132,6 +145,22 @@ pub(crate) fn fmt_duration(us: u64) -> String {
     }
 }
 
+/// Wall-clock span at minute resolution once it passes a minute: `42s`, `17m`, `2h05m`.
+pub(crate) fn fmt_span(secs: i64) -> String {
+    match secs {
+        s if s < 60 => format!("{s}s"),
+        s if s < 3_600 => format!("{}m", s / 60),
+        s => format!("{}h{:02}m", s / 3_600, s % 3_600 / 60),
+    }
+}
+
+/// `⚑ <reason>` for a command the danger classifier flagged.
+pub(crate) fn flagged_badge(reason: Option<&str>) -> String {
+    reason
+        .map(|r| format!("  {BRIGHT_RED}⚑ {r}{RESET}"))
+        .unwrap_or_default()
+}
+
 pub(crate) fn fmt_tokens(n: u64) -> String {
     match n {
         n if n >= 1_000_000 => format!("{:.1}M", n as f64 / 1_000_000.0),
@@ -199,17 +228,90 @@ const PRICE_TABLE: &[(&str, f64, f64, f64)] = &[
     ("grok",                                           0.20,   1.50,   0.02),
 ];
 
+/// Per-million-token prices for models whose lowercased name contains `match`.
+#[derive(Clone, serde::Deserialize)]
+struct PriceEntry {
+    #[serde(rename = "match")]
+    fragment: String,
+    input: f64,
+    output: f64,
+    cache_read: f64,
+}
+
+static PRICING: OnceLock<Vec<PriceEntry>> = OnceLock::new();
+
+fn pricing_table() -> &'static [PriceEntry] {
+    PRICING.get_or_init(|| {
+        let path = crate::models::vigilo_path("pricing.json");
+        let custom = match std::fs::read_to_string(&path) {
+            Ok(text) => parse_pricing(&text).unwrap_or_else(|| {
+                eprintln!(
+                    "vigilo: ignoring malformed {}, using built-in prices",
+                    path.display()
+                );
+                Vec::new()
+            }),
+            Err(_) => Vec::new(),
+        };
+        merge_pricing(custom)
+    })
+}
+
+fn parse_pricing(text: &str) -> Option<Vec<PriceEntry>> {
+    let entries: Vec<PriceEntry> = serde_json::from_str(text).ok()?;
+    entries
+        .into_iter()
+        .map(|e| {
+            let valid = !e.fragment.is_empty()
+                && [e.input, e.output, e.cache_read]
+                    .iter()
+                    .all(|p| p.is_finite() && *p >= 0.0);
+            valid.then(|| PriceEntry {
+                fragment: e.fragment.to_lowercase(),
+                ..e
+            })
+        })
+        .collect()
+}
+
+/// Custom entries replace built-ins with the same `match` and add new ones.
+/// Longer fragments are tried first so `gpt-5-mini` wins over `gpt-5`.
+fn merge_pricing(custom: Vec<PriceEntry>) -> Vec<PriceEntry> {
+    let mut table: Vec<PriceEntry> = PRICE_TABLE
+        .iter()
+        .filter(|(f, ..)| !custom.iter().any(|c| c.fragment.eq_ignore_ascii_case(f)))
+        .map(|&(f, input, output, cache_read)| PriceEntry {
+            fragment: f.to_string(),
+            input,
+            output,
+            cache_read,
+        })
+        .collect();
+    table.extend(custom);
+    table.sort_by_key(|e| std::cmp::Reverse(e.fragment.len()));
+    table
+}
+
 fn pricing_for(model: &str) -> Option<(f64, f64, f64)> {
+    lookup_price(pricing_table(), model)
+}
+
+fn lookup_price(table: &[PriceEntry], model: &str) -> Option<(f64, f64, f64)> {
     let m = model.to_lowercase();
-    for (fragment, inp_m, out_m, cr_m) in PRICE_TABLE {
-        if m.contains(fragment) {
-            return Some((inp_m / 1_000_000.0, out_m / 1_000_000.0, cr_m / 1_000_000.0));
-        }
-    }
-    None
+    table.iter().find(|e| m.contains(&e.fragment)).map(|e| {
+        (
+            e.input / 1_000_000.0,
+            e.output / 1_000_000.0,
+            e.cache_read / 1_000_000.0,
+        )
+    })
 }
 
+/// Dry-run calls never reached a tool, so they cost nothing.
 pub(crate) fn event_cost_usd(e: &McpEvent) -> Option<f64> {
+    if e.dry_run {
+        return None;
+    }
     let (ip, op, crp) = pricing_for(e.model()?)?;
     let inp = e.input_tokens()? as f64;
     let out = e.output_tokens().unwrap_or(0) as f64;
@@ -222,11 +324,79 @@ pub(crate) fn session_cost_usd(events: &[McpEvent]) -> f64 {
     events.iter().filter_map(event_cost_usd).sum()
 }
 
+/// Display currency for cost estimates: `CURRENCY` picks the symbol and
+/// `USD_RATE` converts from USD. Defaults to USD at 1.0.
+pub(crate) struct Currency {
+    symbol: String,
+    rate: f64,
+}
+
+static CURRENCY: OnceLock<Currency> = OnceLock::new();
+
+pub(crate) fn currency() -> &'static Currency {
+    CURRENCY.get_or_init(|| {
+        let config = crate::models::load_config();
+        let setting = |key| crate::models::config_setting(&config, key);
+        Currency::new(
+            setting("CURRENCY").as_deref(),
+            setting("USD_RATE").as_deref(),
+        )
+    })
+}
+
+impl Currency {
+    fn new(code: Option<&str>, rate: Option<&str>) -> Self {
+        let rate = match rate.map(|r| r.trim().parse::<f64>()) {
+            None => 1.0,
+            Some(Ok(r)) if r.is_finite() && r > 0.0 => r,
+            Some(_) => {
+                eprintln!("vigilo: ignoring invalid USD_RATE, showing costs at rate 1.0");
+                1.0
+            }
+        };
+        let code = code.map(|c| c.trim().to_uppercase()).unwrap_or_default();
+        let symbol = match code.as_str() {
+            "" | "USD" => "$".to_string(),
+            "EUR" => "€".to_string(),
+            "GBP" => "£".to_string(),
+            "JPY" | "CNY" => "¥".to_string(),
+            "INR" => "₹".to_string(),
+            "KRW" => "₩".to_string(),
+            other => format!("{other} "),
+        };
+        Self { symbol, rate }
+    }
+
+    pub(crate) fn convert(&self, usd: f64) -> f64 {
+        usd * self.rate
+    }
+
+    pub(crate) fn symbol(&self) -> &str {
+        &self.symbol
+    }
+}
+
 pub(crate) fn fmt_cost(usd: f64) -> String {
-    match usd {
-        usd if usd < 0.001 => format!("${usd:.5}"),
-        usd if usd < 1.0 => format!("${usd:.4}"),
-        usd => format!("${usd:.2}"),
+    fmt_cost_in(currency(), usd)
+}
+
+fn fmt_cost_in(cur: &Currency, usd: f64) -> String {
+    let sym = cur.symbol();
+    match cur.convert(usd) {
+        v if v < 0.001 => format!("{sym}{v:.5}"),
+        v if v < 1.0 => format!("{sym}{v:.4}"),
+        v => format!("{sym}{v:.2}"),
+    }
+}
+
+/// The cache part of a token summary line: ` · cache: 1K read · 200 write`,
+/// omitting whichever side is zero.
+pub(crate) fn fmt_cache(read: u64, write: u64) -> String {
+    match (read, write) {
+        (0, 0) => String::new(),
+        (r, 0) => format!(" · cache: {} read", fmt_tokens(r)),
+        (0, w) => format!(" · cache: {} write", fmt_tokens(w)),
+        (r, w) => format!(" · cache: {} read · {} write", fmt_tokens(r), fmt_tokens(w)),
     }
 }
 
@@ -278,6 +448,12 @@ pub(crate) fn primary_arg(args: &serde_json::Value) -> serde_json::Value {
         .unwrap_or(serde_json::Value::String("—".to_string()))
 }
 
+/// The path, command or pattern a call acted on, decrypted when the key is
+/// available (whole-argument or per-field encryption).
+pub(crate) fn primary_arg_text(e: &McpEvent, key: Option<&EncryptionKey>) -> String {
+    maybe_decrypt(key, &primary_arg(&decrypt_args(key, &e.arguments)))
+}
+
 pub(crate) fn maybe_decrypt(key: Option<&EncryptionKey>, value: &serde_json::Value) -> String {
     let binding = value.to_string();
     let s = value.as_str().unwrap_or(&binding);
@@ -304,7 +480,10 @@ pub(crate) fn fmt_arg(
     }
 }
 
-fn decrypt_args(key: Option<&EncryptionKey>, args: &serde_json::Value) -> serde_json::Value {
+pub(crate) fn decrypt_args(
+    key: Option<&EncryptionKey>,
+    args: &serde_json::Value,
+) -> serde_json::Value {
     if let Some(s) = args.as_str() {
         if let Some(k) = key {
             if crypto::is_encrypted(s) {
@@ -343,12 +522,26 @@ mod tests {
         assert_eq!(fmt_duration(2_500_000), "2.5s");
     }
 
+    #[test]
+    fn fmt_span_scales_units() {
+        assert_eq!(fmt_span(42), "42s");
+        assert_eq!(fmt_span(17 * 60 + 5), "17m");
+        assert_eq!(fmt_span(2 * 3_600 + 5 * 60), "2h05m");
+    }
+
     #[test]
     fn strip_ansi_removes_escape_sequences() {
         let colored = format!("{BOLD}hello{RESET} {RED}world{RESET}");
         assert_eq!(strip_ansi(&colored), "hello world");
     }
 
+    #[test]
+    fn disabled_color_emits_no_escape_sequences() {
+        let badge = client_badge("cursor");
+        assert!(!render(&badge, false).contains("\x1b["));
+        assert_eq!(render(&badge, true), badge);
+    }
+
     #[test]
     fn strip_ansi_preserves_plain_text() {
         assert_eq!(strip_ansi("plain text"), "plain text");
@@ -378,6 +571,14 @@ mod tests {
         assert!(result.len() <= 10); // 4 chars + ellipsis
     }
 
+    #[test]
+    fn fmt_cache_omits_zero_sides() {
+        assert_eq!(fmt_cache(0, 0), "");
+        assert_eq!(fmt_cache(1500, 0), " · cache: 1K read");
+        assert_eq!(fmt_cache(0, 200), " · cache: 200 write");
+        assert_eq!(fmt_cache(1500, 200), " · cache: 1K read · 200 write");
+    }
+
     #[test]
     fn diff_summary_counts_adds_and_removes() {
         let diff = "+added\n-removed\n context\n+another add\n";
@@ -389,4 +590,60 @@ mod tests {
         let diff = "--- a/file\n+++ b/file\n+real add\n";
         assert_eq!(diff_summary(diff), (1, 0));
     }
+
+    #[test]
+    fn custom_pricing_overrides_and_extends_builtin_table() {
+        let custom = parse_pricing(
+            r#"[
+                {"match": "claude-sonnet-4", "input": 2.0, "output": 10.0, "cache_read": 0.2},
+                {"match": "Acme-Large", "input": 4.0, "output": 8.0, "cache_read": 0.0}
+            ]"#,
+        )
+        .unwrap();
+        let table = merge_pricing(custom);
+
+        let (ip, op, _) = lookup_price(&table, "claude-sonnet-4-5-20250929").unwrap();
+        assert_eq!((ip * 1e6, op * 1e6), (2.0, 10.0));
+        assert!(lookup_price(&table, "acme-large-2").is_some());
+        let (ip, ..) = lookup_price(&table, "gpt-5-mini").unwrap();
+        assert_eq!(ip * 1e6, 0.25);
+        assert_eq!(
+            table
+                .iter()
+                .filter(|e| e.fragment == "claude-sonnet-4")
+                .count(),
+            1
+        );
+    }
+
+    #[test]
+    fn malformed_pricing_is_rejected() {
+        assert!(parse_pricing("not json").is_none());
+        assert!(parse_pricing(r#"[{"match": "x", "input": 1.0}]"#).is_none());
+        assert!(parse_pricing(
+            r#"[{"match": "x", "input": -1.0, "output": 1.0, "cache_read": 0.0}]"#
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn fmt_cost_converts_with_rate_and_symbol() {
+        let usd = Currency::new(None, None);
+        assert_eq!(fmt_cost_in(&usd, 2.5), "$2.50");
+
+        let eur = Currency::new(Some("eur"), Some("0.9"));
+        assert_eq!(fmt_cost_in(&eur, 10.0), "€9.00");
+        assert_eq!(fmt_cost_in(&eur, 0.5), "€0.4500");
+
+        let chf = Currency::new(Some("CHF"), Some("0.8"));
+        assert_eq!(fmt_cost_in(&chf, 5.0), "CHF 4.00");
+    }
+
+    #[test]
+    fn invalid_usd_rate_falls_back_to_one() {
+        for rate in ["abc", "-2", "0"] {
+            let cur = Currency::new(Some("GBP"), Some(rate));
+            assert_eq!(fmt_cost_in(&cur, 3.0), "£3.00");
+        }
+    }
 }
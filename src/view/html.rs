@@ -0,0 +1,318 @@
+use super::counts::{count_files, count_models, count_tools, EventCounts};
+use super::fmt::{
+    diff_summary, fmt_arg, fmt_cache, fmt_cost, fmt_duration, fmt_tokens, maybe_decrypt,
+    risk_label, short_id,
+};
+use crate::{
+    crypto::{self, EncryptionKey},
+    models::{McpEvent, Outcome},
+};
+use std::io::Write;
+
+const STYLE: &str = "
+body { font: 14px/1.5 system-ui, sans-serif; margin: 2rem auto; max-width: 980px; color: #222; }
+h1 { font-size: 1.4rem; } h2 { font-size: 1.1rem; margin-bottom: .25rem; }
+.muted { color: #777; }
+table { border-collapse: collapse; width: 100%; margin: .5rem 0 1rem; }
+th, td { text-align: left; padding: 2px 8px; border-bottom: 1px solid #eee; vertical-align: top; }
+.session { border-top: 2px solid #ddd; margin-top: 1.5rem; }
+.risk-read { color: #0a7ea4; } .risk-write { color: #b58900; } .risk-exec { color: #d33; }
+.err { color: #d33; font-weight: 600; }
+pre.diff { background: #f7f7f7; padding: .5rem; overflow-x: auto; font-size: 12px; }
+.add { color: #1a7f37; } .del { color: #cf222e; } .ctx { color: #777; }
+";
+
+/// Renders sessions as one self-contained HTML document (inline CSS, no JS).
+pub(super) fn write_report(
+    w: &mut impl Write,
+    sessions: &[(String, Vec<McpEvent>)],
+    key: Option<&EncryptionKey>,
+) -> std::io::Result<()> {
+    writeln!(w, "<!DOCTYPE html>")?;
+    writeln!(w, "<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">")?;
+    writeln!(w, "<title>vigilo report</title>\n<style>{STYLE}</style>")?;
+    writeln!(w, "</head>\n<body>")?;
+    writeln!(w, "<h1>vigilo report</h1>")?;
+
+    let all_events: Vec<&McpEvent> = sessions.iter().flat_map(|(_, e)| e).collect();
+    write_overview(w, sessions, &all_events)?;
+    for (sid, events) in sessions {
+        write_session(w, sid, events, key)?;
+    }
+
+    writeln!(w, "</body>\n</html>")
+}
+
+fn write_overview(
+    w: &mut impl Write,
+    sessions: &[(String, Vec<McpEvent>)],
+    all_events: &[&McpEvent],
+) -> std::io::Result<()> {
+    let mut c = EventCounts::from_events(all_events);
+    c.add_cursor_tokens(sessions);
+
+    writeln!(w, "<section class=\"overview\">")?;
+    writeln!(
+        w,
+        "<p><b>{}</b> sessions · <b>{}</b> calls · <span class=\"{}\">{} errors</span> · {} total</p>",
+        sessions.len(),
+        c.total,
+        if c.errors > 0 { "err" } else { "muted" },
+        c.errors,
+        fmt_duration(c.total_us)
+    )?;
+    writeln!(
+        w,
+        "<p>risk: <span class=\"risk-read\">{} read</span> · <span class=\"risk-write\">{} write</span> · <span class=\"risk-exec\">{} exec</span></p>",
+        c.reads, c.writes, c.execs
+    )?;
+    if c.total_in > 0 || c.total_out > 0 {
+        writeln!(
+            w,
+            "<p>tokens: {} in · {} out{} · ~{}</p>",
+            fmt_tokens(c.total_in),
+            fmt_tokens(c.total_out),
+            fmt_cache(c.total_cr, c.total_cw),
+            fmt_cost(c.total_cost)
+        )?;
+    }
+    write_count_table(w, "tool", &count_tools(all_events))?;
+    write_count_table(w, "file", &count_files(all_events))?;
+    write_model_table(w, sessions, all_events)?;
+    writeln!(w, "</section>")
+}
+
+fn write_model_table(
+    w: &mut impl Write,
+    sessions: &[(String, Vec<McpEvent>)],
+    all_events: &[&McpEvent],
+) -> std::io::Result<()> {
+    let models = count_models(all_events, sessions);
+    if models.is_empty() {
+        return Ok(());
+    }
+    writeln!(
+        w,
+        "<table class=\"counts\"><tr><th>model</th><th>calls</th><th>tokens in</th><th>tokens out</th><th>cost</th></tr>"
+    )?;
+    for (model, m) in models.iter().take(super::MAX_TABLE_ROWS) {
+        writeln!(
+            w,
+            "<tr><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>~{}</td></tr>",
+            escape(model),
+            m.calls,
+            fmt_tokens(m.input),
+            fmt_tokens(m.output),
+            fmt_cost(m.cost)
+        )?;
+    }
+    writeln!(w, "</table>")
+}
+
+fn write_count_table(
+    w: &mut impl Write,
+    label: &str,
+    rows: &[(String, usize)],
+) -> std::io::Result<()> {
+    if rows.is_empty() {
+        return Ok(());
+    }
+    writeln!(
+        w,
+        "<table class=\"counts\"><tr><th>{label}</th><th>calls</th></tr>"
+    )?;
+    for (name, n) in rows.iter().take(super::MAX_TABLE_ROWS) {
+        writeln!(w, "<tr><td>{}</td><td>{n}</td></tr>", escape(name))?;
+    }
+    writeln!(w, "</table>")
+}
+
+fn write_session(
+    w: &mut impl Write,
+    sid: &str,
+    events: &[McpEvent],
+    key: Option<&EncryptionKey>,
+) -> std::io::Result<()> {
+    let Some(first) = events.first() else {
+        return Ok(());
+    };
+    let project_root = first.project.root.as_deref();
+    let date = first.timestamp.get(..16).unwrap_or(&first.timestamp);
+
+    writeln!(
+        w,
+        "<section class=\"session\" id=\"session-{}\">",
+        escape(sid)
+    )?;
+    writeln!(
+        w,
+        "<h2>{} <span class=\"muted\">{} · {}</span></h2>",
+        escape(short_id(sid)),
+        escape(&first.server),
+        escape(&date.replace('T', " "))
+    )?;
+    if let Some(name) = first.project.name.as_deref() {
+        let branch = first.project.branch.as_deref().unwrap_or("");
+        writeln!(
+            w,
+            "<p class=\"muted\">{} {}</p>",
+            escape(name),
+            escape(branch)
+        )?;
+    }
+
+    writeln!(w, "<table class=\"events\">")?;
+    writeln!(
+        w,
+        "<tr><th>time</th><th>risk</th><th>tool</th><th>argument</th><th>duration</th><th>status</th></tr>"
+    )?;
+    for e in events {
+        let risk = risk_label(e.risk);
+        let status = match &e.outcome {
+            Outcome::Err { message, .. } => {
+                format!("<span class=\"err\">{}</span>", escape(message))
+            }
+            Outcome::Blocked { reason } => {
+                format!("<span class=\"err\">blocked: {}</span>", escape(reason))
+            }
+            _ => "ok".to_string(),
+        };
+        writeln!(
+            w,
+            "<tr><td>{}</td><td class=\"risk-{risk}\">{risk}</td><td>{}</td><td>{}</td><td>{}</td><td>{status}</td></tr>",
+            e.timestamp.get(11..19).unwrap_or("??:??:??"),
+            escape(&e.tool),
+            escape(&fmt_arg(e, key, project_root)),
+            fmt_duration(e.duration_us),
+        )?;
+    }
+    writeln!(w, "</table>")?;
+
+    for e in events {
+        write_diff(w, e, key, project_root)?;
+    }
+    writeln!(w, "</section>")
+}
+
+fn write_diff(
+    w: &mut impl Write,
+    e: &McpEvent,
+    key: Option<&EncryptionKey>,
+    project_root: Option<&str>,
+) -> std::io::Result<()> {
+    let Some(raw) = e.diff.as_deref() else {
+        return Ok(());
+    };
+    let diff = if crypto::is_encrypted(raw) {
+        maybe_decrypt(key, &serde_json::json!(raw))
+    } else {
+        raw.to_string()
+    };
+    if crypto::is_encrypted(&diff) || diff == "new file" {
+        return Ok(());
+    }
+    let (added, removed) = diff_summary(&diff);
+    writeln!(
+        w,
+        "<p><b>{}</b> <span class=\"add\">+{added}</span> <span class=\"del\">-{removed}</span></p>",
+        escape(&fmt_arg(e, key, project_root))
+    )?;
+    writeln!(w, "<pre class=\"diff\">")?;
+    for line in diff.lines() {
+        let class = if line.starts_with('+') {
+            "add"
+        } else if line.starts_with('-') {
+            "del"
+        } else {
+            "ctx"
+        };
+        writeln!(w, "<span class=\"{class}\">{}</span>", escape(line))?;
+    }
+    writeln!(w, "</pre>")
+}
+
+fn escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Risk;
+
+    fn event(session_id: uuid::Uuid, tool: &str, risk: Risk, path: &str) -> McpEvent {
+        McpEvent {
+            session_id,
+            timestamp: "2026-03-01T10:00:00Z".to_string(),
+            server: "vigilo".to_string(),
+            tool: tool.to_string(),
+            risk,
+            arguments: serde_json::json!({ "path": path }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn report_has_one_section_per_session_and_escapes_content() {
+        let a = uuid::Uuid::new_v4();
+        let b = uuid::Uuid::new_v4();
+        let mut write = event(a, "write_file", Risk::Write, "src/<main>.rs");
+        write.diff = Some("@@ -1 +1 @@\n-old\n+new".to_string());
+        let sessions = vec![
+            (
+                a.to_string(),
+                vec![event(a, "read_file", Risk::Read, "src/lib.rs"), write],
+            ),
+            (
+                b.to_string(),
+                vec![event(b, "run_command", Risk::Exec, "ls")],
+            ),
+        ];
+
+        let mut out = Vec::new();
+        write_report(&mut out, &sessions, None).unwrap();
+        let html = String::from_utf8(out).unwrap();
+
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.trim_end().ends_with("</html>"));
+        assert_eq!(html.matches("<section class=\"session\"").count(), 2);
+        assert_eq!(
+            html.matches("<section").count(),
+            html.matches("</section>").count()
+        );
+        assert!(html.contains("class=\"risk-exec\""));
+        assert!(html.contains("<span class=\"add\">+new</span>"));
+        assert!(html.contains("&lt;main&gt;"));
+        assert!(!html.contains("<main>"));
+        assert!(!html.contains("\x1b["));
+    }
+
+    #[test]
+    fn report_overview_includes_call_count_and_model_costs() {
+        let sid = uuid::Uuid::new_v4();
+        let mut priced = event(sid, "read_file", Risk::Read, "src/lib.rs");
+        priced.token_usage.model = Some("claude-sonnet-4-5".to_string());
+        priced.token_usage.input_tokens = Some(1000);
+        let sessions = vec![(
+            sid.to_string(),
+            vec![
+                priced,
+                event(sid, "read_file", Risk::Read, "src/main.rs"),
+                event(sid, "run_command", Risk::Exec, "ls"),
+            ],
+        )];
+
+        let mut out = Vec::new();
+        write_report(&mut out, &sessions, None).unwrap();
+        let html = String::from_utf8(out).unwrap();
+
+        assert!(html.contains("<html"));
+        assert!(html.contains("<b>3</b> calls"));
+        assert!(html.contains("<th>model</th>"));
+        assert!(html.contains("<td>claude-sonnet-4-5</td>"));
+        assert!(!html.contains("src=\"http"));
+    }
+}
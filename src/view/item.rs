@@ -0,0 +1,138 @@
+use crate::crypto::EncryptionKey;
+use crate::models::{McpEvent, Outcome, Risk};
+use crate::view::fmt::{decrypt_args, fmt_arg, normalize_model, primary_arg};
+
+/// One event as the dashboard and `vigilo watch --json` present it.
+#[derive(serde::Serialize)]
+pub(crate) struct EventItem {
+    pub id: String,
+    pub timestamp: String,
+    pub session_id: String,
+    pub server: String,
+    pub tool: String,
+    pub risk: Risk,
+    pub duration_us: u64,
+    pub is_error: bool,
+    pub project: Option<String>,
+    pub branch: Option<String>,
+    pub arg_display: String,
+    pub input_tokens: Option<u64>,
+    pub output_tokens: Option<u64>,
+    pub cache_read_tokens: Option<u64>,
+    pub cache_write_tokens: Option<u64>,
+    pub model: Option<String>,
+    pub error_message: Option<String>,
+    pub severity: Severity,
+}
+
+#[derive(serde::Serialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub(crate) enum Severity {
+    Info,
+    Warn,
+    Danger,
+}
+
+pub(crate) fn event_to_item(e: &McpEvent, key: Option<&EncryptionKey>) -> EventItem {
+    let is_error = matches!(e.outcome, Outcome::Err { .. });
+    let project_root = e.project.root.as_deref();
+    let arg_display = fmt_arg(e, key, project_root);
+    let error_message = match &e.outcome {
+        Outcome::Err { message, .. } => Some(message.clone()),
+        _ => None,
+    };
+
+    EventItem {
+        id: e.id.to_string(),
+        timestamp: e.timestamp.clone(),
+        session_id: e.session_id.to_string(),
+        server: e.server.clone(),
+        tool: e.tool.clone(),
+        risk: e.risk,
+        duration_us: e.duration_us,
+        is_error,
+        project: e.project.name.clone(),
+        branch: e.project.branch.clone(),
+        arg_display,
+        input_tokens: e.input_tokens(),
+        output_tokens: e.output_tokens(),
+        cache_read_tokens: e.cache_read_tokens(),
+        cache_write_tokens: e.cache_write_tokens(),
+        model: e.model().map(|m| normalize_model(m).to_string()),
+        error_message,
+        severity: event_severity(e, key),
+    }
+}
+
+/// Exec and write calls warn by default; destructive commands and writes to
+/// sensitive paths escalate to danger, sensitive reads to warn.
+fn event_severity(e: &McpEvent, key: Option<&EncryptionKey>) -> Severity {
+    let args = decrypt_args(key, &e.arguments);
+    let primary = primary_arg(&args);
+    let arg = primary.as_str().unwrap_or("");
+    let sensitive = crate::danger::is_sensitive_path(arg);
+    match e.risk {
+        Risk::Exec if e.flagged.is_some() || crate::danger::dangerous_command(arg).is_some() => {
+            Severity::Danger
+        }
+        Risk::Write if sensitive => Severity::Danger,
+        Risk::Exec | Risk::Write => Severity::Warn,
+        _ if sensitive => Severity::Warn,
+        _ => Severity::Info,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(tool: &str, risk: Risk, args: serde_json::Value) -> McpEvent {
+        McpEvent {
+            tool: tool.to_string(),
+            risk,
+            arguments: args,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn event_to_item_flags_dangerous_exec() {
+        let e = event(
+            "run_command",
+            Risk::Exec,
+            serde_json::json!({"command": "rm -rf /tmp/build"}),
+        );
+        assert_eq!(event_to_item(&e, None).severity, Severity::Danger);
+    }
+
+    #[test]
+    fn event_severity_follows_risk_and_paths() {
+        let exec = event(
+            "run_command",
+            Risk::Exec,
+            serde_json::json!({"command": "ls"}),
+        );
+        assert_eq!(event_severity(&exec, None), Severity::Warn);
+
+        let read = event(
+            "read_file",
+            Risk::Read,
+            serde_json::json!({"path": "/p/main.rs"}),
+        );
+        assert_eq!(event_severity(&read, None), Severity::Info);
+
+        let secret = event(
+            "read_file",
+            Risk::Read,
+            serde_json::json!({"path": "/p/.env"}),
+        );
+        assert_eq!(event_severity(&secret, None), Severity::Warn);
+
+        let write = event(
+            "write_file",
+            Risk::Write,
+            serde_json::json!({"path": "/p/.env"}),
+        );
+        assert_eq!(event_severity(&write, None), Severity::Danger);
+    }
+}
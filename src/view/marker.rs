@@ -0,0 +1,60 @@
+use std::path::{Path, PathBuf};
+
+/// `~/.vigilo/last-viewed` holds the RFC 3339 time of the previous `vigilo view`.
+pub(super) fn marker_path() -> PathBuf {
+    crate::models::vigilo_path("last-viewed")
+}
+
+pub(super) fn read_marker(path: &Path) -> Option<String> {
+    let content = std::fs::read_to_string(path).ok()?;
+    let ts = content.trim();
+    chrono::DateTime::parse_from_rfc3339(ts).ok()?;
+    Some(ts.to_string())
+}
+
+pub(super) fn write_marker(path: &Path, ts: &str) -> std::io::Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(path, format!("{ts}\n"))
+}
+
+pub(super) fn is_newer_than(timestamp: &str, marker: &str) -> bool {
+    let parse = |s: &str| chrono::DateTime::parse_from_rfc3339(s).ok();
+    match (parse(timestamp), parse(marker)) {
+        (Some(t), Some(m)) => t > m,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn marker_round_trips_and_rejects_garbage() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("last-viewed");
+        assert_eq!(read_marker(&path), None);
+
+        write_marker(&path, "2026-03-01T12:00:00+00:00").unwrap();
+        assert_eq!(
+            read_marker(&path).as_deref(),
+            Some("2026-03-01T12:00:00+00:00")
+        );
+
+        std::fs::write(&path, "not a time").unwrap();
+        assert_eq!(read_marker(&path), None);
+    }
+
+    #[test]
+    fn is_newer_than_compares_instants() {
+        let marker = "2026-03-01T12:00:00+00:00";
+        assert!(is_newer_than("2026-03-01T12:00:01Z", marker));
+        assert!(!is_newer_than("2026-03-01T12:00:00Z", marker));
+        assert!(!is_newer_than("2026-03-01T11:59:59Z", marker));
+        // same instant expressed in another offset
+        assert!(!is_newer_than("2026-03-01T14:00:00+02:00", marker));
+        assert!(!is_newer_than("garbage", marker));
+    }
+}
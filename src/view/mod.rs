@@ -1,13 +1,23 @@
+mod anonymize;
+mod audit;
+mod cost;
 pub(crate) mod counts;
 pub(crate) mod data;
 pub(crate) mod fmt;
+mod html;
+pub(crate) mod item;
+mod marker;
 mod search;
 mod session;
 mod stats;
 
-pub use search::{diff, export, query, watch};
-pub use session::{run, sessions, tail};
-pub use stats::{errors, stats_filtered, summary};
+pub use audit::audit;
+pub use cost::cost;
+pub use search::{diff, export, query, watch, QueryArgs, QuerySort, WatchFilter};
+#[cfg(feature = "dashboard")]
+pub(crate) use search::{group_edits_by_file, render_export};
+pub use session::{run, session, sessions, tail};
+pub use stats::{errors, stats_filtered, summary, Period};
 
 #[derive(Default)]
 pub struct ViewArgs {
@@ -15,11 +25,57 @@ pub struct ViewArgs {
     pub risk: Option<String>,
     pub tool: Option<String>,
     pub session: Option<String>,
+    pub project: Option<String>,
+    pub tag: Option<String>,
     pub since: Option<String>,
     pub until: Option<String>,
     pub expand: bool,
+    pub reverse: bool,
+    pub all: bool,
+    pub dedup: bool,
+}
+
+impl ViewArgs {
+    /// Whether any flag narrows which events are shown.
+    fn is_filtered(&self) -> bool {
+        self.last.is_some()
+            || self.risk.is_some()
+            || self.tool.is_some()
+            || self.session.is_some()
+            || self.project.is_some()
+            || self.tag.is_some()
+            || self.since.is_some()
+            || self.until.is_some()
+    }
 }
 
 const COLLAPSE_HEAD: usize = 5;
 const COLLAPSE_TAIL: usize = 5;
 const MAX_TABLE_ROWS: usize = 8;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_narrowing_flags_count_as_filtered() {
+        let plain = ViewArgs {
+            expand: true,
+            reverse: true,
+            all: true,
+            dedup: true,
+            ..Default::default()
+        };
+        assert!(!plain.is_filtered());
+        let by_tool = ViewArgs {
+            tool: Some("Bash".to_string()),
+            ..Default::default()
+        };
+        assert!(by_tool.is_filtered());
+        let recent = ViewArgs {
+            last: Some(3),
+            ..Default::default()
+        };
+        assert!(recent.is_filtered());
+    }
+}
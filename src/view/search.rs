@@ -1,8 +1,9 @@
-use super::data::{load_sessions, LoadFilter};
+use super::data::{dedup_sessions, load_sessions, LoadFilter};
 use super::fmt::{
-    ceprintln, client_badge, cprintln, diff_badge, diff_summary, fmt_arg, fmt_duration,
-    maybe_decrypt, print_colored_diff, risk_decorated, risk_label, short_id, short_path, trunc,
-    BOLD, BRIGHT_RED, CYAN, DIM, GREEN, RED, RESET,
+    ceprintln, client_badge, cprintln, decrypt_args, diff_badge, diff_summary, event_cost_usd,
+    flagged_badge, fmt_arg, fmt_duration, maybe_decrypt, primary_arg_text, print_colored_diff,
+    risk_decorated, risk_label, short_id, short_path, trunc, BOLD, BRIGHT_RED, CYAN, DIM, GREEN,
+    RED, RESET,
 };
 use super::ViewArgs;
 use crate::{
@@ -11,31 +12,66 @@ use crate::{
 };
 use anyhow::{Context, Result};
 use std::fs::File;
-use std::io::{BufRead, BufReader, Seek, SeekFrom, Write};
+use std::io::{BufReader, Seek, SeekFrom, Write};
 
-pub fn query(
-    ledger_path: &str,
-    since: Option<&str>,
-    until: Option<&str>,
-    tool: Option<&str>,
-    risk: Option<&str>,
-    session: Option<&str>,
-) -> Result<()> {
+/// Output order for `vigilo query`. `Duration` and `Cost` put the largest first.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum QuerySort {
+    #[default]
+    Time,
+    Duration,
+    Cost,
+}
+
+impl QuerySort {
+    pub fn parse(s: &str) -> Result<Self> {
+        match s {
+            "time" => Ok(Self::Time),
+            "duration" => Ok(Self::Duration),
+            "cost" => Ok(Self::Cost),
+            other => anyhow::bail!("unknown sort '{other}' (expected time | duration | cost)"),
+        }
+    }
+}
+
+/// Filters for `vigilo query`; every one that is set must match.
+#[derive(Default)]
+pub struct QueryArgs<'a> {
+    pub since: Option<&'a str>,
+    pub until: Option<&'a str>,
+    pub tool: Option<&'a str>,
+    pub tool_regex: Option<&'a str>,
+    pub risk: Option<&'a str>,
+    pub session: Option<&'a str>,
+    pub project: Option<&'a str>,
+    pub tag: Option<&'a str>,
+    pub generation: Option<&'a str>,
+    pub min_duration_us: Option<u64>,
+    pub min_cost: Option<f64>,
+    pub exit_code: Option<i32>,
+    pub contains: Option<&'a str>,
+    /// Only calls the danger classifier flagged.
+    pub flagged: bool,
+    pub sort: QuerySort,
+    pub dedup: bool,
+}
+
+pub fn query(ledger_path: &str, args: &QueryArgs) -> Result<()> {
     let filter = LoadFilter {
-        since,
-        until,
-        session,
+        since: args.since,
+        until: args.until,
+        session: args.session,
+        project: args.project,
+        tag: args.tag,
         ..LoadFilter::default()
     };
-    let sessions = load_sessions(ledger_path, &filter)?;
+    let mut sessions = load_sessions(ledger_path, &filter)?;
+    if args.dedup {
+        dedup_sessions(&mut sessions);
+    }
     let key = crypto::load_key();
 
-    let events: Vec<&McpEvent> = sessions
-        .iter()
-        .flat_map(|(_, events)| events)
-        .filter(|e| tool.is_none_or(|t| e.tool == t))
-        .filter(|e| risk.is_none_or(|r| risk_label(e.risk) == r))
-        .collect();
+    let events = query_events(&sessions, args, key.as_ref())?;
 
     if events.is_empty() {
         println!("no matching events.");
@@ -55,6 +91,52 @@ pub fn query(
     Ok(())
 }
 
+fn query_events<'a>(
+    sessions: &'a [(String, Vec<McpEvent>)],
+    args: &QueryArgs,
+    key: Option<&crate::crypto::EncryptionKey>,
+) -> Result<Vec<&'a McpEvent>> {
+    let needle = args.contains.map(str::to_lowercase);
+    let tool_re = args
+        .tool_regex
+        .map(regex::Regex::new)
+        .transpose()
+        .context("invalid --tool-regex")?;
+    let mut events: Vec<&McpEvent> = sessions
+        .iter()
+        .flat_map(|(_, events)| events)
+        .filter(|e| args.tool.is_none_or(|t| e.tool == t))
+        .filter(|e| tool_re.as_ref().is_none_or(|re| re.is_match(&e.tool)))
+        .filter(|e| args.risk.is_none_or(|r| risk_label(e.risk) == r))
+        .filter(|e| args.generation.is_none_or(|g| matches_generation(e, g)))
+        .filter(|e| args.min_duration_us.is_none_or(|min| e.duration_us >= min))
+        .filter(|e| {
+            args.min_cost
+                .is_none_or(|min| event_cost_usd(e).is_some_and(|c| c >= min))
+        })
+        .filter(|e| args.exit_code.is_none_or(|code| e.exit_code == Some(code)))
+        .filter(|e| !args.flagged || e.flagged.is_some())
+        .filter(|e| {
+            needle
+                .as_deref()
+                .is_none_or(|n| primary_arg_text(e, key).to_lowercase().contains(n))
+        })
+        .collect();
+    match args.sort {
+        QuerySort::Time => events.sort_by(|a, b| a.timestamp.cmp(&b.timestamp)),
+        QuerySort::Duration => events.sort_by_key(|e| std::cmp::Reverse(e.duration_us)),
+        QuerySort::Cost => events.sort_by(|a, b| {
+            let cost = |e: &McpEvent| event_cost_usd(e).unwrap_or(0.0);
+            cost(b).total_cmp(&cost(a))
+        }),
+    }
+    Ok(events)
+}
+
+fn matches_generation(e: &McpEvent, prefix: &str) -> bool {
+    e.generation_id().is_some_and(|g| g.starts_with(prefix))
+}
+
 fn print_query_row(e: &McpEvent, key: Option<&crate::crypto::EncryptionKey>) {
     let is_error = matches!(e.outcome, Outcome::Err { .. });
     let badge = client_badge(&e.server);
@@ -77,8 +159,9 @@ fn print_query_row(e: &McpEvent, key: Option<&crate::crypto::EncryptionKey>) {
     };
     let sid_str = e.session_id.to_string();
     let sid_short = short_id(&sid_str);
+    let flagged = flagged_badge(e.flagged.as_deref());
 
-    cprintln!(" {badge}  {DIM}{date_time}{RESET}  {risk_sym} {tool_name} {arg_display}{diff}{dur}{timeout}  {DIM}{sid_short}{RESET}");
+    cprintln!(" {badge}  {DIM}{date_time}{RESET}  {risk_sym} {tool_name} {arg_display}{diff}{dur}{timeout}{flagged}  {DIM}{sid_short}{RESET}");
 }
 
 pub fn diff(ledger_path: &str, args: &ViewArgs) -> Result<()> {
@@ -87,6 +170,8 @@ pub fn diff(ledger_path: &str, args: &ViewArgs) -> Result<()> {
         since: args.since.as_deref(),
         until: args.until.as_deref(),
         session: args.session.as_deref(),
+        project: args.project.as_deref(),
+        tag: args.tag.as_deref(),
         last: args.last,
     };
     let sessions = load_sessions(ledger_path, &filter)?;
@@ -115,16 +200,8 @@ fn print_diff_session(sid: &str, events: &[McpEvent], key: Option<&crate::crypto
     };
     let badge = client_badge(&first.server);
     let sid_short = short_id(sid);
-    let project_root = first.project.root.as_deref();
 
-    let mut by_file: Vec<(String, Vec<&McpEvent>)> = Vec::new();
-    for e in &edits {
-        let path = extract_file_path(e, key, project_root);
-        match by_file.iter_mut().find(|(p, _)| p == &path) {
-            Some((_, list)) => list.push(e),
-            None => by_file.push((path, vec![e])),
-        }
-    }
+    let by_file = group_edits_by_file(events, key);
 
     println!();
     cprintln!("{DIM}── vigilo diff ── {RESET}{badge} {BOLD}{sid_short}{RESET} {DIM}────────────────────────{RESET}");
@@ -156,6 +233,23 @@ fn print_diff_session(sid: &str, events: &[McpEvent], key: Option<&crate::crypto
     );
 }
 
+/// A session's events that carry a diff, grouped by file in first-edit order.
+pub(crate) fn group_edits_by_file<'a>(
+    events: &'a [McpEvent],
+    key: Option<&crate::crypto::EncryptionKey>,
+) -> Vec<(String, Vec<&'a McpEvent>)> {
+    let project_root = events.first().and_then(|e| e.project.root.as_deref());
+    let mut by_file: Vec<(String, Vec<&McpEvent>)> = Vec::new();
+    for e in events.iter().filter(|e| e.diff.is_some()) {
+        let path = extract_file_path(e, key, project_root);
+        match by_file.iter_mut().find(|(p, _)| p == &path) {
+            Some((_, list)) => list.push(e),
+            None => by_file.push((path, vec![e])),
+        }
+    }
+    by_file
+}
+
 fn print_diff_file(path: &str, file_edits: &[&McpEvent]) -> (usize, usize) {
     let (file_add, file_rem) = file_edits
         .iter()
@@ -242,22 +336,36 @@ pub fn export(
     format: &str,
     args: &ViewArgs,
     output: Option<&str>,
+    summary: bool,
+    anonymize: bool,
 ) -> Result<()> {
     let filter = LoadFilter {
         since: args.since.as_deref(),
         until: args.until.as_deref(),
         session: args.session.as_deref(),
+        project: args.project.as_deref(),
+        tag: args.tag.as_deref(),
         last: args.last,
     };
-    let sessions = load_sessions(ledger_path, &filter)?;
-    let all_events: Vec<&McpEvent> = sessions.iter().flat_map(|(_, e)| e).collect();
+    let mut sessions = load_sessions(ledger_path, &filter)?;
+    let event_count: usize = sessions.iter().map(|(_, e)| e.len()).sum();
 
-    if all_events.is_empty() {
+    if event_count == 0 {
         eprintln!("no events to export.");
         return Ok(());
     }
+    let key = crypto::load_key();
+    if anonymize {
+        let secret = crypto::anonymize_secret()
+            .with_context(|| format!("reading {}", crypto::anonymize_secret_path().display()))?;
+        super::anonymize::anonymize_sessions(&mut sessions, key.as_ref(), &secret);
+    }
 
-    let ext = if format == "json" { "json" } else { "csv" };
+    let ext = match format {
+        "json" | "jsonl" | "ndjson" | "html" => format,
+        "markdown" | "md" => "md",
+        _ => "csv",
+    };
     let default_path = default_export_path(ext);
     let dest = output.unwrap_or(&default_path);
 
@@ -265,21 +373,62 @@ pub fn export(
         std::fs::create_dir_all(parent)?;
     }
 
-    let mut file = std::fs::File::create(dest)?;
-
-    if format == "json" {
-        let json = serde_json::to_string_pretty(&all_events.iter().collect::<Vec<_>>())
-            .map_err(|e| anyhow::anyhow!(e))?;
-        writeln!(file, "{json}")?;
-    } else {
-        write_csv(&mut file, &all_events)?;
+    if summary && format != "json" {
+        eprintln!("vigilo: --summary only applies to --format json");
     }
+    let body = render_export(&sessions, format, summary, key.as_ref())?;
+    std::fs::write(dest, body)?;
 
     let display_path = shorten_home(dest);
-    println!("exported {} events to {display_path}", all_events.len());
+    println!("exported {event_count} events to {display_path}");
     Ok(())
 }
 
+/// The export file body. Unknown formats fall back to CSV, as `export` does.
+pub(crate) fn render_export(
+    sessions: &[(String, Vec<McpEvent>)],
+    format: &str,
+    summary: bool,
+    key: Option<&crypto::EncryptionKey>,
+) -> Result<String> {
+    let all_events: Vec<&McpEvent> = sessions.iter().flat_map(|(_, e)| e).collect();
+    let mut out = Vec::new();
+    match format {
+        "json" => {
+            let json = if summary {
+                serde_json::to_string_pretty(&serde_json::json!({
+                    "summary": export_summary(&all_events),
+                    "events": all_events,
+                }))
+            } else {
+                serde_json::to_string_pretty(&all_events)
+            }?;
+            writeln!(out, "{json}")?;
+        }
+        "jsonl" | "ndjson" => write_jsonl(&mut out, &all_events, key)?,
+        "html" => super::html::write_report(&mut out, sessions, key)?,
+        "markdown" | "md" => write_markdown(&mut out, &all_events, key)?,
+        _ => write_csv(&mut out, &all_events)?,
+    }
+    Ok(String::from_utf8(out)?)
+}
+
+fn export_summary(events: &[&McpEvent]) -> serde_json::Value {
+    let c = super::counts::EventCounts::from_events(events);
+    serde_json::json!({
+        "calls": c.total,
+        "risk": { "read": c.reads, "write": c.writes, "exec": c.execs },
+        "errors": c.errors,
+        "tokens": {
+            "input": c.total_in,
+            "output": c.total_out,
+            "cache_read": c.total_cr,
+            "cache_write": c.total_cw,
+        },
+        "cost": c.total_cost,
+    })
+}
+
 fn default_export_path(ext: &str) -> String {
     crate::models::vigilo_path(&format!("export.{ext}"))
         .to_string_lossy()
@@ -290,6 +439,34 @@ fn shorten_home(path: &str) -> String {
     crate::models::shorten_home(path)
 }
 
+fn write_jsonl(
+    w: &mut impl Write,
+    events: &[&McpEvent],
+    key: Option<&crypto::EncryptionKey>,
+) -> Result<()> {
+    for e in events {
+        serde_json::to_writer(&mut *w, &decrypted_event(e, key)?)?;
+        w.write_all(b"\n")?;
+    }
+    Ok(())
+}
+
+/// The event in its ledger shape, with arguments, result and diff decrypted.
+fn decrypted_event(e: &McpEvent, key: Option<&crypto::EncryptionKey>) -> Result<serde_json::Value> {
+    let mut v = serde_json::to_value(e)?;
+    if key.is_none() {
+        return Ok(v);
+    }
+    v["arguments"] = decrypt_args(key, &e.arguments);
+    if let Outcome::Ok { result } = &e.outcome {
+        v["outcome"]["result"] = decrypt_args(key, result);
+    }
+    if let Some(diff) = e.diff.as_deref().filter(|d| crypto::is_encrypted(d)) {
+        v["diff"] = serde_json::json!(maybe_decrypt(key, &serde_json::json!(diff)));
+    }
+    Ok(v)
+}
+
 fn write_csv(w: &mut impl Write, all_events: &[&McpEvent]) -> Result<()> {
     writeln!(
         w,
@@ -298,6 +475,7 @@ fn write_csv(w: &mut impl Write, all_events: &[&McpEvent]) -> Result<()> {
     for e in all_events {
         let (status, error_msg) = match &e.outcome {
             Outcome::Err { message, .. } => ("error", message.replace('"', "\"\"").clone()),
+            Outcome::Blocked { reason } => ("blocked", reason.replace('"', "\"\"")),
             _ => ("ok", String::new()),
         };
         let risk = format!("{:?}", e.risk).to_lowercase();
@@ -340,13 +518,83 @@ fn write_csv(w: &mut impl Write, all_events: &[&McpEvent]) -> Result<()> {
     Ok(())
 }
 
-pub async fn watch(ledger_path: &str) -> Result<()> {
-    let mut file = wait_for_ledger(ledger_path).await;
-    file.seek(SeekFrom::End(0))?;
+/// GitHub-flavored table, one row per event, for pasting into PRs and issues.
+fn write_markdown(
+    w: &mut impl Write,
+    events: &[&McpEvent],
+    key: Option<&crypto::EncryptionKey>,
+) -> Result<()> {
+    writeln!(
+        w,
+        "| timestamp | session | tool | risk | arg | duration | status |"
+    )?;
+    writeln!(w, "|---|---|---|---|---|---|---|")?;
+    for e in events {
+        let ts = e
+            .timestamp
+            .get(..19)
+            .unwrap_or(&e.timestamp)
+            .replace('T', " ");
+        let sid = e.session_id.to_string();
+        let arg = trunc(&fmt_arg(e, key, e.project.root.as_deref()), 60);
+        let dur = if e.duration_us > 0 {
+            fmt_duration(e.duration_us)
+        } else {
+            String::new()
+        };
+        let status = match &e.outcome {
+            Outcome::Ok { .. } => "ok",
+            Outcome::Err { .. } => "error",
+            Outcome::Blocked { .. } => "blocked",
+        };
+        writeln!(
+            w,
+            "| {ts} | {} | {} | {} | {} | {dur} | {status} |",
+            short_id(&sid),
+            md_cell(&e.tool),
+            risk_label(e.risk),
+            md_cell(&arg),
+        )?;
+    }
+    Ok(())
+}
+
+/// Pipes would split the cell and newlines would end the row.
+fn md_cell(s: &str) -> String {
+    s.replace('|', "\\|").replace(['\n', '\r'], " ")
+}
+
+/// Filters for `vigilo watch`; every one that is set must match.
+#[derive(Default)]
+pub struct WatchFilter<'a> {
+    pub risk: Option<&'a str>,
+    pub tool: Option<&'a str>,
+    pub session: Option<&'a str>,
+    pub project: Option<&'a str>,
+}
+
+impl WatchFilter<'_> {
+    fn matches(&self, e: &McpEvent) -> bool {
+        self.risk.is_none_or(|r| risk_label(e.risk) == r)
+            && self.tool.is_none_or(|t| e.tool == t)
+            && self
+                .session
+                .is_none_or(|s| e.session_id.to_string().starts_with(s))
+            && self
+                .project
+                .is_none_or(|p| e.project.name.as_deref() == Some(p))
+    }
+}
+
+pub async fn watch(ledger_path: &str, json: bool, filter: &WatchFilter<'_>) -> Result<()> {
+    let file = wait_for_ledger(ledger_path).await;
+    let mut follower = LedgerFollower::at_end(ledger_path, file)?;
 
     let key = crypto::load_key();
-    cprintln!("{DIM}[vigilo]{RESET} watching — ctrl+c to stop");
-    println!();
+    if !json {
+        cprintln!("{DIM}[vigilo]{RESET} watching — ctrl+c to stop");
+        println!();
+    }
 
     let (tx, rx) = std::sync::mpsc::channel();
     let ledger_dir = std::path::Path::new(ledger_path)
@@ -375,15 +623,7 @@ pub async fn watch(ledger_path: &str) -> Result<()> {
         // Drain pending events (coalesce multiple writes into one read pass)
         while rx.try_recv().is_ok() {}
 
-        // Read all available lines
-        let mut got_data = false;
-        loop {
-            let mut line = String::new();
-            let n = BufReader::new(&file).read_line(&mut line)?;
-            if n == 0 {
-                break;
-            }
-            got_data = true;
+        for line in follower.poll()? {
             let trimmed = line.trim();
             if trimmed.is_empty() {
                 continue;
@@ -392,20 +632,14 @@ pub async fn watch(ledger_path: &str) -> Result<()> {
                 if e.risk == Risk::Unknown {
                     e.risk = Risk::classify(&e.tool);
                 }
-                print_watch_event(&e, key.as_ref());
-            }
-        }
-
-        if !got_data {
-            // Check for rotation (file shrank or was replaced)
-            let pos = file.stream_position()?;
-            if let Ok(f) = File::open(ledger_path) {
-                let new_len = f.metadata().map(|m| m.len()).unwrap_or(pos);
-                if new_len < pos {
-                    file = f;
-                    file.seek(SeekFrom::Start(0))?;
+                if !filter.matches(&e) {
                     continue;
                 }
+                if json {
+                    write_json_event(&mut std::io::stdout().lock(), &e, key.as_ref())?;
+                } else {
+                    print_watch_event(&e, key.as_ref());
+                }
             }
         }
 
@@ -414,6 +648,66 @@ pub async fn watch(ledger_path: &str) -> Result<()> {
     }
 }
 
+/// Reads complete lines appended to the active ledger, following it across
+/// rotation: once the path points at a new file (different inode, or shorter
+/// than what was already read), the old handle is drained first so nothing
+/// written just before the rename is lost, then reading restarts at the top
+/// of the new file.
+struct LedgerFollower {
+    path: std::path::PathBuf,
+    file: File,
+    pos: u64,
+}
+
+impl LedgerFollower {
+    fn at_end(path: &str, mut file: File) -> Result<Self> {
+        let pos = file.seek(SeekFrom::End(0))?;
+        Ok(Self {
+            path: path.into(),
+            file,
+            pos,
+        })
+    }
+
+    fn poll(&mut self) -> Result<Vec<String>> {
+        let rotated = self.rotated();
+        let mut lines = self.read_new()?;
+        if rotated {
+            if let Ok(f) = File::open(&self.path) {
+                self.file = f;
+                self.pos = 0;
+                lines.extend(self.read_new()?);
+            }
+        }
+        Ok(lines)
+    }
+
+    /// A half-written last line waits for its newline.
+    fn read_new(&mut self) -> Result<Vec<String>> {
+        self.file.seek(SeekFrom::Start(self.pos))?;
+        let (lines, consumed) = super::data::read_complete_lines(&mut BufReader::new(&self.file))?;
+        self.pos += consumed;
+        Ok(lines)
+    }
+
+    fn rotated(&self) -> bool {
+        let Ok(current) = std::fs::metadata(&self.path) else {
+            return false;
+        };
+        if current.len() < self.pos {
+            return true;
+        }
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::MetadataExt;
+            if let Ok(open) = self.file.metadata() {
+                return open.ino() != current.ino() || open.dev() != current.dev();
+            }
+        }
+        false
+    }
+}
+
 async fn wait_for_ledger(ledger_path: &str) -> File {
     loop {
         match File::open(ledger_path) {
@@ -426,6 +720,17 @@ async fn wait_for_ledger(ledger_path: &str) -> File {
     }
 }
 
+/// Writes one event as a single NDJSON line and flushes so pipes see it immediately.
+fn write_json_event(
+    w: &mut impl Write,
+    e: &McpEvent,
+    key: Option<&crate::crypto::EncryptionKey>,
+) -> std::io::Result<()> {
+    let line = serde_json::to_string(&super::item::event_to_item(e, key));
+    writeln!(w, "{}", line.map_err(std::io::Error::other)?)?;
+    w.flush()
+}
+
 fn print_watch_event(e: &McpEvent, key: Option<&crate::crypto::EncryptionKey>) {
     let is_error = matches!(e.outcome, Outcome::Err { .. });
     let badge = client_badge(&e.server);
@@ -451,3 +756,354 @@ fn print_watch_event(e: &McpEvent, key: Option<&crate::crypto::EncryptionKey>) {
         " {badge}  {DIM}{time}{RESET}  {risk_sym} {tool_name} {arg_display}{diff}{dur}{timeout}"
     );
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tool_regex_selects_matching_tools() {
+        let calls = ["git_status", "read_file", "git_commit"].map(|tool| McpEvent {
+            tool: tool.to_string(),
+            ..Default::default()
+        });
+        let sessions = vec![("s1".to_string(), calls.into())];
+        let args = QueryArgs {
+            tool_regex: Some("git_.*"),
+            ..Default::default()
+        };
+        let tools: Vec<&str> = query_events(&sessions, &args, None)
+            .unwrap()
+            .iter()
+            .map(|e| e.tool.as_str())
+            .collect();
+        assert_eq!(tools, ["git_status", "git_commit"]);
+
+        let bad = QueryArgs {
+            tool_regex: Some("git_(("),
+            ..Default::default()
+        };
+        assert!(query_events(&sessions, &bad, None).is_err());
+    }
+
+    #[test]
+    fn watch_filter_suppresses_non_matching_events() {
+        let event = |tool: &str, risk: Risk| McpEvent {
+            tool: tool.to_string(),
+            risk,
+            project: crate::models::ProjectContext {
+                name: Some("vigilo".to_string()),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let exec_only = WatchFilter {
+            risk: Some("exec"),
+            project: Some("vigilo"),
+            ..Default::default()
+        };
+        assert!(exec_only.matches(&event("Bash", Risk::Exec)));
+        assert!(!exec_only.matches(&event("Read", Risk::Read)));
+
+        let other_project = WatchFilter {
+            project: Some("elsewhere"),
+            ..Default::default()
+        };
+        assert!(!other_project.matches(&event("Bash", Risk::Exec)));
+
+        let e = event("Edit", Risk::Write);
+        let prefix = e.session_id.to_string()[..8].to_string();
+        let by_session = WatchFilter {
+            tool: Some("Edit"),
+            session: Some(&prefix),
+            ..Default::default()
+        };
+        assert!(by_session.matches(&e));
+        assert!(WatchFilter::default().matches(&e));
+    }
+
+    #[test]
+    fn follower_keeps_emitting_after_rotation() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("events.jsonl");
+        let append = |p: &std::path::Path, line: &str| {
+            let mut f = std::fs::OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(p)
+                .unwrap();
+            writeln!(f, "{line}").unwrap();
+        };
+        append(&path, "old");
+        let mut follower =
+            LedgerFollower::at_end(path.to_str().unwrap(), File::open(&path).unwrap()).unwrap();
+        assert!(follower.poll().unwrap().is_empty());
+
+        append(&path, "a");
+        assert_eq!(follower.poll().unwrap(), ["a"]);
+
+        // Written just before rotation renames the file away, then new writes
+        // land in a fresh active file.
+        append(&path, "b");
+        std::fs::rename(&path, dir.path().join("events.123.jsonl")).unwrap();
+        append(&path, "c");
+        assert_eq!(follower.poll().unwrap(), ["b", "c"]);
+
+        append(&path, "d");
+        assert_eq!(follower.poll().unwrap(), ["d"]);
+    }
+
+    #[test]
+    fn contains_matches_command_text_case_insensitively() {
+        let calls = ["git push origin main", "ls -la"].map(|command| McpEvent {
+            tool: "run_command".to_string(),
+            arguments: serde_json::json!({ "command": command }),
+            ..Default::default()
+        });
+        let sessions = vec![("s1".to_string(), calls.into())];
+        let args = QueryArgs {
+            contains: Some("PUSH"),
+            ..Default::default()
+        };
+        let found = query_events(&sessions, &args, None).unwrap();
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].arguments["command"], "git push origin main");
+    }
+
+    #[test]
+    fn flagged_filter_keeps_only_flagged_calls() {
+        let calls = [Some("force push"), None].map(|flagged| McpEvent {
+            tool: "run_command".to_string(),
+            flagged: flagged.map(str::to_string),
+            ..Default::default()
+        });
+        let sessions = vec![("s1".to_string(), calls.into())];
+        let args = QueryArgs {
+            flagged: true,
+            ..Default::default()
+        };
+        let found = query_events(&sessions, &args, None).unwrap();
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].flagged.as_deref(), Some("force push"));
+    }
+
+    #[test]
+    fn sort_duration_orders_slowest_first() {
+        let calls = [300, 1_200, 50, 900].map(|duration_us| McpEvent {
+            duration_us,
+            ..Default::default()
+        });
+        let sessions = vec![("s1".to_string(), calls.into())];
+        let args = QueryArgs {
+            sort: QuerySort::Duration,
+            ..Default::default()
+        };
+        let durations: Vec<u64> = query_events(&sessions, &args, None)
+            .unwrap()
+            .iter()
+            .map(|e| e.duration_us)
+            .collect();
+        assert_eq!(durations, [1_200, 900, 300, 50]);
+        assert!(QuerySort::parse("slowest").is_err());
+    }
+
+    #[test]
+    fn min_duration_and_cost_exclude_fast_and_cheap_calls() {
+        let call = |duration_us: u64, input_tokens: u64| {
+            let mut e = McpEvent {
+                duration_us,
+                ..Default::default()
+            };
+            e.token_usage.model = Some("claude-sonnet-4".to_string());
+            e.token_usage.input_tokens = Some(input_tokens);
+            e
+        };
+        let sessions = vec![(
+            "s1".to_string(),
+            vec![
+                call(100_000, 1_000),
+                call(3_000_000, 1_000_000),
+                call(900_000, 10),
+            ],
+        )];
+        let durations = |args: &QueryArgs| -> Vec<u64> {
+            query_events(&sessions, args, None)
+                .unwrap()
+                .iter()
+                .map(|e| e.duration_us)
+                .collect()
+        };
+
+        let slow = QueryArgs {
+            min_duration_us: crate::cli::parse_duration_us("500ms"),
+            ..Default::default()
+        };
+        assert_eq!(durations(&slow), [3_000_000, 900_000]);
+
+        let pricey = QueryArgs {
+            min_cost: Some(1.0),
+            ..Default::default()
+        };
+        assert_eq!(durations(&pricey), [3_000_000]);
+    }
+
+    #[test]
+    fn query_filters_by_exit_code() {
+        let run = |code: Option<i32>| McpEvent {
+            tool: "run_command".to_string(),
+            exit_code: code,
+            ..Default::default()
+        };
+        let sessions = vec![(
+            "s1".to_string(),
+            vec![run(Some(0)), run(Some(3)), run(None)],
+        )];
+        let args = QueryArgs {
+            exit_code: Some(3),
+            ..Default::default()
+        };
+        let found = query_events(&sessions, &args, None).unwrap();
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].exit_code, Some(3));
+    }
+
+    #[test]
+    fn matches_generation_selects_by_prefix() {
+        let mut e = McpEvent::default();
+        e.cursor_meta.generation_id = Some("gen_abc123".to_string());
+        assert!(matches_generation(&e, "gen_abc"));
+        assert!(matches_generation(&e, "gen_abc123"));
+        assert!(!matches_generation(&e, "gen_xyz"));
+        assert!(!matches_generation(&McpEvent::default(), "gen_abc"));
+    }
+
+    #[test]
+    fn write_json_event_emits_one_event_item_line() {
+        let e = McpEvent {
+            tool: "read_file".to_string(),
+            server: "vigilo".to_string(),
+            risk: Risk::Read,
+            arguments: serde_json::json!({"path": "/tmp/a.rs"}),
+            ..Default::default()
+        };
+        let mut out = Vec::new();
+        write_json_event(&mut out, &e, None).unwrap();
+        write_json_event(&mut out, &e, None).unwrap();
+
+        let text = String::from_utf8(out).unwrap();
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(lines.len(), 2);
+        let item: serde_json::Value = serde_json::from_str(lines[0]).unwrap();
+        assert_eq!(item["tool"], "read_file");
+        assert_eq!(item["risk"], "read");
+        assert_eq!(item["is_error"], false);
+        assert_eq!(item["arg_display"], "a.rs");
+    }
+
+    #[test]
+    fn markdown_export_starts_with_header_and_separator() {
+        let event = McpEvent {
+            timestamp: "2026-03-01T10:00:00Z".to_string(),
+            tool: "run_command".to_string(),
+            risk: Risk::Exec,
+            arguments: serde_json::json!({"command": "ls | wc -l"}),
+            duration_us: 1_500,
+            ..Default::default()
+        };
+        let mut out = Vec::new();
+        write_markdown(&mut out, &[&event], None).unwrap();
+        let text = String::from_utf8(out).unwrap();
+        let mut lines = text.lines();
+        assert!(lines.next().unwrap().starts_with("| timestamp |"));
+        assert_eq!(lines.next().unwrap(), "|---|---|---|---|---|---|---|");
+        let row = lines.next().unwrap();
+        assert!(row.contains("| run_command | exec |"));
+        assert!(row.contains("ls \\| wc -l"));
+    }
+
+    #[test]
+    fn export_summary_totals_match_events() {
+        let mut read = McpEvent {
+            tool: "read_file".to_string(),
+            risk: Risk::Read,
+            ..Default::default()
+        };
+        read.token_usage.input_tokens = Some(100);
+        read.token_usage.output_tokens = Some(20);
+        let write = McpEvent {
+            tool: "write_file".to_string(),
+            risk: Risk::Write,
+            outcome: Outcome::Err {
+                code: -1,
+                message: "denied".to_string(),
+            },
+            ..Default::default()
+        };
+        let exec = McpEvent {
+            tool: "run_command".to_string(),
+            risk: Risk::Exec,
+            ..Default::default()
+        };
+
+        let summary = export_summary(&[&read, &write, &exec, &read]);
+        assert_eq!(summary["calls"], 4);
+        assert_eq!(summary["risk"]["read"], 2);
+        assert_eq!(summary["risk"]["write"], 1);
+        assert_eq!(summary["risk"]["exec"], 1);
+        assert_eq!(summary["errors"], 1);
+        assert_eq!(summary["tokens"]["input"], 200);
+        assert_eq!(summary["tokens"]["output"], 40);
+    }
+
+    #[test]
+    fn write_jsonl_emits_decrypted_events_that_reload_as_ledger() {
+        let key = crypto::EncryptionKey::new([7u8; 32]);
+        let arguments = serde_json::json!({ "path": "/tmp/a.rs" });
+        let result = serde_json::json!("fn main() {}");
+        let (enc_args, enc_outcome, enc_diff) = crypto::encrypt_for_ledger(
+            Some(&key),
+            Default::default(),
+            &arguments,
+            &Outcome::Ok {
+                result: result.clone(),
+            },
+            &Some("+added".to_string()),
+        )
+        .unwrap();
+        let session_id = uuid::Uuid::new_v4();
+        let events: Vec<McpEvent> = (0..3)
+            .map(|i| McpEvent {
+                id: uuid::Uuid::new_v4(),
+                timestamp: format!("2026-01-01T00:00:0{i}Z"),
+                session_id,
+                server: "vigilo".to_string(),
+                tool: "write_file".to_string(),
+                arguments: enc_args.clone(),
+                outcome: enc_outcome.clone(),
+                diff: enc_diff.clone(),
+                risk: Risk::Write,
+                ..Default::default()
+            })
+            .collect();
+        let refs: Vec<&McpEvent> = events.iter().collect();
+
+        let mut out = Vec::new();
+        write_jsonl(&mut out, &refs, Some(&key)).unwrap();
+        let text = String::from_utf8(out).unwrap();
+        assert_eq!(text.lines().count(), 3);
+        for line in text.lines() {
+            let e: McpEvent = serde_json::from_str(line).unwrap();
+            assert_eq!(e.arguments, arguments);
+            assert_eq!(e.diff.as_deref(), Some("+added"));
+            assert!(matches!(e.outcome, Outcome::Ok { result: ref r } if *r == result));
+        }
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("events.jsonl");
+        std::fs::write(&path, &text).unwrap();
+        let sessions = load_sessions(&path, &LoadFilter::default()).unwrap();
+        assert_eq!(sessions.len(), 1);
+        assert_eq!(sessions[0].1.len(), 3);
+        assert_eq!(sessions[0].1[0].id, events[0].id);
+    }
+}
@@ -1,8 +1,11 @@
-use super::data::{cursor_session_tokens, load_sessions, load_tail_events, LoadFilter};
+use super::data::{
+    cursor_session_tokens, dedup_sessions, load_sessions, load_tail_events, merge_sessions,
+    session_merge_gap_secs, session_span_secs, LoadFilter,
+};
 use super::fmt::{
-    client_badge, cprintln, diff_badge, fmt_arg, fmt_cost, fmt_duration, fmt_tokens,
-    normalize_model, risk_decorated, risk_label, session_cost_usd, short_id, trunc, BOLD,
-    BRIGHT_RED, CYAN, DIM, RESET, YELLOW,
+    client_badge, cprintln, decrypt_args, diff_badge, flagged_badge, fmt_arg, fmt_cache, fmt_cost,
+    fmt_duration, fmt_span, fmt_tokens, maybe_decrypt, normalize_model, risk_decorated, risk_label,
+    session_cost_usd, short_id, short_path, trunc, BOLD, BRIGHT_RED, CYAN, DIM, RESET, YELLOW,
 };
 use super::{ViewArgs, COLLAPSE_HEAD, COLLAPSE_TAIL};
 use crate::{
@@ -17,16 +20,28 @@ pub fn run(ledger_path: &str, args: ViewArgs) -> Result<()> {
         since: args.since.as_deref(),
         until: args.until.as_deref(),
         session: args.session.as_deref(),
+        project: args.project.as_deref(),
+        tag: args.tag.as_deref(),
         last: args.last,
     };
-    let sessions = load_sessions(ledger_path, &filter)?;
+    let mut sessions = load_sessions(ledger_path, &filter)?;
+    if args.dedup {
+        dedup_sessions(&mut sessions);
+    }
 
     if sessions.is_empty() {
         println!("no events recorded yet.");
         return Ok(());
     }
 
-    for (sid, events) in &sessions {
+    let marker_path = super::marker::marker_path();
+    let last_viewed = if args.all {
+        None
+    } else {
+        super::marker::read_marker(&marker_path)
+    };
+
+    for (sid, events) in display_order(&sessions, args.reverse) {
         let Some(first) = events.first() else {
             continue;
         };
@@ -39,14 +54,32 @@ pub fn run(ledger_path: &str, args: ViewArgs) -> Result<()> {
             args.risk.as_deref(),
             args.tool.as_deref(),
             args.expand,
+            last_viewed.as_deref(),
         );
         print_session_footer(events, &cursor_tokens);
     }
 
     println!();
+    // A filtered view hasn't shown everything, so what it skipped stays NEW.
+    if !args.is_filtered() {
+        let _ = super::marker::write_marker(&marker_path, &chrono::Utc::now().to_rfc3339());
+    }
     Ok(())
 }
 
+/// Sessions load oldest-first; `reverse` puts the newest on top while events
+/// within each session stay chronological.
+fn display_order(
+    sessions: &[(String, Vec<McpEvent>)],
+    reverse: bool,
+) -> Vec<&(String, Vec<McpEvent>)> {
+    if reverse {
+        sessions.iter().rev().collect()
+    } else {
+        sessions.iter().collect()
+    }
+}
+
 fn print_session_header(sid: &str, first: &McpEvent) {
     let badge = client_badge(&first.server);
     let sid_short = short_id(sid);
@@ -90,6 +123,7 @@ fn print_session_events(
     risk_filter: Option<&str>,
     tool_filter: Option<&str>,
     expand: bool,
+    last_viewed: Option<&str>,
 ) {
     if let Some(last_tok) = events.iter().rev().find(|e| e.model().is_some()) {
         let model_str = normalize_model(last_tok.model().unwrap_or("unknown"));
@@ -102,8 +136,21 @@ fn print_session_events(
         .filter(|e| tool_filter.is_none_or(|t| e.tool == t))
         .collect();
 
+    // Cursor tags each agent turn with a generation id; keep a turn's calls together
+    let grouped = events.first().is_some_and(|e| e.server == "cursor")
+        && visible.iter().any(|e| e.generation_id().is_some());
+    let visible = if grouped {
+        group_by_generation(visible)
+            .into_iter()
+            .flat_map(|(_, group)| group)
+            .collect()
+    } else {
+        visible
+    };
+
     let collapse = !expand && visible.len() > COLLAPSE_HEAD + COLLAPSE_TAIL + 2;
     let total_visible = visible.len();
+    let mut current_gen: Option<&str> = None;
 
     for (i, e) in visible.iter().enumerate() {
         if collapse && i == COLLAPSE_HEAD {
@@ -113,14 +160,41 @@ fn print_session_events(
         if collapse && i >= COLLAPSE_HEAD && i < total_visible - COLLAPSE_TAIL {
             continue;
         }
-        print_event_row(e, key, project_root);
+        if grouped && e.generation_id() != current_gen {
+            current_gen = e.generation_id();
+            if let Some(gen) = current_gen {
+                cprintln!(" │  {DIM}▸ gen {}{RESET}", short_id(gen));
+            }
+        }
+        let is_new = last_viewed.is_some_and(|m| super::marker::is_newer_than(&e.timestamp, m));
+        print_event_row(e, key, project_root, is_new);
     }
 }
 
+/// Groups events by generation id in order of first appearance. Events without
+/// an id stay in place, merged only with adjacent id-less events.
+fn group_by_generation(events: Vec<&McpEvent>) -> Vec<(Option<&str>, Vec<&McpEvent>)> {
+    let mut groups: Vec<(Option<&str>, Vec<&McpEvent>)> = Vec::new();
+    for e in events {
+        match e.generation_id() {
+            Some(gen) => match groups.iter_mut().find(|(g, _)| *g == Some(gen)) {
+                Some((_, list)) => list.push(e),
+                None => groups.push((Some(gen), vec![e])),
+            },
+            None => match groups.last_mut() {
+                Some((None, list)) => list.push(e),
+                _ => groups.push((None, vec![e])),
+            },
+        }
+    }
+    groups
+}
+
 fn print_event_row(
     e: &McpEvent,
     key: Option<&crate::crypto::EncryptionKey>,
     project_root: Option<&str>,
+    is_new: bool,
 ) {
     let is_error = matches!(e.outcome, Outcome::Err { .. });
     let time = e.timestamp.get(11..19).unwrap_or("??:??:??");
@@ -136,10 +210,26 @@ fn print_event_row(
     let diff = diff_badge(e.diff.as_deref());
     let timeout = if e.timed_out {
         format!("  {BRIGHT_RED}TIMEOUT{RESET}")
+    } else if matches!(e.outcome, Outcome::Blocked { .. }) {
+        format!("  {BRIGHT_RED}BLOCKED{RESET}")
     } else {
         String::new()
     };
-    cprintln!(" │  {DIM}{time}{RESET}  {risk_sym} {tool_name} {arg_display}{diff}{dur}{timeout}");
+    let new = if is_new {
+        format!("  {YELLOW}NEW{RESET}")
+    } else {
+        String::new()
+    };
+    let flagged = flagged_badge(e.flagged.as_deref());
+    cprintln!(
+        " │  {DIM}{time}{RESET}  {risk_sym} {tool_name} {arg_display}{diff}{dur}{timeout}{flagged}{new}"
+    );
+    if let Some(ref path) = e.overflow {
+        cprintln!(
+            " │            {DIM}↳ full result: {}{RESET}",
+            crate::models::shorten_home(path)
+        );
+    }
 }
 
 fn print_session_footer(
@@ -178,13 +268,10 @@ fn print_footer_tokens(
     let sum_in: u64 = events.iter().filter_map(|e| e.input_tokens()).sum();
     let sum_out: u64 = events.iter().filter_map(|e| e.output_tokens()).sum();
     let sum_cr: u64 = events.iter().filter_map(|e| e.cache_read_tokens()).sum();
+    let sum_cw: u64 = events.iter().filter_map(|e| e.cache_write_tokens()).sum();
 
-    if sum_in > 0 || sum_out > 0 || sum_cr > 0 {
-        let cache_str = if sum_cr > 0 {
-            format!(" · cache: {} read", fmt_tokens(sum_cr))
-        } else {
-            String::new()
-        };
+    if sum_in > 0 || sum_out > 0 || sum_cr > 0 || sum_cw > 0 {
+        let cache_str = fmt_cache(sum_cr, sum_cw);
         let cost = super::fmt::session_cost_usd(events);
         let cost_str = if cost > 0.0 {
             format!(" · ~{} (list pricing)", fmt_cost(cost))
@@ -197,13 +284,9 @@ fn print_footer_tokens(
             fmt_tokens(sum_out)
         );
     } else if let Some(ct) = cursor_tokens {
-        let cache_str = if ct.cache_read_tokens > 0 {
-            format!(" · cache: {} read", fmt_tokens(ct.cache_read_tokens))
-        } else {
-            String::new()
-        };
+        let cache_str = fmt_cache(ct.cache_read_tokens, 0);
         let cost_str = if ct.cost_usd > 0.0 {
-            format!(" · ${:.2}", ct.cost_usd)
+            format!(" · ~{}", fmt_cost(ct.cost_usd))
         } else {
             String::new()
         };
@@ -216,11 +299,118 @@ fn print_footer_tokens(
     }
 }
 
+/// One session in full: every event (never collapsed), the footer totals, then
+/// per-tool counts, every file it touched and the directories it ran in.
+pub fn session(ledger_path: &str, prefix: &str) -> Result<()> {
+    let filter = LoadFilter {
+        session: Some(prefix),
+        ..Default::default()
+    };
+    let sessions = load_sessions(ledger_path, &filter)?;
+    let (sid, events) = match_one_session(&sessions, prefix)?;
+    let Some(first) = events.first() else {
+        anyhow::bail!("no matching session for '{prefix}'");
+    };
+    let key = crypto::load_key();
+    let root = first.project.root.as_deref();
+
+    print_session_header(sid, first);
+    print_session_events(events, key.as_ref(), root, None, None, true, None);
+    print_session_footer(events, &cursor_session_tokens(events));
+
+    let refs: Vec<&McpEvent> = events.iter().collect();
+    println!();
+    cprintln!("  {BOLD}tools{RESET}");
+    for (tool, n) in super::counts::count_tools(&refs) {
+        cprintln!("  {BOLD}{n:>4}×{RESET} {tool}");
+    }
+    let files = touched_files(events, root, key.as_ref());
+    if !files.is_empty() {
+        println!();
+        cprintln!("  {BOLD}files touched{RESET}");
+        for (path, n) in files {
+            cprintln!("  {BOLD}{n:>4}×{RESET} {path}");
+        }
+    }
+    let dirs = working_dirs(events, key.as_ref());
+    if !dirs.is_empty() {
+        println!();
+        cprintln!("  {BOLD}working directories{RESET}");
+        for (dir, n) in dirs {
+            cprintln!("  {BOLD}{n:>4}×{RESET} {dir}");
+        }
+    }
+    println!();
+    Ok(())
+}
+
+fn match_one_session<'a>(
+    sessions: &'a [(String, Vec<McpEvent>)],
+    prefix: &str,
+) -> Result<&'a (String, Vec<McpEvent>)> {
+    match sessions {
+        [] => anyhow::bail!("no matching session for '{prefix}'"),
+        [one] => Ok(one),
+        many => {
+            let ids: Vec<&str> = many.iter().map(|(sid, _)| short_id(sid)).collect();
+            anyhow::bail!(
+                "'{prefix}' matches {} sessions ({}); use a longer prefix",
+                many.len(),
+                ids.join(", ")
+            )
+        }
+    }
+}
+
+/// `file_path` / `path` arguments in first-touched order, relative to the
+/// project root when under it. Arguments are decrypted with `key`; ones that
+/// stay sealed are skipped.
+fn touched_files(
+    events: &[McpEvent],
+    root: Option<&str>,
+    key: Option<&crypto::EncryptionKey>,
+) -> Vec<(String, usize)> {
+    let mut files: Vec<(String, usize)> = Vec::new();
+    for e in events {
+        let arguments = decrypt_args(key, &e.arguments);
+        let Some(path) = arguments
+            .get("file_path")
+            .or_else(|| arguments.get("path"))
+            .and_then(|v| v.as_str())
+            .filter(|p| !crypto::is_encrypted(p))
+        else {
+            continue;
+        };
+        let display = short_path(path, root);
+        match files.iter_mut().find(|(p, _)| *p == display) {
+            Some((_, n)) => *n += 1,
+            None => files.push((display, 1)),
+        }
+    }
+    files
+}
+
+/// Distinct recorded `cwd`s in first-seen order, decrypted with `key`; events
+/// from older ledgers without one are skipped.
+fn working_dirs(events: &[McpEvent], key: Option<&crypto::EncryptionKey>) -> Vec<(String, usize)> {
+    let mut dirs: Vec<(String, usize)> = Vec::new();
+    for cwd in events.iter().filter_map(|e| e.cwd.as_deref()) {
+        let dir = maybe_decrypt(key, &serde_json::Value::String(cwd.to_string()));
+        match dirs.iter_mut().find(|(d, _)| *d == dir) {
+            Some((_, n)) => *n += 1,
+            None => dirs.push((dir, 1)),
+        }
+    }
+    dirs
+}
+
 pub fn sessions(ledger_path: &str, args: ViewArgs) -> Result<()> {
     let filter = LoadFilter {
         since: args.since.as_deref(),
         until: args.until.as_deref(),
         session: None,
+        project: args.project.as_deref(),
+        tag: args.tag.as_deref(),
         last: args.last,
     };
     let sessions = load_sessions(ledger_path, &filter)?;
@@ -237,19 +427,30 @@ pub fn sessions(ledger_path: &str, args: ViewArgs) -> Result<()> {
     );
     println!();
 
-    for (sid, events) in &sessions {
+    let gaps = idle_gaps(&sessions, session_merge_gap_secs());
+    for ((sid, events), gap) in sessions.iter().zip(gaps) {
         let cursor_tokens = cursor_session_tokens(events);
-        print_session_list_row(sid, events, &cursor_tokens);
+        print_session_list_row(sid, events, &cursor_tokens, gap);
     }
 
     println!();
     Ok(())
 }
 
+/// For each session, the idle seconds since the previous session it merges
+/// with, by the dashboard's merge rule.
+fn idle_gaps(sessions: &[(String, Vec<McpEvent>)], gap_secs: u64) -> Vec<Option<i64>> {
+    merge_sessions(sessions, gap_secs)
+        .into_iter()
+        .map(|slot| slot.and_then(|(_, idle)| idle))
+        .collect()
+}
+
 fn print_session_list_row(
     sid: &str,
     events: &[McpEvent],
     cursor_tokens: &Option<crate::cursor::CachedSessionTokens>,
+    idle_gap: Option<i64>,
 ) {
     let Some(first) = events.first() else {
         return;
@@ -282,8 +483,13 @@ fn print_session_list_row(
         String::new()
     };
 
+    let span = session_span_secs(events).map_or_else(|| "—".to_string(), fmt_span);
+    let gap_str = idle_gap
+        .map(|g| format!("  {DIM}↳ {} idle{RESET}", fmt_span(g)))
+        .unwrap_or_default();
+
     cprintln!(
-        "  {badge}  {DIM}{sid_short}{RESET}  {DIM}{date}{RESET}  {CYAN}{project_display:<20}{RESET}  {BOLD}{:>4}{RESET} calls  {}{cost_str}",
+        "  {badge}  {DIM}{sid_short}{RESET}  {DIM}{date}{RESET}  {CYAN}{project_display:<20}{RESET}  {BOLD}{:>4}{RESET} calls  {span:>6} span  {:>8} active{cost_str}{gap_str}",
         events.len(),
         fmt_duration(total_us)
     );
@@ -328,3 +534,207 @@ fn print_tail_row(e: &McpEvent, sid: &str, key: Option<&crate::crypto::Encryptio
         "  {DIM}{date_time}{RESET}  {risk_sym} {tool_name} {arg_display:<30}{diff}    {badge}  {DIM}{sid_short}{RESET}"
     );
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cursor_event(tool: &str, gen: Option<&str>) -> McpEvent {
+        let mut e = McpEvent {
+            server: "cursor".to_string(),
+            tool: tool.to_string(),
+            ..Default::default()
+        };
+        e.cursor_meta.generation_id = gen.map(|g| g.to_string());
+        e
+    }
+
+    fn at(server: &str, project: &str, ts: &str) -> McpEvent {
+        McpEvent {
+            server: server.to_string(),
+            timestamp: ts.to_string(),
+            project: ProjectContext {
+                name: Some(project.to_string()),
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn unknown_session_prefix_is_a_clear_error() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("events.jsonl");
+        let e = at("claude", "vigilo", "2026-03-01T10:00:00Z");
+        std::fs::write(&path, serde_json::to_string(&e).unwrap() + "\n").unwrap();
+
+        let err = session(path.to_str().unwrap(), "zzzz").unwrap_err();
+        assert_eq!(err.to_string(), "no matching session for 'zzzz'");
+
+        let sessions = vec![
+            ("aaaa1111".to_string(), vec![]),
+            ("aaaa2222".to_string(), vec![]),
+        ];
+        let Err(err) = match_one_session(&sessions, "aaaa") else {
+            panic!("an ambiguous prefix should not match");
+        };
+        assert!(err.to_string().contains("matches 2 sessions"), "{err}");
+        assert_eq!(
+            match_one_session(&sessions[..1], "aaaa").unwrap().0,
+            "aaaa1111"
+        );
+    }
+
+    #[test]
+    fn touched_files_are_relative_and_counted_in_first_seen_order() {
+        let touch = |path: &str| McpEvent {
+            arguments: serde_json::json!({ "file_path": path }),
+            ..Default::default()
+        };
+        let events = [
+            touch("/repo/src/main.rs"),
+            touch("/repo/README.md"),
+            touch("/repo/src/main.rs"),
+            McpEvent::default(),
+        ];
+        assert_eq!(
+            touched_files(&events, Some("/repo"), None),
+            [("src/main.rs".to_string(), 2), ("README.md".to_string(), 1)]
+        );
+    }
+
+    #[test]
+    fn touched_files_decrypts_paths_from_an_encrypted_ledger() {
+        let key = crypto::EncryptionKey::new([7u8; 32]);
+        let sealed = |path: &str| McpEvent {
+            session_id: uuid::Uuid::nil(),
+            arguments: serde_json::Value::String(
+                crypto::encrypt(&key, &serde_json::json!({ "file_path": path }).to_string())
+                    .unwrap(),
+            ),
+            ..Default::default()
+        };
+        let dir = tempfile::tempdir().unwrap();
+        let ledger = dir.path().join("events.jsonl");
+        let lines: String = [sealed("/repo/src/lib.rs"), sealed("/repo/src/lib.rs")]
+            .iter()
+            .map(|e| format!("{}\n", serde_json::to_string(e).unwrap()))
+            .collect();
+        std::fs::write(&ledger, lines).unwrap();
+
+        let sessions = load_sessions(ledger.to_str().unwrap(), &LoadFilter::default()).unwrap();
+        let events = &sessions[0].1;
+        assert_eq!(
+            touched_files(events, Some("/repo"), Some(&key)),
+            [("src/lib.rs".to_string(), 2)]
+        );
+        assert!(touched_files(events, Some("/repo"), None).is_empty());
+    }
+
+    #[test]
+    fn span_reflects_first_and_last_timestamps() {
+        let events = [
+            at("claude", "vigilo", "2026-03-01T10:00:00Z"),
+            at("claude", "vigilo", "2026-03-01T10:20:00Z"),
+            at("claude", "vigilo", "2026-03-01T11:05:30Z"),
+        ];
+        assert_eq!(session_span_secs(&events), Some(65 * 60 + 30));
+        assert_eq!(session_span_secs(&events[..1]), Some(0));
+        assert_eq!(session_span_secs(&[]), None);
+    }
+
+    #[test]
+    fn idle_gap_only_within_merge_window_for_same_project() {
+        let session = |server: &str, project: &str, start: &str, end: &str| {
+            (
+                String::new(),
+                vec![at(server, project, start), at(server, project, end)],
+            )
+        };
+        let sessions = vec![
+            session(
+                "claude",
+                "vigilo",
+                "2026-03-01T10:00:00Z",
+                "2026-03-01T10:30:00Z",
+            ),
+            session(
+                "cursor",
+                "vigilo",
+                "2026-03-01T10:40:00Z",
+                "2026-03-01T10:50:00Z",
+            ),
+            session(
+                "claude",
+                "vigilo",
+                "2026-03-01T11:00:00Z",
+                "2026-03-01T11:10:00Z",
+            ),
+            session(
+                "claude",
+                "vigilo",
+                "2026-03-01T15:00:00Z",
+                "2026-03-01T15:10:00Z",
+            ),
+        ];
+        let gaps = idle_gaps(&sessions, 7200);
+        assert_eq!(gaps, vec![None, None, Some(30 * 60), None]);
+    }
+
+    #[test]
+    fn group_by_generation_keeps_shared_ids_together() {
+        let events = [
+            cursor_event("Read", Some("gen_a")),
+            cursor_event("Edit", Some("gen_b")),
+            cursor_event("Bash", Some("gen_a")),
+            cursor_event("Read", Some("gen_b")),
+        ];
+        let groups = group_by_generation(events.iter().collect());
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[0].0, Some("gen_a"));
+        let tools: Vec<&str> = groups[0].1.iter().map(|e| e.tool.as_str()).collect();
+        assert_eq!(tools, vec!["Read", "Bash"]);
+        assert_eq!(groups[1].0, Some("gen_b"));
+        assert_eq!(groups[1].1.len(), 2);
+    }
+
+    #[test]
+    fn group_by_generation_leaves_untagged_events_in_place() {
+        let events = [
+            cursor_event("Read", None),
+            cursor_event("Edit", Some("gen_a")),
+            cursor_event("Bash", None),
+        ];
+        let groups = group_by_generation(events.iter().collect());
+        let keys: Vec<Option<&str>> = groups.iter().map(|(g, _)| *g).collect();
+        assert_eq!(keys, vec![None, Some("gen_a"), None]);
+    }
+
+    #[test]
+    fn display_order_reverse_puts_most_recent_session_first() {
+        let session = |id: &str, ts: &str| {
+            let e = McpEvent {
+                timestamp: ts.to_string(),
+                ..Default::default()
+            };
+            (id.to_string(), vec![e])
+        };
+        let sessions = vec![
+            session("old", "2026-01-01T09:00:00Z"),
+            session("mid", "2026-01-02T09:00:00Z"),
+            session("new", "2026-01-03T09:00:00Z"),
+        ];
+
+        let forward: Vec<&str> = display_order(&sessions, false)
+            .iter()
+            .map(|(id, _)| id.as_str())
+            .collect();
+        assert_eq!(forward, ["old", "mid", "new"]);
+
+        let reversed: Vec<&str> = display_order(&sessions, true)
+            .iter()
+            .map(|(id, _)| id.as_str())
+            .collect();
+        assert_eq!(reversed, ["new", "mid", "old"]);
+    }
+}
@@ -1,11 +1,12 @@
 use super::counts::{
-    collect_active_projects, print_error_chart, print_expanded_errors, print_models_section,
+    collect_active_projects, count_error_tools, count_files, count_models, count_projects,
+    count_tools, print_error_chart, print_expanded_errors, print_models_section,
     print_projects_section, print_recent_errors, print_tool_file_table, EventCounts,
 };
 use super::data::{load_sessions, LoadFilter};
 use super::fmt::{
-    cprintln, fmt_cost, fmt_duration, fmt_tokens, BOLD, BRIGHT_RED, CYAN, DIM, GREEN, RED, RESET,
-    YELLOW,
+    cprintln, fmt_arg, fmt_cache, fmt_cost, fmt_duration, fmt_tokens, trunc, BOLD, BRIGHT_RED,
+    CYAN, DIM, GREEN, RED, RESET, YELLOW,
 };
 use crate::{
     crypto,
@@ -13,7 +14,15 @@ use crate::{
 };
 use anyhow::Result;
 
-pub fn stats_filtered(ledger_path: &str, since: Option<&str>, until: Option<&str>) -> Result<()> {
+/// `top` caps every breakdown; without it tools and files show
+/// `MAX_TABLE_ROWS` and models and projects show them all.
+pub fn stats_filtered(
+    ledger_path: &str,
+    since: Option<&str>,
+    until: Option<&str>,
+    json: bool,
+    top: Option<usize>,
+) -> Result<()> {
     let filter = LoadFilter {
         since,
         until,
@@ -21,26 +30,98 @@ pub fn stats_filtered(ledger_path: &str, since: Option<&str>, until: Option<&str
     };
     let sessions = load_sessions(ledger_path, &filter)?;
 
-    if sessions.is_empty() {
+    if sessions.is_empty() && !json {
         println!("no events recorded yet.");
         return Ok(());
     }
 
-    let all_events: Vec<&McpEvent> = sessions.iter().flat_map(|(_, e)| e).collect();
+    let all_events: Vec<&McpEvent> = sessions
+        .iter()
+        .flat_map(|(_, e)| e)
+        .filter(|e| !e.dry_run)
+        .collect();
+    if json {
+        let stats = stats_json(&all_events, &sessions, top.unwrap_or(usize::MAX));
+        println!("{}", serde_json::to_string_pretty(&stats)?);
+        return Ok(());
+    }
     let mut c = EventCounts::from_events(&all_events);
     c.add_cursor_tokens(&sessions);
 
     print_stats_header(sessions.len(), &c);
     print_stats_tokens(&c);
-    print_tool_file_table(&all_events);
-    print_models_section(&all_events, &sessions);
-    print_projects_section(&all_events);
+    print_tool_file_table(&all_events, top.unwrap_or(super::MAX_TABLE_ROWS));
+    print_models_section(&all_events, &sessions, top.unwrap_or(usize::MAX));
+    print_projects_section(&all_events, top.unwrap_or(usize::MAX));
 
     println!();
     Ok(())
 }
 
+/// Same aggregates as the table, in a stable shape for scripts.
+fn stats_json(
+    events: &[&McpEvent],
+    sessions: &[(String, Vec<McpEvent>)],
+    top: usize,
+) -> serde_json::Value {
+    let mut c = EventCounts::from_events(events);
+    c.add_cursor_tokens(sessions);
+    let counted = |rows: Vec<(String, usize)>, key: &str| -> Vec<serde_json::Value> {
+        rows.into_iter()
+            .take(top)
+            .map(|(name, count)| serde_json::json!({ key: name, "count": count }))
+            .collect()
+    };
+    let models: Vec<_> = count_models(events, sessions)
+        .into_iter()
+        .take(top)
+        .map(|(model, s)| {
+            serde_json::json!({
+                "model": model,
+                "calls": s.calls,
+                "tokens": {
+                    "input": s.input,
+                    "output": s.output,
+                    "cache_read": s.cache_read,
+                    "cache_write": s.cache_write,
+                },
+                "cost": s.cost,
+            })
+        })
+        .collect();
+    let projects: Vec<_> = count_projects(events)
+        .into_iter()
+        .take(top)
+        .map(|p| {
+            serde_json::json!({
+                "project": p.name,
+                "calls": p.calls,
+                "risk": { "read": p.reads, "write": p.writes, "exec": p.execs },
+            })
+        })
+        .collect();
+    serde_json::json!({
+        "sessions": sessions.len(),
+        "calls": c.total,
+        "errors": c.errors,
+        "duration_us": c.total_us,
+        "risk": { "read": c.reads, "write": c.writes, "exec": c.execs },
+        "tokens": {
+            "input": c.total_in,
+            "output": c.total_out,
+            "cache_read": c.total_cr,
+            "cache_write": c.total_cw,
+        },
+        "cost": c.total_cost,
+        "tools": counted(count_tools(events), "tool"),
+        "files": counted(count_files(events), "file"),
+        "models": models,
+        "projects": projects,
+    })
+}
+
 fn print_stats_header(session_count: usize, c: &EventCounts) {
+    #[allow(clippy::manual_checked_ops)]
     let error_pct = if c.total > 0 {
         c.errors * 100 / c.total
     } else {
@@ -75,11 +156,7 @@ fn print_stats_tokens(c: &EventCounts) {
     if c.total_in == 0 && c.total_out == 0 {
         return;
     }
-    let cache_str = if c.total_cr > 0 {
-        format!(" · cache: {} read", fmt_tokens(c.total_cr))
-    } else {
-        String::new()
-    };
+    let cache_str = fmt_cache(c.total_cr, c.total_cw);
     let cost_str = if c.total_cost > 0.0 {
         format!(" · ~{}", fmt_cost(c.total_cost))
     } else {
@@ -96,29 +173,31 @@ pub fn errors(
     ledger_path: &str,
     since: Option<&str>,
     until: Option<&str>,
+    session: Option<&str>,
     expand: bool,
+    json: bool,
 ) -> Result<()> {
     let key = crypto::load_key();
     let filter = LoadFilter {
         since,
         until,
+        session,
         ..Default::default()
     };
     let sessions = load_sessions(ledger_path, &filter)?;
 
-    let all_events: Vec<&McpEvent> = sessions.iter().flat_map(|(_, e)| e).collect();
+    let (all_events, err_events) = split_errors(&sessions);
 
+    if json {
+        let report = errors_json(&all_events, &err_events, key.as_ref());
+        println!("{}", serde_json::to_string_pretty(&report)?);
+        return Ok(());
+    }
     if all_events.is_empty() {
         cprintln!("\n  {DIM}No events in the given time range.{RESET}\n");
         return Ok(());
     }
 
-    let err_events: Vec<&McpEvent> = all_events
-        .iter()
-        .filter(|e| matches!(e.outcome, Outcome::Err { .. }))
-        .copied()
-        .collect();
-
     if err_events.is_empty() {
         cprintln!("\n  {GREEN}No errors found.{RESET}\n");
         return Ok(());
@@ -126,6 +205,7 @@ pub fn errors(
 
     let total = all_events.len();
     let err_count = err_events.len();
+    #[allow(clippy::manual_checked_ops)]
     let pct = if total > 0 {
         err_count * 100 / total
     } else {
@@ -148,21 +228,103 @@ pub fn errors(
     Ok(())
 }
 
-pub fn summary(ledger_path: &str) -> Result<()> {
-    let today = chrono::Local::now()
-        .date_naive()
-        .format("%Y-%m-%d")
-        .to_string();
+/// `recent` holds the last 10 errors, newest first, as in the human view.
+fn errors_json(
+    all_events: &[&McpEvent],
+    err_events: &[&McpEvent],
+    key: Option<&crypto::EncryptionKey>,
+) -> serde_json::Value {
+    let total = all_events.len();
+    let error_count = err_events.len();
+    let by_tool: Vec<_> = count_error_tools(err_events)
+        .into_iter()
+        .map(|(tool, count)| serde_json::json!({ "tool": tool, "count": count }))
+        .collect();
+    let recent: Vec<_> = err_events
+        .iter()
+        .rev()
+        .take(10)
+        .map(|e| {
+            let message = match &e.outcome {
+                Outcome::Err { message, .. } => message.trim(),
+                _ => "",
+            };
+            serde_json::json!({
+                "tool": e.tool,
+                "timestamp": e.timestamp,
+                "arg": trunc(&fmt_arg(e, key, e.project.root.as_deref()), 35),
+                "message": message,
+            })
+        })
+        .collect();
+    serde_json::json!({
+        "total": total,
+        "error_count": error_count,
+        "percent": (error_count * 100).checked_div(total).unwrap_or(0),
+        "by_tool": by_tool,
+        "recent": recent,
+    })
+}
+
+fn split_errors(sessions: &[(String, Vec<McpEvent>)]) -> (Vec<&McpEvent>, Vec<&McpEvent>) {
+    let all_events: Vec<&McpEvent> = sessions.iter().flat_map(|(_, e)| e).collect();
+    let err_events = all_events
+        .iter()
+        .filter(|e| matches!(e.outcome, Outcome::Err { .. }))
+        .copied()
+        .collect();
+    (all_events, err_events)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Period {
+    Today,
+    Week,
+    Month,
+}
+
+impl Period {
+    pub fn parse(s: &str) -> Result<Self> {
+        match s {
+            "today" => Ok(Self::Today),
+            "week" => Ok(Self::Week),
+            "month" => Ok(Self::Month),
+            other => anyhow::bail!("unknown period '{other}' (expected today | week | month)"),
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            Self::Today => "today",
+            Self::Week => "past week",
+            Self::Month => "past month",
+        }
+    }
+
+    /// Inclusive `(since, until)` dates, using the same boundaries as `--since 1w` / `1m`.
+    fn range(self, today: chrono::NaiveDate) -> (String, String) {
+        let expr = match self {
+            Self::Today => "today",
+            Self::Week => "1w",
+            Self::Month => "1m",
+        };
+        let since = crate::cli::parse_date_from(expr, today);
+        (since, today.format("%Y-%m-%d").to_string())
+    }
+}
+
+pub fn summary(ledger_path: &str, period: Period) -> Result<()> {
+    let (since, until) = period.range(chrono::Local::now().date_naive());
 
     let filter = LoadFilter {
-        since: Some(&today),
-        until: Some(&today),
+        since: Some(&since),
+        until: Some(&until),
         ..Default::default()
     };
     let sessions = load_sessions(ledger_path, &filter)?;
 
     if sessions.is_empty() {
-        cprintln!("\n  {DIM}no sessions today.{RESET}\n");
+        cprintln!("\n  {DIM}no sessions {}.{RESET}\n", period.label());
         return Ok(());
     }
 
@@ -170,7 +332,7 @@ pub fn summary(ledger_path: &str) -> Result<()> {
     let mut c = EventCounts::from_events(&all_events);
     c.add_cursor_tokens(&sessions);
 
-    print_summary_body(sessions.len(), &c);
+    print_summary_body(period.label(), sessions.len(), &c);
     print_summary_tokens(&c);
 
     let active_projects = collect_active_projects(&sessions);
@@ -185,14 +347,15 @@ pub fn summary(ledger_path: &str) -> Result<()> {
     Ok(())
 }
 
-fn print_summary_body(session_count: usize, c: &EventCounts) {
+fn print_summary_body(label: &str, session_count: usize, c: &EventCounts) {
     let err_str = if c.errors > 0 {
         format!("{BRIGHT_RED}{} errors{RESET}", c.errors)
     } else {
         "0 errors".to_string()
     };
     println!();
-    cprintln!("{DIM}── today ───────────────────────────────────────{RESET}");
+    let rule = "─".repeat(44usize.saturating_sub(label.chars().count()));
+    cprintln!("{DIM}── {label} {rule}{RESET}");
     println!();
     cprintln!(
         "  {BOLD}{session_count}{RESET} sessions · {BOLD}{}{RESET} calls · {err_str} · {}",
@@ -211,11 +374,7 @@ fn print_summary_tokens(c: &EventCounts) {
     if c.total_in == 0 && c.total_out == 0 {
         return;
     }
-    let cache_str = if c.total_cr > 0 {
-        format!(" · cache: {} read", fmt_tokens(c.total_cr))
-    } else {
-        String::new()
-    };
+    let cache_str = fmt_cache(c.total_cr, c.total_cw);
     let cost_str = if c.total_cost > 0.0 {
         format!(" · ~{}", fmt_cost(c.total_cost))
     } else {
@@ -227,3 +386,170 @@ fn print_summary_tokens(c: &EventCounts) {
         fmt_tokens(c.total_out)
     );
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    fn failed_call(session_id: uuid::Uuid, message: &str) -> McpEvent {
+        McpEvent {
+            id: uuid::Uuid::new_v4(),
+            session_id,
+            timestamp: "2026-03-01T10:00:00Z".to_string(),
+            server: "vigilo".to_string(),
+            tool: "read_file".to_string(),
+            outcome: Outcome::Err {
+                code: -32603,
+                message: message.to_string(),
+            },
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn session_prefix_excludes_errors_from_other_sessions() {
+        let dir = tempfile::tempdir().unwrap();
+        let ledger = dir.path().join("events.jsonl");
+        let mine = uuid::Uuid::parse_str("aaaaaaaa-0000-4000-8000-000000000001").unwrap();
+        let other = uuid::Uuid::parse_str("bbbbbbbb-0000-4000-8000-000000000002").unwrap();
+        let mut f = std::fs::File::create(&ledger).unwrap();
+        for e in [
+            failed_call(mine, "mine"),
+            failed_call(other, "theirs"),
+            failed_call(other, "theirs again"),
+        ] {
+            writeln!(f, "{}", serde_json::to_string(&e).unwrap()).unwrap();
+        }
+
+        let filter = LoadFilter {
+            session: Some("aaaaaaaa"),
+            ..Default::default()
+        };
+        let sessions = load_sessions(&ledger, &filter).unwrap();
+        let (all, errs) = split_errors(&sessions);
+        assert_eq!(all.len(), 1);
+        assert_eq!(errs.len(), 1);
+        assert!(matches!(&errs[0].outcome, Outcome::Err { message, .. } if message == "mine"));
+
+        let sessions = load_sessions(&ledger, &LoadFilter::default()).unwrap();
+        assert_eq!(split_errors(&sessions).1.len(), 3);
+    }
+
+    #[test]
+    fn errors_json_counts_err_outcomes() {
+        let session = uuid::Uuid::new_v4();
+        let ok = || McpEvent {
+            tool: "read_file".to_string(),
+            ..Default::default()
+        };
+        let sessions = vec![(
+            session.to_string(),
+            vec![
+                ok(),
+                failed_call(session, "no such file"),
+                ok(),
+                failed_call(session, "  permission denied\n"),
+            ],
+        )];
+        let (all, errs) = split_errors(&sessions);
+
+        let report = errors_json(&all, &errs, None);
+        let parsed: serde_json::Value =
+            serde_json::from_str(&serde_json::to_string(&report).unwrap()).unwrap();
+        let expected = sessions[0]
+            .1
+            .iter()
+            .filter(|e| matches!(e.outcome, Outcome::Err { .. }))
+            .count();
+        assert_eq!(parsed["error_count"], expected);
+        assert_eq!(parsed["total"], 4);
+        assert_eq!(parsed["percent"], 50);
+        assert_eq!(parsed["by_tool"][0]["tool"], "read_file");
+        assert_eq!(parsed["by_tool"][0]["count"], 2);
+        assert_eq!(parsed["recent"][0]["message"], "permission denied");
+        assert_eq!(parsed["recent"][1]["message"], "no such file");
+    }
+
+    #[test]
+    fn stats_json_reports_tool_counts() {
+        let call = |tool: &str, path: &str| McpEvent {
+            tool: tool.to_string(),
+            risk: crate::models::Risk::classify(tool),
+            arguments: serde_json::json!({ "path": path }),
+            ..Default::default()
+        };
+        let sessions = vec![(
+            "s1".to_string(),
+            vec![
+                call("read_file", "/src/a.rs"),
+                call("read_file", "/src/b.rs"),
+                call("write_file", "/src/a.rs"),
+            ],
+        )];
+        let events: Vec<&McpEvent> = sessions[0].1.iter().collect();
+
+        let stats = stats_json(&events, &sessions, usize::MAX);
+        assert_eq!(stats["calls"], 3);
+        assert_eq!(stats["tools"][0]["tool"], "read_file");
+        assert_eq!(stats["tools"][0]["count"], 2);
+        assert_eq!(stats["tools"][1]["tool"], "write_file");
+        assert_eq!(stats["tools"][1]["count"], 1);
+        assert_eq!(stats["files"][0]["file"], "a.rs");
+        assert_eq!(stats["files"][0]["count"], 2);
+        assert_eq!(stats["risk"]["read"], 2);
+        assert_eq!(stats["projects"][0]["risk"]["write"], 1);
+    }
+
+    #[test]
+    fn stats_json_surfaces_cache_write_tokens() {
+        let call = |model: &str, cw: u64| {
+            let mut e = McpEvent {
+                tool: "read_file".to_string(),
+                ..Default::default()
+            };
+            e.token_usage.model = Some(model.to_string());
+            e.token_usage.input_tokens = Some(100);
+            e.token_usage.cache_write_tokens = Some(cw);
+            e
+        };
+        let sessions = vec![(
+            "s1".to_string(),
+            vec![
+                call("claude-sonnet-4-5", 2_000),
+                call("claude-sonnet-4-5", 500),
+                call("gpt-5", 300),
+            ],
+        )];
+        let events: Vec<&McpEvent> = sessions[0].1.iter().collect();
+
+        let stats = stats_json(&events, &sessions, usize::MAX);
+        assert_eq!(stats["tokens"]["cache_write"], 2_800);
+        assert_eq!(stats["models"][0]["tokens"]["cache_write"], 2_500);
+        assert_eq!(EventCounts::from_events(&events).total_cw, 2_800);
+    }
+
+    #[test]
+    fn period_range_uses_date_expression_boundaries() {
+        let today = chrono::NaiveDate::from_ymd_opt(2026, 3, 15).unwrap();
+        let range = |p: Period| p.range(today);
+        assert_eq!(
+            range(Period::Today),
+            ("2026-03-15".to_string(), "2026-03-15".to_string())
+        );
+        assert_eq!(
+            range(Period::Week),
+            ("2026-03-08".to_string(), "2026-03-15".to_string())
+        );
+        assert_eq!(
+            range(Period::Month),
+            ("2026-02-15".to_string(), "2026-03-15".to_string())
+        );
+    }
+
+    #[test]
+    fn period_parse_rejects_unknown_values() {
+        assert_eq!(Period::parse("week").unwrap(), Period::Week);
+        assert!(Period::parse("year").is_err());
+    }
+}
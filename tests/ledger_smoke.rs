@@ -78,6 +78,7 @@ fn append_event(event: &common::TestEvent, path: &str) {
     file.write_all(line.as_bytes()).unwrap();
 }
 
+#[allow(clippy::lines_filter_map_ok)]
 fn count_events(path: &str) -> usize {
     let file = fs::File::open(path).unwrap();
     BufReader::new(file)
@@ -87,6 +88,7 @@ fn count_events(path: &str) -> usize {
         .count()
 }
 
+#[allow(clippy::lines_filter_map_ok)]
 fn read_events(path: &str) -> Vec<common::TestEvent> {
     let file = fs::File::open(path).unwrap();
     BufReader::new(file)